@@ -14,7 +14,12 @@ use rayexec_postgres::PostgresDataSource;
 use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
 use rayexec_shell::session::SingleUserEngine;
 use rayexec_unity_catalog::UnityCatalogDataSource;
-use section::{AggregateFunctionWriter, ScalarFunctionWriter, TableFunctionWriter};
+use section::{
+    AggregateFunctionWriter,
+    ScalarFunctionWriter,
+    TableFunctionWriter,
+    WindowFunctionWriter,
+};
 use session::DocsSession;
 use tracing::info;
 
@@ -23,6 +28,7 @@ const FILES: &[DocFile] = &[DocFile {
     sections: &[
         ("scalar_functions", &ScalarFunctionWriter),
         ("aggregate_functions", &AggregateFunctionWriter),
+        ("window_functions", &WindowFunctionWriter),
         ("table_functions", &TableFunctionWriter),
     ],
 }];
@@ -49,6 +49,26 @@ impl SectionWriter for AggregateFunctionWriter {
     }
 }
 
+const WINDOW_FUNCTIONS_QUERY: &str = r#"
+SELECT
+    function_name as "Function name",
+    description as "Description"
+FROM list_functions()
+WHERE function_type = 'window'
+GROUP BY "Function name", "Description"
+ORDER BY "Function name";
+"#;
+
+#[derive(Debug)]
+pub struct WindowFunctionWriter;
+
+impl SectionWriter for WindowFunctionWriter {
+    fn write(&self, session: &DocsSession, output: &mut dyn fmt::Write) -> Result<()> {
+        let table = session.query(WINDOW_FUNCTIONS_QUERY)?;
+        write_markdown_table(output, table.schema(), table.iter_batches())
+    }
+}
+
 const TABLE_FUNCTIONS_QUERY: &str = r#"
 SELECT
     function_name as "Function name",
@@ -193,7 +193,8 @@ impl std::ops::IndexMut<usize> for Block {
 #[derive(Debug, Clone)]
 pub struct Sbbf(Vec<Block>);
 
-pub(crate) const SBBF_HEADER_SIZE_ESTIMATE: usize = 20;
+/// Number of bytes that should be enough to read a bloom filter header.
+pub const SBBF_HEADER_SIZE_ESTIMATE: usize = 20;
 
 /// given an initial offset, and a byte buffer, try to read out a bloom filter header and return
 /// both the header and the offset after it (for bitset).
@@ -208,7 +209,7 @@ pub(crate) fn chunk_read_bloom_filter_header_and_offset(
 /// given a [Bytes] buffer, try to read out a bloom filter header and return both the header and
 /// length of the header.
 #[inline]
-pub(crate) fn read_bloom_filter_header_and_length(
+pub fn read_bloom_filter_header_and_length(
     buffer: Bytes,
 ) -> Result<(BloomFilterHeader, u64), ParquetError> {
     let total_length = buffer.len();
@@ -310,6 +311,24 @@ impl Sbbf {
         }
     }
 
+    /// Create a bloom filter from a buffer containing the serialized header
+    /// followed by the complete bitset.
+    pub fn try_from_bytes(buffer: Bytes) -> Result<Self, ParquetError> {
+        let (header, header_len) = read_bloom_filter_header_and_length(buffer.clone())?;
+        let bitset_len: usize = header
+            .num_bytes
+            .try_into()
+            .map_err(|_| ParquetError::General("Bloom filter length is invalid".to_string()))?;
+
+        let bitset = buffer
+            .get(header_len as usize..header_len as usize + bitset_len)
+            .ok_or_else(|| {
+                ParquetError::General("Buffer doesn't contain complete bloom filter".to_string())
+            })?;
+
+        Ok(Self::new(bitset))
+    }
+
     /// Read a new bloom filter from the given offset in the given reader.
     pub(crate) fn read_from_column_chunk<R: ChunkReader>(
         column_metadata: &ColumnChunkMetaData,
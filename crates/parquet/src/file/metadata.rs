@@ -629,8 +629,8 @@ impl ColumnChunkMetaData {
         self.column_index_length
     }
 
-    /// Returns the range for the offset index if any
-    pub(crate) fn column_index_range(&self) -> Option<Range<usize>> {
+    /// Returns the range for the column index if any
+    pub fn column_index_range(&self) -> Option<Range<usize>> {
         let offset = usize::try_from(self.column_index_offset?).ok()?;
         let length = usize::try_from(self.column_index_length?).ok()?;
         Some(offset..(offset + length))
@@ -116,7 +116,9 @@ pub(crate) fn decode_offset_index(data: &[u8]) -> Result<Vec<PageLocation>, Parq
     Ok(offset.page_locations)
 }
 
-pub(crate) fn decode_column_index(data: &[u8], column_type: Type) -> Result<Index, ParquetError> {
+/// Decodes a serialized [`ColumnIndex`] for a column chunk with the given
+/// physical type.
+pub fn decode_column_index(data: &[u8], column_type: Type) -> Result<Index, ParquetError> {
     let mut prot = TCompactSliceInputProtocol::new(data);
 
     let index = ColumnIndex::read_from_in_protocol(&mut prot)?;
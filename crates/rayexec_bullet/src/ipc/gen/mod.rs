@@ -5,6 +5,7 @@
 #![allow(unused_imports)]
 #![allow(non_camel_case_types)]
 #![allow(clippy::all)]
+#![allow(mismatched_lifetime_syntaxes)]
 
 mod File;
 mod Message;
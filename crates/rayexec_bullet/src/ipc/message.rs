@@ -0,0 +1,324 @@
+//! Reading and writing encapsulated record batch messages.
+//!
+//! This only handles the message framing and buffer layout. Encoding arrays
+//! into buffers (and decoding them back out) is left to the array
+//! implementation.
+use std::collections::VecDeque;
+use std::io::{ErrorKind, Read, Write};
+
+use flatbuffers::FlatBufferBuilder;
+use rayexec_error::{OptionExt, RayexecError, Result, ResultExt};
+
+use super::gen::message::{
+    self,
+    FieldNode as IpcFieldNode,
+    MessageBuilder,
+    MessageHeader,
+    RecordBatchBuilder,
+};
+use super::gen::schema::{Buffer as IpcBuffer, MetadataVersion};
+
+/// Marker at the beginning of an encapsulated message.
+pub(crate) const CONTINUATION_MARKER: u32 = 0xFFFFFFFF;
+
+const WRITE_PAD: &[u8; 8] = &[0; 8];
+
+/// A record batch being encoded.
+///
+/// Field nodes and buffers should be pushed in depth-first order of the
+/// batch's columns.
+#[derive(Debug, Default)]
+pub struct RecordBatchWriter {
+    num_rows: usize,
+    nodes: Vec<IpcFieldNode>,
+    buffers: Vec<IpcBuffer>,
+    variadic_counts: Vec<i64>,
+    /// Message body holding all buffer data.
+    body: Vec<u8>,
+}
+
+impl RecordBatchWriter {
+    pub fn new(num_rows: usize) -> Self {
+        RecordBatchWriter {
+            num_rows,
+            ..Default::default()
+        }
+    }
+
+    /// Push a field node for an array.
+    pub fn push_node(&mut self, len: usize, null_count: usize) {
+        self.nodes
+            .push(IpcFieldNode::new(len as i64, null_count as i64));
+    }
+
+    /// Push a buffer for the current array, copying it into the message body.
+    pub fn push_buffer(&mut self, buf: &[u8]) {
+        // Buffer offsets are relative to the start of the body.
+        let offset = self.body.len();
+        self.body.extend_from_slice(buf);
+        self.buffers
+            .push(IpcBuffer::new(offset as i64, buf.len() as i64));
+
+        // Keep every buffer 8 byte aligned.
+        if !self.body.len().is_multiple_of(8) {
+            self.body
+                .extend_from_slice(&WRITE_PAD[self.body.len() % 8..]);
+        }
+    }
+
+    /// Push the number of variadic data buffers for a binary view array.
+    pub fn push_variadic_count(&mut self, count: usize) {
+        self.variadic_counts.push(count as i64);
+    }
+
+    /// Write the record batch as an encapsulated message.
+    pub fn write_to(&self, writer: &mut impl Write) -> Result<()> {
+        let mut builder = FlatBufferBuilder::new();
+
+        let nodes = builder.create_vector(&self.nodes);
+        let buffers = builder.create_vector(&self.buffers);
+        let variadic_counts = builder.create_vector(&self.variadic_counts);
+
+        let mut batch = RecordBatchBuilder::new(&mut builder);
+        batch.add_length(self.num_rows as i64);
+        batch.add_nodes(nodes);
+        batch.add_buffers(buffers);
+        batch.add_variadicBufferCounts(variadic_counts);
+        let batch = batch.finish().as_union_value();
+
+        let mut message = MessageBuilder::new(&mut builder);
+        message.add_version(MetadataVersion::V5);
+        message.add_header_type(MessageHeader::RecordBatch);
+        message.add_bodyLength(self.body.len() as i64);
+        message.add_header(batch);
+        let message = message.finish();
+
+        builder.finish(message, None);
+
+        write_encapsulated_header(writer, builder.finished_data())?;
+        writer.write_all(&self.body)?;
+
+        Ok(())
+    }
+}
+
+/// A record batch read from an encapsulated message.
+///
+/// Field nodes and buffers are returned in the order they were written.
+#[derive(Debug)]
+pub struct RecordBatchReader {
+    num_rows: usize,
+    /// (length, null_count) for each field node.
+    nodes: VecDeque<(usize, usize)>,
+    /// (offset, length) into the body for each buffer.
+    buffers: VecDeque<(usize, usize)>,
+    variadic_counts: VecDeque<usize>,
+    body: Vec<u8>,
+}
+
+impl RecordBatchReader {
+    /// Read the next record batch message from the reader.
+    ///
+    /// Returns Ok(None) if the stream is complete.
+    pub fn try_read_from(reader: &mut impl Read) -> Result<Option<Self>> {
+        let mut buf = Vec::new();
+        if !read_encapsulated_header(reader, &mut buf)? {
+            return Ok(None);
+        }
+
+        let message =
+            message::root_as_message(&buf[8..]).context("Failed to read flat buffer for batch")?;
+        let batch = match message.header_as_record_batch() {
+            Some(batch) => batch,
+            None => {
+                return Err(RayexecError::new(format!(
+                    "Unexpected header type: {:?}",
+                    message.header_type()
+                )))
+            }
+        };
+
+        if batch.compression().is_some() {
+            return Err(RayexecError::new("IPC decompression not supported"));
+        }
+
+        let nodes = batch
+            .nodes()
+            .required("nodes")?
+            .iter()
+            .map(|node| (node.length() as usize, node.null_count() as usize))
+            .collect();
+        let buffers = batch
+            .buffers()
+            .required("buffers")?
+            .iter()
+            .map(|buf| (buf.offset() as usize, buf.length() as usize))
+            .collect();
+        let variadic_counts = batch
+            .variadicBufferCounts()
+            .map(|counts| counts.iter().map(|c| c as usize).collect())
+            .unwrap_or_default();
+
+        let mut body = vec![0; message.bodyLength() as usize];
+        reader.read_exact(&mut body)?;
+
+        Ok(Some(RecordBatchReader {
+            num_rows: batch.length() as usize,
+            nodes,
+            buffers,
+            variadic_counts,
+            body,
+        }))
+    }
+
+    pub fn num_rows(&self) -> usize {
+        self.num_rows
+    }
+
+    /// Get the (length, null_count) of the next field node.
+    pub fn try_next_node(&mut self) -> Result<(usize, usize)> {
+        self.nodes.pop_front().required("missing next node")
+    }
+
+    /// Get the next buffer.
+    pub fn try_next_buffer(&mut self) -> Result<&[u8]> {
+        let (offset, len) = self.buffers.pop_front().required("missing next buffer")?;
+        self.body
+            .get(offset..offset + len)
+            .ok_or_else(|| RayexecError::new("Buffer out of bounds of message body"))
+    }
+
+    /// Get the next variadic buffer count.
+    pub fn try_next_variadic_count(&mut self) -> Result<usize> {
+        self.variadic_counts
+            .pop_front()
+            .required("missing next variadic count")
+    }
+}
+
+/// Reads an encapsulated message header.
+///
+/// May return Ok(false) if the stream is complete. A stream is complete if
+/// either the stream returns and EOF, or writes a 0 size metatadata length.
+pub(crate) fn read_encapsulated_header(reader: &mut impl Read, buf: &mut Vec<u8>) -> Result<bool> {
+    buf.truncate(0);
+    buf.resize(4, 0);
+
+    match reader.read_exact(buf) {
+        Ok(_) => (),
+        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(false),
+        Err(e) => return Err(e.into()),
+    };
+
+    if buf[0..4] != CONTINUATION_MARKER.to_le_bytes() {
+        return Err(RayexecError::new(format!(
+            "Unexpected bytes at beginning of reader: {buf:?}"
+        )));
+    }
+
+    buf.resize(8, 0);
+    let metadata_size = {
+        reader.read_exact(&mut buf[4..8])?;
+        i32::from_le_bytes(buf[4..8].try_into().unwrap())
+    };
+
+    if metadata_size == 0 {
+        return Ok(false);
+    }
+
+    buf.resize(metadata_size as usize + 8, 0);
+    reader.read_exact(&mut buf[8..])?;
+
+    Ok(true)
+}
+
+pub(crate) fn write_encapsulated_header(writer: &mut impl Write, buf: &[u8]) -> Result<()> {
+    writer.write_all(&CONTINUATION_MARKER.to_be_bytes())?;
+
+    let mut metadata_size = buf.len();
+    if !buf.len().is_multiple_of(8) {
+        metadata_size += 8 - buf.len() % 8;
+    }
+
+    writer.write_all(&i32::to_le_bytes(metadata_size as i32))?;
+    writer.write_all(buf)?;
+
+    if !buf.len().is_multiple_of(8) {
+        let idx = buf.len() % 8;
+        writer.write_all(&WRITE_PAD[idx..])?;
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn record_batch_roundtrip() {
+        let mut batch = RecordBatchWriter::new(3);
+        batch.push_node(3, 1);
+        batch.push_buffer(&[0b101]);
+        batch.push_buffer(&[1, 2, 3]);
+        batch.push_node(3, 0);
+        batch.push_variadic_count(1);
+        batch.push_buffer(&[4, 5, 6, 7, 8, 9, 10, 11, 12]);
+
+        let mut out = Vec::new();
+        batch.write_to(&mut out).unwrap();
+        batch.write_to(&mut out).unwrap();
+
+        let mut reader = Cursor::new(out);
+        for _ in 0..2 {
+            let mut got = RecordBatchReader::try_read_from(&mut reader)
+                .unwrap()
+                .unwrap();
+            assert_eq!(3, got.num_rows());
+            assert_eq!((3, 1), got.try_next_node().unwrap());
+            assert_eq!(&[0b101], got.try_next_buffer().unwrap());
+            assert_eq!(&[1, 2, 3], got.try_next_buffer().unwrap());
+            assert_eq!((3, 0), got.try_next_node().unwrap());
+            assert_eq!(1, got.try_next_variadic_count().unwrap());
+            assert_eq!(
+                &[4, 5, 6, 7, 8, 9, 10, 11, 12],
+                got.try_next_buffer().unwrap()
+            );
+            assert!(got.try_next_node().is_err());
+        }
+
+        assert!(RecordBatchReader::try_read_from(&mut reader)
+            .unwrap()
+            .is_none());
+    }
+
+    #[test]
+    fn write_encapsulated_header_unpadded() {
+        let mut out = Vec::new();
+        write_encapsulated_header(&mut out, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
+
+        assert_eq!(
+            CONTINUATION_MARKER,
+            u32::from_le_bytes(out[0..4].try_into().unwrap())
+        );
+        assert_eq!(0, out.len() % 8);
+        assert_eq!(8, i32::from_le_bytes(out[4..8].try_into().unwrap()));
+        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], &out[out.len() - 8..]);
+    }
+
+    #[test]
+    fn write_encapsulated_header_padded() {
+        let mut out = Vec::new();
+        write_encapsulated_header(&mut out, &[1, 2, 3, 4, 5]).unwrap();
+
+        assert_eq!(
+            CONTINUATION_MARKER,
+            u32::from_le_bytes(out[0..4].try_into().unwrap())
+        );
+        assert_eq!(0, out.len() % 8);
+        assert_eq!(8, i32::from_le_bytes(out[4..8].try_into().unwrap()));
+        assert_eq!(&[1, 2, 3, 4, 5, 0, 0, 0], &out[out.len() - 8..]);
+    }
+}
@@ -2,10 +2,11 @@
 //!
 //! Spec: <https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc>
 // pub mod stream;
+pub mod message;
 
 // mod batch;
 // mod compression;
-// mod gen;
+mod gen;
 // mod schema;
 
 #[derive(Debug, Clone, Default)]
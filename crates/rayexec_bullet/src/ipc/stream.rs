@@ -1,4 +1,4 @@
-use std::io::{ErrorKind, Read, Write};
+use std::io::{Read, Write};
 
 use flatbuffers::FlatBufferBuilder;
 use rayexec_error::{RayexecError, Result, ResultExt};
@@ -6,6 +6,7 @@ use rayexec_error::{RayexecError, Result, ResultExt};
 use super::batch::batch_to_ipc;
 use super::gen::message::{self, MessageBuilder, MessageHeader};
 use super::gen::schema::MetadataVersion;
+use super::message::{read_encapsulated_header, write_encapsulated_header};
 use super::schema::schema_to_ipc;
 use super::IpcConfig;
 use crate::batch::Batch;
@@ -13,9 +14,6 @@ use crate::field::Schema;
 use crate::ipc::batch::ipc_to_batch;
 use crate::ipc::schema::ipc_to_schema;
 
-/// Marker at the beginning of an encapsulated message.
-pub(crate) const CONTINUATION_MARKER: u32 = 0xFFFFFFFF;
-
 const WRITE_PAD: &[u8; 8] = &[0; 8];
 
 #[derive(Debug)]
@@ -101,42 +99,6 @@ impl<R: Read> StreamReader<R> {
     }
 }
 
-/// Reads an encapsulated message header.
-///
-/// May return Ok(false) if the stream is complete. A stream is complete if
-/// either the stream returns and EOF, or writes a 0 size metatadata length.
-fn read_encapsulated_header(reader: &mut impl Read, buf: &mut Vec<u8>) -> Result<bool> {
-    buf.truncate(0);
-    buf.resize(4, 0);
-
-    match reader.read_exact(buf) {
-        Ok(_) => (),
-        Err(e) if e.kind() == ErrorKind::UnexpectedEof => return Ok(false),
-        Err(e) => return Err(e.into()),
-    };
-
-    if buf[0..4] != CONTINUATION_MARKER.to_le_bytes() {
-        return Err(RayexecError::new(format!(
-            "Unexpected bytes at beginning of reader: {buf:?}"
-        )));
-    }
-
-    buf.resize(8, 0);
-    let metadata_size = {
-        reader.read_exact(&mut buf[4..8])?;
-        i32::from_le_bytes(buf[4..8].try_into().unwrap())
-    };
-
-    if metadata_size == 0 {
-        return Ok(false);
-    }
-
-    buf.resize(metadata_size as usize + 8, 0);
-    reader.read_exact(&mut buf[8..])?;
-
-    Ok(true)
-}
-
 #[derive(Debug)]
 #[allow(dead_code)]
 pub struct StreamWriter<W: Write> {
@@ -206,25 +168,6 @@ impl<W: Write> StreamWriter<W> {
     }
 }
 
-fn write_encapsulated_header(writer: &mut impl Write, buf: &[u8]) -> Result<()> {
-    writer.write_all(&CONTINUATION_MARKER.to_be_bytes())?;
-
-    let mut metadata_size = buf.len();
-    if buf.len() % 8 != 0 {
-        metadata_size += 8 - buf.len() % 8;
-    }
-
-    writer.write_all(&i32::to_le_bytes(metadata_size as i32))?;
-    writer.write_all(buf)?;
-
-    if buf.len() % 8 != 0 {
-        let idx = buf.len() % 8;
-        writer.write_all(&WRITE_PAD[idx..])?;
-    }
-
-    Ok(())
-}
-
 #[cfg(test)]
 mod tests {
     use std::io::{self, Read, Write};
@@ -336,32 +279,4 @@ mod tests {
         assert_eq!(batch1, got1);
         assert_eq!(batch2, got2);
     }
-
-    #[test]
-    fn write_encapsulated_header_unpadded() {
-        let mut out = Vec::new();
-        write_encapsulated_header(&mut out, &[1, 2, 3, 4, 5, 6, 7, 8]).unwrap();
-
-        assert_eq!(
-            CONTINUATION_MARKER,
-            u32::from_le_bytes(out[0..4].try_into().unwrap())
-        );
-        assert_eq!(0, out.len() % 8);
-        assert_eq!(8, i32::from_le_bytes(out[4..8].try_into().unwrap()));
-        assert_eq!(&[1, 2, 3, 4, 5, 6, 7, 8], &out[out.len() - 8..]);
-    }
-
-    #[test]
-    fn write_encapsulated_header_padded() {
-        let mut out = Vec::new();
-        write_encapsulated_header(&mut out, &[1, 2, 3, 4, 5]).unwrap();
-
-        assert_eq!(
-            CONTINUATION_MARKER,
-            u32::from_le_bytes(out[0..4].try_into().unwrap())
-        );
-        assert_eq!(0, out.len() % 8);
-        assert_eq!(8, i32::from_le_bytes(out[4..8].try_into().unwrap()));
-        assert_eq!(&[1, 2, 3, 4, 5, 0, 0, 0], &out[out.len() - 8..]);
-    }
 }
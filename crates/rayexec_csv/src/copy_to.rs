@@ -22,6 +22,10 @@ impl<R: Runtime> CopyToFunction for CsvCopyToFunction<R> {
         "csv_copy_to"
     }
 
+    fn file_extension(&self) -> Option<&'static str> {
+        Some("csv")
+    }
+
     // TODO: Access config
     fn create_sinks(
         &self,
@@ -1,72 +1,195 @@
+use std::collections::VecDeque;
 use std::fmt::{self, Debug};
+use std::sync::Arc;
 
 use futures::future::BoxFuture;
-use rayexec_error::Result;
+use futures::StreamExt;
+use rayexec_error::{RayexecError, Result};
 use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::runtime::Runtime;
-use rayexec_execution::storage::table_storage::{
-    DataTable,
-    DataTableScan,
-    EmptyTableScan,
-    ProjectedScan,
-    Projections,
-};
+use rayexec_execution::storage::multi_file::{FileProjection, MultiFileSchema};
+use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
 use rayexec_io::location::{AccessConfig, FileLocation};
-use rayexec_io::FileProvider;
+use rayexec_io::{FileProvider, FileSource};
 
+use crate::decoder::{CsvDecoder, DecoderState};
 use crate::reader::{AsyncCsvReader, CsvSchema, DialectOptions};
 
-/// Data table implementation that reads from a single file.
-///
-/// This will produce a single scan that reads the actual file, with the
-/// remaining scans being empty.
-///
-/// This should be extended to support multiple files once we add in glob
-/// support.
-#[derive(Debug)]
-pub struct SingleFileCsvDataTable<R: Runtime> {
+/// A single csv file that's part of a table.
+#[derive(Debug, Clone)]
+pub struct CsvFile {
     pub options: DialectOptions,
+    /// Schema of the file itself.
     pub csv_schema: CsvSchema,
-    pub location: FileLocation,
+}
+
+impl CsvFile {
+    /// Infer the dialect and schema for a single file from the start of the
+    /// file.
+    pub async fn infer<P>(provider: &P, location: FileLocation, conf: &AccessConfig) -> Result<Self>
+    where
+        P: FileProvider + ?Sized,
+    {
+        let mut source = provider.file_source(location.clone(), conf)?;
+
+        let mut stream = source.read_stream();
+        // TODO: Actually make sure this is a sufficient size to infer from.
+        // TODO: This throws away the buffer after inferring.
+        let infer_buf = match stream.next().await {
+            Some(result) => {
+                const INFER_SIZE: usize = 1024;
+                let buf = result?;
+                if buf.len() > INFER_SIZE {
+                    buf.slice(0..INFER_SIZE)
+                } else {
+                    buf
+                }
+            }
+            None => {
+                return Err(RayexecError::new(format!(
+                    "Stream returned no data for '{location}'"
+                )))
+            }
+        };
+
+        let dialect = DialectOptions::infer_from_sample(&infer_buf)?;
+        let mut decoder = CsvDecoder::new(dialect);
+        let mut state = DecoderState::default();
+        let _ = decoder.decode(&infer_buf, &mut state)?;
+        let completed = state.completed_records();
+        let csv_schema = CsvSchema::infer_from_records(completed)?;
+
+        Ok(CsvFile {
+            options: dialect,
+            csv_schema,
+        })
+    }
+}
+
+/// Data table implementation that reads from one or more csv files.
+///
+/// Files are distributed across partitions, with each file being read in its
+/// entirety by a single partition. Partitions without a file are empty.
+#[derive(Debug)]
+pub struct CsvDataTable<R: Runtime> {
+    /// Files in the table, in the same order as the locations in the schema.
+    ///
+    /// Files that haven't been inferred yet are None, and are inferred when
+    /// scanned.
+    pub files: Vec<Option<CsvFile>>,
+    pub schema: Arc<MultiFileSchema>,
     pub conf: AccessConfig,
     pub runtime: R,
 }
 
-impl<R: Runtime> DataTable for SingleFileCsvDataTable<R> {
+impl<R: Runtime> DataTable for CsvDataTable<R> {
     fn scan(
         &self,
         projections: Projections,
+        filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
-        let reader = self
-            .runtime
-            .file_provider()
-            .file_source(self.location.clone(), &self.conf)?;
-        let csv_reader = AsyncCsvReader::new(reader, self.csv_schema.clone(), self.options);
-
-        let mut scans: Vec<Box<dyn DataTableScan>> = vec![Box::new(ProjectedScan::new(
-            CsvFileScan { reader: csv_reader },
-            projections,
-        ))];
-        // Reset are empty (for now)
-        scans.extend((1..num_partitions).map(|_| Box::new(EmptyTableScan) as _));
+        let mut partitioned_files = vec![VecDeque::new(); num_partitions];
+
+        let locations = self.schema.locations();
+        for (idx, file_idx) in self.schema.files_matching(filters).into_iter().enumerate() {
+            partitioned_files[idx % num_partitions].push_back(PendingFile {
+                file_idx,
+                location: locations[file_idx].clone(),
+                file: self.files[file_idx].clone(),
+            });
+        }
+
+        let scans: Vec<Box<dyn DataTableScan>> = partitioned_files
+            .into_iter()
+            .map(|pending| {
+                Box::new(CsvFilesScan {
+                    runtime: self.runtime.clone(),
+                    conf: self.conf.clone(),
+                    schema: self.schema.clone(),
+                    projections: projections.clone(),
+                    pending,
+                    current: None,
+                }) as _
+            })
+            .collect();
 
         Ok(scans)
     }
 }
 
-pub struct CsvFileScan {
-    reader: AsyncCsvReader,
+#[derive(Debug, Clone)]
+struct PendingFile {
+    file_idx: usize,
+    location: FileLocation,
+    /// The file if it's already been inferred.
+    file: Option<CsvFile>,
+}
+
+pub struct CsvFilesScan<R: Runtime> {
+    runtime: R,
+    conf: AccessConfig,
+    schema: Arc<MultiFileSchema>,
+    projections: Projections,
+    pending: VecDeque<PendingFile>,
+    current: Option<(AsyncCsvReader, FileProjection)>,
+}
+
+impl<R: Runtime> CsvFilesScan<R> {
+    #[allow(deprecated)]
+    async fn pull_inner(&mut self) -> Result<Option<Batch>> {
+        loop {
+            if let Some((reader, projection)) = &mut self.current {
+                if let Some(batch) = reader.read_next().await? {
+                    // Reader produces all columns in the file.
+                    let batch = batch.project(&projection.file_columns);
+                    return Ok(Some(projection.project_batch(batch)?));
+                }
+                self.current = None;
+            }
+
+            let pending = match self.pending.pop_front() {
+                Some(pending) => pending,
+                None => return Ok(None),
+            };
+
+            let provider = self.runtime.file_provider();
+            let file = match pending.file {
+                Some(file) => file,
+                None => {
+                    let mut file =
+                        CsvFile::infer(provider.as_ref(), pending.location.clone(), &self.conf)
+                            .await?;
+                    // Read using the table's types so that values are parsed
+                    // the same way as files inferred during planning.
+                    file.csv_schema.set_types_from(&self.schema.schema);
+                    file
+                }
+            };
+
+            let projection = self.schema.file_projection(
+                pending.file_idx,
+                &file.csv_schema.schema,
+                &self.projections,
+            )?;
+
+            let source = provider.file_source(pending.location, &self.conf)?;
+            let reader = AsyncCsvReader::new(source, file.csv_schema, file.options);
+
+            self.current = Some((reader, projection));
+        }
+    }
 }
 
-impl DataTableScan for CsvFileScan {
+impl<R: Runtime> DataTableScan for CsvFilesScan<R> {
     fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
-        Box::pin(async { self.reader.read_next().await })
+        Box::pin(async { self.pull_inner().await })
     }
 }
 
-impl fmt::Debug for CsvFileScan {
+impl<R: Runtime> fmt::Debug for CsvFilesScan<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("CsvFileScan").finish_non_exhaustive()
+        f.debug_struct("CsvFilesScan").finish_non_exhaustive()
     }
 }
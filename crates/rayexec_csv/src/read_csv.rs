@@ -1,15 +1,15 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use futures::future::BoxFuture;
-use futures::{FutureExt, StreamExt};
-use rayexec_error::{RayexecError, Result};
+use futures::future::{self, BoxFuture};
+use futures::FutureExt;
+use rayexec_error::Result;
 use rayexec_execution::arrays::datatype::DataTypeId;
 use rayexec_execution::arrays::scalar::OwnedScalarValue;
 use rayexec_execution::database::DatabaseContext;
 use rayexec_execution::expr;
 use rayexec_execution::functions::table::{
-    try_location_and_access_config_from_args,
+    try_locations_and_access_config_from_args,
     PlannedTableFunction,
     ScanPlanner,
     TableFunction,
@@ -17,13 +17,11 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
 use rayexec_execution::runtime::Runtime;
-use rayexec_io::{FileProvider, FileSource};
+use rayexec_execution::storage::multi_file::{MultiFileOptions, MultiFileSchema};
 
-use crate::datatable::SingleFileCsvDataTable;
-use crate::decoder::{CsvDecoder, DecoderState};
-use crate::reader::{CsvSchema, DialectOptions};
+use crate::datatable::{CsvDataTable, CsvFile};
+use crate::reader::CsvSchema;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadCsv<R: Runtime> {
@@ -73,43 +71,47 @@ impl<R: Runtime> ReadCsv<R> {
         positional_inputs: Vec<OwnedScalarValue>,
         named_inputs: HashMap<String, OwnedScalarValue>,
     ) -> Result<PlannedTableFunction> {
-        let (location, conf) =
-            try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
+        let provider = self.runtime.file_provider();
+        let (locations, conf) = try_locations_and_access_config_from_args(
+            &self,
+            &positional_inputs,
+            &named_inputs,
+            provider.as_ref(),
+        )
+        .await?;
+        let options = MultiFileOptions::try_from_named_args(&named_inputs)?;
 
-        let mut source = self
-            .runtime
-            .file_provider()
-            .file_source(location.clone(), &conf)?;
+        // Infer the files we need to determine the schema concurrently. Any
+        // remaining files are inferred when scanned.
+        let num_infer = options.num_files_to_infer(&locations)?;
+        let mut inferred = future::try_join_all(
+            locations[..num_infer]
+                .iter()
+                .map(|location| CsvFile::infer(provider.as_ref(), location.clone(), &conf)),
+        )
+        .await?;
 
-        let mut stream = source.read_stream();
-        // TODO: Actually make sure this is a sufficient size to infer from.
-        // TODO: This throws away the buffer after inferring.
-        let infer_buf = match stream.next().await {
-            Some(result) => {
-                const INFER_SIZE: usize = 1024;
-                let buf = result?;
-                if buf.len() > INFER_SIZE {
-                    buf.slice(0..INFER_SIZE)
-                } else {
-                    buf
-                }
-            }
-            None => return Err(RayexecError::new("Stream returned no data")),
-        };
+        let mut csv_schemas: Vec<_> = inferred.iter().map(|f| f.csv_schema.clone()).collect();
+        CsvSchema::unify_types(&mut csv_schemas);
+        for (file, csv_schema) in inferred.iter_mut().zip(csv_schemas) {
+            file.csv_schema = csv_schema;
+        }
 
-        let dialect = DialectOptions::infer_from_sample(&infer_buf)?;
-        let mut decoder = CsvDecoder::new(dialect);
-        let mut state = DecoderState::default();
-        let _ = decoder.decode(&infer_buf, &mut state)?;
-        let completed = state.completed_records();
-        let csv_schema = CsvSchema::infer_from_records(completed)?;
+        let file_schemas: Vec<_> = inferred
+            .iter()
+            .map(|f| f.csv_schema.schema.clone())
+            .collect();
+        let multi_schema = MultiFileSchema::try_unify(locations, &file_schemas, options)?;
+        let schema = multi_schema.schema.clone();
+        // Only virtual columns have statistics.
+        let statistics = multi_schema.table_statistics(std::iter::empty());
 
-        let schema = csv_schema.schema.clone();
+        let mut files: Vec<_> = inferred.into_iter().map(Some).collect();
+        files.resize(multi_schema.locations().len(), None);
 
-        let datatable = SingleFileCsvDataTable {
-            options: dialect,
-            csv_schema,
-            location,
+        let datatable = CsvDataTable {
+            files,
+            schema: Arc::new(multi_schema),
             conf,
             runtime: self.runtime.clone(),
         };
@@ -119,7 +121,7 @@ impl<R: Runtime> ReadCsv<R> {
             positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
             named_inputs,
             function_impl: TableFunctionImpl::Scan(Arc::new(datatable)),
-            cardinality: StatisticsValue::Unknown,
+            statistics,
             schema,
         })
     }
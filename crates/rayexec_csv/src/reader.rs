@@ -17,6 +17,7 @@
 //!
 //! Determine if there's a header by trying to parse the first record into the
 //! inferred types from the previous step. If it differs, assume a header.
+use std::collections::HashMap;
 use std::fmt;
 
 use bytes::Bytes;
@@ -316,6 +317,48 @@ impl CsvSchema {
             has_header,
         })
     }
+
+    /// Widen column types so that columns with the same name have the same
+    /// type across all schemas.
+    ///
+    /// Inference for each file in a multi-file read only looks at a sample of
+    /// that file, so the same column may be inferred as different types.
+    pub fn unify_types(schemas: &mut [CsvSchema]) {
+        let mut types: HashMap<String, DataType> = HashMap::new();
+        for field in schemas.iter().flat_map(|s| s.schema.fields.iter()) {
+            types
+                .entry(field.name.clone())
+                .and_modify(|datatype| *datatype = widen_type(datatype, &field.datatype))
+                .or_insert_with(|| field.datatype.clone());
+        }
+
+        for field in schemas.iter_mut().flat_map(|s| s.schema.fields.iter_mut()) {
+            field.datatype = types[&field.name].clone();
+        }
+    }
+
+    /// Use the types from another schema for columns with the same name.
+    pub fn set_types_from(&mut self, schema: &Schema) {
+        for field in self.schema.fields.iter_mut() {
+            if let Some(other) = schema.iter().find(|f| f.name == field.name) {
+                field.datatype = other.datatype.clone();
+            }
+        }
+    }
+}
+
+/// Get a type that can represent values inferred as either input type.
+///
+/// Integers can always be read as floats, everything else falls back to
+/// strings.
+fn widen_type(a: &DataType, b: &DataType) -> DataType {
+    match (a, b) {
+        (a, b) if a == b => a.clone(),
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            DataType::Float64
+        }
+        _ => DataType::Utf8,
+    }
 }
 
 pub struct AsyncCsvReader {
@@ -5,8 +5,10 @@ use parking_lot::Mutex;
 use rayexec_error::{RayexecError, Result};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Field;
+use rayexec_execution::database::catalog::CatalogTx;
 use rayexec_execution::database::catalog_entry::CatalogEntry;
 use rayexec_execution::execution::operators::sink::PartitionSink;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::storage::table_storage::{
     DataTable,
     DataTableScan,
@@ -65,7 +67,12 @@ impl DebugTableStorage {
 }
 
 impl TableStorage for DebugTableStorage {
-    fn data_table(&self, schema: &str, ent: &CatalogEntry) -> Result<Box<dyn DataTable>> {
+    fn data_table(
+        &self,
+        _tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> Result<Box<dyn DataTable>> {
         let key = TableKey {
             schema: schema.to_string(),
             name: ent.name.clone(),
@@ -82,6 +89,7 @@ impl TableStorage for DebugTableStorage {
 
     fn create_physical_table(
         &self,
+        _tx: &CatalogTx,
         schema: &str,
         ent: &CatalogEntry,
     ) -> BoxFuture<'_, Result<Box<dyn DataTable>>> {
@@ -105,7 +113,12 @@ impl TableStorage for DebugTableStorage {
         })
     }
 
-    fn drop_physical_table(&self, schema: &str, ent: &CatalogEntry) -> BoxFuture<'_, Result<()>> {
+    fn drop_physical_table(
+        &self,
+        _tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> BoxFuture<'_, Result<()>> {
         let key = TableKey {
             schema: schema.to_string(),
             name: ent.name.clone(),
@@ -131,6 +144,7 @@ impl DataTable for DebugDataTable {
     fn scan(
         &self,
         projections: Projections,
+        _filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let mut scans: Vec<_> = (0..num_partitions)
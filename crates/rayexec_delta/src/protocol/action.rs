@@ -14,7 +14,7 @@ pub enum Action {
     #[serde(rename = "metaData")]
     ChangeMetadata(ActionChangeMetadata),
 
-    // TODO: stats, tags, deletionVector, baseRowId, ...
+    // TODO: tags, deletionVector, baseRowId, ...
     #[serde(rename = "add")]
     AddFile(ActionAddFile),
 
@@ -66,6 +66,43 @@ pub struct ActionAddFile {
     pub size: u64,
     pub modification_time: u64,
     pub data_change: bool,
+    /// JSON serialized statistics for the file.
+    pub stats: Option<String>,
+}
+
+impl ActionAddFile {
+    /// Deserializes the file statistics if present.
+    ///
+    /// Like the schema, statistics are stored as a json string inside the
+    /// action.
+    pub fn deserialize_stats(&self) -> Result<Option<FileStatistics>> {
+        match &self.stats {
+            Some(stats) => Ok(Some(
+                serde_json::from_str(stats).context("failed to deserialize file stats")?,
+            )),
+            None => Ok(None),
+        }
+    }
+}
+
+/// Per-file statistics from an add action.
+///
+/// See <https://github.com/delta-io/delta/blob/master/PROTOCOL.md#per-file-statistics>
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FileStatistics {
+    pub num_records: u64,
+    /// Min values keyed by column name.
+    ///
+    /// Values for nested columns will be nested json objects.
+    #[serde(default)]
+    pub min_values: HashMap<String, serde_json::Value>,
+    /// Max values keyed by column name.
+    #[serde(default)]
+    pub max_values: HashMap<String, serde_json::Value>,
+    /// Null counts keyed by column name.
+    #[serde(default)]
+    pub null_count: HashMap<String, serde_json::Value>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -171,6 +208,7 @@ mod tests {
             size: 841454,
             modification_time: 1512909768000,
             data_change: true,
+            stats: Some("{\"numRecords\":1,\"minValues\":{\"val...".to_string()),
         });
 
         assert_eq!(expected, action);
@@ -201,11 +239,33 @@ mod tests {
             size: 841454,
             modification_time: 1512909768000,
             data_change: true,
+            stats: Some("{\"numRecords\":1,\"minValues\":{\"val...".to_string()),
         });
 
         assert_eq!(expected, action);
     }
 
+    #[test]
+    fn add_file_stats() {
+        let add = ActionAddFile {
+            path: "part-00000.parquet".to_string(),
+            partition_values: HashMap::new(),
+            size: 1024,
+            modification_time: 1512909768000,
+            data_change: true,
+            stats: Some(
+                r#"{"numRecords":3,"minValues":{"a":1,"b":"x"},"maxValues":{"a":5,"b":"z"},"nullCount":{"a":0,"b":1}}"#
+                    .to_string(),
+            ),
+        };
+
+        let stats = add.deserialize_stats().unwrap().unwrap();
+        assert_eq!(3, stats.num_records);
+        assert_eq!(serde_json::json!(1), stats.min_values["a"]);
+        assert_eq!(serde_json::json!("z"), stats.max_values["b"]);
+        assert_eq!(serde_json::json!(1), stats.null_count["b"]);
+    }
+
     #[test]
     fn action_remove() {
         let input = r#"
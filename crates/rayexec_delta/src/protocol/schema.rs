@@ -31,7 +31,6 @@ pub struct MapType {
     pub value_type: SchemaType,
 }
 
-// TODO: Timestamp timezone
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub enum PrimitiveType {
@@ -46,7 +45,11 @@ pub enum PrimitiveType {
     Boolean,
     Binary,
     Date,
+    /// Microsecond precision timestamp adjusted to UTC.
     Timestamp,
+    /// Microsecond precision timestamp without a time zone.
+    #[serde(rename = "timestamp_ntz")]
+    TimestampNtz,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -82,6 +85,34 @@ mod tests {
         assert_eq!(expected, field);
     }
 
+    #[test]
+    fn struct_field_timestamps() {
+        let input = r#"
+        {
+          "name" : "ts",
+          "type" : "timestamp",
+          "nullable" : true,
+          "metadata" : { }
+        }
+        "#;
+        let field: StructField = serde_json::from_str(input).unwrap();
+        assert_eq!(SchemaType::Primitive(PrimitiveType::Timestamp), field.typ);
+
+        let input = r#"
+        {
+          "name" : "ts_ntz",
+          "type" : "timestamp_ntz",
+          "nullable" : true,
+          "metadata" : { }
+        }
+        "#;
+        let field: StructField = serde_json::from_str(input).unwrap();
+        assert_eq!(
+            SchemaType::Primitive(PrimitiveType::TimestampNtz),
+            field.typ
+        );
+    }
+
     #[test]
     fn struct_field_array() {
         let input = r#"
@@ -1,4 +1,4 @@
-use std::collections::VecDeque;
+use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
 use futures::{StreamExt, TryStreamExt};
@@ -7,6 +7,8 @@ use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::datatype::{DataType, DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
 use rayexec_execution::arrays::field::{Field, Schema};
 use rayexec_execution::arrays::scalar::decimal::{Decimal128Type, DecimalType};
+use rayexec_execution::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use rayexec_execution::logical::statistics::{ColumnStatistics, StatisticsValue, TableStatistics};
 use rayexec_execution::storage::table_storage::Projections;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::{FileProvider, FileSource};
@@ -14,7 +16,7 @@ use rayexec_parquet::metadata::Metadata;
 use rayexec_parquet::reader::AsyncBatchReader;
 use serde_json::Deserializer;
 
-use super::action::Action;
+use super::action::{Action, FileStatistics};
 use super::schema::{StructField, StructType};
 use super::snapshot::Snapshot;
 use crate::protocol::schema::{PrimitiveType, SchemaType};
@@ -131,6 +133,28 @@ impl Table {
         schema_from_struct_type(schema)
     }
 
+    /// Get table statistics from the stats recorded on each add action.
+    ///
+    /// Row counts are only known if every file has stats.
+    pub fn statistics(&self) -> Result<TableStatistics> {
+        let schema = self.table_schema()?;
+
+        let mut combined: Option<TableStatistics> = None;
+        for add in self.snapshot.add.values() {
+            let file_stats = match add.deserialize_stats()? {
+                Some(stats) => file_statistics(&schema, &stats),
+                None => return Ok(TableStatistics::unknown()),
+            };
+
+            combined = Some(match combined {
+                Some(combined) => combined.merge(file_stats),
+                None => file_stats,
+            });
+        }
+
+        Ok(combined.unwrap_or(TableStatistics::with_num_rows(StatisticsValue::Exact(0))))
+    }
+
     // TODO: batch size, projection
     // TODO: Reference partition values.
     // TODO: Properly filter based on deletion vector.
@@ -245,6 +269,57 @@ impl TableScan {
     }
 }
 
+/// Convert stats for a single file into table statistics.
+fn file_statistics(schema: &Schema, stats: &FileStatistics) -> TableStatistics {
+    let columns = schema
+        .fields
+        .iter()
+        .map(|field| {
+            let null_count = match stats.null_count.get(&field.name).and_then(|v| v.as_u64()) {
+                Some(count) => StatisticsValue::Exact(count as usize),
+                None => StatisticsValue::Unknown,
+            };
+
+            let bound = |values: &HashMap<String, serde_json::Value>| match values
+                .get(&field.name)
+                .and_then(|v| json_to_scalar(v, &field.datatype))
+            {
+                Some(v) => StatisticsValue::Exact(v),
+                None => StatisticsValue::Unknown,
+            };
+
+            ColumnStatistics {
+                num_distinct: StatisticsValue::Unknown,
+                null_count,
+                min: bound(&stats.min_values),
+                max: bound(&stats.max_values),
+            }
+        })
+        .collect();
+
+    TableStatistics {
+        num_rows: StatisticsValue::Exact(stats.num_records as usize),
+        columns,
+    }
+}
+
+/// Convert a json min/max value to a scalar of the given type.
+///
+/// Returns None for types we don't currently handle.
+fn json_to_scalar(value: &serde_json::Value, datatype: &DataType) -> Option<OwnedScalarValue> {
+    Some(match datatype {
+        DataType::Boolean => ScalarValue::Boolean(value.as_bool()?),
+        DataType::Int8 => ScalarValue::Int8(value.as_i64()?.try_into().ok()?),
+        DataType::Int16 => ScalarValue::Int16(value.as_i64()?.try_into().ok()?),
+        DataType::Int32 => ScalarValue::Int32(value.as_i64()?.try_into().ok()?),
+        DataType::Int64 => ScalarValue::Int64(value.as_i64()?),
+        DataType::Float32 => ScalarValue::Float32(value.as_f64()? as f32),
+        DataType::Float64 => ScalarValue::Float64(value.as_f64()?),
+        DataType::Utf8 => ScalarValue::from(value.as_str()?.to_string()),
+        _ => return None,
+    })
+}
+
 /// Create a schema from a struct type representing the schema of a delta table.
 pub fn schema_from_struct_type(typ: StructType) -> Result<Schema> {
     let fields = typ
@@ -273,6 +348,9 @@ fn struct_field_to_field(field: StructField) -> Result<Field> {
             PrimitiveType::Binary => DataType::Binary,
             PrimitiveType::Date => DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Second)), // TODO: This is just year/month/day
             PrimitiveType::Timestamp => {
+                DataType::Timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "UTC"))
+            }
+            PrimitiveType::TimestampNtz => {
                 DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
             }
         },
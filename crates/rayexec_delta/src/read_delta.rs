@@ -17,7 +17,6 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
 use rayexec_execution::runtime::Runtime;
 
 use crate::datatable::DeltaDataTable;
@@ -78,6 +77,7 @@ impl<R: Runtime> ReadDelta<R> {
 
         let table = Table::load(location.clone(), provider, conf.clone()).await?;
         let schema = table.table_schema()?;
+        let statistics = table.statistics()?;
 
         Ok(PlannedTableFunction {
             function: Box::new(self),
@@ -86,7 +86,7 @@ impl<R: Runtime> ReadDelta<R> {
             function_impl: TableFunctionImpl::Scan(Arc::new(DeltaDataTable {
                 table: Arc::new(table), // TODO: Arc Arc
             })),
-            cardinality: StatisticsValue::Unknown,
+            statistics,
             schema,
         })
     }
@@ -1,9 +1,10 @@
 use core::alloc;
 use std::fmt::Debug;
 use std::ops::Deref;
+use std::sync::atomic::{self, AtomicUsize};
 use std::sync::Arc;
 
-use rayexec_error::{Result, ResultExt};
+use rayexec_error::{RayexecError, Result, ResultExt};
 
 pub trait BufferManager: Debug + Sync + Send + Sized {
     // TODO: T => Spillable or something.
@@ -71,6 +72,31 @@ where
     pub fn layout(&self) -> alloc::Layout {
         alloc::Layout::from_size_align(self.size, self.align).unwrap()
     }
+
+    /// Merge another reservation from the same manager into this one.
+    ///
+    /// The size of this reservation will be increased by the size of `other`,
+    /// and `other` will not need to be dropped separately.
+    pub fn merge(&mut self, other: Reservation<B>) {
+        debug_assert!(Arc::ptr_eq(&self.manager, &other.manager));
+        self.size += other.size;
+    }
+
+    /// Split off some number of bytes from this reservation into a new
+    /// reservation.
+    ///
+    /// The size of this reservation will be decreased by the size of the
+    /// returned reservation.
+    pub fn split(&mut self, size_bytes: usize) -> Reservation<B> {
+        let size = usize::min(size_bytes, self.size);
+        self.size -= size;
+
+        Reservation {
+            manager: self.manager.clone(),
+            size,
+            align: self.align,
+        }
+    }
 }
 
 // TODO: Probably rename, I don't think we want the 'cow' logic on this. Instead
@@ -113,3 +139,163 @@ impl BufferManager for NopBufferManager {
         // Ok
     }
 }
+
+/// Buffer manager that tracks the total number of bytes reserved, optionally
+/// enforcing a limit on that total.
+///
+/// Operators that buffer large amounts of data (sorts, hash aggregates, hash
+/// joins) reserve memory through this manager, and spill to disk once a
+/// reservation would exceed the limit.
+#[derive(Debug)]
+pub struct LimitedBufferManager {
+    /// Maximum number of bytes that can be reserved, `usize::MAX` if there's no
+    /// limit.
+    limit: AtomicUsize,
+    /// Number of bytes currently reserved.
+    reserved: AtomicUsize,
+}
+
+impl LimitedBufferManager {
+    pub fn new(limit: Option<usize>) -> Self {
+        LimitedBufferManager {
+            limit: AtomicUsize::new(limit.unwrap_or(usize::MAX)),
+            reserved: AtomicUsize::new(0),
+        }
+    }
+
+    /// Set the memory limit.
+    ///
+    /// Existing reservations are kept even if they exceed the new limit.
+    pub fn set_limit(&self, limit: Option<usize>) {
+        self.limit
+            .store(limit.unwrap_or(usize::MAX), atomic::Ordering::Relaxed);
+    }
+
+    pub fn limit(&self) -> Option<usize> {
+        match self.limit.load(atomic::Ordering::Relaxed) {
+            usize::MAX => None,
+            limit => Some(limit),
+        }
+    }
+
+    /// Number of bytes currently reserved.
+    pub fn reserved(&self) -> usize {
+        self.reserved.load(atomic::Ordering::Relaxed)
+    }
+
+    /// Try to reserve some number of bytes.
+    ///
+    /// Returns `None` if the reservation would exceed the memory limit.
+    pub fn try_reserve(self: &Arc<Self>, size_bytes: usize) -> Result<Option<Reservation<Self>>> {
+        let limit = self.limit.load(atomic::Ordering::Relaxed);
+        let result = self.reserved.fetch_update(
+            atomic::Ordering::Relaxed,
+            atomic::Ordering::Relaxed,
+            |reserved| {
+                let new_reserved = reserved.checked_add(size_bytes)?;
+                if new_reserved > limit {
+                    None
+                } else {
+                    Some(new_reserved)
+                }
+            },
+        );
+
+        match result {
+            Ok(_) => Ok(Some(Reservation::try_new(self.clone(), size_bytes, 1)?)),
+            Err(_) => Ok(None),
+        }
+    }
+
+    /// Reserve some number of bytes without checking the memory limit.
+    ///
+    /// Used to account for memory that's already been allocated.
+    pub fn reserve_unchecked(self: &Arc<Self>, size_bytes: usize) -> Result<Reservation<Self>> {
+        self.reserved
+            .fetch_add(size_bytes, atomic::Ordering::Relaxed);
+        Reservation::try_new(self.clone(), size_bytes, 1)
+    }
+}
+
+impl BufferManager for LimitedBufferManager {
+    type CowPtr<T>
+        = Arc<T>
+    where
+        T: Debug;
+
+    fn reserve_external(
+        self: &Arc<Self>,
+        size_bytes: usize,
+        align: usize,
+    ) -> Result<Reservation<Self>> {
+        match self.try_reserve(size_bytes)? {
+            Some(mut reservation) => {
+                reservation.align = align;
+                Ok(reservation)
+            }
+            None => Err(RayexecError::new(format!(
+                "Memory limit of {} bytes exceeded",
+                self.limit().unwrap_or(usize::MAX)
+            ))
+            .with_field("requested", size_bytes)
+            .with_field("reserved", self.reserved())),
+        }
+    }
+
+    fn make_cow<T: Debug>(&self, item: T) -> Result<Self::CowPtr<T>, T> {
+        Ok(Arc::new(item))
+    }
+
+    fn drop_reservation(&self, reservation: &Reservation<Self>) {
+        self.reserved
+            .fetch_sub(reservation.size, atomic::Ordering::Relaxed);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn limited_reserve_within_limit() {
+        let manager = Arc::new(LimitedBufferManager::new(Some(64)));
+
+        let r1 = manager.try_reserve(32).unwrap().unwrap();
+        let r2 = manager.try_reserve(32).unwrap().unwrap();
+        assert_eq!(64, manager.reserved());
+
+        assert!(manager.try_reserve(1).unwrap().is_none());
+        assert!(manager.reserve_external(1, 1).is_err());
+
+        manager.drop_reservation(&r1);
+        assert_eq!(32, manager.reserved());
+        manager.drop_reservation(&r2);
+        assert_eq!(0, manager.reserved());
+    }
+
+    #[test]
+    fn limited_merge_reservations() {
+        let manager = Arc::new(LimitedBufferManager::new(None));
+
+        let mut r1 = manager.try_reserve(16).unwrap().unwrap();
+        let r2 = manager.try_reserve(8).unwrap().unwrap();
+        r1.merge(r2);
+        assert_eq!(24, r1.size());
+
+        manager.drop_reservation(&r1);
+        assert_eq!(0, manager.reserved());
+    }
+
+    #[test]
+    fn limited_set_limit() {
+        let manager = Arc::new(LimitedBufferManager::new(None));
+        let r = manager.try_reserve(100).unwrap().unwrap();
+
+        manager.set_limit(Some(50));
+        assert_eq!(Some(50), manager.limit());
+        assert!(manager.try_reserve(1).unwrap().is_none());
+
+        manager.drop_reservation(&r);
+        assert!(manager.try_reserve(50).unwrap().is_some());
+    }
+}
@@ -65,8 +65,10 @@ use crate::arrays::storage::{
     BooleanStorage,
     ContiguousVarlenStorage,
     GermanVarlenStorage,
+    ListItemMetadata2,
     ListStorage,
     PrimitiveStorage,
+    StructStorage,
     UntypedNullStorage,
 };
 
@@ -536,6 +538,18 @@ where
                     .unwrap();
                 Ok(ScalarValue::Interval(*v))
             }
+            DataType::Time64 => {
+                let v = PhysicalI64::get_addressable(flat.array_buffer)?
+                    .get(idx)
+                    .unwrap();
+                Ok(ScalarValue::Time64(*v))
+            }
+            DataType::Uuid => {
+                let v = PhysicalU128::get_addressable(flat.array_buffer)?
+                    .get(idx)
+                    .unwrap();
+                Ok(ScalarValue::Uuid(*v))
+            }
             DataType::Timestamp(m) => {
                 let v = PhysicalI64::get_addressable(flat.array_buffer)?
                     .get(idx)
@@ -543,6 +557,7 @@ where
                 Ok(ScalarValue::Timestamp(TimestampScalar {
                     unit: m.unit,
                     value: *v,
+                    tz: m.tz.clone(),
                 }))
             }
             DataType::Utf8 => {
@@ -636,6 +651,12 @@ where
             ScalarValue::Interval(val) => {
                 PhysicalInterval::get_addressable_mut(data)?.put(idx, val);
             }
+            ScalarValue::Time64(val) => {
+                PhysicalI64::get_addressable_mut(data)?.put(idx, val);
+            }
+            ScalarValue::Uuid(val) => {
+                PhysicalU128::get_addressable_mut(data)?.put(idx, val);
+            }
             ScalarValue::Utf8(val) => {
                 PhysicalUtf8::get_addressable_mut(data)?.put(idx, val);
             }
@@ -702,9 +723,33 @@ impl Array {
 
     /// Creates a new typed array with all values being set to null.
     pub fn new_typed_null_array(datatype: DataType, len: usize) -> Result<Self> {
+        if len == 0 {
+            // Avoid a selection on empty arrays, as they may be used as list
+            // children.
+            return Self::new_empty_array(datatype);
+        }
+
         // Create physical array data of length 1, and use a selection vector to
         // extend it out to the desired size.
-        let data = datatype.physical_type().zeroed_array_data(1);
+        let data = match &datatype {
+            // Struct children need to be typed as well.
+            DataType::Struct(m) => {
+                let children = m
+                    .fields
+                    .iter()
+                    .map(|f| Array::new_typed_null_array(f.datatype.clone(), 1))
+                    .collect::<Result<Vec<_>>>()?;
+                StructStorage::try_new(children, 1)?.into()
+            }
+            // List child needs to be typed so the array can be concatenated
+            // with other lists.
+            DataType::List(m) => ListStorage {
+                metadata: vec![ListItemMetadata2::default()].into(),
+                array: Array::new_empty_array(m.datatype.as_ref().clone())?,
+            }
+            .into(),
+            _ => datatype.physical_type().zeroed_array_data(1),
+        };
         let validity = Bitmap::new_with_all_false(1);
         let selection = SelectionVector::repeated(len, 0);
 
@@ -717,6 +762,28 @@ impl Array {
         })
     }
 
+    /// Create a new array with zero rows for the given datatype.
+    pub fn new_empty_array(datatype: DataType) -> Result<Self> {
+        let data: ArrayData2 = match &datatype {
+            DataType::Struct(m) => {
+                let children = m
+                    .fields
+                    .iter()
+                    .map(|f| Array::new_empty_array(f.datatype.clone()))
+                    .collect::<Result<Vec<_>>>()?;
+                StructStorage::try_new(children, 0)?.into()
+            }
+            DataType::List(m) => ListStorage {
+                metadata: Vec::<ListItemMetadata2>::new().into(),
+                array: Array::new_empty_array(m.datatype.as_ref().clone())?,
+            }
+            .into(),
+            _ => datatype.physical_type().zeroed_array_data(0),
+        };
+
+        Ok(Array::new_with_array_data(datatype, data))
+    }
+
     pub fn new_with_array_data(datatype: DataType, data: impl Into<ArrayData2>) -> Self {
         Array {
             datatype,
@@ -955,6 +1022,7 @@ impl Array {
                 ArrayData2::Int64(arr) => ScalarValue::Timestamp(TimestampScalar {
                     unit: m.unit,
                     value: arr.as_ref().as_ref()[idx],
+                    tz: m.tz.clone(),
                 }),
                 _other => return Err(array_not_valid_for_type_err(&self.datatype)),
             },
@@ -962,6 +1030,14 @@ impl Array {
                 ArrayData2::Interval(arr) => arr.as_ref().as_ref()[idx].into(),
                 _other => return Err(array_not_valid_for_type_err(&self.datatype)),
             },
+            DataType::Time64 => match &self.data2 {
+                ArrayData2::Int64(arr) => ScalarValue::Time64(arr.as_ref().as_ref()[idx]),
+                _other => return Err(array_not_valid_for_type_err(&self.datatype)),
+            },
+            DataType::Uuid => match &self.data2 {
+                ArrayData2::UInt128(arr) => ScalarValue::Uuid(arr.as_ref().as_ref()[idx]),
+                _other => return Err(array_not_valid_for_type_err(&self.datatype)),
+            },
             DataType::Utf8 => {
                 let v = match &self.data2 {
                     ArrayData2::Binary(BinaryData::Binary(arr)) => arr
@@ -993,7 +1069,19 @@ impl Array {
                 };
                 v.into()
             }
-            DataType::Struct(_) => not_implemented!("get value: struct"),
+            DataType::Struct(m) => match &self.data2 {
+                ArrayData2::Struct(s) => {
+                    let vals = m
+                        .fields
+                        .iter()
+                        .zip(&s.children)
+                        .map(|(field, child)| Ok((field.name.clone(), child.logical_value(idx)?)))
+                        .collect::<Result<Vec<_>>>()?;
+
+                    ScalarValue::Struct(vals)
+                }
+                _other => return Err(array_not_valid_for_type_err(&self.datatype)),
+            },
             DataType::List(_) => match &self.data2 {
                 ArrayData2::List(list) => {
                     let meta = list
@@ -1003,7 +1091,19 @@ impl Array {
                         .ok_or_else(|| RayexecError::new("Out of bounds"))?;
 
                     let vals = (meta.offset..meta.offset + meta.len)
-                        .map(|idx| list.array.physical_scalar(idx as usize))
+                        .map(|idx| {
+                            let idx = idx as usize;
+                            let valid = list
+                                .array
+                                .validity()
+                                .map(|validity| validity.value(idx))
+                                .unwrap_or(true);
+                            if valid {
+                                list.array.physical_scalar(idx)
+                            } else {
+                                Ok(ScalarValue::Null)
+                            }
+                        })
                         .collect::<Result<Vec<_>>>()?;
 
                     ScalarValue::List(vals)
@@ -1118,6 +1218,18 @@ impl Array {
                     Some(arr_val) => arr_val == *v,
                     None => false,
                 }),
+            ScalarValue::Time64(v) => {
+                UnaryExecutor::value_at2::<PhysicalI64>(self, row).map(|arr_val| match arr_val {
+                    Some(arr_val) => arr_val == *v,
+                    None => false,
+                })
+            }
+            ScalarValue::Uuid(v) => {
+                UnaryExecutor::value_at2::<PhysicalU128>(self, row).map(|arr_val| match arr_val {
+                    Some(arr_val) => arr_val == *v,
+                    None => false,
+                })
+            }
             ScalarValue::Utf8(v) => {
                 UnaryExecutor::value_at2::<PhysicalUtf8>(self, row).map(|arr_val| match arr_val {
                     Some(arr_val) => arr_val == v.as_ref(),
@@ -1258,6 +1370,26 @@ impl<'a> FromIterator<&'a str> for Array {
     }
 }
 
+impl<'a> FromIterator<&'a [u8]> for Array {
+    fn from_iter<T: IntoIterator<Item = &'a [u8]>>(iter: T) -> Self {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut german = GermanVarlenStorage::with_metadata_capacity(lower);
+
+        for b in iter {
+            german.try_push(b).unwrap();
+        }
+
+        Array {
+            datatype: DataType::Binary,
+            selection2: None,
+            validity2: None,
+            data2: ArrayData2::Binary(BinaryData::German(Arc::new(german))),
+            next: None,
+        }
+    }
+}
+
 macro_rules! impl_primitive_from_iter {
     ($prim:ty, $variant:ident) => {
         impl FromIterator<$prim> for Array {
@@ -1322,6 +1454,7 @@ pub enum ArrayData2 {
     Interval(Arc<PrimitiveStorage<Interval>>),
     Binary(BinaryData),
     List(Arc<ListStorage>),
+    Struct(Arc<StructStorage>),
 }
 
 impl ArrayData2 {
@@ -1345,6 +1478,7 @@ impl ArrayData2 {
             Self::Interval(_) => PhysicalType::Interval,
             Self::Binary(_) => PhysicalType::Binary,
             Self::List(_) => PhysicalType::List,
+            Self::Struct(_) => PhysicalType::Struct,
         }
     }
 
@@ -1372,6 +1506,7 @@ impl ArrayData2 {
                 BinaryData::German(s) => s.len(),
             },
             ArrayData2::List(s) => s.len(),
+            ArrayData2::Struct(s) => s.len(),
         }
     }
 
@@ -1508,6 +1643,12 @@ impl From<ListStorage> for ArrayData2 {
     }
 }
 
+impl From<StructStorage> for ArrayData2 {
+    fn from(value: StructStorage) -> Self {
+        ArrayData2::Struct(Arc::new(value))
+    }
+}
+
 /// Helper for copying rows.
 fn copy_rows<S, B>(
     from: &Array<B>,
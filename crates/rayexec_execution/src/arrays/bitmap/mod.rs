@@ -68,6 +68,11 @@ impl Bitmap {
         self.data.len()
     }
 
+    /// Get the underlying bytes of the bitmap.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
     pub fn count_trues(&self) -> usize {
         let mut count = self
             .data
@@ -6,6 +6,7 @@ use rayexec_error::{RayexecError, Result};
 
 use super::behavior::CastFailBehavior;
 use super::format::{
+    BinaryFormatter,
     BoolFormatter,
     Decimal128Formatter,
     Decimal64Formatter,
@@ -17,17 +18,24 @@ use super::format::{
     Int32Formatter,
     Int64Formatter,
     Int8Formatter,
+    Time64Formatter,
     TimestampMicrosecondsFormatter,
     TimestampMillisecondsFormatter,
     TimestampNanosecondsFormatter,
     TimestampSecondsFormatter,
+    TimestampTzMicrosecondsFormatter,
+    TimestampTzMillisecondsFormatter,
+    TimestampTzNanosecondsFormatter,
+    TimestampTzSecondsFormatter,
     UInt128Formatter,
     UInt16Formatter,
     UInt32Formatter,
     UInt64Formatter,
     UInt8Formatter,
+    UuidFormatter,
 };
 use super::parse::{
+    BinaryParser,
     BoolParser,
     Date32Parser,
     Decimal128Parser,
@@ -42,13 +50,17 @@ use super::parse::{
     Int8Parser,
     IntervalParser,
     Parser,
+    Time64Parser,
+    TimestampParser,
     UInt128Parser,
     UInt16Parser,
     UInt32Parser,
     UInt64Parser,
     UInt8Parser,
+    UuidParser,
 };
 use crate::arrays::array::physical_type::{
+    PhysicalBinary,
     PhysicalBool,
     PhysicalF16,
     PhysicalF32,
@@ -68,7 +80,9 @@ use crate::arrays::array::physical_type::{
 };
 use crate::arrays::array::{Array, ArrayData2};
 use crate::arrays::bitmap::Bitmap;
-use crate::arrays::datatype::{DataType, TimeUnit};
+use crate::arrays::compute::date::{date32_to_timestamp, nanoseconds_in_unit, SECONDS_IN_DAY};
+use crate::arrays::compute::time_zone::parse_time_zone;
+use crate::arrays::datatype::{DataType, TimeUnit, TimestampTypeMeta};
 use crate::arrays::executor::builder::{
     ArrayBuilder,
     BooleanBuffer,
@@ -77,7 +91,7 @@ use crate::arrays::executor::builder::{
 };
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
-use crate::arrays::storage::{AddressableStorage, PrimitiveStorage};
+use crate::arrays::storage::{AddressableStorage, ListStorage, PrimitiveStorage, StructStorage};
 
 pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Result<Array> {
     if arr.datatype() == &to {
@@ -86,6 +100,10 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
     }
 
     let arr = match arr.datatype() {
+        // Struct and list nulls need typed children.
+        DataType::Null if matches!(to, DataType::Struct(_) | DataType::List(_)) => {
+            Array::new_typed_null_array(to, arr.logical_len())?
+        }
         DataType::Null => {
             // Can cast NULL to anything else.
             let data = to.physical_type().zeroed_array_data(arr.logical_len());
@@ -214,6 +232,23 @@ pub fn cast_array(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Resu
             other => return Err(RayexecError::new(format!("Unhandled data type: {other}"))),
         },
 
+        // Timestamp to timestamp (unit and/or time zone change).
+        DataType::Timestamp(_) if matches!(to, DataType::Timestamp(_)) => {
+            cast_timestamp(arr, to, behavior)?
+        }
+
+        // Nested types, casting the child arrays.
+        DataType::List(_) if matches!(to, DataType::List(_)) => cast_list(arr, to, behavior)?,
+        DataType::Struct(_) if matches!(to, DataType::Struct(_)) => cast_struct(arr, to, behavior)?,
+
+        // Date to timestamp and back.
+        DataType::Date32 if matches!(to, DataType::Timestamp(_)) => {
+            cast_date32_to_timestamp(arr, to, behavior)?
+        }
+        DataType::Timestamp(_) if to == DataType::Date32 => {
+            cast_timestamp_to_date32(arr, behavior)?
+        }
+
         // Anything to string.
         _ if to.is_utf8() => cast_to_utf8(arr, behavior)?,
 
@@ -243,6 +278,13 @@ where
     }
 }
 
+/// Compute `10^scale` in the decimal's primitive type.
+fn decimal_scale_amount<T: PrimInt>(scale: u8) -> Result<T> {
+    let ten = <T as NumCast>::from(10).expect("10 to be in range");
+    num::checked_pow(ten, scale as usize)
+        .ok_or_else(|| RayexecError::new(format!("Decimal scale {scale} out of range")))
+}
+
 pub fn decimal_rescale<'a, S, D>(
     arr: &'a Array,
     to: DataType,
@@ -257,10 +299,8 @@ where
     let new_meta = to.try_get_decimal_type_meta()?;
     let arr_meta = arr.datatype().try_get_decimal_type_meta()?;
 
-    let scale_amount = <D::Primitive as NumCast>::from(
-        10.pow((arr_meta.scale - new_meta.scale).unsigned_abs() as u32),
-    )
-    .expect("to be in range");
+    let scale_amount =
+        decimal_scale_amount::<D::Primitive>((arr_meta.scale - new_meta.scale).unsigned_abs())?;
 
     let mut fail_state = behavior.new_state_for_array(arr);
     let output = UnaryExecutor::execute2::<S, _, _>(
@@ -327,10 +367,9 @@ where
     let scale = decimal_meta.scale;
     let precision = decimal_meta.precision;
 
-    let scale = <<S::Storage<'a> as AddressableStorage>::T as NumCast>::from(
-        10.pow(scale.unsigned_abs() as u32),
-    )
-    .ok_or_else(|| RayexecError::new(format!("Failed to cast scale {scale} to float")))?;
+    let scale = <<S::Storage<'a> as AddressableStorage>::T as NumCast>::from(10)
+        .ok_or_else(|| RayexecError::new(format!("Failed to cast scale {scale} to float")))?
+        .powi(scale.unsigned_abs() as i32);
 
     let mut fail_state = behavior.new_state_for_array(arr);
     let output = UnaryExecutor::execute2::<S, _, _>(
@@ -429,8 +468,7 @@ where
     let scale = decimal_meta.scale;
     let precision = decimal_meta.precision;
 
-    let scale_amount = <D::Primitive as NumCast>::from(10.pow(scale.unsigned_abs() as u32))
-        .expect("to be in range");
+    let scale_amount = decimal_scale_amount::<D::Primitive>(scale.unsigned_abs())?;
 
     let mut fail_state = behavior.new_state_for_array(arr);
     let output = UnaryExecutor::execute2::<S, _, _>(
@@ -534,6 +572,248 @@ where
     fail_state.check_and_apply(arr, output)
 }
 
+/// Cast between timestamps with different units or time zones.
+///
+/// Timestamps with a time zone are stored as instants since the UTC epoch,
+/// while timestamps without a time zone are stored as wall clock time. Casting
+/// between the two follows Postgres and interprets the wall clock time in the
+/// time zone of the timestamp that has one.
+fn cast_timestamp(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Result<Array> {
+    let (from_meta, to_meta) = match (arr.datatype(), &to) {
+        (DataType::Timestamp(from), DataType::Timestamp(to)) => (from, to),
+        (from, to) => {
+            return Err(RayexecError::new(format!(
+                "Expected timestamps for cast, got {from} and {to}"
+            )))
+        }
+    };
+
+    let from_nanos = nanoseconds_in_unit(from_meta.unit);
+    let to_nanos = nanoseconds_in_unit(to_meta.unit);
+    let to_unit = to_meta.unit;
+
+    // Zone to use when changing between wall clock time and UTC instants, and
+    // if we're converting to UTC.
+    let zone = match (&from_meta.tz, &to_meta.tz) {
+        (None, Some(tz)) => Some((parse_time_zone(tz)?, true)),
+        (Some(tz), None) => Some((parse_time_zone(tz)?, false)),
+        _ => None,
+    };
+
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute2::<PhysicalI64, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: to.clone(),
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| {
+            let v = if from_nanos >= to_nanos {
+                v.checked_mul(from_nanos / to_nanos)
+            } else {
+                Some(v.div_euclid(to_nanos / from_nanos))
+            };
+            let v = v.and_then(|v| match zone {
+                Some((zone, true)) => zone.local_to_utc(v, to_unit),
+                Some((zone, false)) => zone.utc_to_local(v, to_unit),
+                None => Some(v),
+            });
+            match v {
+                Some(v) => buf.put(&v),
+                None => fail_state.set_did_fail(buf.idx),
+            }
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
+/// Cast a list to a list with a different element type.
+///
+/// Only the child array is cast, list metadata is left untouched.
+fn cast_list(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Result<Array> {
+    let child_type = match &to {
+        DataType::List(m) => m.datatype.as_ref().clone(),
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected list for cast, got {other}"
+            )))
+        }
+    };
+
+    let list = match arr.array_data() {
+        ArrayData2::List(list) => list,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected list array data, got {:?}",
+                other.physical_type()
+            )))
+        }
+    };
+
+    let child = cast_array(&list.array, child_type, behavior)?;
+
+    Ok(Array {
+        datatype: to,
+        selection2: arr.selection2.clone(),
+        validity2: arr.validity2.clone(),
+        data2: ListStorage {
+            metadata: list.metadata.clone(),
+            array: child,
+        }
+        .into(),
+        next: None,
+    })
+}
+
+/// Cast a struct to a struct with different field types or field order.
+///
+/// Fields are matched by name, and both structs must have the same set of
+/// fields.
+fn cast_struct(arr: &Array, to: DataType, behavior: CastFailBehavior) -> Result<Array> {
+    let (from_meta, to_meta) = match (arr.datatype(), &to) {
+        (DataType::Struct(from), DataType::Struct(to)) => (from, to),
+        (from, to) => {
+            return Err(RayexecError::new(format!(
+                "Expected structs for cast, got {from} and {to}"
+            )))
+        }
+    };
+
+    let storage = match arr.array_data() {
+        ArrayData2::Struct(storage) => storage,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected struct array data, got {:?}",
+                other.physical_type()
+            )))
+        }
+    };
+
+    // Fields are matched by name, not position.
+    if let Some(extra) = from_meta
+        .fields
+        .iter()
+        .find(|from| !to_meta.fields.iter().any(|to| to.name == from.name))
+    {
+        return Err(RayexecError::new(format!(
+            "Cannot cast {} to {to}, field '{}' does not exist in the target struct",
+            arr.datatype(),
+            extra.name,
+        )));
+    }
+
+    let children = to_meta
+        .fields
+        .iter()
+        .map(|field| {
+            let idx = from_meta
+                .fields
+                .iter()
+                .position(|from| from.name == field.name)
+                .ok_or_else(|| {
+                    RayexecError::new(format!(
+                        "Cannot cast {} to {to}, field '{}' is missing from the source struct",
+                        arr.datatype(),
+                        field.name,
+                    ))
+                })?;
+            cast_array(&storage.children[idx], field.datatype.clone(), behavior)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Array {
+        datatype: to,
+        selection2: arr.selection2.clone(),
+        validity2: arr.validity2.clone(),
+        data2: StructStorage::try_new(children, storage.len())?.into(),
+        next: None,
+    })
+}
+
+/// Cast a date to a timestamp at midnight of that date.
+///
+/// If the timestamp has a time zone, midnight is in that time zone.
+fn cast_date32_to_timestamp(
+    arr: &Array,
+    to: DataType,
+    behavior: CastFailBehavior,
+) -> Result<Array> {
+    let meta = match &to {
+        DataType::Timestamp(meta) => meta,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected timestamp for cast, got {other}"
+            )))
+        }
+    };
+
+    let zone = meta.tz.as_deref().map(parse_time_zone).transpose()?;
+    let unit = meta.unit;
+
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute2::<PhysicalI32, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: to.clone(),
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| {
+            let v = date32_to_timestamp(unit, v).and_then(|v| match zone {
+                Some(zone) => zone.local_to_utc(v, unit),
+                None => Some(v),
+            });
+            match v {
+                Some(v) => buf.put(&v),
+                None => fail_state.set_did_fail(buf.idx),
+            }
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
+/// Cast a timestamp to a date, truncating the time portion.
+///
+/// If the timestamp has a time zone, the date is determined in that time zone.
+fn cast_timestamp_to_date32(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
+    let meta = match arr.datatype() {
+        DataType::Timestamp(meta) => meta,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected timestamp for cast, got {other}"
+            )))
+        }
+    };
+
+    let per_sec = 1_000_000_000 / nanoseconds_in_unit(meta.unit);
+    let zone = meta.tz.as_deref().map(parse_time_zone).transpose()?;
+    let unit = meta.unit;
+    let per_day = SECONDS_IN_DAY * per_sec;
+
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute2::<PhysicalI64, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: DataType::Date32,
+            buffer: PrimitiveBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| {
+            let local = match zone {
+                Some(zone) => zone.utc_to_local(v, unit),
+                None => Some(v),
+            };
+            let days = local.and_then(|v| i32::try_from(v.div_euclid(per_day)).ok());
+            match days {
+                Some(v) => buf.put(&v),
+                None => fail_state.set_did_fail(buf.idx),
+            }
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
 pub fn cast_from_utf8(
     arr: &Array,
     datatype: DataType,
@@ -575,9 +855,19 @@ pub fn cast_from_utf8(
             Decimal128Parser::new(m.precision, m.scale),
         ),
         DataType::Date32 => cast_parse_primitive(arr, datatype, behavior, Date32Parser),
+        DataType::Timestamp(ref m) => {
+            let parser = TimestampParser {
+                unit: m.unit,
+                zone: m.tz.as_deref().map(parse_time_zone).transpose()?,
+            };
+            cast_parse_primitive(arr, datatype, behavior, parser)
+        }
         DataType::Interval => {
             cast_parse_primitive(arr, datatype, behavior, IntervalParser::default())
         }
+        DataType::Time64 => cast_parse_primitive(arr, datatype, behavior, Time64Parser),
+        DataType::Uuid => cast_parse_primitive(arr, datatype, behavior, UuidParser),
+        DataType::Binary => cast_parse_binary(arr, behavior),
         other => Err(RayexecError::new(format!(
             "Unable to cast utf8 array to {other}"
         ))),
@@ -625,6 +915,31 @@ pub fn cast_to_utf8(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
             Decimal128Formatter::new(m.precision, m.scale),
             behavior,
         ),
+        DataType::Timestamp(TimestampTypeMeta { unit, tz: Some(tz) }) => {
+            let zone = parse_time_zone(tz)?;
+            match unit {
+                TimeUnit::Second => cast_format::<PhysicalI64, _>(
+                    arr,
+                    TimestampTzSecondsFormatter::new(zone),
+                    behavior,
+                ),
+                TimeUnit::Millisecond => cast_format::<PhysicalI64, _>(
+                    arr,
+                    TimestampTzMillisecondsFormatter::new(zone),
+                    behavior,
+                ),
+                TimeUnit::Microsecond => cast_format::<PhysicalI64, _>(
+                    arr,
+                    TimestampTzMicrosecondsFormatter::new(zone),
+                    behavior,
+                ),
+                TimeUnit::Nanosecond => cast_format::<PhysicalI64, _>(
+                    arr,
+                    TimestampTzNanosecondsFormatter::new(zone),
+                    behavior,
+                ),
+            }
+        }
         DataType::Timestamp(m) => match m.unit {
             TimeUnit::Second => {
                 cast_format::<PhysicalI64, _>(arr, TimestampSecondsFormatter::default(), behavior)
@@ -645,6 +960,9 @@ pub fn cast_to_utf8(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
                 behavior,
             ),
         },
+        DataType::Time64 => cast_format::<PhysicalI64, _>(arr, Time64Formatter, behavior),
+        DataType::Uuid => cast_format::<PhysicalU128, _>(arr, UuidFormatter, behavior),
+        DataType::Binary => cast_format_binary(arr, behavior),
         other => Err(RayexecError::new(format!(
             "Unable to cast {other} array to utf8"
         ))),
@@ -681,6 +999,32 @@ where
     fail_state.check_and_apply(arr, output)
 }
 
+/// Format binary values using the hex format.
+///
+/// Separate from `cast_format` since the formatter operates on unsized byte
+/// slices.
+fn cast_format_binary(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let mut string_buf = String::new();
+
+    let output = UnaryExecutor::execute2::<PhysicalBinary, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: DataType::Utf8,
+            buffer: GermanVarlenBuffer::with_len(arr.logical_len()),
+        },
+        |v, buf| {
+            string_buf.clear();
+            match BinaryFormatter.write(v, &mut string_buf) {
+                Ok(_) => buf.put(string_buf.as_str()),
+                Err(_) => fail_state.set_did_fail(buf.idx),
+            }
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
 fn cast_parse_bool(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
     let mut fail_state = behavior.new_state_for_array(arr);
     let output = UnaryExecutor::execute2::<PhysicalUtf8, _, _>(
@@ -698,6 +1042,23 @@ fn cast_parse_bool(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
     fail_state.check_and_apply(arr, output)
 }
 
+fn cast_parse_binary(arr: &Array, behavior: CastFailBehavior) -> Result<Array> {
+    let mut fail_state = behavior.new_state_for_array(arr);
+    let output = UnaryExecutor::execute2::<PhysicalUtf8, _, _>(
+        arr,
+        ArrayBuilder {
+            datatype: DataType::Binary,
+            buffer: GermanVarlenBuffer::<[u8]>::with_len(arr.logical_len()),
+        },
+        |v, buf| match BinaryParser.parse(v) {
+            Some(v) => buf.put(v.as_slice()),
+            None => fail_state.set_did_fail(buf.idx),
+        },
+    )?;
+
+    fail_state.check_and_apply(arr, output)
+}
+
 fn cast_parse_primitive<P, T>(
     arr: &Array,
     datatype: DataType,
@@ -729,6 +1090,7 @@ where
 mod tests {
     use super::*;
     use crate::arrays::datatype::DecimalTypeMeta;
+    use crate::arrays::scalar::timestamp::TimestampScalar;
     use crate::arrays::scalar::ScalarValue;
 
     #[test]
@@ -788,4 +1150,59 @@ mod tests {
         assert_eq!(ScalarValue::Float64(2.0), got.logical_value(1).unwrap());
         assert_eq!(ScalarValue::Float64(2.5), got.logical_value(2).unwrap());
     }
+
+    #[test]
+    fn array_cast_utf8_to_timestamptz() {
+        let arr = Array::from_iter(["2024-01-01 05:30:00+05:30", "2024-01-01 01:00:00"]);
+        let to = DataType::Timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Second, "+01:00"));
+
+        let got = cast_array(&arr, to, CastFailBehavior::Error).unwrap();
+        let got = cast_array(&got, DataType::Utf8, CastFailBehavior::Error).unwrap();
+
+        assert_eq!(
+            ScalarValue::from("2024-01-01 01:00:00+01:00"),
+            got.logical_value(0).unwrap()
+        );
+        assert_eq!(
+            ScalarValue::from("2024-01-01 01:00:00+01:00"),
+            got.logical_value(1).unwrap()
+        );
+    }
+
+    #[test]
+    fn array_cast_timestamp_to_timestamptz() {
+        // 2024-01-01 00:00:00
+        let arr = Array::new_with_array_data(
+            DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Second)),
+            PrimitiveStorage::from(vec![1_704_067_200_i64]),
+        );
+
+        // Interpret wall clock time in +01:00, convert to milliseconds.
+        let to = DataType::Timestamp(TimestampTypeMeta::new_with_tz(
+            TimeUnit::Millisecond,
+            "+01:00",
+        ));
+        let got = cast_array(&arr, to.clone(), CastFailBehavior::Error).unwrap();
+        assert_eq!(&to, got.datatype());
+        assert_eq!(
+            ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Millisecond,
+                value: 1_704_063_600_000,
+                tz: Some("+01:00".to_string()),
+            }),
+            got.logical_value(0).unwrap()
+        );
+
+        // And back.
+        let to = DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Second));
+        let got = cast_array(&got, to, CastFailBehavior::Error).unwrap();
+        assert_eq!(
+            ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Second,
+                value: 1_704_067_200,
+                tz: None,
+            }),
+            got.logical_value(0).unwrap()
+        );
+    }
 }
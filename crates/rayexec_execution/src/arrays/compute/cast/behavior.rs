@@ -1,6 +1,7 @@
 use rayexec_error::{RayexecError, Result};
 
 use crate::arrays::array::Array;
+use crate::arrays::datatype::DataType;
 
 /// Behavior when a cast fail due to under/overflow.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -20,6 +21,58 @@ impl CastFailBehavior {
     }
 }
 
+/// Behavior when integer arithmetic overflows the type of its inputs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OverflowBehavior {
+    /// Return an error.
+    Error,
+    /// Compute the result using the next wider integer type.
+    ///
+    /// Overflowing the widest integer type still errors.
+    Promote,
+}
+
+impl OverflowBehavior {
+    pub fn from_name(name: &str) -> Result<Self> {
+        match name {
+            "error" => Ok(OverflowBehavior::Error),
+            "promote" => Ok(OverflowBehavior::Promote),
+            other => Err(RayexecError::new(format!(
+                "Invalid overflow behavior '{other}', expected 'error' or 'promote'"
+            ))),
+        }
+    }
+
+    pub const fn name(&self) -> &'static str {
+        match self {
+            OverflowBehavior::Error => "error",
+            OverflowBehavior::Promote => "promote",
+        }
+    }
+
+    /// Returns the type integer inputs of the given type should be cast to
+    /// before applying an arithmetic operation.
+    ///
+    /// Returns None if no promotion should happen.
+    pub fn promoted_type(&self, datatype: &DataType) -> Option<DataType> {
+        if *self == OverflowBehavior::Error {
+            return None;
+        }
+
+        Some(match datatype {
+            DataType::Int8 => DataType::Int16,
+            DataType::Int16 => DataType::Int32,
+            DataType::Int32 => DataType::Int64,
+            DataType::Int64 => DataType::Int128,
+            DataType::UInt8 => DataType::UInt16,
+            DataType::UInt16 => DataType::UInt32,
+            DataType::UInt32 => DataType::UInt64,
+            DataType::UInt64 => DataType::UInt128,
+            _ => return None,
+        })
+    }
+}
+
 #[derive(Debug)]
 pub struct ErrorIndex {
     /// Row index that we failed on.
@@ -6,12 +6,13 @@ use chrono::{DateTime, Utc};
 use half::f16;
 
 use crate::arrays::compute::date::SECONDS_IN_DAY;
+use crate::arrays::compute::time_zone::TimeZone;
 use crate::arrays::scalar::interval::Interval;
 
 /// Logic for formatting and writing a type to a buffer.
 pub trait Formatter {
     /// Type we're formatting.
-    type Type;
+    type Type: ?Sized;
 
     /// Write the value to the buffer.
     fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result;
@@ -74,12 +75,16 @@ impl<T: Display> Formatter for DecimalFormatter<T> {
         match self.scale {
             scale if scale > 0 => {
                 write!(&mut self.buf, "{val}").expect("string write to not fail");
-                if self.buf.len() <= self.scale as usize {
-                    let pad = self.scale.unsigned_abs() as usize;
-                    write!(buf, "0.{val:0>pad$}")
+                let (sign, digits) = match self.buf.strip_prefix('-') {
+                    Some(digits) => ("-", digits),
+                    None => ("", self.buf.as_str()),
+                };
+                let scale = scale as usize;
+                if digits.len() <= scale {
+                    write!(buf, "{sign}0.{digits:0>scale$}")
                 } else {
-                    self.buf.insert(self.buf.len() - self.scale as usize, '.');
-                    write!(buf, "{}", self.buf)
+                    let (int, frac) = digits.split_at(digits.len() - scale);
+                    write!(buf, "{sign}{int}.{frac}")
                 }
             }
             scale if scale < 0 => {
@@ -147,6 +152,43 @@ impl<T: DateTimeFromTimestamp> Formatter for TimestampFormatter<T> {
     }
 }
 
+/// Formatter for timestamps with a time zone.
+///
+/// Values are instants since the UTC epoch, and are displayed at the offset
+/// of the given zone at that instant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampTzFormatter<T: DateTimeFromTimestamp> {
+    zone: TimeZone,
+    _type: PhantomData<T>,
+}
+
+pub type TimestampTzSecondsFormatter = TimestampTzFormatter<DateTimeFromSeconds>;
+pub type TimestampTzMillisecondsFormatter = TimestampTzFormatter<DateTimeFromMilliseconds>;
+pub type TimestampTzMicrosecondsFormatter = TimestampTzFormatter<DateTimeFromMicroseconds>;
+pub type TimestampTzNanosecondsFormatter = TimestampTzFormatter<DateTimeFromNanoseconds>;
+
+impl<T: DateTimeFromTimestamp> TimestampTzFormatter<T> {
+    pub fn new(zone: TimeZone) -> Self {
+        TimestampTzFormatter {
+            zone,
+            _type: PhantomData,
+        }
+    }
+}
+
+impl<T: DateTimeFromTimestamp> Formatter for TimestampTzFormatter<T> {
+    type Type = i64;
+    fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        let datetime = T::from(*val).ok_or(fmt::Error)?;
+        let offset = self
+            .zone
+            .offset_at_utc(datetime.timestamp())
+            .ok_or(fmt::Error)?;
+        let datetime = datetime.with_timezone(&offset);
+        write!(buf, "{}", datetime.format("%Y-%m-%d %H:%M:%S%.f%:z"))
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct Date32Formatter;
 
@@ -178,62 +220,47 @@ impl Formatter for IntervalFormatter {
     fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result {
         let years = val.months / 12;
         let months = val.months % 12;
-
         let days = val.days;
 
-        let mut nanos = val.nanos;
-        let hours = nanos / Interval::NANOSECONDS_IN_HOUR;
-        nanos %= Interval::NANOSECONDS_IN_HOUR;
-        let minutes = nanos / Interval::NANOSECONDS_IN_MINUTE;
-        nanos %= Interval::NANOSECONDS_IN_MINUTE;
-        let seconds = nanos / Interval::NANOSECONDS_IN_SECOND;
-        nanos %= Interval::NANOSECONDS_IN_SECOND;
-        let millis = nanos / Interval::NANOSECONDS_IN_MILLISECOND;
-
         let mut pad = false;
-
-        if years > 0 {
-            write!(buf, "{years} year")?;
-            if years > 1 {
-                write!(buf, "s")?;
+        for (amount, unit) in [(years, "year"), (months, "mon"), (days, "day")] {
+            if amount == 0 {
+                continue;
             }
-            pad = true;
-        }
-
-        if months > 0 {
             if pad {
                 write!(buf, " ")?;
             }
-
-            write!(buf, "{months} mon")?;
-            if months > 1 {
+            write!(buf, "{amount} {unit}")?;
+            if amount != 1 {
                 write!(buf, "s")?;
             }
             pad = true;
         }
 
-        if days > 0 {
+        // Only write the "time" portion if it's non-zero, or if everything
+        // else is zero.
+        if val.nanos != 0 || !pad {
             if pad {
                 write!(buf, " ")?;
             }
-
-            write!(buf, "{days} day")?;
-            if days > 1 {
-                write!(buf, "s")?;
+            if val.nanos < 0 {
+                write!(buf, "-")?;
             }
-            pad = true;
-        }
 
-        // Only write the "time" portion if anything is non-zero.
-        if (hours + minutes + seconds + millis) != 0 {
-            if pad {
-                write!(buf, " ")?;
-            }
+            let mut nanos = val.nanos.unsigned_abs();
+            let hours = nanos / Interval::NANOSECONDS_IN_HOUR as u64;
+            nanos %= Interval::NANOSECONDS_IN_HOUR as u64;
+            let minutes = nanos / Interval::NANOSECONDS_IN_MINUTE as u64;
+            nanos %= Interval::NANOSECONDS_IN_MINUTE as u64;
+            let seconds = nanos / Interval::NANOSECONDS_IN_SECOND as u64;
+            nanos %= Interval::NANOSECONDS_IN_SECOND as u64;
+            let micros = nanos / Interval::NANOSECONDS_IN_MICROSECOND as u64;
 
             write!(buf, "{:02}:{:02}:{:02}", hours, minutes, seconds)?;
 
-            if millis > 0 {
-                write!(buf, ".{:.6}", millis)?;
+            if micros > 0 {
+                let frac = format!("{micros:06}");
+                write!(buf, ".{}", frac.trim_end_matches('0'))?;
             }
         }
 
@@ -241,8 +268,72 @@ impl Formatter for IntervalFormatter {
     }
 }
 
+/// Formats microseconds since midnight as a time of day.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time64Formatter;
+
+impl Formatter for Time64Formatter {
+    type Type = i64;
+    fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        let secs = val.div_euclid(1_000_000);
+        let micros = val.rem_euclid(1_000_000);
+
+        write!(
+            buf,
+            "{:02}:{:02}:{:02}",
+            secs / 3600,
+            (secs % 3600) / 60,
+            secs % 60
+        )?;
+
+        if micros > 0 {
+            let frac = format!("{micros:06}");
+            write!(buf, ".{}", frac.trim_end_matches('0'))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Formats a UUID in its canonical hyphenated form.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidFormatter;
+
+impl Formatter for UuidFormatter {
+    type Type = u128;
+    fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        let hex = format!("{val:032x}");
+        write!(
+            buf,
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    }
+}
+
+/// Formats binary using Postgres' hex format, e.g. `\xdeadbeef`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryFormatter;
+
+impl Formatter for BinaryFormatter {
+    type Type = [u8];
+    fn write<W: fmt::Write>(&mut self, val: &Self::Type, buf: &mut W) -> fmt::Result {
+        write!(buf, "\\x")?;
+        for b in val {
+            write!(buf, "{b:02x}")?;
+        }
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use chrono::FixedOffset;
+
     use super::*;
 
     #[test]
@@ -259,6 +350,14 @@ mod tests {
         let mut buf = String::new();
         formatter.write(&12, &mut buf).unwrap();
         assert_eq!("0.012", buf);
+
+        let mut buf = String::new();
+        formatter.write(&-123450, &mut buf).unwrap();
+        assert_eq!("-123.450", buf);
+
+        let mut buf = String::new();
+        formatter.write(&-12, &mut buf).unwrap();
+        assert_eq!("-0.012", buf);
     }
 
     #[test]
@@ -285,6 +384,35 @@ mod tests {
         assert_eq!("23", buf);
     }
 
+    #[test]
+    fn time64_basic() {
+        let mut buf = String::new();
+        Time64Formatter.write(&45_045_000_000, &mut buf).unwrap();
+        assert_eq!("12:30:45", buf);
+
+        let mut buf = String::new();
+        Time64Formatter.write(&45_045_120_000, &mut buf).unwrap();
+        assert_eq!("12:30:45.12", buf);
+    }
+
+    #[test]
+    fn uuid_basic() {
+        let mut buf = String::new();
+        UuidFormatter
+            .write(&0xa0eebc999c0b4ef8bb6d6bb9bd380a11, &mut buf)
+            .unwrap();
+        assert_eq!("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11", buf);
+    }
+
+    #[test]
+    fn binary_hex() {
+        let mut buf = String::new();
+        BinaryFormatter
+            .write(&[0xde, 0xad, 0x01], &mut buf)
+            .unwrap();
+        assert_eq!("\\xdead01", buf);
+    }
+
     #[test]
     fn date32_basic() {
         let mut formatter = Date32Formatter;
@@ -293,6 +421,42 @@ mod tests {
         assert_eq!("1992-10-11", buf);
     }
 
+    #[test]
+    fn timestamptz_offset() {
+        // 2024-01-01 00:00:00 UTC
+        let val = 1_704_067_200_000_000;
+
+        let mut formatter = TimestampTzMicrosecondsFormatter::new(TimeZone::Fixed(
+            FixedOffset::east_opt(0).unwrap(),
+        ));
+        let mut buf = String::new();
+        formatter.write(&val, &mut buf).unwrap();
+        assert_eq!("2024-01-01 00:00:00+00:00", buf);
+
+        let mut formatter = TimestampTzMicrosecondsFormatter::new(TimeZone::Fixed(
+            FixedOffset::east_opt(19800).unwrap(),
+        ));
+        let mut buf = String::new();
+        formatter.write(&val, &mut buf).unwrap();
+        assert_eq!("2024-01-01 05:30:00+05:30", buf);
+    }
+
+    #[test]
+    fn timestamptz_named_zone() {
+        let zone = TimeZone::Named(chrono_tz::Tz::America__New_York);
+        let mut formatter = TimestampTzMicrosecondsFormatter::new(zone);
+
+        // 2024-01-01 00:00:00 UTC
+        let mut buf = String::new();
+        formatter.write(&1_704_067_200_000_000, &mut buf).unwrap();
+        assert_eq!("2023-12-31 19:00:00-05:00", buf);
+
+        // 2024-07-01 00:00:00 UTC
+        let mut buf = String::new();
+        formatter.write(&1_719_792_000_000_000, &mut buf).unwrap();
+        assert_eq!("2024-06-30 20:00:00-04:00", buf);
+    }
+
     #[test]
     fn interval() {
         let interval = Interval {
@@ -353,4 +517,22 @@ mod tests {
         IntervalFormatter.write(&interval, &mut buf).unwrap();
         assert_eq!("1 year 2 mons 11 days 03:00:24.982", buf);
     }
+
+    #[test]
+    fn interval_negative_and_zero() {
+        let mut buf = String::new();
+        IntervalFormatter
+            .write(&Interval::new(0, 0, 0), &mut buf)
+            .unwrap();
+        assert_eq!("00:00:00", buf);
+
+        let interval = Interval {
+            months: -14,
+            days: -1,
+            nanos: -(12 * Interval::NANOSECONDS_IN_HOUR + 5 * Interval::NANOSECONDS_IN_MILLISECOND),
+        };
+        let mut buf = String::new();
+        IntervalFormatter.write(&interval, &mut buf).unwrap();
+        assert_eq!("-1 years -2 mons -1 days -12:00:00.005", buf);
+    }
 }
@@ -3,11 +3,13 @@ use std::fmt::Write;
 use std::marker::PhantomData;
 use std::str::FromStr;
 
-use chrono::{Datelike, NaiveDate};
+use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime, Timelike};
 use half::f16;
 use num::PrimInt;
 
 use crate::arrays::compute::date::EPOCH_DAYS_FROM_CE;
+use crate::arrays::compute::time_zone::{parse_time_zone, TimeZone};
+use crate::arrays::datatype::TimeUnit;
 use crate::arrays::scalar::interval::Interval;
 
 /// Logic for parsing a string into some type.
@@ -82,6 +84,106 @@ impl Parser for Date32Parser {
     }
 }
 
+/// Parse a string timestamp into a value with the given unit.
+///
+/// Example formats:
+///
+/// '1992-10-11'
+/// '1992-10-11 12:30:00'
+/// '1992-10-11T12:30:00.123'
+/// '1992-10-11 12:30:00+05:30'
+/// '1992-10-11 12:30:00 UTC'
+///
+/// If `zone` is set, we're parsing into a timestamp with a time zone, and the
+/// output is the instant since the UTC epoch. Strings without an explicit zone
+/// are interpreted in the provided zone.
+///
+/// If `zone` is not set, any zone in the string is ignored, matching Postgres'
+/// behavior for `TIMESTAMP WITHOUT TIME ZONE`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TimestampParser {
+    pub unit: TimeUnit,
+    pub zone: Option<TimeZone>,
+}
+
+impl TimestampParser {
+    const FORMATS: &[&str] = &[
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M",
+        "%Y-%m-%dT%H:%M",
+    ];
+
+    /// Split a string into its date/time part and optional zone part.
+    fn split_zone(s: &str) -> Option<(&str, Option<TimeZone>)> {
+        let s = s.trim();
+
+        if let Some(rest) = s.strip_suffix('Z').or_else(|| s.strip_suffix('z')) {
+            return Some((rest, Some(parse_time_zone("UTC").ok()?)));
+        }
+
+        if let Some((rest, zone)) = s.rsplit_once(' ') {
+            // Zone names start with a letter, but may contain digits and
+            // signs (e.g. 'Etc/GMT+5', 'America/Port-au-Prince').
+            let is_name = zone.starts_with(|c: char| c.is_ascii_alphabetic())
+                && zone
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '/' | '_' | '-' | '+'));
+            if is_name {
+                return Some((rest.trim_end(), Some(parse_time_zone(zone).ok()?)));
+            }
+        }
+
+        // Only look for a sign after the date portion (which contains '-').
+        let time_start = s.find([' ', 'T'])?;
+        match s[time_start..].rfind(['+', '-']) {
+            Some(idx) => {
+                let idx = time_start + idx;
+                let offset = parse_time_zone(&s[idx..]).ok()?;
+                Some((s[..idx].trim_end(), Some(offset)))
+            }
+            None => Some((s, None)),
+        }
+    }
+
+    fn parse_naive(s: &str) -> Option<NaiveDateTime> {
+        for format in Self::FORMATS {
+            if let Ok(datetime) = NaiveDateTime::parse_from_str(s, format) {
+                return Some(datetime);
+            }
+        }
+        NaiveDate::from_str(s).ok()?.and_hms_opt(0, 0, 0)
+    }
+}
+
+impl Parser for TimestampParser {
+    type Type = i64;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        let (datetime, zone) = match Self::split_zone(s) {
+            Some(split) => split,
+            None => (s.trim(), None),
+        };
+        let naive = Self::parse_naive(datetime)?;
+
+        let naive = match self.zone {
+            Some(target) => {
+                let zone = zone.unwrap_or(target);
+                let offset = zone.offset_at_local(naive.and_utc().timestamp())?;
+                naive.checked_sub_offset(offset)?
+            }
+            None => naive,
+        };
+
+        let datetime = naive.and_utc();
+        match self.unit {
+            TimeUnit::Second => Some(datetime.timestamp()),
+            TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+            TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+            TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct DecimalParser<T: PrimInt> {
     precision: u8,
@@ -349,8 +451,128 @@ impl Parser for IntervalParser {
     }
 }
 
+/// Parse a time of day into microseconds since midnight.
+///
+/// Example formats:
+///
+/// '12:30'
+/// '12:30:45'
+/// '12:30:45.123456'
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Time64Parser;
+
+impl Parser for Time64Parser {
+    type Type = i64;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        let s = s.trim();
+        let time = NaiveTime::parse_from_str(s, "%H:%M:%S%.f")
+            .or_else(|_| NaiveTime::parse_from_str(s, "%H:%M"))
+            .ok()?;
+        let secs = time.num_seconds_from_midnight() as i64;
+        let micros = (time.nanosecond() / 1000) as i64;
+        Some(secs * 1_000_000 + micros)
+    }
+}
+
+/// Parse a UUID into its 128-bit representation.
+///
+/// Accepts the canonical hyphenated form, the plain 32 hex digit form, and
+/// either wrapped in braces.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct UuidParser;
+
+impl Parser for UuidParser {
+    type Type = u128;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        let s = s.trim();
+        let s = match s.strip_prefix('{') {
+            Some(s) => s.strip_suffix('}')?,
+            None => s,
+        };
+
+        let mut v: u128 = 0;
+        let mut digits = 0;
+        for (idx, c) in s.char_indices() {
+            if c == '-' {
+                // Hyphens only allowed between groups.
+                if digits == 0 || idx == s.len() - 1 {
+                    return None;
+                }
+                continue;
+            }
+            let d = c.to_digit(16)?;
+            v = (v << 4) | d as u128;
+            digits += 1;
+            if digits > 32 {
+                return None;
+            }
+        }
+
+        if digits != 32 {
+            return None;
+        }
+
+        Some(v)
+    }
+}
+
+/// Parse a string into binary using either Postgres' hex or escape format.
+///
+/// Hex format: '\xdeadbeef'
+/// Escape format: 'abc\000\\' (octal escapes and escaped backslashes)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BinaryParser;
+
+impl Parser for BinaryParser {
+    type Type = Vec<u8>;
+    fn parse(&mut self, s: &str) -> Option<Self::Type> {
+        if let Some(hex) = s.strip_prefix("\\x") {
+            let mut out = Vec::with_capacity(hex.len() / 2);
+            let mut digits = hex.chars().filter(|c| !c.is_whitespace());
+            while let Some(hi) = digits.next() {
+                let lo = digits.next()?;
+                let byte = (hi.to_digit(16)? << 4) | lo.to_digit(16)?;
+                out.push(byte as u8);
+            }
+            return Some(out);
+        }
+
+        let bytes = s.as_bytes();
+        let mut out = Vec::with_capacity(bytes.len());
+        let mut idx = 0;
+        while idx < bytes.len() {
+            if bytes[idx] != b'\\' {
+                out.push(bytes[idx]);
+                idx += 1;
+                continue;
+            }
+
+            if bytes.get(idx + 1) == Some(&b'\\') {
+                out.push(b'\\');
+                idx += 2;
+                continue;
+            }
+
+            match bytes.get(idx + 1..idx + 4) {
+                Some(octal) if octal.iter().all(|b| (b'0'..=b'7').contains(b)) => {
+                    let v = octal
+                        .iter()
+                        .fold(0_u16, |acc, b| acc * 8 + (b - b'0') as u16);
+                    out.push(u8::try_from(v).ok()?);
+                    idx += 4;
+                }
+                _ => return None,
+            }
+        }
+
+        Some(out)
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use chrono::FixedOffset;
+
     use super::*;
     use crate::arrays::compute::date::EPOCH_NAIVE_DATE;
 
@@ -365,6 +587,100 @@ mod tests {
         assert_eq!(-1, Date32Parser.parse("1969-12-31").unwrap());
     }
 
+    #[test]
+    fn parse_timestamp_naive() {
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Second,
+            zone: None,
+        };
+        // 2024-01-01 00:00:00
+        assert_eq!(1_704_067_200, parser.parse("2024-01-01").unwrap());
+        assert_eq!(1_704_067_200, parser.parse("2024-01-01 00:00:00").unwrap());
+        assert_eq!(1_704_067_200, parser.parse("2024-01-01T00:00").unwrap());
+        // Offset ignored.
+        assert_eq!(
+            1_704_067_200,
+            parser.parse("2024-01-01 00:00:00+05:30").unwrap()
+        );
+        assert_eq!(
+            1_704_067_200,
+            parser.parse("2024-01-01 00:00:00 UTC").unwrap()
+        );
+
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Millisecond,
+            zone: None,
+        };
+        assert_eq!(
+            1_704_067_200_123,
+            parser.parse("2024-01-01 00:00:00.123").unwrap()
+        );
+
+        assert_eq!(
+            1_704_067_200_000,
+            parser
+                .parse("2024-01-01 00:00:00 America/New_York")
+                .unwrap()
+        );
+        assert_eq!(None, parser.parse("2024-01-01 00:00:00 America/Nowhere"));
+        assert_eq!(
+            1_704_067_200_000,
+            parser
+                .parse("2024-01-01 00:00:00 America/Port-au-Prince")
+                .unwrap()
+        );
+        assert_eq!(None, parser.parse("not a timestamp"));
+    }
+
+    #[test]
+    fn parse_timestamp_tz() {
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Second,
+            zone: Some(TimeZone::Fixed(FixedOffset::east_opt(3600).unwrap())),
+        };
+        // 2024-01-01 00:00:00 UTC
+        assert_eq!(1_704_067_200, parser.parse("2024-01-01 00:00:00Z").unwrap());
+        assert_eq!(
+            1_704_067_200,
+            parser.parse("2024-01-01 00:00:00 utc").unwrap()
+        );
+        assert_eq!(
+            1_704_067_200,
+            parser.parse("2024-01-01 05:30:00+05:30").unwrap()
+        );
+        assert_eq!(
+            1_704_067_200,
+            parser.parse("2023-12-31 16:00:00-08").unwrap()
+        );
+        // No offset, use the target offset.
+        assert_eq!(1_704_067_200, parser.parse("2024-01-01 01:00:00").unwrap());
+        // Named zone, offset depends on the date.
+        assert_eq!(
+            1_704_067_200,
+            parser
+                .parse("2023-12-31 19:00:00 America/New_York")
+                .unwrap()
+        );
+        assert_eq!(
+            1_719_792_000,
+            parser
+                .parse("2024-06-30 20:00:00 America/New_York")
+                .unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_timestamp_named_zone_target() {
+        let mut parser = TimestampParser {
+            unit: TimeUnit::Second,
+            zone: Some(TimeZone::Named(chrono_tz::Tz::America__New_York)),
+        };
+        // 2024-01-01 05:00:00 UTC
+        assert_eq!(1_704_085_200, parser.parse("2024-01-01 00:00:00").unwrap());
+        // 2024-07-01 04:00:00 UTC
+        assert_eq!(1_719_806_400, parser.parse("2024-07-01 00:00:00").unwrap());
+    }
+
     #[test]
     fn parse_decimal() {
         // Can parse
@@ -423,4 +739,65 @@ mod tests {
             IntervalParser::default().parse("1.5 days 2 hours").unwrap()
         );
     }
+
+    #[test]
+    fn test_parse_time64() {
+        assert_eq!(0, Time64Parser.parse("00:00:00").unwrap());
+        assert_eq!(45_000_000_000, Time64Parser.parse("12:30").unwrap());
+        assert_eq!(45_045_000_000, Time64Parser.parse("12:30:45").unwrap());
+        assert_eq!(
+            45_045_123_456,
+            Time64Parser.parse("12:30:45.123456").unwrap()
+        );
+        assert_eq!(None, Time64Parser.parse("25:00:00"));
+        assert_eq!(None, Time64Parser.parse("noon"));
+    }
+
+    #[test]
+    fn test_parse_uuid() {
+        let expected = 0xa0eebc999c0b4ef8bb6d6bb9bd380a11;
+        assert_eq!(
+            expected,
+            UuidParser
+                .parse("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+                .unwrap()
+        );
+        assert_eq!(
+            expected,
+            UuidParser
+                .parse("A0EEBC999C0B4EF8BB6D6BB9BD380A11")
+                .unwrap()
+        );
+        assert_eq!(
+            expected,
+            UuidParser
+                .parse("{a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11}")
+                .unwrap()
+        );
+        assert_eq!(None, UuidParser.parse("a0eebc99-9c0b-4ef8-bb6d"));
+        assert_eq!(
+            None,
+            UuidParser.parse("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11-")
+        );
+        assert_eq!(
+            None,
+            UuidParser.parse("g0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11")
+        );
+    }
+
+    #[test]
+    fn test_parse_binary() {
+        assert_eq!(
+            vec![0xde, 0xad, 0xbe, 0xef],
+            BinaryParser.parse("\\xDEADbeef").unwrap()
+        );
+        assert_eq!(Vec::<u8>::new(), BinaryParser.parse("\\x").unwrap());
+        assert_eq!(None, BinaryParser.parse("\\xabc"));
+        assert_eq!(b"abc".to_vec(), BinaryParser.parse("abc").unwrap());
+        assert_eq!(
+            vec![b'a', 0, b'\\', b'b'],
+            BinaryParser.parse("a\\000\\\\b").unwrap()
+        );
+        assert_eq!(None, BinaryParser.parse("a\\9"));
+    }
 }
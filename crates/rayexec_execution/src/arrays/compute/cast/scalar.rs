@@ -18,7 +18,14 @@ use super::parse::{
     UInt64Parser,
     UInt8Parser,
 };
-use crate::arrays::compute::cast::parse::{BoolParser, Date32Parser, IntervalParser};
+use crate::arrays::compute::cast::parse::{
+    BinaryParser,
+    BoolParser,
+    Date32Parser,
+    IntervalParser,
+    Time64Parser,
+    UuidParser,
+};
 use crate::arrays::datatype::DataType;
 use crate::arrays::scalar::decimal::{Decimal128Scalar, Decimal64Scalar};
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
@@ -304,6 +311,9 @@ fn cast_from_utf8_scalar(v: &str, datatype: &DataType) -> Result<OwnedScalarValu
         }),
         DataType::Date32 => ScalarValue::Date32(parse(Date32Parser, v, datatype)?),
         DataType::Interval => ScalarValue::Interval(parse(IntervalParser::default(), v, datatype)?),
+        DataType::Time64 => ScalarValue::Time64(parse(Time64Parser, v, datatype)?),
+        DataType::Uuid => ScalarValue::Uuid(parse(UuidParser, v, datatype)?),
+        DataType::Binary => ScalarValue::Binary(parse(BinaryParser, v, datatype)?.into()),
         other => {
             return Err(RayexecError::new(format!(
                 "Unable to cast utf8 scalar to {other}"
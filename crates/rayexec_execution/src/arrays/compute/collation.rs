@@ -0,0 +1,158 @@
+//! String collations.
+//!
+//! Collations are implemented by mapping strings to a collation key, where two
+//! strings compare equal under the collation if their keys are byte-wise
+//! equal. Comparisons, sorting, and hashing then operate on the keys without
+//! needing to know about collations.
+
+use std::borrow::Cow;
+use std::fmt;
+
+use rayexec_error::{RayexecError, Result};
+use unicode_normalization::char::is_combining_mark;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::arrays::array::physical_type::PhysicalUtf8;
+use crate::arrays::array::{Array, ArrayData2};
+use crate::arrays::datatype::DataType;
+use crate::arrays::executor::builder::{ArrayBuilder, GermanVarlenBuffer};
+use crate::arrays::executor::scalar::UnaryExecutor;
+
+/// A string collation.
+///
+/// Parsed from dot separated names, e.g. 'nocase', 'noaccent', or
+/// 'nocase.noaccent'. 'binary' is the default collation and compares strings
+/// by their bytes.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Collation {
+    /// Ignore differences in case.
+    pub case_insensitive: bool,
+    /// Ignore accents and other combining marks.
+    pub accent_insensitive: bool,
+}
+
+impl Collation {
+    pub const BINARY: Collation = Collation {
+        case_insensitive: false,
+        accent_insensitive: false,
+    };
+
+    pub const NOCASE: Collation = Collation {
+        case_insensitive: true,
+        accent_insensitive: false,
+    };
+
+    pub const NOACCENT: Collation = Collation {
+        case_insensitive: false,
+        accent_insensitive: true,
+    };
+
+    pub fn from_name(name: &str) -> Result<Self> {
+        let mut collation = Collation::BINARY;
+        for part in name.split('.') {
+            match part.to_lowercase().as_str() {
+                "binary" => (),
+                "nocase" => collation.case_insensitive = true,
+                "noaccent" => collation.accent_insensitive = true,
+                _ => {
+                    return Err(RayexecError::new(format!(
+                        "Unknown collation '{name}', expected a combination of 'binary', 'nocase', and 'noaccent'"
+                    )))
+                }
+            }
+        }
+        Ok(collation)
+    }
+
+    pub const fn is_binary(&self) -> bool {
+        !self.case_insensitive && !self.accent_insensitive
+    }
+
+    /// Combine this collation with another.
+    pub const fn union(self, other: Collation) -> Collation {
+        Collation {
+            case_insensitive: self.case_insensitive || other.case_insensitive,
+            accent_insensitive: self.accent_insensitive || other.accent_insensitive,
+        }
+    }
+
+    /// Get the collation key for a string.
+    pub fn collate<'a>(&self, s: &'a str) -> Cow<'a, str> {
+        let mut s = Cow::Borrowed(s);
+        if self.accent_insensitive && !s.is_ascii() {
+            s = Cow::Owned(s.nfd().filter(|c| !is_combining_mark(*c)).collect());
+        }
+        if self.case_insensitive {
+            if s.is_ascii() {
+                if s.bytes().any(|b| b.is_ascii_uppercase()) {
+                    s = Cow::Owned(s.to_ascii_lowercase());
+                }
+            } else {
+                s = Cow::Owned(s.to_lowercase());
+            }
+        }
+        s
+    }
+
+    /// Get the collation keys for all strings in a Utf8 array.
+    pub fn collate_array(&self, input: &Array) -> Result<Array> {
+        if self.is_binary() {
+            return Ok(input.clone());
+        }
+
+        let cap = match input.array_data() {
+            ArrayData2::Binary(bin) => bin.binary_data_size_bytes(),
+            _ => return Err(RayexecError::new("Unexpected array data type")),
+        };
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Utf8,
+            buffer: GermanVarlenBuffer::<str>::with_len_and_data_capacity(input.logical_len(), cap),
+        };
+
+        UnaryExecutor::execute2::<PhysicalUtf8, _, _>(input, builder, |v, buf| {
+            buf.put(self.collate(v).as_ref())
+        })
+    }
+}
+
+impl fmt::Display for Collation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match (self.case_insensitive, self.accent_insensitive) {
+            (false, false) => write!(f, "binary"),
+            (true, false) => write!(f, "nocase"),
+            (false, true) => write!(f, "noaccent"),
+            (true, true) => write!(f, "nocase.noaccent"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_names() {
+        assert_eq!(Collation::BINARY, Collation::from_name("binary").unwrap());
+        assert_eq!(Collation::NOCASE, Collation::from_name("NOCASE").unwrap());
+        assert_eq!(
+            Collation::NOCASE.union(Collation::NOACCENT),
+            Collation::from_name("noaccent.nocase").unwrap()
+        );
+        Collation::from_name("nocase.french").unwrap_err();
+    }
+
+    #[test]
+    fn collation_keys() {
+        assert_eq!("Hello", Collation::BINARY.collate("Hello"));
+        assert_eq!("hello", Collation::NOCASE.collate("Hello"));
+        assert_eq!("école", Collation::NOCASE.collate("ÉCOLE"));
+        assert_eq!("ECOLE", Collation::NOACCENT.collate("ÉCOLE"));
+        assert_eq!(
+            "creme brulee",
+            Collation::from_name("nocase.noaccent")
+                .unwrap()
+                .collate("Crème Brûlée")
+        );
+    }
+}
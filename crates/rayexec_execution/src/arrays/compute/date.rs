@@ -1,4 +1,4 @@
-use chrono::{DateTime, Datelike, NaiveDate, Timelike, Utc};
+use chrono::{DateTime, Datelike, Months, NaiveDate, NaiveDateTime, TimeDelta, Timelike, Utc};
 use rayexec_error::{not_implemented, RayexecError, Result};
 
 use crate::arrays::array::physical_type::{PhysicalI32, PhysicalI64};
@@ -7,6 +7,7 @@ use crate::arrays::datatype::{DataType, DecimalTypeMeta, TimeUnit};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
 
 pub const EPOCH_NAIVE_DATE: NaiveDate = match NaiveDate::from_ymd_opt(1970, 1, 1) {
     Some(date) => date,
@@ -17,6 +18,206 @@ pub const EPOCH_DAYS_FROM_CE: i32 = 719_163;
 
 pub const SECONDS_IN_DAY: i64 = 86_400;
 
+/// Adds an interval to a timestamp value with the given unit.
+///
+/// Follows Postgres semantics: months are added first (clamping to the last
+/// day of the month if needed), followed by days, then the remaining sub-day
+/// part.
+pub fn timestamp_add_interval(unit: TimeUnit, val: i64, interval: Interval) -> Result<i64> {
+    let datetime = match unit {
+        TimeUnit::Second => DateTime::from_timestamp(val, 0),
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(val),
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(val),
+        TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(val)),
+    };
+
+    let datetime = datetime.and_then(|datetime| {
+        let datetime = if interval.months >= 0 {
+            datetime.checked_add_months(Months::new(interval.months as u32))?
+        } else {
+            datetime.checked_sub_months(Months::new(interval.months.unsigned_abs()))?
+        };
+
+        datetime
+            .checked_add_signed(TimeDelta::days(interval.days as i64))?
+            .checked_add_signed(TimeDelta::nanoseconds(interval.nanos))
+    });
+
+    let out = datetime.and_then(|datetime| match unit {
+        TimeUnit::Second => Some(datetime.timestamp()),
+        TimeUnit::Millisecond => Some(datetime.timestamp_millis()),
+        TimeUnit::Microsecond => Some(datetime.timestamp_micros()),
+        TimeUnit::Nanosecond => datetime.timestamp_nanos_opt(),
+    });
+
+    out.ok_or_else(|| RayexecError::new("Timestamp out of range"))
+}
+
+/// Number of nanoseconds in a single value of the given unit.
+pub const fn nanoseconds_in_unit(unit: TimeUnit) -> i64 {
+    match unit {
+        TimeUnit::Second => 1_000_000_000,
+        TimeUnit::Millisecond => 1_000_000,
+        TimeUnit::Microsecond => 1_000,
+        TimeUnit::Nanosecond => 1,
+    }
+}
+
+/// Convert a timestamp value with the given unit to a naive datetime.
+pub fn timestamp_to_datetime(unit: TimeUnit, val: i64) -> Option<NaiveDateTime> {
+    let datetime = match unit {
+        TimeUnit::Second => DateTime::from_timestamp(val, 0),
+        TimeUnit::Millisecond => DateTime::from_timestamp_millis(val),
+        TimeUnit::Microsecond => DateTime::from_timestamp_micros(val),
+        TimeUnit::Nanosecond => Some(DateTime::from_timestamp_nanos(val)),
+    };
+    datetime.map(|datetime| datetime.naive_utc())
+}
+
+/// Convert a number of days since epoch to a timestamp value with the given
+/// unit.
+pub fn date32_to_timestamp(unit: TimeUnit, days: i32) -> Option<i64> {
+    let per_day = SECONDS_IN_DAY * (1_000_000_000 / nanoseconds_in_unit(unit));
+    (days as i64).checked_mul(per_day)
+}
+
+/// Subtract two timestamps, producing an interval.
+///
+/// Follows Postgres: the result only contains days and a time portion, with no
+/// months.
+pub fn timestamp_sub_timestamp(
+    left_unit: TimeUnit,
+    left: i64,
+    right_unit: TimeUnit,
+    right: i64,
+) -> Result<Interval> {
+    let diff = (left as i128 * nanoseconds_in_unit(left_unit) as i128)
+        - (right as i128 * nanoseconds_in_unit(right_unit) as i128);
+
+    let days = diff / Interval::NANOSECONDS_IN_DAY as i128;
+    let nanos = diff % Interval::NANOSECONDS_IN_DAY as i128;
+
+    let days = i32::try_from(days).map_err(|_| RayexecError::new("Interval out of range"))?;
+    Ok(Interval::new(0, days, nanos as i64))
+}
+
+/// Compute the symbolic difference between two timestamps, producing an
+/// interval with years, months, and days.
+///
+/// Follows Postgres' `age` function. Fields are subtracted individually, and
+/// negative fields borrow from the next larger field. Days are borrowed using
+/// the length of the month of the earlier timestamp.
+pub fn timestamp_age(
+    left_unit: TimeUnit,
+    left: i64,
+    right_unit: TimeUnit,
+    right: i64,
+) -> Result<Interval> {
+    let out_of_range = || RayexecError::new("Timestamp out of range");
+    let left = timestamp_to_datetime(left_unit, left).ok_or_else(out_of_range)?;
+    let right = timestamp_to_datetime(right_unit, right).ok_or_else(out_of_range)?;
+
+    // Always compute as a positive difference, and flip the sign at the end.
+    let (negate, later, earlier) = if left < right {
+        (true, right, left)
+    } else {
+        (false, left, right)
+    };
+
+    let mut nanos = later.nanosecond() as i64 - earlier.nanosecond() as i64;
+    let mut secs =
+        later.num_seconds_from_midnight() as i64 - earlier.num_seconds_from_midnight() as i64;
+    let mut days = later.day() as i32 - earlier.day() as i32;
+    let mut months = later.month() as i32 - earlier.month() as i32;
+    let years = later.year() - earlier.year();
+
+    if nanos < 0 {
+        nanos += Interval::NANOSECONDS_IN_SECOND;
+        secs -= 1;
+    }
+    if secs < 0 {
+        secs += SECONDS_IN_DAY;
+        days -= 1;
+    }
+    if days < 0 {
+        days += days_in_month(earlier.year(), earlier.month())?;
+        months -= 1;
+    }
+    let months = years * 12 + months;
+
+    let interval = Interval::new(months, days, secs * Interval::NANOSECONDS_IN_SECOND + nanos);
+
+    if negate {
+        Ok(Interval::new(
+            -interval.months,
+            -interval.days,
+            -interval.nanos,
+        ))
+    } else {
+        Ok(interval)
+    }
+}
+
+fn days_in_month(year: i32, month: u32) -> Result<i32> {
+    let first = NaiveDate::from_ymd_opt(year, month, 1);
+    let next = first.and_then(|first| first.checked_add_months(Months::new(1)));
+    match (first, next) {
+        (Some(first), Some(next)) => Ok((next - first).num_days() as i32),
+        _ => Err(RayexecError::new("Date out of range")),
+    }
+}
+
+/// Compute the number of date part boundaries crossed between two
+/// timestamps.
+///
+/// For example, the difference in years between '2023-12-31' and
+/// '2024-01-01' is 1.
+pub fn timestamp_date_diff(
+    part: DatePart,
+    start_unit: TimeUnit,
+    start: i64,
+    end_unit: TimeUnit,
+    end: i64,
+) -> Result<i64> {
+    let out_of_range = || RayexecError::new("Timestamp out of range");
+    let start_dt = timestamp_to_datetime(start_unit, start).ok_or_else(out_of_range)?;
+    let end_dt = timestamp_to_datetime(end_unit, end).ok_or_else(out_of_range)?;
+
+    let months = |dt: &NaiveDateTime| dt.year() as i64 * 12 + dt.month0() as i64;
+    // Boundaries crossed for fixed sized parts.
+    let fixed = |nanos_in_part: i64| -> i64 {
+        let start = start as i128 * nanoseconds_in_unit(start_unit) as i128;
+        let end = end as i128 * nanoseconds_in_unit(end_unit) as i128;
+        let nanos_in_part = nanos_in_part as i128;
+        (end.div_euclid(nanos_in_part) - start.div_euclid(nanos_in_part)) as i64
+    };
+
+    Ok(match part {
+        DatePart::Millenium => {
+            (end_dt.year() as i64 - 1).div_euclid(1000)
+                - (start_dt.year() as i64 - 1).div_euclid(1000)
+        }
+        DatePart::Century => {
+            (end_dt.year() as i64 - 1).div_euclid(100)
+                - (start_dt.year() as i64 - 1).div_euclid(100)
+        }
+        DatePart::Decade => {
+            (end_dt.year() as i64).div_euclid(10) - (start_dt.year() as i64).div_euclid(10)
+        }
+        DatePart::Year => end_dt.year() as i64 - start_dt.year() as i64,
+        DatePart::Quarter => months(&end_dt).div_euclid(3) - months(&start_dt).div_euclid(3),
+        DatePart::Month => months(&end_dt) - months(&start_dt),
+        DatePart::Week => (end_dt.date() - start_dt.date()).num_days() / 7,
+        DatePart::Day => (end_dt.date() - start_dt.date()).num_days(),
+        DatePart::Hour => fixed(Interval::NANOSECONDS_IN_HOUR),
+        DatePart::Minute => fixed(Interval::NANOSECONDS_IN_MINUTE),
+        DatePart::Second => fixed(Interval::NANOSECONDS_IN_SECOND),
+        DatePart::Milliseconds => fixed(Interval::NANOSECONDS_IN_MILLISECOND),
+        DatePart::Microseconds => fixed(Interval::NANOSECONDS_IN_MICROSECOND),
+        other => not_implemented!("Date diff for {other:?}"),
+    })
+}
+
 /// Date parts that can be extracted for date and time values.
 ///
 /// Follows Postgres conventions: <https://www.postgresql.org/docs/current/functions-datetime.html#FUNCTIONS-DATETIME-EXTRACT>
@@ -261,3 +462,134 @@ fn extract_milliseconds<T: Datelike + Timelike>(val: T) -> i64 {
 fn extract_microseconds<T: Datelike + Timelike>(val: T) -> i64 {
     val.nanosecond() as i64
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn micros(s: &str) -> i64 {
+        NaiveDateTime::parse_from_str(s, "%Y-%m-%d %H:%M:%S")
+            .unwrap()
+            .and_utc()
+            .timestamp_micros()
+    }
+
+    #[test]
+    fn sub_timestamps() {
+        let got = timestamp_sub_timestamp(
+            TimeUnit::Microsecond,
+            micros("2024-03-01 00:00:00"),
+            TimeUnit::Microsecond,
+            micros("2024-02-28 12:00:00"),
+        )
+        .unwrap();
+        assert_eq!(Interval::new(0, 1, 12 * Interval::NANOSECONDS_IN_HOUR), got);
+
+        // Mixed units.
+        let got = timestamp_sub_timestamp(
+            TimeUnit::Second,
+            micros("2024-02-28 12:00:00") / 1_000_000,
+            TimeUnit::Microsecond,
+            micros("2024-03-01 00:00:00"),
+        )
+        .unwrap();
+        assert_eq!(
+            Interval::new(0, -1, -12 * Interval::NANOSECONDS_IN_HOUR),
+            got
+        );
+    }
+
+    #[test]
+    fn age() {
+        // Matches postgres docs.
+        let got = timestamp_age(
+            TimeUnit::Microsecond,
+            micros("2001-04-10 00:00:00"),
+            TimeUnit::Microsecond,
+            micros("1957-06-13 00:00:00"),
+        )
+        .unwrap();
+        assert_eq!(Interval::new(43 * 12 + 9, 27, 0), got);
+
+        let got = timestamp_age(
+            TimeUnit::Microsecond,
+            micros("1957-06-13 00:00:00"),
+            TimeUnit::Microsecond,
+            micros("2001-04-10 00:00:00"),
+        )
+        .unwrap();
+        assert_eq!(Interval::new(-(43 * 12 + 9), -27, 0), got);
+
+        let got = timestamp_age(
+            TimeUnit::Microsecond,
+            micros("2024-03-01 06:00:00"),
+            TimeUnit::Microsecond,
+            micros("2024-01-31 12:00:00"),
+        )
+        .unwrap();
+        assert_eq!(Interval::new(1, 0, 18 * Interval::NANOSECONDS_IN_HOUR), got);
+    }
+
+    #[test]
+    fn date_diff() {
+        let diff = |part, start, end| {
+            timestamp_date_diff(
+                part,
+                TimeUnit::Microsecond,
+                micros(start),
+                TimeUnit::Microsecond,
+                micros(end),
+            )
+            .unwrap()
+        };
+
+        assert_eq!(
+            1,
+            diff(DatePart::Year, "2023-12-31 00:00:00", "2024-01-01 00:00:00")
+        );
+        assert_eq!(
+            1,
+            diff(
+                DatePart::Month,
+                "2024-01-31 00:00:00",
+                "2024-02-01 00:00:00"
+            )
+        );
+        assert_eq!(
+            -13,
+            diff(
+                DatePart::Month,
+                "2024-02-01 00:00:00",
+                "2023-01-31 00:00:00"
+            )
+        );
+        assert_eq!(
+            1,
+            diff(
+                DatePart::Quarter,
+                "2024-03-31 00:00:00",
+                "2024-04-01 00:00:00"
+            )
+        );
+        assert_eq!(
+            1,
+            diff(DatePart::Day, "2024-01-01 23:59:59", "2024-01-02 00:00:00")
+        );
+        assert_eq!(
+            2,
+            diff(DatePart::Week, "2024-01-01 00:00:00", "2024-01-16 00:00:00")
+        );
+        assert_eq!(
+            1,
+            diff(DatePart::Hour, "2024-01-01 00:59:59", "2024-01-01 01:00:00")
+        );
+        assert_eq!(
+            90,
+            diff(
+                DatePart::Minute,
+                "2024-01-01 00:00:00",
+                "2024-01-01 01:30:00"
+            )
+        );
+    }
+}
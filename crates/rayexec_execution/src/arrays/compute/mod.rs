@@ -1,5 +1,7 @@
 //! Compute kernels.
 pub mod cast;
+pub mod collation;
 pub mod date;
+pub mod time_zone;
 
 pub mod util;
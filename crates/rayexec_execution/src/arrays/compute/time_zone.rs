@@ -0,0 +1,258 @@
+//! Time zone parsing.
+//!
+//! Supports UTC, fixed offsets from UTC, and named zones from the tz database
+//! (e.g. 'America/New_York'). Named zones may have offsets that change over
+//! time (e.g. daylight saving time), so offsets are resolved per value.
+use std::fmt;
+
+use chrono::{DateTime, FixedOffset, LocalResult, Offset, TimeZone as _};
+use chrono_tz::Tz;
+use rayexec_error::{RayexecError, Result};
+
+use super::date::{nanoseconds_in_unit, SECONDS_IN_DAY};
+use crate::arrays::datatype::TimeUnit;
+
+/// A parsed time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeZone {
+    /// Fixed offset from UTC.
+    Fixed(FixedOffset),
+    /// Named zone from the tz database.
+    Named(Tz),
+}
+
+impl TimeZone {
+    /// Get the offset from UTC at an instant, given as seconds since the UTC
+    /// epoch.
+    pub fn offset_at_utc(&self, secs: i64) -> Option<FixedOffset> {
+        match self {
+            Self::Fixed(offset) => Some(*offset),
+            Self::Named(tz) => {
+                let datetime = DateTime::from_timestamp(secs, 0)?.naive_utc();
+                Some(tz.offset_from_utc_datetime(&datetime).fix())
+            }
+        }
+    }
+
+    /// Get the offset from UTC for a wall clock time, given as seconds since
+    /// the epoch in local time.
+    ///
+    /// Follows Postgres for wall clock times that are ambiguous or skipped
+    /// over by a transition. Ambiguous times use the offset in effect after
+    /// the transition, and skipped times use the offset in effect before it.
+    pub fn offset_at_local(&self, secs: i64) -> Option<FixedOffset> {
+        match self {
+            Self::Fixed(offset) => Some(*offset),
+            Self::Named(tz) => {
+                let datetime = DateTime::from_timestamp(secs, 0)?.naive_utc();
+                match tz.offset_from_local_datetime(&datetime) {
+                    LocalResult::Single(offset) => Some(offset.fix()),
+                    LocalResult::Ambiguous(_, later) => Some(later.fix()),
+                    // Transitions never happen within a day of each other.
+                    LocalResult::None => self.offset_at_utc(secs - SECONDS_IN_DAY),
+                }
+            }
+        }
+    }
+
+    /// Convert a wall clock time in this zone to an instant since the UTC
+    /// epoch.
+    pub fn local_to_utc(&self, value: i64, unit: TimeUnit) -> Option<i64> {
+        let per_sec = 1_000_000_000 / nanoseconds_in_unit(unit);
+        let offset = self.offset_at_local(value.div_euclid(per_sec))?;
+        value.checked_sub(offset.local_minus_utc() as i64 * per_sec)
+    }
+
+    /// Convert an instant since the UTC epoch to wall clock time in this
+    /// zone.
+    pub fn utc_to_local(&self, value: i64, unit: TimeUnit) -> Option<i64> {
+        let per_sec = 1_000_000_000 / nanoseconds_in_unit(unit);
+        let offset = self.offset_at_utc(value.div_euclid(per_sec))?;
+        value.checked_add(offset.local_minus_utc() as i64 * per_sec)
+    }
+}
+
+impl fmt::Display for TimeZone {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Fixed(offset) => write!(f, "{}", format_offset(*offset)),
+            Self::Named(tz) => write!(f, "{}", tz.name()),
+        }
+    }
+}
+
+/// Parse a time zone string.
+///
+/// Accepts 'UTC', 'GMT', 'Z' (case-insensitive), offsets in the form '[+-]HH',
+/// '[+-]HH:MM', or '[+-]HHMM', and names from the tz database.
+pub fn parse_time_zone(s: &str) -> Result<TimeZone> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("utc") || s.eq_ignore_ascii_case("gmt") || s.eq_ignore_ascii_case("z")
+    {
+        return Ok(TimeZone::Fixed(
+            FixedOffset::east_opt(0).expect("zero offset to be valid"),
+        ));
+    }
+
+    if let Some(offset) = parse_offset(s) {
+        return Ok(TimeZone::Fixed(offset));
+    }
+
+    s.parse::<Tz>().map(TimeZone::Named).map_err(|_| {
+        RayexecError::new(format!(
+            "Unsupported time zone '{s}', expected UTC, a fixed offset like '+05:30', or a name like 'America/New_York'"
+        ))
+    })
+}
+
+/// Parse and normalize a time zone string.
+///
+/// A zero offset normalizes to 'UTC', other offsets normalize to '[+-]HH:MM',
+/// and named zones use their canonical name.
+pub fn normalize_time_zone(s: &str) -> Result<String> {
+    Ok(parse_time_zone(s)?.to_string())
+}
+
+/// Format an offset in its normalized form.
+pub fn format_offset(offset: FixedOffset) -> String {
+    let secs = offset.local_minus_utc();
+    if secs == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if secs < 0 { '-' } else { '+' };
+    let secs = secs.abs();
+    format!("{sign}{:02}:{:02}", secs / 3600, (secs % 3600) / 60)
+}
+
+fn parse_offset(s: &str) -> Option<FixedOffset> {
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+
+    if !rest.bytes().all(|b| b.is_ascii_digit() || b == b':') {
+        return None;
+    }
+
+    let (hours, minutes) = match (rest.len(), rest.split_once(':')) {
+        (_, Some((h, m))) if h.len() == 2 && m.len() == 2 => (h, m),
+        (2, None) => (rest, "00"),
+        (4, None) => (&rest[0..2], &rest[2..4]),
+        _ => return None,
+    };
+
+    let hours: i32 = hours.parse().ok()?;
+    let minutes: i32 = minutes.parse().ok()?;
+    if hours > 15 || minutes > 59 {
+        return None;
+    }
+
+    FixedOffset::east_opt(sign * (hours * 3600 + minutes * 60))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fixed_secs(s: &str) -> i32 {
+        match parse_time_zone(s).unwrap() {
+            TimeZone::Fixed(offset) => offset.local_minus_utc(),
+            other => panic!("expected fixed offset, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn parse_utc() {
+        for s in ["UTC", "utc", "GMT", "Z", "+00", "-00:00", "+0000"] {
+            assert_eq!(0, fixed_secs(s), "input: {s}");
+        }
+    }
+
+    #[test]
+    fn parse_offsets() {
+        assert_eq!(19800, fixed_secs("+05:30"));
+        assert_eq!(19800, fixed_secs("+0530"));
+        assert_eq!(-28800, fixed_secs("-08"));
+    }
+
+    #[test]
+    fn parse_named() {
+        assert_eq!(
+            TimeZone::Named(Tz::America__New_York),
+            parse_time_zone("America/New_York").unwrap()
+        );
+    }
+
+    #[test]
+    fn parse_invalid() {
+        for s in [
+            "",
+            "America/Nowhere",
+            "+5",
+            "+05:3",
+            "+16:00",
+            "+05:60",
+            "05:00",
+        ] {
+            parse_time_zone(s).unwrap_err();
+        }
+    }
+
+    #[test]
+    fn normalize() {
+        assert_eq!("UTC", normalize_time_zone("gmt").unwrap());
+        assert_eq!("UTC", normalize_time_zone("+00:00").unwrap());
+        assert_eq!("+05:30", normalize_time_zone("+0530").unwrap());
+        assert_eq!("-08:00", normalize_time_zone("-08").unwrap());
+        assert_eq!(
+            "Europe/London",
+            normalize_time_zone("Europe/London").unwrap()
+        );
+    }
+
+    #[test]
+    fn named_offsets_follow_dst() {
+        let tz = parse_time_zone("America/New_York").unwrap();
+
+        // 2024-01-15 12:00:00 UTC
+        assert_eq!(
+            -5 * 3600,
+            tz.offset_at_utc(1_705_320_000).unwrap().local_minus_utc()
+        );
+        // 2024-07-15 12:00:00 UTC
+        assert_eq!(
+            -4 * 3600,
+            tz.offset_at_utc(1_721_044_800).unwrap().local_minus_utc()
+        );
+    }
+
+    #[test]
+    fn named_local_transitions() {
+        let tz = parse_time_zone("America/New_York").unwrap();
+
+        // 2024-03-10 02:30:00 local doesn't exist, uses the offset before the
+        // transition.
+        assert_eq!(
+            -5 * 3600,
+            tz.offset_at_local(1_710_037_800).unwrap().local_minus_utc()
+        );
+        // 2024-11-03 01:30:00 local happens twice, uses the offset after the
+        // transition.
+        assert_eq!(
+            -5 * 3600,
+            tz.offset_at_local(1_730_597_400).unwrap().local_minus_utc()
+        );
+    }
+
+    #[test]
+    fn local_utc_roundtrip() {
+        let tz = parse_time_zone("Europe/Berlin").unwrap();
+
+        // 2024-07-15 12:00:00 local, 10:00:00 UTC
+        let local = 1_721_044_800_000_000;
+        let utc = tz.local_to_utc(local, TimeUnit::Microsecond).unwrap();
+        assert_eq!(local - 2 * 3600 * 1_000_000, utc);
+        assert_eq!(local, tz.utc_to_local(utc, TimeUnit::Microsecond).unwrap());
+    }
+}
@@ -48,6 +48,8 @@ pub enum DataTypeId {
     Binary,
     Struct,
     List,
+    Time64,
+    Uuid,
 }
 
 impl DataTypeId {
@@ -79,6 +81,8 @@ impl DataTypeId {
             Self::Binary => "Binary",
             Self::Struct => "Struct",
             Self::List => "List",
+            Self::Time64 => "Time64",
+            Self::Uuid => "Uuid",
         }
     }
 }
@@ -114,6 +118,8 @@ impl ProtoConv for DataTypeId {
             Self::Binary => Self::ProtoType::Binary,
             Self::Struct => Self::ProtoType::Struct,
             Self::List => Self::ProtoType::List,
+            Self::Time64 => Self::ProtoType::Time64,
+            Self::Uuid => Self::ProtoType::Uuid,
         })
     }
 
@@ -146,6 +152,8 @@ impl ProtoConv for DataTypeId {
             Self::ProtoType::Binary => Self::Binary,
             Self::ProtoType::Struct => Self::Struct,
             Self::ProtoType::List => Self::List,
+            Self::ProtoType::Time64 => Self::Time64,
+            Self::ProtoType::Uuid => Self::Uuid,
         })
     }
 }
@@ -190,12 +198,24 @@ impl ProtoConv for DecimalTypeMeta {
 #[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
 pub struct TimestampTypeMeta {
     pub unit: TimeUnit,
-    // TODO: Optional timezone (hence no copy)
+    /// Optional time zone.
+    ///
+    /// If set, values represent instants since the UTC epoch, and the time
+    /// zone is only used when displaying values. If unset, values represent a
+    /// wall clock time with no associated time zone.
+    pub tz: Option<String>,
 }
 
 impl TimestampTypeMeta {
     pub const fn new(unit: TimeUnit) -> Self {
-        TimestampTypeMeta { unit }
+        TimestampTypeMeta { unit, tz: None }
+    }
+
+    pub fn new_with_tz(unit: TimeUnit, tz: impl Into<String>) -> Self {
+        TimestampTypeMeta {
+            unit,
+            tz: Some(tz.into()),
+        }
     }
 }
 
@@ -205,12 +225,18 @@ impl ProtoConv for TimestampTypeMeta {
     fn to_proto(&self) -> Result<Self::ProtoType> {
         Ok(Self::ProtoType {
             unit: self.unit.to_proto()? as i32,
+            tz: self.tz.clone().unwrap_or_default(),
         })
     }
 
     fn from_proto(proto: Self::ProtoType) -> Result<Self> {
         Ok(Self {
             unit: TimeUnit::from_proto(proto.unit())?,
+            tz: if proto.tz.is_empty() {
+                None
+            } else {
+                Some(proto.tz)
+            },
         })
     }
 }
@@ -355,8 +381,12 @@ pub enum DataType {
     Date64,
     /// Some time interval with nanosecond resolution.
     Interval,
+    /// Microseconds since midnight.
+    Time64,
     Utf8,
     Binary,
+    /// 128-bit UUID.
+    Uuid,
     /// A struct of different types.
     Struct(StructTypeMeta),
     /// A list of values all of the same type.
@@ -397,14 +427,16 @@ impl DataType {
                 Decimal128Type::MAX_PRECISION,
                 Decimal128Type::DEFAULT_SCALE,
             )),
-            DataTypeId::Timestamp => DataType::Timestamp(TimestampTypeMeta {
-                unit: TimeUnit::Microsecond,
-            }),
+            DataTypeId::Timestamp => {
+                DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
+            }
             DataTypeId::Date32 => DataType::Date32,
             DataTypeId::Date64 => DataType::Date64,
             DataTypeId::Interval => DataType::Interval,
+            DataTypeId::Time64 => DataType::Time64,
             DataTypeId::Utf8 => DataType::Utf8,
             DataTypeId::Binary => DataType::Binary,
+            DataTypeId::Uuid => DataType::Uuid,
             DataTypeId::Struct => {
                 return Err(RayexecError::new("Cannot create a default Struct datatype"))
             }
@@ -438,8 +470,10 @@ impl DataType {
             DataType::Date32 => DataTypeId::Date32,
             DataType::Date64 => DataTypeId::Date64,
             DataType::Interval => DataTypeId::Interval,
+            DataType::Time64 => DataTypeId::Time64,
             DataType::Utf8 => DataTypeId::Utf8,
             DataType::Binary => DataTypeId::Binary,
+            DataType::Uuid => DataTypeId::Uuid,
             DataType::Struct(_) => DataTypeId::Struct,
             DataType::List(_) => DataTypeId::List,
         }
@@ -468,8 +502,10 @@ impl DataType {
             DataType::Date32 => PhysicalType::Int32,
             DataType::Date64 => PhysicalType::Int64,
             DataType::Interval => PhysicalType::Interval,
+            DataType::Time64 => PhysicalType::Int64,
             DataType::Utf8 => PhysicalType::Utf8,
             DataType::Binary => PhysicalType::Binary,
+            DataType::Uuid => PhysicalType::UInt128,
             DataType::Struct(_) => PhysicalType::Struct,
             DataType::List(_) => PhysicalType::List,
         }
@@ -579,8 +615,10 @@ impl ProtoConv for DataType {
             DataType::Date32 => Value::TypeDate32(EmptyMeta {}),
             DataType::Date64 => Value::TypeDate64(EmptyMeta {}),
             DataType::Interval => Value::TypeInterval(EmptyMeta {}),
+            DataType::Time64 => Value::TypeTime64(EmptyMeta {}),
             DataType::Utf8 => Value::TypeUtf8(EmptyMeta {}),
             DataType::Binary => Value::TypeBinary(EmptyMeta {}),
+            DataType::Uuid => Value::TypeUuid(EmptyMeta {}),
             DataType::Struct(m) => Value::TypeStruct(m.to_proto()?),
             DataType::List(m) => Value::TypeList(Box::new(m.to_proto()?)),
         };
@@ -616,6 +654,8 @@ impl ProtoConv for DataType {
             Value::TypeBinary(_) => DataType::Binary,
             Value::TypeStruct(m) => DataType::Struct(StructTypeMeta::from_proto(m)?),
             Value::TypeList(m) => DataType::List(ListTypeMeta::from_proto(*m)?),
+            Value::TypeTime64(_) => DataType::Time64,
+            Value::TypeUuid(_) => DataType::Uuid,
         })
     }
 }
@@ -640,12 +680,17 @@ impl fmt::Display for DataType {
             Self::Float64 => write!(f, "Float64"),
             Self::Decimal64(meta) => write!(f, "Decimal64({},{})", meta.precision, meta.scale),
             Self::Decimal128(meta) => write!(f, "Decimal128({},{})", meta.precision, meta.scale),
-            Self::Timestamp(meta) => write!(f, "Timestamp({})", meta.unit),
+            Self::Timestamp(meta) => match &meta.tz {
+                Some(tz) => write!(f, "Timestamp({}, {tz})", meta.unit),
+                None => write!(f, "Timestamp({})", meta.unit),
+            },
             Self::Date32 => write!(f, "Date32"),
             Self::Date64 => write!(f, "Date64"),
             Self::Interval => write!(f, "Interval"),
+            Self::Time64 => write!(f, "Time64"),
             Self::Utf8 => write!(f, "Utf8"),
             Self::Binary => write!(f, "Binary"),
+            Self::Uuid => write!(f, "Uuid"),
             Self::Struct(meta) => {
                 write!(
                     f,
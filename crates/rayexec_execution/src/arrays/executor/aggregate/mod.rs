@@ -28,6 +28,13 @@ pub trait AggregateState<Input, Output>: Debug {
     /// Produce a single value from the state, along with a bool indicating if
     /// the value is valid.
     fn finalize(&mut self) -> Result<(Output, bool)>;
+
+    /// Number of bytes the state has allocated on the heap.
+    ///
+    /// Used to track the memory used by aggregate hash tables.
+    fn heap_size_bytes(&self) -> usize {
+        0
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -63,6 +70,28 @@ impl StateCombiner {
 
         Ok(())
     }
+
+    /// Combine states, merging copies of the states in `sources` into
+    /// `targets`.
+    ///
+    /// Unlike `combine`, source states are left untouched, allowing a single
+    /// source state to be merged into multiple targets.
+    pub fn combine_cloned<State, Input, Output>(
+        sources: &[State],
+        mapping: impl IntoIterator<Item = RowToStateMapping>,
+        targets: &mut [State],
+    ) -> Result<()>
+    where
+        State: AggregateState<Input, Output> + Clone,
+    {
+        for mapping in mapping {
+            let target = &mut targets[mapping.to_state];
+            let mut source = sources[mapping.from_row].clone();
+            target.merge(&mut source)?;
+        }
+
+        Ok(())
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
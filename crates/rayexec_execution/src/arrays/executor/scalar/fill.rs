@@ -41,6 +41,7 @@ use crate::arrays::storage::{
     ListItemMetadata2,
     ListStorage,
     PrimitiveStorage,
+    StructStorage,
     UntypedNullStorage,
 };
 
@@ -281,10 +282,80 @@ pub(crate) fn concat_with_exact_total_len(arrays: &[&Array], total_len: usize) -
             concat_with_fill_state::<PhysicalBinary, _>(arrays, state)
         }
         PhysicalType::List => concat_lists(datatype.clone(), arrays, total_len),
+        PhysicalType::Struct => concat_structs(datatype.clone(), arrays, total_len),
         other => not_implemented!("concat: {other}"),
     }
 }
 
+/// Get a child array for a struct with the struct's selection applied.
+///
+/// Logical rows in the returned array line up with the logical rows in the
+/// struct array. Validity of the struct itself is not applied.
+pub fn struct_child_with_selection(array: &Array, child_idx: usize) -> Result<Array> {
+    let storage = match array.array_data() {
+        ArrayData2::Struct(storage) => storage,
+        other => {
+            return Err(RayexecError::new(format!(
+                "Expected struct array data, got {:?}",
+                other.physical_type()
+            )))
+        }
+    };
+
+    let mut child = storage
+        .children
+        .get(child_idx)
+        .ok_or_else(|| RayexecError::new(format!("Missing struct child {child_idx}")))?
+        .clone();
+
+    if let Some(selection) = &array.selection2 {
+        child.select_mut2(selection.clone());
+    }
+
+    Ok(child)
+}
+
+fn num_struct_fields(datatype: &DataType) -> Result<usize> {
+    match datatype {
+        DataType::Struct(m) => Ok(m.fields.len()),
+        other => Err(RayexecError::new(format!(
+            "Expected struct datatype, got {other}"
+        ))),
+    }
+}
+
+fn concat_structs(datatype: DataType, arrays: &[&Array], total_len: usize) -> Result<Array> {
+    let children = (0..num_struct_fields(&datatype)?)
+        .map(|child_idx| {
+            let child_arrays = arrays
+                .iter()
+                .map(|arr| struct_child_with_selection(arr, child_idx))
+                .collect::<Result<Vec<_>>>()?;
+            let refs: Vec<_> = child_arrays.iter().collect();
+            concat_with_exact_total_len(&refs, total_len)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut validity = Bitmap::new_with_all_true(total_len);
+    let mut idx = 0;
+    for array in arrays {
+        for row in 0..array.logical_len() {
+            if !array.is_valid(row).unwrap_or(false) {
+                validity.set_unchecked(idx, false);
+            }
+            idx += 1;
+        }
+    }
+
+    Ok(Array {
+        datatype,
+        selection2: None,
+        validity2: Some(validity.into()),
+        data2: StructStorage::try_new(children, total_len)?.into(),
+        next: None,
+    })
+}
+
 fn concat_lists(datatype: DataType, arrays: &[&Array], total_len: usize) -> Result<Array> {
     let inner_arrays = arrays
         .iter()
@@ -507,10 +578,120 @@ pub fn interleave(arrays: &[&Array], indices: &[(usize, usize)]) -> Result<Array
             });
             interleave_with_fill_state::<PhysicalBinary, _>(arrays, indices, state)
         }
+        PhysicalType::List => interleave_lists(datatype.clone(), arrays, indices),
+        PhysicalType::Struct => interleave_structs(datatype.clone(), arrays, indices),
         other => not_implemented!("interleave: {other}"),
     }
 }
 
+fn interleave_lists(
+    datatype: DataType,
+    arrays: &[&Array],
+    indices: &[(usize, usize)],
+) -> Result<Array> {
+    let inner_arrays = arrays
+        .iter()
+        .map(|arr| match arr.array_data() {
+            ArrayData2::List(list) => {
+                if list.array.has_selection() {
+                    return Err(RayexecError::new("List child array has selection"));
+                }
+                Ok(&list.array)
+            }
+            other => Err(RayexecError::new(format!(
+                "Invalid inner array data for interleaving lists, got {:?}",
+                other.physical_type()
+            ))),
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    // (offset, len) for every logical row in each input.
+    let input_metadatas = arrays
+        .iter()
+        .map(|arr| {
+            let mut metadatas = Vec::with_capacity(arr.logical_len());
+            UnaryExecutor::for_each2::<PhysicalList, _>(arr, |_row_num, metadata| {
+                metadatas.push(metadata.map(|m| (m.offset, m.len)))
+            })?;
+            Ok(metadatas)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut child_indices = Vec::new();
+    let mut metadatas = Vec::with_capacity(indices.len());
+    let mut validity = Bitmap::new_with_all_true(indices.len());
+
+    for (idx, &(arr_idx, row)) in indices.iter().enumerate() {
+        let metadata = input_metadatas
+            .get(arr_idx)
+            .and_then(|metadatas| metadatas.get(row).copied())
+            .flatten();
+
+        match metadata {
+            Some((offset, len)) => {
+                metadatas.push(ListItemMetadata2 {
+                    offset: child_indices.len() as i32,
+                    len,
+                });
+                child_indices.extend((offset..offset + len).map(|i| (arr_idx, i as usize)));
+            }
+            None => {
+                metadatas.push(ListItemMetadata2::default());
+                validity.set_unchecked(idx, false);
+            }
+        }
+    }
+
+    let data = ListStorage {
+        metadata: PrimitiveStorage::from(metadatas),
+        array: interleave(&inner_arrays, &child_indices)?,
+    };
+
+    Ok(Array {
+        datatype,
+        selection2: None,
+        validity2: Some(validity.into()),
+        data2: data.into(),
+        next: None,
+    })
+}
+
+fn interleave_structs(
+    datatype: DataType,
+    arrays: &[&Array],
+    indices: &[(usize, usize)],
+) -> Result<Array> {
+    let children = (0..num_struct_fields(&datatype)?)
+        .map(|child_idx| {
+            let child_arrays = arrays
+                .iter()
+                .map(|arr| struct_child_with_selection(arr, child_idx))
+                .collect::<Result<Vec<_>>>()?;
+            let refs: Vec<_> = child_arrays.iter().collect();
+            interleave(&refs, indices)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    let mut validity = Bitmap::new_with_all_true(indices.len());
+    for (idx, &(arr_idx, row)) in indices.iter().enumerate() {
+        let valid = arrays
+            .get(arr_idx)
+            .and_then(|arr| arr.is_valid(row))
+            .unwrap_or(false);
+        if !valid {
+            validity.set_unchecked(idx, false);
+        }
+    }
+
+    Ok(Array {
+        datatype,
+        selection2: None,
+        validity2: Some(validity.into()),
+        data2: StructStorage::try_new(children, indices.len())?.into(),
+        next: None,
+    })
+}
+
 fn interleave_with_fill_state<'a, S, B>(
     arrays: &'a [&Array],
     indices: &[(usize, usize)],
@@ -17,6 +17,7 @@ use crate::arrays::array::physical_type::{
     PhysicalList,
     PhysicalStorage,
     PhysicalType,
+    PhysicalU128,
     PhysicalU16,
     PhysicalU32,
     PhysicalU64,
@@ -74,7 +75,7 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalU64, CombineSetHash>(array, hashes)?
             }
             PhysicalType::UInt128 => {
-                Self::hash_one_inner::<PhysicalI128, CombineSetHash>(array, hashes)?
+                Self::hash_one_inner::<PhysicalU128, CombineSetHash>(array, hashes)?
             }
             PhysicalType::Float16 => {
                 Self::hash_one_inner::<PhysicalF16, CombineSetHash>(array, hashes)?
@@ -95,6 +96,7 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalInterval, CombineSetHash>(array, hashes)?
             }
             PhysicalType::List => Self::hash_list::<CombineSetHash>(array, hashes)?,
+            PhysicalType::Struct => Self::hash_struct::<CombineSetHash>(array, hashes)?,
             other => not_implemented!("Hash for type: {other}"),
         }
 
@@ -139,7 +141,7 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalU64, OverwriteSetHash>(array, hashes)?
             }
             PhysicalType::UInt128 => {
-                Self::hash_one_inner::<PhysicalI128, OverwriteSetHash>(array, hashes)?
+                Self::hash_one_inner::<PhysicalU128, OverwriteSetHash>(array, hashes)?
             }
             PhysicalType::Float16 => {
                 Self::hash_one_inner::<PhysicalF16, OverwriteSetHash>(array, hashes)?
@@ -160,6 +162,7 @@ impl HashExecutor {
                 Self::hash_one_inner::<PhysicalInterval, OverwriteSetHash>(array, hashes)?
             }
             PhysicalType::List => Self::hash_list::<OverwriteSetHash>(array, hashes)?,
+            PhysicalType::Struct => Self::hash_struct::<OverwriteSetHash>(array, hashes)?,
             other => not_implemented!("Hash for type: {other}"),
         }
 
@@ -282,6 +285,40 @@ impl HashExecutor {
 
         Ok(())
     }
+
+    fn hash_struct<H>(array: &Array, hashes: &mut [u64]) -> Result<()>
+    where
+        H: SetHash,
+    {
+        let children = match array.array_data() {
+            ArrayData2::Struct(storage) => &storage.children,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Unexpected array data for struct hashing: {:?}",
+                    other.physical_type(),
+                )))
+            }
+        };
+
+        // Hashes for each physical struct value, combined across all fields.
+        let mut field_hashes_buf = vec![0; array.array_data().len()];
+        Self::hash_many(children, &mut field_hashes_buf)?;
+
+        let selection = array.selection_vector();
+        let validity = array.validity();
+
+        for (idx, hash) in hashes.iter_mut().enumerate() {
+            let sel = unsafe { selection::get_unchecked(selection, idx) };
+
+            if validity.map(|v| v.value(sel)).unwrap_or(true) {
+                H::set_hash(field_hashes_buf[sel], hash);
+            } else {
+                H::set_hash(null_hash_value(), hash);
+            }
+        }
+
+        Ok(())
+    }
 }
 
 trait SetHash {
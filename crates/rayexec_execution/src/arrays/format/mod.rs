@@ -102,7 +102,7 @@ mod tests {
             (ScalarValue::Utf8("hello".into()), "hello"),
             (
                 ScalarValue::Binary([245, 255, 18].as_slice().into()),
-                "[F5, FF, 12]",
+                "\\xf5ff12",
             ),
         ];
 
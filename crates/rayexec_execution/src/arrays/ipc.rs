@@ -0,0 +1,349 @@
+//! Encoding batches as Arrow IPC record batch messages.
+//!
+//! Array buffers are written as-is using the bullet IPC message framing. Since
+//! the buffers are native endian, the output is only intended to be read back
+//! by the same process (e.g. for spilling to disk).
+//!
+//! Spec: <https://arrow.apache.org/docs/format/Columnar.html#serialization-and-interprocess-communication-ipc>
+use std::borrow::Cow;
+use std::io::{Read, Write};
+
+use half::f16;
+use rayexec_bullet::ipc::message::{RecordBatchReader, RecordBatchWriter};
+use rayexec_error::{RayexecError, Result};
+
+use super::array::physical_type::PhysicalType;
+use super::array::{Array, ArrayData2, BinaryData};
+use super::batch::Batch;
+use super::bitmap::Bitmap;
+use super::datatype::DataType;
+use super::executor::scalar::interleave;
+use super::scalar::interval::Interval;
+use super::storage::{
+    BooleanStorage,
+    GermanVarlenStorage,
+    ListItemMetadata2,
+    ListStorage,
+    PrimitiveStorage,
+    StructStorage,
+    UnionedGermanMetadata,
+    UntypedNullStorage,
+};
+
+/// Write a batch as a single record batch message.
+pub fn write_batch(writer: &mut impl Write, batch: &Batch) -> Result<()> {
+    let mut message = RecordBatchWriter::new(batch.num_rows());
+    for array in batch.arrays() {
+        encode_array(array, &mut message)?;
+    }
+    message.write_to(writer)
+}
+
+/// Read the next record batch message, decoding arrays using the provided
+/// types.
+///
+/// Returns Ok(None) once the reader is exhausted.
+pub fn read_batch(reader: &mut impl Read, datatypes: &[DataType]) -> Result<Option<Batch>> {
+    let mut message = match RecordBatchReader::try_read_from(reader)? {
+        Some(message) => message,
+        None => return Ok(None),
+    };
+
+    let arrays = datatypes
+        .iter()
+        .map(|datatype| decode_array(&mut message, datatype))
+        .collect::<Result<Vec<_>>>()?;
+
+    let batch = Batch::try_from_arrays(arrays)?;
+    if batch.num_rows() != message.num_rows() {
+        return Err(
+            RayexecError::new("Decoded batch has unexpected number of rows")
+                .with_field("expected", message.num_rows())
+                .with_field("got", batch.num_rows()),
+        );
+    }
+
+    Ok(Some(batch))
+}
+
+/// Encode an array, pushing its field node and buffers to the message.
+///
+/// Buffer listing: <https://arrow.apache.org/docs/format/Columnar.html#buffer-listing-for-each-layout>
+fn encode_array(array: &Array, message: &mut RecordBatchWriter) -> Result<()> {
+    // Arrow doesn't have selection vectors, and we only encode german varlen
+    // storage. Materialize into a new array if needed.
+    let needs_materialize = array.has_selection()
+        || matches!(
+            array.array_data(),
+            ArrayData2::Binary(BinaryData::Binary(_) | BinaryData::LargeBinary(_))
+        );
+    let array = if needs_materialize {
+        let indices: Vec<_> = (0..array.logical_len()).map(|idx| (0, idx)).collect();
+        Cow::Owned(interleave(&[array], &indices)?)
+    } else {
+        Cow::Borrowed(array)
+    };
+
+    let len = array.logical_len();
+    match array.validity() {
+        Some(validity) => {
+            message.push_node(len, len - validity.count_trues());
+            message.push_buffer(validity.data());
+        }
+        None => {
+            // Validity buffer may be omitted if there's no nulls.
+            message.push_node(len, 0);
+            message.push_buffer(&[]);
+        }
+    }
+
+    match array.array_data() {
+        ArrayData2::UntypedNull(_) => (),
+        ArrayData2::Boolean(d) => message.push_buffer(d.0.data()),
+        ArrayData2::Float16(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Float32(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Float64(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Int8(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Int16(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Int32(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Int64(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Int128(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::UInt8(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::UInt16(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::UInt32(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::UInt64(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::UInt128(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Interval(d) => message.push_buffer(d.as_bytes()),
+        ArrayData2::Binary(BinaryData::German(d)) => {
+            message.push_buffer(d.metadata.as_bytes());
+            // Currently only hold 1 data buffer for these.
+            message.push_variadic_count(1);
+            message.push_buffer(d.data.as_bytes());
+        }
+        ArrayData2::Binary(_) => {
+            return Err(RayexecError::new(
+                "Expected german varlen storage for IPC encoding",
+            ))
+        }
+        ArrayData2::List(d) => {
+            message.push_buffer(d.metadata.as_bytes());
+            encode_array(&d.array, message)?;
+        }
+        ArrayData2::Struct(d) => {
+            for child in &d.children {
+                encode_array(child, message)?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+fn decode_array(message: &mut RecordBatchReader, datatype: &DataType) -> Result<Array> {
+    let (len, null_count) = message.try_next_node()?;
+
+    let validity_buffer = message.try_next_buffer()?;
+    let validity = if null_count > 0 {
+        Some(Bitmap::try_new(validity_buffer.to_vec(), len)?)
+    } else {
+        None
+    };
+
+    let data: ArrayData2 = match datatype.physical_type() {
+        PhysicalType::UntypedNull => UntypedNullStorage(len).into(),
+        PhysicalType::Boolean => {
+            let bitmap = Bitmap::try_new(message.try_next_buffer()?.to_vec(), len)?;
+            BooleanStorage(bitmap).into()
+        }
+        PhysicalType::Float16 => decode_primitive::<f16>(message)?.into(),
+        PhysicalType::Float32 => decode_primitive::<f32>(message)?.into(),
+        PhysicalType::Float64 => decode_primitive::<f64>(message)?.into(),
+        PhysicalType::Int8 => decode_primitive::<i8>(message)?.into(),
+        PhysicalType::Int16 => decode_primitive::<i16>(message)?.into(),
+        PhysicalType::Int32 => decode_primitive::<i32>(message)?.into(),
+        PhysicalType::Int64 => decode_primitive::<i64>(message)?.into(),
+        PhysicalType::Int128 => decode_primitive::<i128>(message)?.into(),
+        PhysicalType::UInt8 => decode_primitive::<u8>(message)?.into(),
+        PhysicalType::UInt16 => decode_primitive::<u16>(message)?.into(),
+        PhysicalType::UInt32 => decode_primitive::<u32>(message)?.into(),
+        PhysicalType::UInt64 => decode_primitive::<u64>(message)?.into(),
+        PhysicalType::UInt128 => decode_primitive::<u128>(message)?.into(),
+        PhysicalType::Interval => decode_primitive::<Interval>(message)?.into(),
+        PhysicalType::Binary | PhysicalType::Utf8 => {
+            let metadata = decode_primitive::<UnionedGermanMetadata>(message)?;
+            let buffer_count = message.try_next_variadic_count()?;
+            if buffer_count != 1 {
+                return Err(RayexecError::new("Expected a single varlen data buffer")
+                    .with_field("count", buffer_count));
+            }
+            let data = decode_primitive::<u8>(message)?;
+            GermanVarlenStorage { metadata, data }.into()
+        }
+        PhysicalType::List => {
+            let child_type = match datatype {
+                DataType::List(m) => m.datatype.as_ref(),
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Expected list type for IPC decoding, got {other}"
+                    )))
+                }
+            };
+            let metadata = decode_primitive::<ListItemMetadata2>(message)?;
+            let child = decode_array(message, child_type)?;
+            ListStorage::try_new(metadata, child)?.into()
+        }
+        PhysicalType::Struct => {
+            let fields = match datatype {
+                DataType::Struct(m) => &m.fields,
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Expected struct type for IPC decoding, got {other}"
+                    )))
+                }
+            };
+            let children = fields
+                .iter()
+                .map(|field| decode_array(message, &field.datatype))
+                .collect::<Result<Vec<_>>>()?;
+            StructStorage::try_new(children, len)?.into()
+        }
+        other => {
+            return Err(RayexecError::new(format!(
+                "Unsupported physical type for IPC decoding: {other}"
+            )))
+        }
+    };
+
+    if data.len() != len {
+        return Err(RayexecError::new("Decoded array has unexpected length")
+            .with_field("expected", len)
+            .with_field("got", data.len()));
+    }
+
+    Ok(match validity {
+        Some(validity) => Array::new_with_validity_and_array_data(datatype.clone(), validity, data),
+        None => Array::new_with_array_data(datatype.clone(), data),
+    })
+}
+
+fn decode_primitive<T>(message: &mut RecordBatchReader) -> Result<PrimitiveStorage<T>>
+where
+    T: Default + Copy,
+{
+    PrimitiveStorage::copy_from_bytes(message.try_next_buffer()?)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::arrays::datatype::{DecimalTypeMeta, ListTypeMeta, StructTypeMeta};
+    use crate::arrays::executor::scalar::concat;
+    use crate::arrays::field::Field;
+    use crate::arrays::row::OwnedScalarRow;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::arrays::selection::SelectionVector;
+
+    #[allow(deprecated)]
+    fn batch_rows(batch: &Batch) -> Vec<OwnedScalarRow> {
+        (0..batch.num_rows())
+            .map(|idx| batch.row(idx).unwrap().into_owned())
+            .collect()
+    }
+
+    fn roundtrip(batch: Batch) {
+        let datatypes: Vec<_> = batch
+            .arrays()
+            .iter()
+            .map(|arr| arr.datatype().clone())
+            .collect();
+
+        let mut buf = Vec::new();
+        write_batch(&mut buf, &batch).unwrap();
+
+        let mut reader = Cursor::new(buf);
+        let got = read_batch(&mut reader, &datatypes).unwrap().unwrap();
+        assert_eq!(batch_rows(&batch), batch_rows(&got));
+        for (expected, got) in datatypes.iter().zip(got.arrays()) {
+            assert_eq!(expected, got.datatype());
+        }
+
+        assert!(read_batch(&mut reader, &datatypes).unwrap().is_none());
+    }
+
+    #[test]
+    fn primitive_roundtrip() {
+        let batch = Batch::try_from_arrays([
+            Array::from_iter([Some(3), None, Some(1)]),
+            Array::from_iter([9.5_f64, 8.0, 7.25]),
+            Array::from_iter([true, false, true]),
+        ])
+        .unwrap();
+
+        roundtrip(batch);
+    }
+
+    #[test]
+    fn utf8_roundtrip() {
+        let batch = Batch::try_from_arrays([Array::from_iter([
+            Some("mario"),
+            None,
+            Some("a string longer than the inline threshold"),
+        ])])
+        .unwrap();
+
+        roundtrip(batch);
+    }
+
+    #[test]
+    fn decimal_roundtrip() {
+        let datatype = DataType::Decimal128(DecimalTypeMeta::new(4, 2));
+        let arr = Array::new_with_array_data(
+            datatype,
+            PrimitiveStorage::from(vec![1000_i128, 1200, 1250]),
+        );
+
+        roundtrip(Batch::try_from_arrays([arr]).unwrap());
+    }
+
+    #[test]
+    fn selection_roundtrip() {
+        let mut arr = Array::from_iter(["a", "b", "c", "d"]);
+        arr.select_mut2(SelectionVector::from_iter([3, 1, 1]));
+
+        roundtrip(Batch::try_from_arrays([arr]).unwrap());
+    }
+
+    #[test]
+    fn nested_roundtrip() {
+        let list = concat(&[
+            &ScalarValue::List(vec![1.into(), 2.into()])
+                .as_array(1)
+                .unwrap(),
+            &Array::new_typed_null_array(DataType::List(ListTypeMeta::new(DataType::Int32)), 1)
+                .unwrap(),
+            &ScalarValue::List(vec![3.into()]).as_array(1).unwrap(),
+        ])
+        .unwrap();
+
+        let strukt = Array::new_with_array_data(
+            DataType::Struct(StructTypeMeta {
+                fields: vec![
+                    Field::new("a", DataType::Int64, true),
+                    Field::new("b", DataType::Utf8, true),
+                ],
+            }),
+            StructStorage::try_new(
+                vec![
+                    Array::from_iter([1_i64, 2, 3]),
+                    Array::from_iter(["x", "y", "z"]),
+                ],
+                3,
+            )
+            .unwrap(),
+        );
+
+        roundtrip(Batch::try_from_arrays([list, strukt]).unwrap());
+    }
+}
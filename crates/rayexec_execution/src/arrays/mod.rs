@@ -6,6 +6,7 @@ pub mod datatype;
 pub mod executor;
 pub mod field;
 pub mod format;
+pub mod ipc;
 pub mod row;
 pub mod scalar;
 pub mod selection;
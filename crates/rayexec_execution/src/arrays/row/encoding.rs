@@ -20,10 +20,14 @@ use crate::arrays::array::physical_type::{
     PhysicalU32,
     PhysicalU64,
     PhysicalU8,
+    PhysicalUtf8,
 };
-use crate::arrays::array::{Array, ArrayData2, BinaryData};
+use crate::arrays::array::{Array, ArrayData2};
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::datatype::DataType;
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::arrays::scalar::interval::Interval;
+use crate::arrays::selection;
 
 /// Binary-encoded rows suitable for comparisons.
 #[derive(Debug)]
@@ -39,6 +43,11 @@ impl ComparableRows {
         self.offsets.len() - 1
     }
 
+    /// Number of bytes used by the encoded rows.
+    pub fn size_bytes(&self) -> usize {
+        self.data.len() + std::mem::size_of_val(self.offsets.as_slice())
+    }
+
     pub fn first(&self) -> Option<ComparableRow<'_>> {
         self.row(0)
     }
@@ -119,6 +128,11 @@ pub struct ComparableColumn {
     /// If we should encode nulls such that they should be ordered before any
     /// valid values.
     pub nulls_first: bool,
+    /// Collation to apply to string values before encoding.
+    ///
+    /// Strings are encoded using their collation keys, so strings that are
+    /// equal under the collation encode to the same bytes.
+    pub collation: Collation,
 }
 
 impl ComparableColumn {
@@ -177,8 +191,7 @@ impl ComparableRowEncoder {
             }
         }
 
-        let size = self.compute_data_size(columns)?;
-        let mut data = vec![0; size];
+        let mut data = Vec::new();
 
         // Track start offset per row.
         //
@@ -187,189 +200,185 @@ impl ComparableRowEncoder {
         offsets.push(0);
 
         for row_idx in 0..num_rows {
-            let data = data.as_mut_slice();
-
-            let mut row_offset = *offsets.last().unwrap();
             for (arr, cmp_col) in columns.iter().zip(self.columns.iter()) {
-                row_offset = match arr.array_data() {
-                    ArrayData2::UntypedNull(_) => {
-                        Self::encode_untyped_null(cmp_col, data, row_offset)?
-                    }
-                    ArrayData2::Boolean(_) => Self::encode_primitive::<PhysicalBool>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Int8(_) => Self::encode_primitive::<PhysicalI8>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Int16(_) => Self::encode_primitive::<PhysicalI16>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Int32(_) => Self::encode_primitive::<PhysicalI32>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Int64(_) => Self::encode_primitive::<PhysicalI64>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Int128(_) => Self::encode_primitive::<PhysicalI128>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::UInt8(_) => Self::encode_primitive::<PhysicalU8>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::UInt16(_) => Self::encode_primitive::<PhysicalU16>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::UInt32(_) => Self::encode_primitive::<PhysicalU32>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::UInt64(_) => Self::encode_primitive::<PhysicalU64>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::UInt128(_) => Self::encode_primitive::<PhysicalU128>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Float16(_) => Self::encode_primitive::<PhysicalF16>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Float32(_) => Self::encode_primitive::<PhysicalF32>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Float64(_) => Self::encode_primitive::<PhysicalF64>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Interval(_) => Self::encode_primitive::<PhysicalInterval>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::Binary(_) => Self::encode_varlen::<PhysicalBinary>(
-                        cmp_col, arr, row_idx, data, row_offset,
-                    )?,
-                    ArrayData2::List(_) => not_implemented!("Row encode list"),
-                };
+                Self::encode_value(cmp_col, arr, row_idx, &mut data)?;
             }
 
-            offsets.push(row_offset);
+            offsets.push(data.len());
         }
 
         Ok(ComparableRows { data, offsets })
     }
 
-    /// Compute the size of the data buffer we'll need for storing all encoded
-    /// rows.
-    fn compute_data_size(&self, columns: &[&Array]) -> Result<usize> {
-        let mut size = 0;
-        for arr in columns {
-            let mut arr_size = match arr.array_data() {
-                ArrayData2::UntypedNull(_) => 0, // Nulls will be encoded in the "validity" portion of the row.
-                ArrayData2::Boolean(d) => d.len() * std::mem::size_of::<bool>(), // Note this will expand the 1 bit bools to bytes.
-                ArrayData2::Int8(d) => d.data_size_bytes(),
-                ArrayData2::Int16(d) => d.data_size_bytes(),
-                ArrayData2::Int32(d) => d.data_size_bytes(),
-                ArrayData2::Int64(d) => d.data_size_bytes(),
-                ArrayData2::Int128(d) => d.data_size_bytes(),
-                ArrayData2::UInt8(d) => d.data_size_bytes(),
-                ArrayData2::UInt16(d) => d.data_size_bytes(),
-                ArrayData2::UInt32(d) => d.data_size_bytes(),
-                ArrayData2::UInt64(d) => d.data_size_bytes(),
-                ArrayData2::UInt128(d) => d.data_size_bytes(),
-                ArrayData2::Float16(d) => d.data_size_bytes(),
-                ArrayData2::Float32(d) => d.data_size_bytes(),
-                ArrayData2::Float64(d) => d.data_size_bytes(),
-                ArrayData2::Interval(d) => d.data_size_bytes(),
-                ArrayData2::Binary(d) => match d {
-                    BinaryData::Binary(d) => d.data_size_bytes(),
-                    BinaryData::LargeBinary(d) => d.data_size_bytes(),
-                    BinaryData::German(d) => d.data_size_bytes(),
-                },
-                ArrayData2::List(_) => not_implemented!("Row encode list"),
-            };
-
-            // Account for validities.
-            //
-            // Currently all rows will have validities written for every column
-            // even if there's no validity bitmap for the column. This just
-            // makes implementation easier.
-            arr_size += std::mem::size_of::<u8>() * arr.logical_len();
-
-            size += arr_size;
+    /// Encodes the value at the logical `row` of `arr`, appending it to `buf`.
+    fn encode_value(
+        col: &ComparableColumn,
+        arr: &Array,
+        row: usize,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        match arr.array_data() {
+            ArrayData2::UntypedNull(_) => Self::encode_untyped_null(col, buf),
+            ArrayData2::Boolean(_) => Self::encode_primitive::<PhysicalBool>(col, arr, row, buf),
+            ArrayData2::Int8(_) => Self::encode_primitive::<PhysicalI8>(col, arr, row, buf),
+            ArrayData2::Int16(_) => Self::encode_primitive::<PhysicalI16>(col, arr, row, buf),
+            ArrayData2::Int32(_) => Self::encode_primitive::<PhysicalI32>(col, arr, row, buf),
+            ArrayData2::Int64(_) => Self::encode_primitive::<PhysicalI64>(col, arr, row, buf),
+            ArrayData2::Int128(_) => Self::encode_primitive::<PhysicalI128>(col, arr, row, buf),
+            ArrayData2::UInt8(_) => Self::encode_primitive::<PhysicalU8>(col, arr, row, buf),
+            ArrayData2::UInt16(_) => Self::encode_primitive::<PhysicalU16>(col, arr, row, buf),
+            ArrayData2::UInt32(_) => Self::encode_primitive::<PhysicalU32>(col, arr, row, buf),
+            ArrayData2::UInt64(_) => Self::encode_primitive::<PhysicalU64>(col, arr, row, buf),
+            ArrayData2::UInt128(_) => Self::encode_primitive::<PhysicalU128>(col, arr, row, buf),
+            ArrayData2::Float16(_) => Self::encode_primitive::<PhysicalF16>(col, arr, row, buf),
+            ArrayData2::Float32(_) => Self::encode_primitive::<PhysicalF32>(col, arr, row, buf),
+            ArrayData2::Float64(_) => Self::encode_primitive::<PhysicalF64>(col, arr, row, buf),
+            ArrayData2::Interval(_) => {
+                Self::encode_primitive::<PhysicalInterval>(col, arr, row, buf)
+            }
+            ArrayData2::Binary(_) => {
+                if !col.collation.is_binary() && arr.datatype() == &DataType::Utf8 {
+                    Self::encode_collated_utf8(col, arr, row, buf)
+                } else {
+                    Self::encode_varlen::<PhysicalBinary>(col, arr, row, buf)
+                }
+            }
+            ArrayData2::List(_) => not_implemented!("Row encode list"),
+            ArrayData2::Struct(_) => Self::encode_struct(col, arr, row, buf),
         }
-
-        Ok(size)
     }
 
-    /// Encodes a variable length array into `buf` starting at `start`.
+    /// Encodes a variable length value into `buf`.
     ///
-    /// This should return the new offset to write to for the next value.
+    /// Zero bytes in the value are escaped, and the value is terminated with
+    /// two zero bytes. This keeps the encoding prefix-free so that a value
+    /// followed by another column (or struct field) can't compare equal to a
+    /// longer value, and shorter values sort before longer values sharing the
+    /// same prefix.
     fn encode_varlen<'a, S>(
         col: &ComparableColumn,
         arr: &'a Array,
         row: usize,
-        buf: &mut [u8],
-        start: usize,
-    ) -> Result<usize>
+        buf: &mut Vec<u8>,
+    ) -> Result<()>
     where
         S: PhysicalStorage,
-        S::Type<'a>: ComparableEncode + AsBytes,
+        S::Type<'a>: AsBytes,
     {
-        let null_byte = col.null_byte();
-        let valid_byte = col.valid_byte();
-
         match UnaryExecutor::value_at2::<S>(arr, row)? {
             Some(val) => {
-                buf[start] = valid_byte;
-                let end = start + 1 + val.as_bytes().len();
-                let write_buf = &mut buf[start + 1..end];
-                val.encode(write_buf);
-                col.invert_if_desc(write_buf);
+                Self::encode_varlen_bytes(col, val.as_bytes(), buf);
+            }
+            None => buf.push(col.null_byte()),
+        }
+
+        Ok(())
+    }
 
-                Ok(start + 1 + write_buf.len())
+    /// Encodes the collation key of a string value into `buf`.
+    fn encode_collated_utf8(
+        col: &ComparableColumn,
+        arr: &Array,
+        row: usize,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        match UnaryExecutor::value_at2::<PhysicalUtf8>(arr, row)? {
+            Some(val) => {
+                let key = col.collation.collate(val);
+                Self::encode_varlen_bytes(col, key.as_bytes(), buf);
             }
-            None => {
-                buf[start] = null_byte;
+            None => buf.push(col.null_byte()),
+        }
+
+        Ok(())
+    }
 
-                Ok(start + 1)
+    fn encode_varlen_bytes(col: &ComparableColumn, val: &[u8], buf: &mut Vec<u8>) {
+        buf.push(col.valid_byte());
+        let start = buf.len();
+        for &b in val {
+            if b == 0 {
+                buf.extend_from_slice(&[0, 0xFF]);
+            } else {
+                buf.push(b);
             }
         }
+        buf.extend_from_slice(&[0, 0]);
+        col.invert_if_desc(&mut buf[start..]);
     }
 
-    fn encode_untyped_null(col: &ComparableColumn, buf: &mut [u8], start: usize) -> Result<usize> {
-        let null_byte = col.null_byte();
-        buf[start] = null_byte;
-        Ok(start + 1)
+    fn encode_untyped_null(col: &ComparableColumn, buf: &mut Vec<u8>) -> Result<()> {
+        buf.push(col.null_byte());
+        Ok(())
     }
 
-    /// Encodes a primitive length array into `buf` starting at `start`.
-    ///
-    /// This should return the new offset to write to for the next value.
+    /// Encodes a primitive value into `buf`.
     fn encode_primitive<'a, S>(
         col: &ComparableColumn,
         arr: &'a Array,
         row: usize,
-        buf: &mut [u8],
-        start: usize,
-    ) -> Result<usize>
+        buf: &mut Vec<u8>,
+    ) -> Result<()>
     where
         S: PhysicalStorage,
         S::Type<'a>: ComparableEncode,
     {
-        let null_byte = col.null_byte();
-        let valid_byte = col.valid_byte();
-
         match UnaryExecutor::value_at2::<S>(arr, row)? {
             Some(val) => {
-                buf[start] = valid_byte;
-                let end = start + 1 + std::mem::size_of::<S::Type<'a>>();
-                let write_buf = &mut buf[start + 1..end];
+                buf.push(col.valid_byte());
+                let start = buf.len();
+                buf.resize(start + std::mem::size_of::<S::Type<'a>>(), 0);
+                let write_buf = &mut buf[start..];
                 val.encode(write_buf);
                 col.invert_if_desc(write_buf);
+            }
+            None => buf.push(col.null_byte()),
+        }
+
+        Ok(())
+    }
 
-                Ok(start + 1 + write_buf.len())
+    /// Encodes a struct value into `buf`.
+    ///
+    /// Fields are encoded in order after the struct's validity byte, giving a
+    /// lexicographic ordering on the fields.
+    fn encode_struct(
+        col: &ComparableColumn,
+        arr: &Array,
+        row: usize,
+        buf: &mut Vec<u8>,
+    ) -> Result<()> {
+        let storage = match arr.array_data() {
+            ArrayData2::Struct(storage) => storage,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected struct array data, got {:?}",
+                    other.physical_type()
+                )))
             }
-            None => {
-                buf[start] = null_byte;
+        };
 
-                Ok(start + 1)
+        match arr.is_valid(row) {
+            Some(true) => (),
+            Some(false) => {
+                buf.push(col.null_byte());
+                return Ok(());
+            }
+            None => {
+                return Err(RayexecError::new(format!(
+                    "Row {row} out of bounds for array of length {}",
+                    arr.logical_len()
+                )))
             }
         }
+
+        buf.push(col.valid_byte());
+        let sel = selection::get(arr.selection_vector(), row);
+        for child in &storage.children {
+            Self::encode_value(col, child, sel, buf)?;
+        }
+
+        Ok(())
     }
 }
 
@@ -462,23 +471,14 @@ impl ComparableEncode for bool {
     }
 }
 
-impl ComparableEncode for &str {
-    fn encode(&self, buf: &mut [u8]) {
-        buf.copy_from_slice(self.as_bytes())
-    }
-}
-
-impl ComparableEncode for &[u8] {
-    fn encode(&self, buf: &mut [u8]) {
-        buf.copy_from_slice(self)
-    }
-}
-
 #[cfg(test)]
 mod tests {
     use std::cmp::Ordering;
 
     use super::*;
+    use crate::arrays::datatype::{DataType, StructTypeMeta};
+    use crate::arrays::field::Field;
+    use crate::arrays::storage::StructStorage;
 
     #[test]
     fn simple_primitive_cmp_between_cols_asc() {
@@ -489,6 +489,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: false,
                 nulls_first: false,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -515,6 +516,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: true,
                 nulls_first: false,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -542,6 +544,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: false,
                 nulls_first: false,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -568,6 +571,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: false,
                 nulls_first: false,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -592,6 +596,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: true,
                 nulls_first: false,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -616,6 +621,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: false,
                 nulls_first: true,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -640,6 +646,7 @@ mod tests {
             columns: vec![ComparableColumn {
                 desc: true,
                 nulls_first: true,
+                collation: Collation::BINARY,
             }],
         };
 
@@ -654,4 +661,90 @@ mod tests {
         assert!(rows1.row(2).unwrap() < rows2.row(2).unwrap());
         assert!(rows1.row(3).unwrap() > rows2.row(3).unwrap());
     }
+
+    #[test]
+    fn varlen_multiple_cols_prefix_free() {
+        let a1 = Array::from_iter(["a", "a"]);
+        let b1 = Array::from_iter(["bc", "z"]);
+        let a2 = Array::from_iter(["ab", "ab"]);
+        let b2 = Array::from_iter(["c", "a"]);
+
+        let encoder = ComparableRowEncoder {
+            columns: vec![
+                ComparableColumn {
+                    desc: false,
+                    nulls_first: false,
+                    collation: Collation::BINARY,
+                };
+                2
+            ],
+        };
+
+        let rows1 = encoder.encode(&[&a1, &b1]).unwrap();
+        let rows2 = encoder.encode(&[&a2, &b2]).unwrap();
+
+        // ('a', 'bc') < ('ab', 'c')
+        assert!(rows1.row(0).unwrap() < rows2.row(0).unwrap());
+        // ('a', 'z') < ('ab', 'a')
+        assert!(rows1.row(1).unwrap() < rows2.row(1).unwrap());
+    }
+
+    #[test]
+    fn varlen_collated() {
+        let arr = Array::from_iter(["b", "A", "a", "C"]);
+
+        let encoder = ComparableRowEncoder {
+            columns: vec![ComparableColumn {
+                desc: false,
+                nulls_first: false,
+                collation: Collation::NOCASE,
+            }],
+        };
+        let rows = encoder.encode(&[&arr]).unwrap();
+
+        // 'A' and 'a' are equal under the collation.
+        assert_eq!(rows.row(1).unwrap(), rows.row(2).unwrap());
+        // 'A' < 'b' < 'C'
+        assert!(rows.row(1).unwrap() < rows.row(0).unwrap());
+        assert!(rows.row(0).unwrap() < rows.row(3).unwrap());
+    }
+
+    #[test]
+    fn struct_cmp_by_fields() {
+        let datatype = DataType::Struct(StructTypeMeta {
+            fields: vec![
+                Field::new("a", DataType::Int32, true),
+                Field::new("b", DataType::Utf8, true),
+            ],
+        });
+        let make_struct = |a: Vec<i32>, b: Vec<&str>| {
+            let len = a.len();
+            let children = vec![Array::from_iter(a), Array::from_iter(b)];
+            Array::new_with_array_data(
+                datatype.clone(),
+                StructStorage::try_new(children, len).unwrap(),
+            )
+        };
+
+        let col1 = make_struct(vec![1, 2, 2], vec!["b", "a", "a"]);
+        let col2 = make_struct(vec![2, 1, 2], vec!["a", "c", "a"]);
+
+        let encoder = ComparableRowEncoder {
+            columns: vec![ComparableColumn {
+                desc: false,
+                nulls_first: false,
+                collation: Collation::BINARY,
+            }],
+        };
+
+        let rows1 = encoder.encode(&[&col1]).unwrap();
+        let rows2 = encoder.encode(&[&col2]).unwrap();
+
+        let cmps: Vec<_> = (rows1.iter().zip(rows2.iter()))
+            .map(|(left, right)| left.cmp(&right))
+            .collect();
+
+        let expected = vec![Ordering::Less, Ordering::Greater, Ordering::Equal];
+        assert_eq!(expected, cmps);
+    }
 }
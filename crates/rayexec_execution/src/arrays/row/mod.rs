@@ -6,7 +6,7 @@ use crate::arrays::array::Array;
 use crate::arrays::scalar::ScalarValue;
 
 /// Scalar representation of a single row.
-#[derive(Debug, Clone, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ScalarRow<'a> {
     pub columns: Vec<ScalarValue<'a>>,
 }
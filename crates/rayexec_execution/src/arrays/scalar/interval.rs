@@ -1,5 +1,6 @@
 use std::fmt;
 
+use num::NumCast;
 use rayexec_error::Result;
 use rayexec_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
@@ -25,6 +26,8 @@ impl Interval {
     pub const NANOSECONDS_IN_SECOND: i64 = 1_000_000_000;
     pub const NANOSECONDS_IN_MINUTE: i64 = 60 * Self::NANOSECONDS_IN_SECOND;
     pub const NANOSECONDS_IN_HOUR: i64 = 60 * Self::NANOSECONDS_IN_MINUTE;
+    pub const NANOSECONDS_IN_DAY: i64 =
+        Self::ASSUMED_HOURS_IN_DAY as i64 * Self::NANOSECONDS_IN_HOUR;
 
     pub const fn new(months: i32, days: i32, nanos: i64) -> Self {
         Interval {
@@ -81,6 +84,62 @@ impl Interval {
     pub fn add_millenium(&mut self, millenium: i32) {
         self.add_years(millenium * 1000)
     }
+
+    /// Add two intervals field by field, returning None on overflow.
+    pub fn checked_add(self, other: Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_add(other.months)?,
+            days: self.days.checked_add(other.days)?,
+            nanos: self.nanos.checked_add(other.nanos)?,
+        })
+    }
+
+    /// Subtract two intervals field by field, returning None on overflow.
+    pub fn checked_sub(self, other: Interval) -> Option<Interval> {
+        Some(Interval {
+            months: self.months.checked_sub(other.months)?,
+            days: self.days.checked_sub(other.days)?,
+            nanos: self.nanos.checked_sub(other.nanos)?,
+        })
+    }
+
+    /// Multiply an interval by a (possibly fractional) factor.
+    ///
+    /// Follows Postgres: fractional months spill into days (assuming 30 days
+    /// per month), and fractional days spill into the time portion (assuming
+    /// 24 hours per day).
+    pub fn checked_mul_f64(self, factor: f64) -> Option<Interval> {
+        let months = self.months as f64 * factor;
+        let days = self.days as f64 * factor;
+        let nanos = self.nanos as f64 * factor;
+
+        if !months.is_finite() || !days.is_finite() || !nanos.is_finite() {
+            return None;
+        }
+
+        let whole_months = months.trunc();
+        let month_remainder_days = (months - whole_months) * Self::ASSUMED_DAYS_IN_MONTH as f64;
+
+        let whole_days = days.trunc() + month_remainder_days.trunc();
+        let day_remainder = (days - days.trunc()) + (month_remainder_days.fract());
+        let nanos = nanos + day_remainder * Self::NANOSECONDS_IN_DAY as f64;
+
+        Some(Interval {
+            months: NumCast::from(whole_months)?,
+            days: NumCast::from(whole_days)?,
+            nanos: NumCast::from(nanos.round())?,
+        })
+    }
+
+    /// Divide an interval by a (possibly fractional) divisor.
+    ///
+    /// Returns None when dividing by zero.
+    pub fn checked_div_f64(self, divisor: f64) -> Option<Interval> {
+        if divisor == 0.0 {
+            return None;
+        }
+        self.checked_mul_f64(1.0 / divisor)
+    }
 }
 
 impl fmt::Display for Interval {
@@ -9,7 +9,7 @@ use std::hash::Hash;
 use decimal::{Decimal128Scalar, Decimal64Scalar};
 use half::f16;
 use interval::Interval;
-use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
+use rayexec_error::{OptionExt, RayexecError, Result};
 use rayexec_proto::ProtoConv;
 use serde::{Deserialize, Serialize};
 use timestamp::TimestampScalar;
@@ -17,6 +17,7 @@ use timestamp::TimestampScalar;
 use crate::arrays::array::{Array, ArrayData2};
 use crate::arrays::bitmap::Bitmap;
 use crate::arrays::compute::cast::format::{
+    BinaryFormatter,
     BoolFormatter,
     Date32Formatter,
     Date64Formatter,
@@ -32,24 +33,33 @@ use crate::arrays::compute::cast::format::{
     Int64Formatter,
     Int8Formatter,
     IntervalFormatter,
+    Time64Formatter,
     TimestampMicrosecondsFormatter,
     TimestampMillisecondsFormatter,
     TimestampNanosecondsFormatter,
     TimestampSecondsFormatter,
+    TimestampTzMicrosecondsFormatter,
+    TimestampTzMillisecondsFormatter,
+    TimestampTzNanosecondsFormatter,
+    TimestampTzSecondsFormatter,
     UInt128Formatter,
     UInt16Formatter,
     UInt32Formatter,
     UInt64Formatter,
     UInt8Formatter,
+    UuidFormatter,
 };
+use crate::arrays::compute::time_zone::parse_time_zone;
 use crate::arrays::datatype::{
     DataType,
     DecimalTypeMeta,
     ListTypeMeta,
+    StructTypeMeta,
     TimeUnit,
     TimestampTypeMeta,
 };
 use crate::arrays::executor::scalar::concat;
+use crate::arrays::field::Field;
 use crate::arrays::selection::SelectionVector;
 use crate::arrays::storage::{
     BooleanStorage,
@@ -57,6 +67,7 @@ use crate::arrays::storage::{
     ListItemMetadata2,
     ListStorage,
     PrimitiveStorage,
+    StructStorage,
 };
 
 /// A single scalar value.
@@ -83,9 +94,13 @@ pub enum ScalarValue<'a> {
     Date64(i64),
     Timestamp(TimestampScalar),
     Interval(Interval),
+    /// Microseconds since midnight.
+    Time64(i64),
     Utf8(Cow<'a, str>),
     Binary(Cow<'a, [u8]>),
-    Struct(Vec<ScalarValue<'a>>),
+    Uuid(u128),
+    /// Struct value with field names and values.
+    Struct(Vec<(String, ScalarValue<'a>)>),
     List(Vec<ScalarValue<'a>>),
 }
 
@@ -117,8 +132,10 @@ impl Hash for ScalarValue<'_> {
             Self::Date64(v) => v.hash(state),
             Self::Timestamp(v) => v.hash(state),
             Self::Interval(v) => v.hash(state),
+            Self::Time64(v) => v.hash(state),
             Self::Utf8(v) => v.hash(state),
             Self::Binary(v) => v.hash(state),
+            Self::Uuid(v) => v.hash(state),
             Self::Struct(v) => v.hash(state),
             Self::List(v) => v.hash(state),
         }
@@ -153,11 +170,21 @@ impl ScalarValue<'_> {
             }
             ScalarValue::Date32(_) => DataType::Date32,
             ScalarValue::Date64(_) => DataType::Date64,
-            ScalarValue::Timestamp(v) => DataType::Timestamp(TimestampTypeMeta::new(v.unit)),
+            ScalarValue::Timestamp(v) => DataType::Timestamp(TimestampTypeMeta {
+                unit: v.unit,
+                tz: v.tz.clone(),
+            }),
             ScalarValue::Interval(_) => DataType::Interval,
+            ScalarValue::Time64(_) => DataType::Time64,
             ScalarValue::Utf8(_) => DataType::Utf8,
             ScalarValue::Binary(_) => DataType::Binary,
-            ScalarValue::Struct(_fields) => unimplemented!(), // TODO: Fill out the meta
+            ScalarValue::Uuid(_) => DataType::Uuid,
+            ScalarValue::Struct(fields) => DataType::Struct(StructTypeMeta {
+                fields: fields
+                    .iter()
+                    .map(|(name, v)| Field::new(name.clone(), v.datatype(), true))
+                    .collect(),
+            }),
             ScalarValue::List(list) => match list.first() {
                 Some(first) => DataType::List(ListTypeMeta {
                     datatype: Box::new(first.datatype()),
@@ -192,10 +219,12 @@ impl ScalarValue<'_> {
             Self::Date64(v) => OwnedScalarValue::Date64(v),
             Self::Timestamp(v) => OwnedScalarValue::Timestamp(v),
             Self::Interval(v) => OwnedScalarValue::Interval(v),
+            Self::Time64(v) => OwnedScalarValue::Time64(v),
             Self::Utf8(v) => OwnedScalarValue::Utf8(v.into_owned().into()),
             Self::Binary(v) => OwnedScalarValue::Binary(v.into_owned().into()),
+            Self::Uuid(v) => OwnedScalarValue::Uuid(v),
             Self::Struct(v) => {
-                OwnedScalarValue::Struct(v.into_iter().map(|v| v.into_owned()).collect())
+                OwnedScalarValue::Struct(v.into_iter().map(|(k, v)| (k, v.into_owned())).collect())
             }
             Self::List(v) => {
                 OwnedScalarValue::List(v.into_iter().map(|v| v.into_owned()).collect())
@@ -227,15 +256,17 @@ impl ScalarValue<'_> {
             Self::Date64(v) => PrimitiveStorage::from(vec![*v]).into(),
             Self::Timestamp(v) => PrimitiveStorage::from(vec![v.value]).into(),
             Self::Interval(v) => PrimitiveStorage::from(vec![*v]).into(),
+            Self::Time64(v) => PrimitiveStorage::from(vec![*v]).into(),
             Self::Utf8(v) => GermanVarlenStorage::with_value(v.as_ref()).into(),
             Self::Binary(v) => GermanVarlenStorage::with_value(v.as_ref()).into(),
+            Self::Uuid(v) => PrimitiveStorage::from(vec![*v]).into(),
             Self::List(v) => {
                 if v.is_empty() {
                     let metadata = ListItemMetadata2 { offset: 0, len: 0 };
 
                     ListStorage {
                         metadata: vec![metadata].into(),
-                        array: Array::new_untyped_null_array(0),
+                        array: Array::new_empty_array(DataType::Null)?,
                     }
                     .into()
                 } else {
@@ -258,7 +289,13 @@ impl ScalarValue<'_> {
                     .into()
                 }
             }
-            other => not_implemented!("{other:?} to array"), // Struct, List
+            Self::Struct(v) => {
+                let children = v
+                    .iter()
+                    .map(|(_, v)| v.as_array(1))
+                    .collect::<Result<Vec<_>>>()?;
+                StructStorage::try_new(children, 1)?.into()
+            }
         };
 
         let mut array = Array::new_with_array_data(self.datatype(), data);
@@ -379,6 +416,25 @@ impl fmt::Display for ScalarValue<'_> {
             }
             Self::Date32(v) => Date32Formatter.write(v, f),
             Self::Date64(v) => Date64Formatter.write(v, f),
+            Self::Timestamp(TimestampScalar {
+                unit,
+                value,
+                tz: Some(tz),
+            }) => {
+                let zone = parse_time_zone(tz).map_err(|_| fmt::Error)?;
+                match unit {
+                    TimeUnit::Second => TimestampTzSecondsFormatter::new(zone).write(value, f),
+                    TimeUnit::Millisecond => {
+                        TimestampTzMillisecondsFormatter::new(zone).write(value, f)
+                    }
+                    TimeUnit::Microsecond => {
+                        TimestampTzMicrosecondsFormatter::new(zone).write(value, f)
+                    }
+                    TimeUnit::Nanosecond => {
+                        TimestampTzNanosecondsFormatter::new(zone).write(value, f)
+                    }
+                }
+            }
             Self::Timestamp(v) => match v.unit {
                 TimeUnit::Second => TimestampSecondsFormatter::default().write(&v.value, f),
                 TimeUnit::Millisecond => {
@@ -391,13 +447,15 @@ impl fmt::Display for ScalarValue<'_> {
             },
             Self::Interval(v) => IntervalFormatter.write(v, f),
             Self::Utf8(v) => write!(f, "{}", v),
-            Self::Binary(v) => write!(f, "{:X?}", v),
+            Self::Time64(v) => Time64Formatter.write(v, f),
+            Self::Binary(v) => BinaryFormatter.write(v.as_ref(), f),
+            Self::Uuid(v) => UuidFormatter.write(v, f),
             Self::Struct(fields) => write!(
                 f,
                 "{{{}}}",
                 fields
                     .iter()
-                    .map(|typ| format!("{typ}"))
+                    .map(|(name, v)| format!("{name}: {v}"))
                     .collect::<Vec<_>>()
                     .join(", ")
             ),
@@ -547,11 +605,17 @@ impl ProtoConv for OwnedScalarValue {
             Self::Date32(v) => Value::ScalarDate32(*v),
             Self::Date64(v) => Value::ScalarDate64(*v),
             Self::Interval(v) => Value::ScalarInterval(v.to_proto()?),
+            Self::Time64(v) => Value::ScalarTime64(*v),
             Self::Utf8(v) => Value::ScalarUtf8(v.clone().into()),
             Self::Binary(v) => Value::ScalarBinary(v.clone().into()),
+            Self::Uuid(v) => Value::ScalarUuid(v.to_le_bytes().to_vec()),
             Self::Struct(v) => {
-                let values = v.iter().map(|v| v.to_proto()).collect::<Result<Vec<_>>>()?;
-                Value::ScalarStruct(StructScalar { values })
+                let keys = v.iter().map(|(k, _)| k.clone()).collect();
+                let values = v
+                    .iter()
+                    .map(|(_, v)| v.to_proto())
+                    .collect::<Result<Vec<_>>>()?;
+                Value::ScalarStruct(StructScalar { values, keys })
             }
             Self::List(v) => {
                 let values = v.iter().map(|v| v.to_proto()).collect::<Result<Vec<_>>>()?;
@@ -592,13 +656,24 @@ impl ProtoConv for OwnedScalarValue {
             Value::ScalarDate32(v) => Self::Date32(v),
             Value::ScalarDate64(v) => Self::Date64(v),
             Value::ScalarInterval(v) => Self::Interval(Interval::from_proto(v)?),
+            Value::ScalarTime64(v) => Self::Time64(v),
             Value::ScalarUtf8(v) => Self::Utf8(v.into()),
             Value::ScalarBinary(v) => Self::Binary(v.into()),
+            Value::ScalarUuid(v) => Self::Uuid(u128::from_le_bytes(
+                v.try_into()
+                    .map_err(|_| RayexecError::new("byte buffer not 16 bytes"))?,
+            )),
             Value::ScalarStruct(v) => {
+                if v.keys.len() != v.values.len() {
+                    return Err(RayexecError::new(
+                        "Struct scalar keys and values have different lengths",
+                    ));
+                }
                 let values = v
-                    .values
+                    .keys
                     .into_iter()
-                    .map(OwnedScalarValue::from_proto)
+                    .zip(v.values)
+                    .map(|(k, v)| Ok((k, OwnedScalarValue::from_proto(v)?)))
                     .collect::<Result<Vec<_>>>()?;
                 Self::Struct(values)
             }
@@ -8,6 +8,8 @@ use crate::arrays::datatype::TimeUnit;
 pub struct TimestampScalar {
     pub unit: TimeUnit,
     pub value: i64,
+    /// Time zone of the timestamp, see `TimestampTypeMeta`.
+    pub tz: Option<String>,
 }
 
 impl ProtoConv for TimestampScalar {
@@ -17,6 +19,7 @@ impl ProtoConv for TimestampScalar {
         Ok(Self::ProtoType {
             unit: self.unit.to_proto()? as i32,
             value: self.value,
+            tz: self.tz.clone().unwrap_or_default(),
         })
     }
 
@@ -24,6 +27,11 @@ impl ProtoConv for TimestampScalar {
         Ok(Self {
             unit: TimeUnit::from_proto(proto.unit())?,
             value: proto.value,
+            tz: if proto.tz.is_empty() {
+                None
+            } else {
+                Some(proto.tz)
+            },
         })
     }
 }
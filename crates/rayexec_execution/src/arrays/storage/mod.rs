@@ -20,6 +20,9 @@ use std::fmt::Debug;
 
 pub use list::*;
 
+mod struct_storage;
+pub use struct_storage::*;
+
 /// In-memory array storage that can be directly indexed into.
 pub trait AddressableStorage: Debug {
     /// The type we can get from the storage.
@@ -0,0 +1,39 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+
+/// Storage for struct arrays.
+///
+/// Each field is stored as its own child array. All children must be the same
+/// length as the struct array itself.
+#[derive(Debug, PartialEq)]
+pub struct StructStorage {
+    pub(crate) children: Vec<Array>,
+    pub(crate) len: usize,
+}
+
+impl StructStorage {
+    pub fn try_new(children: Vec<Array>, len: usize) -> Result<Self> {
+        for child in &children {
+            if child.logical_len() != len {
+                return Err(RayexecError::new("Struct child array has incorrect length")
+                    .with_field("expected", len)
+                    .with_field("got", child.logical_len()));
+            }
+        }
+
+        Ok(StructStorage { children, len })
+    }
+
+    pub fn children(&self) -> &[Array] {
+        &self.children
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
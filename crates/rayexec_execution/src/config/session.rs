@@ -3,6 +3,8 @@ use std::sync::LazyLock;
 
 use rayexec_error::{RayexecError, Result};
 
+use crate::arrays::compute::cast::behavior::OverflowBehavior;
+use crate::arrays::compute::time_zone::normalize_time_zone;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::runtime::{PipelineExecutor, Runtime};
 
@@ -16,6 +18,10 @@ pub struct SessionConfig {
     pub batch_size: u64,
     pub verify_optimized_plan: bool,
     pub enable_function_chaining: bool,
+    pub max_recursive_cte_iterations: u64,
+    pub time_zone: String,
+    pub integer_overflow: OverflowBehavior,
+    pub memory_limit: Option<u64>,
 }
 
 impl SessionConfig {
@@ -32,6 +38,10 @@ impl SessionConfig {
             batch_size: 4096,
             verify_optimized_plan: false,
             enable_function_chaining: true,
+            max_recursive_cte_iterations: 10_000,
+            time_zone: "UTC".to_string(),
+            integer_overflow: OverflowBehavior::Error,
+            memory_limit: None,
         }
     }
 
@@ -106,6 +116,10 @@ static GET_SET_FUNCTIONS: LazyLock<HashMap<&'static str, SettingFunctions>> = La
     insert_setting::<Partitions>(&mut map);
     insert_setting::<BatchSize>(&mut map);
     insert_setting::<EnableFunctionChaining>(&mut map);
+    insert_setting::<MaxRecursiveCteIterations>(&mut map);
+    insert_setting::<TimeZone>(&mut map);
+    insert_setting::<IntegerOverflow>(&mut map);
+    insert_setting::<MemoryLimit>(&mut map);
 
     map
 });
@@ -238,6 +252,157 @@ impl SessionSetting for EnableFunctionChaining {
     }
 }
 
+pub struct MaxRecursiveCteIterations;
+
+impl SessionSetting for MaxRecursiveCteIterations {
+    const NAME: &'static str = "max_recursive_cte_iterations";
+    const DESCRIPTION: &'static str =
+        "Maximum number of iterations a recursive CTE may run before erroring";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_as_i64()?;
+        if val < 0 {
+            return Err(RayexecError::new(format!(
+                "'{}' must not be negative, got {val}",
+                Self::NAME
+            )));
+        }
+        conf.max_recursive_cte_iterations = val as u64;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.max_recursive_cte_iterations.into()
+    }
+}
+
+pub struct TimeZone;
+
+impl SessionSetting for TimeZone {
+    const NAME: &'static str = "timezone";
+    const DESCRIPTION: &'static str = "Time zone used for timestamps with time zone";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_into_string()?;
+        conf.time_zone = normalize_time_zone(&val)?;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.time_zone.clone().into()
+    }
+}
+
+pub struct IntegerOverflow;
+
+impl SessionSetting for IntegerOverflow {
+    const NAME: &'static str = "integer_overflow";
+    const DESCRIPTION: &'static str =
+        "Behavior when integer arithmetic overflows, either 'error' or 'promote'";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = scalar.try_into_string()?;
+        conf.integer_overflow = OverflowBehavior::from_name(&val)?;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        conf.integer_overflow.name().into()
+    }
+}
+
+pub struct MemoryLimit;
+
+impl SessionSetting for MemoryLimit {
+    const NAME: &'static str = "memory_limit";
+    const DESCRIPTION: &'static str =
+        "Maximum memory operators may use before spilling to disk, e.g. '4GB' or 'unlimited'";
+
+    fn set_from_scalar(scalar: ScalarValue, conf: &mut SessionConfig) -> Result<()> {
+        let val = match scalar {
+            ScalarValue::Utf8(s) => parse_memory_size(&s)?,
+            other => Some(other.try_as_i64().and_then(|v| {
+                u64::try_from(v).map_err(|_| {
+                    RayexecError::new(format!("'{}' must not be negative, got {v}", Self::NAME))
+                })
+            })?),
+        };
+        conf.memory_limit = val;
+        Ok(())
+    }
+
+    fn get_as_scalar(conf: &SessionConfig) -> OwnedScalarValue {
+        match conf.memory_limit {
+            Some(limit) => format_memory_size(limit).into(),
+            None => "unlimited".into(),
+        }
+    }
+}
+
+/// Units accepted when parsing memory sizes, largest first.
+const MEMORY_UNITS: &[(&str, u64)] = &[
+    ("TiB", 1 << 40),
+    ("GiB", 1 << 30),
+    ("MiB", 1 << 20),
+    ("KiB", 1 << 10),
+    ("TB", 1_000_000_000_000),
+    ("GB", 1_000_000_000),
+    ("MB", 1_000_000),
+    ("KB", 1_000),
+    ("B", 1),
+];
+
+/// Parse a human readable memory size like '512MB' or '2 GiB'.
+///
+/// Returns `None` for 'unlimited' or 'none'.
+fn parse_memory_size(s: &str) -> Result<Option<u64>> {
+    let s = s.trim();
+    if s.eq_ignore_ascii_case("unlimited") || s.eq_ignore_ascii_case("none") {
+        return Ok(None);
+    }
+
+    let split = s
+        .find(|c: char| !(c.is_ascii_digit() || c == '.'))
+        .unwrap_or(s.len());
+    let (num, unit) = s.split_at(split);
+    let unit = unit.trim();
+
+    let invalid = || {
+        RayexecError::new(format!(
+            "Invalid memory size '{s}', expected a number followed by a unit like 'MB' or 'GiB'"
+        ))
+    };
+
+    let multiplier = if unit.is_empty() {
+        1
+    } else {
+        MEMORY_UNITS
+            .iter()
+            .find(|(name, _)| name.eq_ignore_ascii_case(unit))
+            .map(|(_, multiplier)| *multiplier)
+            .ok_or_else(invalid)?
+    };
+
+    let num: f64 = num.parse().map_err(|_| invalid())?;
+    let bytes = num * multiplier as f64;
+    if !bytes.is_finite() || bytes > u64::MAX as f64 {
+        return Err(invalid());
+    }
+
+    Ok(Some(bytes as u64))
+}
+
+/// Format a memory size using the unit that results in the smallest whole
+/// number.
+fn format_memory_size(bytes: u64) -> String {
+    MEMORY_UNITS
+        .iter()
+        .filter(|(_, multiplier)| bytes != 0 && bytes.is_multiple_of(*multiplier))
+        .min_by_key(|(_, multiplier)| bytes / multiplier)
+        .map(|(name, multiplier)| format!("{}{name}", bytes / multiplier))
+        .unwrap_or_else(|| format!("{bytes}B"))
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -251,6 +416,10 @@ mod tests {
             batch_size: 4096,
             verify_optimized_plan: false,
             enable_function_chaining: true,
+            max_recursive_cte_iterations: 10_000,
+            time_zone: "UTC".to_string(),
+            integer_overflow: OverflowBehavior::Error,
+            memory_limit: None,
         }
     }
 
@@ -279,4 +448,50 @@ mod tests {
         let val = conf.get_as_scalar("partitions").unwrap();
         assert_eq!(ScalarValue::UInt64(13), val);
     }
+
+    #[test]
+    fn set_time_zone_normalizes() {
+        let mut conf = new_test_config();
+        conf.set_from_scalar("timezone", "+0530".into()).unwrap();
+
+        let val = conf.get_as_scalar("timezone").unwrap();
+        assert_eq!("+05:30", val.try_as_str().unwrap());
+
+        conf.set_from_scalar("timezone", "America/New_York".into())
+            .unwrap();
+        let val = conf.get_as_scalar("timezone").unwrap();
+        assert_eq!("America/New_York", val.try_as_str().unwrap());
+
+        conf.set_from_scalar("timezone", "America/Nowhere".into())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn set_memory_limit() {
+        let mut conf = new_test_config();
+
+        conf.set_from_scalar("memory_limit", "512MB".into())
+            .unwrap();
+        assert_eq!(Some(512_000_000), conf.memory_limit);
+        let val = conf.get_as_scalar("memory_limit").unwrap();
+        assert_eq!("512MB", val.try_as_str().unwrap());
+
+        conf.set_from_scalar("memory_limit", "1.5 GiB".into())
+            .unwrap();
+        assert_eq!(Some(3 << 29), conf.memory_limit);
+
+        conf.set_from_scalar("memory_limit", ScalarValue::Int32(4096))
+            .unwrap();
+        let val = conf.get_as_scalar("memory_limit").unwrap();
+        assert_eq!("4KiB", val.try_as_str().unwrap());
+
+        conf.set_from_scalar("memory_limit", "unlimited".into())
+            .unwrap();
+        assert_eq!(None, conf.memory_limit);
+
+        conf.set_from_scalar("memory_limit", "12 parsecs".into())
+            .unwrap_err();
+        conf.set_from_scalar("memory_limit", ScalarValue::Int32(-1))
+            .unwrap_err();
+    }
 }
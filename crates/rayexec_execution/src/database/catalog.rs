@@ -1,10 +1,220 @@
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
 
-#[derive(Debug, Default)]
-pub struct CatalogTx {}
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+
+/// Timestamp of the most recent commit.
+///
+/// Timestamps only need to be monotonic, so a single clock is shared by all
+/// catalogs and table storage in the process.
+static COMMIT_CLOCK: AtomicU64 = AtomicU64::new(0);
+
+/// Source of ids for transactions that track their changes.
+static NEXT_TX_ID: AtomicU64 = AtomicU64::new(1);
+
+/// Held while committing changes, and while taking a snapshot for a new
+/// transaction.
+///
+/// This ensures a transaction never sees a partially committed transaction.
+static COMMIT_LOCK: Mutex<()> = parking_lot::const_mutex(());
+
+/// Version stamp for a change to a catalog entry or table data.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Version {
+    /// Change was committed at this timestamp.
+    Committed(u64),
+    /// Change was made by the transaction with this id, and that transaction
+    /// hasn't yet committed.
+    Uncommitted(u64),
+}
+
+/// A change made as part of a transaction that tracks its changes.
+///
+/// All changes get stamped with the commit timestamp on commit, or undone on
+/// rollback.
+pub trait TransactionChange: Debug + Sync + Send {
+    fn commit(&self, tx_id: u64, commit_ts: u64);
+    fn rollback(&self, tx_id: u64);
+}
+
+/// Transaction used when reading or modifying the catalog and table storage.
+///
+/// The default transaction is an autocommit transaction, where changes are
+/// committed as soon as they're made and all committed changes are visible.
+///
+/// An explicit transaction (from `begin`) reads from the snapshot taken when
+/// it began, and its changes are only visible to itself until commit.
+///
+/// A statement transaction (from `begin_statement`) behaves the same, but
+/// covers a single statement. Sessions run each statement outside of an
+/// explicit transaction in one so that all changes made by the statement are
+/// committed with a single timestamp once the statement finishes.
+#[derive(Debug, Clone, Default)]
+pub struct CatalogTx {
+    tracked: Option<Arc<TrackedTx>>,
+}
+
+/// A transaction whose changes are committed or rolled back together.
+#[derive(Debug)]
+struct TrackedTx {
+    id: u64,
+    /// Changes committed at or before this timestamp are visible.
+    snapshot: u64,
+    /// If this transaction was started with `begin`.
+    explicit: bool,
+    state: Mutex<TrackedTxState>,
+}
+
+#[derive(Debug)]
+struct TrackedTxState {
+    /// If this transaction has not yet committed or rolled back.
+    active: bool,
+    changes: Vec<Arc<dyn TransactionChange>>,
+}
 
 impl CatalogTx {
+    /// Create an autocommit transaction.
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Begin a new explicit transaction.
+    pub fn begin() -> Self {
+        Self::new_tracked(true)
+    }
+
+    /// Begin a new transaction for a single statement.
+    pub fn begin_statement() -> Self {
+        Self::new_tracked(false)
+    }
+
+    fn new_tracked(explicit: bool) -> Self {
+        let id = NEXT_TX_ID.fetch_add(1, Ordering::Relaxed);
+        let snapshot = {
+            let _guard = COMMIT_LOCK.lock();
+            COMMIT_CLOCK.load(Ordering::Acquire)
+        };
+
+        CatalogTx {
+            tracked: Some(Arc::new(TrackedTx {
+                id,
+                snapshot,
+                explicit,
+                state: Mutex::new(TrackedTxState {
+                    active: true,
+                    changes: Vec::new(),
+                }),
+            })),
+        }
+    }
+
+    pub fn is_explicit(&self) -> bool {
+        self.tracked.as_ref().is_some_and(|tx| tx.explicit)
+    }
+
+    /// Check if a change with the given version is visible to this
+    /// transaction.
+    pub fn is_visible(&self, version: Version) -> bool {
+        match (&self.tracked, version) {
+            (None, Version::Committed(_)) => true,
+            (None, Version::Uncommitted(_)) => false,
+            (Some(tx), Version::Committed(ts)) => ts <= tx.snapshot,
+            (Some(tx), Version::Uncommitted(id)) => tx.id == id,
+        }
+    }
+
+    /// Check if this transaction can make a change to an item whose latest
+    /// change has the given version.
+    ///
+    /// Changes that aren't visible to this transaction were made concurrently,
+    /// and modifying the item would be a write-write conflict.
+    pub(crate) fn check_write_conflict(&self, version: Version) -> Result<()> {
+        if self.is_visible(version) {
+            return Ok(());
+        }
+        Err(RayexecError::new(
+            "Write conflict, item was modified by a concurrent transaction",
+        ))
+    }
+
+    /// Get the version to stamp a new change with.
+    ///
+    /// For statement and explicit transactions, the change must be registered
+    /// with `register_change` so that it's committed or rolled back along with
+    /// the transaction.
+    pub(crate) fn write_version(&self) -> Result<Version> {
+        match &self.tracked {
+            Some(tx) => {
+                if !tx.state.lock().active {
+                    return Err(RayexecError::new("Transaction is no longer active"));
+                }
+                Ok(Version::Uncommitted(tx.id))
+            }
+            None => {
+                let _guard = COMMIT_LOCK.lock();
+                Ok(Version::Committed(
+                    COMMIT_CLOCK.fetch_add(1, Ordering::AcqRel) + 1,
+                ))
+            }
+        }
+    }
+
+    /// Register a change made by this transaction.
+    ///
+    /// Does nothing for autocommit transactions.
+    pub(crate) fn register_change(&self, change: Arc<dyn TransactionChange>) {
+        if let Some(tx) = &self.tracked {
+            tx.state.lock().changes.push(change);
+        }
+    }
+
+    /// Commit a statement or explicit transaction, stamping all of its changes
+    /// with a single commit timestamp.
+    pub fn commit(&self) -> Result<()> {
+        let tx = self.tracked_tx()?;
+        let changes = tx.finish()?;
+        if changes.is_empty() {
+            return Ok(());
+        }
+
+        let _guard = COMMIT_LOCK.lock();
+        let commit_ts = COMMIT_CLOCK.load(Ordering::Acquire) + 1;
+        for change in changes {
+            change.commit(tx.id, commit_ts);
+        }
+        COMMIT_CLOCK.fetch_max(commit_ts, Ordering::AcqRel);
+
+        Ok(())
+    }
+
+    /// Rollback a statement or explicit transaction, undoing all of its
+    /// changes.
+    pub fn rollback(&self) -> Result<()> {
+        let tx = self.tracked_tx()?;
+        for change in tx.finish()? {
+            change.rollback(tx.id);
+        }
+        Ok(())
+    }
+
+    fn tracked_tx(&self) -> Result<&TrackedTx> {
+        self.tracked.as_deref().ok_or_else(|| {
+            RayexecError::new("Autocommit transactions cannot be committed or rolled back")
+        })
+    }
+}
+
+impl TrackedTx {
+    /// Mark the transaction as no longer active, returning all changes made
+    /// by the transaction.
+    fn finish(&self) -> Result<Vec<Arc<dyn TransactionChange>>> {
+        let mut state = self.state.lock();
+        if !state.active {
+            return Err(RayexecError::new("Transaction is no longer active"));
+        }
+        state.active = false;
+        Ok(std::mem::take(&mut state.changes))
+    }
 }
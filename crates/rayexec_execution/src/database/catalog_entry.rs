@@ -10,6 +10,7 @@ use crate::functions::aggregate::AggregateFunction;
 use crate::functions::copy::CopyToFunction;
 use crate::functions::scalar::ScalarFunction;
 use crate::functions::table::TableFunction;
+use crate::functions::window::WindowFunction;
 use crate::proto::DatabaseProtoConv;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -19,6 +20,7 @@ pub enum CatalogEntryType {
     View,
     ScalarFunction,
     AggregateFunction,
+    WindowFunction,
     TableFunction,
     CopyToFunction,
 }
@@ -31,6 +33,7 @@ impl fmt::Display for CatalogEntryType {
             Self::View => write!(f, "view"),
             Self::ScalarFunction => write!(f, "scalar function"),
             Self::AggregateFunction => write!(f, "aggregate function"),
+            Self::WindowFunction => write!(f, "window function"),
             Self::TableFunction => write!(f, "table function"),
             Self::CopyToFunction => write!(f, "copy to function"),
         }
@@ -47,6 +50,7 @@ impl ProtoConv for CatalogEntryType {
             Self::View => unimplemented!(),
             Self::ScalarFunction => Self::ProtoType::ScalarFunction,
             Self::AggregateFunction => Self::ProtoType::AggregateFunction,
+            Self::WindowFunction => Self::ProtoType::WindowFunction,
             Self::TableFunction => Self::ProtoType::TableFunction,
             Self::CopyToFunction => Self::ProtoType::CopyToFunction,
         })
@@ -59,6 +63,7 @@ impl ProtoConv for CatalogEntryType {
             Self::ProtoType::Schema => Self::Schema,
             Self::ProtoType::ScalarFunction => Self::ScalarFunction,
             Self::ProtoType::AggregateFunction => Self::AggregateFunction,
+            Self::ProtoType::WindowFunction => Self::WindowFunction,
             Self::ProtoType::TableFunction => Self::TableFunction,
             Self::ProtoType::CopyToFunction => Self::CopyToFunction,
         })
@@ -113,6 +118,7 @@ pub enum CatalogEntryInner {
     View(ViewEntry),
     ScalarFunction(ScalarFunctionEntry),
     AggregateFunction(AggregateFunctionEntry),
+    WindowFunction(WindowFunctionEntry),
     TableFunction(TableFunctionEntry),
     CopyToFunction(CopyToFunctionEntry),
 }
@@ -129,6 +135,7 @@ impl DatabaseProtoConv for CatalogEntryInner {
             Self::View(_ent) => unimplemented!(),
             Self::ScalarFunction(ent) => Value::ScalarFunction(ent.to_proto_ctx(context)?),
             Self::AggregateFunction(ent) => Value::AggregateFunction(ent.to_proto_ctx(context)?),
+            Self::WindowFunction(ent) => Value::WindowFunction(ent.to_proto_ctx(context)?),
             Self::TableFunction(ent) => Value::TableFunction(ent.to_proto_ctx(context)?),
             Self::CopyToFunction(ent) => Value::CopyToFunction(ent.to_proto_ctx(context)?),
         };
@@ -148,6 +155,9 @@ impl DatabaseProtoConv for CatalogEntryInner {
             Value::AggregateFunction(ent) => {
                 Self::AggregateFunction(DatabaseProtoConv::from_proto_ctx(ent, context)?)
             }
+            Value::WindowFunction(ent) => {
+                Self::WindowFunction(DatabaseProtoConv::from_proto_ctx(ent, context)?)
+            }
             Value::TableFunction(ent) => {
                 Self::TableFunction(DatabaseProtoConv::from_proto_ctx(ent, context)?)
             }
@@ -206,6 +216,30 @@ impl DatabaseProtoConv for AggregateFunctionEntry {
     }
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct WindowFunctionEntry {
+    pub function: Box<dyn WindowFunction>,
+}
+
+impl DatabaseProtoConv for WindowFunctionEntry {
+    type ProtoType = rayexec_proto::generated::catalog::WindowFunctionEntry;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            function: Some(self.function.to_proto_ctx(context)?),
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            function: DatabaseProtoConv::from_proto_ctx(
+                proto.function.required("function")?,
+                context,
+            )?,
+        })
+    }
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct TableFunctionEntry {
     pub function: Box<dyn TableFunction>,
@@ -333,6 +367,7 @@ impl CatalogEntry {
             CatalogEntryInner::View(_) => CatalogEntryType::View,
             CatalogEntryInner::ScalarFunction(_) => CatalogEntryType::ScalarFunction,
             CatalogEntryInner::AggregateFunction(_) => CatalogEntryType::AggregateFunction,
+            CatalogEntryInner::WindowFunction(_) => CatalogEntryType::WindowFunction,
             CatalogEntryInner::TableFunction(_) => CatalogEntryType::TableFunction,
             CatalogEntryInner::CopyToFunction(_) => CatalogEntryType::CopyToFunction,
         }
@@ -366,6 +401,13 @@ impl CatalogEntry {
         }
     }
 
+    pub fn try_as_window_function_entry(&self) -> Result<&WindowFunctionEntry> {
+        match &self.entry {
+            CatalogEntryInner::WindowFunction(ent) => Ok(ent),
+            _ => Err(RayexecError::new("Entry not a window function")),
+        }
+    }
+
     pub fn try_as_table_function_entry(&self) -> Result<&TableFunctionEntry> {
         match &self.entry {
             CatalogEntryInner::TableFunction(ent) => Ok(ent),
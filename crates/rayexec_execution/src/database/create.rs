@@ -7,6 +7,7 @@ use crate::functions::aggregate::AggregateFunction;
 use crate::functions::copy::CopyToFunction;
 use crate::functions::scalar::ScalarFunction;
 use crate::functions::table::TableFunction;
+use crate::functions::window::WindowFunction;
 
 /// Behavior on create conflict.
 #[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
@@ -132,6 +133,13 @@ pub struct CreateAggregateFunctionInfo {
     pub on_conflict: OnConflict,
 }
 
+#[derive(Debug, PartialEq, Eq)]
+pub struct CreateWindowFunctionInfo {
+    pub name: String,
+    pub implementation: Box<dyn WindowFunction>,
+    pub on_conflict: OnConflict,
+}
+
 #[derive(Debug, PartialEq, Eq)]
 pub struct CreateTableFunctionInfo {
     pub name: String,
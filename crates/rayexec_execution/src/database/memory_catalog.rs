@@ -1,7 +1,6 @@
 use std::sync::Arc;
 
 use rayexec_error::{RayexecError, Result};
-use scc::ebr::Guard;
 
 use super::catalog::CatalogTx;
 use super::catalog_entry::{
@@ -15,6 +14,7 @@ use super::catalog_entry::{
     TableEntry,
     TableFunctionEntry,
     ViewEntry,
+    WindowFunctionEntry,
 };
 use super::catalog_map::CatalogMap;
 use super::create::{
@@ -25,8 +25,10 @@ use super::create::{
     CreateTableFunctionInfo,
     CreateTableInfo,
     CreateViewInfo,
+    CreateWindowFunctionInfo,
 };
 use super::drop::{DropInfo, DropObject};
+use super::mvcc::VersionedMap;
 use crate::database::create::OnConflict;
 
 // Using `scc` package for concurrent datastructures.
@@ -45,7 +47,8 @@ use crate::database::create::OnConflict;
 //
 // However these methods require a Guard for EBR, but these don't actually
 // matter for us. Any synchronization for data removal that's required for our
-// use case will go through more typical transaction semantics with timestamps.
+// use case goes through the versioning in `mvcc`, where each entry holds all
+// of its versions stamped with transaction timestamps.
 //
 // I (Sean) opted for `scc` over DashMap primarily for the lock-free read-only
 // properties. DashMap has a fixed number of shards, and any read will acquire a
@@ -63,18 +66,17 @@ use crate::database::create::OnConflict;
 
 #[derive(Debug, Default)]
 pub struct MemoryCatalog {
-    schemas: scc::HashIndex<String, Arc<MemorySchema>>,
+    schemas: VersionedMap<String, Arc<MemorySchema>>,
 }
 
 impl MemoryCatalog {
-    pub fn get_schema(&self, _tx: &CatalogTx, name: &str) -> Result<Option<Arc<MemorySchema>>> {
-        let guard = Guard::new();
-        Ok(self.schemas.peek(name, &guard).cloned())
+    pub fn get_schema(&self, tx: &CatalogTx, name: &str) -> Result<Option<Arc<MemorySchema>>> {
+        Ok(self.schemas.get(tx, name))
     }
 
     pub fn create_schema(
         &self,
-        _tx: &CatalogTx,
+        tx: &CatalogTx,
         create: &CreateSchemaInfo,
     ) -> Result<Arc<MemorySchema>> {
         let schema = Arc::new(MemorySchema {
@@ -90,23 +92,23 @@ impl MemoryCatalog {
             copy_to_functions: CatalogMap::default(),
         });
 
-        use scc::hash_index::Entry;
-
-        match (self.schemas.entry(create.name.clone()), create.on_conflict) {
-            (Entry::Vacant(ent), _) => {
-                ent.insert_entry(schema.clone());
+        match (self.schemas.get(tx, &create.name), create.on_conflict) {
+            (None, _) => {
+                self.schemas
+                    .insert(tx, create.name.clone(), schema.clone())?;
                 Ok(schema)
             }
-            (Entry::Occupied(ent), OnConflict::Ignore) => {
+            (Some(existing), OnConflict::Ignore) => {
                 // Return existing entry.
-                Ok(ent.get().clone())
+                Ok(existing)
             }
-            (Entry::Occupied(ent), OnConflict::Replace) => {
-                // TODO: Drop then replace.
-                ent.update(schema.clone());
+            (Some(_), OnConflict::Replace) => {
+                self.schemas.remove(tx, &create.name)?;
+                self.schemas
+                    .insert(tx, create.name.clone(), schema.clone())?;
                 Ok(schema)
             }
-            (Entry::Occupied(_), OnConflict::Error) => Err(RayexecError::new(format!(
+            (Some(_), OnConflict::Error) => Err(RayexecError::new(format!(
                 "Duplicate schema name: '{}'",
                 create.name,
             ))),
@@ -119,8 +121,7 @@ impl MemoryCatalog {
                 return Err(RayexecError::new("CASCADE not yet supported"));
             }
 
-            // TODO: Schemas should be implemented as a CatalogMap.
-            if !self.schemas.remove(&drop.schema) && !drop.if_exists {
+            if !self.schemas.remove(tx, &drop.schema)? && !drop.if_exists {
                 return Err(RayexecError::new(format!(
                     "Missing schema: {}",
                     drop.schema
@@ -132,7 +133,7 @@ impl MemoryCatalog {
 
         let schema = self
             .schemas
-            .get(&drop.schema)
+            .get(tx, &drop.schema)
             .ok_or_else(|| RayexecError::new(format!("Missing schema: {}", drop.schema)))?;
 
         schema.drop_entry(tx, drop)?;
@@ -140,15 +141,11 @@ impl MemoryCatalog {
         Ok(())
     }
 
-    pub fn for_each_schema<F>(&self, _tx: &CatalogTx, func: &mut F) -> Result<()>
+    pub fn for_each_schema<F>(&self, tx: &CatalogTx, func: &mut F) -> Result<()>
     where
         F: FnMut(&String, &Arc<MemorySchema>) -> Result<()>,
     {
-        let guard = Guard::new();
-        for (name, schema) in self.schemas.iter(&guard) {
-            func(name, schema)?;
-        }
-        Ok(())
+        self.schemas.for_each(tx, func)
     }
 }
 
@@ -160,7 +157,7 @@ pub struct MemorySchema {
     tables: CatalogMap,
     /// All table functions in the schema.
     table_functions: CatalogMap,
-    /// All scalar and aggregate functions in the schema.
+    /// All scalar, aggregate, and window functions in the schema.
     functions: CatalogMap,
     /// All functions implementing COPY TO for a fomat in the schema.
     copy_to_functions: CatalogMap,
@@ -240,6 +237,23 @@ impl MemorySchema {
         Self::create_entry(tx, &self.functions, ent, create.on_conflict)
     }
 
+    pub fn create_window_function(
+        &self,
+        tx: &CatalogTx,
+        create: &CreateWindowFunctionInfo,
+    ) -> Result<Arc<CatalogEntry>> {
+        let ent = CatalogEntry {
+            oid: 0,
+            name: create.name.clone(),
+            entry: CatalogEntryInner::WindowFunction(WindowFunctionEntry {
+                function: create.implementation.clone(),
+            }),
+            child: None,
+        };
+
+        Self::create_entry(tx, &self.functions, ent, create.on_conflict)
+    }
+
     pub fn create_table_function(
         &self,
         tx: &CatalogTx,
@@ -361,6 +375,20 @@ impl MemorySchema {
         Ok(ent)
     }
 
+    pub fn get_window_function(
+        &self,
+        tx: &CatalogTx,
+        name: &str,
+    ) -> Result<Option<Arc<CatalogEntry>>> {
+        let ent = self.functions.get_entry(tx, name)?;
+        let ent = ent.and_then(|ent| match &ent.entry {
+            CatalogEntryInner::WindowFunction(_) => Some(ent),
+            _ => None,
+        });
+
+        Ok(ent)
+    }
+
     pub fn get_copy_to_function(
         &self,
         tx: &CatalogTx,
@@ -470,6 +498,12 @@ impl MemorySchema {
                         Ok(())
                     })?
                 }
+                CatalogEntryType::WindowFunction => {
+                    self.functions.for_each_entry(tx, &mut |_, ent| {
+                        SimilarEntry::maybe_update(&mut similar, ent, name);
+                        Ok(())
+                    })?
+                }
                 CatalogEntryType::TableFunction => {
                     self.table_functions.for_each_entry(tx, &mut |_, ent| {
                         SimilarEntry::maybe_update(&mut similar, ent, name);
@@ -528,7 +562,7 @@ mod tests {
         let catalog = MemoryCatalog::default();
         let _schema = catalog
             .create_schema(
-                &CatalogTx {},
+                &CatalogTx::new(),
                 &CreateSchemaInfo {
                     name: "test".to_string(),
                     on_conflict: OnConflict::Error,
@@ -542,11 +576,14 @@ mod tests {
     #[test]
     fn similarity_function_name() {
         let catalog = create_test_catalog();
-        let schema = catalog.get_schema(&CatalogTx {}, "test").unwrap().unwrap();
+        let schema = catalog
+            .get_schema(&CatalogTx::new(), "test")
+            .unwrap()
+            .unwrap();
 
         schema
             .create_aggregate_function(
-                &CatalogTx {},
+                &CatalogTx::new(),
                 &CreateAggregateFunctionInfo {
                     name: "sum".to_string(),
                     implementation: Box::new(Sum),
@@ -557,7 +594,7 @@ mod tests {
 
         let similar = schema
             .find_similar_entry(
-                &CatalogTx {},
+                &CatalogTx::new(),
                 &[CatalogEntryType::AggregateFunction],
                 "summ",
             )
@@ -566,13 +603,21 @@ mod tests {
         assert_eq!("sum", similar.entry.name);
 
         let similar = schema
-            .find_similar_entry(&CatalogTx {}, &[CatalogEntryType::AggregateFunction], "sim")
+            .find_similar_entry(
+                &CatalogTx::new(),
+                &[CatalogEntryType::AggregateFunction],
+                "sim",
+            )
             .unwrap()
             .unwrap();
         assert_eq!("sum", similar.entry.name);
 
         let similar = schema
-            .find_similar_entry(&CatalogTx {}, &[CatalogEntryType::AggregateFunction], "ham")
+            .find_similar_entry(
+                &CatalogTx::new(),
+                &[CatalogEntryType::AggregateFunction],
+                "ham",
+            )
             .unwrap();
         assert_eq!(None, similar);
     }
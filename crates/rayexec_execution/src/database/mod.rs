@@ -4,6 +4,7 @@ pub mod catalog_entry;
 pub mod create;
 pub mod drop;
 pub mod memory_catalog;
+pub mod mvcc;
 pub mod system;
 
 mod catalog_map;
@@ -18,7 +19,9 @@ use memory_catalog::MemoryCatalog;
 use rayexec_error::{RayexecError, Result};
 use rayexec_proto::ProtoConv;
 
+use crate::arrays::array::buffer_manager::LimitedBufferManager;
 use crate::arrays::scalar::OwnedScalarValue;
+use crate::runtime::spill::SpillProvider;
 use crate::storage::catalog_storage::CatalogStorage;
 use crate::storage::memory::MemoryTableStorage;
 use crate::storage::table_storage::TableStorage;
@@ -81,9 +84,17 @@ pub struct Database {
 ///
 /// Attaching external databases falls outside the normal catalog flow, and so
 /// will not follow the same transactional semantics.
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct DatabaseContext {
     databases: HashMap<String, Database>,
+    /// Transaction to use when accessing catalogs and tables.
+    tx: CatalogTx,
+    /// Buffer manager tracking memory reserved by operators.
+    ///
+    /// Shared by all queries running using this context.
+    buffer_manager: Arc<LimitedBufferManager>,
+    /// Where operators spill data to when exceeding the memory limit.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
 }
 
 impl DatabaseContext {
@@ -108,7 +119,7 @@ impl DatabaseContext {
 
         let temp = MemoryCatalog::default();
         temp.create_schema(
-            &CatalogTx {},
+            &CatalogTx::new(),
             &CreateSchemaInfo {
                 name: "temp".to_string(),
                 on_conflict: OnConflict::Error,
@@ -125,7 +136,26 @@ impl DatabaseContext {
             },
         );
 
-        Ok(DatabaseContext { databases })
+        Ok(DatabaseContext {
+            databases,
+            tx: CatalogTx::new(),
+            buffer_manager: Arc::new(LimitedBufferManager::new(None)),
+            spill_provider: None,
+        })
+    }
+
+    /// Get the buffer manager operators should reserve memory through.
+    pub fn buffer_manager(&self) -> &Arc<LimitedBufferManager> {
+        &self.buffer_manager
+    }
+
+    /// Get the provider operators should spill to, if spilling is supported.
+    pub fn spill_provider(&self) -> Option<&Arc<dyn SpillProvider>> {
+        self.spill_provider.as_ref()
+    }
+
+    pub fn set_spill_provider(&mut self, provider: Option<Arc<dyn SpillProvider>>) {
+        self.spill_provider = provider;
     }
 
     pub fn system_catalog(&self) -> Result<&MemoryCatalog> {
@@ -135,6 +165,16 @@ impl DatabaseContext {
             .ok_or_else(|| RayexecError::new("Missing system catalog"))
     }
 
+    /// Get the transaction that should be used for all catalog and table
+    /// access using this context.
+    pub fn catalog_tx(&self) -> &CatalogTx {
+        &self.tx
+    }
+
+    pub fn set_catalog_tx(&mut self, tx: CatalogTx) {
+        self.tx = tx;
+    }
+
     pub fn attach_database(&mut self, name: impl Into<String>, database: Database) -> Result<()> {
         let name = name.into();
         if self.databases.contains_key(&name) {
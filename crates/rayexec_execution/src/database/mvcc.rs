@@ -0,0 +1,352 @@
+use std::hash::Hash;
+use std::sync::Arc;
+
+use parking_lot::RwLock;
+use rayexec_error::Result;
+use scc::ebr::Guard;
+use scc::Equivalent;
+
+use super::catalog::{CatalogTx, TransactionChange, Version};
+
+/// A single version of an item.
+#[derive(Debug)]
+struct ItemVersion<T> {
+    item: T,
+    created: Version,
+    deleted: Option<Version>,
+}
+
+impl<T> ItemVersion<T> {
+    fn is_visible(&self, tx: &CatalogTx) -> bool {
+        tx.is_visible(self.created) && !self.deleted.is_some_and(|v| tx.is_visible(v))
+    }
+
+    /// Version of the most recent change to this version.
+    fn latest_change(&self) -> Version {
+        self.deleted.unwrap_or(self.created)
+    }
+}
+
+/// All versions of a single item, oldest first.
+///
+/// A transaction sees at most one version of the item.
+// TODO: Vacuum versions that are no longer visible to any transaction.
+#[derive(Debug)]
+pub struct VersionChain<T> {
+    versions: RwLock<Vec<ItemVersion<T>>>,
+}
+
+impl<T> VersionChain<T>
+where
+    T: Clone + std::fmt::Debug + Sync + Send + 'static,
+{
+    /// Create a new chain containing a single item created by the given
+    /// transaction.
+    pub fn new_with_item(tx: &CatalogTx, item: T) -> Result<Arc<Self>> {
+        let chain = Arc::new(VersionChain {
+            versions: RwLock::new(vec![ItemVersion {
+                item,
+                created: tx.write_version()?,
+                deleted: None,
+            }]),
+        });
+        tx.register_change(chain.clone());
+        Ok(chain)
+    }
+
+    fn empty() -> Arc<Self> {
+        Arc::new(VersionChain {
+            versions: RwLock::new(Vec::new()),
+        })
+    }
+
+    /// Get the version of the item visible to the transaction.
+    pub fn get(&self, tx: &CatalogTx) -> Option<T> {
+        self.versions
+            .read()
+            .iter()
+            .rev()
+            .find(|v| v.is_visible(tx))
+            .map(|v| v.item.clone())
+    }
+
+    /// Insert a new version of the item.
+    ///
+    /// Returns false without inserting if a version of the item is already
+    /// visible to the transaction.
+    pub fn insert(self: &Arc<Self>, tx: &CatalogTx, item: T) -> Result<bool> {
+        let mut versions = self.versions.write();
+        if let Some(latest) = versions.last() {
+            tx.check_write_conflict(latest.latest_change())?;
+            if latest.is_visible(tx) {
+                return Ok(false);
+            }
+        }
+
+        versions.push(ItemVersion {
+            item,
+            created: tx.write_version()?,
+            deleted: None,
+        });
+        tx.register_change(self.clone());
+
+        Ok(true)
+    }
+
+    /// Delete the version of the item visible to the transaction.
+    ///
+    /// Returns false if no version is visible.
+    pub fn delete(self: &Arc<Self>, tx: &CatalogTx) -> Result<bool> {
+        let mut versions = self.versions.write();
+        let latest = match versions.last_mut() {
+            Some(latest) => latest,
+            None => return Ok(false),
+        };
+
+        tx.check_write_conflict(latest.latest_change())?;
+        if !latest.is_visible(tx) {
+            return Ok(false);
+        }
+
+        latest.deleted = Some(tx.write_version()?);
+        tx.register_change(self.clone());
+
+        Ok(true)
+    }
+
+    /// Replace the version of the item visible to the transaction with a new
+    /// version.
+    pub fn replace(self: &Arc<Self>, tx: &CatalogTx, item: T) -> Result<()> {
+        self.delete(tx)?;
+        self.insert(tx, item)?;
+        Ok(())
+    }
+}
+
+impl<T> TransactionChange for VersionChain<T>
+where
+    T: std::fmt::Debug + Sync + Send,
+{
+    fn commit(&self, tx_id: u64, commit_ts: u64) {
+        let uncommitted = Version::Uncommitted(tx_id);
+        let committed = Version::Committed(commit_ts);
+
+        for version in self.versions.write().iter_mut() {
+            if version.created == uncommitted {
+                version.created = committed;
+            }
+            if version.deleted == Some(uncommitted) {
+                version.deleted = Some(committed);
+            }
+        }
+    }
+
+    fn rollback(&self, tx_id: u64) {
+        let uncommitted = Version::Uncommitted(tx_id);
+
+        let mut versions = self.versions.write();
+        versions.retain(|version| version.created != uncommitted);
+        for version in versions.iter_mut() {
+            if version.deleted == Some(uncommitted) {
+                version.deleted = None;
+            }
+        }
+    }
+}
+
+/// Maps keys to versioned items.
+#[derive(Debug)]
+pub struct VersionedMap<K, T>
+where
+    K: Eq + Hash + Clone + 'static,
+    T: 'static,
+{
+    entries: scc::HashIndex<K, Arc<VersionChain<T>>>,
+}
+
+impl<K, T> Default for VersionedMap<K, T>
+where
+    K: Eq + Hash + Clone + 'static,
+    T: 'static,
+{
+    fn default() -> Self {
+        VersionedMap {
+            entries: scc::HashIndex::default(),
+        }
+    }
+}
+
+impl<K, T> VersionedMap<K, T>
+where
+    K: Eq + Hash + Clone + Sync + Send + 'static,
+    T: Clone + std::fmt::Debug + Sync + Send + 'static,
+{
+    /// Get the item for a key visible to the transaction.
+    pub fn get<Q>(&self, tx: &CatalogTx, key: &Q) -> Option<T>
+    where
+        Q: Equivalent<K> + Hash + ?Sized,
+    {
+        let guard = Guard::new();
+        self.entries
+            .peek(key, &guard)
+            .and_then(|chain| chain.get(tx))
+    }
+
+    /// Insert an item for a key.
+    ///
+    /// Returns false without inserting if an item for the key is already
+    /// visible to the transaction.
+    pub fn insert(&self, tx: &CatalogTx, key: K, item: T) -> Result<bool> {
+        let chain = match self.entries.entry(key) {
+            scc::hash_index::Entry::Occupied(ent) => ent.get().clone(),
+            scc::hash_index::Entry::Vacant(ent) => {
+                let chain = VersionChain::empty();
+                ent.insert_entry(chain.clone());
+                chain
+            }
+        };
+        chain.insert(tx, item)
+    }
+
+    /// Remove the item for a key.
+    ///
+    /// Returns false if no item for the key is visible to the transaction.
+    pub fn remove<Q>(&self, tx: &CatalogTx, key: &Q) -> Result<bool>
+    where
+        Q: Equivalent<K> + Hash + ?Sized,
+    {
+        let chain = {
+            let guard = Guard::new();
+            self.entries.peek(key, &guard).cloned()
+        };
+        match chain {
+            Some(chain) => chain.delete(tx),
+            None => Ok(false),
+        }
+    }
+
+    /// Call `func` for every item visible to the transaction.
+    pub fn for_each<F>(&self, tx: &CatalogTx, func: &mut F) -> Result<()>
+    where
+        F: FnMut(&K, &T) -> Result<()>,
+    {
+        let guard = Guard::new();
+        for (key, chain) in self.entries.iter(&guard) {
+            if let Some(item) = chain.get(tx) {
+                func(key, &item)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn autocommit_visible_immediately() {
+        let map = VersionedMap::<String, i32>::default();
+        let tx = CatalogTx::new();
+
+        assert!(map.insert(&tx, "a".to_string(), 1).unwrap());
+        assert_eq!(Some(1), map.get(&tx, "a"));
+        assert_eq!(Some(1), map.get(&CatalogTx::new(), "a"));
+
+        // Duplicate.
+        assert!(!map.insert(&tx, "a".to_string(), 2).unwrap());
+    }
+
+    #[test]
+    fn explicit_invisible_until_commit() {
+        let map = VersionedMap::<String, i32>::default();
+        let tx = CatalogTx::begin();
+        let reader = CatalogTx::begin();
+
+        assert!(map.insert(&tx, "a".to_string(), 1).unwrap());
+        assert_eq!(Some(1), map.get(&tx, "a"));
+        assert_eq!(None, map.get(&CatalogTx::new(), "a"));
+        assert_eq!(None, map.get(&reader, "a"));
+
+        tx.commit().unwrap();
+        assert_eq!(Some(1), map.get(&CatalogTx::new(), "a"));
+        assert_eq!(Some(1), map.get(&CatalogTx::begin(), "a"));
+
+        // Reader began before the commit.
+        assert_eq!(None, map.get(&reader, "a"));
+    }
+
+    #[test]
+    fn rollback_undoes_changes() {
+        let map = VersionedMap::<String, i32>::default();
+        map.insert(&CatalogTx::new(), "a".to_string(), 1).unwrap();
+
+        let tx = CatalogTx::begin();
+        assert!(map.remove(&tx, "a").unwrap());
+        assert!(map.insert(&tx, "b".to_string(), 2).unwrap());
+        assert_eq!(None, map.get(&tx, "a"));
+        assert_eq!(Some(1), map.get(&CatalogTx::new(), "a"));
+
+        tx.rollback().unwrap();
+        assert_eq!(Some(1), map.get(&CatalogTx::new(), "a"));
+        assert_eq!(None, map.get(&CatalogTx::new(), "b"));
+    }
+
+    #[test]
+    fn snapshot_ignores_later_commits() {
+        let map = VersionedMap::<String, i32>::default();
+        map.insert(&CatalogTx::new(), "a".to_string(), 1).unwrap();
+
+        let reader = CatalogTx::begin();
+        map.remove(&CatalogTx::new(), "a").unwrap();
+
+        assert_eq!(Some(1), map.get(&reader, "a"));
+        assert_eq!(None, map.get(&CatalogTx::new(), "a"));
+    }
+
+    #[test]
+    fn statement_replace_committed_once() {
+        let chain = VersionChain::new_with_item(&CatalogTx::new(), 1).unwrap();
+
+        let stmt = CatalogTx::begin_statement();
+        chain.replace(&stmt, 2).unwrap();
+        assert_eq!(Some(2), chain.get(&stmt));
+
+        // Neither the delete nor the insert is visible until the statement
+        // commits.
+        let before = CatalogTx::begin();
+        assert_eq!(Some(1), chain.get(&before));
+        assert_eq!(Some(1), chain.get(&CatalogTx::new()));
+
+        stmt.commit().unwrap();
+        assert_eq!(Some(1), chain.get(&before));
+        assert_eq!(Some(2), chain.get(&CatalogTx::begin()));
+        assert_eq!(Some(2), chain.get(&CatalogTx::new()));
+    }
+
+    #[test]
+    fn write_conflict() {
+        let map = VersionedMap::<String, i32>::default();
+        let tx1 = CatalogTx::begin();
+        let tx2 = CatalogTx::begin();
+
+        map.insert(&tx1, "a".to_string(), 1).unwrap();
+        map.insert(&tx2, "a".to_string(), 2).unwrap_err();
+
+        tx1.commit().unwrap();
+
+        // Still a conflict, tx2's snapshot doesn't include tx1's commit.
+        map.insert(&tx2, "a".to_string(), 2).unwrap_err();
+    }
+
+    #[test]
+    fn finished_transaction() {
+        let map = VersionedMap::<String, i32>::default();
+        let tx = CatalogTx::begin();
+        tx.commit().unwrap();
+
+        map.insert(&tx, "a".to_string(), 1).unwrap_err();
+        tx.commit().unwrap_err();
+        tx.rollback().unwrap_err();
+    }
+}
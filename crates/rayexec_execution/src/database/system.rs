@@ -9,12 +9,14 @@ use crate::database::create::{
     CreateScalarFunctionInfo,
     CreateSchemaInfo,
     CreateTableFunctionInfo,
+    CreateWindowFunctionInfo,
     OnConflict,
 };
 use crate::datasource::DataSourceRegistry;
 use crate::functions::aggregate::builtin::BUILTIN_AGGREGATE_FUNCTIONS;
 use crate::functions::scalar::builtin::BUILTIN_SCALAR_FUNCTIONS;
 use crate::functions::table::builtin::BUILTIN_TABLE_FUNCTIONS;
+use crate::functions::window::builtin::BUILTIN_WINDOW_FUNCTIONS;
 
 /// Create a new system catalog with builtin functions.
 ///
@@ -23,7 +25,7 @@ use crate::functions::table::builtin::BUILTIN_TABLE_FUNCTIONS;
 pub fn new_system_catalog(registry: &DataSourceRegistry) -> Result<MemoryCatalog> {
     let catalog = MemoryCatalog::default();
 
-    let tx = &CatalogTx {};
+    let tx = &CatalogTx::new();
 
     let builtin = catalog.create_schema(
         tx,
@@ -95,6 +97,29 @@ pub fn new_system_catalog(registry: &DataSourceRegistry) -> Result<MemoryCatalog
         }
     }
 
+    // Add builtin window functions.
+    for func in BUILTIN_WINDOW_FUNCTIONS.iter() {
+        builtin.create_window_function(
+            tx,
+            &CreateWindowFunctionInfo {
+                name: func.name().to_string(),
+                implementation: func.clone(),
+                on_conflict: OnConflict::Error,
+            },
+        )?;
+
+        for alias in func.aliases() {
+            builtin.create_window_function(
+                tx,
+                &CreateWindowFunctionInfo {
+                    name: alias.to_string(),
+                    implementation: func.clone(),
+                    on_conflict: OnConflict::Error,
+                },
+            )?;
+        }
+    }
+
     // Add builtin table functions.
     for func in BUILTIN_TABLE_FUNCTIONS.iter() {
         builtin.create_table_function(
@@ -11,6 +11,7 @@ use tracing::warn;
 use super::profiler::PlanningProfileData;
 use crate::arrays::batch::Batch;
 use crate::arrays::field::Schema;
+use crate::database::catalog::CatalogTx;
 use crate::database::DatabaseContext;
 use crate::execution::operators::sink::{PartitionSink, SinkOperation};
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -18,11 +19,18 @@ use crate::runtime::handle::QueryHandle;
 use crate::runtime::ErrorSink;
 
 /// Create sinks and streams for sending query output to a client.
-pub fn new_results_sinks() -> (ResultStream, ResultSink, ResultErrorSink) {
+///
+/// If a statement transaction is provided, it's committed once all output has
+/// been produced, and rolled back if the query errors or is dropped before
+/// finishing.
+pub fn new_results_sinks(
+    statement_tx: Option<CatalogTx>,
+) -> (ResultStream, ResultSink, ResultErrorSink) {
     let inner = Arc::new(Mutex::new(InnerState {
         batch: None,
         error: None,
         finished: false,
+        statement_tx,
         push_waker: None,
         pull_waker: None,
     }));
@@ -51,6 +59,22 @@ pub struct ResultStream {
     inner: Arc<Mutex<InnerState>>,
 }
 
+impl ResultStream {
+    /// Create a stream that produces a single batch.
+    pub fn from_batch(batch: Batch) -> Self {
+        ResultStream {
+            inner: Arc::new(Mutex::new(InnerState {
+                batch: Some(batch),
+                error: None,
+                finished: true,
+                statement_tx: None,
+                push_waker: None,
+                pull_waker: None,
+            })),
+        }
+    }
+}
+
 impl Stream for ResultStream {
     type Item = Result<Batch>;
     fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
@@ -146,6 +170,10 @@ impl ErrorSink for ResultErrorSink {
             inner.error = Some(error);
         }
 
+        if let Some(tx) = inner.statement_tx.take() {
+            let _ = tx.rollback();
+        }
+
         if let Some(waker) = inner.pull_waker.take() {
             waker.wake();
         }
@@ -160,10 +188,21 @@ struct InnerState {
     batch: Option<Batch>,
     error: Option<RayexecError>,
     finished: bool,
+    /// Transaction to commit once the query finishes.
+    statement_tx: Option<CatalogTx>,
     push_waker: Option<Waker>,
     pull_waker: Option<Waker>,
 }
 
+impl Drop for InnerState {
+    fn drop(&mut self) {
+        // Query never finished, undo any changes it made.
+        if let Some(tx) = self.statement_tx.take() {
+            let _ = tx.rollback();
+        }
+    }
+}
+
 struct PushFuture {
     batch: Option<Batch>,
     inner: Arc<Mutex<InnerState>>,
@@ -197,6 +236,15 @@ impl Future for FinalizeFuture {
     type Output = Result<()>;
     fn poll(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<Self::Output> {
         let mut inner = self.inner.lock();
+
+        // Commit before marking the stream as finished so that the changes
+        // are visible to the next statement.
+        if let Some(tx) = inner.statement_tx.take() {
+            if let Err(e) = tx.commit() {
+                return Poll::Ready(Err(e));
+            }
+        }
+
         inner.finished = true;
 
         if let Some(pull_waker) = inner.pull_waker.take() {
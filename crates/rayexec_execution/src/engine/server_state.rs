@@ -104,6 +104,8 @@ where
         let binder = StatementBinder {
             session_config: &session_config,
             resolve_context: &resolve_context,
+            parameters: Some(&[]),
+            parameter_types: &[],
         };
         let (bound_stmt, mut bind_context) = binder.bind(stmt)?;
         let mut logical = StatementPlanner.plan(&mut bind_context, bound_stmt)?;
@@ -1,16 +1,23 @@
 use std::sync::Arc;
 
+use futures::TryStreamExt;
 use hashbrown::HashMap;
-use rayexec_error::{OptionExt, RayexecError, Result};
+use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
+use rayexec_parser::ast::TransactionStatement;
 use rayexec_parser::parser;
-use rayexec_parser::statement::RawStatement;
+use rayexec_parser::statement::{RawStatement, Statement};
 use uuid::Uuid;
 
 use super::profiler::PlanningProfileData;
 use super::result::{new_results_sinks, ExecutionResult, ResultErrorSink, ResultStream};
 use super::verifier::QueryVerifier;
 use super::DataSourceRegistry;
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::cast::scalar::cast_scalar;
+use crate::arrays::datatype::DataType;
 use crate::arrays::field::{Field, Schema};
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::config::execution::{ExecutablePlanConfig, IntermediatePlanConfig};
 use crate::config::session::SessionConfig;
 use crate::database::catalog::CatalogTx;
@@ -23,9 +30,13 @@ use crate::execution::intermediate::pipeline::{
     IntermediatePipelineGroup,
 };
 use crate::execution::intermediate::planner::IntermediatePipelinePlanner;
+use crate::explain::formatter::format_execution_profile;
 use crate::hybrid::client::HybridClient;
+use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_statement::StatementBinder;
+use crate::logical::binder::constant_binder::ConstantBinder;
 use crate::logical::logical_attach::LogicalAttachDatabase;
+use crate::logical::logical_explain::ExplainFormat;
 use crate::logical::logical_set::VariableOrAll;
 use crate::logical::operator::{LogicalOperator, Node};
 use crate::logical::planner::plan_statement::StatementPlanner;
@@ -93,6 +104,37 @@ pub struct Session<P: PipelineExecutor, R: Runtime> {
 struct PreparedStatement {
     verifier: Option<QueryVerifier>,
     statement: RawStatement,
+    /// Types for the parameters, either declared or inferred from how each
+    /// parameter is used in the statement.
+    ///
+    /// Parameter values provided for these parameters will be cast to the
+    /// parameter's type. Values for parameters with an unknown type are used
+    /// as is.
+    param_types: Vec<Option<DataType>>,
+}
+
+impl PreparedStatement {
+    fn cast_parameters(&self, params: Vec<OwnedScalarValue>) -> Result<Vec<OwnedScalarValue>> {
+        if !self.param_types.is_empty() && params.len() != self.param_types.len() {
+            return Err(RayexecError::new(format!(
+                "Prepared statement expects {} parameters, got {}",
+                self.param_types.len(),
+                params.len()
+            )));
+        }
+
+        params
+            .into_iter()
+            .enumerate()
+            .map(|(idx, param)| match self.param_types.get(idx) {
+                // NULLs are cast when bound.
+                Some(Some(datatype)) if !matches!(param, ScalarValue::Null) => {
+                    cast_scalar(param, datatype)
+                }
+                _ => Ok(param),
+            })
+            .collect()
+    }
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -113,6 +155,9 @@ struct IntermediatePortal {
     intermediate_pipelines: IntermediatePipelineGroup,
     intermediate_materializations: IntermediateMaterializationGroup,
     output_schema: Schema,
+    /// Output format and if timings are included if this is an EXPLAIN
+    /// ANALYZE.
+    explain_analyze: Option<(ExplainFormat, bool)>,
 }
 
 /// Portal containing executable pipelines.
@@ -139,6 +184,12 @@ struct ExecutablePortal {
     profile: PlanningProfileData,
     /// Optional verifier that we're carrying through planning.
     verifier: Option<QueryVerifier>,
+    /// Output format and if timings are included if this is an EXPLAIN
+    /// ANALYZE.
+    ///
+    /// The query output is discarded and replaced with the rendered execution
+    /// profile once the query completes.
+    explain_analyze: Option<(ExplainFormat, bool)>,
 }
 
 impl<P, R> Session<P, R>
@@ -147,12 +198,13 @@ where
     R: Runtime,
 {
     pub fn new(
-        context: DatabaseContext,
+        mut context: DatabaseContext,
         executor: P,
         runtime: R,
         registry: Arc<DataSourceRegistry>,
     ) -> Self {
         let config = SessionConfig::new(&executor, &runtime);
+        context.set_spill_provider(runtime.spill_provider());
 
         Session {
             context,
@@ -186,7 +238,7 @@ where
 
         for stmt in stmts {
             self.prepare(UNNAMED, stmt)?;
-            self.bind(UNNAMED, UNNAMED).await?;
+            self.bind(UNNAMED, UNNAMED, Vec::new()).await?;
             let result = self.execute(UNNAMED).await?;
             results.push(result);
         }
@@ -194,8 +246,17 @@ where
         Ok(results)
     }
 
-    // TODO: Typed parameters at some point.
     pub fn prepare(&mut self, prepared_name: impl Into<String>, stmt: RawStatement) -> Result<()> {
+        self.prepare_with_types(prepared_name, stmt, Vec::new())
+    }
+
+    /// Prepare a statement with known types for its parameters.
+    pub fn prepare_with_types(
+        &mut self,
+        prepared_name: impl Into<String>,
+        stmt: RawStatement,
+        param_types: Vec<Option<DataType>>,
+    ) -> Result<()> {
         let verifier = if self.config.verify_optimized_plan {
             Some(QueryVerifier::new(stmt.clone()))
         } else {
@@ -207,6 +268,7 @@ where
             PreparedStatement {
                 statement: stmt,
                 verifier,
+                param_types,
             },
         );
         Ok(())
@@ -214,22 +276,55 @@ where
 
     /// Gets a prepared statement by name and generates intermedidate executable
     /// pipelines that get placed into a portal.
+    ///
+    /// `params` provides the values for positional parameters (`$1`, `?`) in
+    /// the statement.
+    ///
+    /// Statements outside of an explicit transaction are bound in their own
+    /// transaction, committed once the statement finishes.
     pub async fn bind(
         &mut self,
         prepared_name: &str,
         portal_name: impl Into<String>,
+        params: Vec<OwnedScalarValue>,
+    ) -> Result<()> {
+        if !self.context.catalog_tx().is_explicit() {
+            self.context.set_catalog_tx(CatalogTx::begin_statement());
+        }
+
+        let result = self.bind_inner(prepared_name, portal_name, params).await;
+
+        // Operators hold on to the statement transaction, go back to
+        // autocommit until the next statement.
+        if !self.context.catalog_tx().is_explicit() {
+            self.context.set_catalog_tx(CatalogTx::new());
+        }
+
+        result
+    }
+
+    async fn bind_inner(
+        &mut self,
+        prepared_name: &str,
+        portal_name: impl Into<String>,
+        params: Vec<OwnedScalarValue>,
     ) -> Result<()> {
         let stmt = self.prepared.get(prepared_name).ok_or_else(|| {
             RayexecError::new(format!(
                 "Missing named prepared statement: '{prepared_name}'"
             ))
         })?;
-        let verifier = stmt.verifier.clone();
+        let params = stmt.cast_parameters(params)?;
+        let param_types = stmt.param_types.clone();
+        let verifier = stmt
+            .verifier
+            .clone()
+            .map(|verifier| verifier.with_parameters(params.clone(), param_types.clone()));
+        let statement = stmt.statement.clone();
 
         let mut profile = PlanningProfileData::default();
 
-        // TODO: Store tx state on session.
-        let tx = CatalogTx::new();
+        let tx = self.context.catalog_tx().clone();
 
         let resolve_mode = if self.hybrid_client.is_some() {
             ResolveMode::Hybrid
@@ -245,17 +340,88 @@ where
             self.registry.get_file_handlers(),
             ResolveConfig {
                 enable_function_chaining: self.config.enable_function_chaining,
+                time_zone: self.config.time_zone.clone(),
             },
         )
-        .resolve_statement(stmt.statement.clone())
+        .resolve_statement(statement)
         .await?;
         profile.resolve_step = Some(timer.stop());
 
-        let intermediate_portal = self
-            .plan_intermediate(resolved_stmt, resolve_context, resolve_mode, &mut profile)
-            .await?;
+        let intermediate_portal = match resolved_stmt {
+            Statement::Prepare(prepare) => {
+                let param_types = self
+                    .infer_parameter_types(
+                        (*prepare.statement).clone(),
+                        prepare.param_types,
+                        resolve_mode,
+                    )
+                    .await?;
+                self.prepare_with_types(
+                    prepare.name.into_normalized_string(),
+                    *prepare.statement,
+                    param_types,
+                )?;
+                Self::empty_intermediate_portal()?
+            }
+            Statement::Execute(execute) => {
+                let binder = ConstantBinder::new(&resolve_context);
+                let params = execute
+                    .params
+                    .iter()
+                    .map(|param| binder.bind_constant_expression(param))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let name = execute.name.into_normalized_string();
+                if !self.prepared.contains_key(&name) {
+                    return Err(RayexecError::new(format!(
+                        "Prepared statement '{name}' does not exist"
+                    )));
+                }
+
+                return Box::pin(self.bind(&name, portal_name, params)).await;
+            }
+            Statement::Deallocate(deallocate) => {
+                match deallocate.name {
+                    Some(name) => {
+                        let name = name.into_normalized_string();
+                        if self.prepared.remove(&name).is_none() {
+                            return Err(RayexecError::new(format!(
+                                "Prepared statement '{name}' does not exist"
+                            )));
+                        }
+                    }
+                    None => self.prepared.clear(),
+                }
+                Self::empty_intermediate_portal()?
+            }
+            Statement::Transaction(transaction) => {
+                self.handle_transaction(transaction)?;
+                Self::empty_intermediate_portal()?
+            }
+            stmt => {
+                self.plan_intermediate(
+                    stmt,
+                    resolve_context,
+                    resolve_mode,
+                    params,
+                    param_types,
+                    &mut profile,
+                )
+                .await?
+            }
+        };
+
+        // Note that the transaction may have changed if this was a transaction
+        // statement. The statement transaction is committed regardless, it'll
+        // just have no changes.
+        let statement_tx = (!tx.is_explicit()).then_some(tx);
+        let (stream, sink, errors) = new_results_sinks(statement_tx);
 
-        let (stream, sink, errors) = new_results_sinks();
+        // Operators reserve memory through the context's buffer manager, make
+        // sure it's using the session's current limit.
+        self.context
+            .buffer_manager()
+            .set_limit(self.config.memory_limit.map(|limit| limit as usize));
 
         let mut planner = ExecutablePipelinePlanner::<R>::new(
             &self.context,
@@ -286,11 +452,56 @@ where
                 error_sink: errors,
                 profile,
                 verifier,
+                explain_analyze: intermediate_portal.explain_analyze,
             },
         );
         Ok(())
     }
 
+    /// Infers the types of the parameters in a statement being prepared.
+    ///
+    /// The statement is bound with placeholders for its parameters, with each
+    /// parameter taking the type it's cast to where it's used. Declared types
+    /// take precedence.
+    async fn infer_parameter_types(
+        &self,
+        stmt: RawStatement,
+        declared: Vec<DataType>,
+        resolve_mode: ResolveMode,
+    ) -> Result<Vec<Option<DataType>>> {
+        let declared: Vec<_> = declared.into_iter().map(Some).collect();
+
+        let tx = self.context.catalog_tx().clone();
+        let (resolved_stmt, resolve_context) = Resolver::new(
+            resolve_mode,
+            &tx,
+            &self.context,
+            self.registry.get_file_handlers(),
+            ResolveConfig {
+                enable_function_chaining: self.config.enable_function_chaining,
+                time_zone: self.config.time_zone.clone(),
+            },
+        )
+        .resolve_statement(stmt)
+        .await?;
+
+        // Binding requires everything to be resolved locally, and transaction
+        // statements aren't bound.
+        if resolve_context.any_unresolved() || matches!(resolved_stmt, Statement::Transaction(_)) {
+            return Ok(declared);
+        }
+
+        let binder = StatementBinder {
+            session_config: &self.config,
+            resolve_context: &resolve_context,
+            parameters: None,
+            parameter_types: &declared,
+        };
+        let (_, bind_context) = binder.bind(resolved_stmt)?;
+
+        Ok(bind_context.parameter_types().to_vec())
+    }
+
     /// Plans the intermediate pipelines from a resolved statement.
     ///
     /// If the resolve context indicates that not all objects were resolved,
@@ -300,10 +511,16 @@ where
         stmt: ResolvedStatement,
         resolve_context: ResolveContext,
         resolve_mode: ResolveMode,
+        params: Vec<OwnedScalarValue>,
+        param_types: Vec<Option<DataType>>,
         profile: &mut PlanningProfileData,
     ) -> Result<IntermediatePortal> {
         match resolve_mode {
             ResolveMode::Hybrid if resolve_context.any_unresolved() => {
+                if !params.is_empty() {
+                    not_implemented!("Parameters with hybrid execution")
+                }
+
                 // Hybrid planning, send to remote to complete planning.
                 let hybrid_client = self.hybrid_client.clone().required("hybrid_client")?;
                 let resp = hybrid_client
@@ -316,6 +533,7 @@ where
                     intermediate_pipelines: resp.pipelines,
                     intermediate_materializations: IntermediateMaterializationGroup::default(), // TODO: Need to get these somehow.
                     output_schema: resp.schema,
+                    explain_analyze: None,
                 })
             }
             _ => {
@@ -324,6 +542,8 @@ where
                 let binder = StatementBinder {
                     session_config: &self.config,
                     resolve_context: &resolve_context,
+                    parameters: Some(&params),
+                    parameter_types: &param_types,
                 };
                 let timer = Timer::<R::Instant>::start();
                 let (bound_stmt, mut bind_context) = binder.bind(stmt)?;
@@ -349,6 +569,13 @@ where
                     explain.node.logical_optimized = Some(Box::new(child.clone()));
                 }
 
+                let explain_analyze = match &logical {
+                    LogicalOperator::Explain(explain) if explain.node.analyze => {
+                        Some((explain.node.format, explain.node.timing))
+                    }
+                    _ => None,
+                };
+
                 let schema = Schema::new(
                     bind_context
                         .iter_tables_in_scope(bind_context.root_scope_ref())?
@@ -364,6 +591,8 @@ where
                 let planner = IntermediatePipelinePlanner::new(
                     IntermediatePlanConfig {
                         allow_nested_loop_join: self.config.allow_nested_loop_join,
+                        max_recursive_cte_iterations: self.config.max_recursive_cte_iterations
+                            as usize,
                     },
                     query_id,
                 );
@@ -425,11 +654,64 @@ where
                     intermediate_pipelines: pipelines.local,
                     intermediate_materializations: pipelines.materializations,
                     output_schema: schema,
+                    explain_analyze,
                 })
             }
         }
     }
 
+    /// Begin, commit, or rollback an explicit transaction.
+    ///
+    /// Statements outside of an explicit transaction use an autocommit
+    /// transaction.
+    fn handle_transaction(&mut self, transaction: TransactionStatement) -> Result<()> {
+        let tx = self.context.catalog_tx();
+
+        match transaction {
+            TransactionStatement::Begin => {
+                if tx.is_explicit() {
+                    return Err(RayexecError::new("Transaction already in progress"));
+                }
+                if self.hybrid_client.is_some() {
+                    not_implemented!("Transactions with hybrid execution")
+                }
+                self.context.set_catalog_tx(CatalogTx::begin());
+            }
+            TransactionStatement::Commit | TransactionStatement::Rollback => {
+                if !tx.is_explicit() {
+                    return Err(RayexecError::new("No transaction in progress"));
+                }
+                // Always reset to autocommit, even if commit fails.
+                let tx = self.context.catalog_tx().clone();
+                self.context.set_catalog_tx(CatalogTx::new());
+                match transaction {
+                    TransactionStatement::Commit => tx.commit()?,
+                    _ => tx.rollback()?,
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Plans an intermediate portal that produces no output.
+    ///
+    /// Used for statements that only modify session state.
+    fn empty_intermediate_portal() -> Result<IntermediatePortal> {
+        let query_id = Uuid::new_v4();
+        let planner = IntermediatePipelinePlanner::new(IntermediatePlanConfig::default(), query_id);
+        let pipelines = planner.plan_pipelines(LogicalOperator::EMPTY, BindContext::new())?;
+
+        Ok(IntermediatePortal {
+            query_id,
+            execution_mode: ExecutionMode::LocalOnly,
+            intermediate_pipelines: pipelines.local,
+            intermediate_materializations: pipelines.materializations,
+            output_schema: Schema::empty(),
+            explain_analyze: None,
+        })
+    }
+
     /// Executes the pipelines in the given portal.
     ///
     /// This will go through the final phase of planning (producing executable
@@ -450,13 +732,29 @@ where
             .executor
             .spawn_pipelines(portal.executable_pipelines, Arc::new(portal.error_sink));
 
-        let exec_result = ExecutionResult {
+        let mut exec_result = ExecutionResult {
             planning_profile: portal.profile,
             output_schema: portal.output_schema,
             stream: portal.result_stream,
             handle: handle.into(),
         };
 
+        if let Some((format, timing)) = portal.explain_analyze {
+            // Run the query to completion, then replace the (empty) output
+            // with the rendered profile.
+            exec_result
+                .stream
+                .try_for_each(|_| async { Ok(()) })
+                .await?;
+            let profile = exec_result.handle.generate_execution_profile_data().await?;
+
+            let batch = Batch::try_from_arrays([
+                Array::from_iter(["analyze"]),
+                Array::from_iter([format_execution_profile(&profile, format, timing)?]),
+            ])?;
+            exec_result.stream = ResultStream::from_batch(batch);
+        }
+
         match portal.verifier {
             Some(verifier) => {
                 // TODO: Try to avoid the box pin here. `verify` should probably
@@ -536,3 +834,17 @@ where
         self.hybrid_client = None;
     }
 }
+
+impl<P, R> Drop for Session<P, R>
+where
+    P: PipelineExecutor,
+    R: Runtime,
+{
+    fn drop(&mut self) {
+        // Undo changes from a transaction that was never committed.
+        let tx = self.context.catalog_tx();
+        if tx.is_explicit() {
+            let _ = tx.rollback();
+        }
+    }
+}
@@ -4,17 +4,37 @@ use rayexec_parser::statement::RawStatement;
 
 use super::result::ExecutionResult;
 use super::session::Session;
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::OwnedScalarValue;
 use crate::runtime::{PipelineExecutor, Runtime};
 
 /// Verify the results of a query.
 #[derive(Debug, Clone)]
 pub struct QueryVerifier {
     statement: RawStatement,
+    /// Parameter values the statement was bound with.
+    params: Vec<OwnedScalarValue>,
+    /// Types of the parameters the statement was prepared with.
+    param_types: Vec<Option<DataType>>,
 }
 
 impl QueryVerifier {
     pub fn new(statement: RawStatement) -> Self {
-        QueryVerifier { statement }
+        QueryVerifier {
+            statement,
+            params: Vec::new(),
+            param_types: Vec::new(),
+        }
+    }
+
+    pub fn with_parameters(
+        mut self,
+        params: Vec<OwnedScalarValue>,
+        param_types: Vec<Option<DataType>>,
+    ) -> Self {
+        self.params = params;
+        self.param_types = param_types;
+        self
     }
 
     pub async fn verify<P, R>(
@@ -38,8 +58,14 @@ impl QueryVerifier {
 
         const VERIFIED_NAME: &str = "__verify_optimized_plan";
 
-        session.prepare(VERIFIED_NAME, self.statement.clone())?;
-        session.bind(VERIFIED_NAME, VERIFIED_NAME).await?;
+        session.prepare_with_types(
+            VERIFIED_NAME,
+            self.statement.clone(),
+            self.param_types.clone(),
+        )?;
+        session
+            .bind(VERIFIED_NAME, VERIFIED_NAME, self.params.clone())
+            .await?;
 
         let result = session.execute(VERIFIED_NAME).await;
 
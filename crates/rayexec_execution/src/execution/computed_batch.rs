@@ -58,6 +58,14 @@ impl ComputedBatches {
         }
     }
 
+    pub fn num_batches(&self) -> usize {
+        match self {
+            Self::Single(_) => 1,
+            Self::Multi(batches) => batches.len(),
+            Self::None => 0,
+        }
+    }
+
     /// Checks if this collection of batches is empty.
     // TODO: Think about the behavior for this when batches is length zero.
     // There was a bug where loop join produce no batches, called this
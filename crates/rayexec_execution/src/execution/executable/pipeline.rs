@@ -1,6 +1,7 @@
 use std::fmt;
 use std::sync::Arc;
 use std::task::{Context, Poll};
+use std::time::Duration;
 
 use rayexec_error::{RayexecError, Result};
 use tracing::trace;
@@ -18,7 +19,7 @@ use crate::execution::operators::{
     PollPush,
 };
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
-use crate::runtime::time::{RuntimeInstant, Timer};
+use crate::runtime::time::RuntimeInstant;
 
 // TODO: Include intermedate pipeline to track lineage.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -99,6 +100,7 @@ impl ExecutablePipeline {
                 operator_state: operator_state.clone(),
                 partition_state,
                 profile_data: OperatorProfileData::default(),
+                first_poll: None,
             });
 
         for (operator, partition_pipeline) in operators.zip(self.partitions.iter_mut()) {
@@ -254,6 +256,10 @@ pub struct OperatorWithState {
 
     /// Profile data for this operator.
     profile_data: OperatorProfileData,
+
+    /// When this operator was first polled, relative to the runtime's
+    /// reference point.
+    first_poll: Option<Duration>,
 }
 
 impl OperatorWithState {
@@ -264,6 +270,21 @@ impl OperatorWithState {
     pub fn profile_data(&self) -> &OperatorProfileData {
         &self.profile_data
     }
+
+    /// Update profile data for a poll that started and ended at the given
+    /// instants.
+    fn record_poll<I: RuntimeInstant>(&mut self, start: I, end: I) {
+        let start_offset = start.since_reference();
+        let end_offset = end.since_reference();
+
+        let first_poll = *self.first_poll.get_or_insert(start_offset);
+        self.profile_data.elapsed = end_offset.saturating_sub(first_poll);
+        self.profile_data.poll_time += end.duration_since(start);
+        self.profile_data.peak_memory = self
+            .profile_data
+            .peak_memory
+            .max(self.partition_state.reserved_memory());
+    }
 }
 
 #[derive(Clone)]
@@ -345,18 +366,18 @@ impl ExecutablePartitionPipeline {
                         .expect("operator to exist");
 
                     // Otherwise do a normal pull.
-                    let timer = Timer::<I>::start();
+                    let start = I::now();
                     let poll_pull = operator.physical.poll_pull(
                         cx,
                         &mut operator.partition_state,
                         &operator.operator_state,
                     );
-                    let elapsed = timer.stop();
-                    operator.profile_data.elapsed += elapsed;
+                    operator.record_poll(start, I::now());
 
                     match poll_pull {
                         Ok(PollPull::Computed(mut computed)) => {
                             operator.profile_data.rows_emitted += computed.total_num_rows(); // TODO: We should have something to indicate materialized vs not.
+                            operator.profile_data.batches_emitted += computed.num_batches();
 
                             let batch = match computed.try_pop_front()? {
                                 Some(batch) => batch,
@@ -415,14 +436,13 @@ impl ExecutablePartitionPipeline {
                         .get_mut(*operator_idx)
                         .expect("next operator to exist");
 
-                    let timer = Timer::<I>::start();
+                    let start = I::now();
                     let poll_finalize = next_operator.physical.poll_finalize_push(
                         cx,
                         &mut next_operator.partition_state,
                         &next_operator.operator_state,
                     );
-                    let elapsed = timer.stop();
-                    next_operator.profile_data.elapsed += elapsed;
+                    next_operator.record_poll(start, I::now());
 
                     match poll_finalize {
                         Ok(PollFinalize::Finalized) => {
@@ -458,16 +478,16 @@ impl ExecutablePartitionPipeline {
                         .expect("operator to exist");
 
                     operator.profile_data.rows_read += batch.num_rows();
+                    operator.profile_data.batches_read += 1;
 
-                    let timer = Timer::<I>::start();
+                    let start = I::now();
                     let poll_push = operator.physical.poll_push(
                         cx,
                         &mut operator.partition_state,
                         &operator.operator_state,
                         batch,
                     );
-                    let elapsed = timer.stop();
-                    operator.profile_data.elapsed += elapsed;
+                    operator.record_poll(start, I::now());
 
                     match poll_push {
                         Ok(PollPush::Pushed) => {
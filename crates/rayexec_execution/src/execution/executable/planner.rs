@@ -1,8 +1,11 @@
 use std::collections::{HashMap, VecDeque};
 use std::sync::Arc;
 
+use bytes::Bytes;
 use rayexec_error::{RayexecError, Result};
-use rayexec_io::http::HttpClient;
+use rayexec_io::http::reqwest::header::HeaderMap;
+use rayexec_io::http::reqwest::{Request, StatusCode};
+use rayexec_io::http::{HttpClient, HttpResponse};
 
 use super::pipeline::{ExecutablePipeline, PipelineId};
 use crate::config::execution::ExecutablePlanConfig;
@@ -114,6 +117,69 @@ impl<'a, R: Runtime> ExecutablePipelinePlanner<'a, R> {
     }
 }
 
+/// Plan executable pipelines for a group of pipelines that's executed from
+/// within an operator, e.g. the recursive term of a recursive CTE.
+///
+/// Pipelines are planned with a single partition, and may not contain query
+/// outputs, materializations, or pipelines executing in some other group.
+pub(crate) fn plan_nested_pipelines(
+    context: &DatabaseContext,
+    group: IntermediatePipelineGroup,
+) -> Result<Vec<ExecutablePipeline>> {
+    let config = ExecutablePlanConfig { partitions: 1 };
+    let mut pending = PendingQuery::try_from_operators_and_materializations(
+        &config,
+        context,
+        group,
+        IntermediateMaterializationGroup::default(),
+    )?;
+
+    let mut loc_state: PlanLocationState<NestedHttpClient> = PlanLocationState::Client {
+        output_sink: None,
+        hybrid_client: None,
+    };
+    let mut id_gen = PipelineIdGen { gen: PipelineId(0) };
+
+    pending.plan_executable_pipelines(context, &mut loc_state, &mut id_gen)
+}
+
+/// Http client for planning nested pipelines.
+///
+/// Nested pipelines never communicate with a remote server, so this can never
+/// be constructed.
+#[derive(Debug, Clone)]
+enum NestedHttpClient {}
+
+impl HttpClient for NestedHttpClient {
+    type Response = NestedHttpClient;
+    type RequestFuture = std::future::Pending<Result<NestedHttpClient>>;
+
+    fn do_request(&self, _request: Request) -> Self::RequestFuture {
+        match *self {}
+    }
+}
+
+impl HttpResponse for NestedHttpClient {
+    type BytesFuture = std::future::Pending<Result<Bytes>>;
+    type BytesStream = futures::stream::Pending<Result<Bytes>>;
+
+    fn status(&self) -> StatusCode {
+        match *self {}
+    }
+
+    fn headers(&self) -> &HeaderMap {
+        match *self {}
+    }
+
+    fn bytes(self) -> Self::BytesFuture {
+        match self {}
+    }
+
+    fn bytes_stream(self) -> Self::BytesStream {
+        match self {}
+    }
+}
+
 #[derive(Debug)]
 struct PendingQuery {
     /// All pending operators in a query.
@@ -4,7 +4,7 @@ use std::time::Duration;
 
 use super::pipeline::{ExecutablePartitionPipeline, PipelineId};
 use crate::explain::context_display::ContextDisplayMode;
-use crate::explain::explainable::ExplainConfig;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry};
 
 #[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ExecutionProfileData {
@@ -22,16 +22,14 @@ impl ExecutionProfileData {
                 .iter()
                 .map(|op| op.profile_data().clone())
                 .collect(),
-            explain_strings: partition
+            explain_entries: partition
                 .operators()
                 .iter()
                 .map(|op| {
-                    op.physical_operator()
-                        .explain_entry(ExplainConfig {
-                            context_mode: ContextDisplayMode::Raw,
-                            verbose: false,
-                        })
-                        .to_string()
+                    op.physical_operator().explain_entry(ExplainConfig {
+                        context_mode: ContextDisplayMode::Raw,
+                        verbose: false,
+                    })
                 })
                 .collect(),
         };
@@ -53,23 +51,31 @@ impl fmt::Display for ExecutionProfileData {
                 #[allow(clippy::write_literal)]
                 writeln!(
                     f,
-                    "    [{:>2}]  {:>8}  {:>8}  {:>16}  {}",
-                    "Op", "Read", "Emitted", "Elapsed (micro)", "Explain",
+                    "    [{:>2}]  {:>8}  {:>8}  {:>16}  {:>16}  {:>12}  {}",
+                    "Op",
+                    "Read",
+                    "Emitted",
+                    "Elapsed (micro)",
+                    "Polling (micro)",
+                    "Peak memory",
+                    "Explain",
                 )?;
 
                 for (idx, (operator, explain)) in partition
                     .operators
                     .iter()
-                    .zip(&partition.explain_strings)
+                    .zip(&partition.explain_entries)
                     .enumerate()
                 {
                     writeln!(
                         f,
-                        "    [{:>2}]  {:>8}  {:>8}  {:>16}  {}",
+                        "    [{:>2}]  {:>8}  {:>8}  {:>16}  {:>16}  {:>12}  {}",
                         idx,
                         operator.rows_read,
                         operator.rows_emitted,
                         operator.elapsed.as_micros(),
+                        operator.poll_time.as_micros(),
+                        operator.peak_memory,
                         explain,
                     )?;
                 }
@@ -88,14 +94,47 @@ pub struct PipelineProfileData {
     pub partitions: BTreeMap<usize, PartitionPipelineProfileData>,
 }
 
+impl PipelineProfileData {
+    /// Combine the profile data for each operator across all partitions.
+    ///
+    /// Counts and polling time are summed. Elapsed time is the max across
+    /// partitions. Peak memory is summed as partitions execute concurrently.
+    pub fn combined_operators(&self) -> Vec<(ExplainEntry, OperatorProfileData)> {
+        let mut combined: Vec<(ExplainEntry, OperatorProfileData)> = Vec::new();
+
+        for partition in self.partitions.values() {
+            for (idx, (operator, entry)) in partition
+                .operators
+                .iter()
+                .zip(&partition.explain_entries)
+                .enumerate()
+            {
+                match combined.get_mut(idx) {
+                    Some((_, data)) => {
+                        data.rows_read += operator.rows_read;
+                        data.rows_emitted += operator.rows_emitted;
+                        data.batches_read += operator.batches_read;
+                        data.batches_emitted += operator.batches_emitted;
+                        data.elapsed = data.elapsed.max(operator.elapsed);
+                        data.poll_time += operator.poll_time;
+                        data.peak_memory += operator.peak_memory;
+                    }
+                    None => combined.push((entry.clone(), operator.clone())),
+                }
+            }
+        }
+
+        combined
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PartitionPipelineProfileData {
     /// Profile data for all operators in this partition pipeline.
     pub operators: Vec<OperatorProfileData>,
-    // TODO: This is here just to help debug. Evetually I want to just be able
-    // to line up this data with the original plan using the pipeline/partition
-    // ids.
-    pub explain_strings: Vec<String>,
+    // TODO: Evetually I want to just be able to line up this data with the
+    // original plan using the pipeline/partition ids.
+    pub explain_entries: Vec<ExplainEntry>,
 }
 
 #[derive(Debug, Default, Clone, PartialEq, Eq)]
@@ -104,6 +143,15 @@ pub struct OperatorProfileData {
     pub rows_read: usize,
     /// Number of rows produced by the operator.
     pub rows_emitted: usize,
-    /// Elapsed time while activley executing this operator.
+    /// Number of batches read into the operator.
+    pub batches_read: usize,
+    /// Number of batches produced by the operator.
+    pub batches_emitted: usize,
+    /// Wall clock time between the first and last time this operator was
+    /// polled.
     pub elapsed: Duration,
+    /// Time spent actively executing this operator (polling).
+    pub poll_time: Duration,
+    /// Peak number of bytes reserved by the operator.
+    pub peak_memory: usize,
 }
@@ -279,7 +279,7 @@ impl ProtoConv for PipelineSource {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct IntermediatePipelineGroup {
     pub(crate) pipelines: HashMap<IntermediatePipelineId, IntermediatePipeline>,
 }
@@ -347,7 +347,7 @@ pub struct IntermediateMaterialization {
     pub(crate) scan_count: usize,
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntermediatePipeline {
     pub(crate) id: IntermediatePipelineId,
     pub(crate) sink: PipelineSink,
@@ -385,7 +385,7 @@ impl DatabaseProtoConv for IntermediatePipeline {
     }
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct IntermediateOperator {
     /// The physical operator that will be used in the executable pipline.
     pub(crate) operator: Arc<PhysicalOperator>,
@@ -15,13 +15,17 @@ mod plan_join;
 mod plan_limit;
 mod plan_magic_scan;
 mod plan_materialize_scan;
+mod plan_modify;
 mod plan_project;
+mod plan_recursive_cte;
 mod plan_scan;
 mod plan_set_operation;
 mod plan_show_var;
 mod plan_sort;
 mod plan_unnest;
+mod plan_window;
 
+use std::collections::HashMap;
 use std::sync::Arc;
 
 use rayexec_error::{not_implemented, OptionExt, RayexecError, Result};
@@ -40,9 +44,10 @@ use super::pipeline::{
 };
 use crate::config::execution::IntermediatePlanConfig;
 use crate::execution::operators::batch_resizer::PhysicalBatchResizer;
+use crate::execution::operators::recursive_cte::WorkingTable;
 use crate::execution::operators::PhysicalOperator;
 use crate::expr::physical::planner::PhysicalExpressionPlanner;
-use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_context::{BindContext, CteRef};
 use crate::logical::operator::{self, LocationRequirement, LogicalOperator};
 
 /// Planned pipelines grouped into locations for where they should be executed.
@@ -125,6 +130,8 @@ impl PipelineIdGen {
 struct Materializations {
     local: IntermediateMaterializationGroup,
     // TODO: Remote materializations.
+    /// Working tables for recursive CTEs currently being planned.
+    working_tables: HashMap<CteRef, Arc<WorkingTable>>,
 }
 
 /// Represents an intermediate pipeline that we're building up.
@@ -184,6 +191,7 @@ impl<'a> IntermediatePipelineBuildState<'a> {
 
         let mut materializations = Materializations {
             local: IntermediateMaterializationGroup::default(),
+            working_tables: HashMap::new(),
         };
 
         for mat in self.bind_context.iter_materializations() {
@@ -238,6 +246,7 @@ impl<'a> IntermediatePipelineBuildState<'a> {
             }
             LogicalOperator::Empty(empty) => self.plan_empty(id_gen, empty),
             LogicalOperator::Aggregate(agg) => self.plan_aggregate(id_gen, materializations, agg),
+            LogicalOperator::Window(window) => self.plan_window(id_gen, materializations, window),
             LogicalOperator::Limit(limit) => self.plan_limit(id_gen, materializations, limit),
             LogicalOperator::Order(order) => self.plan_sort(id_gen, materializations, order),
             LogicalOperator::ShowVar(show_var) => self.plan_show_var(id_gen, show_var),
@@ -252,6 +261,9 @@ impl<'a> IntermediatePipelineBuildState<'a> {
             LogicalOperator::CreateSchema(create) => self.plan_create_schema(id_gen, create),
             LogicalOperator::Drop(drop) => self.plan_drop(id_gen, drop),
             LogicalOperator::Insert(insert) => self.plan_insert(id_gen, materializations, insert),
+            LogicalOperator::Update(update) => self.plan_update(id_gen, materializations, update),
+            LogicalOperator::Delete(delete) => self.plan_delete(id_gen, materializations, delete),
+            LogicalOperator::Merge(merge) => self.plan_merge(id_gen, materializations, merge),
             LogicalOperator::CopyTo(copy_to) => {
                 self.plan_copy_to(id_gen, materializations, copy_to)
             }
@@ -266,6 +278,12 @@ impl<'a> IntermediatePipelineBuildState<'a> {
                 self.plan_set_operation(id_gen, materializations, setop)
             }
             LogicalOperator::InOut(inout) => self.plan_inout(id_gen, materializations, inout),
+            LogicalOperator::RecursiveCte(cte) => {
+                self.plan_recursive_cte(id_gen, materializations, cte)
+            }
+            LogicalOperator::RecursiveCteScan(scan) => {
+                self.plan_recursive_cte_scan(id_gen, materializations, scan)
+            }
             LogicalOperator::SetVar(_) => {
                 Err(RayexecError::new("SET should be handled in the session"))
             }
@@ -63,6 +63,12 @@ impl IntermediatePipelineBuildState<'_> {
         }
 
         // Place group by expressions in pre-projection as well.
+        let group_types = agg
+            .node
+            .group_exprs
+            .iter()
+            .map(|expr| expr.datatype(self.bind_context.get_table_list()))
+            .collect::<Result<Vec<_>>>()?;
         for group_expr in agg.node.group_exprs {
             let scalar = self
                 .expr_planner
@@ -93,6 +99,7 @@ impl IntermediatePipelineBuildState<'_> {
                     operator: Arc::new(PhysicalOperator::HashAggregate(
                         PhysicalHashAggregate::new(
                             phys_aggs,
+                            group_types,
                             grouping_sets,
                             agg.node.grouping_functions,
                         ),
@@ -50,6 +50,7 @@ impl IntermediatePipelineBuildState<'_> {
             IntermediateOperator {
                 operator: Arc::new(PhysicalOperator::HashAggregate(PhysicalHashAggregate::new(
                     Vec::new(),
+                    group_types,
                     grouping_sets,
                     Vec::new(),
                 ))),
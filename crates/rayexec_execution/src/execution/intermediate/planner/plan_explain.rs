@@ -1,12 +1,13 @@
 use std::sync::Arc;
 
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{RayexecError, Result};
 use tracing::error;
 
 use super::{InProgressPipeline, IntermediatePipelineBuildState, Materializations, PipelineIdGen};
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
 use crate::execution::intermediate::pipeline::{IntermediateOperator, PipelineSource};
+use crate::execution::operators::analyze::PhysicalAnalyze;
 use crate::execution::operators::values::PhysicalValues;
 use crate::execution::operators::PhysicalOperator;
 use crate::explain::context_display::ContextDisplayMode;
@@ -29,7 +30,19 @@ impl IntermediatePipelineBuildState<'_> {
         }
 
         if explain.node.analyze {
-            not_implemented!("explain analyze")
+            // Plan the input as part of the real query so it actually
+            // executes. The analyze operator discards the output, and the
+            // session renders the profile data once execution completes.
+            let input = explain.take_one_child_exact()?;
+            self.walk(materializations, id_gen, input)?;
+
+            let operator = IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::Analyze(PhysicalAnalyze)),
+                partitioning_requirement: None,
+            };
+            self.push_intermediate_operator(operator, location, id_gen)?;
+
+            return Ok(());
         }
 
         // Plan in seperate planner to avoid conmingling pipelines we will be
@@ -1,6 +1,6 @@
 use std::sync::Arc;
 
-use rayexec_error::{not_implemented, RayexecError, Result, ResultExt};
+use rayexec_error::{RayexecError, Result, ResultExt};
 
 use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
 use crate::execution::intermediate::pipeline::IntermediateOperator;
@@ -153,11 +153,9 @@ impl IntermediatePipelineBuildState<'_> {
         } else {
             // Need to fall back to nested loop join.
 
-            if join.node.join_type != JoinType::Inner {
-                not_implemented!("join type with nl join: {}", join.node.join_type);
-            }
-
-            let table_refs = join.get_output_table_refs(self.bind_context);
+            // Conditions are evaluated on the output of the cross product, so
+            // plan against the table refs from both children.
+            let table_refs = join.get_children_table_refs(self.bind_context);
             let [left, right] = join.take_two_children_exact()?;
 
             let condition = expr::and(
@@ -204,17 +202,6 @@ impl IntermediatePipelineBuildState<'_> {
             )
             .context("Failed to plan expressions arbitrary join filter")?;
 
-        // Modify the filter as to match the join type.
-        let filter = match join.node.join_type {
-            JoinType::Inner => filter,
-            other => {
-                // TODO: Other join types.
-                return Err(RayexecError::new(format!(
-                    "Unhandled join type for arbitrary join: {other:?}"
-                )));
-            }
-        };
-
         let [left, right] = join.take_two_children_exact()?;
 
         self.push_nl_join(
@@ -266,6 +253,18 @@ impl IntermediatePipelineBuildState<'_> {
     ) -> Result<()> {
         self.config.check_nested_loop_join_allowed()?;
 
+        let mut left_types = Vec::new();
+        for table_ref in left.get_output_table_refs(self.bind_context) {
+            let table = self.bind_context.get_table(table_ref)?;
+            left_types.extend(table.column_types.iter().cloned());
+        }
+
+        let mut right_types = Vec::new();
+        for table_ref in right.get_output_table_refs(self.bind_context) {
+            let table = self.bind_context.get_table(table_ref)?;
+            right_types.extend(table.column_types.iter().cloned());
+        }
+
         // Continue to build up all the inputs into the right side.
         self.walk(materializations, id_gen, right)?;
 
@@ -289,7 +288,7 @@ impl IntermediatePipelineBuildState<'_> {
 
         let operator = IntermediateOperator {
             operator: Arc::new(PhysicalOperator::NestedLoopJoin(
-                PhysicalNestedLoopJoin::new(filter, join_type),
+                PhysicalNestedLoopJoin::new(filter, join_type, left_types, right_types),
             )),
             partitioning_requirement: None,
         };
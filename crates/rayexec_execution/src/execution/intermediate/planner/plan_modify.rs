@@ -0,0 +1,96 @@
+use std::sync::Arc;
+
+use rayexec_error::Result;
+
+use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::IntermediateOperator;
+use crate::execution::operators::delete::DeleteOperation;
+use crate::execution::operators::merge::MergeOperation;
+use crate::execution::operators::sink::SinkOperator;
+use crate::execution::operators::update::UpdateOperation;
+use crate::execution::operators::PhysicalOperator;
+use crate::logical::logical_modify::{LogicalDelete, LogicalMerge, LogicalUpdate};
+use crate::logical::operator::Node;
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_update(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut update: Node<LogicalUpdate>,
+    ) -> Result<()> {
+        let location = update.location;
+        let input = update.take_one_child_exact()?;
+
+        self.walk(materializations, id_gen, input)?;
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Update(SinkOperator::new(
+                UpdateOperation {
+                    catalog: update.node.catalog,
+                    schema: update.node.schema,
+                    table: update.node.table,
+                },
+            ))),
+            partitioning_requirement: None,
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
+
+    pub fn plan_delete(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut delete: Node<LogicalDelete>,
+    ) -> Result<()> {
+        let location = delete.location;
+        let input = delete.take_one_child_exact()?;
+
+        self.walk(materializations, id_gen, input)?;
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Delete(SinkOperator::new(
+                DeleteOperation {
+                    catalog: delete.node.catalog,
+                    schema: delete.node.schema,
+                    table: delete.node.table,
+                },
+            ))),
+            partitioning_requirement: None,
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
+
+    pub fn plan_merge(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut merge: Node<LogicalMerge>,
+    ) -> Result<()> {
+        let location = merge.location;
+        let input = merge.take_one_child_exact()?;
+
+        self.walk(materializations, id_gen, input)?;
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::Merge(SinkOperator::new(MergeOperation {
+                catalog: merge.node.catalog,
+                schema: merge.node.schema,
+                table: merge.node.table,
+                actions: merge.node.actions,
+                insert_conflict: merge.node.insert_conflict,
+            }))),
+            partitioning_requirement: None,
+        };
+
+        self.push_intermediate_operator(operator, location, id_gen)?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,149 @@
+use std::sync::Arc;
+
+use rayexec_error::{not_implemented, RayexecError, Result};
+
+use super::{InProgressPipeline, IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::{
+    IntermediateOperator,
+    IntermediatePipeline,
+    PipelineSink,
+    PipelineSource,
+};
+use crate::execution::operators::recursive_cte::{
+    PhysicalRecursiveCte,
+    WorkingTable,
+    WorkingTableScan,
+    WorkingTableSink,
+};
+use crate::execution::operators::sink::SinkOperator;
+use crate::execution::operators::source::SourceOperator;
+use crate::execution::operators::PhysicalOperator;
+use crate::logical::logical_recursive_cte::{LogicalRecursiveCte, LogicalRecursiveCteScan};
+use crate::logical::operator::{LocationRequirement, Node};
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_recursive_cte(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut cte: Node<LogicalRecursiveCte>,
+    ) -> Result<()> {
+        let location = cte.location;
+
+        let [anchor, recursive] = cte.take_two_children_exact()?;
+
+        // Plan the recursive term as its own group of pipelines. These
+        // pipelines get executed by the recursive CTE operator once per
+        // iteration.
+        let working_table = Arc::new(WorkingTable::default());
+        materializations
+            .working_tables
+            .insert(cte.node.working_table, working_table.clone());
+
+        let mut recursive_builder =
+            IntermediatePipelineBuildState::new(self.config, self.bind_context);
+        let result = recursive_builder.walk(materializations, id_gen, recursive);
+        materializations
+            .working_tables
+            .remove(&cte.node.working_table);
+        result?;
+
+        recursive_builder.push_intermediate_operator(
+            IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::DynSink(SinkOperator::new(Box::new(
+                    WorkingTableSink {
+                        working_table: working_table.clone(),
+                    },
+                )))),
+                partitioning_requirement: None,
+            },
+            LocationRequirement::ClientLocal,
+            id_gen,
+        )?;
+
+        let in_progress = recursive_builder.take_in_progress_pipeline()?;
+        if in_progress.location == LocationRequirement::Remote
+            || !recursive_builder.remote_group.is_empty()
+        {
+            not_implemented!("Remote execution of the recursive term of a recursive CTE");
+        }
+
+        let mut recursive_group = recursive_builder.local_group;
+        recursive_group.pipelines.insert(
+            in_progress.id,
+            IntermediatePipeline {
+                id: in_progress.id,
+                sink: PipelineSink::InPipeline,
+                source: in_progress.source,
+                operators: in_progress.operators,
+            },
+        );
+
+        if recursive_group
+            .pipelines
+            .values()
+            .any(|pipeline| matches!(pipeline.source, PipelineSource::Materialization { .. }))
+        {
+            not_implemented!("Materializations within the recursive term of a recursive CTE");
+        }
+
+        // Anchor feeds directly into the recursive CTE operator.
+        self.walk(materializations, id_gen, anchor)?;
+
+        self.push_intermediate_operator(
+            IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::RecursiveCte(PhysicalRecursiveCte {
+                    all: cte.node.all,
+                    max_iterations: self.config.max_recursive_cte_iterations,
+                    working_table,
+                    recursive: recursive_group,
+                })),
+                partitioning_requirement: None,
+            },
+            location,
+            id_gen,
+        )?;
+
+        Ok(())
+    }
+
+    pub fn plan_recursive_cte_scan(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        scan: Node<LogicalRecursiveCteScan>,
+    ) -> Result<()> {
+        let working_table = materializations
+            .working_tables
+            .get(&scan.node.working_table)
+            .ok_or_else(|| {
+                RayexecError::new(format!(
+                    "Missing working table for ref: {}",
+                    scan.node.working_table
+                ))
+            })?
+            .clone();
+
+        if self.in_progress.is_some() {
+            return Err(RayexecError::new(
+                "Expected in progress to be None for recursive CTE scan",
+            ));
+        }
+
+        let operator = IntermediateOperator {
+            operator: Arc::new(PhysicalOperator::DynSource(SourceOperator::new(Box::new(
+                WorkingTableScan { working_table },
+            )))),
+            partitioning_requirement: None,
+        };
+
+        self.in_progress = Some(InProgressPipeline {
+            id: id_gen.next_pipeline_id(),
+            operators: vec![operator],
+            location: scan.location,
+            source: PipelineSource::InPipeline,
+        });
+
+        Ok(())
+    }
+}
@@ -38,20 +38,20 @@ impl IntermediatePipelineBuildState<'_> {
                 catalog,
                 schema,
                 source,
+                row_ids,
             } => IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::Scan(PhysicalScan::new(
-                    catalog,
-                    schema,
-                    source,
-                    projections,
-                ))),
+                operator: Arc::new(PhysicalOperator::Scan(
+                    PhysicalScan::new(catalog, schema, source, projections)
+                        .with_filters(scan.node.scan_filters)
+                        .with_row_ids(row_ids),
+                )),
                 partitioning_requirement: None,
             },
             ScanSource::TableFunction { function } => IntermediateOperator {
-                operator: Arc::new(PhysicalOperator::TableFunction(PhysicalTableFunction::new(
-                    function,
-                    projections,
-                ))),
+                operator: Arc::new(PhysicalOperator::TableFunction(
+                    PhysicalTableFunction::new(function, projections)
+                        .with_filters(scan.node.scan_filters),
+                )),
                 partitioning_requirement: None,
             },
             ScanSource::ExpressionList { rows } => {
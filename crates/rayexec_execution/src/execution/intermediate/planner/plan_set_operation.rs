@@ -63,13 +63,15 @@ impl IntermediatePipelineBuildState<'_> {
 
             let grouping_sets = vec![(0..output_types.len()).collect()];
 
-            let operator =
-                IntermediateOperator {
-                    operator: Arc::new(PhysicalOperator::HashAggregate(
-                        PhysicalHashAggregate::new(Vec::new(), grouping_sets, Vec::new()),
-                    )),
-                    partitioning_requirement: None,
-                };
+            let operator = IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::HashAggregate(PhysicalHashAggregate::new(
+                    Vec::new(),
+                    output_types,
+                    grouping_sets,
+                    Vec::new(),
+                ))),
+                partitioning_requirement: None,
+            };
 
             self.push_intermediate_operator(operator, location, id_gen)?;
         }
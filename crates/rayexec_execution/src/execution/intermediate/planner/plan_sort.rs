@@ -11,6 +11,7 @@ use crate::execution::intermediate::pipeline::{
 };
 use crate::execution::operators::sort::gather_sort::PhysicalGatherSort;
 use crate::execution::operators::sort::scatter_sort::PhysicalScatterSort;
+use crate::execution::operators::sort::top_k::PhysicalTopK;
 use crate::execution::operators::PhysicalOperator;
 use crate::logical::logical_order::LogicalOrder;
 use crate::logical::operator::{LocationRequirement, LogicalNode, Node};
@@ -32,32 +33,49 @@ impl IntermediatePipelineBuildState<'_> {
             .expr_planner
             .plan_sorts(&input_refs, &order.node.exprs)?;
 
-        // Resize input batches.
-        //
-        // The local sort is going to be converting things into a row
-        // represenations so better to do that on large batches.
-        self.push_batch_resizer(id_gen)?;
+        match order.node.limit {
+            Some(limit) => {
+                // Limit on top of the order, each partition only needs to keep
+                // its top rows before merging into a single partition.
+                let operator = IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::TopK(PhysicalTopK::new(
+                        exprs,
+                        limit.limit,
+                        limit.offset,
+                    ))),
+                    partitioning_requirement: None,
+                };
+                self.push_intermediate_operator(operator, location, id_gen)?;
+            }
+            None => {
+                // Resize input batches.
+                //
+                // The local sort is going to be converting things into a row
+                // represenations so better to do that on large batches.
+                self.push_batch_resizer(id_gen)?;
 
-        // Partition-local sorting.
-        let operator = IntermediateOperator {
-            operator: Arc::new(PhysicalOperator::LocalSort(PhysicalScatterSort::new(
-                exprs.clone(),
-            ))),
-            partitioning_requirement: None,
-        };
-        self.push_intermediate_operator(operator, location, id_gen)?;
+                // Partition-local sorting.
+                let operator = IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::LocalSort(PhysicalScatterSort::new(
+                        exprs.clone(),
+                    ))),
+                    partitioning_requirement: None,
+                };
+                self.push_intermediate_operator(operator, location, id_gen)?;
 
-        // Global sorting.
-        let operator = IntermediateOperator {
-            operator: Arc::new(PhysicalOperator::MergeSorted(PhysicalGatherSort::new(
-                exprs,
-            ))),
-            partitioning_requirement: None,
-        };
-        self.push_intermediate_operator(operator, location, id_gen)?;
+                // Global sorting.
+                let operator = IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::MergeSorted(PhysicalGatherSort::new(
+                        exprs,
+                    ))),
+                    partitioning_requirement: None,
+                };
+                self.push_intermediate_operator(operator, location, id_gen)?;
+            }
+        }
 
-        // Global sorting accepts n-partitions, but produces only a single
-        // partition. We finish the current pipeline
+        // Global sorting and TopK accept n-partitions, but produce only a
+        // single partition. We finish the current pipeline
 
         let in_progress = self.take_in_progress_pipeline()?;
         self.in_progress = Some(InProgressPipeline {
@@ -0,0 +1,242 @@
+use std::sync::Arc;
+
+use rayexec_error::{RayexecError, Result, ResultExt};
+
+use super::{IntermediatePipelineBuildState, Materializations, PipelineIdGen};
+use crate::execution::intermediate::pipeline::IntermediateOperator;
+use crate::execution::operators::project::{PhysicalProject, ProjectOperation};
+use crate::execution::operators::window::PhysicalWindow;
+use crate::execution::operators::PhysicalOperator;
+use crate::expr::physical::column_expr::PhysicalColumnExpr;
+use crate::expr::physical::{
+    PhysicalScalarExpression,
+    PhysicalSortExpression,
+    PhysicalWindowExpression,
+    PhysicalWindowFrameBound,
+};
+use crate::expr::window_expr::{WindowExpr, WindowFrameBound, WindowFrameUnit};
+use crate::expr::Expression;
+use crate::logical::logical_window::LogicalWindow;
+use crate::logical::operator::{LogicalNode, Node};
+
+/// Column indices for a single window expression in the pre-projection.
+#[derive(Debug)]
+struct PreprojectedWindow {
+    window: WindowExpr,
+    partition_by: Vec<PhysicalColumnExpr>,
+    order_by: Vec<PhysicalSortExpression>,
+    inputs: Vec<PhysicalColumnExpr>,
+    start: PhysicalWindowFrameBound,
+    end: PhysicalWindowFrameBound,
+}
+
+impl IntermediatePipelineBuildState<'_> {
+    pub fn plan_window(
+        &mut self,
+        id_gen: &mut PipelineIdGen,
+        materializations: &mut Materializations,
+        mut window: Node<LogicalWindow>,
+    ) -> Result<()> {
+        let location = window.location;
+
+        let input = window.take_one_child_exact()?;
+        let input_refs = input.get_output_table_refs(self.bind_context);
+        self.walk(materializations, id_gen, input)?;
+
+        let mut num_input_cols = 0;
+        for table_ref in &input_refs {
+            num_input_cols += self.bind_context.get_table(*table_ref)?.num_columns();
+        }
+
+        // Pre-projection containing all input columns, followed by the
+        // partition, order by, and function inputs for each window.
+        let mut preproject_exprs: Vec<_> = (0..num_input_cols)
+            .map(|idx| PhysicalScalarExpression::Column(PhysicalColumnExpr { idx }))
+            .collect();
+
+        let mut preprojected = Vec::with_capacity(window.node.windows.len());
+        for expr in window.node.windows {
+            let window = match expr {
+                Expression::Window(window) => window,
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Expected window expression, got: {other}"
+                    )))
+                }
+            };
+
+            let mut push_scalar = |expr: &Expression| -> Result<PhysicalColumnExpr> {
+                let scalar = self
+                    .expr_planner
+                    .plan_scalar(&input_refs, expr)
+                    .context("Failed to plan expressions for window pre-projection")?;
+                preproject_exprs.push(scalar);
+                Ok(PhysicalColumnExpr {
+                    idx: preproject_exprs.len() - 1,
+                })
+            };
+
+            let partition_by = window
+                .partition_by
+                .iter()
+                .map(&mut push_scalar)
+                .collect::<Result<Vec<_>>>()?;
+
+            let order_by = window
+                .order_by
+                .iter()
+                .map(|order_by| {
+                    Ok(PhysicalSortExpression {
+                        column: push_scalar(&order_by.expr)?,
+                        desc: order_by.desc,
+                        nulls_first: order_by.nulls_first,
+                        collation: order_by.collation,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            let inputs = window
+                .function
+                .inputs()
+                .iter()
+                .map(&mut push_scalar)
+                .collect::<Result<Vec<_>>>()?;
+
+            // RANGE offsets have their boundary values computed as part of the
+            // pre-projection.
+            let mut plan_bound = |bound: &WindowFrameBound| -> Result<PhysicalWindowFrameBound> {
+                Ok(match bound {
+                    WindowFrameBound::UnboundedPreceding(_) => {
+                        PhysicalWindowFrameBound::UnboundedPreceding
+                    }
+                    WindowFrameBound::UnboundedFollowing(_) => {
+                        PhysicalWindowFrameBound::UnboundedFollowing
+                    }
+                    WindowFrameBound::CurrentRow(_) => PhysicalWindowFrameBound::CurrentRow,
+                    WindowFrameBound::Preceding(WindowFrameUnit::Range, expr)
+                    | WindowFrameBound::Following(WindowFrameUnit::Range, expr) => {
+                        PhysicalWindowFrameBound::RangeOffset(push_scalar(expr)?)
+                    }
+                    WindowFrameBound::Preceding(_, expr) => {
+                        PhysicalWindowFrameBound::Preceding(frame_offset(expr)?)
+                    }
+                    WindowFrameBound::Following(_, expr) => {
+                        PhysicalWindowFrameBound::Following(frame_offset(expr)?)
+                    }
+                })
+            };
+
+            let start = plan_bound(&window.start)?;
+            let end = plan_bound(&window.end)?;
+
+            preprojected.push(PreprojectedWindow {
+                window,
+                partition_by,
+                order_by,
+                inputs,
+                start,
+                end,
+            });
+        }
+
+        let num_preprojected_cols = preproject_exprs.len();
+
+        self.push_intermediate_operator(
+            IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::Project(PhysicalProject {
+                    operation: ProjectOperation::new(preproject_exprs),
+                })),
+                partitioning_requirement: None,
+            },
+            location,
+            id_gen,
+        )?;
+
+        // Group windows that share the same partitioning and ordering. Each
+        // group gets its own window operator.
+        let mut groups: Vec<Vec<usize>> = Vec::new();
+        for (idx, window) in preprojected.iter().enumerate() {
+            let existing = groups.iter_mut().find(|group| {
+                let other = &preprojected[group[0]].window;
+                other.partition_by == window.window.partition_by
+                    && other.order_by == window.window.order_by
+            });
+
+            match existing {
+                Some(group) => group.push(idx),
+                None => groups.push(vec![idx]),
+            }
+        }
+
+        // Output column index for each window after all window operators have
+        // been applied.
+        let mut output_cols = vec![0; preprojected.len()];
+        let mut next_output_col = num_preprojected_cols;
+
+        let mut preprojected: Vec<_> = preprojected.into_iter().map(Some).collect();
+        for group in groups {
+            let first = preprojected[group[0]].as_ref().expect("window to exist");
+            let partition_by = first.partition_by.clone();
+            let order_by = first.order_by.clone();
+
+            let mut functions = Vec::with_capacity(group.len());
+            for idx in group {
+                let window = preprojected[idx].take().expect("window to exist");
+                functions.push(PhysicalWindowExpression {
+                    function: window.window.function,
+                    columns: window.inputs,
+                    unit: window.window.start.unit(),
+                    start: window.start,
+                    end: window.end,
+                    exclude: window.window.exclude,
+                });
+
+                output_cols[idx] = next_output_col;
+                next_output_col += 1;
+            }
+
+            self.push_intermediate_operator(
+                IntermediateOperator {
+                    operator: Arc::new(PhysicalOperator::Window(PhysicalWindow::new(
+                        partition_by,
+                        order_by,
+                        functions,
+                    ))),
+                    partitioning_requirement: None,
+                },
+                location,
+                id_gen,
+            )?;
+        }
+
+        // Project out the original input columns followed by the window
+        // outputs in the order the logical operator expects.
+        let project_exprs = (0..num_input_cols)
+            .chain(output_cols)
+            .map(|idx| PhysicalScalarExpression::Column(PhysicalColumnExpr { idx }))
+            .collect();
+
+        self.push_intermediate_operator(
+            IntermediateOperator {
+                operator: Arc::new(PhysicalOperator::Project(PhysicalProject {
+                    operation: ProjectOperation::new(project_exprs),
+                })),
+                partitioning_requirement: None,
+            },
+            location,
+            id_gen,
+        )?;
+
+        Ok(())
+    }
+}
+
+/// Get the ROWS or GROUPS offset from a bound frame offset expression.
+fn frame_offset(expr: &Expression) -> Result<usize> {
+    match expr {
+        Expression::Literal(lit) => lit.literal.try_as_usize(),
+        other => Err(RayexecError::new(format!(
+            "Expected literal for window frame offset, got {other}"
+        ))),
+    }
+}
@@ -1,10 +1,12 @@
-use std::task::Context;
+use std::sync::Arc;
+use std::task::{Context, Waker};
 
 use rayexec_error::Result;
 
 use super::{
     ExecutableOperator,
     ExecutionStates,
+    InputOutputStates,
     OperatorState,
     PartitionState,
     PollFinalize,
@@ -15,17 +17,43 @@ use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 
+#[derive(Debug)]
+pub struct AnalyzePartitionState {
+    /// If inputs are finished.
+    finished: bool,
+
+    /// Waker on pull side if input isn't finished.
+    pull_waker: Option<Waker>,
+}
+
 /// Physical operator for EXPLAIN ANALYZE.
+///
+/// Sits at the top of the plan being analyzed and discards all batches pushed
+/// to it. The query still runs to completion, but no output is sent to the
+/// client. The session then renders the collected profile data as the output
+/// of the EXPLAIN.
 #[derive(Debug)]
-pub struct PhysicalAnalyze {}
+pub struct PhysicalAnalyze;
 
 impl ExecutableOperator for PhysicalAnalyze {
     fn create_states(
         &self,
         _context: &DatabaseContext,
-        _partitions: Vec<usize>,
+        partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
-        unimplemented!()
+        Ok(ExecutionStates {
+            operator_state: Arc::new(OperatorState::None),
+            partition_states: InputOutputStates::OneToOne {
+                partition_states: (0..partitions[0])
+                    .map(|_| {
+                        PartitionState::Analyze(AnalyzePartitionState {
+                            finished: false,
+                            pull_waker: None,
+                        })
+                    })
+                    .collect(),
+            },
+        })
     }
 
     fn poll_push(
@@ -35,25 +63,47 @@ impl ExecutableOperator for PhysicalAnalyze {
         _operator_state: &OperatorState,
         _batch: Batch,
     ) -> Result<PollPush> {
-        unimplemented!()
+        // Row/batch counts are already tracked by the pipeline, nothing to do
+        // with the batch itself.
+        Ok(PollPush::NeedsMore)
     }
 
     fn poll_finalize_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Analyze(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        state.finished = true;
+        if let Some(waker) = state.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
     }
 
     fn poll_pull(
         &self,
-        _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
     ) -> Result<PollPull> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Analyze(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.finished {
+            return Ok(PollPull::Exhausted);
+        }
+
+        state.pull_waker = Some(cx.waker().clone());
+        Ok(PollPull::Pending)
     }
 }
 
@@ -8,6 +8,7 @@ use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::functions::copy::CopyToFunction;
 use crate::proto::DatabaseProtoConv;
+use crate::storage::hive::HivePartitionedSink;
 
 pub type PhysicalCopyTo = SinkOperator<CopyToOperation>;
 
@@ -16,6 +17,9 @@ pub struct CopyToOperation {
     pub copy_to: Box<dyn CopyToFunction>,
     pub location: FileLocation,
     pub schema: Schema,
+    /// Columns to partition by, writing a hive partitioned directory tree to
+    /// the location.
+    pub partition_by: Vec<usize>,
 }
 
 impl SinkOperation for CopyToOperation {
@@ -24,8 +28,30 @@ impl SinkOperation for CopyToOperation {
         _context: &DatabaseContext,
         num_sinks: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
-        self.copy_to
-            .create_sinks(self.schema.clone(), self.location.clone(), num_sinks)
+        if self.partition_by.is_empty() {
+            return self.copy_to.create_sinks(
+                self.schema.clone(),
+                self.location.clone(),
+                num_sinks,
+            );
+        }
+
+        (0..num_sinks)
+            .map(|idx| {
+                let file_name = match self.copy_to.file_extension() {
+                    Some(ext) => format!("data_{idx}.{ext}"),
+                    None => format!("data_{idx}"),
+                };
+                let sink = HivePartitionedSink::try_new(
+                    self.copy_to.clone(),
+                    self.location.clone(),
+                    &self.schema,
+                    self.partition_by.clone(),
+                    file_name,
+                )?;
+                Ok(Box::new(sink) as _)
+            })
+            .collect()
     }
 
     fn partition_requirement(&self) -> Option<usize> {
@@ -36,7 +62,16 @@ impl SinkOperation for CopyToOperation {
 
 impl Explainable for CopyToOperation {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("CopyTo").with_value("location", &self.location)
+        let ent = ExplainEntry::new("CopyTo").with_value("location", &self.location);
+        if self.partition_by.is_empty() {
+            ent
+        } else {
+            let names = self
+                .partition_by
+                .iter()
+                .map(|&idx| &self.schema.fields[idx].name);
+            ent.with_values("partition_by", names)
+        }
     }
 }
 
@@ -48,6 +83,12 @@ impl DatabaseProtoConv for PhysicalCopyTo {
             copy_to: Some(self.sink.copy_to.to_proto_ctx(context)?),
             location: Some(self.sink.location.to_proto()?),
             schema: Some(self.sink.schema.to_proto()?),
+            partition_by: self
+                .sink
+                .partition_by
+                .iter()
+                .map(|&idx| idx as u32)
+                .collect(),
         })
     }
 
@@ -59,6 +100,11 @@ impl DatabaseProtoConv for PhysicalCopyTo {
             )?,
             location: ProtoConv::from_proto(proto.location.required("location")?)?,
             schema: ProtoConv::from_proto(proto.schema.required("schema")?)?,
+            partition_by: proto
+                .partition_by
+                .into_iter()
+                .map(|idx| idx as usize)
+                .collect(),
         }))
     }
 }
@@ -7,7 +7,6 @@ use rayexec_proto::ProtoConv;
 use super::sink::{PartitionSink, SinkOperation, SinkOperator};
 use super::util::barrier::PartitionBarrier;
 use crate::arrays::batch::Batch;
-use crate::database::catalog::CatalogTx;
 use crate::database::create::CreateTableInfo;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -30,8 +29,7 @@ impl SinkOperation for CreateTableSinkOperation {
         context: &DatabaseContext,
         num_sinks: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
-        // TODO: Placeholder.
-        let tx = CatalogTx::new();
+        let tx = context.catalog_tx().clone();
 
         let database = context.get_database(&self.catalog)?;
         let table_storage = database
@@ -56,7 +54,7 @@ impl SinkOperation for CreateTableSinkOperation {
         let create_table_fut = Box::pin(async move {
             let table_ent = schema_ent.create_table(&tx, &info)?;
             let datatable = table_storage
-                .create_physical_table(&schema_ent.entry().name, &table_ent)
+                .create_physical_table(&tx, &schema_ent.entry().name, &table_ent)
                 .await?;
 
             Ok(datatable)
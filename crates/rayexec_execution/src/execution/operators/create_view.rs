@@ -17,7 +17,6 @@ use super::{
     PollPush,
 };
 use crate::arrays::batch::Batch;
-use crate::database::catalog::CatalogTx;
 use crate::database::create::CreateViewInfo;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -52,8 +51,7 @@ impl ExecutableOperator for PhysicalCreateView {
             ));
         }
 
-        // TODO: Placeholder.
-        let tx = CatalogTx::new();
+        let tx = context.catalog_tx().clone();
 
         let database = context.get_database(&self.catalog)?;
         let schema_ent = database
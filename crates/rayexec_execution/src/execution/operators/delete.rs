@@ -0,0 +1,50 @@
+use std::sync::Arc;
+
+use rayexec_error::{RayexecError, Result};
+
+use super::sink::{PartitionSink, SinkOperation, SinkOperator};
+use crate::database::catalog_entry::CatalogEntry;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+
+pub type PhysicalDelete = SinkOperator<DeleteOperation>;
+
+#[derive(Debug)]
+pub struct DeleteOperation {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+}
+
+impl SinkOperation for DeleteOperation {
+    fn create_partition_sinks(
+        &self,
+        context: &DatabaseContext,
+        num_sinks: usize,
+    ) -> Result<Vec<Box<dyn PartitionSink>>> {
+        let database = context.get_database(&self.catalog)?;
+        let data_table = database
+            .table_storage
+            .as_ref()
+            .ok_or_else(|| RayexecError::new("Missing table storage for delete"))?
+            .data_table(context.catalog_tx(), &self.schema, &self.table)?;
+
+        let deletes = data_table
+            .delete(num_sinks)?
+            .into_iter()
+            .map(|delete| delete as Box<dyn PartitionSink>)
+            .collect();
+
+        Ok(deletes)
+    }
+
+    fn partition_requirement(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Explainable for DeleteOperation {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Delete").with_value("table", &self.table.name)
+    }
+}
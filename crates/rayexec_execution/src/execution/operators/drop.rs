@@ -18,7 +18,6 @@ use super::{
     PollPush,
 };
 use crate::arrays::batch::Batch;
-use crate::database::catalog::CatalogTx;
 use crate::database::drop::DropInfo;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -56,8 +55,7 @@ impl ExecutableOperator for PhysicalDrop {
             return Err(RayexecError::new("Drop can only handle one partition"));
         }
 
-        // TODO: Placeholder.
-        let tx = CatalogTx::new();
+        let tx = context.catalog_tx().clone();
 
         let catalog = context.get_database(&self.catalog)?.catalog.clone();
         let info = self.info.clone();
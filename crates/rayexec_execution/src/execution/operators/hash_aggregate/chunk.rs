@@ -6,6 +6,7 @@ use crate::arrays::array::physical_type::PhysicalType;
 use crate::arrays::array::Array;
 use crate::arrays::executor::scalar::concat;
 use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
+use crate::execution::operators::util::spill::array_size_bytes;
 use crate::functions::aggregate::ChunkGroupAddressIter;
 
 /// Holds a chunk of value for the aggregate hash table.
@@ -46,6 +47,19 @@ impl GroupChunk {
         true
     }
 
+    /// Estimated number of bytes used by the group values and aggregate states
+    /// in this chunk.
+    pub fn size_bytes(&self) -> usize {
+        let arrays_size: usize = self.arrays.iter().map(array_size_bytes).sum();
+        let states_size: usize = self
+            .aggregate_states
+            .iter()
+            .map(|states| states.states.size_bytes())
+            .sum();
+
+        self.hashes.capacity() * std::mem::size_of::<u64>() + arrays_size + states_size
+    }
+
     /// Appends group values to this chunk, instantating all necessary aggregate
     /// states.
     pub fn append_group_values(
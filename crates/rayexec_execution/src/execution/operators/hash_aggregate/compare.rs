@@ -158,6 +158,9 @@ where
             PhysicalType::Utf8 => {
                 compare_rows_eq::<PhysicalUtf8, _, _>(array1, array2, rows1, rows2, not_eq_rows)?
             }
+            PhysicalType::Struct => {
+                compare_nested_rows_eq(array1, array2, rows1, rows2, not_eq_rows)?
+            }
             other => not_implemented!("Row compare: {other}"),
         }
     }
@@ -236,3 +239,26 @@ where
 
     Ok(())
 }
+
+/// Compares rows for nested arrays using their logical scalar values.
+///
+/// Like `compare_rows_eq`, NULLs are considered equal to each other.
+fn compare_nested_rows_eq<I1, I2>(
+    array1: &Array,
+    array2: &Array,
+    rows1: I1,
+    rows2: I2,
+    not_eq_rows: &mut BTreeSet<usize>,
+) -> Result<()>
+where
+    I1: Iterator<Item = usize>,
+    I2: Iterator<Item = usize>,
+{
+    for (row1, row2) in rows1.zip(rows2) {
+        if array1.logical_value(row1)? != array2.logical_value(row2)? {
+            not_eq_rows.insert(row1);
+        }
+    }
+
+    Ok(())
+}
@@ -1,9 +1,11 @@
+use std::any::Any;
 use std::sync::Arc;
 
-use rayexec_error::Result;
+use rayexec_error::{not_implemented, Result};
 
 use super::hash_table::HashTable;
 use crate::arrays::array::Array;
+use crate::arrays::executor::aggregate::RowToStateMapping;
 use crate::arrays::executor::scalar::HashExecutor;
 use crate::arrays::selection::SelectionVector;
 use crate::execution::operators::hash_aggregate::hash_table::GroupAddress;
@@ -40,6 +42,10 @@ impl AggregateGroupStates for DistinctGroupedStates {
         OpaqueStatesMut(&mut self.distinct_inputs)
     }
 
+    fn opaque_states(&self) -> &dyn Any {
+        &self.distinct_inputs
+    }
+
     fn new_states(&mut self, count: usize) {
         // Hash tables created with empty aggregates.
         self.distinct_inputs
@@ -50,6 +56,18 @@ impl AggregateGroupStates for DistinctGroupedStates {
         self.distinct_inputs.len()
     }
 
+    fn size_bytes(&self) -> usize {
+        let tables_size: usize = self
+            .distinct_inputs
+            .iter()
+            .flatten()
+            .map(|table| table.size_bytes())
+            .sum();
+        self.distinct_inputs.capacity() * std::mem::size_of::<Option<HashTable>>()
+            + tables_size
+            + self.states.size_bytes()
+    }
+
     fn update_states(&mut self, inputs: &[&Array], mapping: ChunkGroupAddressIter) -> Result<()> {
         // TODO: Would be cool not needing to do this.
         let mappings: Vec<_> = mapping.collect();
@@ -114,6 +132,14 @@ impl AggregateGroupStates for DistinctGroupedStates {
         Ok(())
     }
 
+    fn combine_from(
+        &mut self,
+        _other: &dyn AggregateGroupStates,
+        _mapping: &[RowToStateMapping],
+    ) -> Result<()> {
+        not_implemented!("combine distinct aggregate states without consuming")
+    }
+
     fn finalize(&mut self) -> Result<Array> {
         // And now we actually create the states we need.
         self.states.new_states(self.distinct_inputs.len());
@@ -86,6 +86,28 @@ impl HashTable {
         self.entries.len()
     }
 
+    /// Number of groups in the table.
+    pub fn num_groups(&self) -> usize {
+        self.num_occupied
+    }
+
+    /// Estimated number of bytes used by the table.
+    pub fn size_bytes(&self) -> usize {
+        let chunks_size: usize = self.chunks.iter().map(|chunk| chunk.size_bytes()).sum();
+        self.entries.len() * std::mem::size_of::<EntryKey<GroupAddress>>() + chunks_size
+    }
+
+    /// Estimate the number of bytes the table would grow by if every input row
+    /// created a new group, with each group's values and states taking up
+    /// `row_size` bytes.
+    pub fn estimate_insert_bytes(&self, num_inputs: usize, row_size: usize) -> usize {
+        let new_capacity = self.capacity_for(num_inputs);
+        let entries_growth =
+            (new_capacity - self.capacity()) * std::mem::size_of::<EntryKey<GroupAddress>>();
+
+        entries_growth + num_inputs * (row_size + std::mem::size_of::<u64>())
+    }
+
     pub fn insert(&mut self, groups: &[Array], hashes: &[u64], inputs: &[Array]) -> Result<()> {
         // Find and create groups as needed.
         self.find_or_create_groups(groups, hashes)?;
@@ -110,6 +132,67 @@ impl HashTable {
         Ok(())
     }
 
+    /// Create groups that don't already exist in the table without updating
+    /// any aggregate states.
+    ///
+    /// New groups start with empty states.
+    pub fn insert_groups(&mut self, groups: &[Array], hashes: &[u64]) -> Result<()> {
+        self.find_or_create_groups(groups, hashes)
+    }
+
+    /// Update aggregate states for rows that belong to groups already in the
+    /// table, without creating any new groups.
+    ///
+    /// Returns a selection of the rows that don't have a group in the table.
+    pub fn insert_existing(
+        &mut self,
+        groups: &[Array],
+        hashes: &[u64],
+        inputs: &[Array],
+    ) -> Result<SelectionVector> {
+        let missing = self.find_existing_groups(groups, hashes)?;
+        if missing.len() == hashes.len() {
+            return Ok(missing);
+        }
+
+        // Only update states using rows with a group.
+        let num_found = hashes.len() - missing.len();
+        let mut found = SelectionVector::with_capacity(num_found);
+        let mut found_addresses = Vec::with_capacity(num_found);
+        {
+            let mut missing_iter = missing.iter_locations().peekable();
+            for (row_idx, addr) in self.insert_buffers.group_addresses.iter().enumerate() {
+                if missing_iter.next_if_eq(&row_idx).is_some() {
+                    continue;
+                }
+                found.push_location(row_idx);
+                found_addresses.push(*addr);
+            }
+        }
+
+        let found = Arc::new(found);
+        let inputs: Vec<_> = inputs
+            .iter()
+            .map(|arr| {
+                let mut arr = arr.clone();
+                arr.select_mut2(found.clone());
+                arr
+            })
+            .collect();
+
+        self.insert_buffers.chunk_indices.clear();
+        self.insert_buffers
+            .chunk_indices
+            .extend(found_addresses.iter().map(|addr| addr.chunk_idx));
+
+        for &chunk_idx in &self.insert_buffers.chunk_indices {
+            let chunk = &mut self.chunks[chunk_idx as usize];
+            chunk.update_states(&inputs, &found_addresses)?;
+        }
+
+        Ok(missing)
+    }
+
     pub fn merge_many(&mut self, others: &mut [HashTable]) -> Result<()> {
         let other_inputs: usize = others.iter().map(|table| table.num_occupied).sum();
         self.resize_if_needed(other_inputs)?;
@@ -155,6 +238,115 @@ impl HashTable {
         }
     }
 
+    /// Find the groups for each input row, writing the group addresses to the
+    /// insert buffers.
+    ///
+    /// Returns a selection of rows that don't have a group in the table. The
+    /// addresses for these rows are left unset.
+    fn find_existing_groups(
+        &mut self,
+        groups: &[Array],
+        hashes: &[u64],
+    ) -> Result<SelectionVector> {
+        let num_inputs = hashes.len();
+
+        self.insert_buffers
+            .group_addresses
+            .resize(num_inputs, GroupAddress::default());
+
+        let mut missing = BTreeSet::new();
+
+        self.insert_buffers.offsets.clear();
+        self.insert_buffers.offsets.resize(num_inputs, 0);
+        let cap = self.capacity() as u64;
+        for (idx, &hash) in hashes.iter().enumerate() {
+            self.insert_buffers.offsets[idx] = compute_offset_from_hash(hash, cap) as usize;
+        }
+
+        self.insert_buffers.needs_insert.clear();
+        self.insert_buffers
+            .needs_insert
+            .append_locations(0..num_inputs);
+
+        let mut remaining = num_inputs;
+        let cap = self.capacity();
+
+        while remaining > 0 {
+            self.insert_buffers.needs_compare.clear();
+            self.insert_buffers.not_eq_rows.clear();
+
+            for idx in 0..remaining {
+                let row_idx = self.insert_buffers.needs_insert.get(idx);
+                let offset = &mut self.insert_buffers.offsets[row_idx];
+                let row_hash = hashes[row_idx];
+
+                // Probe until we either hit an empty entry (no group) or an
+                // entry with a matching hash that needs to be compared.
+                for iter_count in 0..cap {
+                    // SAFETY: Updates to `offset` wraps it around according to
+                    // entries len.
+                    let ent = unsafe { self.entries.get_unchecked(*offset) };
+
+                    if ent.is_empty() {
+                        missing.insert(row_idx);
+                        break;
+                    }
+
+                    if ent.hash == row_hash {
+                        self.insert_buffers.needs_compare.push_location(row_idx);
+                        break;
+                    }
+
+                    *offset = inc_and_wrap_offset(*offset, cap);
+
+                    if iter_count == cap - 1 {
+                        // Table is full and we've checked every entry.
+                        missing.insert(row_idx);
+                    }
+                }
+            }
+
+            if !self.insert_buffers.needs_compare.is_empty() {
+                for row_idx in self.insert_buffers.needs_compare.iter_locations() {
+                    let offset = self.insert_buffers.offsets[row_idx];
+                    self.insert_buffers.group_addresses[row_idx] = self.entries[offset].key;
+                }
+
+                self.insert_buffers.chunk_indices.clear();
+                self.insert_buffers.chunk_indices.extend(
+                    self.insert_buffers
+                        .needs_compare
+                        .iter_locations()
+                        .map(|row_idx| self.insert_buffers.group_addresses[row_idx].chunk_idx),
+                );
+
+                group_values_eq(
+                    groups,
+                    &self.insert_buffers.needs_compare,
+                    &self.chunks,
+                    &self.insert_buffers.group_addresses,
+                    &self.insert_buffers.chunk_indices,
+                    &mut self.insert_buffers.not_eq_rows,
+                )?;
+            }
+
+            // Rows that failed the equality check try the next entry.
+            for &row_idx in &self.insert_buffers.not_eq_rows {
+                let offset = &mut self.insert_buffers.offsets[row_idx];
+                *offset = inc_and_wrap_offset(*offset, cap);
+            }
+
+            self.insert_buffers.needs_insert.clear();
+            self.insert_buffers
+                .needs_insert
+                .extend(self.insert_buffers.not_eq_rows.iter().copied());
+
+            remaining = self.insert_buffers.needs_insert.len();
+        }
+
+        Ok(SelectionVector::from_iter(missing))
+    }
+
     fn find_or_create_groups(&mut self, groups: &[Array], hashes: &[u64]) -> Result<()> {
         let num_inputs = hashes.len();
 
@@ -442,6 +634,19 @@ impl HashTable {
     /// Resize the hash table if needed, keeping the load factor less than
     /// LOAD_FACTOR.
     fn resize_if_needed(&mut self, num_inputs: usize) -> Result<()> {
+        let new_capacity = self.capacity_for(num_inputs);
+
+        // Now resize if needed.
+        if new_capacity != self.capacity() {
+            self.resize(new_capacity)?;
+        }
+
+        Ok(())
+    }
+
+    /// Compute the capacity needed to keep the load factor less than
+    /// LOAD_FACTOR after inserting `num_inputs` new groups.
+    fn capacity_for(&self, num_inputs: usize) -> usize {
         let possible_occupied = num_inputs + self.num_occupied;
 
         // Calculate the minimum required capacity.
@@ -450,12 +655,7 @@ impl HashTable {
             new_capacity *= 2;
         }
 
-        // Now resize if needed.
-        if new_capacity != self.capacity() {
-            self.resize(new_capacity)?;
-        }
-
-        Ok(())
+        new_capacity
     }
 }
 
@@ -5,9 +5,9 @@ pub mod drain;
 pub mod entry;
 pub mod hash_table;
 
-use std::collections::BTreeSet;
+use std::collections::{BTreeSet, VecDeque};
 use std::sync::Arc;
-use std::task::{Context, Waker};
+use std::task::{Context, Poll, Waker};
 
 use distinct::DistinctGroupedStates;
 use drain::HashTableDrain;
@@ -16,6 +16,7 @@ use parking_lot::Mutex;
 use rayexec_error::{RayexecError, Result};
 
 use super::{ExecutionStates, InputOutputStates, PollFinalize};
+use crate::arrays::array::buffer_manager::LimitedBufferManager;
 use crate::arrays::array::physical_type::PhysicalU64;
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
@@ -28,6 +29,12 @@ use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::execution::computed_batch::ComputedBatches;
 use crate::execution::operators::util::hash::partition_for_hash;
+use crate::execution::operators::util::spill::{
+    batch_size_bytes,
+    MemoryReservation,
+    SpillFile,
+    SpillReader,
+};
 use crate::execution::operators::{
     ExecutableOperator,
     OperatorState,
@@ -40,6 +47,7 @@ use crate::expr::physical::PhysicalAggregateExpression;
 use crate::functions::aggregate::states::AggregateGroupStates;
 use crate::functions::aggregate::AggregateFunctionImpl;
 use crate::logical::logical_aggregate::GroupingFunction;
+use crate::runtime::spill::SpillProvider;
 
 #[derive(Debug)]
 pub struct Aggregate {
@@ -107,6 +115,16 @@ pub struct AggregatingPartitionState {
     ///
     /// There exists one hash table per output partition.
     output_hashtables: Vec<HashTable>,
+    /// Memory reserved for each output hash table.
+    reservations: Vec<MemoryReservation>,
+    /// Rows that didn't fit in memory, spilled per output partition.
+    ///
+    /// Each batch contains the aggregate inputs followed by the masked group
+    /// values. These get replayed into the final hash table for the output
+    /// partition.
+    spilled: Vec<Option<SpillFile>>,
+    /// Where to spill rows to.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
     /// Reusable hashes buffer.
     hash_buf: Vec<u64>,
     /// Resusable partitions buffer.
@@ -123,6 +141,47 @@ pub struct ProducingPartitionState {
     /// May be None if the final hash table hasn't been built yet. If it
     /// hasn't been built, then the shared state will be need to be checked.
     hashtable_drain: Option<HashTableDrain>,
+    /// Spilled rows being inserted into the next table to drain.
+    spilled_insert: Option<Box<SpilledInsert>>,
+    /// Memory reserved for the final hash table.
+    reservation: Option<MemoryReservation>,
+    /// Spilled rows that didn't fit in the final hash table.
+    ///
+    /// These rows belong to groups that aren't in the final table, and get
+    /// aggregated in a new table once the current one is drained.
+    overflow: Option<Box<SpillFile>>,
+    /// Where to spill overflowing rows to.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
+}
+
+/// Rows that were spilled to disk being inserted into a hash table.
+#[derive(Debug)]
+struct SpilledInsert {
+    /// Table the rows are being inserted into.
+    table: HashTable,
+    /// Readers for the spilled files, read in order.
+    readers: VecDeque<SpillReader>,
+    /// The spilled files, removed from disk once dropped.
+    _files: Vec<SpillFile>,
+    /// Rows that didn't fit in the table.
+    overflow: Option<SpillFile>,
+    /// Reusable hashes buffer.
+    hash_buf: Vec<u64>,
+}
+
+impl SpilledInsert {
+    fn try_new(table: HashTable, files: Vec<SpillFile>) -> Result<Self> {
+        Ok(SpilledInsert {
+            table,
+            readers: files
+                .iter()
+                .map(|file| file.reader())
+                .collect::<Result<_>>()?,
+            _files: files,
+            overflow: None,
+            hash_buf: Vec::new(),
+        })
+    }
 }
 
 impl HashAggregatePartitionState {
@@ -132,6 +191,18 @@ impl HashAggregatePartitionState {
             HashAggregatePartitionState::Producing(s) => s.partition_idx,
         }
     }
+
+    /// Number of bytes reserved by this partition.
+    pub fn reserved_memory(&self) -> usize {
+        match self {
+            HashAggregatePartitionState::Aggregating(s) => {
+                s.reservations.iter().map(|r| r.size()).sum()
+            }
+            HashAggregatePartitionState::Producing(s) => {
+                s.reservation.as_ref().map(|r| r.size()).unwrap_or(0)
+            }
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -146,6 +217,13 @@ struct SharedOutputPartitionState {
     /// one final output table.
     completed: Vec<HashTable>,
 
+    /// Memory reserved for the completed hash tables.
+    reservations: Vec<MemoryReservation>,
+
+    /// Spilled rows from input partitions that need to be inserted into the
+    /// final table.
+    spilled: Vec<SpillFile>,
+
     /// Number of remaining inputs. Initially set to number of input partitions.
     ///
     /// Once zero, the final hash table can be created.
@@ -167,8 +245,14 @@ pub struct PhysicalHashAggregate {
     /// Null masks for determining which column values should be part of a
     /// group.
     null_masks: Vec<Bitmap>,
+    /// Number of identical grouping sets preceding each grouping set.
+    ///
+    /// Used to keep the groups for duplicate grouping sets separate.
+    duplicate_ordinals: Vec<u64>,
     /// Distinct columns that are used in the grouping sets.
     group_columns: Vec<usize>,
+    /// Datatypes for the distinct group columns.
+    group_types: Vec<DataType>,
     /// Union of all column indices that are inputs to the aggregate functions.
     aggregate_columns: Vec<usize>,
     exprs: Vec<PhysicalAggregateExpression>,
@@ -177,6 +261,7 @@ pub struct PhysicalHashAggregate {
 impl PhysicalHashAggregate {
     pub fn new(
         exprs: Vec<PhysicalAggregateExpression>,
+        group_types: Vec<DataType>,
         grouping_sets: Vec<BTreeSet<usize>>,
         grouping_functions: Vec<GroupingFunction>,
     ) -> Self {
@@ -206,6 +291,17 @@ impl PhysicalHashAggregate {
             })
             .collect();
 
+        let duplicate_ordinals = grouping_sets
+            .iter()
+            .enumerate()
+            .map(|(idx, set)| grouping_sets[..idx].iter().filter(|s| *s == set).count() as u64)
+            .collect();
+
+        let group_types = distinct_group_cols
+            .iter()
+            .map(|&col| group_types[col].clone())
+            .collect();
+
         // Adjust group cols to take into account physical column index.
         let group_columns = distinct_group_cols
             .into_iter()
@@ -215,7 +311,9 @@ impl PhysicalHashAggregate {
         PhysicalHashAggregate {
             grouping_functions,
             null_masks,
+            duplicate_ordinals,
             group_columns,
+            group_types,
             aggregate_columns: agg_input_cols.into_iter().collect(),
             exprs,
         }
@@ -225,28 +323,18 @@ impl PhysicalHashAggregate {
 impl ExecutableOperator for PhysicalHashAggregate {
     fn create_states(
         &self,
-        _context: &DatabaseContext,
+        context: &DatabaseContext,
         partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
         let num_partitions = partitions[0];
 
-        // Create column selection bitmaps for each aggregate expression. These
-        // bitmaps are used to mask input columns into the operator.
-        let mut col_selections = Vec::with_capacity(self.exprs.len());
-        for expr in &self.exprs {
-            let col_selection = Bitmap::from_iter(
-                self.aggregate_columns
-                    .iter()
-                    .map(|idx| expr.contains_column_idx(*idx)),
-            );
-            col_selections.push(col_selection);
-        }
-
         let operator_state = OperatorState::HashAggregate(HashAggregateOperatorState {
             output_states: (0..num_partitions)
                 .map(|_| {
                     Mutex::new(SharedOutputPartitionState {
                         completed: Vec::new(),
+                        reservations: Vec::new(),
+                        spilled: Vec::new(),
                         remaining: num_partitions,
                         pull_waker: None,
                     })
@@ -256,26 +344,18 @@ impl ExecutableOperator for PhysicalHashAggregate {
 
         let mut partition_states = Vec::with_capacity(num_partitions);
         for idx in 0..num_partitions {
-            let partition_local_tables = (0..num_partitions)
-                .map(|_| {
-                    let aggregates: Vec<_> = self
-                        .exprs
-                        .iter()
-                        .zip(col_selections.iter())
-                        .map(|(expr, col_selection)| Aggregate {
-                            function: expr.function.function_impl.clone(),
-                            col_selection: col_selection.clone(),
-                            is_distinct: expr.is_distinct,
-                        })
-                        .collect();
-                    HashTable::new(16, aggregates)
-                })
-                .collect();
+            let partition_local_tables =
+                (0..num_partitions).map(|_| self.new_hash_table()).collect();
 
             let partition_state = PartitionState::HashAggregate(
                 HashAggregatePartitionState::Aggregating(AggregatingPartitionState {
                     partition_idx: idx,
                     output_hashtables: partition_local_tables,
+                    reservations: (0..num_partitions)
+                        .map(|_| MemoryReservation::new(context.buffer_manager()))
+                        .collect(),
+                    spilled: (0..num_partitions).map(|_| None).collect(),
+                    spill_provider: context.spill_provider().cloned(),
                     hash_buf: Vec::new(),
                     partitions_idx_buf: Vec::new(),
                     partition_row_sel: (0..num_partitions)
@@ -335,7 +415,9 @@ impl ExecutableOperator for PhysicalHashAggregate {
         };
 
         match state {
-            HashAggregatePartitionState::Aggregating(_) => {
+            HashAggregatePartitionState::Aggregating(aggregating_state) => {
+                let spill_provider = aggregating_state.spill_provider.clone();
+
                 // Set this partition's state to producing with an empty hash
                 // table.
                 //
@@ -346,18 +428,33 @@ impl ExecutableOperator for PhysicalHashAggregate {
                     HashAggregatePartitionState::Producing(ProducingPartitionState {
                         partition_idx: state.partition_idx(),
                         hashtable_drain: None,
+                        spilled_insert: None,
+                        reservation: None,
+                        overflow: None,
+                        spill_provider,
                     });
                 let aggregating_state = std::mem::replace(state, producing_state);
-                let partition_hashtables = match aggregating_state {
-                    HashAggregatePartitionState::Aggregating(state) => state.output_hashtables,
+                let aggregating_state = match aggregating_state {
+                    HashAggregatePartitionState::Aggregating(state) => state,
                     _ => unreachable!("state variant already checked in outer match"),
                 };
 
-                for (partition_idx, partition_hashtable) in
-                    partition_hashtables.into_iter().enumerate()
+                for (partition_idx, ((partition_hashtable, reservation), spilled)) in
+                    aggregating_state
+                        .output_hashtables
+                        .into_iter()
+                        .zip(aggregating_state.reservations)
+                        .zip(aggregating_state.spilled)
+                        .enumerate()
                 {
                     let mut output_state = operator_state.output_states[partition_idx].lock();
                     output_state.completed.push(partition_hashtable);
+                    output_state.reservations.push(reservation);
+
+                    if let Some(mut spilled) = spilled {
+                        spilled.finish()?;
+                        output_state.spilled.push(spilled);
+                    }
 
                     output_state.remaining -= 1;
 
@@ -396,7 +493,7 @@ impl ExecutableOperator for PhysicalHashAggregate {
             HashAggregatePartitionState::Producing(state) => {
                 // Check if we have the finaly hash table. Try to build it if we
                 // don't.
-                if state.hashtable_drain.is_none() {
+                if state.hashtable_drain.is_none() && state.spilled_insert.is_none() {
                     let mut shared_state = operator_state.output_states[state.partition_idx].lock();
                     if shared_state.remaining != 0 {
                         // Still need to wait for some input partitions to complete. Store our
@@ -413,16 +510,75 @@ impl ExecutableOperator for PhysicalHashAggregate {
                         completed.pop().expect("there to be at least one partition");
 
                     final_table.merge_many(&mut completed)?;
+                    self.insert_empty_grouping_sets(
+                        &mut final_table,
+                        state.partition_idx,
+                        operator_state.output_states.len(),
+                    )?;
 
-                    let drain = final_table.into_drain();
-                    state.hashtable_drain = Some(drain);
+                    let mut reservations = std::mem::take(&mut shared_state.reservations);
+                    let mut reservation = reservations
+                        .pop()
+                        .expect("there to be at least one reservation");
+                    for other in reservations {
+                        reservation.merge(other);
+                    }
+
+                    // Insert rows that were spilled to disk before draining.
+                    let spilled = std::mem::take(&mut shared_state.spilled);
+                    state.spilled_insert =
+                        Some(Box::new(SpilledInsert::try_new(final_table, spilled)?));
+                    state.reservation = Some(reservation);
                 }
 
-                // Drain should be Some by here.
-                let batch = match state.hashtable_drain.as_mut().unwrap().next() {
-                    Some(Ok(batch)) => batch,
-                    Some(Err(e)) => return Err(e),
-                    None => return Ok(PollPull::Exhausted),
+                let batch = loop {
+                    if let Some(insert) = state.spilled_insert.as_mut() {
+                        let reservation = state
+                            .reservation
+                            .as_mut()
+                            .expect("reservation to exist for producing partition");
+                        match self.poll_insert_spilled(
+                            cx,
+                            insert,
+                            reservation,
+                            state.spill_provider.as_deref(),
+                        ) {
+                            Poll::Ready(Ok(())) => (),
+                            Poll::Ready(Err(e)) => return Err(e),
+                            Poll::Pending => return Ok(PollPull::Pending),
+                        }
+
+                        let insert = state.spilled_insert.take().expect("insert to exist");
+                        state.overflow = insert.overflow.map(Box::new);
+                        state.hashtable_drain = Some(insert.table.into_drain());
+                    }
+
+                    // Drain should be Some by here.
+                    match state.hashtable_drain.as_mut().unwrap().next() {
+                        Some(Ok(batch)) => break batch,
+                        Some(Err(e)) => return Err(e),
+                        None => {
+                            let overflow = match state.overflow.take() {
+                                Some(overflow) => overflow,
+                                None => return Ok(PollPull::Exhausted),
+                            };
+
+                            // Aggregate the rows that didn't fit in the
+                            // previous table. Release the memory for the
+                            // drained table first.
+                            state.hashtable_drain = None;
+                            let reservation = state
+                                .reservation
+                                .as_mut()
+                                .expect("reservation to exist for producing partition");
+                            reservation.release();
+
+                            state.spilled_insert = Some(Box::new(SpilledInsert::try_new(
+                                self.new_hash_table(),
+                                vec![*overflow],
+                            )?));
+                        }
+                    }
                 };
 
                 // Prune off GROUP ID column, generate appropriate GROUPING
@@ -514,6 +670,9 @@ impl PhysicalHashAggregate {
         state.hash_buf.resize(num_rows, 0);
         state.partitions_idx_buf.resize(num_rows, 0);
 
+        // Estimated memory needed per row inserted into a hash table.
+        let row_size = batch_size_bytes(&batch).div_ceil(num_rows);
+
         let mut masked_grouping_columns: Vec<Array> = Vec::with_capacity(grouping_columns.len());
 
         // Reused to select hashes per partition.
@@ -521,7 +680,8 @@ impl PhysicalHashAggregate {
 
         // For null mask, create a new set of grouping values, hash
         // them, and put into the hash maps.
-        for null_mask in &self.null_masks {
+        for (null_mask, &duplicate_ordinal) in self.null_masks.iter().zip(&self.duplicate_ordinals)
+        {
             masked_grouping_columns.clear();
 
             for (col_idx, col_is_null) in null_mask.iter().enumerate() {
@@ -539,7 +699,7 @@ impl PhysicalHashAggregate {
 
             // Group id for disambiguating NULL values in user columns vs NULLs
             // we're applying for the mask.
-            let grouping_set_id = null_mask.try_as_u64()?;
+            let grouping_set_id = grouping_set_id(null_mask, duplicate_ordinal)?;
 
             // Append group id to group val columns. Can be retrieved via the
             // GROUPING function call.
@@ -569,9 +729,7 @@ impl PhysicalHashAggregate {
             // For each partition, produce a selection vector, and
             // insert the rows corresponding to that partition into the
             // partition's hash table.
-            for (partition_idx, partition_hashtable) in
-                state.output_hashtables.iter_mut().enumerate()
-            {
+            for partition_idx in 0..num_partitions {
                 // Only select rows that this partition is concerned
                 // about.
                 let selection = Arc::new(state.partition_row_sel[partition_idx].clone());
@@ -605,12 +763,273 @@ impl PhysicalHashAggregate {
                     })
                     .collect();
 
-                partition_hashtable.insert(&groups, &partition_hashes, &inputs)?;
+                let missing = insert_with_reservation(
+                    &mut state.output_hashtables[partition_idx],
+                    &mut state.reservations[partition_idx],
+                    &groups,
+                    &partition_hashes,
+                    &inputs,
+                    row_size,
+                    NewGroups::IfReserved,
+                )?;
+
+                if let Some(missing) = missing {
+                    // Table for this partition can't grow anymore, write the
+                    // rows without a group out to disk to be aggregated once
+                    // we're producing the final table.
+                    spill_rows(
+                        state.spill_provider.as_deref(),
+                        state.reservations[partition_idx].buffer_manager(),
+                        &mut state.spilled[partition_idx],
+                        inputs,
+                        groups,
+                        missing,
+                    )?;
+                }
             }
         }
 
         Ok(())
     }
+
+    /// Insert the groups for empty grouping sets (e.g. the grand total of a
+    /// ROLLUP) into the final table for an output partition.
+    ///
+    /// Empty grouping sets produce a row even when there's no input, the same
+    /// as an ungrouped aggregate. Groups already created by input rows are
+    /// left as is.
+    fn insert_empty_grouping_sets(
+        &self,
+        table: &mut HashTable,
+        partition_idx: usize,
+        num_partitions: usize,
+    ) -> Result<()> {
+        let mut hash_buf = vec![0];
+        for (null_mask, &duplicate_ordinal) in self.null_masks.iter().zip(&self.duplicate_ordinals)
+        {
+            if !null_mask.iter().all(|is_null| is_null) {
+                continue;
+            }
+
+            let mut groups = self
+                .group_types
+                .iter()
+                .map(|datatype| Array::new_typed_null_array(datatype.clone(), 1))
+                .collect::<Result<Vec<_>>>()?;
+            let grouping_set_id = grouping_set_id(null_mask, duplicate_ordinal)?;
+            groups.push(ScalarValue::UInt64(grouping_set_id).as_array(1)?);
+
+            let hashes = HashExecutor::hash_many(&groups, &mut hash_buf)?;
+            if partition_for_hash(hashes[0], num_partitions) != partition_idx {
+                continue;
+            }
+
+            table.insert_groups(&groups, hashes)?;
+        }
+
+        Ok(())
+    }
+
+    /// Poll inserting rows that were spilled to disk into a hash table.
+    ///
+    /// Rows are inserted under the reservation. Once the table can't grow
+    /// anymore, rows that don't belong to a group already in the table are
+    /// spilled again to the insert's overflow file. No new groups are created
+    /// after that point, keeping the groups in the overflow file disjoint from
+    /// the groups in the table.
+    fn poll_insert_spilled(
+        &self,
+        cx: &mut Context,
+        insert: &mut SpilledInsert,
+        reservation: &mut MemoryReservation,
+        spill_provider: Option<&dyn SpillProvider>,
+    ) -> Poll<Result<()>> {
+        while let Some(reader) = insert.readers.front_mut() {
+            let batch = match reader.poll_read_batch(cx) {
+                Poll::Ready(Ok(Some(batch))) => batch,
+                Poll::Ready(Ok(None)) => {
+                    insert.readers.pop_front();
+                    continue;
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            let row_size = batch_size_bytes(&batch).div_ceil(batch.num_rows());
+
+            let mut inputs = batch.into_arrays();
+            let groups = inputs.split_off(self.aggregate_columns.len());
+
+            insert.hash_buf.clear();
+            insert.hash_buf.resize(groups[0].logical_len(), 0);
+            let hashes = HashExecutor::hash_many(&groups, &mut insert.hash_buf)?;
+
+            let new_groups = if insert.overflow.is_some() {
+                NewGroups::Never
+            } else if insert.table.num_groups() == 0 {
+                // Always make progress, even if a single batch exceeds the
+                // limit.
+                NewGroups::Always
+            } else {
+                NewGroups::IfReserved
+            };
+
+            let missing = insert_with_reservation(
+                &mut insert.table,
+                reservation,
+                &groups,
+                hashes,
+                &inputs,
+                row_size,
+                new_groups,
+            )?;
+
+            if let Some(missing) = missing {
+                spill_rows(
+                    spill_provider,
+                    reservation.buffer_manager(),
+                    &mut insert.overflow,
+                    inputs,
+                    groups,
+                    missing,
+                )?;
+            }
+        }
+
+        if let Some(overflow) = insert.overflow.as_mut() {
+            overflow.finish()?;
+        }
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Create a new hash table for computing the aggregates.
+    fn new_hash_table(&self) -> HashTable {
+        let aggregates = self
+            .exprs
+            .iter()
+            .map(|expr| {
+                // Mask input columns into the aggregate.
+                let col_selection = Bitmap::from_iter(
+                    self.aggregate_columns
+                        .iter()
+                        .map(|idx| expr.contains_column_idx(*idx)),
+                );
+                Aggregate {
+                    function: expr.function.function_impl.clone(),
+                    col_selection,
+                    is_distinct: expr.is_distinct,
+                }
+            })
+            .collect();
+
+        HashTable::new(16, aggregates)
+    }
+}
+
+/// Whether new groups can be created when inserting into a hash table under a
+/// memory reservation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum NewGroups {
+    /// Create new groups if memory for them can be reserved.
+    IfReserved,
+    /// Always create new groups, even if it exceeds the memory limit.
+    Always,
+    /// Never create new groups.
+    Never,
+}
+
+/// Get the group id for a grouping set.
+///
+/// Duplicate grouping sets store their ordinal in the bits above the mask so
+/// that each produces its own groups. GROUPING only looks at the bits for the
+/// group columns.
+fn grouping_set_id(null_mask: &Bitmap, duplicate_ordinal: u64) -> Result<u64> {
+    let mut grouping_set_id = null_mask.try_as_u64()?;
+    if duplicate_ordinal > 0 {
+        let shift = null_mask.len() as u32;
+        if shift >= u64::BITS || duplicate_ordinal.leading_zeros() < shift {
+            return Err(RayexecError::new("Too many duplicate grouping sets"));
+        }
+        grouping_set_id |= duplicate_ordinal << shift;
+    }
+    Ok(grouping_set_id)
+}
+
+/// Insert rows into a hash table, reserving memory for new groups and state
+/// growth.
+///
+/// If new groups can't be created, only rows belonging to existing groups are
+/// inserted, and the selection of the remaining rows is returned.
+fn insert_with_reservation(
+    table: &mut HashTable,
+    reservation: &mut MemoryReservation,
+    groups: &[Array],
+    hashes: &[u64],
+    inputs: &[Array],
+    row_size: usize,
+    new_groups: NewGroups,
+) -> Result<Option<SelectionVector>> {
+    let size_before = table.size_bytes();
+
+    // Reserve assuming every row creates a new group, then adjust to what the
+    // table actually grew by.
+    let estimate = table.estimate_insert_bytes(hashes.len(), row_size);
+    let reserved = match new_groups {
+        NewGroups::IfReserved | NewGroups::Always if reservation.try_grow(estimate)? => estimate,
+        _ => 0,
+    };
+
+    let missing = if reserved > 0 || new_groups == NewGroups::Always {
+        table.insert(groups, hashes, inputs)?;
+        None
+    } else {
+        let missing = table.insert_existing(groups, hashes, inputs)?;
+        Some(missing).filter(|missing| !missing.is_empty())
+    };
+
+    let growth = table.size_bytes().saturating_sub(size_before);
+    if growth < reserved {
+        reservation.shrink(reserved - growth);
+    } else if growth > reserved {
+        // Memory has already been allocated (e.g. states for existing groups
+        // grew), make sure it's accounted for.
+        reservation.grow_unchecked(growth - reserved)?;
+    }
+
+    Ok(missing)
+}
+
+/// Spill the selected rows, creating the spill file if needed.
+fn spill_rows(
+    spill_provider: Option<&dyn SpillProvider>,
+    buffer_manager: &Arc<LimitedBufferManager>,
+    spilled: &mut Option<SpillFile>,
+    inputs: Vec<Array>,
+    groups: Vec<Array>,
+    selection: SelectionVector,
+) -> Result<()> {
+    let selection = Arc::new(selection);
+    let arrays = inputs.into_iter().chain(groups).map(|mut arr| {
+        arr.select_mut2(selection.clone());
+        arr
+    });
+    let batch = Batch::try_from_arrays(arrays)?;
+
+    let spilled = match spilled {
+        Some(spilled) => spilled,
+        None => spilled.insert(SpillFile::try_new(
+            spill_provider,
+            buffer_manager,
+            batch
+                .arrays()
+                .iter()
+                .map(|arr| arr.datatype().clone())
+                .collect(),
+        )?),
+    };
+
+    spilled.write_batch(&batch)
 }
 
 impl Explainable for PhysicalHashAggregate {
@@ -12,7 +12,6 @@ use super::condition::{
 };
 use super::partition_hash_table::{PartitionHashTable, RowKey};
 use crate::arrays::batch::Batch;
-use crate::arrays::datatype::DataType;
 use crate::arrays::selection::SelectionVector;
 use crate::execution::operators::util::outer_join_tracker::{
     LeftOuterJoinTracker,
@@ -31,12 +30,6 @@ pub struct GlobalHashTable {
     conditions: LeftPrecomputedJoinConditions,
     /// Hash table pointing to a row.
     hash_table: RawTable<(u64, RowKey)>,
-    /// Column types for left side of join.
-    ///
-    /// Used when generating the left columns for a RIGHT OUTER join.
-    left_types: Vec<DataType>,
-    /// If we're a right join.
-    right_join: bool,
     /// If we're a mark join.
     ///
     /// If true, we won't actually be doing any joining, and instead just update
@@ -47,8 +40,6 @@ pub struct GlobalHashTable {
 impl GlobalHashTable {
     /// Merge many partition hash tables into a new global hash table.
     pub fn new(
-        left_types: Vec<DataType>,
-        right_join: bool,
         is_mark: bool,
         partition_tables: Vec<PartitionHashTable>,
         conditions: &[HashJoinCondition],
@@ -113,8 +104,6 @@ impl GlobalHashTable {
             batches,
             conditions,
             hash_table,
-            left_types,
-            right_join,
             is_mark,
         }
     }
@@ -124,12 +113,16 @@ impl GlobalHashTable {
     }
 
     /// Probe the table.
+    ///
+    /// Rows visited on the left and right are marked in the provided trackers.
+    /// Producing the unvisited rows is left to the caller.
     #[allow(deprecated)]
     pub fn probe(
         &self,
         right: &Batch,
         hashes: &[u64],
         mut left_outer_tracker: Option<&mut LeftOuterJoinTracker>,
+        mut right_outer_tracker: Option<&mut RightOuterJoinTracker>,
     ) -> Result<Vec<Batch>> {
         // Track per-batch row indices that match the input columns.
         //
@@ -171,12 +164,6 @@ impl GlobalHashTable {
             }
         }
 
-        let mut right_tracker = if self.right_join {
-            Some(RightOuterJoinTracker::new_for_batch(right))
-        } else {
-            None
-        };
-
         let mut batches = Vec::with_capacity(row_indices.len());
         for (batch_idx, row_indices) in row_indices {
             // Initial selection vectors, may include rows that aren't part of
@@ -198,7 +185,7 @@ impl GlobalHashTable {
 
             // Update right unvisited bitmap. May be None if we're not doing a
             // RIGHT JOIN.
-            if let Some(right_outer_tracker) = right_tracker.as_mut() {
+            if let Some(right_outer_tracker) = right_outer_tracker.as_mut() {
                 right_outer_tracker.mark_rows_visited(right_row_sel.iter_locations());
             }
 
@@ -233,13 +220,6 @@ impl GlobalHashTable {
             batches.push(batch);
         }
 
-        // Append batch from RIGHT OUTER if needed.
-        if let Some(right_tracker) = right_tracker {
-            if let Some(extra) = right_tracker.into_unvisited(&self.left_types, right)? {
-                batches.push(extra);
-            }
-        }
-
         Ok(batches)
     }
 }
@@ -3,8 +3,9 @@ pub mod condition;
 mod global_hash_table;
 mod partition_hash_table;
 
+use std::collections::VecDeque;
 use std::sync::Arc;
-use std::task::{Context, Waker};
+use std::task::{Context, Poll, Waker};
 
 use condition::HashJoinCondition;
 use global_hash_table::GlobalHashTable;
@@ -12,7 +13,13 @@ use parking_lot::Mutex;
 use partition_hash_table::PartitionHashTable;
 use rayexec_error::{OptionExt, RayexecError, Result};
 
-use super::util::outer_join_tracker::{LeftOuterJoinDrainState, LeftOuterJoinTracker};
+use super::util::hash::partition_for_hash;
+use super::util::outer_join_tracker::{
+    LeftOuterJoinDrainState,
+    LeftOuterJoinTracker,
+    RightOuterJoinTracker,
+};
+use super::util::spill::{batch_size_bytes, MemoryReservation, SpillFile, SpillReader};
 use super::{
     ComputedBatches,
     ExecutableOperator,
@@ -24,12 +31,26 @@ use super::{
     PollPull,
     PollPush,
 };
+use crate::arrays::array::buffer_manager::LimitedBufferManager;
+use crate::arrays::array::{Array, ArrayData2};
 use crate::arrays::batch::Batch;
 use crate::arrays::datatype::DataType;
-use crate::arrays::executor::scalar::HashExecutor;
+use crate::arrays::executor::scalar::{HashExecutor, SelectExecutor};
+use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::PhysicalScalarExpression;
 use crate::logical::logical_join::JoinType;
+use crate::runtime::spill::SpillProvider;
+
+/// Number of buckets rows are partitioned into by hash when spilling to disk.
+///
+/// Spilled build and probe rows in the same bucket get joined together once
+/// probing completes.
+const SPILL_BUCKETS: usize = 16;
+
+/// Spill files indexed by spill bucket.
+type SpillBuckets = Vec<Vec<SpillFile>>;
 
 #[derive(Debug)]
 pub struct HashJoinBuildPartitionState {
@@ -38,10 +59,23 @@ pub struct HashJoinBuildPartitionState {
     /// Optional to enable moving from the local to global state once this
     /// partition finishes building.
     local_hashtable: Option<PartitionHashTable>,
+    /// Memory reserved for the batches in the local hash table.
+    reservation: MemoryReservation,
+    /// Build rows that didn't fit in memory, indexed by spill bucket.
+    spilled: Vec<Option<SpillFile>>,
+    /// Where to spill build rows to.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
     /// Reusable hashes buffer.
     hash_buf: Vec<u64>,
 }
 
+impl HashJoinBuildPartitionState {
+    /// Number of bytes reserved by this partition.
+    pub fn reserved_memory(&self) -> usize {
+        self.reservation.size()
+    }
+}
+
 #[derive(Debug)]
 pub struct HashJoinProbePartitionState {
     /// Index of this partition.
@@ -49,6 +83,24 @@ pub struct HashJoinProbePartitionState {
     /// The final output table. If None, the global state should be checked to
     /// see if it's ready to copy into the partition local state.
     global: Option<Arc<GlobalHashTable>>,
+    /// Build rows that were spilled to disk across all build partitions.
+    ///
+    /// Set alongside the global hash table.
+    spilled_build: Option<Arc<SpillBuckets>>,
+    /// Probe rows that still need to be joined with spilled build rows,
+    /// indexed by spill bucket.
+    spilled_probe: Vec<Option<SpillFile>>,
+    /// Where to spill probe rows to.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
+    /// Buffer manager used to account for probe rows waiting to be spilled.
+    buffer_manager: Arc<LimitedBufferManager>,
+    /// Spill buckets this partition is responsible for joining along with the
+    /// spilled probe rows from all partitions for each bucket.
+    ///
+    /// Taken from the global state once all probe inputs have finished.
+    spilled_buckets: Option<Vec<(usize, Vec<SpillFile>)>>,
+    /// State for joining the spilled rows for the current bucket.
+    spilled_join: Option<SpilledBucketJoin>,
     /// Reusable hashes buffer.
     hash_buf: Vec<u64>,
     /// Buffered output batch.
@@ -69,6 +121,47 @@ pub struct HashJoinProbePartitionState {
     outer_join_drain_state: Option<LeftOuterJoinDrainState>,
 }
 
+/// Joins the spilled probe rows for a single bucket against the spilled build
+/// rows for that bucket.
+#[derive(Debug)]
+struct SpilledBucketJoin {
+    /// Readers for the spilled build rows from each build partition.
+    build_readers: VecDeque<SpillReader>,
+    /// Table the spilled build rows are inserted into while reading them.
+    build_table: Option<PartitionHashTable>,
+    /// Hash table built from the spilled build rows.
+    ///
+    /// Set once all build rows for this bucket have been read.
+    table: Option<GlobalHashTable>,
+    /// Readers for the spilled probe rows from each probe partition.
+    probe_readers: VecDeque<SpillReader>,
+    /// The spilled probe rows, removed from disk once dropped.
+    _probe_files: Vec<SpillFile>,
+    /// Track visited build rows in this bucket.
+    ///
+    /// Moved into the drain state once all probe rows have been joined.
+    left_tracker: Option<LeftOuterJoinTracker>,
+    /// State for draining build rows in this bucket.
+    drain_state: Option<LeftOuterJoinDrainState>,
+}
+
+impl SpilledBucketJoin {
+    /// Poll for the next batch of spilled probe rows, returning None once all
+    /// probe rows for this bucket have been read.
+    fn poll_next_probe_batch(&mut self, cx: &mut Context) -> Poll<Result<Option<Batch>>> {
+        while let Some(reader) = self.probe_readers.front_mut() {
+            match reader.poll_read_batch(cx) {
+                Poll::Ready(Ok(Some(batch))) => return Poll::Ready(Ok(Some(batch))),
+                Poll::Ready(Ok(None)) => {
+                    self.probe_readers.pop_front();
+                }
+                other => return other,
+            }
+        }
+        Poll::Ready(Ok(None))
+    }
+}
+
 #[derive(Debug)]
 pub struct HashJoinOperatorState {
     inner: Mutex<SharedState>,
@@ -83,6 +176,16 @@ struct SharedState {
     completed_hash_tables: Vec<PartitionHashTable>,
     /// Global hash table once it's been built.
     global_hash_table: Option<Arc<GlobalHashTable>>,
+    /// Memory reserved for the build side, held until the operator completes.
+    build_reservations: Vec<MemoryReservation>,
+    /// Build rows spilled by completed build partitions, indexed by spill
+    /// bucket.
+    spilled_build: SpillBuckets,
+    /// All spilled build rows, set once the global hash table has been built.
+    global_spilled_build: Option<Arc<SpillBuckets>>,
+    /// Probe rows spilled by finished probe partitions, indexed by spill
+    /// bucket.
+    spilled_probe: SpillBuckets,
     /// Number of partitions that are probiding the table.
     ///
     /// This is used to initialize the drain states such that each partition
@@ -169,9 +272,16 @@ impl PhysicalHashJoin {
         // the right chopped off, we need to be able to handle duplicate rows on
         // the left. So we use the same mechanism for the LEFT MARK join to
         // accomplish the deduplication.
+        //
+        // ANTI joins emit the left rows that were never visited, same as the
+        // drain for LEFT joins.
         matches!(
             self.join_type,
-            JoinType::Left | JoinType::Full | JoinType::Semi | JoinType::LeftMark { .. }
+            JoinType::Left
+                | JoinType::Full
+                | JoinType::Semi
+                | JoinType::Anti
+                | JoinType::LeftMark { .. }
         )
     }
 
@@ -180,17 +290,20 @@ impl PhysicalHashJoin {
     }
 
     const fn is_mark_join(&self) -> bool {
-        // Note this includes SEMI join since it's just an extension of a mark
-        // join, just that we return the left visited rows instead of bools that
-        // they've been visited.
-        matches!(self.join_type, JoinType::Semi | JoinType::LeftMark { .. })
+        // Note this includes SEMI and ANTI joins since they're just an
+        // extension of a mark join, just that we return the left visited (or
+        // unvisited) rows instead of bools that they've been visited.
+        matches!(
+            self.join_type,
+            JoinType::Semi | JoinType::Anti | JoinType::LeftMark { .. }
+        )
     }
 }
 
 impl ExecutableOperator for PhysicalHashJoin {
     fn create_states(
         &self,
-        _context: &DatabaseContext,
+        context: &DatabaseContext,
         partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
         // TODO: Determine if this is what we want.
@@ -200,6 +313,10 @@ impl ExecutableOperator for PhysicalHashJoin {
         let shared = SharedState {
             completed_hash_tables: Vec::with_capacity(build_partitions),
             global_hash_table: None,
+            build_reservations: Vec::with_capacity(build_partitions),
+            spilled_build: (0..SPILL_BUCKETS).map(|_| Vec::new()).collect(),
+            global_spilled_build: None,
+            spilled_probe: (0..SPILL_BUCKETS).map(|_| Vec::new()).collect(),
             probe_partition_count: probe_partitions,
             build_inputs_remaining: build_partitions,
             probe_inputs_remaining: probe_partitions,
@@ -216,6 +333,9 @@ impl ExecutableOperator for PhysicalHashJoin {
             .map(|_| {
                 PartitionState::HashJoinBuild(HashJoinBuildPartitionState {
                     local_hashtable: Some(PartitionHashTable::new(&self.conditions)),
+                    reservation: MemoryReservation::new(context.buffer_manager()),
+                    spilled: (0..SPILL_BUCKETS).map(|_| None).collect(),
+                    spill_provider: context.spill_provider().cloned(),
                     hash_buf: Vec::new(),
                 })
             })
@@ -226,6 +346,12 @@ impl ExecutableOperator for PhysicalHashJoin {
                 PartitionState::HashJoinProbe(HashJoinProbePartitionState {
                     partition_idx: idx,
                     global: None,
+                    spilled_build: None,
+                    spilled_probe: (0..SPILL_BUCKETS).map(|_| None).collect(),
+                    spill_provider: context.spill_provider().cloned(),
+                    buffer_manager: context.buffer_manager().clone(),
+                    spilled_buckets: None,
+                    spilled_join: None,
                     hash_buf: Vec::new(),
                     buffered_output: ComputedBatches::None,
                     push_waker: None,
@@ -305,32 +431,54 @@ impl ExecutableOperator for PhysicalHashJoin {
                     // Final hash table built, store in our partition local
                     // state.
                     state.global = Some(global);
+                    state.spilled_build = shared.global_spilled_build.clone();
 
                     // Continue on.
                 }
 
                 // Compute right hashes on equality condition.
-                state.hash_buf.clear();
-                state.hash_buf.resize(batch.num_rows(), 0);
-
-                for (idx, equality) in self.equalities.iter().enumerate() {
-                    let result = equality.right.eval(&batch)?;
-
-                    if idx == 0 {
-                        HashExecutor::hash_no_combine(&result, &mut state.hash_buf)?;
-                    } else {
-                        HashExecutor::hash_combine(&result, &mut state.hash_buf)?;
-                    }
-                }
+                compute_join_hashes(
+                    self.equalities.iter().map(|eq| &eq.right),
+                    &batch,
+                    &mut state.hash_buf,
+                )?;
 
                 let hashtable = state.global.as_ref().expect("hash table to exist");
 
-                let batches = hashtable.probe(
+                let mut right_tracker = if self.is_right_join() {
+                    Some(RightOuterJoinTracker::new_for_batch(&batch))
+                } else {
+                    None
+                };
+
+                let mut batches = hashtable.probe(
                     &batch,
                     &state.hash_buf,
                     state.partition_outer_join_tracker.as_mut(),
+                    right_tracker.as_mut(),
                 )?;
 
+                // Write out rows that might match spilled build rows, they'll
+                // be joined once all input has been received.
+                if let Some(spilled_build) = &state.spilled_build {
+                    spill_probe_rows(
+                        state.spill_provider.as_deref(),
+                        &state.buffer_manager,
+                        &mut state.spilled_probe,
+                        spilled_build,
+                        &batch,
+                        &state.hash_buf,
+                        right_tracker.as_mut(),
+                    )?;
+                }
+
+                // Append batch from RIGHT OUTER if needed.
+                if let Some(right_tracker) = right_tracker {
+                    if let Some(extra) = right_tracker.into_unvisited(&self.left_types, &batch)? {
+                        batches.push(extra);
+                    }
+                }
+
                 state.buffered_output = ComputedBatches::new(batches);
                 if state.buffered_output.is_empty() {
                     // No batches joined, keep pushing to this operator.
@@ -365,6 +513,14 @@ impl ExecutableOperator for PhysicalHashJoin {
                     Some(table) => shared.completed_hash_tables.push(table),
                     None => return Err(RayexecError::new("Missing partition table")), // Shouldn't happen.
                 }
+                shared.build_reservations.push(state.reservation.take());
+
+                for (bucket, spilled) in state.spilled.iter_mut().enumerate() {
+                    if let Some(mut spilled) = spilled.take() {
+                        spilled.finish()?;
+                        shared.spilled_build[bucket].push(spilled);
+                    }
+                }
 
                 shared.build_inputs_remaining -= 1;
 
@@ -377,18 +533,18 @@ impl ExecutableOperator for PhysicalHashJoin {
                 if shared.build_inputs_remaining == 0 {
                     let completed = std::mem::take(&mut shared.completed_hash_tables);
 
+                    let spilled_build = std::mem::take(&mut shared.spilled_build);
+                    if spilled_build.iter().any(|files| !files.is_empty()) {
+                        shared.global_spilled_build = Some(Arc::new(spilled_build));
+                    }
+
                     // Release the lock. Building the table can be
                     // computationally expensive. Other threads still need
                     // access to the global state to register wakers.
                     std::mem::drop(shared);
 
-                    let global = GlobalHashTable::new(
-                        self.left_types.clone(),
-                        self.is_right_join(),
-                        self.is_mark_join(),
-                        completed,
-                        &self.conditions,
-                    );
+                    let global =
+                        GlobalHashTable::new(self.is_mark_join(), completed, &self.conditions);
 
                     // Reacquire, and place in global state.
                     let mut shared = match operator_state {
@@ -439,6 +595,7 @@ impl ExecutableOperator for PhysicalHashJoin {
                     Some(table) => {
                         if state.global.is_none() {
                             state.global = Some(table.clone());
+                            state.spilled_build = shared.global_spilled_build.clone();
                         }
                     }
                     None => {
@@ -450,9 +607,18 @@ impl ExecutableOperator for PhysicalHashJoin {
                 state.input_finished = true;
                 shared.probe_inputs_remaining -= 1;
 
-                if self.join_requires_drain() {
-                    let probe_finished = shared.probe_inputs_remaining == 0;
+                // Spilled probe rows are joined once all probe inputs have
+                // finished.
+                for (bucket, spilled) in state.spilled_probe.iter_mut().enumerate() {
+                    if let Some(mut spilled) = spilled.take() {
+                        spilled.finish()?;
+                        shared.spilled_probe[bucket].push(spilled);
+                    }
+                }
 
+                let probe_finished = shared.probe_inputs_remaining == 0;
+
+                if self.join_requires_drain() {
                     // Merge local left visit bitmaps into global if we have it.
                     let global = match shared.global_outer_join_tracker.as_mut() {
                         Some(global) => global,
@@ -469,14 +635,14 @@ impl ExecutableOperator for PhysicalHashJoin {
                     if let Some(local) = &state.partition_outer_join_tracker {
                         global.merge_from(local)
                     }
+                }
 
-                    if probe_finished {
-                        // Wake up pending probers, they can initialize drain
-                        // states now.
-                        for waker in shared.probe_drain_wakers.iter_mut() {
-                            if let Some(waker) = waker.take() {
-                                waker.wake();
-                            }
+                if probe_finished {
+                    // Wake up pending probers, they can initialize drain
+                    // states and join spilled rows now.
+                    for waker in shared.probe_drain_wakers.iter_mut() {
+                        if let Some(waker) = waker.take() {
+                            waker.wake();
                         }
                     }
                 }
@@ -562,29 +728,14 @@ impl ExecutableOperator for PhysicalHashJoin {
 
                 // Check if we're still draining unvisited left rows.
                 if let Some(drain_state) = state.outer_join_drain_state.as_mut() {
-                    if matches!(self.join_type, JoinType::LeftMark { .. }) {
-                        // Mark drain
-                        match drain_state.drain_mark_next()? {
-                            Some(batch) => return Ok(PollPull::Computed(batch.into())),
-                            None => return Ok(PollPull::Exhausted),
-                        }
-                    } else if matches!(self.join_type, JoinType::Semi) {
-                        // Semi drain
-                        match drain_state.drain_semi_next()? {
-                            Some(batch) => return Ok(PollPull::Computed(batch.into())),
-                            None => return Ok(PollPull::Exhausted),
-                        }
-                    } else {
-                        // Normal left drain
-                        match drain_state.drain_next()? {
-                            Some(batch) => return Ok(PollPull::Computed(batch.into())),
-                            None => return Ok(PollPull::Exhausted),
-                        }
+                    if let Some(batch) = self.drain_next(drain_state)? {
+                        return Ok(PollPull::Computed(batch.into()));
                     }
                 }
 
-                // We're done.
-                return Ok(PollPull::Exhausted);
+                // Join any rows that were spilled to disk, we're done after
+                // that.
+                return self.poll_spilled_join(cx, state, operator_state);
             }
 
             // No batch available, come back later.
@@ -608,18 +759,22 @@ impl PhysicalHashJoin {
         batch: Batch,
     ) -> Result<()> {
         // Compute left hashes on equality conditions.
-
-        state.hash_buf.clear();
-        state.hash_buf.resize(batch.num_rows(), 0);
-
-        for (idx, equality) in self.equalities.iter().enumerate() {
-            let result = equality.left.eval(&batch)?;
-
-            if idx == 0 {
-                HashExecutor::hash_no_combine(&result, &mut state.hash_buf)?;
-            } else {
-                HashExecutor::hash_combine(&result, &mut state.hash_buf)?;
-            }
+        compute_join_hashes(
+            self.equalities.iter().map(|eq| &eq.left),
+            &batch,
+            &mut state.hash_buf,
+        )?;
+
+        let size = batch_size_bytes(&batch) + batch.num_rows() * std::mem::size_of::<(u64, u64)>();
+        if !state.reservation.try_grow(size)? {
+            return spill_by_bucket(
+                state.spill_provider.as_deref(),
+                state.reservation.buffer_manager(),
+                &mut state.spilled,
+                &batch,
+                &state.hash_buf,
+                |_| true,
+            );
         }
 
         state
@@ -630,6 +785,358 @@ impl PhysicalHashJoin {
 
         Ok(())
     }
+
+    /// Drain the next batch of left rows.
+    fn drain_next(&self, drain_state: &mut LeftOuterJoinDrainState) -> Result<Option<Batch>> {
+        match self.join_type {
+            // Mark drain
+            JoinType::LeftMark { .. } => drain_state.drain_mark_next(),
+            // Semi drain
+            JoinType::Semi => drain_state.drain_semi_next(),
+            // Normal left drain, also used for ANTI joins
+            _ => drain_state.drain_next(),
+        }
+    }
+
+    /// Join the rows that were spilled to disk.
+    ///
+    /// Spilled rows are joined once all probe inputs have finished, with each
+    /// spill bucket being joined by a single partition. Since that partition
+    /// sees every probe row for the bucket, it can also drain the bucket's
+    /// build rows afterwards.
+    ///
+    /// Buckets are joined one at a time, so only the build rows for a single
+    /// bucket need to be in memory.
+    fn poll_spilled_join(
+        &self,
+        cx: &mut Context,
+        state: &mut HashJoinProbePartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let spilled_build = match &state.spilled_build {
+            Some(spilled) => spilled.clone(),
+            None => return Ok(PollPull::Exhausted),
+        };
+
+        if state.spilled_buckets.is_none() {
+            let mut shared = match operator_state {
+                OperatorState::HashJoin(state) => state.inner.lock(),
+                other => panic!("invalid operator state: {other:?}"),
+            };
+
+            if shared.probe_inputs_remaining != 0 {
+                // Other partitions may still spill probe rows.
+                shared.probe_drain_wakers[state.partition_idx] = Some(cx.waker().clone());
+                return Ok(PollPull::Pending);
+            }
+
+            let buckets = (state.partition_idx..SPILL_BUCKETS)
+                .step_by(shared.probe_partition_count)
+                .filter(|&bucket| !spilled_build[bucket].is_empty())
+                .map(|bucket| (bucket, std::mem::take(&mut shared.spilled_probe[bucket])))
+                // Buckets without probe rows only need to be drained.
+                .filter(|(_, probe)| !probe.is_empty() || self.join_requires_drain())
+                .collect();
+            state.spilled_buckets = Some(buckets);
+        }
+
+        loop {
+            let join = match state.spilled_join.as_mut() {
+                Some(join) => join,
+                None => {
+                    let (bucket, probe_files) =
+                        match state.spilled_buckets.as_mut().and_then(|b| b.pop()) {
+                            Some(next) => next,
+                            None => return Ok(PollPull::Exhausted),
+                        };
+
+                    let join =
+                        self.start_spilled_bucket_join(&spilled_build[bucket], probe_files)?;
+                    state.spilled_join.insert(join)
+                }
+            };
+
+            if join.table.is_none() {
+                match self.poll_build_spilled(cx, join, &mut state.hash_buf) {
+                    Poll::Ready(Ok(())) => (),
+                    Poll::Ready(Err(e)) => return Err(e),
+                    Poll::Pending => return Ok(PollPull::Pending),
+                }
+            }
+
+            let batch = match join.poll_next_probe_batch(cx) {
+                Poll::Ready(Ok(batch)) => batch,
+                Poll::Ready(Err(e)) => return Err(e),
+                Poll::Pending => return Ok(PollPull::Pending),
+            };
+
+            if let Some(batch) = batch {
+                let computed =
+                    ComputedBatches::new(self.probe_spilled(join, batch, &mut state.hash_buf)?);
+                if computed.has_batches() {
+                    return Ok(PollPull::Computed(computed));
+                }
+                continue;
+            }
+
+            // All probe rows joined, drain the build rows if needed.
+            if let Some(tracker) = join.left_tracker.take() {
+                let table = join.table.as_ref().expect("table to be built");
+                join.drain_state = Some(LeftOuterJoinDrainState::new(
+                    0,
+                    1,
+                    tracker,
+                    table.collected_batches().to_vec(),
+                    self.right_types.clone(),
+                ));
+            }
+
+            if let Some(drain_state) = join.drain_state.as_mut() {
+                if let Some(batch) = self.drain_next(drain_state)? {
+                    return Ok(PollPull::Computed(batch.into()));
+                }
+            }
+
+            // Bucket complete, move on to the next one.
+            state.spilled_join = None;
+        }
+    }
+
+    /// Create the state for joining a spilled bucket.
+    fn start_spilled_bucket_join(
+        &self,
+        build_files: &[SpillFile],
+        probe_files: Vec<SpillFile>,
+    ) -> Result<SpilledBucketJoin> {
+        Ok(SpilledBucketJoin {
+            build_readers: build_files
+                .iter()
+                .map(|file| file.reader())
+                .collect::<Result<_>>()?,
+            build_table: Some(PartitionHashTable::new(&self.conditions)),
+            table: None,
+            probe_readers: probe_files
+                .iter()
+                .map(|file| file.reader())
+                .collect::<Result<_>>()?,
+            _probe_files: probe_files,
+            left_tracker: None,
+            drain_state: None,
+        })
+    }
+
+    /// Poll building the hash table for a spilled bucket's build rows.
+    fn poll_build_spilled(
+        &self,
+        cx: &mut Context,
+        join: &mut SpilledBucketJoin,
+        hash_buf: &mut Vec<u64>,
+    ) -> Poll<Result<()>> {
+        let build_table = join
+            .build_table
+            .as_mut()
+            .expect("build table to exist while building");
+
+        while let Some(reader) = join.build_readers.front_mut() {
+            let batch = match reader.poll_read_batch(cx) {
+                Poll::Ready(Ok(Some(batch))) => batch,
+                Poll::Ready(Ok(None)) => {
+                    join.build_readers.pop_front();
+                    continue;
+                }
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            compute_join_hashes(self.equalities.iter().map(|eq| &eq.left), &batch, hash_buf)?;
+            build_table.insert_batch(batch, hash_buf)?;
+        }
+
+        let build_table = join.build_table.take().expect("build table to exist");
+        let table = GlobalHashTable::new(self.is_mark_join(), vec![build_table], &self.conditions);
+
+        if self.join_requires_drain() {
+            join.left_tracker = Some(LeftOuterJoinTracker::new_for_batches(
+                table.collected_batches(),
+            ));
+        }
+        join.table = Some(table);
+
+        Poll::Ready(Ok(()))
+    }
+
+    /// Probe a spilled bucket with a batch of spilled probe rows.
+    fn probe_spilled(
+        &self,
+        join: &mut SpilledBucketJoin,
+        batch: Batch,
+        hash_buf: &mut Vec<u64>,
+    ) -> Result<Vec<Batch>> {
+        // RIGHT joins spill if the rows matched any in-memory build rows as
+        // the last column.
+        let (batch, mut right_tracker) = if self.is_right_join() {
+            let mut arrays = batch.into_arrays();
+            let matched = arrays.pop().required("matched column")?;
+            let batch = Batch::try_from_arrays(arrays)?;
+
+            let mut tracker = RightOuterJoinTracker::new_for_batch(&batch);
+            let mut selection = SelectionVector::with_capacity(batch.num_rows());
+            SelectExecutor::select(&matched, &mut selection)?;
+            tracker.mark_rows_visited(selection.iter_locations());
+
+            (batch, Some(tracker))
+        } else {
+            (batch, None)
+        };
+
+        compute_join_hashes(self.equalities.iter().map(|eq| &eq.right), &batch, hash_buf)?;
+
+        let table = join.table.as_ref().expect("table to be built");
+        let mut batches = table.probe(
+            &batch,
+            hash_buf,
+            join.left_tracker.as_mut(),
+            right_tracker.as_mut(),
+        )?;
+
+        if let Some(right_tracker) = right_tracker {
+            if let Some(extra) = right_tracker.into_unvisited(&self.left_types, &batch)? {
+                batches.push(extra);
+            }
+        }
+
+        Ok(batches)
+    }
+}
+
+/// Compute the hashes for the join keys in a batch.
+fn compute_join_hashes<'a>(
+    exprs: impl Iterator<Item = &'a PhysicalScalarExpression>,
+    batch: &Batch,
+    hash_buf: &mut Vec<u64>,
+) -> Result<()> {
+    hash_buf.clear();
+    hash_buf.resize(batch.num_rows(), 0);
+
+    for (idx, expr) in exprs.enumerate() {
+        let result = expr.eval(batch)?;
+
+        if idx == 0 {
+            HashExecutor::hash_no_combine(&result, hash_buf)?;
+        } else {
+            HashExecutor::hash_combine(&result, hash_buf)?;
+        }
+    }
+
+    Ok(())
+}
+
+/// Get the spill bucket for a hash.
+fn spill_bucket_for_hash(hash: u64) -> usize {
+    // Rotate so that buckets don't line up with hash repartitioning, which
+    // would put all of a partition's rows in the same bucket.
+    partition_for_hash(hash.rotate_left(32), SPILL_BUCKETS)
+}
+
+/// Spill probe rows that might match spilled build rows.
+///
+/// For RIGHT joins, whether or not each row matched an in-memory build row is
+/// spilled as an extra column. The rows are then marked as visited so they're
+/// only emitted as unmatched once joined with the spilled build rows.
+fn spill_probe_rows(
+    spill_provider: Option<&dyn SpillProvider>,
+    buffer_manager: &Arc<LimitedBufferManager>,
+    files: &mut [Option<SpillFile>],
+    spilled_build: &SpillBuckets,
+    batch: &Batch,
+    hashes: &[u64],
+    right_tracker: Option<&mut RightOuterJoinTracker>,
+) -> Result<()> {
+    let include_bucket = |bucket: usize| !spilled_build[bucket].is_empty();
+
+    let right_tracker = match right_tracker {
+        Some(tracker) => tracker,
+        None => {
+            return spill_by_bucket(
+                spill_provider,
+                buffer_manager,
+                files,
+                batch,
+                hashes,
+                include_bucket,
+            )
+        }
+    };
+
+    let mut matched = right_tracker.unvisited.clone();
+    matched.bit_negate();
+    let matched = Array::new_with_array_data(
+        DataType::Boolean,
+        ArrayData2::Boolean(Arc::new(matched.into())),
+    );
+    let with_matched = Batch::try_from_arrays(batch.arrays().iter().cloned().chain([matched]))?;
+    spill_by_bucket(
+        spill_provider,
+        buffer_manager,
+        files,
+        &with_matched,
+        hashes,
+        include_bucket,
+    )?;
+
+    right_tracker.mark_rows_visited(
+        hashes
+            .iter()
+            .enumerate()
+            .filter(|(_, hash)| include_bucket(spill_bucket_for_hash(**hash)))
+            .map(|(row_idx, _)| row_idx),
+    );
+
+    Ok(())
+}
+
+/// Write rows from a batch into spill files according to their hash.
+///
+/// Only rows for buckets where `include_bucket` returns true are written.
+#[allow(deprecated)]
+fn spill_by_bucket(
+    spill_provider: Option<&dyn SpillProvider>,
+    buffer_manager: &Arc<LimitedBufferManager>,
+    files: &mut [Option<SpillFile>],
+    batch: &Batch,
+    hashes: &[u64],
+    include_bucket: impl Fn(usize) -> bool,
+) -> Result<()> {
+    let mut selections: Vec<_> = (0..SPILL_BUCKETS)
+        .map(|_| SelectionVector::empty())
+        .collect();
+
+    for (row_idx, hash) in hashes.iter().enumerate() {
+        selections[spill_bucket_for_hash(*hash)].push_location(row_idx);
+    }
+
+    for (bucket, selection) in selections.into_iter().enumerate() {
+        if selection.is_empty() || !include_bucket(bucket) {
+            continue;
+        }
+
+        let selected = batch.select_old(Arc::new(selection));
+        let file = match &mut files[bucket] {
+            Some(file) => file,
+            file @ None => file.insert(SpillFile::try_new(
+                spill_provider,
+                buffer_manager,
+                batch
+                    .arrays()
+                    .iter()
+                    .map(|arr| arr.datatype().clone())
+                    .collect(),
+            )?),
+        };
+        file.write_batch(&selected)?;
+    }
+
+    Ok(())
 }
 
 impl Explainable for PhysicalHashJoin {
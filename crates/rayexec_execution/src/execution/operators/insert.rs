@@ -3,7 +3,6 @@ use std::sync::Arc;
 use rayexec_error::{OptionExt, RayexecError, Result};
 
 use super::sink::{PartitionSink, SinkOperation, SinkOperator};
-use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -24,15 +23,12 @@ impl SinkOperation for InsertOperation {
         context: &DatabaseContext,
         num_sinks: usize,
     ) -> Result<Vec<Box<dyn PartitionSink>>> {
-        // TODO: Placeholder.
-        let _tx = CatalogTx::new();
-
         let database = context.get_database(&self.catalog)?;
         let data_table = database
             .table_storage
             .as_ref()
             .ok_or_else(|| RayexecError::new("Missing table storage for insert"))?
-            .data_table(&self.schema, &self.table)?;
+            .data_table(context.catalog_tx(), &self.schema, &self.table)?;
 
         // TODO: Pass constraints, on conflict
         let inserts = data_table.insert(num_sinks)?;
@@ -0,0 +1,350 @@
+use std::collections::HashSet;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+
+use super::sink::{PartitionSink, SinkOperation, SinkOperator};
+use crate::arrays::array::physical_type::{PhysicalI32, PhysicalU64};
+use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::arrays::row::{OwnedScalarRow, ScalarRow};
+use crate::arrays::scalar::ScalarValue;
+use crate::arrays::selection::SelectionVector;
+use crate::database::catalog_entry::CatalogEntry;
+use crate::database::DatabaseContext;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::logical::logical_modify::{InsertConflict, MergeAction};
+use crate::storage::table_storage::{DataTableDelete, DataTableUpdate};
+
+pub type PhysicalMerge = SinkOperator<MergeOperation>;
+
+#[derive(Debug)]
+pub struct MergeOperation {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+    pub actions: Vec<MergeAction>,
+    pub insert_conflict: Option<InsertConflict>,
+}
+
+/// State shared across all partitions of a merge.
+#[derive(Debug)]
+struct MergeSharedState {
+    /// Row ids of the table rows updated or deleted so far.
+    modified_row_ids: HashSet<u64>,
+    /// Conflict column values for the rows inserted so far.
+    inserted_keys: HashSet<OwnedScalarRow>,
+    /// Number of partitions that haven't been finalized yet.
+    partitions_remaining: usize,
+    /// Table sinks from partitions that have been finalized.
+    ///
+    /// Table sinks are only finalized once every partition is done, so a merge
+    /// that errors in one partition doesn't leave changes from another
+    /// partition in the table.
+    pending: Vec<TableSinks>,
+}
+
+/// Sinks for writing to the table for a single partition.
+#[derive(Debug)]
+struct TableSinks {
+    insert: Option<Box<dyn PartitionSink>>,
+    update: Option<Box<dyn DataTableUpdate>>,
+    delete: Option<Box<dyn DataTableDelete>>,
+}
+
+impl SinkOperation for MergeOperation {
+    fn create_partition_sinks(
+        &self,
+        context: &DatabaseContext,
+        num_sinks: usize,
+    ) -> Result<Vec<Box<dyn PartitionSink>>> {
+        let database = context.get_database(&self.catalog)?;
+        let data_table = database
+            .table_storage
+            .as_ref()
+            .ok_or_else(|| RayexecError::new("Missing table storage for merge"))?
+            .data_table(context.catalog_tx(), &self.schema, &self.table)?;
+
+        let num_columns = self.table.try_as_table_entry()?.columns.len();
+
+        // Only create the sinks needed by the actions.
+        let has_action = |f: fn(&MergeAction) -> bool| self.actions.iter().any(f);
+
+        let mut inserts = if has_action(|a| matches!(a, MergeAction::Insert { .. })) {
+            data_table
+                .insert(num_sinks)?
+                .into_iter()
+                .map(Some)
+                .collect()
+        } else {
+            (0..num_sinks).map(|_| None).collect::<Vec<_>>()
+        };
+        let mut updates = if has_action(|a| matches!(a, MergeAction::Update { .. })) {
+            data_table
+                .update(num_sinks)?
+                .into_iter()
+                .map(Some)
+                .collect()
+        } else {
+            (0..num_sinks).map(|_| None).collect::<Vec<_>>()
+        };
+        let mut deletes = if has_action(|a| matches!(a, MergeAction::Delete)) {
+            data_table
+                .delete(num_sinks)?
+                .into_iter()
+                .map(Some)
+                .collect()
+        } else {
+            (0..num_sinks).map(|_| None).collect::<Vec<_>>()
+        };
+
+        let shared = Arc::new(Mutex::new(MergeSharedState {
+            modified_row_ids: HashSet::new(),
+            inserted_keys: HashSet::new(),
+            partitions_remaining: num_sinks,
+            pending: Vec::new(),
+        }));
+
+        let sinks = (0..num_sinks)
+            .map(|_| {
+                Box::new(MergePartitionSink {
+                    actions: self.actions.clone(),
+                    insert_conflict: self.insert_conflict.clone(),
+                    shared: shared.clone(),
+                    num_rows_skipped: 0,
+                    num_columns,
+                    sinks: Some(TableSinks {
+                        insert: inserts.pop().flatten(),
+                        update: updates.pop().flatten(),
+                        delete: deletes.pop().flatten(),
+                    }),
+                }) as _
+            })
+            .collect();
+
+        Ok(sinks)
+    }
+
+    fn partition_requirement(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Explainable for MergeOperation {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Merge").with_value("table", &self.table.name)
+    }
+}
+
+/// Routes rows to the insert, update, or delete sink for the table depending
+/// on the action for the row.
+#[derive(Debug)]
+struct MergePartitionSink {
+    actions: Vec<MergeAction>,
+    insert_conflict: Option<InsertConflict>,
+    shared: Arc<Mutex<MergeSharedState>>,
+    /// Rows not inserted due to conflicting with another inserted row.
+    num_rows_skipped: usize,
+    /// Number of columns in the table.
+    num_columns: usize,
+    /// Sinks for this partition, taken on finalize.
+    sinks: Option<TableSinks>,
+}
+
+impl PartitionSink for MergePartitionSink {
+    #[allow(deprecated)]
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async move {
+            let action_indices = batch
+                .array(0)
+                .ok_or_else(|| RayexecError::new("Missing action column for merge"))?;
+
+            let mut selections = vec![Vec::new(); self.actions.len()];
+            UnaryExecutor::for_each2::<PhysicalI32, _>(action_indices, |idx, action| {
+                if let Some(action) = action {
+                    selections[action as usize].push(idx);
+                }
+            })?;
+
+            let actions = self.actions.clone();
+            for (action, selection) in actions.iter().zip(selections) {
+                if selection.is_empty() {
+                    continue;
+                }
+
+                let selection = match action {
+                    MergeAction::Insert { offset } => {
+                        self.check_insert_conflicts(&batch, *offset, selection)?
+                    }
+                    MergeAction::Update { .. } | MergeAction::Delete => {
+                        self.check_modified_rows(&batch, &selection)?;
+                        selection
+                    }
+                };
+                if selection.is_empty() {
+                    continue;
+                }
+
+                let selected = batch.select_old(Arc::new(SelectionVector::from_iter(selection)));
+                let sinks = self
+                    .sinks
+                    .as_mut()
+                    .ok_or_else(|| RayexecError::new("Merge sink already finalized"))?;
+
+                match action {
+                    MergeAction::Insert { offset } => {
+                        let columns: Vec<_> = (*offset..offset + self.num_columns).collect();
+                        let sink = sinks.insert.as_mut().expect("insert sink to exist");
+                        sink.push(selected.project(&columns)).await?;
+                    }
+                    MergeAction::Update { offset } => {
+                        // Row id followed by the new values.
+                        let columns: Vec<_> = std::iter::once(1)
+                            .chain(*offset..offset + self.num_columns)
+                            .collect();
+                        let sink = sinks.update.as_mut().expect("update sink to exist");
+                        sink.push(selected.project(&columns)).await?;
+                    }
+                    MergeAction::Delete => {
+                        let sink = sinks.delete.as_mut().expect("delete sink to exist");
+                        sink.push(selected.project(&[1])).await?;
+                    }
+                }
+            }
+
+            Ok(())
+        })
+    }
+
+    fn num_rows_skipped(&self) -> usize {
+        self.num_rows_skipped
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async {
+            let sinks = self
+                .sinks
+                .take()
+                .ok_or_else(|| RayexecError::new("Merge sink already finalized"))?;
+
+            // Only the last partition to finish writes to the table.
+            let pending = {
+                let mut shared = self.shared.lock();
+                shared.pending.push(sinks);
+                shared.partitions_remaining -= 1;
+                if shared.partitions_remaining > 0 {
+                    return Ok(());
+                }
+                std::mem::take(&mut shared.pending)
+            };
+
+            // Inserts only append to the table, and updates happen in place,
+            // so row ids stay valid for the delete.
+            for mut sinks in pending {
+                if let Some(insert) = &mut sinks.insert {
+                    insert.finalize().await?;
+                }
+                if let Some(update) = &mut sinks.update {
+                    update.finalize().await?;
+                }
+                if let Some(delete) = &mut sinks.delete {
+                    delete.finalize().await?;
+                }
+            }
+
+            Ok(())
+        })
+    }
+}
+
+impl MergePartitionSink {
+    /// Name of the statement to use in errors.
+    fn statement_name(&self) -> &'static str {
+        if self.insert_conflict.is_some() {
+            "ON CONFLICT DO UPDATE"
+        } else {
+            "MERGE"
+        }
+    }
+
+    /// Check that none of the selected rows update or delete a table row
+    /// that's already been modified by this statement.
+    fn check_modified_rows(&self, batch: &Batch, selection: &[usize]) -> Result<()> {
+        let row_ids = batch
+            .array(1)
+            .ok_or_else(|| RayexecError::new("Missing row id column for merge"))?;
+
+        let mut shared = self.shared.lock();
+        for &idx in selection {
+            let row_id = UnaryExecutor::value_at2::<PhysicalU64>(row_ids, idx)?
+                .ok_or_else(|| RayexecError::new("Unexpected NULL row id for merge"))?;
+
+            if !shared.modified_row_ids.insert(row_id) {
+                return Err(RayexecError::new(format!(
+                    "{} command cannot affect row a second time",
+                    self.statement_name()
+                ))
+                .with_field(
+                    "hint",
+                    "Ensure that no more than one source row matches any one target row",
+                ));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Check the rows being inserted for conflicts with other rows inserted by
+    /// this statement, returning the rows that should be inserted.
+    ///
+    /// Rows with a NULL in any of the conflict columns never conflict.
+    fn check_insert_conflicts(
+        &mut self,
+        batch: &Batch,
+        offset: usize,
+        selection: Vec<usize>,
+    ) -> Result<Vec<usize>> {
+        let conflict = match &self.insert_conflict {
+            Some(conflict) => conflict,
+            None => return Ok(selection),
+        };
+
+        let keys = conflict
+            .columns
+            .iter()
+            .map(|&col| {
+                batch
+                    .array(offset + col)
+                    .ok_or_else(|| RayexecError::new("Missing conflict column for merge"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let mut shared = self.shared.lock();
+        let mut keep = Vec::with_capacity(selection.len());
+        for idx in selection {
+            let key = ScalarRow::try_new_from_arrays(&keys, idx)?;
+            if key.iter().any(|v| matches!(v, ScalarValue::Null)) {
+                keep.push(idx);
+                continue;
+            }
+
+            if shared.inserted_keys.insert(key.into_owned()) {
+                keep.push(idx);
+            } else if conflict.error_on_conflict {
+                return Err(RayexecError::new(
+                    "ON CONFLICT DO UPDATE command cannot affect row a second time",
+                )
+                .with_field(
+                    "hint",
+                    "Ensure that no two rows being inserted have the same conflict values",
+                ));
+            } else {
+                self.num_rows_skipped += 1;
+            }
+        }
+
+        Ok(keep)
+    }
+}
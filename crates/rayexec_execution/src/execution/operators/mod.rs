@@ -6,6 +6,7 @@ pub mod copy_to;
 pub mod create_schema;
 pub mod create_table;
 pub mod create_view;
+pub mod delete;
 pub mod drop;
 pub mod empty;
 pub mod filter;
@@ -14,8 +15,10 @@ pub mod hash_join;
 pub mod insert;
 pub mod limit;
 pub mod materialize;
+pub mod merge;
 pub mod nl_join;
 pub mod project;
+pub mod recursive_cte;
 pub mod round_robin;
 pub mod scan;
 pub mod simple;
@@ -27,6 +30,7 @@ pub mod table_inout;
 pub mod ungrouped_aggregate;
 pub mod union;
 pub mod unnest;
+pub mod update;
 pub mod values;
 pub mod window;
 
@@ -39,11 +43,13 @@ use std::fmt::Debug;
 use std::sync::Arc;
 use std::task::Context;
 
+use analyze::{AnalyzePartitionState, PhysicalAnalyze};
 use batch_resizer::{BatchResizerPartitionState, PhysicalBatchResizer};
 use copy_to::PhysicalCopyTo;
 use create_schema::{CreateSchemaPartitionState, PhysicalCreateSchema};
 use create_table::PhysicalCreateTable;
 use create_view::{CreateViewPartitionState, PhysicalCreateView};
+use delete::PhysicalDelete;
 use drop::{DropPartitionState, PhysicalDrop};
 use empty::PhysicalEmpty;
 use filter::{FilterOperation, PhysicalFilter};
@@ -57,15 +63,18 @@ use hash_join::{
 use insert::PhysicalInsert;
 use limit::PhysicalLimit;
 use materialize::{MaterializeSourceOperation, MaterializedSinkOperation};
+use merge::PhysicalMerge;
 use nl_join::PhysicalNestedLoopJoin;
 use project::{PhysicalProject, ProjectOperation};
 use rayexec_error::{not_implemented, OptionExt, Result};
+use recursive_cte::{PhysicalRecursiveCte, RecursiveCteOperatorState, RecursiveCtePartitionState};
 use round_robin::PhysicalRoundRobinRepartition;
 use scan::{PhysicalScan, ScanPartitionState};
 use simple::SimpleOperator;
 use sink::{SinkOperation, SinkOperator, SinkOperatorState, SinkPartitionState};
 use sort::gather_sort::PhysicalGatherSort;
 use sort::scatter_sort::PhysicalScatterSort;
+use sort::top_k::PhysicalTopK;
 use source::{SourceOperation, SourceOperator, SourcePartitionState};
 use table_function::{PhysicalTableFunction, TableFunctionPartitionState};
 use table_inout::{PhysicalTableInOut, TableInOutPartitionState};
@@ -76,8 +85,9 @@ use ungrouped_aggregate::{
 };
 use union::{PhysicalUnion, UnionBottomPartitionState, UnionOperatorState, UnionTopPartitionState};
 use unnest::{PhysicalUnnest, UnnestPartitionState};
+use update::PhysicalUpdate;
 use values::PhysicalValues;
-use window::PhysicalWindow;
+use window::{PhysicalWindow, WindowOperatorState, WindowPartitionState};
 
 use self::empty::EmptyPartitionState;
 use self::hash_aggregate::{HashAggregateOperatorState, HashAggregatePartitionState};
@@ -99,6 +109,7 @@ use self::sort::gather_sort::{
     GatherSortPushPartitionState,
 };
 use self::sort::scatter_sort::ScatterSortPartitionState;
+use self::sort::top_k::{TopKOperatorState, TopKPullPartitionState, TopKPushPartitionState};
 use self::values::ValuesPartitionState;
 use super::computed_batch::ComputedBatches;
 use crate::arrays::batch::Batch;
@@ -125,6 +136,8 @@ pub enum PartitionState {
     GatherSortPush(GatherSortPushPartitionState),
     GatherSortPull(GatherSortPullPartitionState),
     ScatterSort(ScatterSortPartitionState),
+    TopKPush(TopKPushPartitionState),
+    TopKPull(TopKPullPartitionState),
     Limit(LimitPartitionState),
     Unnest(UnnestPartitionState),
     UnionTop(UnionTopPartitionState),
@@ -138,9 +151,27 @@ pub enum PartitionState {
     Drop(DropPartitionState),
     Empty(EmptyPartitionState),
     BatchResizer(BatchResizerPartitionState),
+    Window(WindowPartitionState),
+    RecursiveCte(RecursiveCtePartitionState),
+    Analyze(AnalyzePartitionState),
     None,
 }
 
+impl PartitionState {
+    /// Number of bytes currently reserved through the buffer manager by this
+    /// partition.
+    ///
+    /// Only operators that track their memory usage report a non-zero value.
+    pub fn reserved_memory(&self) -> usize {
+        match self {
+            Self::HashAggregate(s) => s.reserved_memory(),
+            Self::HashJoinBuild(s) => s.reserved_memory(),
+            Self::ScatterSort(s) => s.reserved_memory(),
+            _ => 0,
+        }
+    }
+}
+
 /// A global state across all partitions in an operator.
 // Current size: 144 bytes
 #[derive(Debug)]
@@ -151,8 +182,11 @@ pub enum OperatorState {
     HashJoin(HashJoinOperatorState),
     RoundRobin(RoundRobinOperatorState),
     GatherSort(GatherSortOperatorState),
+    TopK(TopKOperatorState),
     Union(UnionOperatorState),
     Sink(SinkOperatorState),
+    Window(WindowOperatorState),
+    RecursiveCte(RecursiveCteOperatorState),
     None,
 }
 
@@ -318,6 +352,7 @@ pub enum PhysicalOperator {
     HashAggregate(PhysicalHashAggregate),
     UngroupedAggregate(PhysicalUngroupedAggregate),
     Window(PhysicalWindow),
+    RecursiveCte(PhysicalRecursiveCte),
     NestedLoopJoin(PhysicalNestedLoopJoin),
     HashJoin(PhysicalHashJoin),
     Values(PhysicalValues),
@@ -329,6 +364,7 @@ pub enum PhysicalOperator {
     RoundRobin(PhysicalRoundRobinRepartition),
     MergeSorted(PhysicalGatherSort),
     LocalSort(PhysicalScatterSort),
+    TopK(PhysicalTopK),
     Limit(PhysicalLimit),
     Union(PhysicalUnion),
     Filter(SimpleOperator<FilterOperation>),
@@ -338,6 +374,9 @@ pub enum PhysicalOperator {
     TableFunction(PhysicalTableFunction),
     TableInOut(PhysicalTableInOut),
     Insert(PhysicalInsert),
+    Update(PhysicalUpdate),
+    Delete(PhysicalDelete),
+    Merge(PhysicalMerge),
     CopyTo(PhysicalCopyTo),
     CreateTable(PhysicalCreateTable),
     CreateSchema(PhysicalCreateSchema),
@@ -345,6 +384,7 @@ pub enum PhysicalOperator {
     Drop(PhysicalDrop),
     Empty(PhysicalEmpty),
     BatchResizer(PhysicalBatchResizer),
+    Analyze(PhysicalAnalyze),
 }
 
 impl ExecutableOperator for PhysicalOperator {
@@ -357,6 +397,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::HashAggregate(op) => op.create_states(context, partitions),
             Self::UngroupedAggregate(op) => op.create_states(context, partitions),
             Self::Window(op) => op.create_states(context, partitions),
+            Self::RecursiveCte(op) => op.create_states(context, partitions),
             Self::NestedLoopJoin(op) => op.create_states(context, partitions),
             Self::HashJoin(op) => op.create_states(context, partitions),
             Self::Values(op) => op.create_states(context, partitions),
@@ -368,6 +409,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.create_states(context, partitions),
             Self::MergeSorted(op) => op.create_states(context, partitions),
             Self::LocalSort(op) => op.create_states(context, partitions),
+            Self::TopK(op) => op.create_states(context, partitions),
             Self::Limit(op) => op.create_states(context, partitions),
             Self::Union(op) => op.create_states(context, partitions),
             Self::Filter(op) => op.create_states(context, partitions),
@@ -377,6 +419,9 @@ impl ExecutableOperator for PhysicalOperator {
             Self::TableFunction(op) => op.create_states(context, partitions),
             Self::TableInOut(op) => op.create_states(context, partitions),
             Self::Insert(op) => op.create_states(context, partitions),
+            Self::Update(op) => op.create_states(context, partitions),
+            Self::Delete(op) => op.create_states(context, partitions),
+            Self::Merge(op) => op.create_states(context, partitions),
             Self::CopyTo(op) => op.create_states(context, partitions),
             Self::CreateTable(op) => op.create_states(context, partitions),
             Self::CreateSchema(op) => op.create_states(context, partitions),
@@ -384,6 +429,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.create_states(context, partitions),
             Self::Empty(op) => op.create_states(context, partitions),
             Self::BatchResizer(op) => op.create_states(context, partitions),
+            Self::Analyze(op) => op.create_states(context, partitions),
         }
     }
 
@@ -400,6 +446,7 @@ impl ExecutableOperator for PhysicalOperator {
                 op.poll_push(cx, partition_state, operator_state, batch)
             }
             Self::Window(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::RecursiveCte(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::NestedLoopJoin(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::HashJoin(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Values(op) => op.poll_push(cx, partition_state, operator_state, batch),
@@ -413,6 +460,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::MergeSorted(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::LocalSort(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::TopK(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Limit(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Union(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Filter(op) => op.poll_push(cx, partition_state, operator_state, batch),
@@ -422,6 +470,9 @@ impl ExecutableOperator for PhysicalOperator {
             Self::TableFunction(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::TableInOut(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Insert(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Update(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Delete(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Merge(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::CopyTo(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::CreateTable(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::CreateSchema(op) => op.poll_push(cx, partition_state, operator_state, batch),
@@ -429,6 +480,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::Empty(op) => op.poll_push(cx, partition_state, operator_state, batch),
             Self::BatchResizer(op) => op.poll_push(cx, partition_state, operator_state, batch),
+            Self::Analyze(op) => op.poll_push(cx, partition_state, operator_state, batch),
         }
     }
 
@@ -444,6 +496,7 @@ impl ExecutableOperator for PhysicalOperator {
                 op.poll_finalize_push(cx, partition_state, operator_state)
             }
             Self::Window(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::RecursiveCte(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::NestedLoopJoin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::HashJoin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Values(op) => op.poll_finalize_push(cx, partition_state, operator_state),
@@ -459,6 +512,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::MergeSorted(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::LocalSort(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::TopK(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Limit(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Union(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Filter(op) => op.poll_finalize_push(cx, partition_state, operator_state),
@@ -468,6 +522,9 @@ impl ExecutableOperator for PhysicalOperator {
             Self::TableFunction(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::TableInOut(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Insert(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Update(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Delete(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Merge(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::CopyTo(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::CreateTable(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::CreateSchema(op) => op.poll_finalize_push(cx, partition_state, operator_state),
@@ -475,6 +532,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::Empty(op) => op.poll_finalize_push(cx, partition_state, operator_state),
             Self::BatchResizer(op) => op.poll_finalize_push(cx, partition_state, operator_state),
+            Self::Analyze(op) => op.poll_finalize_push(cx, partition_state, operator_state),
         }
     }
 
@@ -488,6 +546,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::HashAggregate(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::UngroupedAggregate(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Window(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::RecursiveCte(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::NestedLoopJoin(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::HashJoin(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Values(op) => op.poll_pull(cx, partition_state, operator_state),
@@ -499,6 +558,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::RoundRobin(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::MergeSorted(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::LocalSort(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::TopK(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Limit(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Union(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Filter(op) => op.poll_pull(cx, partition_state, operator_state),
@@ -508,6 +568,9 @@ impl ExecutableOperator for PhysicalOperator {
             Self::TableFunction(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::TableInOut(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Insert(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Update(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Delete(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Merge(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::CopyTo(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::CreateTable(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::CreateSchema(op) => op.poll_pull(cx, partition_state, operator_state),
@@ -515,6 +578,7 @@ impl ExecutableOperator for PhysicalOperator {
             Self::Drop(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::Empty(op) => op.poll_pull(cx, partition_state, operator_state),
             Self::BatchResizer(op) => op.poll_pull(cx, partition_state, operator_state),
+            Self::Analyze(op) => op.poll_pull(cx, partition_state, operator_state),
         }
     }
 }
@@ -525,6 +589,7 @@ impl Explainable for PhysicalOperator {
             Self::HashAggregate(op) => op.explain_entry(conf),
             Self::UngroupedAggregate(op) => op.explain_entry(conf),
             Self::Window(op) => op.explain_entry(conf),
+            Self::RecursiveCte(op) => op.explain_entry(conf),
             Self::NestedLoopJoin(op) => op.explain_entry(conf),
             Self::HashJoin(op) => op.explain_entry(conf),
             Self::Values(op) => op.explain_entry(conf),
@@ -536,6 +601,7 @@ impl Explainable for PhysicalOperator {
             Self::RoundRobin(op) => op.explain_entry(conf),
             Self::MergeSorted(op) => op.explain_entry(conf),
             Self::LocalSort(op) => op.explain_entry(conf),
+            Self::TopK(op) => op.explain_entry(conf),
             Self::Limit(op) => op.explain_entry(conf),
             Self::Union(op) => op.explain_entry(conf),
             Self::Filter(op) => op.explain_entry(conf),
@@ -545,6 +611,9 @@ impl Explainable for PhysicalOperator {
             Self::TableFunction(op) => op.explain_entry(conf),
             Self::TableInOut(op) => op.explain_entry(conf),
             Self::Insert(op) => op.explain_entry(conf),
+            Self::Update(op) => op.explain_entry(conf),
+            Self::Delete(op) => op.explain_entry(conf),
+            Self::Merge(op) => op.explain_entry(conf),
             Self::CopyTo(op) => op.explain_entry(conf),
             Self::CreateTable(op) => op.explain_entry(conf),
             Self::CreateSchema(op) => op.explain_entry(conf),
@@ -552,6 +621,7 @@ impl Explainable for PhysicalOperator {
             Self::Drop(op) => op.explain_entry(conf),
             Self::Empty(op) => op.explain_entry(conf),
             Self::BatchResizer(op) => op.explain_entry(conf),
+            Self::Analyze(op) => op.explain_entry(conf),
         }
     }
 }
@@ -580,6 +650,7 @@ impl DatabaseProtoConv for PhysicalOperator {
             Self::CopyTo(op) => Value::CopyTo(op.to_proto_ctx(context)?),
             Self::LocalSort(op) => Value::LocalSort(op.to_proto_ctx(context)?),
             Self::MergeSorted(op) => Value::MergeSorted(op.to_proto_ctx(context)?),
+            Self::TopK(op) => Value::TopK(op.to_proto_ctx(context)?),
             other => not_implemented!("to proto: {other:?}"),
         };
 
@@ -637,6 +708,7 @@ impl DatabaseProtoConv for PhysicalOperator {
             Value::MergeSorted(op) => {
                 PhysicalOperator::MergeSorted(PhysicalGatherSort::from_proto_ctx(op, context)?)
             }
+            Value::TopK(op) => PhysicalOperator::TopK(PhysicalTopK::from_proto_ctx(op, context)?),
         })
     }
 }
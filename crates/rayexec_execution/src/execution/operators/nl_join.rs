@@ -2,11 +2,16 @@ use std::sync::Arc;
 use std::task::{Context, Waker};
 
 use parking_lot::Mutex;
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
-use super::util::outer_join_tracker::LeftOuterJoinTracker;
+use super::util::outer_join_tracker::{
+    LeftOuterJoinDrainState,
+    LeftOuterJoinTracker,
+    RightOuterJoinTracker,
+};
 use super::ComputedBatches;
 use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
 use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
 use crate::execution::operators::{
@@ -80,6 +85,11 @@ pub struct NestedLoopJoinProbePartitionState {
 
     /// Track rows visited on the left side for this partition.
     partition_outer_join_tracker: Option<LeftOuterJoinTracker>,
+
+    /// State for tracking rows on the left side that we still need to emit.
+    ///
+    /// Initialized once all probe partitions have finished.
+    outer_join_drain_state: Option<LeftOuterJoinDrainState>,
 }
 
 impl NestedLoopJoinProbePartitionState {
@@ -93,6 +103,7 @@ impl NestedLoopJoinProbePartitionState {
             pull_waker: None,
             input_finished: false,
             partition_outer_join_tracker: None,
+            outer_join_drain_state: None,
         }
     }
 }
@@ -149,6 +160,19 @@ enum SharedOperatorState {
         ///
         /// Referenced with draining unvisited rows in the case of a LEFT join.
         global_outer_join_tracker: Option<LeftOuterJoinTracker>,
+
+        /// Number of partitions we're still waiting to complete on the probe
+        /// side.
+        ///
+        /// Unvisited rows on the left can only be drained once all probe
+        /// partitions have merged their bitmaps into the global tracker.
+        probe_partitions_remaining: usize,
+
+        /// Wakers for partitions on the probe side waiting for all other
+        /// probe partitions to complete before draining.
+        ///
+        /// Indexed by partition index on the probe side.
+        probe_drain_wakers: Vec<Option<Waker>>,
     },
 }
 
@@ -157,7 +181,7 @@ impl SharedOperatorState {
     ///
     /// Must be called when number of partitions remaining on the build side is
     /// zero.
-    fn transition_into_probing(&mut self, join_type: JoinType) {
+    fn transition_into_probing(&mut self, requires_drain: bool) {
         match self {
             Self::Building {
                 batches,
@@ -167,23 +191,25 @@ impl SharedOperatorState {
                 assert_eq!(0, *build_partitions_remaining);
 
                 // Wake any pending probers.
-                for waker in probe_side_wakers {
+                for waker in probe_side_wakers.iter_mut() {
                     if let Some(waker) = waker.take() {
                         waker.wake();
                     }
                 }
 
-                let global_outer_join_tracker = match join_type {
-                    JoinType::Left | JoinType::Full => {
-                        Some(LeftOuterJoinTracker::new_for_batches(batches))
-                    }
-                    _ => None,
+                let global_outer_join_tracker = if requires_drain {
+                    Some(LeftOuterJoinTracker::new_for_batches(batches))
+                } else {
+                    None
                 };
 
+                let num_probe_partitions = probe_side_wakers.len();
                 let batches = std::mem::take(batches);
                 *self = Self::Probing {
                     batches: Arc::new(batches),
                     global_outer_join_tracker,
+                    probe_partitions_remaining: num_probe_partitions,
+                    probe_drain_wakers: vec![None; num_probe_partitions],
                 }
             }
             Self::Probing { .. } => panic!("inner state is already probing"),
@@ -197,14 +223,56 @@ pub struct PhysicalNestedLoopJoin {
     /// Filter to apply after cross joining batches.
     filter: Option<PhysicalScalarExpression>,
     join_type: JoinType,
+    /// Types for the batches we'll be receiving from the left side. Used during
+    /// RIGHT joins to produce null columns on the left side.
+    left_types: Vec<DataType>,
+    /// Types for the batches we'll be receiving from the right side. Used
+    /// during LEFT joins to produce null columns on the right side.
+    right_types: Vec<DataType>,
 }
 
 impl PhysicalNestedLoopJoin {
     pub const BUILD_SIDE_INPUT_INDEX: usize = 0;
     pub const PROBE_SIDE_INPUT_INDEX: usize = 1;
 
-    pub fn new(filter: Option<PhysicalScalarExpression>, join_type: JoinType) -> Self {
-        PhysicalNestedLoopJoin { filter, join_type }
+    pub fn new(
+        filter: Option<PhysicalScalarExpression>,
+        join_type: JoinType,
+        left_types: Vec<DataType>,
+        right_types: Vec<DataType>,
+    ) -> Self {
+        PhysicalNestedLoopJoin {
+            filter,
+            join_type,
+            left_types,
+            right_types,
+        }
+    }
+
+    /// If we need to track visited rows on the left and drain them once all
+    /// probe partitions complete.
+    const fn join_requires_drain(&self) -> bool {
+        matches!(
+            self.join_type,
+            JoinType::Left
+                | JoinType::Full
+                | JoinType::Semi
+                | JoinType::Anti
+                | JoinType::LeftMark { .. }
+        )
+    }
+
+    const fn is_right_join(&self) -> bool {
+        matches!(self.join_type, JoinType::Full | JoinType::Right)
+    }
+
+    /// If we should only be updating the visit bitmaps, and not emitting joined
+    /// rows.
+    const fn is_mark_join(&self) -> bool {
+        matches!(
+            self.join_type,
+            JoinType::Semi | JoinType::Anti | JoinType::LeftMark { .. }
+        )
     }
 }
 
@@ -278,6 +346,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                         SharedOperatorState::Probing {
                             batches,
                             global_outer_join_tracker,
+                            ..
                         } => {
                             // Otherwise the batches are ready for us. Clone the
                             // reference into our local state.
@@ -301,6 +370,12 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                     return Ok(PollPush::Pending(batch));
                 }
 
+                let mut right_outer_tracker = if self.is_right_join() {
+                    Some(RightOuterJoinTracker::new_for_batch(&batch))
+                } else {
+                    None
+                };
+
                 // Do the join.
                 let mut batches = Vec::new();
                 for (left_idx, left) in state.all_batches.iter().enumerate() {
@@ -310,11 +385,22 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                         &batch,
                         self.filter.as_ref(),
                         state.partition_outer_join_tracker.as_mut(),
-                        false,
+                        right_outer_tracker.as_mut(),
+                        self.is_mark_join(),
                     )?;
                     batches.append(&mut out);
                 }
 
+                // Append rows from the right that didn't join with anything on
+                // the left.
+                if let Some(right_outer_tracker) = right_outer_tracker {
+                    if let Some(extra) =
+                        right_outer_tracker.into_unvisited(&self.left_types, &batch)?
+                    {
+                        batches.push(extra);
+                    }
+                }
+
                 state.buffered = ComputedBatches::new(batches);
                 if state.buffered.is_empty() {
                     // Nothing produces, signal to push more.
@@ -334,7 +420,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
 
     fn poll_finalize_push(
         &self,
-        _cx: &mut Context,
+        cx: &mut Context,
         partition_state: &mut PartitionState,
         operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
@@ -361,7 +447,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                         // If we're the last build partition, go ahead and
                         // transition the global state to begin probing.
                         if *build_partitions_remaining == 0 {
-                            inner.transition_into_probing(self.join_type);
+                            inner.transition_into_probing(self.join_requires_drain());
                         }
 
                         // And we're done.
@@ -371,11 +457,64 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                 }
             }
             PartitionState::NestedLoopJoinProbe(state) => {
-                state.input_finished = true;
-                if let Some(waker) = state.pull_waker.take() {
-                    waker.wake();
+                let operator_state = match operator_state {
+                    OperatorState::NestedLoopJoin(operater_state) => operater_state,
+                    other => panic!("invalid operator state: {other:?}"),
+                };
+
+                let mut inner = operator_state.inner.lock();
+                match &mut *inner {
+                    SharedOperatorState::Building {
+                        probe_side_wakers, ..
+                    } => {
+                        // We need the complete left side before we can merge
+                        // visited rows. Come back once the build is complete.
+                        probe_side_wakers[state.partition_idx] = Some(cx.waker().clone());
+                        Ok(PollFinalize::Pending)
+                    }
+                    SharedOperatorState::Probing {
+                        global_outer_join_tracker,
+                        probe_partitions_remaining,
+                        probe_drain_wakers,
+                        ..
+                    } => {
+                        state.input_finished = true;
+                        *probe_partitions_remaining -= 1;
+
+                        if self.join_requires_drain() {
+                            let global = match global_outer_join_tracker.as_mut() {
+                                Some(global) => global,
+                                None => {
+                                    return Err(RayexecError::new(
+                                        "Global left outer tracker unexpectedly None",
+                                    ))
+                                }
+                            };
+
+                            // Local may be None if this partition didn't
+                            // receive any batches for probing.
+                            if let Some(local) = &state.partition_outer_join_tracker {
+                                global.merge_from(local);
+                            }
+
+                            if *probe_partitions_remaining == 0 {
+                                // Wake up pending probers, they can initialize
+                                // drain states now.
+                                for waker in probe_drain_wakers.iter_mut() {
+                                    if let Some(waker) = waker.take() {
+                                        waker.wake();
+                                    }
+                                }
+                            }
+                        }
+
+                        if let Some(waker) = state.pull_waker.take() {
+                            waker.wake();
+                        }
+
+                        Ok(PollFinalize::Finalized)
+                    }
                 }
-                Ok(PollFinalize::Finalized)
             }
             other => panic!("invalid partition state: {other:?}"),
         }
@@ -385,7 +524,7 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
         &self,
         cx: &mut Context,
         partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        operator_state: &OperatorState,
     ) -> Result<PollPull> {
         match partition_state {
             PartitionState::NestedLoopJoinProbe(state) => {
@@ -393,6 +532,70 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
                 if computed.has_batches() {
                     Ok(PollPull::Computed(computed))
                 } else if state.input_finished {
+                    if state.outer_join_drain_state.is_none() && self.join_requires_drain() {
+                        // We don't yet have a drain, check the global state to
+                        // see if we can create it.
+                        let operator_state = match operator_state {
+                            OperatorState::NestedLoopJoin(operater_state) => operater_state,
+                            other => panic!("invalid operator state: {other:?}"),
+                        };
+
+                        let mut inner = operator_state.inner.lock();
+                        let (batches, global, probe_partitions_remaining, probe_drain_wakers) =
+                            match &mut *inner {
+                                SharedOperatorState::Probing {
+                                    batches,
+                                    global_outer_join_tracker,
+                                    probe_partitions_remaining,
+                                    probe_drain_wakers,
+                                } => (
+                                    batches,
+                                    global_outer_join_tracker,
+                                    *probe_partitions_remaining,
+                                    probe_drain_wakers,
+                                ),
+                                other => panic!("inner join state is not probing: {other:?}"),
+                            };
+
+                        if probe_partitions_remaining != 0 {
+                            // Global state does not yet have all inputs. Need
+                            // to wait.
+                            probe_drain_wakers[state.partition_idx] = Some(cx.waker().clone());
+                            return Ok(PollPull::Pending);
+                        }
+
+                        let global = match global.as_ref() {
+                            Some(global) => global,
+                            None => {
+                                return Err(RayexecError::new(
+                                    "Global left outer tracker unexpectedly None",
+                                ))
+                            }
+                        };
+
+                        state.outer_join_drain_state = Some(LeftOuterJoinDrainState::new(
+                            state.partition_idx,
+                            probe_drain_wakers.len(),
+                            global.clone(),
+                            batches.as_ref().clone(),
+                            self.right_types.clone(),
+                        ));
+                    }
+
+                    // Check if we're still draining unvisited left rows.
+                    if let Some(drain_state) = state.outer_join_drain_state.as_mut() {
+                        let batch = match self.join_type {
+                            JoinType::LeftMark { .. } => drain_state.drain_mark_next()?,
+                            JoinType::Semi => drain_state.drain_semi_next()?,
+                            _ => drain_state.drain_next()?,
+                        };
+
+                        return match batch {
+                            Some(batch) => Ok(PollPull::Computed(batch.into())),
+                            None => Ok(PollPull::Exhausted),
+                        };
+                    }
+
                     Ok(PollPull::Exhausted)
                 } else {
                     // We just gotta wait for more input.
@@ -419,6 +622,9 @@ impl ExecutableOperator for PhysicalNestedLoopJoin {
 
 /// Generate a cross product of two batches, applying an optional filter to the
 /// result.
+///
+/// If `is_mark` is true, only the visit trackers will be updated, and no batches
+/// will be returned.
 #[allow(deprecated)]
 fn cross_join(
     left_batch_idx: usize,
@@ -426,7 +632,8 @@ fn cross_join(
     right: &Batch,
     filter_expr: Option<&PhysicalScalarExpression>,
     mut left_outer_tracker: Option<&mut LeftOuterJoinTracker>,
-    _right_join: bool,
+    mut right_outer_tracker: Option<&mut RightOuterJoinTracker>,
+    is_mark: bool,
 ) -> Result<Vec<Batch>> {
     let mut batches = Vec::with_capacity(left.num_rows() * right.num_rows());
 
@@ -447,15 +654,27 @@ fn cross_join(
             let selection = Arc::new(filter_expr.select(&output)?);
             output = output.select_old(selection.clone());
 
-            // If we're left joining, compute indices in the left batch that we
+            // If we're right joining, mark the rows on the right that we
             // visited.
+            if let Some(right_outer_tracker) = &mut right_outer_tracker {
+                right_outer_tracker.mark_rows_visited(selection.iter_locations());
+            }
+        } else if let Some(right_outer_tracker) = &mut right_outer_tracker {
+            // No filter, every row on the right joins with this row.
+            right_outer_tracker.mark_rows_visited(0..right.num_rows());
+        }
+
+        // If we're left joining, mark the left row as visited if it joined
+        // with anything on the right.
+        if output.num_rows() > 0 {
             if let Some(left_outer_tracker) = &mut left_outer_tracker {
-                left_outer_tracker
-                    .mark_rows_visited_for_batch(left_batch_idx, selection.iter_locations());
+                left_outer_tracker.mark_rows_visited_for_batch(left_batch_idx, [left_idx]);
             }
         }
 
-        batches.push(output);
+        if !is_mark {
+            batches.push(output);
+        }
     }
 
     Ok(batches)
@@ -0,0 +1,501 @@
+use std::collections::{HashSet, VecDeque};
+use std::sync::Arc;
+use std::task::{Context, Poll, Waker};
+use std::time::Duration;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+
+use super::sink::{PartitionSink, SinkOperation};
+use super::source::{PartitionSource, SourceOperation};
+use super::{
+    ExecutableOperator,
+    ExecutionStates,
+    InputOutputStates,
+    OperatorState,
+    PartitionState,
+    PollFinalize,
+    PollPull,
+    PollPush,
+};
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::executor::scalar::interleave;
+use crate::arrays::row::encoding::{ComparableColumn, ComparableRowEncoder};
+use crate::database::DatabaseContext;
+use crate::execution::computed_batch::ComputedBatches;
+use crate::execution::executable::pipeline::ExecutablePartitionPipeline;
+use crate::execution::executable::planner::plan_nested_pipelines;
+use crate::execution::intermediate::pipeline::IntermediatePipelineGroup;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::runtime::time::RuntimeInstant;
+
+/// Working table for a recursive CTE.
+///
+/// Holds the rows produced by the previous iteration (read by the recursive
+/// term), and the rows being produced by the current iteration.
+#[derive(Debug, Default)]
+pub struct WorkingTable {
+    /// Batches to be read by the recursive term for the current iteration.
+    scan: Mutex<VecDeque<Batch>>,
+    /// Batches written by the recursive term during the current iteration.
+    output: Mutex<Vec<Batch>>,
+}
+
+/// Source for reading the working table from within the recursive term.
+#[derive(Debug)]
+pub struct WorkingTableScan {
+    pub working_table: Arc<WorkingTable>,
+}
+
+impl SourceOperation for WorkingTableScan {
+    fn create_partition_sources(&self, num_sources: usize) -> Vec<Box<dyn PartitionSource>> {
+        (0..num_sources)
+            .map(|_| {
+                Box::new(WorkingTablePartitionScan {
+                    working_table: self.working_table.clone(),
+                }) as _
+            })
+            .collect()
+    }
+
+    fn partition_requirement(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Explainable for WorkingTableScan {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("WorkingTableScan")
+    }
+}
+
+#[derive(Debug)]
+struct WorkingTablePartitionScan {
+    working_table: Arc<WorkingTable>,
+}
+
+impl PartitionSource for WorkingTablePartitionScan {
+    fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
+        let batch = self.working_table.scan.lock().pop_front();
+        async move { Ok(batch) }.boxed()
+    }
+}
+
+/// Sink for writing the output of the recursive term to the working table.
+#[derive(Debug)]
+pub struct WorkingTableSink {
+    pub working_table: Arc<WorkingTable>,
+}
+
+impl SinkOperation for WorkingTableSink {
+    fn create_partition_sinks(
+        &self,
+        _context: &DatabaseContext,
+        num_sinks: usize,
+    ) -> Result<Vec<Box<dyn PartitionSink>>> {
+        Ok((0..num_sinks)
+            .map(|_| {
+                Box::new(WorkingTablePartitionSink {
+                    working_table: self.working_table.clone(),
+                }) as _
+            })
+            .collect())
+    }
+
+    fn partition_requirement(&self) -> Option<usize> {
+        None
+    }
+}
+
+impl Explainable for WorkingTableSink {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("WorkingTableSink")
+    }
+}
+
+#[derive(Debug)]
+struct WorkingTablePartitionSink {
+    working_table: Arc<WorkingTable>,
+}
+
+impl PartitionSink for WorkingTablePartitionSink {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        if batch.num_rows() > 0 {
+            self.working_table.output.lock().push(batch);
+        }
+        async { Ok(()) }.boxed()
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        async { Ok(()) }.boxed()
+    }
+}
+
+#[derive(Debug)]
+pub struct RecursiveCtePartitionState {
+    /// Index of this partition.
+    partition_idx: usize,
+    /// Batches from the anchor pushed to this partition.
+    batches: Vec<Batch>,
+    /// State for iterating the recursive term.
+    ///
+    /// Only set on the first partition once all anchor batches have been
+    /// received.
+    iteration: Option<Box<IterationState>>,
+}
+
+#[derive(Debug)]
+pub struct RecursiveCteOperatorState {
+    /// Context used for creating the states for the recursive term's
+    /// pipelines.
+    context: DatabaseContext,
+    inner: Mutex<RecursiveCteOperatorStateInner>,
+}
+
+#[derive(Debug)]
+struct RecursiveCteOperatorStateInner {
+    /// Batches from the anchor across all partitions.
+    batches: Vec<Batch>,
+    /// Number of partitions still pushing anchor batches.
+    remaining: usize,
+    /// Waker for the first partition if it attempted to pull before all
+    /// anchor batches were received.
+    pull_waker: Option<Waker>,
+}
+
+#[derive(Debug)]
+struct IterationState {
+    /// Number of times the recursive term has been executed.
+    ///
+    /// Only iterations that produce rows count towards the max number of
+    /// iterations.
+    iteration: usize,
+    /// Batches ready to be emitted.
+    output: VecDeque<Batch>,
+    /// Pipelines for the currently executing iteration.
+    ///
+    /// Empty if we're not currently executing an iteration.
+    pipelines: Vec<ExecutablePartitionPipeline>,
+    /// Encoded rows we've emitted so far. Only used for UNION.
+    seen: HashSet<Vec<u8>>,
+    /// If we've finished iterating.
+    finished: bool,
+}
+
+/// Executes a recursive CTE.
+///
+/// Batches pushed to this operator are the output of the anchor. Once all
+/// anchor batches have been received, the recursive term is repeatedly executed
+/// against the output of the previous iteration until an iteration produces no
+/// rows. All output is produced by the first partition.
+///
+/// Each iteration of the recursive term is planned as a fresh set of
+/// single-partition pipelines, and executed inline when pulling from this
+/// operator.
+#[derive(Debug)]
+pub struct PhysicalRecursiveCte {
+    /// If duplicate rows should be kept (UNION ALL).
+    pub(crate) all: bool,
+    /// Max number of iterations before erroring.
+    pub(crate) max_iterations: usize,
+    /// Working table shared with the scan and sink in the recursive term.
+    pub(crate) working_table: Arc<WorkingTable>,
+    /// Pipelines making up the recursive term.
+    pub(crate) recursive: IntermediatePipelineGroup,
+}
+
+impl ExecutableOperator for PhysicalRecursiveCte {
+    fn create_states(
+        &self,
+        context: &DatabaseContext,
+        partitions: Vec<usize>,
+    ) -> Result<ExecutionStates> {
+        let num_partitions = partitions[0];
+
+        let operator_state = OperatorState::RecursiveCte(RecursiveCteOperatorState {
+            context: context.clone(),
+            inner: Mutex::new(RecursiveCteOperatorStateInner {
+                batches: Vec::new(),
+                remaining: num_partitions,
+                pull_waker: None,
+            }),
+        });
+
+        let partition_states = (0..num_partitions)
+            .map(|idx| {
+                PartitionState::RecursiveCte(RecursiveCtePartitionState {
+                    partition_idx: idx,
+                    batches: Vec::new(),
+                    iteration: None,
+                })
+            })
+            .collect();
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(operator_state),
+            partition_states: InputOutputStates::OneToOne { partition_states },
+        })
+    }
+
+    fn poll_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        _operator_state: &OperatorState,
+        batch: Batch,
+    ) -> Result<PollPush> {
+        let state = match partition_state {
+            PartitionState::RecursiveCte(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if batch.num_rows() > 0 {
+            state.batches.push(batch);
+        }
+
+        Ok(PollPush::NeedsMore)
+    }
+
+    fn poll_finalize_push(
+        &self,
+        _cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollFinalize> {
+        let state = match partition_state {
+            PartitionState::RecursiveCte(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let operator_state = match operator_state {
+            OperatorState::RecursiveCte(state) => state,
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        let mut shared = operator_state.inner.lock();
+        shared.batches.append(&mut state.batches);
+        shared.remaining -= 1;
+
+        if let Some(waker) = shared.pull_waker.take() {
+            waker.wake();
+        }
+
+        Ok(PollFinalize::Finalized)
+    }
+
+    fn poll_pull(
+        &self,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
+    ) -> Result<PollPull> {
+        let state = match partition_state {
+            PartitionState::RecursiveCte(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let operator_state = match operator_state {
+            OperatorState::RecursiveCte(state) => state,
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        if state.partition_idx != 0 {
+            return Ok(PollPull::Exhausted);
+        }
+
+        if state.iteration.is_none() {
+            let mut shared = operator_state.inner.lock();
+            if shared.remaining != 0 {
+                // Still waiting on the anchor.
+                shared.pull_waker = Some(cx.waker().clone());
+                return Ok(PollPull::Pending);
+            }
+
+            let batches = std::mem::take(&mut shared.batches);
+            std::mem::drop(shared);
+
+            let mut iteration = IterationState {
+                iteration: 0,
+                output: VecDeque::new(),
+                pipelines: Vec::new(),
+                seen: HashSet::new(),
+                finished: false,
+            };
+            self.next_working_table(&mut iteration, batches)?;
+
+            state.iteration = Some(Box::new(iteration));
+        }
+
+        let iteration = state.iteration.as_mut().unwrap();
+
+        loop {
+            if let Some(batch) = iteration.output.pop_front() {
+                return Ok(PollPull::Computed(ComputedBatches::Single(batch)));
+            }
+
+            if iteration.finished {
+                return Ok(PollPull::Exhausted);
+            }
+
+            if iteration.pipelines.is_empty() {
+                // Start the next iteration.
+                if self.working_table.scan.lock().is_empty() {
+                    // Previous iteration produced no rows, we're done.
+                    iteration.finished = true;
+                    continue;
+                }
+
+                iteration.iteration += 1;
+
+                iteration.pipelines =
+                    plan_nested_pipelines(&operator_state.context, self.recursive.clone())?
+                        .into_iter()
+                        .flat_map(|pipeline| pipeline.into_partition_pipeline_iter())
+                        .collect();
+            }
+
+            // Drive the recursive term's pipelines until they either all
+            // complete, or none of them can make progress.
+            loop {
+                let mut progress = false;
+                let mut idx = 0;
+                while idx < iteration.pipelines.len() {
+                    match iteration.pipelines[idx].poll_execute::<NopInstant>(cx) {
+                        Poll::Ready(None) => {
+                            iteration.pipelines.swap_remove(idx);
+                            progress = true;
+                        }
+                        Poll::Ready(Some(Ok(()))) => {
+                            progress = true;
+                            idx += 1;
+                        }
+                        Poll::Ready(Some(Err(e))) => return Err(e),
+                        Poll::Pending => idx += 1,
+                    }
+                }
+
+                if iteration.pipelines.is_empty() {
+                    break;
+                }
+
+                if !progress {
+                    // Wakers registered by the pending pipelines will wake
+                    // this partition back up.
+                    return Ok(PollPull::Pending);
+                }
+            }
+
+            let batches = std::mem::take(&mut *self.working_table.output.lock());
+            if !batches.is_empty() && iteration.iteration > self.max_iterations {
+                return Err(RayexecError::new(format!(
+                    "Recursive CTE exceeded the maximum number of iterations ({}). The limit can be changed with 'SET max_recursive_cte_iterations = ...'",
+                    self.max_iterations
+                )));
+            }
+
+            self.next_working_table(iteration, batches)?;
+        }
+    }
+}
+
+impl PhysicalRecursiveCte {
+    /// Sets the batches as the working table for the next iteration, and
+    /// queues them for output.
+    ///
+    /// Rows that have already been emitted are removed if we're not keeping
+    /// duplicates.
+    fn next_working_table(
+        &self,
+        iteration: &mut IterationState,
+        batches: Vec<Batch>,
+    ) -> Result<()> {
+        let mut working = VecDeque::with_capacity(batches.len());
+
+        for batch in batches {
+            let batch = if self.all {
+                batch
+            } else {
+                match dedup_batch(&mut iteration.seen, batch)? {
+                    Some(batch) => batch,
+                    None => continue,
+                }
+            };
+
+            iteration.output.push_back(batch.clone());
+            working.push_back(batch);
+        }
+
+        *self.working_table.scan.lock() = working;
+
+        Ok(())
+    }
+}
+
+/// Remove rows from the batch that have already been seen.
+///
+/// Returns None if all rows have been seen.
+fn dedup_batch(seen: &mut HashSet<Vec<u8>>, batch: Batch) -> Result<Option<Batch>> {
+    let encoder = ComparableRowEncoder {
+        columns: vec![
+            ComparableColumn {
+                desc: false,
+                nulls_first: false,
+                collation: Collation::BINARY,
+            };
+            batch.num_arrays()
+        ],
+    };
+    let columns: Vec<_> = batch.arrays().iter().collect();
+    let rows = encoder.encode(&columns)?;
+
+    let indices: Vec<_> = rows
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, row)| seen.insert(row.data().to_vec()).then_some((0, idx)))
+        .collect();
+
+    if indices.is_empty() {
+        return Ok(None);
+    }
+    if indices.len() == batch.num_rows() {
+        return Ok(Some(batch));
+    }
+
+    let arrays = batch
+        .arrays()
+        .iter()
+        .map(|arr| interleave(&[arr], &indices))
+        .collect::<Result<Vec<_>>>()?;
+
+    Ok(Some(Batch::try_from_arrays(arrays)?))
+}
+
+/// Instant used when executing the pipelines for the recursive term.
+///
+/// Profiling data isn't collected for these pipelines.
+#[derive(Debug)]
+struct NopInstant;
+
+impl RuntimeInstant for NopInstant {
+    fn now() -> Self {
+        NopInstant
+    }
+
+    fn duration_since(&self, _earlier: Self) -> Duration {
+        Duration::ZERO
+    }
+
+    fn since_reference(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+impl Explainable for PhysicalRecursiveCte {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        let kind = if self.all { "UNION ALL" } else { "UNION" };
+        ExplainEntry::new("RecursiveCte").with_value("kind", kind)
+    }
+}
@@ -18,10 +18,10 @@ use super::{
     PollPush,
 };
 use crate::arrays::batch::Batch;
-use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::logical::scan_filter::ScanFilter;
 use crate::proto::DatabaseProtoConv;
 use crate::storage::table_storage::{DataTableScan, Projections};
 
@@ -43,6 +43,11 @@ pub struct PhysicalScan {
     schema: String,
     table: Arc<CatalogEntry>,
     projections: Projections,
+    /// Filters the table may use to skip data.
+    filters: Vec<ScanFilter>,
+    /// If row ids should be produced as an additional column after all table
+    /// columns.
+    row_ids: bool,
 }
 
 impl PhysicalScan {
@@ -57,8 +62,20 @@ impl PhysicalScan {
             schema: schema.into(),
             table,
             projections,
+            filters: Vec::new(),
+            row_ids: false,
         }
     }
+
+    pub fn with_filters(mut self, filters: Vec<ScanFilter>) -> Self {
+        self.filters = filters;
+        self
+    }
+
+    pub fn with_row_ids(mut self, row_ids: bool) -> Self {
+        self.row_ids = row_ids;
+        self
+    }
 }
 
 impl ExecutableOperator for PhysicalScan {
@@ -67,19 +84,18 @@ impl ExecutableOperator for PhysicalScan {
         context: &DatabaseContext,
         partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
-        // TODO: Placeholder for now. Transaction info should probably go on the
-        // operator.
-        let _tx = CatalogTx::new();
-
         let database = context.get_database(&self.catalog)?;
         let data_table = database
             .table_storage
             .as_ref()
             .ok_or_else(|| RayexecError::new("Missing table storage for scan"))?
-            .data_table(&self.schema, &self.table)?;
+            .data_table(context.catalog_tx(), &self.schema, &self.table)?;
 
-        // TODO: Pushdown projections, filters
-        let scans = data_table.scan(self.projections.clone(), partitions[0])?;
+        let scans = if self.row_ids {
+            data_table.scan_with_row_ids(self.projections.clone(), partitions[0])?
+        } else {
+            data_table.scan(self.projections.clone(), &self.filters, partitions[0])?
+        };
 
         let states = scans
             .into_iter()
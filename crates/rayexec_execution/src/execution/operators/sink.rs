@@ -72,6 +72,13 @@ pub trait PartitionSink: Debug + Send {
     /// pending work that needs to happen (flushing), it should happen here.
     /// Once this returns, the sink is complete.
     fn finalize(&mut self) -> BoxFuture<'_, Result<()>>;
+
+    /// Number of pushed rows the sink chose not to write.
+    ///
+    /// These rows are excluded from the row count returned by the operator.
+    fn num_rows_skipped(&self) -> usize {
+        0
+    }
 }
 
 pub enum SinkPartitionState {
@@ -267,6 +274,7 @@ impl<S: SinkOperation> ExecutableOperator for SinkOperator<S> {
                     }
 
                     let mut inner = inner.take().unwrap();
+                    let num_rows_skipped = inner.sink.num_rows_skipped();
                     let mut finalize_future = inner.sink.finalize();
                     match finalize_future.poll_unpin(cx) {
                         Poll::Ready(Ok(_)) => {
@@ -280,7 +288,8 @@ impl<S: SinkOperation> ExecutableOperator for SinkOperator<S> {
                             match operator_state {
                                 OperatorState::Sink(state) => {
                                     let mut state = state.inner.lock();
-                                    state.global_row_count += inner.current_row_count;
+                                    state.global_row_count +=
+                                        inner.current_row_count - num_rows_skipped;
                                     state.partitions_remaining -= 1;
                                 }
                                 other => panic!("invalid operator state: {other:?}"),
@@ -317,8 +326,9 @@ impl<S: SinkOperation> ExecutableOperator for SinkOperator<S> {
                             match operator_state {
                                 OperatorState::Sink(state) => {
                                     let mut state = state.inner.lock();
+                                    let inner = inner.as_ref().unwrap();
                                     state.global_row_count +=
-                                        inner.as_ref().unwrap().current_row_count;
+                                        inner.current_row_count - inner.sink.num_rows_skipped();
                                     state.partitions_remaining -= 1;
                                 }
                                 other => panic!("invalid operator state: {other:?}"),
@@ -613,6 +613,7 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
+    use crate::arrays::compute::collation::Collation;
     use crate::execution::operators::test_util::{
         make_i32_batch,
         unwrap_poll_pull_batch,
@@ -632,6 +633,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: true,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let (operator_state, push_states, pull_states) = operator.create_states_orig(1);
         let operator_state = Arc::new(OperatorState::GatherSort(operator_state));
@@ -724,6 +726,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: true,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let (operator_state, push_states, pull_states) = operator.create_states_orig(2);
         let operator_state = Arc::new(OperatorState::GatherSort(operator_state));
@@ -2,4 +2,4 @@ pub mod gather_sort;
 pub mod scatter_sort;
 pub mod top_k;
 
-mod util;
+pub(crate) mod util;
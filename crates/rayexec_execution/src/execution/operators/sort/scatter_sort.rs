@@ -1,14 +1,25 @@
 use std::sync::Arc;
-use std::task::{Context, Waker};
+use std::task::{Context, Poll, Waker};
 
 use rayexec_error::Result;
 
 use super::util::merger::{IterState, KWayMerger, MergeResult};
 use super::util::sort_keys::SortKeysExtractor;
-use super::util::sorted_batch::{IndexSortedBatch, SortedIndicesIter};
+use super::util::sorted_batch::{
+    IndexSortedBatch,
+    PhysicallySortedBatch,
+    SortedIndicesIter,
+    SortedKeysIter,
+};
 use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
+use crate::execution::operators::util::spill::{
+    batch_size_bytes,
+    MemoryReservation,
+    SpillFile,
+    SpillReader,
+};
 use crate::execution::operators::{
     ExecutableOperator,
     ExecutionStates,
@@ -22,6 +33,7 @@ use crate::execution::operators::{
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::physical::PhysicalSortExpression;
 use crate::proto::DatabaseProtoConv;
+use crate::runtime::spill::SpillProvider;
 
 #[derive(Debug)]
 pub enum ScatterSortPartitionState {
@@ -31,6 +43,20 @@ pub enum ScatterSortPartitionState {
     Producing(ProducingPartitionState),
 }
 
+impl ScatterSortPartitionState {
+    /// Number of bytes reserved by this partition.
+    pub fn reserved_memory(&self) -> usize {
+        match self {
+            ScatterSortPartitionState::Consuming(s) => s.reservation.size(),
+            ScatterSortPartitionState::Producing(ProducingPartitionState::InMemory {
+                reservation,
+                ..
+            }) => reservation.size(),
+            ScatterSortPartitionState::Producing(ProducingPartitionState::Spilled(_)) => 0,
+        }
+    }
+}
+
 #[derive(Debug)]
 pub struct ConsumingPartitionState {
     /// Extract the sort keys from a batch.
@@ -39,15 +65,48 @@ pub struct ConsumingPartitionState {
     ///
     /// Batches are not sorted relative to each other.
     batches: Vec<IndexSortedBatch>,
+    /// Memory reserved for the batches we're buffering.
+    reservation: MemoryReservation,
+    /// Sorted runs that were spilled to disk since buffering more batches
+    /// would've exceeded the memory limit.
+    spilled_runs: Vec<SpillFile>,
+    /// Where to spill runs to.
+    spill_provider: Option<Arc<dyn SpillProvider>>,
     /// Waker on the pull side that tried to get a batch before we were done
     /// sorting this partition.
     pull_waker: Option<Waker>,
 }
 
 #[derive(Debug)]
-pub struct ProducingPartitionState {
-    /// Merger for merging all batches in this partition.
-    merger: KWayMerger<SortedIndicesIter>,
+pub enum ProducingPartitionState {
+    /// All batches for this partition fit in memory.
+    InMemory {
+        /// Merger for merging all batches in this partition.
+        merger: KWayMerger<SortedIndicesIter>,
+        /// Memory reserved for the batches being merged.
+        reservation: MemoryReservation,
+    },
+    /// Batches were spilled to disk as sorted runs.
+    Spilled(SpilledMergeState),
+}
+
+#[derive(Debug)]
+pub struct SpilledMergeState {
+    /// Extract sort keys from batches read back from disk.
+    extractor: SortKeysExtractor,
+    /// Merger for merging the runs, with one input per run.
+    ///
+    /// Initialized once the first batch for each run has been read.
+    merger: Option<KWayMerger<SortedKeysIter>>,
+    /// Inputs for initializing the merger, containing the first batch for each
+    /// run read so far.
+    inputs: Vec<(Option<Batch>, IterState<SortedKeysIter>)>,
+    /// Merger input we're waiting on the next batch for.
+    needs_input: Option<usize>,
+    /// Readers for each run, indexed by merger input.
+    readers: Vec<SpillReader>,
+    /// The spilled runs, removed from disk once dropped.
+    _runs: Vec<SpillFile>,
 }
 
 /// Physical operator for sorting batches within a partition stream.
@@ -65,7 +124,7 @@ impl PhysicalScatterSort {
 impl ExecutableOperator for PhysicalScatterSort {
     fn create_states(
         &self,
-        _context: &DatabaseContext,
+        context: &DatabaseContext,
         partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
         let partitions = partitions[0];
@@ -77,6 +136,9 @@ impl ExecutableOperator for PhysicalScatterSort {
                     ConsumingPartitionState {
                         extractor: extractor.clone(),
                         batches: Vec::new(),
+                        reservation: MemoryReservation::new(context.buffer_manager()),
+                        spilled_runs: Vec::new(),
+                        spill_provider: context.spill_provider().cloned(),
                         pull_waker: None,
                     },
                 ))
@@ -130,21 +192,22 @@ impl ExecutableOperator for PhysicalScatterSort {
             ScatterSortPartitionState::Consuming(consuming_state) => {
                 let pull_waker = consuming_state.pull_waker.take(); // Taken here to satisfy lifetime.
 
-                // Initialize the merger with all the batches.
-                let mut inputs = Vec::with_capacity(consuming_state.batches.len());
-
-                let batches = std::mem::take(&mut consuming_state.batches);
-
-                // Filter out any batches that don't have rows, and add them to the merger inputs.
-                for batch in batches
-                    .into_iter()
-                    .filter(|batch| batch.batch.num_rows() > 0)
-                {
-                    let (batch, iter) = batch.into_batch_and_iter();
-                    inputs.push((Some(batch), IterState::Iterator(iter)));
-                }
-
-                let merger = KWayMerger::try_new(inputs)?;
+                let producing = if consuming_state.spilled_runs.is_empty() {
+                    let batches = std::mem::take(&mut consuming_state.batches);
+                    ProducingPartitionState::InMemory {
+                        merger: Self::merger_for_batches(batches)?,
+                        reservation: consuming_state.reservation.take(),
+                    }
+                } else {
+                    // Spill whatever's remaining so that we're only merging
+                    // runs from disk.
+                    Self::spill_run(consuming_state)?;
+                    let runs = std::mem::take(&mut consuming_state.spilled_runs);
+                    ProducingPartitionState::Spilled(SpilledMergeState::try_new(
+                        consuming_state.extractor.clone(),
+                        runs,
+                    )?)
+                };
 
                 // Wake up thread waiting to pull.
                 if let Some(waker) = pull_waker {
@@ -152,7 +215,7 @@ impl ExecutableOperator for PhysicalScatterSort {
                 }
 
                 // Update partition state to "producing" using the merger.
-                *state = ScatterSortPartitionState::Producing(ProducingPartitionState { merger });
+                *state = ScatterSortPartitionState::Producing(producing);
 
                 Ok(PollFinalize::Finalized)
             }
@@ -179,10 +242,13 @@ impl ExecutableOperator for PhysicalScatterSort {
                 state.pull_waker = Some(cx.waker().clone());
                 Ok(PollPull::Pending)
             }
-            ScatterSortPartitionState::Producing(state) => {
+            ScatterSortPartitionState::Producing(ProducingPartitionState::InMemory {
+                merger,
+                ..
+            }) => {
                 loop {
                     // TODO: Configurable batch size.
-                    match state.merger.try_merge(DEFAULT_TARGET_BATCH_SIZE)? {
+                    match merger.try_merge(DEFAULT_TARGET_BATCH_SIZE)? {
                         MergeResult::Batch(batch) => {
                             return Ok(PollPull::Computed(batch.into()));
                         }
@@ -193,12 +259,20 @@ impl ExecutableOperator for PhysicalScatterSort {
                             // We're merging all batch in this partition, and
                             // the merger already has everything, so we go ahead
                             // and mark this batch as complete.
-                            state.merger.input_finished(idx);
+                            merger.input_finished(idx);
                             // Continue to keep merging...
                         }
                     }
                 }
             }
+            ScatterSortPartitionState::Producing(ProducingPartitionState::Spilled(state)) => {
+                match state.poll_merge(cx) {
+                    Poll::Ready(Ok(Some(batch))) => Ok(PollPull::Computed(batch.into())),
+                    Poll::Ready(Ok(None)) => Ok(PollPull::Exhausted),
+                    Poll::Ready(Err(e)) => Err(e),
+                    Poll::Pending => Ok(PollPull::Pending),
+                }
+            }
         }
     }
 }
@@ -222,10 +296,163 @@ impl PhysicalScatterSort {
             keys,
             batch,
         };
+
+        let size = batch_size_bytes(&batch.batch) + batch.keys.size_bytes();
+        let fits = state.reservation.try_grow(size)?;
         state.batches.push(batch);
 
+        if !fits {
+            // Buffering this batch puts us over the memory limit, write
+            // everything we have out as a sorted run.
+            Self::spill_run(state)?;
+        }
+
         Ok(())
     }
+
+    /// Create a merger for merging batches that are all in memory.
+    fn merger_for_batches(batches: Vec<IndexSortedBatch>) -> Result<KWayMerger<SortedIndicesIter>> {
+        let mut inputs = Vec::with_capacity(batches.len());
+
+        // Filter out any batches that don't have rows, and add them to the merger inputs.
+        for batch in batches
+            .into_iter()
+            .filter(|batch| batch.batch.num_rows() > 0)
+        {
+            let (batch, iter) = batch.into_batch_and_iter();
+            inputs.push((Some(batch), IterState::Iterator(iter)));
+        }
+
+        KWayMerger::try_new(inputs)
+    }
+
+    /// Merge all buffered batches into a single sorted run, and write that run
+    /// to disk.
+    ///
+    /// Memory reserved for the buffered batches is released.
+    fn spill_run(state: &mut ConsumingPartitionState) -> Result<()> {
+        let batches = std::mem::take(&mut state.batches);
+        let datatypes = match batches.first() {
+            Some(batch) => batch
+                .batch
+                .arrays()
+                .iter()
+                .map(|arr| arr.datatype().clone())
+                .collect(),
+            None => return Ok(()),
+        };
+
+        let mut merger = Self::merger_for_batches(batches)?;
+        let mut run = SpillFile::try_new(
+            state.spill_provider.as_deref(),
+            state.reservation.buffer_manager(),
+            datatypes,
+        )?;
+
+        loop {
+            match merger.try_merge(DEFAULT_TARGET_BATCH_SIZE)? {
+                MergeResult::Batch(batch) => run.write_batch(&batch)?,
+                MergeResult::Exhausted => break,
+                MergeResult::NeedsInput(idx) => merger.input_finished(idx),
+            }
+        }
+
+        run.finish()?;
+        state.spilled_runs.push(run);
+        state.reservation.release();
+
+        Ok(())
+    }
+}
+
+impl SpilledMergeState {
+    fn try_new(extractor: SortKeysExtractor, runs: Vec<SpillFile>) -> Result<Self> {
+        Ok(SpilledMergeState {
+            extractor,
+            merger: None,
+            inputs: Vec::with_capacity(runs.len()),
+            needs_input: None,
+            readers: runs.iter().map(|run| run.reader()).collect::<Result<_>>()?,
+            _runs: runs,
+        })
+    }
+
+    /// Poll for the next merged batch, returning None once all runs have been
+    /// merged.
+    fn poll_merge(&mut self, cx: &mut Context) -> Poll<Result<Option<Batch>>> {
+        if self.merger.is_none() {
+            // Initialize the merger with the first batch from each run.
+            while self.inputs.len() < self.readers.len() {
+                let idx = self.inputs.len();
+                match self.poll_next_sorted_batch(cx, idx) {
+                    Poll::Ready(Ok(Some(batch))) => {
+                        let (batch, iter) = batch.into_batch_and_iter();
+                        self.inputs.push((Some(batch), IterState::Iterator(iter)));
+                    }
+                    Poll::Ready(Ok(None)) => self.inputs.push((None, IterState::Finished)),
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                }
+            }
+
+            let inputs = std::mem::take(&mut self.inputs);
+            self.merger = Some(KWayMerger::try_new(inputs)?);
+        }
+
+        loop {
+            if let Some(idx) = self.needs_input {
+                // Read the next batch for the run.
+                let batch = match self.poll_next_sorted_batch(cx, idx) {
+                    Poll::Ready(Ok(batch)) => batch,
+                    Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                    Poll::Pending => return Poll::Pending,
+                };
+
+                let merger = self.merger.as_mut().expect("merger to be initialized");
+                match batch {
+                    Some(batch) => {
+                        let (batch, iter) = batch.into_batch_and_iter();
+                        merger.push_batch_for_input(idx, batch, iter)?;
+                    }
+                    None => merger.input_finished(idx),
+                }
+                self.needs_input = None;
+            }
+
+            let merger = self.merger.as_mut().expect("merger to be initialized");
+            match merger.try_merge(DEFAULT_TARGET_BATCH_SIZE)? {
+                MergeResult::Batch(batch) => return Poll::Ready(Ok(Some(batch))),
+                MergeResult::Exhausted => return Poll::Ready(Ok(None)),
+                MergeResult::NeedsInput(idx) => self.needs_input = Some(idx),
+            }
+        }
+    }
+
+    /// Poll for the next batch for a run.
+    ///
+    /// Runs are written in sorted order, so only the sort keys need to be
+    /// computed.
+    fn poll_next_sorted_batch(
+        &mut self,
+        cx: &mut Context,
+        idx: usize,
+    ) -> Poll<Result<Option<PhysicallySortedBatch>>> {
+        loop {
+            let batch = match self.readers[idx].poll_read_batch(cx) {
+                Poll::Ready(Ok(Some(batch))) => batch,
+                Poll::Ready(Ok(None)) => return Poll::Ready(Ok(None)),
+                Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+                Poll::Pending => return Poll::Pending,
+            };
+
+            if batch.num_rows() == 0 {
+                continue;
+            }
+
+            let keys = self.extractor.sort_keys(&batch)?;
+            return Poll::Ready(Ok(Some(PhysicallySortedBatch { batch, keys })));
+        }
+    }
 }
 
 impl Explainable for PhysicalScatterSort {
@@ -263,6 +490,7 @@ mod tests {
     use std::sync::Arc;
 
     use super::*;
+    use crate::arrays::compute::collation::Collation;
     use crate::execution::operators::test_util::{
         make_i32_batch,
         test_database_context,
@@ -293,6 +521,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: true,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
@@ -323,6 +552,81 @@ mod tests {
         assert_eq!(expected, output);
     }
 
+    #[test]
+    #[allow(deprecated)]
+    fn sort_single_partition_spilled() {
+        let inputs = vec![
+            make_i32_batch([8, 10, 8, 4]),
+            make_i32_batch([2, 3]),
+            make_i32_batch([9, 1, 7, -1]),
+        ];
+
+        let operator = Arc::new(PhysicalScatterSort::new(vec![PhysicalSortExpression {
+            column: PhysicalColumnExpr { idx: 0 },
+            desc: true,
+            nulls_first: true,
+            collation: Collation::BINARY,
+        }]));
+        let operator_state = Arc::new(OperatorState::None);
+
+        // Limit is small enough that every batch gets spilled as its own run.
+        let context = test_database_context();
+        context.buffer_manager().set_limit(Some(8));
+        let mut partition_states = match operator
+            .create_states(&context, vec![1])
+            .unwrap()
+            .partition_states
+        {
+            InputOutputStates::OneToOne { partition_states } => partition_states,
+            other => panic!("unexpected states: {other:?}"),
+        };
+
+        // Push all the inputs.
+        let push_cx = TestWakerContext::new();
+        for input in inputs {
+            let poll_push = push_cx
+                .poll_push(&operator, &mut partition_states[0], &operator_state, input)
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+        operator
+            .poll_finalize_push(
+                &mut push_cx.context(),
+                &mut partition_states[0],
+                &operator_state,
+            )
+            .unwrap();
+
+        match &partition_states[0] {
+            PartitionState::ScatterSort(ScatterSortPartitionState::Producing(
+                ProducingPartitionState::Spilled(state),
+            )) => assert_eq!(3, state.readers.len()),
+            other => panic!("unexpected state: {other:?}"),
+        }
+
+        // Now pull.
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+        let expected = make_i32_batch([10, 9, 8, 8, 7, 4, 3, 2, 1, -1]);
+        // Batches read back from disk don't share the same physical layout, so
+        // compare row by row.
+        for idx in 0..expected.num_rows() {
+            assert_eq!(expected.row(idx), output.row(idx));
+        }
+        assert_eq!(expected.num_rows(), output.num_rows());
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut partition_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+
+        // Nothing reserved once the runs are spilled.
+        assert_eq!(0, context.buffer_manager().reserved());
+    }
+
     #[test]
     fn sort_single_partition_asc_nulls_first() {
         let inputs = vec![
@@ -335,6 +639,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: false,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
@@ -381,6 +686,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: true,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
@@ -446,6 +752,7 @@ mod tests {
             column: PhysicalColumnExpr { idx: 0 },
             desc: true,
             nulls_first: true,
+            collation: Collation::BINARY,
         }]));
         let operator_state = Arc::new(OperatorState::None);
         let mut partition_states = create_states(&operator, 1);
@@ -1,12 +1,19 @@
-use std::task::Context;
+use std::collections::BinaryHeap;
+use std::sync::Arc;
+use std::task::{Context, Waker};
 
+use parking_lot::Mutex;
 use rayexec_error::Result;
 
+use super::util::sort_keys::SortKeysExtractor;
 use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::interleave;
 use crate::database::DatabaseContext;
+use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
 use crate::execution::operators::{
     ExecutableOperator,
     ExecutionStates,
+    InputOutputStates,
     OperatorState,
     PartitionState,
     PollFinalize,
@@ -14,56 +21,577 @@ use crate::execution::operators::{
     PollPush,
 };
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::PhysicalSortExpression;
+use crate::proto::DatabaseProtoConv;
 
+/// Partition state on the push side.
 #[derive(Debug)]
-pub struct TopKPartitionState {}
+pub struct TopKPushPartitionState {
+    /// Extract the sort keys from a batch.
+    extractor: SortKeysExtractor,
+    /// Top rows seen so far for this partition.
+    heap: TopKHeap,
+}
+
+/// Partition state on the pull side.
+#[derive(Debug)]
+pub struct TopKPullPartitionState {
+    /// Merged output across all partitions.
+    ///
+    /// None until all input partitions have finished.
+    output: Option<MergedOutput>,
+}
+
+#[derive(Debug)]
+pub struct TopKOperatorState {
+    shared: Mutex<SharedState>,
+}
+
+#[derive(Debug)]
+struct SharedState {
+    /// Sorted top rows from each input partition that's finished.
+    partition_rows: Vec<SortedRows>,
+    /// Number of input partitions that still need to finish.
+    remaining: usize,
+    /// Waker on the pull side that tried to pull before all inputs were
+    /// finished.
+    pull_waker: Option<Waker>,
+}
+
+/// Rows from an input partition sorted by their keys.
+#[derive(Debug)]
+struct SortedRows {
+    batch: Batch,
+    /// Sort key for each row in the batch.
+    keys: Vec<Vec<u8>>,
+}
 
+/// Final output after merging the top rows from every partition.
 #[derive(Debug)]
-pub struct TopKOperatorState {}
+struct MergedOutput {
+    batches: Vec<Batch>,
+    /// (batch_idx, row_idx) pairs for the output rows in sort order, with the
+    /// offset already applied.
+    indices: Vec<(usize, usize)>,
+    /// Position in `indices` of the next row to output.
+    pos: usize,
+}
 
+/// Bounded heap keeping the top k rows of the batches inserted into it.
 #[derive(Debug)]
-pub struct PhysicalTopK {}
+struct TopKHeap {
+    /// Number of rows to keep.
+    k: usize,
+    /// Batches referenced by rows in the heap.
+    batches: Vec<Batch>,
+    /// Total number of rows across all buffered batches.
+    buffered_rows: usize,
+    /// Max heap of rows, the "largest" row that we're keeping is at the top
+    /// and is the first to be evicted.
+    heap: BinaryHeap<HeapEntry>,
+}
+
+/// A row in the heap.
+///
+/// Ties on the key are broken by the batch and row index, preferring the rows
+/// we saw first.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+struct HeapEntry {
+    key: Vec<u8>,
+    batch_idx: usize,
+    row_idx: usize,
+}
+
+impl TopKHeap {
+    fn new(k: usize) -> Self {
+        TopKHeap {
+            k,
+            batches: Vec::new(),
+            buffered_rows: 0,
+            heap: BinaryHeap::new(),
+        }
+    }
+
+    fn insert(&mut self, extractor: &SortKeysExtractor, batch: Batch) -> Result<()> {
+        if self.k == 0 || batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        let keys = extractor.sort_keys(&batch)?;
+        let batch_idx = self.batches.len();
+        let mut used = false;
+
+        for (row_idx, key) in keys.iter().enumerate() {
+            if self.heap.len() >= self.k {
+                let top = self.heap.peek().expect("heap to not be empty");
+                if key.data() >= top.key.as_slice() {
+                    // Row wouldn't make it into the top k.
+                    continue;
+                }
+                self.heap.pop();
+            }
+
+            self.heap.push(HeapEntry {
+                key: key.data().to_vec(),
+                batch_idx,
+                row_idx,
+            });
+            used = true;
+        }
+
+        if used {
+            self.buffered_rows += batch.num_rows();
+            self.batches.push(batch);
+
+            // Avoid holding on to batches where most of the rows have since
+            // been evicted.
+            if self.buffered_rows > self.k.saturating_mul(2).max(DEFAULT_TARGET_BATCH_SIZE) {
+                self.compact()?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Copy the rows in the heap into a single batch, dropping all other
+    /// buffered batches.
+    ///
+    /// Rows in the compacted batch are in sort order.
+    fn compact(&mut self) -> Result<()> {
+        let entries = std::mem::take(&mut self.heap).into_sorted_vec();
+        if entries.is_empty() {
+            self.batches.clear();
+            self.buffered_rows = 0;
+            return Ok(());
+        }
+
+        let indices: Vec<_> = entries
+            .iter()
+            .map(|entry| (entry.batch_idx, entry.row_idx))
+            .collect();
+        let batch = interleave_batches(&self.batches, &indices)?;
+
+        self.batches = vec![batch];
+        self.buffered_rows = entries.len();
+        self.heap = entries
+            .into_iter()
+            .enumerate()
+            .map(|(row_idx, entry)| HeapEntry {
+                key: entry.key,
+                batch_idx: 0,
+                row_idx,
+            })
+            .collect();
+
+        Ok(())
+    }
+
+    /// Produce the top rows in sort order.
+    fn into_sorted_rows(mut self) -> Result<Option<SortedRows>> {
+        self.compact()?;
+        let batch = match self.batches.pop() {
+            Some(batch) => batch,
+            None => return Ok(None),
+        };
+
+        let keys = self
+            .heap
+            .into_sorted_vec()
+            .into_iter()
+            .map(|entry| entry.key)
+            .collect();
+
+        Ok(Some(SortedRows { batch, keys }))
+    }
+}
+
+/// Physical operator for ORDER BY with a LIMIT.
+///
+/// Each input partition keeps only its top `limit + offset` rows. Once all
+/// inputs are finished, the rows from every partition are merged into a single
+/// output partition with the offset and limit applied.
+#[derive(Debug)]
+pub struct PhysicalTopK {
+    exprs: Vec<PhysicalSortExpression>,
+    limit: usize,
+    offset: Option<usize>,
+}
+
+impl PhysicalTopK {
+    pub fn new(exprs: Vec<PhysicalSortExpression>, limit: usize, offset: Option<usize>) -> Self {
+        PhysicalTopK {
+            exprs,
+            limit,
+            offset,
+        }
+    }
+}
 
 impl ExecutableOperator for PhysicalTopK {
     fn create_states(
         &self,
         _context: &DatabaseContext,
-        _partitions: Vec<usize>,
+        partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
-        unimplemented!()
+        let input_partitions = partitions[0];
+
+        let operator_state = OperatorState::TopK(TopKOperatorState {
+            shared: Mutex::new(SharedState {
+                partition_rows: Vec::with_capacity(input_partitions),
+                remaining: input_partitions,
+                pull_waker: None,
+            }),
+        });
+
+        let extractor = SortKeysExtractor::new(&self.exprs);
+        let k = self.limit.saturating_add(self.offset.unwrap_or(0));
+
+        let push_states = (0..input_partitions)
+            .map(|_| {
+                PartitionState::TopKPush(TopKPushPartitionState {
+                    extractor: extractor.clone(),
+                    heap: TopKHeap::new(k),
+                })
+            })
+            .collect();
+
+        // Single output partition containing the global top k.
+        let pull_states = vec![PartitionState::TopKPull(TopKPullPartitionState {
+            output: None,
+        })];
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(operator_state),
+            partition_states: InputOutputStates::SeparateInputOutput {
+                push_states,
+                pull_states,
+            },
+        })
     }
 
     fn poll_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
-        _batch: Batch,
+        batch: Batch,
     ) -> Result<PollPush> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::TopKPush(state) => state,
+            PartitionState::TopKPull(_) => {
+                panic!("uses pull state when push state expected")
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        state.heap.insert(&state.extractor, batch)?;
+
+        Ok(PollPush::NeedsMore)
     }
 
     fn poll_finalize_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::TopKPush(state) => state,
+            PartitionState::TopKPull(_) => {
+                panic!("uses pull state when push state expected")
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let heap = std::mem::replace(&mut state.heap, TopKHeap::new(0));
+        let rows = heap.into_sorted_rows()?;
+
+        let mut shared = match operator_state {
+            OperatorState::TopK(state) => state.shared.lock(),
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        if let Some(rows) = rows {
+            shared.partition_rows.push(rows);
+        }
+        shared.remaining -= 1;
+
+        if shared.remaining == 0 {
+            if let Some(waker) = shared.pull_waker.take() {
+                waker.wake();
+            }
+        }
+
+        Ok(PollFinalize::Finalized)
     }
 
     fn poll_pull(
         &self,
-        _cx: &mut Context,
-        _partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
     ) -> Result<PollPull> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::TopKPull(state) => state,
+            PartitionState::TopKPush(_) => {
+                panic!("uses push state when pull state expected")
+            }
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.output.is_none() {
+            let mut shared = match operator_state {
+                OperatorState::TopK(state) => state.shared.lock(),
+                other => panic!("invalid operator state: {other:?}"),
+            };
+
+            if shared.remaining > 0 {
+                // Still waiting on inputs.
+                shared.pull_waker = Some(cx.waker().clone());
+                return Ok(PollPull::Pending);
+            }
+
+            let partition_rows = std::mem::take(&mut shared.partition_rows);
+            std::mem::drop(shared);
+
+            state.output = Some(self.merge(partition_rows));
+        }
+
+        let output = state.output.as_mut().expect("output to be set");
+        if output.pos >= output.indices.len() {
+            return Ok(PollPull::Exhausted);
+        }
+
+        let end = (output.pos + DEFAULT_TARGET_BATCH_SIZE).min(output.indices.len());
+        let batch = interleave_batches(&output.batches, &output.indices[output.pos..end])?;
+        output.pos = end;
+
+        Ok(PollPull::Computed(batch.into()))
+    }
+}
+
+impl PhysicalTopK {
+    /// Merge the sorted rows from all partitions, applying the limit and
+    /// offset.
+    fn merge(&self, partition_rows: Vec<SortedRows>) -> MergedOutput {
+        // Each partition holds at most `limit + offset` rows, so just sorting
+        // everything is fine here.
+        let mut rows: Vec<_> = partition_rows
+            .iter()
+            .enumerate()
+            .flat_map(|(batch_idx, rows)| {
+                rows.keys
+                    .iter()
+                    .enumerate()
+                    .map(move |(row_idx, key)| (key.as_slice(), batch_idx, row_idx))
+            })
+            .collect();
+        rows.sort_unstable();
+
+        let indices = rows
+            .into_iter()
+            .skip(self.offset.unwrap_or(0))
+            .take(self.limit)
+            .map(|(_, batch_idx, row_idx)| (batch_idx, row_idx))
+            .collect();
+
+        MergedOutput {
+            batches: partition_rows.into_iter().map(|rows| rows.batch).collect(),
+            indices,
+            pos: 0,
+        }
     }
 }
 
+/// Interleave rows from multiple batches into a single batch.
+fn interleave_batches(batches: &[Batch], indices: &[(usize, usize)]) -> Result<Batch> {
+    let num_cols = batches.first().map(|b| b.num_arrays()).unwrap_or(0);
+
+    let arrays = (0..num_cols)
+        .map(|col_idx| {
+            let cols: Vec<_> = batches
+                .iter()
+                .map(|batch| batch.array(col_idx).expect("column to exist"))
+                .collect();
+            interleave(&cols, indices)
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    Batch::try_from_arrays(arrays)
+}
+
 impl Explainable for PhysicalTopK {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("TopK")
+        let mut ent = ExplainEntry::new("TopK").with_value("limit", self.limit);
+        if let Some(offset) = self.offset {
+            ent = ent.with_value("offset", offset);
+        }
+        ent
+    }
+}
+
+impl DatabaseProtoConv for PhysicalTopK {
+    type ProtoType = rayexec_proto::generated::execution::PhysicalTopK;
+
+    fn to_proto_ctx(&self, context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            exprs: self
+                .exprs
+                .iter()
+                .map(|expr| expr.to_proto_ctx(context))
+                .collect::<Result<Vec<_>>>()?,
+            limit: self.limit as u64,
+            offset: self.offset.map(|o| o as u64),
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        Ok(Self {
+            exprs: proto
+                .exprs
+                .into_iter()
+                .map(|expr| DatabaseProtoConv::from_proto_ctx(expr, context))
+                .collect::<Result<Vec<_>>>()?,
+            limit: proto.limit as usize,
+            offset: proto.offset.map(|o| o as usize),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::compute::collation::Collation;
+    use crate::execution::operators::test_util::{
+        make_i32_batch,
+        test_database_context,
+        unwrap_poll_pull_batch,
+        TestWakerContext,
+    };
+    use crate::expr::physical::column_expr::PhysicalColumnExpr;
+
+    fn create_states(
+        operator: &PhysicalTopK,
+        partitions: usize,
+    ) -> (Arc<OperatorState>, Vec<PartitionState>, Vec<PartitionState>) {
+        let context = test_database_context();
+        let states = operator.create_states(&context, vec![partitions]).unwrap();
+
+        match states.partition_states {
+            InputOutputStates::SeparateInputOutput {
+                push_states,
+                pull_states,
+            } => (states.operator_state, push_states, pull_states),
+            other => panic!("unexpected states: {other:?}"),
+        }
+    }
+
+    #[test]
+    fn top_k_single_partition() {
+        let inputs = vec![
+            make_i32_batch([8, 10, 8, 4]),
+            make_i32_batch([2, 3]),
+            make_i32_batch([9, 1, 7, -1]),
+        ];
+
+        let operator = Arc::new(PhysicalTopK::new(
+            vec![PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 0 },
+                desc: true,
+                nulls_first: true,
+                collation: Collation::BINARY,
+            }],
+            4,
+            None,
+        ));
+        let (operator_state, mut push_states, mut pull_states) = create_states(&operator, 1);
+
+        let push_cx = TestWakerContext::new();
+        for input in inputs {
+            let poll_push = push_cx
+                .poll_push(&operator, &mut push_states[0], &operator_state, input)
+                .unwrap();
+            assert_eq!(PollPush::NeedsMore, poll_push);
+        }
+        operator
+            .poll_finalize_push(&mut push_cx.context(), &mut push_states[0], &operator_state)
+            .unwrap();
+
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+        let expected = make_i32_batch([10, 9, 8, 8]);
+        assert_eq!(expected, output);
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
+    }
+
+    #[test]
+    fn top_k_two_partitions_with_offset() {
+        let p0_inputs = vec![make_i32_batch([8, 6, 6]), make_i32_batch([5, 4, 3, 2])];
+        let p1_inputs = vec![make_i32_batch([10, 1, 9]), make_i32_batch([7, 0])];
+
+        let operator = Arc::new(PhysicalTopK::new(
+            vec![PhysicalSortExpression {
+                column: PhysicalColumnExpr { idx: 0 },
+                desc: false,
+                nulls_first: true,
+                collation: Collation::BINARY,
+            }],
+            3,
+            Some(2),
+        ));
+        let (operator_state, mut push_states, mut pull_states) = create_states(&operator, 2);
+
+        // Nothing to pull until all inputs are finished.
+        let pull_cx = TestWakerContext::new();
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Pending, poll_pull);
+
+        let push_cx = TestWakerContext::new();
+        for input in p0_inputs {
+            push_cx
+                .poll_push(&operator, &mut push_states[0], &operator_state, input)
+                .unwrap();
+        }
+        operator
+            .poll_finalize_push(&mut push_cx.context(), &mut push_states[0], &operator_state)
+            .unwrap();
+
+        // Still waiting on the second partition.
+        assert_eq!(0, pull_cx.wake_count());
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Pending, poll_pull);
+
+        for input in p1_inputs {
+            push_cx
+                .poll_push(&operator, &mut push_states[1], &operator_state, input)
+                .unwrap();
+        }
+        operator
+            .poll_finalize_push(&mut push_cx.context(), &mut push_states[1], &operator_state)
+            .unwrap();
+        assert_eq!(1, pull_cx.wake_count());
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        let output = unwrap_poll_pull_batch(poll_pull);
+        let expected = make_i32_batch([2, 3, 4]);
+        assert_eq!(expected, output);
+
+        let poll_pull = pull_cx
+            .poll_pull(&operator, &mut pull_states[0], &operator_state)
+            .unwrap();
+        assert_eq!(PollPull::Exhausted, poll_pull);
     }
 }
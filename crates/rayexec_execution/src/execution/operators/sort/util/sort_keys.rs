@@ -23,6 +23,7 @@ impl SortKeysExtractor {
                 .map(|expr| ComparableColumn {
                     desc: expr.desc,
                     nulls_first: expr.nulls_first,
+                    collation: expr.collation,
                 })
                 .collect(),
         };
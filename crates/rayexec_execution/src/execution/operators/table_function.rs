@@ -21,6 +21,7 @@ use crate::arrays::batch::Batch;
 use crate::database::DatabaseContext;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::functions::table::{PlannedTableFunction, TableFunctionImpl};
+use crate::logical::scan_filter::ScanFilter;
 use crate::proto::DatabaseProtoConv;
 use crate::storage::table_storage::{DataTableScan, Projections};
 
@@ -41,6 +42,8 @@ impl fmt::Debug for TableFunctionPartitionState {
 pub struct PhysicalTableFunction {
     function: PlannedTableFunction,
     projections: Projections,
+    /// Filters the scan may use to skip data.
+    filters: Vec<ScanFilter>,
 }
 
 impl PhysicalTableFunction {
@@ -48,8 +51,14 @@ impl PhysicalTableFunction {
         PhysicalTableFunction {
             function,
             projections,
+            filters: Vec::new(),
         }
     }
+
+    pub fn with_filters(mut self, filters: Vec<ScanFilter>) -> Self {
+        self.filters = filters;
+        self
+    }
 }
 
 impl ExecutableOperator for PhysicalTableFunction {
@@ -68,8 +77,7 @@ impl ExecutableOperator for PhysicalTableFunction {
             }
         };
 
-        // TODO: Pushdown  filters
-        let scans = scan_func.scan(self.projections.clone(), partitions[0])?;
+        let scans = scan_func.scan(self.projections.clone(), &self.filters, partitions[0])?;
 
         let states = scans
             .into_iter()
@@ -149,7 +157,11 @@ impl ExecutableOperator for PhysicalTableFunction {
 
 impl Explainable for PhysicalTableFunction {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("TableFunction")
+        let ent = ExplainEntry::new("TableFunction");
+        match &self.function.function_impl {
+            TableFunctionImpl::Scan(table) => table.explain_scan(ent, &self.filters),
+            _ => ent,
+        }
     }
 }
 
@@ -19,12 +19,15 @@ use crate::arrays::scalar::ScalarValue;
 use crate::database::system::new_system_catalog;
 use crate::database::DatabaseContext;
 use crate::datasource::DataSourceRegistry;
+use crate::runtime::spill::MemorySpillProvider;
 
 pub fn test_database_context() -> DatabaseContext {
-    DatabaseContext::new(Arc::new(
+    let mut context = DatabaseContext::new(Arc::new(
         new_system_catalog(&DataSourceRegistry::default()).unwrap(),
     ))
-    .unwrap()
+    .unwrap();
+    context.set_spill_provider(Some(Arc::new(MemorySpillProvider)));
+    context
 }
 
 /// Test context containg a waker implementation that counts the number of times
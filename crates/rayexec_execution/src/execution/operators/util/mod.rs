@@ -4,3 +4,4 @@ pub mod futures;
 pub mod hash;
 pub mod outer_join_tracker;
 pub mod resizer;
+pub mod spill;
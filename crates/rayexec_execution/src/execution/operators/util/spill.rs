@@ -0,0 +1,407 @@
+use std::sync::Arc;
+use std::task::{Context, Poll};
+
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::buffer_manager::{BufferManager, LimitedBufferManager, Reservation};
+use crate::arrays::array::{Array, ArrayData2, BinaryData};
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::arrays::ipc;
+use crate::runtime::spill::{SpillChunk, SpillProvider, SpillStorage, SpillStorageReader};
+
+/// Memory reserved by an operator for data it's buffering.
+///
+/// The reservation is released back to the buffer manager on drop.
+#[derive(Debug)]
+pub struct MemoryReservation {
+    manager: Arc<LimitedBufferManager>,
+    reservation: Option<Reservation<LimitedBufferManager>>,
+}
+
+impl MemoryReservation {
+    pub fn new(manager: &Arc<LimitedBufferManager>) -> Self {
+        MemoryReservation {
+            manager: manager.clone(),
+            reservation: None,
+        }
+    }
+
+    /// Get the buffer manager this reservation is reserving memory from.
+    pub fn buffer_manager(&self) -> &Arc<LimitedBufferManager> {
+        &self.manager
+    }
+
+    /// Number of bytes currently reserved.
+    pub fn size(&self) -> usize {
+        self.reservation.as_ref().map(|r| r.size()).unwrap_or(0)
+    }
+
+    /// Try to grow the reservation by some number of bytes.
+    ///
+    /// Returns false if growing would exceed the memory limit, in which case
+    /// the reservation is left unchanged.
+    pub fn try_grow(&mut self, size_bytes: usize) -> Result<bool> {
+        let additional = match self.manager.try_reserve(size_bytes)? {
+            Some(reservation) => reservation,
+            None => return Ok(false),
+        };
+
+        self.merge_reservation(additional);
+
+        Ok(true)
+    }
+
+    /// Grow the reservation without checking the memory limit.
+    ///
+    /// Used to account for memory that's already been allocated, e.g. aggregate
+    /// states that grew while being updated.
+    pub fn grow_unchecked(&mut self, size_bytes: usize) -> Result<()> {
+        let additional = self.manager.reserve_unchecked(size_bytes)?;
+        self.merge_reservation(additional);
+        Ok(())
+    }
+
+    /// Shrink the reservation by some number of bytes, releasing them back to
+    /// the buffer manager.
+    pub fn shrink(&mut self, size_bytes: usize) {
+        if let Some(reservation) = self.reservation.as_mut() {
+            let released = reservation.split(size_bytes);
+            self.manager.drop_reservation(&released);
+        }
+    }
+
+    /// Merge another reservation into this one.
+    pub fn merge(&mut self, mut other: MemoryReservation) {
+        if let Some(reservation) = other.reservation.take() {
+            self.merge_reservation(reservation);
+        }
+    }
+
+    fn merge_reservation(&mut self, additional: Reservation<LimitedBufferManager>) {
+        match self.reservation.as_mut() {
+            Some(reservation) => reservation.merge(additional),
+            None => self.reservation = Some(additional),
+        }
+    }
+
+    /// Move the memory held by this reservation into a new reservation,
+    /// leaving this one empty.
+    pub fn take(&mut self) -> Self {
+        MemoryReservation {
+            manager: self.manager.clone(),
+            reservation: self.reservation.take(),
+        }
+    }
+
+    /// Release all memory held by this reservation.
+    pub fn release(&mut self) {
+        if let Some(reservation) = self.reservation.take() {
+            self.manager.drop_reservation(&reservation);
+        }
+    }
+}
+
+impl Drop for MemoryReservation {
+    fn drop(&mut self) {
+        self.release();
+    }
+}
+
+/// Estimate the number of bytes a batch is holding on to.
+///
+/// This only accounts for the underlying array data, and may over count when
+/// arrays share data.
+pub fn batch_size_bytes(batch: &Batch) -> usize {
+    batch.arrays().iter().map(array_size_bytes).sum()
+}
+
+/// Estimate the number of bytes an array is holding on to.
+pub fn array_size_bytes(array: &Array) -> usize {
+    let data_size = match array.array_data() {
+        ArrayData2::UntypedNull(_) => 0,
+        ArrayData2::Boolean(s) => s.len().div_ceil(8),
+        ArrayData2::Float16(s) => s.data_size_bytes(),
+        ArrayData2::Float32(s) => s.data_size_bytes(),
+        ArrayData2::Float64(s) => s.data_size_bytes(),
+        ArrayData2::Int8(s) => s.data_size_bytes(),
+        ArrayData2::Int16(s) => s.data_size_bytes(),
+        ArrayData2::Int32(s) => s.data_size_bytes(),
+        ArrayData2::Int64(s) => s.data_size_bytes(),
+        ArrayData2::Int128(s) => s.data_size_bytes(),
+        ArrayData2::UInt8(s) => s.data_size_bytes(),
+        ArrayData2::UInt16(s) => s.data_size_bytes(),
+        ArrayData2::UInt32(s) => s.data_size_bytes(),
+        ArrayData2::UInt64(s) => s.data_size_bytes(),
+        ArrayData2::UInt128(s) => s.data_size_bytes(),
+        ArrayData2::Interval(s) => s.data_size_bytes(),
+        ArrayData2::Binary(b) => {
+            // Account for per-value metadata on top of the actual data.
+            let metadata_size = match b {
+                BinaryData::Binary(s) => s.len() * std::mem::size_of::<i32>(),
+                BinaryData::LargeBinary(s) => s.len() * std::mem::size_of::<i64>(),
+                BinaryData::German(s) => s.len() * 16,
+            };
+            metadata_size + b.binary_data_size_bytes()
+        }
+        ArrayData2::List(s) => s.metadata.data_size_bytes() + array_size_bytes(&s.array),
+        ArrayData2::Struct(s) => s.children.iter().map(array_size_bytes).sum(),
+    };
+
+    let selection_size = array
+        .selection_vector()
+        .map(|sel| sel.num_rows() * std::mem::size_of::<usize>())
+        .unwrap_or(0);
+
+    data_size + selection_size
+}
+
+/// Batches that have been spilled to storage provided by the runtime.
+///
+/// Each batch is written as a single chunk containing an IPC record batch
+/// message. Spilled data is removed once this is dropped.
+///
+/// Chunks count against the memory limit until the storage has persisted
+/// them.
+#[derive(Debug)]
+pub struct SpillFile {
+    storage: Box<dyn SpillStorage>,
+    buffer_manager: Arc<LimitedBufferManager>,
+    datatypes: Vec<DataType>,
+    finished: bool,
+}
+
+impl SpillFile {
+    /// Create a new spill file for batches with the given types.
+    ///
+    /// Errors if there's no spill provider, since there's nowhere for the
+    /// batches to go.
+    pub fn try_new(
+        provider: Option<&dyn SpillProvider>,
+        buffer_manager: &Arc<LimitedBufferManager>,
+        datatypes: Vec<DataType>,
+    ) -> Result<Self> {
+        let provider = provider.ok_or_else(|| {
+            RayexecError::new(
+                "Memory limit exceeded, and spilling is not supported by this runtime",
+            )
+        })?;
+
+        Ok(SpillFile {
+            storage: provider.create_storage()?,
+            buffer_manager: buffer_manager.clone(),
+            datatypes,
+            finished: false,
+        })
+    }
+
+    /// Append a batch to the file.
+    pub fn write_batch(&mut self, batch: &Batch) -> Result<()> {
+        if batch.num_rows() == 0 {
+            return Ok(());
+        }
+
+        if batch.num_arrays() != self.datatypes.len() {
+            return Err(
+                RayexecError::new("Spilled batch has unexpected number of columns")
+                    .with_field("expected", self.datatypes.len())
+                    .with_field("got", batch.num_arrays()),
+            );
+        }
+
+        if self.finished {
+            return Err(RayexecError::new("Cannot write to a finished spill file"));
+        }
+
+        let mut chunk = Vec::new();
+        ipc::write_batch(&mut chunk, batch)?;
+
+        // Chunk is already allocated, account for it regardless of the limit.
+        let mut reservation = MemoryReservation::new(&self.buffer_manager);
+        reservation.grow_unchecked(chunk.len())?;
+        self.storage
+            .write_chunk(SpillChunk::new(chunk, reservation))?;
+
+        Ok(())
+    }
+
+    /// Finish the file.
+    ///
+    /// No more batches can be written once finished.
+    pub fn finish(&mut self) -> Result<()> {
+        if !self.finished {
+            self.finished = true;
+            self.storage.finish()?;
+        }
+        Ok(())
+    }
+
+    /// Create a reader for reading back all batches in the file.
+    ///
+    /// The file must be finished before reading.
+    pub fn reader(&self) -> Result<SpillReader> {
+        if !self.finished {
+            return Err(RayexecError::new(
+                "Spill file must be finished before reading",
+            ));
+        }
+
+        Ok(SpillReader {
+            reader: self.storage.reader()?,
+            datatypes: self.datatypes.clone(),
+        })
+    }
+}
+
+/// Reads batches back from a spill file in the order they were written.
+#[derive(Debug)]
+pub struct SpillReader {
+    reader: Box<dyn SpillStorageReader>,
+    datatypes: Vec<DataType>,
+}
+
+impl SpillReader {
+    /// Poll for the next batch in the file, returning None once all batches
+    /// have been read.
+    pub fn poll_read_batch(&mut self, cx: &mut Context) -> Poll<Result<Option<Batch>>> {
+        let chunk = match self.reader.poll_next_chunk(cx) {
+            Poll::Ready(Ok(Some(chunk))) => chunk,
+            Poll::Ready(Ok(None)) => return Poll::Ready(Ok(None)),
+            Poll::Ready(Err(e)) => return Poll::Ready(Err(e)),
+            Poll::Pending => return Poll::Pending,
+        };
+
+        let batch = ipc::read_batch(&mut chunk.as_slice(), &self.datatypes).and_then(|batch| {
+            batch.ok_or_else(|| RayexecError::new("Spilled chunk missing batch"))
+        });
+
+        Poll::Ready(batch.map(Some))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use futures::task::noop_waker_ref;
+
+    use super::*;
+    use crate::arrays::row::OwnedScalarRow;
+    use crate::runtime::spill::MemorySpillProvider;
+
+    #[allow(deprecated)]
+    fn batch_rows(batch: &Batch) -> Vec<OwnedScalarRow> {
+        (0..batch.num_rows())
+            .map(|idx| batch.row(idx).unwrap().into_owned())
+            .collect()
+    }
+
+    #[test]
+    fn reservation_released_on_drop() {
+        let manager = Arc::new(LimitedBufferManager::new(Some(100)));
+
+        let mut reservation = MemoryReservation::new(&manager);
+        assert!(reservation.try_grow(60).unwrap());
+        assert!(!reservation.try_grow(60).unwrap());
+        assert!(reservation.try_grow(40).unwrap());
+        assert_eq!(100, reservation.size());
+        assert_eq!(100, manager.reserved());
+
+        std::mem::drop(reservation);
+        assert_eq!(0, manager.reserved());
+    }
+
+    #[test]
+    fn reservation_shrink_and_grow_unchecked() {
+        let manager = Arc::new(LimitedBufferManager::new(Some(100)));
+
+        let mut reservation = MemoryReservation::new(&manager);
+        assert!(reservation.try_grow(80).unwrap());
+        reservation.shrink(50);
+        assert_eq!(30, reservation.size());
+        assert_eq!(30, manager.reserved());
+
+        // Unchecked growth may exceed the limit, causing later checked growth
+        // to fail.
+        reservation.grow_unchecked(90).unwrap();
+        assert_eq!(120, manager.reserved());
+        assert!(!reservation.try_grow(1).unwrap());
+
+        let mut other = MemoryReservation::new(&manager);
+        reservation.shrink(100);
+        assert!(other.try_grow(50).unwrap());
+        reservation.merge(other);
+        assert_eq!(70, reservation.size());
+        assert_eq!(70, manager.reserved());
+
+        std::mem::drop(reservation);
+        assert_eq!(0, manager.reserved());
+    }
+
+    #[test]
+    fn spill_roundtrip() {
+        let batch1 = Batch::try_from_arrays([
+            Array::from_iter([Some(1), None, Some(3)]),
+            Array::from_iter(["a", "b", "c"]),
+        ])
+        .unwrap();
+        let batch2 = Batch::try_from_arrays([
+            Array::from_iter([Some(4), Some(5)]),
+            Array::from_iter(["d", "e"]),
+        ])
+        .unwrap();
+
+        let manager = Arc::new(LimitedBufferManager::new(None));
+        let mut file = SpillFile::try_new(
+            Some(&MemorySpillProvider),
+            &manager,
+            vec![DataType::Int32, DataType::Utf8],
+        )
+        .unwrap();
+        file.write_batch(&batch1).unwrap();
+        file.write_batch(&batch2).unwrap();
+        file.finish().unwrap();
+
+        // Memory storage releases chunk reservations once written.
+        assert_eq!(0, manager.reserved());
+
+        let mut cx = Context::from_waker(noop_waker_ref());
+        let mut reader = file.reader().unwrap();
+        let mut read_batch = || match reader.poll_read_batch(&mut cx) {
+            Poll::Ready(batch) => batch.unwrap(),
+            Poll::Pending => panic!("unexpected pending"),
+        };
+
+        let out1 = read_batch().unwrap();
+        assert_eq!(batch_rows(&batch1), batch_rows(&out1));
+        assert_eq!(&DataType::Utf8, out1.array(1).unwrap().datatype());
+        let out2 = read_batch().unwrap();
+        assert_eq!(batch_rows(&batch2), batch_rows(&out2));
+        assert!(read_batch().is_none());
+    }
+
+    #[test]
+    fn spill_without_provider() {
+        let manager = Arc::new(LimitedBufferManager::new(None));
+        SpillFile::try_new(None, &manager, vec![DataType::Int32]).unwrap_err();
+    }
+
+    #[test]
+    fn spill_read_before_finish() {
+        let manager = Arc::new(LimitedBufferManager::new(None));
+        let file = SpillFile::try_new(Some(&MemorySpillProvider), &manager, vec![DataType::Int32])
+            .unwrap();
+        file.reader().unwrap_err();
+    }
+
+    #[test]
+    fn spill_chunk_reserved_until_taken() {
+        let manager = Arc::new(LimitedBufferManager::new(Some(4)));
+        let mut reservation = MemoryReservation::new(&manager);
+        reservation.grow_unchecked(8).unwrap();
+
+        let chunk = SpillChunk::new(vec![0; 8], reservation);
+        assert_eq!(8, manager.reserved());
+
+        assert_eq!(8, chunk.into_data().len());
+        assert_eq!(0, manager.reserved());
+    }
+}
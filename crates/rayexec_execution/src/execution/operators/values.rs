@@ -48,9 +48,10 @@ impl ExecutableOperator for PhysicalValues {
             })
             .collect();
 
-        for (idx, batch) in self.batches.iter().enumerate() {
-            states[idx % num_partitions].batches.push(batch.clone());
-        }
+        // All batches are emitted from a single partition so that rows come
+        // out in the order they were written. Batches are popped off the end
+        // when pulling, so store them reversed.
+        states[0].batches = self.batches.iter().rev().cloned().collect();
 
         Ok(ExecutionStates {
             operator_state: Arc::new(OperatorState::None),
@@ -1,63 +1,682 @@
-use std::task::Context;
+mod segment_tree;
 
-use rayexec_error::Result;
+use std::collections::VecDeque;
+use std::ops::Range;
+use std::sync::Arc;
+use std::task::{Context, Waker};
+
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result};
+use segment_tree::SegmentTree;
 
 use super::{
     ExecutableOperator,
     ExecutionStates,
+    InputOutputStates,
     OperatorState,
     PartitionState,
     PollFinalize,
     PollPull,
     PollPush,
 };
+use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::executor::scalar::{concat, interleave, HashExecutor};
+use crate::arrays::row::encoding::ComparableRows;
 use crate::database::DatabaseContext;
+use crate::execution::computed_batch::ComputedBatches;
+use crate::execution::operators::sort::util::sort_keys::SortKeysExtractor;
+use crate::execution::operators::util::hash::partition_for_hash;
+use crate::execution::operators::util::resizer::DEFAULT_TARGET_BATCH_SIZE;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::physical::column_expr::PhysicalColumnExpr;
+use crate::expr::physical::{
+    PhysicalSortExpression,
+    PhysicalWindowExpression,
+    PhysicalWindowFrameBound,
+};
+use crate::expr::window_expr::{WindowFrameExclusion, WindowFrameUnit, WindowFunctionKind};
+use crate::functions::aggregate::AggregateFunctionImpl;
+use crate::functions::window::WindowRowBounds;
+
+#[derive(Debug)]
+pub struct WindowPartitionState {
+    /// Index of this partition.
+    partition_idx: usize,
+    /// Batches we've collected for each output partition.
+    ///
+    /// Emptied once this partition finishes pushing.
+    output_buffers: Vec<Vec<Batch>>,
+    /// Reusable hashes buffer.
+    hash_buf: Vec<u64>,
+    /// Computed output batches for this partition.
+    ///
+    /// None if we haven't computed the windows yet.
+    output: Option<VecDeque<Batch>>,
+}
+
+#[derive(Debug)]
+pub struct WindowOperatorState {
+    /// States containing pending batches from input partitions.
+    output_states: Vec<Mutex<SharedOutputPartitionState>>,
+}
 
 #[derive(Debug)]
-pub struct PhysicalWindow {}
+struct SharedOutputPartitionState {
+    /// Batches from input partitions that belong to this output partition.
+    batches: Vec<Batch>,
+    /// Number of remaining inputs. Initially set to number of input partitions.
+    remaining: usize,
+    /// Waker for the thread that attempted to pull before all inputs
+    /// completed.
+    pull_waker: Option<Waker>,
+}
+
+/// Computes window functions over the input.
+///
+/// Input rows are hash partitioned on the PARTITION BY columns such that all
+/// rows for a single window partition end up in the same output partition.
+/// Each output partition then sorts its rows by the partition and order by
+/// columns before computing the window functions.
+///
+/// Output batches contain all input columns (in sorted order) followed by a
+/// column for each window function.
+#[derive(Debug)]
+pub struct PhysicalWindow {
+    /// Columns making up the window partitions.
+    partition_by: Vec<PhysicalColumnExpr>,
+    /// Ordering of rows within each window partition.
+    order_by: Vec<PhysicalSortExpression>,
+    /// Functions to compute.
+    ///
+    /// All functions share the same partitioning and ordering.
+    functions: Vec<PhysicalWindowExpression>,
+}
+
+impl PhysicalWindow {
+    pub fn new(
+        partition_by: Vec<PhysicalColumnExpr>,
+        order_by: Vec<PhysicalSortExpression>,
+        functions: Vec<PhysicalWindowExpression>,
+    ) -> Self {
+        PhysicalWindow {
+            partition_by,
+            order_by,
+            functions,
+        }
+    }
+}
 
 impl ExecutableOperator for PhysicalWindow {
     fn create_states(
         &self,
         _context: &DatabaseContext,
-        _partitions: Vec<usize>,
+        partitions: Vec<usize>,
     ) -> Result<ExecutionStates> {
-        unimplemented!()
+        let num_partitions = partitions[0];
+
+        let operator_state = OperatorState::Window(WindowOperatorState {
+            output_states: (0..num_partitions)
+                .map(|_| {
+                    Mutex::new(SharedOutputPartitionState {
+                        batches: Vec::new(),
+                        remaining: num_partitions,
+                        pull_waker: None,
+                    })
+                })
+                .collect(),
+        });
+
+        let partition_states = (0..num_partitions)
+            .map(|idx| {
+                PartitionState::Window(WindowPartitionState {
+                    partition_idx: idx,
+                    output_buffers: (0..num_partitions).map(|_| Vec::new()).collect(),
+                    hash_buf: Vec::new(),
+                    output: None,
+                })
+            })
+            .collect();
+
+        Ok(ExecutionStates {
+            operator_state: Arc::new(operator_state),
+            partition_states: InputOutputStates::OneToOne { partition_states },
+        })
     }
 
     fn poll_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
+        partition_state: &mut PartitionState,
         _operator_state: &OperatorState,
-        _batch: Batch,
+        batch: Batch,
     ) -> Result<PollPush> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Window(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        if state.output.is_some() {
+            return Err(RayexecError::new(
+                "Attempted to push to partition that should be producing batches",
+            ));
+        }
+
+        if batch.num_rows() == 0 {
+            return Ok(PollPush::NeedsMore);
+        }
+
+        let num_partitions = state.output_buffers.len();
+
+        // Without PARTITION BY, everything needs to end up in the same output
+        // partition.
+        if self.partition_by.is_empty() || num_partitions == 1 {
+            state.output_buffers[0].push(batch);
+            return Ok(PollPush::NeedsMore);
+        }
+
+        let partition_cols = self
+            .partition_by
+            .iter()
+            .map(|col| {
+                batch
+                    .array(col.idx)
+                    .cloned()
+                    .ok_or_else(|| RayexecError::new("Missing partition column"))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        state.hash_buf.resize(batch.num_rows(), 0);
+        let hashes = HashExecutor::hash_many(&partition_cols, &mut state.hash_buf)?;
+
+        let mut partition_rows = vec![Vec::new(); num_partitions];
+        for (row_idx, hash) in hashes.iter().enumerate() {
+            partition_rows[partition_for_hash(*hash, num_partitions)].push((0, row_idx));
+        }
+
+        for (partition_idx, rows) in partition_rows.iter().enumerate() {
+            if rows.is_empty() {
+                continue;
+            }
+
+            let arrays = batch
+                .arrays()
+                .iter()
+                .map(|arr| interleave(&[arr], rows))
+                .collect::<Result<Vec<_>>>()?;
+
+            state.output_buffers[partition_idx].push(Batch::try_from_arrays(arrays)?);
+        }
+
+        Ok(PollPush::NeedsMore)
     }
 
     fn poll_finalize_push(
         &self,
         _cx: &mut Context,
-        _partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
     ) -> Result<PollFinalize> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Window(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let operator_state = match operator_state {
+            OperatorState::Window(state) => state,
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        let buffers = std::mem::take(&mut state.output_buffers);
+        for (partition_idx, batches) in buffers.into_iter().enumerate() {
+            let mut output_state = operator_state.output_states[partition_idx].lock();
+            output_state.batches.extend(batches);
+            output_state.remaining -= 1;
+
+            if let Some(waker) = output_state.pull_waker.take() {
+                waker.wake();
+            }
+        }
+
+        Ok(PollFinalize::Finalized)
     }
 
     fn poll_pull(
         &self,
-        _cx: &mut Context,
-        _partition_state: &mut PartitionState,
-        _operator_state: &OperatorState,
+        cx: &mut Context,
+        partition_state: &mut PartitionState,
+        operator_state: &OperatorState,
     ) -> Result<PollPull> {
-        unimplemented!()
+        let state = match partition_state {
+            PartitionState::Window(state) => state,
+            other => panic!("invalid partition state: {other:?}"),
+        };
+
+        let operator_state = match operator_state {
+            OperatorState::Window(state) => state,
+            other => panic!("invalid operator state: {other:?}"),
+        };
+
+        if state.output.is_none() {
+            let mut shared = operator_state.output_states[state.partition_idx].lock();
+            if shared.remaining != 0 {
+                // Still waiting on some inputs.
+                shared.pull_waker = Some(cx.waker().clone());
+                return Ok(PollPull::Pending);
+            }
+
+            let batches = std::mem::take(&mut shared.batches);
+            std::mem::drop(shared);
+
+            state.output = Some(self.compute_windows(batches)?);
+        }
+
+        match state.output.as_mut().unwrap().pop_front() {
+            Some(batch) => Ok(PollPull::Computed(ComputedBatches::Single(batch))),
+            None => Ok(PollPull::Exhausted),
+        }
     }
 }
 
+impl PhysicalWindow {
+    /// Compute the window functions for all batches in an output partition.
+    ///
+    /// Returns the output batches.
+    fn compute_windows(&self, batches: Vec<Batch>) -> Result<VecDeque<Batch>> {
+        let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+        if num_rows == 0 {
+            return Ok(VecDeque::new());
+        }
+
+        // Combine everything into a single batch.
+        let num_cols = batches[0].arrays().len();
+        let arrays = (0..num_cols)
+            .map(|col_idx| {
+                let cols: Vec<_> = batches
+                    .iter()
+                    .map(|batch| batch.array(col_idx).expect("column to exist"))
+                    .collect();
+                concat(&cols)
+            })
+            .collect::<Result<Vec<_>>>()?;
+        let batch = Batch::try_from_arrays(arrays)?;
+
+        // Sort by partition columns, then order by columns.
+        let partition_sort_exprs: Vec<_> = self
+            .partition_by
+            .iter()
+            .map(|col| PhysicalSortExpression {
+                column: col.clone(),
+                desc: false,
+                nulls_first: false,
+                collation: Collation::BINARY,
+            })
+            .collect();
+        let mut full_sort_exprs = partition_sort_exprs.clone();
+        full_sort_exprs.extend(self.order_by.iter().cloned());
+
+        let batch = if full_sort_exprs.is_empty() {
+            batch
+        } else {
+            let keys = SortKeysExtractor::new(&full_sort_exprs).sort_keys(&batch)?;
+            let mut sort_indices: Vec<_> = (0..num_rows).collect();
+            sort_indices.sort_by_key(|idx| keys.row(*idx).expect("row to exist"));
+
+            let interleave_indices: Vec<_> = sort_indices.iter().map(|idx| (0, *idx)).collect();
+            let arrays = batch
+                .arrays()
+                .iter()
+                .map(|arr| interleave(&[arr], &interleave_indices))
+                .collect::<Result<Vec<_>>>()?;
+            Batch::try_from_arrays(arrays)?
+        };
+
+        // Find partition and peer boundaries on the sorted batch.
+        let partitions = boundaries(&partition_sort_exprs, &batch)?;
+        let peers = boundaries(&full_sort_exprs, &batch)?;
+        let groups = PeerGroups::new(&peers);
+
+        let mut outputs = Vec::with_capacity(self.functions.len());
+        for function in &self.functions {
+            let bounds = FrameContext {
+                batch: &batch,
+                order_by: &self.order_by,
+                partitions: &partitions,
+                peers: &peers,
+                groups: &groups,
+            }
+            .compute_row_bounds(function)?;
+
+            let inputs: Vec<_> = function
+                .columns
+                .iter()
+                .map(|col| batch.array(col.idx).expect("input column to exist"))
+                .collect();
+
+            let output = match &function.function {
+                WindowFunctionKind::Window(window) => {
+                    window.function_impl.evaluate(&inputs, &bounds)?
+                }
+                WindowFunctionKind::Aggregate(agg) => {
+                    evaluate_aggregate(agg.function_impl.as_ref(), &inputs, &bounds)?
+                }
+            };
+
+            outputs.push(output);
+        }
+
+        let mut arrays = batch.into_arrays();
+        let num_input_cols = arrays.len();
+        arrays.extend(outputs);
+
+        debug_assert_eq!(num_input_cols + self.functions.len(), arrays.len());
+
+        // Produce reasonably sized output batches.
+        let mut output_batches = VecDeque::new();
+        let mut offset = 0;
+        while offset < num_rows {
+            let count = usize::min(DEFAULT_TARGET_BATCH_SIZE, num_rows - offset);
+            let arrays: Vec<_> = arrays.iter().map(|arr| arr.slice(offset, count)).collect();
+            output_batches.push_back(Batch::try_from_arrays(arrays)?);
+            offset += count;
+        }
+
+        Ok(output_batches)
+    }
+}
+
+/// Compute the ranges of consecutive rows with equal keys.
+///
+/// Returns a vector with the range containing each row. If there are no sort
+/// expressions, all rows are considered equal.
+fn boundaries(exprs: &[PhysicalSortExpression], batch: &Batch) -> Result<Vec<Range<usize>>> {
+    let num_rows = batch.num_rows();
+    if exprs.is_empty() {
+        return Ok(vec![0..num_rows; num_rows]);
+    }
+
+    let keys = SortKeysExtractor::new(exprs).sort_keys(batch)?;
+
+    let mut ranges = Vec::with_capacity(num_rows);
+    let mut start = 0;
+    for idx in 1..=num_rows {
+        if idx == num_rows || keys.row(idx) != keys.row(start) {
+            ranges.extend((start..idx).map(|_| start..idx));
+            start = idx;
+        }
+    }
+
+    Ok(ranges)
+}
+
+/// Peer groups numbered in order across the entire sorted input.
+#[derive(Debug)]
+struct PeerGroups {
+    /// Peer group index for each row.
+    row_groups: Vec<usize>,
+    /// Start row for each group, with an additional trailing entry containing
+    /// the total number of rows.
+    group_starts: Vec<usize>,
+}
+
+impl PeerGroups {
+    fn new(peers: &[Range<usize>]) -> Self {
+        let mut row_groups = Vec::with_capacity(peers.len());
+        let mut group_starts = Vec::new();
+
+        for (row, peers) in peers.iter().enumerate() {
+            if peers.start == row {
+                group_starts.push(row);
+            }
+            row_groups.push(group_starts.len() - 1);
+        }
+        group_starts.push(peers.len());
+
+        PeerGroups {
+            row_groups,
+            group_starts,
+        }
+    }
+}
+
+/// Everything needed to compute the frames for rows in the sorted input.
+#[derive(Debug)]
+struct FrameContext<'a> {
+    batch: &'a Batch,
+    order_by: &'a [PhysicalSortExpression],
+    partitions: &'a [Range<usize>],
+    peers: &'a [Range<usize>],
+    groups: &'a PeerGroups,
+}
+
+impl FrameContext<'_> {
+    /// Compute the bounds for each row for a window function.
+    fn compute_row_bounds(
+        &self,
+        function: &PhysicalWindowExpression,
+    ) -> Result<Vec<WindowRowBounds>> {
+        // Encoded ORDER BY values and boundary values for RANGE offsets.
+        let order_keys = match (&function.start, &function.end) {
+            (PhysicalWindowFrameBound::RangeOffset(_), _)
+            | (_, PhysicalWindowFrameBound::RangeOffset(_)) => {
+                let order_by = self.order_by.first().ok_or_else(|| {
+                    RayexecError::new("RANGE with offset requires an ORDER BY column")
+                })?;
+                Some(SortKeysExtractor::new(std::slice::from_ref(order_by)).sort_keys(self.batch)?)
+            }
+            _ => None,
+        };
+        let start_keys = self.range_boundary_keys(&function.start)?;
+        let end_keys = self.range_boundary_keys(&function.end)?;
+
+        (0..self.batch.num_rows())
+            .map(|row| {
+                let partition = &self.partitions[row];
+                let peers = &self.peers[row];
+
+                let start = match &function.start {
+                    PhysicalWindowFrameBound::RangeOffset(_) => {
+                        let order_keys = order_keys.as_ref().expect("order keys to exist");
+                        let boundary = start_keys.as_ref().expect("start keys to exist").row(row);
+                        // First row not before the boundary value.
+                        partition_point(partition.clone(), |idx| order_keys.row(idx) < boundary)
+                    }
+                    bound => self.frame_start(function.unit, bound, row)?,
+                };
+
+                let end = match &function.end {
+                    PhysicalWindowFrameBound::RangeOffset(_) => {
+                        let order_keys = order_keys.as_ref().expect("order keys to exist");
+                        let boundary = end_keys.as_ref().expect("end keys to exist").row(row);
+                        // First row after the boundary value.
+                        partition_point(partition.clone(), |idx| order_keys.row(idx) <= boundary)
+                    }
+                    bound => self.frame_end(function.unit, bound, row)?,
+                };
+
+                let frame = start..usize::max(start, end);
+
+                let (exclude, include) = match function.exclude {
+                    WindowFrameExclusion::ExcludeNoOthers => (0..0, None),
+                    WindowFrameExclusion::ExcludeCurrentRow => (row..(row + 1), None),
+                    WindowFrameExclusion::ExcludeGroup => (peers.clone(), None),
+                    WindowFrameExclusion::ExcludeTies => (peers.clone(), Some(row)),
+                };
+
+                Ok(WindowRowBounds::new(
+                    partition.clone(),
+                    peers.clone(),
+                    frame,
+                    exclude,
+                    include,
+                ))
+            })
+            .collect()
+    }
+
+    /// Encode the boundary values for a RANGE offset bound such that they can
+    /// be compared with the encoded ORDER BY values.
+    fn range_boundary_keys(
+        &self,
+        bound: &PhysicalWindowFrameBound,
+    ) -> Result<Option<ComparableRows>> {
+        match bound {
+            PhysicalWindowFrameBound::RangeOffset(col) => {
+                let order_by = self.order_by.first().ok_or_else(|| {
+                    RayexecError::new("RANGE with offset requires an ORDER BY column")
+                })?;
+                let expr = PhysicalSortExpression {
+                    column: col.clone(),
+                    desc: order_by.desc,
+                    nulls_first: order_by.nulls_first,
+                    collation: order_by.collation,
+                };
+                Ok(Some(SortKeysExtractor::new(&[expr]).sort_keys(self.batch)?))
+            }
+            _ => Ok(None),
+        }
+    }
+
+    /// Compute the first row in the frame for a row.
+    fn frame_start(
+        &self,
+        unit: WindowFrameUnit,
+        bound: &PhysicalWindowFrameBound,
+        row: usize,
+    ) -> Result<usize> {
+        let partition = &self.partitions[row];
+        let peers = &self.peers[row];
+
+        Ok(match (unit, bound) {
+            (_, PhysicalWindowFrameBound::UnboundedPreceding) => partition.start,
+            (_, PhysicalWindowFrameBound::UnboundedFollowing) => partition.end,
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::CurrentRow) => row,
+            (_, PhysicalWindowFrameBound::CurrentRow) => peers.start,
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::Preceding(n)) => {
+                usize::max(row.saturating_sub(*n), partition.start)
+            }
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::Following(n)) => {
+                usize::min(row.saturating_add(*n), partition.end)
+            }
+            (WindowFrameUnit::Groups, PhysicalWindowFrameBound::Preceding(n)) => {
+                let first_group = self.groups.row_groups[partition.start];
+                let group = self.groups.row_groups[row].saturating_sub(*n);
+                self.groups.group_starts[usize::max(group, first_group)]
+            }
+            (WindowFrameUnit::Groups, PhysicalWindowFrameBound::Following(n)) => {
+                let last_group = self.groups.row_groups[partition.end - 1];
+                let group = self.groups.row_groups[row].saturating_add(*n);
+                if group > last_group {
+                    partition.end
+                } else {
+                    self.groups.group_starts[group]
+                }
+            }
+            (unit, bound) => {
+                return Err(RayexecError::new(format!(
+                    "Unexpected frame start bound for {unit}: {bound:?}"
+                )))
+            }
+        })
+    }
+
+    /// Compute the end (exclusive) of the frame for a row.
+    fn frame_end(
+        &self,
+        unit: WindowFrameUnit,
+        bound: &PhysicalWindowFrameBound,
+        row: usize,
+    ) -> Result<usize> {
+        let partition = &self.partitions[row];
+        let peers = &self.peers[row];
+
+        Ok(match (unit, bound) {
+            (_, PhysicalWindowFrameBound::UnboundedPreceding) => partition.start,
+            (_, PhysicalWindowFrameBound::UnboundedFollowing) => partition.end,
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::CurrentRow) => row + 1,
+            (_, PhysicalWindowFrameBound::CurrentRow) => peers.end,
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::Preceding(n)) => {
+                usize::max((row + 1).saturating_sub(*n), partition.start)
+            }
+            (WindowFrameUnit::Rows, PhysicalWindowFrameBound::Following(n)) => {
+                usize::min(row.saturating_add(*n).saturating_add(1), partition.end)
+            }
+            (WindowFrameUnit::Groups, PhysicalWindowFrameBound::Preceding(n)) => {
+                let first_group = self.groups.row_groups[partition.start];
+                let group = self.groups.row_groups[row];
+                if group < first_group.saturating_add(*n) {
+                    partition.start
+                } else {
+                    self.groups.group_starts[group - n + 1]
+                }
+            }
+            (WindowFrameUnit::Groups, PhysicalWindowFrameBound::Following(n)) => {
+                let last_group = self.groups.row_groups[partition.end - 1];
+                let group = usize::min(self.groups.row_groups[row].saturating_add(*n), last_group);
+                self.groups.group_starts[group + 1]
+            }
+            (unit, bound) => {
+                return Err(RayexecError::new(format!(
+                    "Unexpected frame end bound for {unit}: {bound:?}"
+                )))
+            }
+        })
+    }
+}
+
+/// Returns the first index in `range` where `pred` returns false.
+///
+/// Assumes `pred` returns true for some prefix of the range, and false for the
+/// rest.
+fn partition_point(range: Range<usize>, pred: impl Fn(usize) -> bool) -> usize {
+    let mut low = range.start;
+    let mut high = range.end;
+    while low < high {
+        let mid = low + (high - low) / 2;
+        if pred(mid) {
+            low = mid + 1;
+        } else {
+            high = mid;
+        }
+    }
+    low
+}
+
+/// Compute an aggregate over each row's frame.
+///
+/// Rows with identical frames (e.g. peers) share a single aggregate state.
+fn evaluate_aggregate(
+    function: &dyn AggregateFunctionImpl,
+    inputs: &[&Array],
+    bounds: &[WindowRowBounds],
+) -> Result<Array> {
+    // Deduplicate consecutive frames.
+    let mut frames: Vec<Vec<Range<usize>>> = Vec::new();
+    let mut row_frames = Vec::with_capacity(bounds.len());
+    for bounds in bounds {
+        let ranges: Vec<_> = bounds.frame_ranges().collect();
+        if frames.last() != Some(&ranges) {
+            frames.push(ranges);
+        }
+        row_frames.push((0, frames.len() - 1));
+    }
+
+    let num_rows = bounds.len();
+    let tree = SegmentTree::try_new(function, inputs, num_rows)?;
+    let results = tree.aggregate(&frames)?;
+
+    interleave(&[&results], &row_frames)
+}
+
 impl Explainable for PhysicalWindow {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("Window")
+        let functions: Vec<_> = self
+            .functions
+            .iter()
+            .map(|f| f.function.name().to_string())
+            .collect();
+
+        ExplainEntry::new("Window").with_values("functions", functions)
     }
 }
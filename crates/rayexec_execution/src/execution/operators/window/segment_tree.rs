@@ -0,0 +1,344 @@
+use std::ops::Range;
+
+use rayexec_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::executor::aggregate::RowToStateMapping;
+use crate::arrays::executor::scalar::{concat, interleave};
+use crate::execution::operators::hash_aggregate::hash_table::GroupAddress;
+use crate::functions::aggregate::states::AggregateGroupStates;
+use crate::functions::aggregate::{AggregateFunctionImpl, ChunkGroupAddressIter};
+
+/// Number of children for each node in the tree.
+const FANOUT: usize = 16;
+
+/// Max number of aggregate states we'll update at once.
+///
+/// Limited by the size of the row index in `GroupAddress`.
+const MAX_AGGREGATE_STATES: usize = u16::MAX as usize;
+
+/// Tree of partial aggregate states used to compute aggregates over window
+/// frames.
+///
+/// The leaf level of the tree is the input rows themselves. Each node in the
+/// next level up holds the combined state for `FANOUT` nodes in the level
+/// below. Computing the aggregate for a frame combines the fewest nodes
+/// covering the frame, which keeps sliding frames from having to visit every
+/// row in the frame.
+///
+/// Nodes are always combined in row order to preserve the semantics of order
+/// sensitive aggregates (e.g. `string_agg`).
+#[derive(Debug)]
+pub struct SegmentTree<'a> {
+    function: &'a dyn AggregateFunctionImpl,
+    inputs: &'a [&'a Array],
+    /// Levels above the leaf level.
+    ///
+    /// `levels[0]` contains the states for each group of `FANOUT` rows. Only
+    /// full groups get a node.
+    levels: Vec<Box<dyn AggregateGroupStates>>,
+}
+
+impl<'a> SegmentTree<'a> {
+    pub fn try_new(
+        function: &'a dyn AggregateFunctionImpl,
+        inputs: &'a [&'a Array],
+        num_rows: usize,
+    ) -> Result<Self> {
+        let mut levels: Vec<Box<dyn AggregateGroupStates>> = Vec::new();
+
+        // Build the first level from the input rows.
+        let num_nodes = num_rows / FANOUT;
+        if num_nodes > 0 {
+            let mut level = function.new_states();
+            level.new_states(num_nodes);
+
+            let mut node_offset = 0;
+            while node_offset < num_nodes {
+                let count = usize::min(MAX_AGGREGATE_STATES, num_nodes - node_offset);
+
+                let mut states = function.new_states();
+                states.new_states(count);
+
+                let sliced: Vec<_> = inputs
+                    .iter()
+                    .map(|input| input.slice(node_offset * FANOUT, count * FANOUT))
+                    .collect();
+                let sliced: Vec<_> = sliced.iter().collect();
+
+                let addresses: Vec<_> = (0..(count * FANOUT))
+                    .map(|idx| GroupAddress {
+                        chunk_idx: 0,
+                        row_idx: (idx / FANOUT) as u16,
+                    })
+                    .collect();
+                states.update_states(&sliced, ChunkGroupAddressIter::new(0, &addresses))?;
+
+                let mapping: Vec<_> = (0..count)
+                    .map(|idx| RowToStateMapping {
+                        from_row: idx,
+                        to_state: node_offset + idx,
+                    })
+                    .collect();
+                level.combine_from(states.as_ref(), &mapping)?;
+
+                node_offset += count;
+            }
+
+            levels.push(level);
+        }
+
+        // Build the remaining levels from the level below.
+        while let Some(prev) = levels.last() {
+            let num_nodes = prev.num_states() / FANOUT;
+            if num_nodes == 0 {
+                break;
+            }
+
+            let mut level = function.new_states();
+            level.new_states(num_nodes);
+
+            let mapping: Vec<_> = (0..(num_nodes * FANOUT))
+                .map(|idx| RowToStateMapping {
+                    from_row: idx,
+                    to_state: idx / FANOUT,
+                })
+                .collect();
+            level.combine_from(prev.as_ref(), &mapping)?;
+
+            levels.push(level);
+        }
+
+        Ok(SegmentTree {
+            function,
+            inputs,
+            levels,
+        })
+    }
+
+    /// Compute the aggregate for each frame.
+    ///
+    /// Each frame is made up of one or more disjoint row ranges in ascending
+    /// order. Returns an array with one value per frame.
+    pub fn aggregate(&self, frames: &[Vec<Range<usize>>]) -> Result<Array> {
+        let mut results = Vec::new();
+
+        for frames in frames.chunks(MAX_AGGREGATE_STATES) {
+            results.push(self.aggregate_block(frames)?);
+        }
+
+        if results.is_empty() {
+            let mut states = self.function.new_states();
+            return states.finalize();
+        }
+
+        let results: Vec<_> = results.iter().collect();
+        concat(&results)
+    }
+
+    /// Compute aggregates for a block of frames that fit within a single set
+    /// of states.
+    fn aggregate_block(&self, frames: &[Vec<Range<usize>>]) -> Result<Array> {
+        let mut states = self.function.new_states();
+        states.new_states(frames.len());
+
+        // Pieces for each frame are grouped into phases such that executing
+        // the phases in order combines each frame's pieces in row order.
+        //
+        // Within a single row range, the pieces on the left edge come first
+        // ordered by ascending level, followed by pieces on the right edge
+        // ordered by descending level.
+        let num_levels = self.levels.len() + 1;
+        let phases_per_range = num_levels * 2;
+        let max_ranges = frames.iter().map(|ranges| ranges.len()).max().unwrap_or(0);
+
+        let mut phases: Vec<Vec<(usize, usize, Range<usize>)>> =
+            vec![Vec::new(); phases_per_range * max_ranges];
+
+        let mut left = Vec::new();
+        let mut right = Vec::new();
+
+        for (state_idx, ranges) in frames.iter().enumerate() {
+            for (range_idx, range) in ranges.iter().enumerate() {
+                self.split_range(range.clone(), &mut left, &mut right);
+
+                let phase_offset = range_idx * phases_per_range;
+                for (level, nodes) in left.drain(..) {
+                    phases[phase_offset + level].push((state_idx, level, nodes));
+                }
+                for (level, nodes) in right.drain(..) {
+                    phases[phase_offset + phases_per_range - 1 - level]
+                        .push((state_idx, level, nodes));
+                }
+            }
+        }
+
+        let mut rows = Vec::new();
+        let mut addresses = Vec::new();
+        let mut mapping = Vec::new();
+
+        for phase in phases {
+            // All pieces in a phase are at the same level.
+            let level = match phase.first() {
+                Some((_, level, _)) => *level,
+                None => continue,
+            };
+
+            if level == 0 {
+                // Update directly from the input rows.
+                rows.clear();
+                addresses.clear();
+                for (state_idx, _, nodes) in phase {
+                    for row in nodes {
+                        rows.push((0, row));
+                        addresses.push(GroupAddress {
+                            chunk_idx: 0,
+                            row_idx: state_idx as u16,
+                        });
+                    }
+                }
+
+                let gathered = self
+                    .inputs
+                    .iter()
+                    .map(|input| interleave(&[input], &rows))
+                    .collect::<Result<Vec<_>>>()?;
+                let gathered: Vec<_> = gathered.iter().collect();
+
+                states.update_states(&gathered, ChunkGroupAddressIter::new(0, &addresses))?;
+            } else {
+                // Combine the precomputed states for the nodes.
+                mapping.clear();
+                for (state_idx, _, nodes) in phase {
+                    mapping.extend(nodes.map(|node| RowToStateMapping {
+                        from_row: node,
+                        to_state: state_idx,
+                    }));
+                }
+
+                states.combine_from(self.levels[level - 1].as_ref(), &mapping)?;
+            }
+        }
+
+        states.finalize()
+    }
+
+    /// Split a range of rows into ranges of nodes covering those rows.
+    ///
+    /// Pieces on the left edge of the range are pushed to `left` in ascending
+    /// level, with the topmost piece being the last piece in `left`. Pieces on
+    /// the right edge are pushed to `right`, also in ascending level.
+    fn split_range(
+        &self,
+        range: Range<usize>,
+        left: &mut Vec<(usize, Range<usize>)>,
+        right: &mut Vec<(usize, Range<usize>)>,
+    ) {
+        let mut begin = range.start;
+        let mut end = range.end;
+        let mut level = 0;
+
+        while begin < end {
+            let parent_begin = begin.div_ceil(FANOUT);
+            let parent_end = end / FANOUT;
+
+            if parent_begin >= parent_end {
+                // No complete parent nodes in this range.
+                left.push((level, begin..end));
+                return;
+            }
+
+            if begin < parent_begin * FANOUT {
+                left.push((level, begin..(parent_begin * FANOUT)));
+            }
+            if parent_end * FANOUT < end {
+                right.push((level, (parent_end * FANOUT)..end));
+            }
+
+            begin = parent_begin;
+            end = parent_end;
+            level += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::arrays::datatype::DataType;
+    use crate::arrays::scalar::ScalarValue;
+    use crate::expr;
+    use crate::functions::aggregate::builtin::string_agg::StringAgg;
+    use crate::functions::aggregate::builtin::sum::Sum;
+    use crate::functions::aggregate::AggregateFunction;
+    use crate::logical::binder::table_list::TableList;
+
+    #[test]
+    fn sum_sliding_frames() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(None, vec![DataType::Int64], vec!["a".to_string()])
+            .unwrap();
+        let planned = Sum
+            .plan(&table_list, vec![expr::col_ref(table_ref, 0)])
+            .unwrap();
+
+        let num_rows = 1000;
+        let input = Array::from_iter(0..(num_rows as i64));
+        let inputs = [&input];
+
+        let tree = SegmentTree::try_new(planned.function_impl.as_ref(), &inputs, num_rows).unwrap();
+
+        let frames: Vec<_> = (0..num_rows)
+            .map(|row| {
+                let frame = row.saturating_sub(300)..usize::min(row + 301, num_rows);
+                vec![frame]
+            })
+            .collect();
+        let out = tree.aggregate(&frames).unwrap();
+
+        for (row, frame) in frames.iter().enumerate() {
+            let expected: i64 = frame[0].clone().map(|v| v as i64).sum();
+            assert_eq!(
+                ScalarValue::Int64(expected),
+                out.logical_value(row).unwrap(),
+                "row: {row}"
+            );
+        }
+    }
+
+    #[test]
+    fn string_agg_preserves_order() {
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Utf8, DataType::Utf8],
+                vec!["a".to_string(), "sep".to_string()],
+            )
+            .unwrap();
+        let planned = StringAgg
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::lit("")],
+            )
+            .unwrap();
+
+        let values: Vec<_> = (0..100).map(|v| (v % 10).to_string()).collect();
+        let input = Array::from_iter(values.iter().map(|v| v.as_str()));
+        let inputs = [&input];
+
+        let tree = SegmentTree::try_new(planned.function_impl.as_ref(), &inputs, 100).unwrap();
+
+        // Frame with a hole in the middle.
+        let frames = vec![vec![3..40, 45..97]];
+        let out = tree.aggregate(&frames).unwrap();
+
+        let expected: String = values[3..40]
+            .iter()
+            .chain(&values[45..97])
+            .map(|v| v.as_str())
+            .collect();
+        assert_eq!(ScalarValue::from(expected), out.logical_value(0).unwrap());
+    }
+}
@@ -3,6 +3,7 @@ use serde::{Deserialize, Serialize};
 use tracing::error;
 
 use super::explainable::{ExplainConfig, ExplainEntry};
+use crate::execution::executable::profiler::ExecutionProfileData;
 use crate::execution::intermediate::pipeline::{
     IntermediatePipeline,
     IntermediatePipelineGroup,
@@ -60,46 +61,63 @@ impl<'a> ExplainFormatter<'a> {
     }
 
     fn format(&self, node: &ExplainNode) -> Result<String> {
-        match self.format {
-            ExplainFormat::Text => {
-                fn fmt(node: &ExplainNode, indent: usize, buf: &mut String) -> Result<()> {
-                    use std::fmt::Write as _;
-
-                    writeln!(buf, "{}{}", " ".repeat(indent), node.entry.name)?;
-
-                    for (idx, (item_name, item)) in node.entry.items.iter().enumerate() {
-                        let border = if idx == node.entry.items.len() - 1 {
-                            DEFAULT_EXPLAIN_CHARS.item_left_border_last
-                        } else {
-                            DEFAULT_EXPLAIN_CHARS.item_left_border
-                        };
-
-                        writeln!(
-                            buf,
-                            "{}  {} {}: {}",
-                            " ".repeat(indent),
-                            border,
-                            item_name,
-                            item
-                        )?;
-                    }
+        format_node(node, self.format)
+    }
+}
 
-                    for child in &node.children {
-                        fmt(child, indent + 2, buf)?;
-                    }
+/// Format profile data collected during execution of a query.
+///
+/// Used for EXPLAIN ANALYZE output. Each pipeline is rendered with its
+/// operators annotated with the profile data combined across all partitions.
+///
+/// If `timing` is false, elapsed and polling times are omitted.
+pub fn format_execution_profile(
+    profile: &ExecutionProfileData,
+    format: ExplainFormat,
+    timing: bool,
+) -> Result<String> {
+    let node = ExplainNode::from_execution_profile(profile, timing);
+    format_node(&node, format)
+}
 
-                    Ok(())
+fn format_node(node: &ExplainNode, format: ExplainFormat) -> Result<String> {
+    match format {
+        ExplainFormat::Text => {
+            fn fmt(node: &ExplainNode, indent: usize, buf: &mut String) -> Result<()> {
+                use std::fmt::Write as _;
+
+                writeln!(buf, "{}{}", " ".repeat(indent), node.entry.name)?;
+
+                for (idx, (item_name, item)) in node.entry.items.iter().enumerate() {
+                    let border = if idx == node.entry.items.len() - 1 {
+                        DEFAULT_EXPLAIN_CHARS.item_left_border_last
+                    } else {
+                        DEFAULT_EXPLAIN_CHARS.item_left_border
+                    };
+
+                    writeln!(
+                        buf,
+                        "{}  {} {}: {}",
+                        " ".repeat(indent),
+                        border,
+                        item_name,
+                        item
+                    )?;
                 }
 
-                let mut buf = String::new();
-                fmt(node, 0, &mut buf)?;
+                for child in &node.children {
+                    fmt(child, indent + 2, buf)?;
+                }
 
-                Ok(buf)
-            }
-            ExplainFormat::Json => {
-                serde_json::to_string(&node).context("failed to serialize to json")
+                Ok(())
             }
+
+            let mut buf = String::new();
+            fmt(node, 0, &mut buf)?;
+
+            Ok(buf)
         }
+        ExplainFormat::Json => serde_json::to_string(&node).context("failed to serialize to json"),
     }
 }
 
@@ -110,6 +128,45 @@ struct ExplainNode {
 }
 
 impl ExplainNode {
+    fn from_execution_profile(profile: &ExecutionProfileData, timing: bool) -> ExplainNode {
+        let entry = ExplainEntry::new("ExecutionProfile");
+
+        let children = profile
+            .pipelines
+            .iter()
+            .map(|(id, pipeline)| {
+                let entry = ExplainEntry::new(format!("Pipeline {}", id.0))
+                    .with_value("partitions", pipeline.partitions.len());
+
+                let children = pipeline
+                    .combined_operators()
+                    .into_iter()
+                    .map(|(entry, data)| {
+                        let mut entry = entry
+                            .with_value("rows_read", data.rows_read)
+                            .with_value("rows_emitted", data.rows_emitted)
+                            .with_value("batches_read", data.batches_read)
+                            .with_value("batches_emitted", data.batches_emitted)
+                            .with_value("peak_memory", data.peak_memory);
+                        if timing {
+                            entry = entry
+                                .with_value("elapsed", format!("{:?}", data.elapsed))
+                                .with_value("poll_time", format!("{:?}", data.poll_time));
+                        }
+                        ExplainNode {
+                            entry,
+                            children: Vec::new(),
+                        }
+                    })
+                    .collect();
+
+                ExplainNode { entry, children }
+            })
+            .collect();
+
+        ExplainNode { entry, children }
+    }
+
     fn from_intermediate_groups(
         bind_context: &BindContext,
         groups: &[(&str, &IntermediatePipelineGroup)],
@@ -243,6 +300,9 @@ impl ExplainNode {
             LogicalOperator::DetachDatabase(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Drop(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Insert(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Update(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Delete(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::Merge(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateSchema(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateTable(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::CreateView(n) => (n.explain_entry(config), &n.children),
@@ -256,6 +316,8 @@ impl ExplainNode {
             LogicalOperator::Unnest(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::Window(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::InOut(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::RecursiveCte(n) => (n.explain_entry(config), &n.children),
+            LogicalOperator::RecursiveCteScan(n) => (n.explain_entry(config), &n.children),
             LogicalOperator::MaterializationScan(n) => {
                 // Materialization special case, walk children by get
                 // materialization from bind context.
@@ -295,3 +357,151 @@ impl ExplainNode {
         ExplainNode { entry, children }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::BTreeMap;
+    use std::time::Duration;
+
+    use super::*;
+    use crate::execution::executable::pipeline::PipelineId;
+    use crate::execution::executable::profiler::{
+        OperatorProfileData,
+        PartitionPipelineProfileData,
+        PipelineProfileData,
+    };
+
+    /// Profile data for a single pipeline with two partitions, each running a
+    /// scan followed by a filter.
+    fn test_profile() -> ExecutionProfileData {
+        let partition = |rows: usize| PartitionPipelineProfileData {
+            operators: vec![
+                OperatorProfileData {
+                    rows_read: 0,
+                    rows_emitted: rows,
+                    batches_read: 0,
+                    batches_emitted: 1,
+                    elapsed: Duration::from_millis(rows as u64),
+                    poll_time: Duration::from_millis(1),
+                    peak_memory: 0,
+                },
+                OperatorProfileData {
+                    rows_read: rows,
+                    rows_emitted: rows / 2,
+                    batches_read: 1,
+                    batches_emitted: 1,
+                    elapsed: Duration::from_millis(2),
+                    poll_time: Duration::from_millis(2),
+                    peak_memory: 64,
+                },
+            ],
+            explain_entries: vec![ExplainEntry::new("Scan"), ExplainEntry::new("Filter")],
+        };
+
+        let pipeline = PipelineProfileData {
+            partitions: [(0, partition(10)), (1, partition(20))]
+                .into_iter()
+                .collect(),
+        };
+
+        ExecutionProfileData {
+            pipelines: [(PipelineId(0), pipeline)].into_iter().collect(),
+        }
+    }
+
+    #[test]
+    fn execution_profile_text() {
+        let out = format_execution_profile(&test_profile(), ExplainFormat::Text, true).unwrap();
+
+        let expected = [
+            "ExecutionProfile",
+            "  Pipeline 0",
+            "    └ partitions: 2",
+            "    Scan",
+            "      ├ batches_emitted: 2",
+            "      ├ batches_read: 0",
+            "      ├ elapsed: 20ms",
+            "      ├ peak_memory: 0",
+            "      ├ poll_time: 2ms",
+            "      ├ rows_emitted: 30",
+            "      └ rows_read: 0",
+            "    Filter",
+            "      ├ batches_emitted: 2",
+            "      ├ batches_read: 2",
+            "      ├ elapsed: 2ms",
+            "      ├ peak_memory: 128",
+            "      ├ poll_time: 4ms",
+            "      ├ rows_emitted: 15",
+            "      └ rows_read: 30",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn execution_profile_text_without_timing() {
+        let out = format_execution_profile(&test_profile(), ExplainFormat::Text, false).unwrap();
+
+        let expected = [
+            "ExecutionProfile",
+            "  Pipeline 0",
+            "    └ partitions: 2",
+            "    Scan",
+            "      ├ batches_emitted: 2",
+            "      ├ batches_read: 0",
+            "      ├ peak_memory: 0",
+            "      ├ rows_emitted: 30",
+            "      └ rows_read: 0",
+            "    Filter",
+            "      ├ batches_emitted: 2",
+            "      ├ batches_read: 2",
+            "      ├ peak_memory: 128",
+            "      ├ rows_emitted: 15",
+            "      └ rows_read: 30",
+            "",
+        ]
+        .join("\n");
+
+        assert_eq!(expected, out);
+    }
+
+    #[test]
+    fn execution_profile_json() {
+        let out = format_execution_profile(&test_profile(), ExplainFormat::Json, true).unwrap();
+        let node: ExplainNode = serde_json::from_str(&out).unwrap();
+
+        assert_eq!("ExecutionProfile", node.entry.name);
+        assert_eq!(1, node.children.len());
+
+        let pipeline = &node.children[0];
+        assert_eq!("Pipeline 0", pipeline.entry.name);
+
+        let operators: Vec<_> = pipeline
+            .children
+            .iter()
+            .map(|child| {
+                let items: BTreeMap<_, _> = child
+                    .entry
+                    .items
+                    .iter()
+                    .map(|(k, v)| (k.as_str(), v.to_string()))
+                    .collect();
+                (
+                    child.entry.name.as_str(),
+                    items["rows_read"].clone(),
+                    items["rows_emitted"].clone(),
+                )
+            })
+            .collect();
+
+        assert_eq!(
+            vec![
+                ("Scan", "0".to_string(), "30".to_string()),
+                ("Filter", "30".to_string(), "15".to_string()),
+            ],
+            operators
+        );
+    }
+}
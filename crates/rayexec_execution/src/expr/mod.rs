@@ -10,6 +10,7 @@ pub mod grouping_set_expr;
 pub mod is_expr;
 pub mod literal_expr;
 pub mod negate_expr;
+pub mod parameter_expr;
 pub mod scalar_function_expr;
 pub mod subquery_expr;
 pub mod unnest_expr;
@@ -32,6 +33,7 @@ use grouping_set_expr::GroupingSetExpr;
 use is_expr::IsExpr;
 use literal_expr::LiteralExpr;
 use negate_expr::NegateExpr;
+use parameter_expr::ParameterExpr;
 use rayexec_error::{RayexecError, Result};
 use scalar_function_expr::ScalarFunctionExpr;
 use subquery_expr::SubqueryExpr;
@@ -57,6 +59,7 @@ pub enum Expression {
     Is(IsExpr),
     Literal(LiteralExpr),
     Negate(NegateExpr),
+    Parameter(ParameterExpr),
     ScalarFunction(ScalarFunctionExpr),
     Subquery(SubqueryExpr),
     Window(WindowExpr),
@@ -82,9 +85,11 @@ impl Expression {
             Self::Is(_) => DataType::Boolean,
             Self::Literal(expr) => expr.literal.datatype(),
             Self::Negate(expr) => expr.datatype(table_list)?,
+            // Type isn't known until the parameter is bound to a value.
+            Self::Parameter(_) => DataType::Null,
             Self::ScalarFunction(expr) => expr.function.return_type.clone(),
             Self::Subquery(expr) => expr.return_type.clone(),
-            Self::Window(window) => window.agg.return_type.clone(),
+            Self::Window(window) => window.function.return_type().clone(),
             Self::Unnest(expr) => expr.datatype(table_list)?,
             Self::GroupingSet(expr) => expr.datatype(),
         })
@@ -137,6 +142,7 @@ impl Expression {
             Self::Is(is) => func(&mut is.input)?,
             Self::Literal(_) => (),
             Self::Negate(negate) => func(&mut negate.expr)?,
+            Self::Parameter(_) => (),
             Self::ScalarFunction(scalar) => {
                 for input in &mut scalar.function.inputs {
                     func(input)?;
@@ -144,7 +150,7 @@ impl Expression {
             }
             Self::Subquery(_) => (),
             Self::Window(window) => {
-                for input in &mut window.agg.inputs {
+                for input in window.function.inputs_mut() {
                     func(input)?;
                 }
                 for partition in &mut window.partition_by {
@@ -153,6 +159,12 @@ impl Expression {
                 for order_by in &mut window.order_by {
                     func(&mut order_by.expr)?;
                 }
+                if let Some(offset) = window.start.offset_expr_mut() {
+                    func(offset)?;
+                }
+                if let Some(offset) = window.end.offset_expr_mut() {
+                    func(offset)?;
+                }
             }
             Self::Unnest(unnest) => func(&mut unnest.expr)?,
             Self::GroupingSet(grouping) => {
@@ -211,6 +223,7 @@ impl Expression {
             Self::Is(is) => func(&is.input)?,
             Self::Literal(_) => (),
             Self::Negate(negate) => func(&negate.expr)?,
+            Self::Parameter(_) => (),
             Self::ScalarFunction(scalar) => {
                 for input in &scalar.function.inputs {
                     func(input)?;
@@ -218,7 +231,7 @@ impl Expression {
             }
             Self::Subquery(_) => (),
             Self::Window(window) => {
-                for input in &window.agg.inputs {
+                for input in window.function.inputs() {
                     func(input)?;
                 }
                 for partition in &window.partition_by {
@@ -227,6 +240,12 @@ impl Expression {
                 for order_by in &window.order_by {
                     func(&order_by.expr)?;
                 }
+                if let Some(offset) = window.start.offset_expr() {
+                    func(offset)?;
+                }
+                if let Some(offset) = window.end.offset_expr() {
+                    func(offset)?;
+                }
             }
             Self::Unnest(unnest) => func(&unnest.expr)?,
             Self::GroupingSet(grouping) => {
@@ -350,6 +369,7 @@ impl Expression {
             Self::Aggregate(_) => false,
             Self::Window(_) => false,
             Self::Subquery(_) => false, // Subquery shouldn't be in the plan anyways once this gets called.
+            Self::Parameter(_) => false,
             Self::ScalarFunction(f)
                 if f.function.function.volatility() == FunctionVolatility::Volatile =>
             {
@@ -574,6 +594,7 @@ impl ContextDisplay for Expression {
             Self::Is(expr) => expr.fmt_using_context(mode, f),
             Self::Literal(expr) => expr.fmt_using_context(mode, f),
             Self::Negate(expr) => expr.fmt_using_context(mode, f),
+            Self::Parameter(expr) => expr.fmt_using_context(mode, f),
             Self::ScalarFunction(expr) => expr.fmt_using_context(mode, f),
             Self::Subquery(expr) => expr.fmt_using_context(mode, f),
             Self::Window(expr) => expr.fmt_using_context(mode, f),
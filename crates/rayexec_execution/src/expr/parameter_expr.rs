@@ -0,0 +1,23 @@
+use std::fmt;
+
+use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
+
+/// A positional parameter (`$1`, `?`) whose value isn't known yet.
+///
+/// Only produced when binding a prepared statement to infer the types of its
+/// parameters. Statements are bound with the provided values when executed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct ParameterExpr {
+    /// 1-based parameter number.
+    pub number: usize,
+}
+
+impl ContextDisplay for ParameterExpr {
+    fn fmt_using_context(
+        &self,
+        _mode: ContextDisplayMode,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        write!(f, "${}", self.number)
+    }
+}
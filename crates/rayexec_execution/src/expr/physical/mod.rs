@@ -18,9 +18,11 @@ use scalar_function_expr::PhysicalScalarFunctionExpr;
 
 use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
+use crate::arrays::compute::collation::Collation;
 use crate::arrays::executor::scalar::SelectExecutor;
 use crate::arrays::selection::SelectionVector;
 use crate::database::DatabaseContext;
+use crate::expr::window_expr::{WindowFrameExclusion, WindowFrameUnit, WindowFunctionKind};
 use crate::functions::aggregate::PlannedAggregateFunction;
 use crate::proto::DatabaseProtoConv;
 
@@ -157,6 +159,39 @@ impl DatabaseProtoConv for PhysicalAggregateExpression {
     }
 }
 
+#[derive(Debug)]
+pub struct PhysicalWindowExpression {
+    /// The function to compute over the window.
+    pub function: WindowFunctionKind,
+    /// Column expressions for the function inputs.
+    pub columns: Vec<PhysicalColumnExpr>,
+    /// Unit for the frame bounds.
+    pub unit: WindowFrameUnit,
+    /// Start bound for the window frame.
+    pub start: PhysicalWindowFrameBound,
+    /// End bound for the window frame.
+    pub end: PhysicalWindowFrameBound,
+    /// Rows to exclude from the window frame.
+    pub exclude: WindowFrameExclusion,
+}
+
+#[derive(Debug, Clone)]
+pub enum PhysicalWindowFrameBound {
+    UnboundedPreceding,
+    /// Number of rows or peer groups preceding the current row.
+    Preceding(usize),
+    UnboundedFollowing,
+    /// Number of rows or peer groups following the current row.
+    Following(usize),
+    CurrentRow,
+    /// RANGE offset bound with precomputed boundary values.
+    ///
+    /// The column contains the boundary value for each row, with the frame
+    /// starting at (or ending after) the rows whose ORDER BY value is equal to
+    /// the boundary value.
+    RangeOffset(PhysicalColumnExpr),
+}
+
 #[derive(Debug, Clone)]
 pub struct PhysicalSortExpression {
     /// Column this expression is for.
@@ -165,6 +200,8 @@ pub struct PhysicalSortExpression {
     pub desc: bool,
     /// If nulls should be ordered first.
     pub nulls_first: bool,
+    /// Collation to use when ordering string values.
+    pub collation: Collation,
 }
 
 impl DatabaseProtoConv for PhysicalSortExpression {
@@ -175,6 +212,7 @@ impl DatabaseProtoConv for PhysicalSortExpression {
             column: Some(self.column.to_proto_ctx(context)?),
             desc: self.desc,
             nulls_first: self.nulls_first,
+            collation: self.collation.to_string(),
         })
     }
 
@@ -183,6 +221,7 @@ impl DatabaseProtoConv for PhysicalSortExpression {
             column: DatabaseProtoConv::from_proto_ctx(proto.column.required("column")?, context)?,
             desc: proto.desc,
             nulls_first: proto.nulls_first,
+            collation: Collation::from_name(&proto.collation)?,
         })
     }
 }
@@ -243,6 +243,7 @@ impl<'a> PhysicalExpressionPlanner<'a> {
                 column,
                 desc: expr.desc,
                 nulls_first: expr.nulls_first,
+                collation: expr.collation,
             }),
             other => Err(RayexecError::new(format!(
                 "Expected column expression for sort expression, got: {other}"
@@ -7,6 +7,7 @@ use super::Expression;
 use crate::arrays::datatype::DataType;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode, ContextDisplayWrapper};
 use crate::functions::aggregate::PlannedAggregateFunction;
+use crate::functions::window::PlannedWindowFunction;
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
 
@@ -31,12 +32,19 @@ pub enum WindowFrameExclusion {
 }
 
 /// The window frame bound.
+///
+/// For ROWS and GROUPS, the offset for PRECEDING and FOLLOWING is a constant
+/// non-negative Int64 literal.
+///
+/// For RANGE, the expression computes the boundary value for each row by
+/// adding or subtracting the offset from the ORDER BY expression. The
+/// expression returns the same type as the ORDER BY expression.
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum WindowFrameBound {
     UnboundedPreceding(WindowFrameUnit),
-    Preceding(WindowFrameUnit, i64),
+    Preceding(WindowFrameUnit, Box<Expression>),
     UnboundedFollowing(WindowFrameUnit),
-    Following(WindowFrameUnit, i64),
+    Following(WindowFrameUnit, Box<Expression>),
     CurrentRow(WindowFrameUnit),
 }
 
@@ -57,13 +65,121 @@ impl WindowFrameBound {
     pub const fn default_end() -> Self {
         WindowFrameBound::CurrentRow(WindowFrameUnit::Range)
     }
+
+    pub fn unit(&self) -> WindowFrameUnit {
+        match self {
+            Self::UnboundedPreceding(unit)
+            | Self::Preceding(unit, _)
+            | Self::UnboundedFollowing(unit)
+            | Self::Following(unit, _)
+            | Self::CurrentRow(unit) => *unit,
+        }
+    }
+
+    /// Get the offset expression for this bound if it has one.
+    pub fn offset_expr(&self) -> Option<&Expression> {
+        match self {
+            Self::Preceding(_, expr) | Self::Following(_, expr) => Some(expr),
+            _ => None,
+        }
+    }
+
+    pub fn offset_expr_mut(&mut self) -> Option<&mut Expression> {
+        match self {
+            Self::Preceding(_, expr) | Self::Following(_, expr) => Some(expr),
+            _ => None,
+        }
+    }
+}
+
+impl fmt::Display for WindowFrameUnit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Rows => write!(f, "ROWS"),
+            Self::Range => write!(f, "RANGE"),
+            Self::Groups => write!(f, "GROUPS"),
+        }
+    }
+}
+
+impl fmt::Display for WindowFrameExclusion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ExcludeCurrentRow => write!(f, "EXCLUDE CURRENT ROW"),
+            Self::ExcludeGroup => write!(f, "EXCLUDE GROUP"),
+            Self::ExcludeTies => write!(f, "EXCLUDE TIES"),
+            Self::ExcludeNoOthers => write!(f, "EXCLUDE NO OTHERS"),
+        }
+    }
+}
+
+impl ContextDisplay for WindowFrameBound {
+    fn fmt_using_context(
+        &self,
+        mode: ContextDisplayMode,
+        f: &mut fmt::Formatter<'_>,
+    ) -> fmt::Result {
+        match self {
+            Self::UnboundedPreceding(_) => write!(f, "UNBOUNDED PRECEDING"),
+            Self::Preceding(_, expr) => write!(
+                f,
+                "{} PRECEDING",
+                ContextDisplayWrapper::with_mode(expr.as_ref(), mode)
+            ),
+            Self::UnboundedFollowing(_) => write!(f, "UNBOUNDED FOLLOWING"),
+            Self::Following(_, expr) => write!(
+                f,
+                "{} FOLLOWING",
+                ContextDisplayWrapper::with_mode(expr.as_ref(), mode)
+            ),
+            Self::CurrentRow(_) => write!(f, "CURRENT ROW"),
+        }
+    }
+}
+
+/// The function being computed over a window.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum WindowFunctionKind {
+    /// A normal aggregate used with an OVER clause.
+    Aggregate(PlannedAggregateFunction),
+    /// A window-only function (e.g. `row_number`).
+    Window(PlannedWindowFunction),
+}
+
+impl WindowFunctionKind {
+    pub fn name(&self) -> &str {
+        match self {
+            Self::Aggregate(agg) => agg.function.name(),
+            Self::Window(window) => window.function.name(),
+        }
+    }
+
+    pub fn return_type(&self) -> &DataType {
+        match self {
+            Self::Aggregate(agg) => &agg.return_type,
+            Self::Window(window) => &window.return_type,
+        }
+    }
+
+    pub fn inputs(&self) -> &[Expression] {
+        match self {
+            Self::Aggregate(agg) => &agg.inputs,
+            Self::Window(window) => &window.inputs,
+        }
+    }
+
+    pub fn inputs_mut(&mut self) -> &mut [Expression] {
+        match self {
+            Self::Aggregate(agg) => &mut agg.inputs,
+            Self::Window(window) => &mut window.inputs,
+        }
+    }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct WindowExpr {
-    /// The aggregate function.
-    // TODO: May need to adjust to allow for window-only functions.
-    pub agg: PlannedAggregateFunction,
+    /// The function to compute for each row.
+    pub function: WindowFunctionKind,
     /// How to partition the input to the function.
     pub partition_by: Vec<Expression>,
     /// How the input is ordered within a partition.
@@ -78,7 +194,7 @@ pub struct WindowExpr {
 
 impl WindowExpr {
     pub fn datatype(&self, _bind_context: &BindContext) -> Result<DataType> {
-        Ok(self.agg.return_type.clone())
+        Ok(self.function.return_type().clone())
     }
 }
 
@@ -88,10 +204,10 @@ impl ContextDisplay for WindowExpr {
         mode: ContextDisplayMode,
         f: &mut fmt::Formatter<'_>,
     ) -> fmt::Result {
-        write!(f, "{}", self.agg.function.name())?;
+        write!(f, "{}", self.function.name())?;
         let inputs = self
-            .agg
-            .inputs
+            .function
+            .inputs()
             .iter()
             .map(|expr| ContextDisplayWrapper::with_mode(expr, mode).to_string())
             .collect::<Vec<_>>()
@@ -101,7 +217,7 @@ impl ContextDisplay for WindowExpr {
         if !self.partition_by.is_empty() {
             write!(
                 f,
-                "PARTION BY {} ",
+                "PARTITION BY {} ",
                 self.partition_by
                     .iter()
                     .map(|expr| ContextDisplayWrapper::with_mode(expr, mode))
@@ -114,7 +230,21 @@ impl ContextDisplay for WindowExpr {
             write!(f, "ORDER BY {} ", self.order_by.display_as_list())?;
         }
 
-        // TODO: Bounds
+        let is_default_frame = self.start == WindowFrameBound::default_start()
+            && self.end == WindowFrameBound::default_end()
+            && self.exclude == WindowFrameExclusion::default();
+        if !is_default_frame {
+            write!(
+                f,
+                "{} BETWEEN {} AND {} ",
+                self.start.unit(),
+                ContextDisplayWrapper::with_mode(&self.start, mode),
+                ContextDisplayWrapper::with_mode(&self.end, mode),
+            )?;
+            if self.exclude != WindowFrameExclusion::ExcludeNoOthers {
+                write!(f, "{} ", self.exclude)?;
+            }
+        }
 
         write!(f, ")")?;
 
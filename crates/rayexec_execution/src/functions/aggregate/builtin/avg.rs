@@ -197,7 +197,7 @@ impl AggregateFunctionImpl for AvgInt64Impl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct AvgStateDecimal<I> {
     sum: i128,
     count: i64,
@@ -225,7 +225,7 @@ impl<I: Into<i128> + Default + Debug> AggregateState<I, (i128, i64)> for AvgStat
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 struct AvgStateF64<I, T> {
     sum: T,
     count: i64,
@@ -81,7 +81,7 @@ impl AggregateFunctionImpl for CorrImpl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CorrelationState {
     covar: CovarState<CovarPopFinalize>,
     stddev_x: VarianceState<StddevPopFinalize>,
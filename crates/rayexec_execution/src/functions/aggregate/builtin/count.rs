@@ -82,7 +82,7 @@ impl AggregateFunctionImpl for CountNonNullImpl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CountNonNullState {
     count: i64,
 }
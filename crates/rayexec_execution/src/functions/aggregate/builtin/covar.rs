@@ -165,7 +165,7 @@ impl CovarFinalize for CovarPopFinalize {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct CovarState<F: CovarFinalize> {
     pub count: i64,
     pub meanx: f64,
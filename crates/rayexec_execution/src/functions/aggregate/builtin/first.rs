@@ -224,7 +224,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct FirstState<T> {
     value: Option<T>,
 }
@@ -253,7 +253,7 @@ impl<T: Default + Debug + Copy> AggregateState<T, T> for FirstState<T> {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct FirstStateBinary {
     value: Option<Vec<u8>>,
 }
@@ -280,4 +280,8 @@ impl AggregateState<&[u8], Vec<u8>> for FirstStateBinary {
             None => Ok((Vec::new(), false)),
         }
     }
+
+    fn heap_size_bytes(&self) -> usize {
+        self.value.as_ref().map(|v| v.capacity()).unwrap_or(0)
+    }
 }
@@ -269,7 +269,7 @@ impl<M> MinMaxBinaryImpl<M> {
 
 impl<M> AggregateFunctionImpl for MinMaxBinaryImpl<M>
 where
-    M: for<'a> AggregateState<&'a [u8], Vec<u8>> + Default + Sync + Send + 'static,
+    M: for<'a> AggregateState<&'a [u8], Vec<u8>> + Default + Clone + Sync + Send + 'static,
 {
     fn new_states(&self) -> Box<dyn AggregateGroupStates> {
         let datatype = self.datatype.clone();
@@ -306,7 +306,7 @@ impl<M> MinMaxBoolImpl<M> {
 
 impl<M> AggregateFunctionImpl for MinMaxBoolImpl<M>
 where
-    M: AggregateState<bool, bool> + Default + Sync + Send + 'static,
+    M: AggregateState<bool, bool> + Default + Clone + Sync + Send + 'static,
 {
     fn new_states(&self) -> Box<dyn AggregateGroupStates> {
         new_unary_aggregate_states::<PhysicalBool, _, _, _, _>(M::default, move |states| {
@@ -348,7 +348,7 @@ impl<M, S, T> AggregateFunctionImpl for MinMaxPrimitiveImpl<M, S, T>
 where
     for<'a> S: PhysicalStorage<Type<'a> = T>,
     T: PartialOrd + Debug + Default + Sync + Send + Copy + 'static,
-    M: AggregateState<T, T> + Default + Sync + Send + 'static,
+    M: AggregateState<T, T> + Default + Clone + Sync + Send + 'static,
     ArrayData2: From<PrimitiveStorage<T>>,
 {
     fn new_states(&self) -> Box<dyn AggregateGroupStates> {
@@ -366,7 +366,7 @@ impl<M, S, T> Clone for MinMaxPrimitiveImpl<M, S, T> {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MinState<T> {
     min: T,
     valid: bool,
@@ -406,7 +406,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MinStateBinary {
     min: Vec<u8>,
     valid: bool,
@@ -442,9 +442,13 @@ impl AggregateState<&[u8], Vec<u8>> for MinStateBinary {
             Ok((Vec::new(), false))
         }
     }
+
+    fn heap_size_bytes(&self) -> usize {
+        self.min.capacity()
+    }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MaxState<T> {
     max: T,
     valid: bool,
@@ -484,7 +488,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct MaxStateBinary {
     max: Vec<u8>,
     valid: bool,
@@ -520,4 +524,8 @@ impl AggregateState<&[u8], Vec<u8>> for MaxStateBinary {
             Ok((Vec::new(), false))
         }
     }
+
+    fn heap_size_bytes(&self) -> usize {
+        self.max.capacity()
+    }
 }
@@ -79,7 +79,7 @@ impl AggregateFunctionImpl for RegrR2Impl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RegrR2State {
     corr: CorrelationState,
 }
@@ -80,7 +80,7 @@ impl AggregateFunctionImpl for RegrSlopeImpl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct RegrSlopeState {
     cov: CovarState<CovarPopFinalize>,
     var: VarianceState<VariancePopFinalize>,
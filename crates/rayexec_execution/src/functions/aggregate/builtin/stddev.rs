@@ -311,7 +311,7 @@ impl VarianceFinalize for VariancePopFinalize {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct VarianceState<F: VarianceFinalize> {
     pub count: i64,
     pub mean: f64,
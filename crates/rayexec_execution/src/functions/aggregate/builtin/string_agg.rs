@@ -109,7 +109,7 @@ impl AggregateFunctionImpl for StringAggImpl {
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct StringAggState {
     /// Separate between concatenated strings.
     sep: String,
@@ -154,4 +154,8 @@ impl AggregateState<&str, String> for StringAggState {
             None => Ok((String::new(), false)),
         }
     }
+
+    fn heap_size_bytes(&self) -> usize {
+        self.sep.capacity() + self.string.as_ref().map(|s| s.capacity()).unwrap_or(0)
+    }
 }
@@ -163,7 +163,7 @@ where
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct SumStateCheckedAdd<T> {
     sum: T,
     set: bool,
@@ -191,7 +191,7 @@ impl<T: CheckedAdd + Default + Debug + Copy> AggregateState<T, T> for SumStateCh
     }
 }
 
-#[derive(Debug, Default)]
+#[derive(Debug, Clone, Default)]
 pub struct SumStateAdd<T> {
     sum: T,
     valid: bool,
@@ -12,6 +12,7 @@ use crate::arrays::datatype::DataType;
 use crate::arrays::executor::aggregate::{
     AggregateState,
     BinaryNonNullUpdater,
+    RowToStateMapping,
     StateCombiner,
     StateFinalizer,
     UnaryNonNullUpdater,
@@ -61,7 +62,8 @@ where
     State: for<'a> AggregateState<
             <<Storage as PhysicalStorage>::Storage<'a> as AddressableStorage>::T,
             Output,
-        > + Sync
+        > + Clone
+        + Sync
         + Send
         + 'static,
     Output: Sync + Send + 'static,
@@ -87,6 +89,7 @@ where
     Storage1: PhysicalStorage,
     Storage2: PhysicalStorage,
     State: for<'a> AggregateState<(Storage1::Type<'a>, Storage2::Type<'a>), Output>
+        + Clone
         + Sync
         + Send
         + 'static,
@@ -107,7 +110,7 @@ where
 impl<State, Input, Output, StateInit, StateUpdate, StateFinalize> AggregateGroupStates
     for TypedAggregateGroupStates<State, Input, Output, StateInit, StateUpdate, StateFinalize>
 where
-    State: AggregateState<Input, Output> + Sync + Send + 'static,
+    State: AggregateState<Input, Output> + Clone + Sync + Send + 'static,
     Input: Sync + Send,
     Output: Sync + Send,
     StateInit: Fn() -> State + Sync + Send,
@@ -118,6 +121,10 @@ where
         OpaqueStatesMut(&mut self.states)
     }
 
+    fn opaque_states(&self) -> &dyn Any {
+        &self.states
+    }
+
     fn new_states(&mut self, count: usize) {
         self.states.extend((0..count).map(|_| (self.state_init)()))
     }
@@ -126,6 +133,19 @@ where
         self.states.len()
     }
 
+    fn size_bytes(&self) -> usize {
+        let mut size = self.states.capacity() * std::mem::size_of::<State>();
+        // Only states that own allocations need to be visited.
+        if std::mem::needs_drop::<State>() {
+            size += self
+                .states
+                .iter()
+                .map(|state| state.heap_size_bytes())
+                .sum::<usize>();
+        }
+        size
+    }
+
     fn update_states(&mut self, inputs: &[&Array], mapping: ChunkGroupAddressIter) -> Result<()> {
         (self.state_update)(inputs, mapping, &mut self.states)
     }
@@ -139,6 +159,20 @@ where
         StateCombiner::combine(consume_states, mapping, &mut self.states)
     }
 
+    fn combine_from(
+        &mut self,
+        other: &dyn AggregateGroupStates,
+        mapping: &[RowToStateMapping],
+    ) -> Result<()> {
+        let other_states = other
+            .opaque_states()
+            .downcast_ref::<Vec<State>>()
+            .ok_or_else(|| {
+                RayexecError::new("Attempted to combine aggregate states of different types")
+            })?;
+        StateCombiner::combine_cloned(other_states, mapping.iter().copied(), &mut self.states)
+    }
+
     fn finalize(&mut self) -> Result<Array> {
         (self.state_finalize)(&mut self.states)
     }
@@ -161,12 +195,19 @@ pub trait AggregateGroupStates: Debug + Sync + Send {
     /// partitions.
     fn opaque_states_mut(&mut self) -> OpaqueStatesMut<'_>;
 
+    /// Get a reference to the underlying states.
+    fn opaque_states(&self) -> &dyn Any;
+
     /// Create `count` number of new states.
     fn new_states(&mut self, count: usize);
 
     /// Returns the number of states being tracked.
     fn num_states(&self) -> usize;
 
+    /// Estimated number of bytes used by the states, including any memory
+    /// allocated by the individual states.
+    fn size_bytes(&self) -> usize;
+
     /// Update states from inputs using some mapping.
     fn update_states(&mut self, inputs: &[&Array], mapping: ChunkGroupAddressIter) -> Result<()>;
 
@@ -177,6 +218,18 @@ pub trait AggregateGroupStates: Debug + Sync + Send {
         mapping: ChunkGroupAddressIter,
     ) -> Result<()>;
 
+    /// Combine states from `other` into self using some mapping, leaving
+    /// `other` unmodified.
+    ///
+    /// Unlike `combine`, a single state in `other` may be combined into any
+    /// number of target states. Used when computing aggregates over window
+    /// frames.
+    fn combine_from(
+        &mut self,
+        other: &dyn AggregateGroupStates,
+        mapping: &[RowToStateMapping],
+    ) -> Result<()>;
+
     /// Finalize the states and return an array.
     fn finalize(&mut self) -> Result<Array>;
 }
@@ -13,6 +13,7 @@ use crate::arrays::scalar::OwnedScalarValue;
 use crate::execution::operators::sink::PartitionSink;
 
 pub const FORMAT_OPT_KEY: &str = "format";
+pub const PARTITION_BY_OPT_KEY: &str = "partition_by";
 
 /// Arguments provided via a COPY TO statement.
 ///
@@ -60,6 +61,20 @@ impl CopyToArgs {
         self.named.remove(FORMAT_OPT_KEY)
     }
 
+    /// Try to get the columns to partition by from the PARTITION_BY option.
+    ///
+    /// Accepts either a single column name or a list of column names.
+    pub fn try_get_partition_by(&self) -> Result<Vec<String>> {
+        match self.named.get(PARTITION_BY_OPT_KEY) {
+            Some(OwnedScalarValue::List(cols)) => cols
+                .iter()
+                .map(|col| Ok(col.try_as_str()?.to_string()))
+                .collect(),
+            Some(col) => Ok(vec![col.try_as_str()?.to_string()]),
+            None => Ok(Vec::new()),
+        }
+    }
+
     pub fn try_get_named(&self, name: &str) -> Result<&OwnedScalarValue> {
         self.named
             .get(name)
@@ -71,6 +86,12 @@ pub trait CopyToFunction: Debug + Sync + Send + DynClone {
     /// Name of the copy to function.
     fn name(&self) -> &'static str;
 
+    /// Extension to use for files written by this function when the COPY TO
+    /// location is a directory, e.g. when writing hive partitioned files.
+    fn file_extension(&self) -> Option<&'static str> {
+        None
+    }
+
     /// Create a COPY TO destination that will write to the given location.
     // TODO: Additional COPY TO args once we have them.
     fn create_sinks(
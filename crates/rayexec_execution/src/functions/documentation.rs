@@ -3,6 +3,7 @@
 pub enum Category {
     General,
     Aggregate,
+    Window,
     Numeric,
     Date,
     Time,
@@ -33,6 +33,13 @@ pub const fn implicit_cast_score(have: &DataType, want: DataTypeId) -> Option<u3
         DataType::Float32 => return float32_cast_score(want),
         DataType::Float64 => return float64_cast_score(want),
 
+        // Dates can be widened to timestamps.
+        DataType::Date32 => {
+            if let DataTypeId::Timestamp = want {
+                return Some(target_score(want));
+            }
+        }
+
         // String casts
         DataType::Utf8 => match want {
             DataTypeId::Int8
@@ -46,7 +53,10 @@ pub const fn implicit_cast_score(have: &DataType, want: DataTypeId) -> Option<u3
             | DataTypeId::Decimal64
             | DataTypeId::Decimal128
             | DataTypeId::Interval
-            | DataTypeId::Timestamp => return Some(target_score(want)),
+            | DataTypeId::Timestamp
+            | DataTypeId::Time64
+            | DataTypeId::Uuid
+            | DataTypeId::Binary => return Some(target_score(want)),
 
             // Non-zero since it's a valid cast, just we would prefer something
             // else.
@@ -232,6 +242,8 @@ mod tests {
         assert!(implicit_cast_score(&DataType::Utf8, DataTypeId::Int32).is_some());
         assert!(implicit_cast_score(&DataType::Utf8, DataTypeId::Timestamp).is_some());
         assert!(implicit_cast_score(&DataType::Utf8, DataTypeId::Interval).is_some());
+        assert!(implicit_cast_score(&DataType::Utf8, DataTypeId::Time64).is_some());
+        assert!(implicit_cast_score(&DataType::Utf8, DataTypeId::Uuid).is_some());
     }
 
     #[test]
@@ -5,6 +5,7 @@ pub mod implicit;
 pub mod proto;
 pub mod scalar;
 pub mod table;
+pub mod window;
 
 use std::borrow::Borrow;
 use std::fmt::Display;
@@ -235,7 +236,7 @@ impl CandidateSignature {
         buf.clear();
 
         for (have, &want) in have.iter().zip(want.iter()) {
-            if have.datatype_id() == want {
+            if have.datatype_id() == want || want == DataTypeId::Any {
                 buf.push(CastType::NoCastNeeded);
                 continue;
             }
@@ -7,6 +7,7 @@ use super::aggregate::{AggregateFunction, PlannedAggregateFunction};
 use super::copy::{CopyToArgs, CopyToFunction};
 use super::scalar::{PlannedScalarFunction, ScalarFunction};
 use super::table::{PlannedTableFunction, TableFunction};
+use super::window::WindowFunction;
 use crate::arrays::scalar::OwnedScalarValue;
 use crate::database::catalog::CatalogTx;
 use crate::database::DatabaseContext;
@@ -24,7 +25,7 @@ impl DatabaseProtoConv for Box<dyn ScalarFunction> {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        let tx = &CatalogTx {};
+        let tx = &CatalogTx::new();
         let ent = context
             .system_catalog()?
             .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -53,7 +54,7 @@ impl DatabaseProtoConv for PlannedScalarFunction {
 
     fn from_proto_ctx(_proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
         unimplemented!()
-        // let tx = &CatalogTx {};
+        // let tx = &CatalogTx::new();
         // let ent = context
         //     .system_catalog()?
         //     .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -78,7 +79,7 @@ impl DatabaseProtoConv for Box<dyn AggregateFunction> {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        let tx = &CatalogTx {};
+        let tx = &CatalogTx::new();
         let ent = context
             .system_catalog()?
             .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -107,7 +108,7 @@ impl DatabaseProtoConv for PlannedAggregateFunction {
 
     fn from_proto_ctx(_proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
         unimplemented!()
-        // let tx = &CatalogTx {};
+        // let tx = &CatalogTx::new();
         // let ent = context
         //     .system_catalog()?
         //     .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -122,6 +123,29 @@ impl DatabaseProtoConv for PlannedAggregateFunction {
     }
 }
 
+impl DatabaseProtoConv for Box<dyn WindowFunction> {
+    type ProtoType = rayexec_proto::generated::functions::WindowFunction;
+
+    fn to_proto_ctx(&self, _context: &DatabaseContext) -> Result<Self::ProtoType> {
+        Ok(Self::ProtoType {
+            name: self.name().to_string(),
+        })
+    }
+
+    fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
+        let tx = &CatalogTx::new();
+        let ent = context
+            .system_catalog()?
+            .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
+            .required("lookup schema")?
+            .get_window_function(tx, &proto.name)?
+            .required("window function")?;
+        let ent = ent.try_as_window_function_entry()?;
+
+        Ok(ent.function.clone())
+    }
+}
+
 impl DatabaseProtoConv for Box<dyn TableFunction> {
     type ProtoType = rayexec_proto::generated::functions::TableFunction;
 
@@ -132,7 +156,7 @@ impl DatabaseProtoConv for Box<dyn TableFunction> {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        let tx = &CatalogTx {};
+        let tx = &CatalogTx::new();
         let ent = context
             .system_catalog()?
             .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -161,7 +185,7 @@ impl DatabaseProtoConv for PlannedTableFunction {
 
     fn from_proto_ctx(_proto: Self::ProtoType, _context: &DatabaseContext) -> Result<Self> {
         unimplemented!()
-        // let tx = &CatalogTx {};
+        // let tx = &CatalogTx::new();
         // let ent = context
         //     .system_catalog()?
         //     .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -186,7 +210,7 @@ impl DatabaseProtoConv for Box<dyn CopyToFunction> {
     }
 
     fn from_proto_ctx(proto: Self::ProtoType, context: &DatabaseContext) -> Result<Self> {
-        let tx = &CatalogTx {};
+        let tx = &CatalogTx::new();
         let ent = context
             .system_catalog()?
             .get_schema(tx, FUNCTION_LOOKUP_CATALOG)?
@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use num_traits::CheckedAdd;
+use rayexec_error::{RayexecError, Result};
 
+use super::{decimal_add_sub_return_type, execute_decimal_arith, overflow_error, CheckedArith};
 use crate::arrays::array::physical_type::{
     PhysicalF16,
     PhysicalF32,
@@ -12,6 +14,7 @@ use crate::arrays::array::physical_type::{
     PhysicalI32,
     PhysicalI64,
     PhysicalI8,
+    PhysicalInterval,
     PhysicalStorage,
     PhysicalU128,
     PhysicalU16,
@@ -20,9 +23,12 @@ use crate::arrays::array::physical_type::{
     PhysicalU8,
 };
 use crate::arrays::array::{Array, ArrayData2};
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::compute::date::{date32_to_timestamp, timestamp_add_interval};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
@@ -73,15 +79,43 @@ impl FunctionInfo for Add {
                 DataTypeId::UInt64,
             ),
             Signature::new_positional(&[DataTypeId::Date32, DataTypeId::Int32], DataTypeId::Date32),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Timestamp],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Date32, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Date32],
+                DataTypeId::Timestamp,
+            ),
             Signature::new_positional(&[DataTypeId::Int32, DataTypeId::Date32], DataTypeId::Date32),
             Signature::new_positional(
-                &[DataTypeId::Interval, DataTypeId::Int64],
+                &[DataTypeId::Interval, DataTypeId::Interval],
                 DataTypeId::Interval,
             ),
             Signature::new_positional(
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Decimal64,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal64, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal64],
+                DataTypeId::Decimal128,
+            ),
         ];
         SIGS
     }
@@ -152,15 +186,20 @@ impl ScalarFunction for Add {
                 DataType::UInt128,
             ),
 
-            // TODO: Split out decimal (for scaling)
-            datatypes @ (DataType::Decimal64(_), DataType::Decimal64(_)) => (
-                Box::new(AddImpl::<PhysicalI64>::new(datatypes.0.clone())),
-                datatypes.0,
-            ),
-            datatypes @ (DataType::Decimal128(_), DataType::Decimal128(_)) => (
-                Box::new(AddImpl::<PhysicalI128>::new(datatypes.0.clone())),
-                datatypes.0,
-            ),
+            // Decimal, inputs rescaled to a common scale.
+            (
+                DataType::Decimal64(a) | DataType::Decimal128(a),
+                DataType::Decimal64(b) | DataType::Decimal128(b),
+            ) => {
+                let return_type = decimal_add_sub_return_type(a, b)?;
+                let function_impl: Box<dyn ScalarFunctionImpl> = match return_type {
+                    DataType::Decimal64(_) => {
+                        Box::new(DecimalAddImpl::<Decimal64Type>::new(return_type.clone()))
+                    }
+                    _ => Box::new(DecimalAddImpl::<Decimal128Type>::new(return_type.clone())),
+                };
+                (function_impl, return_type)
+            }
 
             // Date + days
             (DataType::Date32, DataType::Int32) => (
@@ -174,7 +213,33 @@ impl ScalarFunction for Add {
                 DataType::Date32,
             ),
 
-            // TODO: Interval
+            // Timestamp + interval
+            (DataType::Timestamp(meta), DataType::Interval) => (
+                Box::new(AddTimestampIntervalImpl::<false> { unit: meta.unit }),
+                DataType::Timestamp(meta),
+            ),
+            // Interval + timestamp
+            (DataType::Interval, DataType::Timestamp(meta)) => (
+                Box::new(AddTimestampIntervalImpl::<true> { unit: meta.unit }),
+                DataType::Timestamp(meta),
+            ),
+
+            // Date + interval
+            (DataType::Date32, DataType::Interval) => (
+                Box::new(AddDateIntervalImpl::<false>),
+                DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            ),
+            // Interval + date
+            (DataType::Interval, DataType::Date32) => (
+                Box::new(AddDateIntervalImpl::<true>),
+                DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            ),
+
+            // Interval + interval
+            (DataType::Interval, DataType::Interval) => {
+                (Box::new(AddIntervalImpl), DataType::Interval)
+            }
+
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -205,7 +270,7 @@ impl<S> AddImpl<S> {
 impl<S> ScalarFunctionImpl for AddImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Add<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -217,7 +282,169 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a + b)))
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            match a.add_checked(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(overflow_error(a, "+", b, &self.datatype));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DecimalAddImpl<D> {
+    datatype: DataType,
+    _d: PhantomData<D>,
+}
+
+impl<D> DecimalAddImpl<D> {
+    fn new(datatype: DataType) -> Self {
+        DecimalAddImpl {
+            datatype,
+            _d: PhantomData,
+        }
+    }
+}
+
+impl<D> ScalarFunctionImpl for DecimalAddImpl<D>
+where
+    D: DecimalType,
+    ArrayData2: From<PrimitiveStorage<D::Primitive>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        execute_decimal_arith::<D, _>(&self.datatype, inputs, true, "+", |a, b| a.checked_add(&b))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AddTimestampIntervalImpl<const LHS_RHS_FLIPPED: bool> {
+    unit: TimeUnit,
+}
+
+impl<const LHS_RHS_FLIPPED: bool> ScalarFunctionImpl for AddTimestampIntervalImpl<LHS_RHS_FLIPPED> {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let (a, b) = if LHS_RHS_FLIPPED {
+            (inputs[1], inputs[0])
+        } else {
+            (inputs[0], inputs[1])
+        };
+
+        let builder = ArrayBuilder {
+            datatype: a.datatype().clone(),
+            buffer: PrimitiveBuffer::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, _, _>(
+            a,
+            b,
+            builder,
+            |ts, interval, buf| match timestamp_add_interval(self.unit, ts, interval) {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+/// Adds an interval to a date, producing a timestamp.
+#[derive(Debug, Clone)]
+pub struct AddDateIntervalImpl<const LHS_RHS_FLIPPED: bool>;
+
+impl<const LHS_RHS_FLIPPED: bool> ScalarFunctionImpl for AddDateIntervalImpl<LHS_RHS_FLIPPED> {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let (a, b) = if LHS_RHS_FLIPPED {
+            (inputs[1], inputs[0])
+        } else {
+            (inputs[0], inputs[1])
+        };
+
+        add_date_interval(a, b, false)
+    }
+}
+
+/// Adds (or subtracts if `negate` is true) an interval to a date, producing a
+/// timestamp with microsecond precision.
+pub(crate) fn add_date_interval(date: &Array, interval: &Array, negate: bool) -> Result<Array> {
+    let builder = ArrayBuilder {
+        datatype: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+        buffer: PrimitiveBuffer::with_len(date.logical_len()),
+    };
+
+    let mut result = Ok(());
+    let out = BinaryExecutor::execute::<PhysicalI32, PhysicalInterval, _, _>(
+        date,
+        interval,
+        builder,
+        |days, interval, buf| {
+            let interval = if negate {
+                Interval::new(-interval.months, -interval.days, -interval.nanos)
+            } else {
+                interval
+            };
+            let v = date32_to_timestamp(TimeUnit::Microsecond, days)
+                .ok_or_else(|| RayexecError::new("Date out of range"))
+                .and_then(|ts| timestamp_add_interval(TimeUnit::Microsecond, ts, interval));
+            match v {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            }
+        },
+    )?;
+    result?;
+
+    Ok(out)
+}
+
+#[derive(Debug, Clone)]
+pub struct AddIntervalImpl;
+
+impl ScalarFunctionImpl for AddIntervalImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalInterval, PhysicalInterval, _, _>(
+            a,
+            b,
+            builder,
+            |a, b, buf| match a.checked_add(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(RayexecError::new("Interval out of range"));
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
     }
 }
 
@@ -254,4 +481,28 @@ mod tests {
 
         assert_eq!(expected, out);
     }
+
+    #[test]
+    fn add_i32_overflow() {
+        let a = Array::from_iter([1, i32::MAX]);
+        let b = Array::from_iter([1, 1]);
+
+        let mut table_list = TableList::empty();
+        let table_ref = table_list
+            .push_table(
+                None,
+                vec![DataType::Int32, DataType::Int32],
+                vec!["a".to_string(), "b".to_string()],
+            )
+            .unwrap();
+
+        let planned = Add
+            .plan(
+                &table_list,
+                vec![expr::col_ref(table_ref, 0), expr::col_ref(table_ref, 1)],
+            )
+            .unwrap();
+
+        planned.function_impl.execute(&[&a, &b]).unwrap_err();
+    }
 }
@@ -1,8 +1,10 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use num_traits::ToPrimitive;
+use rayexec_error::{RayexecError, Result};
 
+use super::{division_by_zero_error, overflow_error, CheckedArith};
 use crate::arrays::array::physical_type::{
     PhysicalF16,
     PhysicalF32,
@@ -12,6 +14,7 @@ use crate::arrays::array::physical_type::{
     PhysicalI32,
     PhysicalI64,
     PhysicalI8,
+    PhysicalInterval,
     PhysicalStorage,
     PhysicalU128,
     PhysicalU16,
@@ -26,6 +29,7 @@ use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::BinaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
@@ -76,10 +80,18 @@ impl FunctionInfo for Div {
                 DataTypeId::UInt64,
             ),
             Signature::new_positional(&[DataTypeId::Date32, DataTypeId::Int64], DataTypeId::Date32),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Int32],
+                DataTypeId::Interval,
+            ),
             Signature::new_positional(
                 &[DataTypeId::Interval, DataTypeId::Int64],
                 DataTypeId::Interval,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Float64],
+                DataTypeId::Interval,
+            ),
             Signature::new_positional(
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Float64,
@@ -168,7 +180,21 @@ impl ScalarFunction for Div {
                 DataType::Float64,
             ),
 
-            // TODO: Interval, dates
+            // Interval
+            (DataType::Interval, DataType::Int32) => (
+                Box::new(IntervalDivImpl::<PhysicalI32>::new()),
+                DataType::Interval,
+            ),
+            (DataType::Interval, DataType::Int64) => (
+                Box::new(IntervalDivImpl::<PhysicalI64>::new()),
+                DataType::Interval,
+            ),
+            (DataType::Interval, DataType::Float64) => (
+                Box::new(IntervalDivImpl::<PhysicalF64>::new()),
+                DataType::Interval,
+            ),
+
+            // TODO: Dates
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -181,6 +207,56 @@ impl ScalarFunction for Div {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct IntervalDivImpl<Rhs> {
+    _rhs: PhantomData<Rhs>,
+}
+
+impl<Rhs> IntervalDivImpl<Rhs> {
+    fn new() -> Self {
+        IntervalDivImpl { _rhs: PhantomData }
+    }
+}
+
+impl<Rhs> ScalarFunctionImpl for IntervalDivImpl<Rhs>
+where
+    Rhs: PhysicalStorage,
+    for<'a> Rhs::Type<'a>: ToPrimitive,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out =
+            BinaryExecutor::execute::<PhysicalInterval, Rhs, _, _>(a, b, builder, |a, b, buf| {
+                let divisor = b.to_f64().unwrap_or_default();
+                if divisor == 0.0 {
+                    if result.is_ok() {
+                        result = Err(RayexecError::new("Division by zero"));
+                    }
+                    return;
+                }
+                match a.checked_div_f64(divisor) {
+                    Some(v) => buf.put(&v),
+                    None => {
+                        if result.is_ok() {
+                            result = Err(RayexecError::new("Interval out of range"));
+                        }
+                    }
+                }
+            })?;
+        result?;
+
+        Ok(out)
+    }
+}
+
 // TODO: We could possibly wrap inputs in a cast and avoid the special casing
 // here.
 #[derive(Debug, Clone)]
@@ -242,7 +318,7 @@ impl<S> DivImpl<S> {
 impl<S> ScalarFunctionImpl for DivImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Div<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -254,7 +330,26 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a / b)))
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            if b.is_zero_divisor() {
+                if result.is_ok() {
+                    result = Err(division_by_zero_error());
+                }
+                return;
+            }
+            match a.div_checked(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(overflow_error(a, "/", b, &self.datatype));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
     }
 }
 
@@ -11,4 +11,242 @@ mod mul;
 pub use mul::*;
 
 mod rem;
+use std::fmt::Display;
+
+use half::f16;
+use rayexec_error::{RayexecError, Result};
 pub use rem::*;
+
+use crate::arrays::array::{Array, ArrayData2};
+use crate::arrays::compute::cast::array::cast_array;
+use crate::arrays::compute::cast::behavior::CastFailBehavior;
+use crate::arrays::datatype::{DataType, DecimalTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::storage::PrimitiveStorage;
+
+/// Primitive arithmetic that reports overflow instead of wrapping or
+/// panicking.
+///
+/// Floats never overflow, they just produce infinity.
+pub trait CheckedArith: Copy + Display + Sized {
+    type Output;
+
+    fn add_checked(self, rhs: Self) -> Option<Self::Output>;
+    fn sub_checked(self, rhs: Self) -> Option<Self::Output>;
+    fn mul_checked(self, rhs: Self) -> Option<Self::Output>;
+    fn div_checked(self, rhs: Self) -> Option<Self::Output>;
+    fn rem_checked(self, rhs: Self) -> Option<Self::Output>;
+    fn neg_checked(self) -> Option<Self::Output>;
+
+    /// If this value would cause an integer division by zero when used as a
+    /// divisor.
+    ///
+    /// Always false for floats.
+    fn is_zero_divisor(self) -> bool;
+}
+
+macro_rules! impl_checked_arith_int {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedArith for $ty {
+                type Output = $ty;
+
+                fn add_checked(self, rhs: Self) -> Option<Self::Output> {
+                    self.checked_add(rhs)
+                }
+
+                fn sub_checked(self, rhs: Self) -> Option<Self::Output> {
+                    self.checked_sub(rhs)
+                }
+
+                fn mul_checked(self, rhs: Self) -> Option<Self::Output> {
+                    self.checked_mul(rhs)
+                }
+
+                fn div_checked(self, rhs: Self) -> Option<Self::Output> {
+                    self.checked_div(rhs)
+                }
+
+                fn rem_checked(self, rhs: Self) -> Option<Self::Output> {
+                    self.checked_rem(rhs)
+                }
+
+                fn neg_checked(self) -> Option<Self::Output> {
+                    self.checked_neg()
+                }
+
+                fn is_zero_divisor(self) -> bool {
+                    self == 0
+                }
+            }
+        )*
+    };
+}
+
+macro_rules! impl_checked_arith_float {
+    ($($ty:ty),*) => {
+        $(
+            impl CheckedArith for $ty {
+                type Output = $ty;
+
+                fn add_checked(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self + rhs)
+                }
+
+                fn sub_checked(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self - rhs)
+                }
+
+                fn mul_checked(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self * rhs)
+                }
+
+                fn div_checked(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self / rhs)
+                }
+
+                fn rem_checked(self, rhs: Self) -> Option<Self::Output> {
+                    Some(self % rhs)
+                }
+
+                fn neg_checked(self) -> Option<Self::Output> {
+                    Some(-self)
+                }
+
+                fn is_zero_divisor(self) -> bool {
+                    false
+                }
+            }
+        )*
+    };
+}
+
+impl_checked_arith_int!(i8, i16, i32, i64, i128, u8, u16, u32, u64, u128);
+impl_checked_arith_float!(f16, f32, f64);
+
+/// Error for when an arithmetic operation overflows the output type.
+fn overflow_error(
+    lhs: impl Display,
+    op: &str,
+    rhs: impl Display,
+    datatype: &DataType,
+) -> RayexecError {
+    RayexecError::new(format!(
+        "Out of range: {lhs} {op} {rhs} overflows {datatype}"
+    ))
+}
+
+/// Error for integer division or remainder with a zero divisor.
+fn division_by_zero_error() -> RayexecError {
+    RayexecError::new("Division by zero")
+}
+
+/// Returns a decimal data type with the given precision and scale, using
+/// Decimal64 if the precision allows for it, otherwise Decimal128.
+fn decimal_datatype(precision: i16, scale: i16) -> Result<DataType> {
+    let max = Decimal128Type::MAX_PRECISION as i16;
+    if scale > max {
+        return Err(RayexecError::new(format!(
+            "Decimal scale {scale} is greater than max precision {max}"
+        )));
+    }
+    // Precision must be able to hold the scale, and is capped at the max
+    // precision. Values exceeding the capped precision error during
+    // execution.
+    let precision = precision.max(scale).clamp(1, max) as u8;
+    let meta = DecimalTypeMeta::new(precision, scale as i8);
+
+    if precision <= Decimal64Type::MAX_PRECISION {
+        Ok(DataType::Decimal64(meta))
+    } else {
+        Ok(DataType::Decimal128(meta))
+    }
+}
+
+/// Result type for adding or subtracting two decimals.
+///
+/// The scale is the max of both scales, with enough precision to hold the
+/// integral digits of both sides plus a carry digit.
+fn decimal_add_sub_return_type(a: DecimalTypeMeta, b: DecimalTypeMeta) -> Result<DataType> {
+    let scale = a.scale.max(b.scale) as i16;
+    let integral = (a.precision as i16 - a.scale as i16).max(b.precision as i16 - b.scale as i16);
+    decimal_datatype(integral + scale + 1, scale)
+}
+
+/// Result type for multiplying two decimals.
+///
+/// Precision and scale are the sums of the input precisions and scales.
+fn decimal_mul_return_type(a: DecimalTypeMeta, b: DecimalTypeMeta) -> Result<DataType> {
+    decimal_datatype(
+        a.precision as i16 + b.precision as i16,
+        a.scale as i16 + b.scale as i16,
+    )
+}
+
+/// Execute a binary decimal operation producing `datatype`.
+///
+/// Inputs are first cast to the physical type of the output. If `rescale` is
+/// true, the inputs are also rescaled to the output scale, otherwise they keep
+/// their original scale.
+///
+/// Errors if the operation overflows, or if the result does not fit in the
+/// output precision.
+fn execute_decimal_arith<D, F>(
+    datatype: &DataType,
+    inputs: &[&Array],
+    rescale: bool,
+    op: &str,
+    f: F,
+) -> Result<Array>
+where
+    D: DecimalType,
+    F: Fn(D::Primitive, D::Primitive) -> Option<D::Primitive>,
+    ArrayData2: From<PrimitiveStorage<D::Primitive>>,
+{
+    let meta = datatype.try_get_decimal_type_meta()?;
+
+    let cast_input = |arr: &Array| -> Result<Array> {
+        let to_meta = if rescale {
+            meta
+        } else {
+            let scale = arr.datatype().try_get_decimal_type_meta()?.scale;
+            DecimalTypeMeta::new(D::MAX_PRECISION, scale)
+        };
+        let to = match datatype {
+            DataType::Decimal64(_) => DataType::Decimal64(to_meta),
+            _ => DataType::Decimal128(to_meta),
+        };
+        cast_array(arr, to, CastFailBehavior::Error)
+    };
+
+    let a = cast_input(inputs[0])?;
+    let b = cast_input(inputs[1])?;
+
+    let builder = ArrayBuilder {
+        datatype: datatype.clone(),
+        buffer: PrimitiveBuffer::<D::Primitive>::with_len(a.logical_len()),
+    };
+
+    let mut result = Ok(());
+    let out =
+        BinaryExecutor::execute::<D::Storage, D::Storage, _, _>(&a, &b, builder, |l, r, buf| {
+            let v = f(l, r)
+                .filter(|v| D::validate_precision(*v, meta.precision).is_ok())
+                .ok_or_else(|| {
+                    RayexecError::new(format!("Out of range: decimal {op} overflows {datatype}"))
+                });
+            match v {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            }
+        })?;
+    result?;
+
+    Ok(out)
+}
@@ -1,9 +1,10 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use num_traits::{NumCast, PrimInt};
-use rayexec_error::Result;
+use num_traits::{CheckedMul, NumCast, PrimInt};
+use rayexec_error::{RayexecError, Result};
 
+use super::{decimal_mul_return_type, execute_decimal_arith, overflow_error, CheckedArith};
 use crate::arrays::array::physical_type::{
     PhysicalF16,
     PhysicalF32,
@@ -22,7 +23,7 @@ use crate::arrays::array::physical_type::{
     PhysicalU8,
 };
 use crate::arrays::array::{Array, ArrayData2};
-use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
+use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::BinaryExecutor;
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
@@ -94,11 +95,31 @@ impl FunctionInfo for Mul {
                 &[DataTypeId::Int64, DataTypeId::Interval],
                 DataTypeId::Interval,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Float64],
+                DataTypeId::Interval,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Float64, DataTypeId::Interval],
+                DataTypeId::Interval,
+            ),
             // Decimal
             Signature::new_positional(
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Decimal64,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal64, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal64],
+                DataTypeId::Decimal128,
+            ),
         ];
         SIGS
     }
@@ -169,27 +190,19 @@ impl ScalarFunction for Mul {
                 DataType::UInt128,
             ),
 
-            // Decimal
-            (DataType::Decimal64(a), DataType::Decimal64(b)) => {
-                // Since we're multiplying, might as well go wide as possible.
-                // Eventually we'll want to bumpt up to 128 if the precision is
-                // over some threshold to be more resilient to overflows.
-                let precision = Decimal64Type::MAX_PRECISION;
-                let scale = a.scale + b.scale;
-                let return_type = DataType::Decimal64(DecimalTypeMeta { precision, scale });
-                (
-                    Box::new(DecimalMulImpl::<Decimal64Type>::new(return_type.clone())),
-                    return_type,
-                )
-            }
-            (DataType::Decimal128(a), DataType::Decimal128(b)) => {
-                let precision = Decimal128Type::MAX_PRECISION;
-                let scale = a.scale + b.scale;
-                let return_type = DataType::Decimal128(DecimalTypeMeta { precision, scale });
-                (
-                    Box::new(DecimalMulImpl::<Decimal128Type>::new(return_type.clone())),
-                    return_type,
-                )
+            // Decimal, scales are summed so no rescaling needed.
+            (
+                DataType::Decimal64(a) | DataType::Decimal128(a),
+                DataType::Decimal64(b) | DataType::Decimal128(b),
+            ) => {
+                let return_type = decimal_mul_return_type(a, b)?;
+                let function_impl: Box<dyn ScalarFunctionImpl> = match return_type {
+                    DataType::Decimal64(_) => {
+                        Box::new(DecimalMulImpl::<Decimal64Type>::new(return_type.clone()))
+                    }
+                    _ => Box::new(DecimalMulImpl::<Decimal128Type>::new(return_type.clone())),
+                };
+                (function_impl, return_type)
             }
 
             // Interval
@@ -209,8 +222,13 @@ impl ScalarFunction for Mul {
                 Box::new(IntervalMulImpl::<PhysicalI64, true>::new()),
                 DataType::Interval,
             ),
+            (DataType::Interval, DataType::Float64) => {
+                (Box::new(IntervalMulFloatImpl::<false>), DataType::Interval)
+            }
+            (DataType::Float64, DataType::Interval) => {
+                (Box::new(IntervalMulFloatImpl::<true>), DataType::Interval)
+            }
 
-            // TODO: Date
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -251,14 +269,70 @@ where
             buffer: PrimitiveBuffer::<Interval>::with_len(lhs.logical_len()),
         };
 
-        BinaryExecutor::execute::<PhysicalInterval, Rhs, _, _>(lhs, rhs, builder, |a, b, buf| {
-            // TODO: Overflow check
-            buf.put(&Interval {
-                months: a.months * (<i32 as NumCast>::from(b).unwrap_or_default()),
-                days: a.days * (<i32 as NumCast>::from(b).unwrap_or_default()),
-                nanos: a.nanos * (<i64 as NumCast>::from(b).unwrap_or_default()),
-            })
-        })
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalInterval, Rhs, _, _>(
+            lhs,
+            rhs,
+            builder,
+            |a, b, buf| {
+                let v = (|| {
+                    let b32 = <i32 as NumCast>::from(b)?;
+                    let b64 = <i64 as NumCast>::from(b)?;
+                    Some(Interval {
+                        months: a.months.checked_mul(b32)?,
+                        days: a.days.checked_mul(b32)?,
+                        nanos: a.nanos.checked_mul(b64)?,
+                    })
+                })();
+                match v {
+                    Some(v) => buf.put(&v),
+                    None => {
+                        if result.is_ok() {
+                            result = Err(RayexecError::new("Interval out of range"));
+                        }
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct IntervalMulFloatImpl<const LHS_RHS_FLIPPED: bool>;
+
+impl<const LHS_RHS_FLIPPED: bool> ScalarFunctionImpl for IntervalMulFloatImpl<LHS_RHS_FLIPPED> {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let (lhs, rhs) = if LHS_RHS_FLIPPED {
+            (inputs[1], inputs[0])
+        } else {
+            (inputs[0], inputs[1])
+        };
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(lhs.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalInterval, PhysicalF64, _, _>(
+            lhs,
+            rhs,
+            builder,
+            |a, b, buf| match a.checked_mul_f64(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(RayexecError::new("Interval out of range"));
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
     }
 }
 
@@ -283,17 +357,7 @@ where
     ArrayData2: From<PrimitiveStorage<D::Primitive>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        let a = inputs[0];
-        let b = inputs[1];
-
-        let builder = ArrayBuilder {
-            datatype: self.datatype.clone(),
-            buffer: PrimitiveBuffer::<D::Primitive>::with_len(a.logical_len()),
-        };
-
-        BinaryExecutor::execute::<D::Storage, D::Storage, _, _>(a, b, builder, |a, b, buf| {
-            buf.put(&(a * b))
-        })
+        execute_decimal_arith::<D, _>(&self.datatype, inputs, false, "*", |a, b| a.checked_mul(&b))
     }
 }
 
@@ -315,7 +379,7 @@ impl<S> MulImpl<S> {
 impl<S> ScalarFunctionImpl for MulImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Mul<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -327,7 +391,20 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a * b)))
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            match a.mul_checked(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(overflow_error(a, "*", b, &self.datatype));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
     }
 }
 
@@ -3,6 +3,7 @@ use std::marker::PhantomData;
 
 use rayexec_error::Result;
 
+use super::{division_by_zero_error, overflow_error, CheckedArith};
 use crate::arrays::array::physical_type::{
     PhysicalF16,
     PhysicalF32,
@@ -197,7 +198,7 @@ impl<S> RemImpl<S> {
 impl<S> ScalarFunctionImpl for RemImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Rem<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -209,7 +210,26 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a % b)))
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            if b.is_zero_divisor() {
+                if result.is_ok() {
+                    result = Err(division_by_zero_error());
+                }
+                return;
+            }
+            match a.rem_checked(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(overflow_error(a, "%", b, &self.datatype));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
     }
 }
 
@@ -1,8 +1,11 @@
 use std::fmt::Debug;
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use num_traits::CheckedSub;
+use rayexec_error::{RayexecError, Result};
 
+use super::add::add_date_interval;
+use super::{decimal_add_sub_return_type, execute_decimal_arith, overflow_error, CheckedArith};
 use crate::arrays::array::physical_type::{
     PhysicalF16,
     PhysicalF32,
@@ -12,6 +15,7 @@ use crate::arrays::array::physical_type::{
     PhysicalI32,
     PhysicalI64,
     PhysicalI8,
+    PhysicalInterval,
     PhysicalStorage,
     PhysicalU128,
     PhysicalU16,
@@ -20,9 +24,12 @@ use crate::arrays::array::physical_type::{
     PhysicalU8,
 };
 use crate::arrays::array::{Array, ArrayData2};
-use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::compute::date::{timestamp_add_interval, timestamp_sub_timestamp};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
+use crate::arrays::scalar::interval::Interval;
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
 use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
@@ -81,12 +88,23 @@ impl FunctionInfo for Sub {
                 DataTypeId::UInt128,
             ),
             Signature::new_positional(&[DataTypeId::Date32, DataTypeId::Int32], DataTypeId::Date32),
-            // TODO
-            // Signature {
-            //     input: &[DataTypeId::Interval, DataTypeId::Int64],
-            //     variadic: None,
-            //     return_type: DataTypeId::Interval,
-            // },
+            Signature::new_positional(&[DataTypeId::Date32, DataTypeId::Date32], DataTypeId::Int32),
+            Signature::new_positional(
+                &[DataTypeId::Date32, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Interval],
+                DataTypeId::Timestamp,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Timestamp, DataTypeId::Timestamp],
+                DataTypeId::Interval,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Interval, DataTypeId::Interval],
+                DataTypeId::Interval,
+            ),
             Signature::new_positional(
                 &[DataTypeId::Decimal64, DataTypeId::Decimal64],
                 DataTypeId::Decimal64,
@@ -95,6 +113,14 @@ impl FunctionInfo for Sub {
                 &[DataTypeId::Decimal128, DataTypeId::Decimal128],
                 DataTypeId::Decimal128,
             ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal64, DataTypeId::Decimal128],
+                DataTypeId::Decimal128,
+            ),
+            Signature::new_positional(
+                &[DataTypeId::Decimal128, DataTypeId::Decimal64],
+                DataTypeId::Decimal128,
+            ),
         ];
         SIGS
     }
@@ -165,15 +191,20 @@ impl ScalarFunction for Sub {
                 DataType::UInt128,
             ),
 
-            // TODO: Split out decimal (for scaling)
-            datatypes @ (DataType::Decimal64(_), DataType::Decimal64(_)) => (
-                Box::new(SubImpl::<PhysicalI64>::new(datatypes.0.clone())),
-                datatypes.0,
-            ),
-            datatypes @ (DataType::Decimal128(_), DataType::Decimal128(_)) => (
-                Box::new(SubImpl::<PhysicalI128>::new(datatypes.0.clone())),
-                datatypes.0,
-            ),
+            // Decimal, inputs rescaled to a common scale.
+            (
+                DataType::Decimal64(a) | DataType::Decimal128(a),
+                DataType::Decimal64(b) | DataType::Decimal128(b),
+            ) => {
+                let return_type = decimal_add_sub_return_type(a, b)?;
+                let function_impl: Box<dyn ScalarFunctionImpl> = match return_type {
+                    DataType::Decimal64(_) => {
+                        Box::new(DecimalSubImpl::<Decimal64Type>::new(return_type.clone()))
+                    }
+                    _ => Box::new(DecimalSubImpl::<Decimal128Type>::new(return_type.clone())),
+                };
+                (function_impl, return_type)
+            }
 
             // Date + days
             (DataType::Date32, DataType::Int32) => (
@@ -181,7 +212,38 @@ impl ScalarFunction for Sub {
                 DataType::Date32,
             ),
 
-            // TODO: Interval
+            // Date - date
+            (DataType::Date32, DataType::Date32) => (
+                Box::new(SubImpl::<PhysicalI32>::new(DataType::Int32)),
+                DataType::Int32,
+            ),
+
+            // Date - interval
+            (DataType::Date32, DataType::Interval) => (
+                Box::new(SubDateIntervalImpl),
+                DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
+            ),
+
+            // Timestamp - interval
+            (DataType::Timestamp(meta), DataType::Interval) => (
+                Box::new(SubTimestampIntervalImpl { unit: meta.unit }),
+                DataType::Timestamp(meta),
+            ),
+
+            // Timestamp - timestamp
+            (DataType::Timestamp(left), DataType::Timestamp(right)) => (
+                Box::new(SubTimestampTimestampImpl {
+                    left_unit: left.unit,
+                    right_unit: right.unit,
+                }),
+                DataType::Interval,
+            ),
+
+            // Interval - interval
+            (DataType::Interval, DataType::Interval) => {
+                (Box::new(SubIntervalImpl), DataType::Interval)
+            }
+
             (a, b) => return Err(invalid_input_types_error(self, &[a, b])),
         };
 
@@ -194,6 +256,31 @@ impl ScalarFunction for Sub {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct DecimalSubImpl<D> {
+    datatype: DataType,
+    _d: PhantomData<D>,
+}
+
+impl<D> DecimalSubImpl<D> {
+    fn new(datatype: DataType) -> Self {
+        DecimalSubImpl {
+            datatype,
+            _d: PhantomData,
+        }
+    }
+}
+
+impl<D> ScalarFunctionImpl for DecimalSubImpl<D>
+where
+    D: DecimalType,
+    ArrayData2: From<PrimitiveStorage<D::Primitive>>,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        execute_decimal_arith::<D, _>(&self.datatype, inputs, true, "-", |a, b| a.checked_sub(&b))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct SubImpl<S> {
     datatype: DataType,
@@ -212,7 +299,7 @@ impl<S> SubImpl<S> {
 impl<S> ScalarFunctionImpl for SubImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Sub<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
@@ -224,7 +311,137 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| buf.put(&(a - b)))
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<S, S, _, _>(a, b, builder, |a, b, buf| {
+            match a.sub_checked(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(overflow_error(a, "-", b, &self.datatype));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubTimestampIntervalImpl {
+    unit: TimeUnit,
+}
+
+impl ScalarFunctionImpl for SubTimestampIntervalImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: a.datatype().clone(),
+            buffer: PrimitiveBuffer::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalInterval, _, _>(
+            a,
+            b,
+            builder,
+            |ts, interval, buf| {
+                // Subtracting is adding the negated interval.
+                let interval = Interval::new(-interval.months, -interval.days, -interval.nanos);
+                match timestamp_add_interval(self.unit, ts, interval) {
+                    Ok(v) => buf.put(&v),
+                    Err(e) => {
+                        if result.is_ok() {
+                            result = Err(e);
+                        }
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubDateIntervalImpl;
+
+impl ScalarFunctionImpl for SubDateIntervalImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        add_date_interval(inputs[0], inputs[1], true)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubTimestampTimestampImpl {
+    left_unit: TimeUnit,
+    right_unit: TimeUnit,
+}
+
+impl ScalarFunctionImpl for SubTimestampTimestampImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalI64, _, _>(
+            a,
+            b,
+            builder,
+            |a, b, buf| match timestamp_sub_timestamp(self.left_unit, a, self.right_unit, b) {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct SubIntervalImpl;
+
+impl ScalarFunctionImpl for SubIntervalImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalInterval, PhysicalInterval, _, _>(
+            a,
+            b,
+            builder,
+            |a, b, buf| match a.checked_sub(b) {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(RayexecError::new("Interval out of range"));
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
     }
 }
 
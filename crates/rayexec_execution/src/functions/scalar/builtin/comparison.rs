@@ -27,11 +27,13 @@ use crate::arrays::array::physical_type::{
     PhysicalUtf8,
 };
 use crate::arrays::array::{Array, ArrayData2};
+use crate::arrays::compute::collation::Collation;
 use crate::arrays::compute::cast::array::decimal_rescale;
 use crate::arrays::compute::cast::behavior::CastFailBehavior;
 use crate::arrays::datatype::{DataType, DataTypeId, DecimalTypeMeta};
 use crate::arrays::executor::builder::{ArrayBuilder, BooleanBuffer};
 use crate::arrays::executor::scalar::{BinaryExecutor, BinaryListReducer, FlexibleListExecutor};
+use crate::arrays::row::encoding::{ComparableColumn, ComparableRowEncoder};
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
 use crate::arrays::storage::PrimitiveStorage;
 use crate::expr::Expression;
@@ -45,7 +47,7 @@ use crate::logical::binder::table_list::TableList;
 // - Normalize scales for decimals for comparisons (will be needed elsewhere too).
 // - Normalize intervals for comparisons
 
-const fn generate_comparison_sigs(doc: &'static Documentation) -> [Signature; 21] {
+const fn generate_comparison_sigs(doc: &'static Documentation) -> [Signature; 24] {
     [
         Signature {
             positional_args: &[DataTypeId::Boolean, DataTypeId::Boolean],
@@ -155,6 +157,18 @@ const fn generate_comparison_sigs(doc: &'static Documentation) -> [Signature; 21
             return_type: DataTypeId::Boolean,
             doc: Some(doc),
         },
+        Signature {
+            positional_args: &[DataTypeId::Time64, DataTypeId::Time64],
+            variadic_arg: None,
+            return_type: DataTypeId::Boolean,
+            doc: Some(doc),
+        },
+        Signature {
+            positional_args: &[DataTypeId::Uuid, DataTypeId::Uuid],
+            variadic_arg: None,
+            return_type: DataTypeId::Boolean,
+            doc: Some(doc),
+        },
         Signature {
             positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
             variadic_arg: None,
@@ -173,6 +187,12 @@ const fn generate_comparison_sigs(doc: &'static Documentation) -> [Signature; 21
             return_type: DataTypeId::Boolean,
             doc: Some(doc),
         },
+        Signature {
+            positional_args: &[DataTypeId::Struct, DataTypeId::Struct],
+            variadic_arg: None,
+            return_type: DataTypeId::Boolean,
+            doc: Some(doc),
+        },
     ]
 }
 
@@ -566,7 +586,7 @@ fn new_comparison_impl<O: ComparisonOperation>(
                 RescalingComparisionImpl::<O, Decimal128Type>::new(left, right),
             ),
             (DataType::Timestamp(_), DataType::Timestamp(_)) => {
-                Box::new(BaseComparisonImpl::<O, PhysicalBool>::new())
+                Box::new(BaseComparisonImpl::<O, PhysicalI64>::new())
             }
             (DataType::Interval, DataType::Interval) => {
                 Box::new(BaseComparisonImpl::<O, PhysicalInterval>::new())
@@ -577,6 +597,12 @@ fn new_comparison_impl<O: ComparisonOperation>(
             (DataType::Date64, DataType::Date64) => {
                 Box::new(BaseComparisonImpl::<O, PhysicalI64>::new())
             }
+            (DataType::Time64, DataType::Time64) => {
+                Box::new(BaseComparisonImpl::<O, PhysicalI64>::new())
+            }
+            (DataType::Uuid, DataType::Uuid) => {
+                Box::new(BaseComparisonImpl::<O, PhysicalU128>::new())
+            }
             (DataType::Utf8, DataType::Utf8) => {
                 Box::new(BaseComparisonImpl::<O, PhysicalUtf8>::new())
             }
@@ -587,11 +613,63 @@ fn new_comparison_impl<O: ComparisonOperation>(
                 // TODO: We'll want to figure out casting for lists.
                 Box::new(ListComparisonImpl::<O>::new(m1.datatype.physical_type()))
             }
+            (DataType::Struct(m1), DataType::Struct(m2)) if m1 == m2 => {
+                Box::new(StructComparisonImpl::<O>::new())
+            }
             (a, b) => return Err(invalid_input_types_error(func, &[a, b])),
         },
     )
 }
 
+/// Compares structs field by field using their row encodings.
+///
+/// Returns NULL if either struct is NULL.
+#[derive(Debug, Clone)]
+struct StructComparisonImpl<O> {
+    _op: PhantomData<O>,
+}
+
+impl<O> StructComparisonImpl<O> {
+    fn new() -> Self {
+        StructComparisonImpl { _op: PhantomData }
+    }
+}
+
+impl<O> ScalarFunctionImpl for StructComparisonImpl<O>
+where
+    O: ComparisonOperation,
+{
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let left = inputs[0];
+        let right = inputs[1];
+
+        let encoder = ComparableRowEncoder {
+            columns: vec![ComparableColumn {
+                desc: false,
+                nulls_first: false,
+                collation: Collation::BINARY,
+            }],
+        };
+        let left_rows = encoder.encode(&[left])?;
+        let right_rows = encoder.encode(&[right])?;
+
+        let results: Vec<Option<bool>> = (0..left.logical_len())
+            .map(|idx| {
+                let valid = left.is_valid(idx).unwrap_or(false)
+                    && right.is_valid(idx).unwrap_or(false);
+                if !valid {
+                    return None;
+                }
+                let l = left_rows.row(idx).expect("row to exist");
+                let r = right_rows.row(idx).expect("row to exist");
+                Some(O::compare(l, r))
+            })
+            .collect();
+
+        Ok(Array::from_iter(results))
+    }
+}
+
 #[derive(Debug)]
 struct ListComparisonReducer<T, O> {
     left_len: i32,
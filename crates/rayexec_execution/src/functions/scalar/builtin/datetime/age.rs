@@ -0,0 +1,103 @@
+use rayexec_error::Result;
+
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::array::Array;
+use crate::arrays::compute::date::timestamp_age;
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::arrays::scalar::interval::Interval;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Age;
+
+impl FunctionInfo for Age {
+    fn name(&self) -> &'static str {
+        "age"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Timestamp, DataTypeId::Timestamp],
+            variadic_arg: None,
+            return_type: DataTypeId::Interval,
+            doc: Some(&Documentation {
+                category: Category::Date,
+                description: "Subtract the second timestamp from the first, producing a \
+                              symbolic result using years, months, and days.",
+                arguments: &["timestamp", "timestamp"],
+                example: Some(Example {
+                    example: "age(TIMESTAMP '2001-04-10', TIMESTAMP '1957-06-13')",
+                    output: "43 years 9 mons 27 days",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Age {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        match (
+            inputs[0].datatype(table_list)?,
+            inputs[1].datatype(table_list)?,
+        ) {
+            (DataType::Timestamp(left), DataType::Timestamp(right)) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Interval,
+                inputs,
+                function_impl: Box::new(AgeImpl {
+                    left_unit: left.unit,
+                    right_unit: right.unit,
+                }),
+            }),
+            (a, b) => Err(invalid_input_types_error(self, &[a, b])),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct AgeImpl {
+    left_unit: TimeUnit,
+    right_unit: TimeUnit,
+}
+
+impl ScalarFunctionImpl for AgeImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let a = inputs[0];
+        let b = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Interval,
+            buffer: PrimitiveBuffer::<Interval>::with_len(a.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalI64, _, _>(
+            a,
+            b,
+            builder,
+            |a, b, buf| match timestamp_age(self.left_unit, a, self.right_unit, b) {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,135 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::array::Array;
+use crate::arrays::compute::time_zone::{parse_time_zone, TimeZone};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::scalar::UnaryExecutor;
+use crate::expr::Expression;
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+/// Implements `timestamp AT TIME ZONE zone`.
+///
+/// Follows Postgres semantics:
+///
+/// - A timestamp without a time zone is interpreted as wall clock time in the
+///   given zone, producing a timestamp with a time zone.
+/// - A timestamp with a time zone is converted to wall clock time in the given
+///   zone, producing a timestamp without a time zone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AtTimeZone;
+
+impl FunctionInfo for AtTimeZone {
+    fn name(&self) -> &'static str {
+        "timezone"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Timestamp],
+            variadic_arg: None,
+            return_type: DataTypeId::Timestamp,
+            doc: None,
+        }]
+    }
+}
+
+impl ScalarFunction for AtTimeZone {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 2)?;
+
+        // Requires zone to be constant (for now)
+        let zone = ConstFold::rewrite(table_list, inputs[0].clone())?
+            .try_into_scalar()
+            .map_err(|_| RayexecError::new("Time zone must be a constant"))?
+            .try_into_string()?;
+        let zone = parse_time_zone(&zone)?;
+
+        match &datatypes[1] {
+            DataType::Timestamp(m) => {
+                let (return_meta, to_utc) = match m.tz {
+                    // Wall clock -> instant.
+                    None => (
+                        TimestampTypeMeta::new_with_tz(m.unit, zone.to_string()),
+                        true,
+                    ),
+                    // Instant -> wall clock.
+                    Some(_) => (TimestampTypeMeta::new(m.unit), false),
+                };
+
+                let return_type = DataType::Timestamp(return_meta);
+
+                Ok(PlannedScalarFunction {
+                    function: Box::new(*self),
+                    return_type: return_type.clone(),
+                    inputs,
+                    function_impl: Box::new(AtTimeZoneImpl {
+                        return_type,
+                        zone,
+                        unit: m.unit,
+                        to_utc,
+                    }),
+                })
+            }
+            other => Err(invalid_input_types_error(self, &[&datatypes[0], other])),
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AtTimeZoneImpl {
+    return_type: DataType,
+    zone: TimeZone,
+    unit: TimeUnit,
+    /// If we're converting wall clock time to UTC instants, or the other way
+    /// around.
+    to_utc: bool,
+}
+
+impl ScalarFunctionImpl for AtTimeZoneImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[1];
+
+        let builder = ArrayBuilder {
+            datatype: self.return_type.clone(),
+            buffer: PrimitiveBuffer::with_len(input.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = UnaryExecutor::execute2::<PhysicalI64, _, _>(input, builder, |v, buf| {
+            let converted = if self.to_utc {
+                self.zone.local_to_utc(v, self.unit)
+            } else {
+                self.zone.utc_to_local(v, self.unit)
+            };
+            match converted {
+                Some(v) => buf.put(&v),
+                None => {
+                    if result.is_ok() {
+                        result = Err(RayexecError::new(format!(
+                            "Timestamp out of range for time zone {}",
+                            self.zone
+                        )));
+                    }
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
+    }
+}
@@ -0,0 +1,66 @@
+use rayexec_error::Result;
+
+use crate::arrays::datatype::DataTypeId;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::builtin::arith::Add;
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+/// Function form of `date + interval` and `timestamp + interval`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateAdd;
+
+impl FunctionInfo for DateAdd {
+    fn name(&self) -> &'static str {
+        "date_add"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Date,
+            description: "Add an interval to a date or timestamp.",
+            arguments: &["date", "interval"],
+            example: Some(Example {
+                example: "date_add(DATE '2024-01-31', INTERVAL '1 month')",
+                output: "2024-02-29 00:00:00 UTC",
+            }),
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Date32, DataTypeId::Interval],
+                variadic_arg: None,
+                return_type: DataTypeId::Timestamp,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Timestamp, DataTypeId::Interval],
+                variadic_arg: None,
+                return_type: DataTypeId::Timestamp,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl ScalarFunction for DateAdd {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        // Same semantics as the `+` operator, just exposed as a function.
+        let planned = Add.plan(table_list, inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: planned.return_type,
+            inputs: planned.inputs,
+            function_impl: planned.function_impl,
+        })
+    }
+}
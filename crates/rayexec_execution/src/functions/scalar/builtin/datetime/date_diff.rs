@@ -0,0 +1,133 @@
+use rayexec_error::Result;
+use rayexec_parser::ast;
+
+use super::convert_ast_date_part;
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::array::Array;
+use crate::arrays::compute::date::{self, timestamp_date_diff};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
+use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
+use crate::arrays::executor::scalar::BinaryExecutor;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DateDiff;
+
+impl FunctionInfo for DateDiff {
+    fn name(&self) -> &'static str {
+        "date_diff"
+    }
+
+    fn aliases(&self) -> &'static [&'static str] {
+        &["datediff"]
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[
+                DataTypeId::Utf8,
+                DataTypeId::Timestamp,
+                DataTypeId::Timestamp,
+            ],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::Date,
+                description: "Get the number of date part boundaries crossed between \
+                              the start and end timestamps.",
+                arguments: &["part", "start", "end"],
+                example: Some(Example {
+                    example: "date_diff('year', DATE '2023-12-31', DATE '2024-01-01')",
+                    output: "1",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for DateDiff {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let datatypes = inputs
+            .iter()
+            .map(|expr| expr.datatype(table_list))
+            .collect::<Result<Vec<_>>>()?;
+
+        plan_check_num_args(self, &datatypes, 3)?;
+
+        // Requires first argument to be constant (for now)
+        let part = ConstFold::rewrite(table_list, inputs[0].clone())?
+            .try_into_scalar()?
+            .try_into_string()?;
+
+        let part = part.parse::<ast::DatePart>()?;
+        let part = convert_ast_date_part(part);
+
+        match (&datatypes[1], &datatypes[2]) {
+            (DataType::Timestamp(start), DataType::Timestamp(end)) => Ok(PlannedScalarFunction {
+                function: Box::new(*self),
+                return_type: DataType::Int64,
+                function_impl: Box::new(DateDiffImpl {
+                    part,
+                    start_unit: start.unit,
+                    end_unit: end.unit,
+                }),
+                inputs,
+            }),
+            (a, b) => Err(invalid_input_types_error(self, &[a, b])),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DateDiffImpl {
+    part: date::DatePart,
+    start_unit: TimeUnit,
+    end_unit: TimeUnit,
+}
+
+impl ScalarFunctionImpl for DateDiffImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        // First input ignored (the constant "part")
+        let start = inputs[1];
+        let end = inputs[2];
+
+        let builder = ArrayBuilder {
+            datatype: DataType::Int64,
+            buffer: PrimitiveBuffer::<i64>::with_len(start.logical_len()),
+        };
+
+        let mut result = Ok(());
+        let out = BinaryExecutor::execute::<PhysicalI64, PhysicalI64, _, _>(
+            start,
+            end,
+            builder,
+            |start, end, buf| match timestamp_date_diff(
+                self.part,
+                self.start_unit,
+                start,
+                self.end_unit,
+                end,
+            ) {
+                Ok(v) => buf.put(&v),
+                Err(e) => {
+                    if result.is_ok() {
+                        result = Err(e);
+                    }
+                }
+            },
+        )?;
+        result?;
+
+        Ok(out)
+    }
+}
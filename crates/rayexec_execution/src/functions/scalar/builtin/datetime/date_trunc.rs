@@ -4,7 +4,7 @@ use rayexec_error::{not_implemented, RayexecError, Result};
 
 use crate::arrays::array::physical_type::PhysicalI64;
 use crate::arrays::array::Array;
-use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit, TimestampTypeMeta};
+use crate::arrays::datatype::{DataType, DataTypeId, TimeUnit};
 use crate::arrays::executor::builder::{ArrayBuilder, PrimitiveBuffer};
 use crate::arrays::executor::scalar::UnaryExecutor;
 use crate::expr::Expression;
@@ -82,7 +82,7 @@ impl ScalarFunction for DateTrunc {
         match &datatypes[1] {
             DataType::Timestamp(m) => Ok(PlannedScalarFunction {
                 function: Box::new(*self),
-                return_type: DataType::Timestamp(TimestampTypeMeta { unit: m.unit }),
+                return_type: DataType::Timestamp(m.clone()),
                 inputs,
                 function_impl: Box::new(DateTruncImpl {
                     input_unit: m.unit,
@@ -182,9 +182,7 @@ impl ScalarFunctionImpl for DateTruncImpl {
         };
 
         let builder = ArrayBuilder {
-            datatype: DataType::Timestamp(TimestampTypeMeta {
-                unit: self.input_unit,
-            }),
+            datatype: input.datatype().clone(),
             buffer: PrimitiveBuffer::with_len(input.logical_len()),
         };
 
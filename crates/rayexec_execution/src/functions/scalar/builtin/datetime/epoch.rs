@@ -45,9 +45,7 @@ impl ScalarFunction for Epoch {
         match inputs[0].datatype(table_list)? {
             DataType::Int64 => Ok(PlannedScalarFunction {
                 function: Box::new(*self),
-                return_type: DataType::Timestamp(TimestampTypeMeta {
-                    unit: TimeUnit::Microsecond,
-                }),
+                return_type: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
                 inputs,
                 function_impl: Box::new(EpochImpl::<1_000_000>),
             }),
@@ -87,9 +85,7 @@ impl ScalarFunction for EpochMs {
         match inputs[0].datatype(table_list)? {
             DataType::Int64 => Ok(PlannedScalarFunction {
                 function: Box::new(*self),
-                return_type: DataType::Timestamp(TimestampTypeMeta {
-                    unit: TimeUnit::Microsecond,
-                }),
+                return_type: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
                 inputs,
                 function_impl: Box::new(EpochImpl::<1000>),
             }),
@@ -110,9 +106,7 @@ impl<const S: i64> ScalarFunctionImpl for EpochImpl<S> {
 
 fn to_timestamp<const S: i64>(input: &Array) -> Result<Array> {
     let builder = ArrayBuilder {
-        datatype: DataType::Timestamp(TimestampTypeMeta {
-            unit: TimeUnit::Microsecond,
-        }),
+        datatype: DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond)),
         buffer: PrimitiveBuffer::with_len(input.logical_len()),
     };
 
@@ -6,3 +6,15 @@ pub use epoch::*;
 
 mod date_trunc;
 pub use date_trunc::*;
+
+mod at_time_zone;
+pub use at_time_zone::*;
+
+mod age;
+pub use age::*;
+
+mod date_diff;
+pub use date_diff::*;
+
+mod date_add;
+pub use date_add::*;
@@ -97,7 +97,7 @@ impl ScalarFunctionImpl for ListValuesImpl {
                 other => panic!("invalid data type: {other}"),
             };
 
-            let data = ListStorage::empty_list(Array::new_typed_null_array(inner_type.clone(), 1)?);
+            let data = ListStorage::empty_list(Array::new_empty_array(inner_type.clone())?);
             return Ok(Array::new_with_array_data(self.list_datatype.clone(), data));
         }
 
@@ -73,8 +73,11 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(string::RightTrim::new()),
         Box::new(string::BTrim::new()),
         Box::new(string::Like),
+        Box::new(string::Collate),
+        Box::new(string::CollationKey),
         // Struct
         Box::new(struct_funcs::StructPack),
+        Box::new(struct_funcs::StructExtract),
         // Unary
         Box::new(negate::Negate),
         Box::new(negate::Not),
@@ -88,6 +91,10 @@ pub static BUILTIN_SCALAR_FUNCTIONS: LazyLock<Vec<Box<dyn ScalarFunction>>> = La
         Box::new(datetime::DateTrunc),
         Box::new(datetime::EpochMs),
         Box::new(datetime::Epoch),
+        Box::new(datetime::AtTimeZone),
+        Box::new(datetime::Age),
+        Box::new(datetime::DateDiff),
+        Box::new(datetime::DateAdd),
         // Is
         Box::new(is::IsNull),
         Box::new(is::IsNotNull),
@@ -1,7 +1,8 @@
 use std::marker::PhantomData;
 
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 
+use super::arith::CheckedArith;
 use crate::arrays::array::physical_type::{
     PhysicalBool,
     PhysicalF16,
@@ -99,12 +100,10 @@ impl<S> NegateImpl<S> {
 impl<S> ScalarFunctionImpl for NegateImpl<S>
 where
     S: PhysicalStorage,
-    for<'a> S::Type<'a>: std::ops::Neg<Output = S::Type<'static>> + Default + Copy,
+    for<'a> S::Type<'a>: CheckedArith<Output = S::Type<'static>> + Default,
     ArrayData2: From<PrimitiveStorage<S::Type<'static>>>,
 {
     fn execute(&self, inputs: &[&Array]) -> Result<Array> {
-        use std::ops::Neg;
-
         let a = inputs[0];
         let datatype = self.datatype.clone();
         let builder = ArrayBuilder {
@@ -112,7 +111,21 @@ where
             buffer: PrimitiveBuffer::with_len(a.logical_len()),
         };
 
-        UnaryExecutor::execute2::<S, _, _>(a, builder, |a, buf| buf.put(&(a.neg())))
+        let mut result = Ok(());
+        let out = UnaryExecutor::execute2::<S, _, _>(a, builder, |a, buf| match a.neg_checked() {
+            Some(v) => buf.put(&v),
+            None => {
+                if result.is_ok() {
+                    result = Err(RayexecError::new(format!(
+                        "Out of range: -{a} overflows {}",
+                        self.datatype
+                    )));
+                }
+            }
+        })?;
+        result?;
+
+        Ok(out)
     }
 }
 
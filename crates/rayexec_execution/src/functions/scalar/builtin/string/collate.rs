@@ -0,0 +1,181 @@
+use rayexec_error::{RayexecError, Result};
+
+use crate::arrays::array::Array;
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
+
+/// Attach a collation to a string.
+///
+/// `<expr> COLLATE <collation>` expressions are planned using this function.
+/// The string itself is returned unchanged, the collation only affects how the
+/// string is compared, sorted, and grouped. The binder reads the collation off
+/// of the planned expression and applies the collation key where needed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Collate;
+
+impl Collate {
+    /// Get the collation for an already planned `collate` expression, or for a
+    /// column bound from one.
+    ///
+    /// Returns None if the expression isn't a call to this function or a
+    /// reference to a collated column.
+    pub fn collation_for_expr(table_list: &TableList, expr: &Expression) -> Option<Collation> {
+        match expr {
+            Expression::Column(col) => table_list
+                .get(col.table_scope)
+                .ok()?
+                .column_collations
+                .get(&col.column)
+                .copied(),
+            Expression::ScalarFunction(scalar) if scalar.function.function.name() == "collate" => {
+                match &scalar.function.inputs[1] {
+                    Expression::Literal(lit) => {
+                        Collation::from_name(lit.literal.try_as_str().ok()?).ok()
+                    }
+                    _ => None,
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+impl FunctionInfo for Collate {
+    fn name(&self) -> &'static str {
+        "collate"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::String,
+                description: "Attach a collation to a string. The string is returned unchanged, the collation is used when comparing, sorting, or grouping the string.",
+                arguments: &["string", "collation"],
+                example: Some(Example {
+                    example: "collate('Crème', 'nocase.noaccent') = 'CREME'",
+                    output: "true",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for Collate {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        // Validate the collation, the collation is read back off of the
+        // expression during binding.
+        plan_collation(self, table_list, &inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Utf8,
+            inputs,
+            function_impl: Box::new(CollateImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CollateImpl;
+
+impl ScalarFunctionImpl for CollateImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        Ok(inputs[0].clone())
+    }
+}
+
+/// Produce the collation key for a string.
+///
+/// Strings with equal keys compare equal under the collation. Comparisons
+/// involving collated strings are planned to compare the keys of both sides.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CollationKey;
+
+impl FunctionInfo for CollationKey {
+    fn name(&self) -> &'static str {
+        "collation_key"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Utf8, DataTypeId::Utf8],
+            variadic_arg: None,
+            return_type: DataTypeId::Utf8,
+            doc: Some(&Documentation {
+                category: Category::String,
+                description: "Get the key for a string under a collation. Strings with equal keys compare equal under the collation.",
+                arguments: &["string", "collation"],
+                example: Some(Example {
+                    example: "collation_key('Crème', 'nocase.noaccent')",
+                    output: "creme",
+                }),
+            }),
+        }]
+    }
+}
+
+impl ScalarFunction for CollationKey {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedScalarFunction> {
+        let collation = plan_collation(self, table_list, &inputs)?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: DataType::Utf8,
+            inputs,
+            function_impl: Box::new(CollationKeyImpl { collation }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CollationKeyImpl {
+    collation: Collation,
+}
+
+impl ScalarFunctionImpl for CollationKeyImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        self.collation.collate_array(inputs[0])
+    }
+}
+
+/// Check the inputs for a collation function, returning the constant
+/// collation from the second argument.
+fn plan_collation(
+    function: &impl FunctionInfo,
+    table_list: &TableList,
+    inputs: &[Expression],
+) -> Result<Collation> {
+    plan_check_num_args(function, inputs, 2)?;
+    match (
+        inputs[0].datatype(table_list)?,
+        inputs[1].datatype(table_list)?,
+    ) {
+        (DataType::Utf8, DataType::Utf8) => (),
+        (a, b) => return Err(invalid_input_types_error(function, &[a, b])),
+    }
+
+    let name = ConstFold::rewrite(table_list, inputs[1].clone())?
+        .try_into_scalar()
+        .map_err(|_| RayexecError::new("Collation must be constant"))?
+        .try_into_string()?;
+
+    Collation::from_name(&name)
+}
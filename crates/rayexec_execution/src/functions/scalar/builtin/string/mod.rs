@@ -12,6 +12,9 @@ pub use ascii::*;
 mod case;
 pub use case::*;
 
+mod collate;
+pub use collate::*;
+
 mod repeat;
 pub use repeat::*;
 
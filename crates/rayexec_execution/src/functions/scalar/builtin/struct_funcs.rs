@@ -1,13 +1,23 @@
 use std::fmt::Debug;
 
-use rayexec_error::{not_implemented, Result};
+use rayexec_error::{RayexecError, Result};
 
-use crate::arrays::datatype::DataTypeId;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId, StructTypeMeta};
+use crate::arrays::executor::scalar::{concat, struct_child_with_selection};
+use crate::arrays::field::Field;
+use crate::arrays::storage::StructStorage;
 use crate::expr::Expression;
-use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction};
-use crate::functions::{FunctionInfo, Signature};
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::scalar::{PlannedScalarFunction, ScalarFunction, ScalarFunctionImpl};
+use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
+use crate::optimizer::expr_rewrite::const_fold::ConstFold;
+use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
 
+/// Create a struct from alternating field names and values.
+///
+/// Struct literals (`{'a': 1, 'b': 2}`) are planned using this function.
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct StructPack;
 
@@ -18,21 +28,101 @@ impl FunctionInfo for StructPack {
 
     fn signatures(&self) -> &[Signature] {
         &[Signature {
-            positional_args: &[DataTypeId::Struct],
-            variadic_arg: None,
+            positional_args: &[],
+            variadic_arg: Some(DataTypeId::Any),
             return_type: DataTypeId::Struct,
-            doc: None,
+            doc: Some(&Documentation {
+                category: Category::General,
+                description: "Create a struct from alternating field names and values.",
+                arguments: &["var_arg"],
+                example: Some(Example {
+                    example: "struct_pack('lat', 52.5, 'lon', 13.4)",
+                    output: "{lat: 52.5, lon: 13.4}",
+                }),
+            }),
         }]
     }
+
+    fn exact_signature(&self, inputs: &[DataType]) -> Option<&Signature> {
+        // Values may each be of a different type, which the variadic `Any`
+        // signature can't express. Only the field names need checking.
+        let names_valid = inputs.len().is_multiple_of(2)
+            && inputs
+                .iter()
+                .step_by(2)
+                .all(|datatype| datatype == &DataType::Utf8);
+
+        if names_valid {
+            self.signatures().first()
+        } else {
+            None
+        }
+    }
 }
 
 impl ScalarFunction for StructPack {
     fn plan(
         &self,
-        _table_list: &TableList,
-        _inputs: Vec<Expression>,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        not_implemented!("struct pack")
+        if inputs.is_empty() || !inputs.len().is_multiple_of(2) {
+            return Err(RayexecError::new(
+                "struct_pack expects alternating field names and values",
+            ));
+        }
+
+        let mut fields: Vec<Field> = Vec::with_capacity(inputs.len() / 2);
+        for pair in inputs.chunks(2) {
+            // Field names are required to be constant.
+            let name = ConstFold::rewrite(table_list, pair[0].clone())?
+                .try_into_scalar()
+                .map_err(|_| RayexecError::new("Struct field names must be constant"))?
+                .try_into_string()?;
+
+            if fields.iter().any(|f| f.name == name) {
+                return Err(RayexecError::new(format!(
+                    "Duplicate struct field name '{name}'"
+                )));
+            }
+
+            fields.push(Field::new(name, pair[1].datatype(table_list)?, true));
+        }
+
+        let return_type = DataType::Struct(StructTypeMeta { fields });
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: return_type.clone(),
+            inputs,
+            function_impl: Box::new(StructPackImpl {
+                datatype: return_type,
+            }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StructPackImpl {
+    datatype: DataType,
+}
+
+impl ScalarFunctionImpl for StructPackImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let len = inputs.first().map(|arr| arr.logical_len()).unwrap_or(0);
+
+        // Values are every other input, names are skipped.
+        let children: Vec<_> = inputs
+            .iter()
+            .skip(1)
+            .step_by(2)
+            .map(|&arr| arr.clone())
+            .collect();
+
+        Ok(Array::new_with_array_data(
+            self.datatype.clone(),
+            StructStorage::try_new(children, len)?,
+        ))
     }
 }
 
@@ -46,10 +136,18 @@ impl FunctionInfo for StructExtract {
 
     fn signatures(&self) -> &[Signature] {
         &[Signature {
-            positional_args: &[DataTypeId::Struct],
+            positional_args: &[DataTypeId::Struct, DataTypeId::Utf8],
             variadic_arg: None,
             return_type: DataTypeId::Any,
-            doc: None,
+            doc: Some(&Documentation {
+                category: Category::General,
+                description: "Extract a field from a struct.",
+                arguments: &["struct", "field"],
+                example: Some(Example {
+                    example: "struct_extract({'lat': 52.5, 'lon': 13.4}, 'lon')",
+                    output: "13.4",
+                }),
+            }),
         }]
     }
 }
@@ -57,9 +155,65 @@ impl FunctionInfo for StructExtract {
 impl ScalarFunction for StructExtract {
     fn plan(
         &self,
-        _table_list: &TableList,
-        _inputs: Vec<Expression>,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
     ) -> Result<PlannedScalarFunction> {
-        not_implemented!("struct extract")
+        plan_check_num_args(self, &inputs, 2)?;
+
+        let meta = match inputs[0].datatype(table_list)? {
+            DataType::Struct(meta) => meta,
+            other => {
+                return Err(invalid_input_types_error(
+                    self,
+                    &[other, inputs[1].datatype(table_list)?],
+                ))
+            }
+        };
+
+        // Requires field name to be constant.
+        let name = ConstFold::rewrite(table_list, inputs[1].clone())?
+            .try_into_scalar()
+            .map_err(|_| RayexecError::new("Struct field name must be constant"))?
+            .try_into_string()?;
+
+        let (child_idx, field) = meta
+            .fields
+            .iter()
+            .enumerate()
+            .find(|(_, f)| f.name == name)
+            .ok_or_else(|| RayexecError::new(format!("Missing struct field '{name}'")))?;
+
+        Ok(PlannedScalarFunction {
+            function: Box::new(*self),
+            return_type: field.datatype.clone(),
+            inputs,
+            function_impl: Box::new(StructExtractImpl { child_idx }),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct StructExtractImpl {
+    child_idx: usize,
+}
+
+impl ScalarFunctionImpl for StructExtractImpl {
+    fn execute(&self, inputs: &[&Array]) -> Result<Array> {
+        let input = inputs[0];
+        let child = struct_child_with_selection(input, self.child_idx)?;
+
+        if input.validity().is_none() {
+            return Ok(child);
+        }
+
+        // Fields of null structs are null.
+        let mut out = concat(&[&child])?;
+        for idx in 0..input.logical_len() {
+            if !input.is_valid(idx).unwrap_or(false) {
+                out.set_physical_validity(idx, false);
+            }
+        }
+
+        Ok(out)
     }
 }
@@ -29,7 +29,7 @@ use crate::functions::{
     Signature,
 };
 use crate::logical::binder::table_list::TableList;
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::statistics::TableStatistics;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub struct GenerateSeries;
@@ -112,7 +112,7 @@ impl InOutPlanner for GenerateSeriesInOutPlanner {
             positional_inputs,
             named_inputs,
             function_impl: TableFunctionImpl::InOut(Box::new(GenerateSeriesInOutImpl)),
-            cardinality: StatisticsValue::Unknown,
+            statistics: TableStatistics::unknown(),
             schema: Schema::new([Field::new("generate_series", DataType::Int64, false)]),
         })
     }
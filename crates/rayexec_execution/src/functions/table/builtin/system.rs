@@ -28,7 +28,8 @@ use crate::functions::table::{
     TableFunctionPlanner,
 };
 use crate::functions::{FunctionInfo, Signature};
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::scan_filter::ScanFilter;
+use crate::logical::statistics::TableStatistics;
 use crate::storage::table_storage::{
     DataTable,
     DataTableScan,
@@ -41,6 +42,7 @@ pub trait SystemFunctionImpl: Debug + Sync + Send + Copy + 'static {
     const NAME: &'static str;
     fn schema() -> Schema;
     fn new_batch(
+        tx: &CatalogTx,
         databases: &mut VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     ) -> Result<Batch>;
 }
@@ -61,6 +63,7 @@ impl SystemFunctionImpl for ListDatabasesImpl {
     }
 
     fn new_batch(
+        _tx: &CatalogTx,
         databases: &mut VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     ) -> Result<Batch> {
         let len = databases.len();
@@ -117,6 +120,7 @@ impl SystemFunctionImpl for ListFunctionsImpl {
     }
 
     fn new_batch(
+        tx: &CatalogTx,
         databases: &mut VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     ) -> Result<Batch> {
         let database = databases.pop_front().required("database")?;
@@ -144,8 +148,6 @@ impl SystemFunctionImpl for ListFunctionsImpl {
         let mut example_outputs_validity = Bitmap::default();
         let mut example_outputs = GermanVarlenStorage::with_metadata_capacity(0);
 
-        let tx = &CatalogTx {};
-
         database.1.for_each_schema(tx, &mut |schema_name, schema| {
             schema.for_each_entry(tx, &mut |_, entry| {
                 let (sigs, function_type) = match &entry.entry {
@@ -155,6 +157,9 @@ impl SystemFunctionImpl for ListFunctionsImpl {
                     CatalogEntryInner::AggregateFunction(func) => {
                         (func.function.signatures(), "aggregate")
                     }
+                    CatalogEntryInner::WindowFunction(func) => {
+                        (func.function.signatures(), "window")
+                    }
                     CatalogEntryInner::TableFunction(func) => (func.function.signatures(), "table"),
                     _ => return Ok(()),
                 };
@@ -283,6 +288,7 @@ impl SystemFunctionImpl for ListTablesImpl {
     }
 
     fn new_batch(
+        tx: &CatalogTx,
         databases: &mut VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     ) -> Result<Batch> {
         let database = databases.pop_front().required("database")?;
@@ -291,8 +297,6 @@ impl SystemFunctionImpl for ListTablesImpl {
         let mut schema_names = GermanVarlenStorage::with_metadata_capacity(0);
         let mut table_names = GermanVarlenStorage::with_metadata_capacity(0);
 
-        let tx = &CatalogTx {};
-
         database.1.for_each_schema(tx, &mut |schema_name, schema| {
             schema.for_each_entry(tx, &mut |_, entry| {
                 if entry.entry_type() != CatalogEntryType::Table {
@@ -332,6 +336,7 @@ impl SystemFunctionImpl for ListSchemasImpl {
     }
 
     fn new_batch(
+        tx: &CatalogTx,
         databases: &mut VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     ) -> Result<Batch> {
         let database = databases.pop_front().required("database")?;
@@ -339,8 +344,6 @@ impl SystemFunctionImpl for ListSchemasImpl {
         let mut database_names = GermanVarlenStorage::with_metadata_capacity(0);
         let mut schema_names = GermanVarlenStorage::with_metadata_capacity(0);
 
-        let tx = &CatalogTx {};
-
         database.1.for_each_schema(tx, &mut |schema_name, _| {
             database_names.try_push(database.0.as_bytes())?;
             schema_names.try_push(schema_name.as_bytes())?;
@@ -418,10 +421,11 @@ where
             positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
             named_inputs,
             function_impl: TableFunctionImpl::Scan(Arc::new(SystemDataTable::<F> {
+                tx: context.catalog_tx().clone(),
                 databases: Arc::new(Mutex::new(Some(databases))),
                 _f: PhantomData,
             })),
-            cardinality: StatisticsValue::Unknown,
+            statistics: TableStatistics::unknown(),
             schema: F::schema(),
         };
 
@@ -431,6 +435,7 @@ where
 
 #[derive(Debug, Clone)]
 struct SystemDataTable<F: SystemFunctionImpl> {
+    tx: CatalogTx,
     #[allow(clippy::type_complexity)] // Temp
     databases: Arc<Mutex<Option<VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>>>>,
     _f: PhantomData<F>,
@@ -440,6 +445,7 @@ impl<F: SystemFunctionImpl> DataTable for SystemDataTable<F> {
     fn scan(
         &self,
         projections: Projections,
+        _filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let databases = self
@@ -450,6 +456,7 @@ impl<F: SystemFunctionImpl> DataTable for SystemDataTable<F> {
 
         let mut scans: Vec<Box<dyn DataTableScan>> = vec![Box::new(ProjectedScan::new(
             SystemDataTableScan::<F> {
+                tx: self.tx.clone(),
                 databases,
                 _f: PhantomData,
             },
@@ -464,6 +471,7 @@ impl<F: SystemFunctionImpl> DataTable for SystemDataTable<F> {
 
 #[derive(Debug)]
 struct SystemDataTableScan<F: SystemFunctionImpl> {
+    tx: CatalogTx,
     databases: VecDeque<(String, Arc<MemoryCatalog>, Option<AttachInfo>)>,
     _f: PhantomData<F>,
 }
@@ -475,7 +483,7 @@ impl<F: SystemFunctionImpl> DataTableScan for SystemDataTableScan<F> {
                 return Ok(None);
             }
 
-            let batch = F::new_batch(&mut self.databases)?;
+            let batch = F::new_batch(&self.tx, &mut self.databases)?;
 
             Ok(Some(batch))
         })
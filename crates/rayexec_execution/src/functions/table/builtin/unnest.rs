@@ -24,7 +24,7 @@ use crate::functions::table::{
 };
 use crate::functions::{invalid_input_types_error, plan_check_num_args, FunctionInfo, Signature};
 use crate::logical::binder::table_list::TableList;
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::statistics::TableStatistics;
 
 #[derive(Debug, Clone, Copy)]
 pub struct Unnest;
@@ -94,7 +94,7 @@ impl InOutPlanner for Unnest {
             positional_inputs,
             named_inputs,
             function_impl: TableFunctionImpl::InOut(Box::new(UnnestInOutImpl)),
-            cardinality: StatisticsValue::Unknown,
+            statistics: TableStatistics::unknown(),
             schema,
         })
     }
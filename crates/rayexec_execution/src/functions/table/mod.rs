@@ -12,6 +12,7 @@ use rayexec_error::{RayexecError, Result};
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::s3::credentials::AwsCredentials;
 use rayexec_io::s3::S3Location;
+use rayexec_io::{glob, FileProvider};
 
 use super::FunctionInfo;
 use crate::arrays::field::Schema;
@@ -19,7 +20,7 @@ use crate::arrays::scalar::OwnedScalarValue;
 use crate::database::DatabaseContext;
 use crate::expr::Expression;
 use crate::logical::binder::table_list::TableList;
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::statistics::TableStatistics;
 use crate::storage::table_storage::DataTable;
 
 /// A generic table function provides a way to dispatch to a more specialized
@@ -101,8 +102,8 @@ pub struct PlannedTableFunction {
     /// The variant used here should match the variant of the planner that
     /// `function` returns from its `planner` method.
     pub function_impl: TableFunctionImpl,
-    /// Output cardinality of the function.
-    pub cardinality: StatisticsValue<usize>,
+    /// Statistics for the function's output, including output cardinality.
+    pub statistics: TableStatistics,
     /// Output schema of the function.
     pub schema: Schema,
 }
@@ -175,6 +176,29 @@ pub fn try_location_and_access_config_from_args(
     Ok((loc, conf))
 }
 
+/// Try to get the locations of all files matching the location in the table
+/// args, along with the access config.
+///
+/// Glob patterns in the location are expanded by listing files with the
+/// provider.
+pub async fn try_locations_and_access_config_from_args<P>(
+    func: &impl TableFunction,
+    positional: &[OwnedScalarValue],
+    named: &HashMap<String, OwnedScalarValue>,
+    provider: &P,
+) -> Result<(Vec<FileLocation>, AccessConfig)>
+where
+    P: FileProvider + ?Sized,
+{
+    let (_, conf) = try_location_and_access_config_from_args(func, positional, named)?;
+    // Location parsed above, we need the raw string here since parsing as a
+    // url may mangle glob characters.
+    let location = try_get_positional(func, 0, positional)?.try_as_str()?;
+    let locations = glob::expand_locations(provider, location, &conf).await?;
+
+    Ok((locations, conf))
+}
+
 pub fn try_get_named<'a>(
     func: &impl TableFunction,
     name: &str,
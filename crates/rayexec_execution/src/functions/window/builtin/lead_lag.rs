@@ -0,0 +1,202 @@
+use rayexec_error::Result;
+
+use super::i64_values;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::arrays::executor::scalar::interleave;
+use crate::expr::cast_expr::CastExpr;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::window::{
+    PlannedWindowFunction,
+    WindowFunction,
+    WindowFunctionImpl,
+    WindowRowBounds,
+};
+use crate::functions::{plan_check_num_args_one_of, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lag;
+
+impl FunctionInfo for Lag {
+    fn name(&self) -> &'static str {
+        "lag"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Window,
+            description: "Value of the row that is 'offset' rows before the current row within the partition. Returns 'default' (or NULL) if there's no such row.",
+            arguments: &["value", "offset", "default"],
+            example: None,
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Any],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Any, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Any, DataTypeId::Int64, DataTypeId::Any],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl WindowFunction for Lag {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_lead_lag(self, table_list, inputs, true)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Lead;
+
+impl FunctionInfo for Lead {
+    fn name(&self) -> &'static str {
+        "lead"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        const DOC: &Documentation = &Documentation {
+            category: Category::Window,
+            description: "Value of the row that is 'offset' rows after the current row within the partition. Returns 'default' (or NULL) if there's no such row.",
+            arguments: &["value", "offset", "default"],
+            example: None,
+        };
+
+        &[
+            Signature {
+                positional_args: &[DataTypeId::Any],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Any, DataTypeId::Int64],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+            Signature {
+                positional_args: &[DataTypeId::Any, DataTypeId::Int64, DataTypeId::Any],
+                variadic_arg: None,
+                return_type: DataTypeId::Any,
+                doc: Some(DOC),
+            },
+        ]
+    }
+}
+
+impl WindowFunction for Lead {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_lead_lag(self, table_list, inputs, false)
+    }
+}
+
+fn plan_lead_lag<F>(
+    func: &F,
+    table_list: &TableList,
+    mut inputs: Vec<Expression>,
+    is_lag: bool,
+) -> Result<PlannedWindowFunction>
+where
+    F: WindowFunction + Clone + 'static,
+{
+    plan_check_num_args_one_of(func, &inputs, [1, 2, 3])?;
+
+    let datatype = inputs[0].datatype(table_list)?;
+
+    // Ensure the default value matches the type of the value we're returning.
+    if inputs.len() == 3 && inputs[2].datatype(table_list)? != datatype {
+        let default = inputs.pop().expect("default input to exist");
+        inputs.push(Expression::Cast(CastExpr {
+            to: datatype.clone(),
+            expr: Box::new(default),
+        }));
+    }
+
+    Ok(PlannedWindowFunction {
+        function: Box::new(func.clone()),
+        return_type: datatype.clone(),
+        inputs,
+        function_impl: Box::new(LeadLagImpl { datatype, is_lag }),
+    })
+}
+
+#[derive(Debug, Clone)]
+pub struct LeadLagImpl {
+    datatype: DataType,
+    /// If we're looking at preceding rows (lag) or following rows (lead).
+    is_lag: bool,
+}
+
+impl WindowFunctionImpl for LeadLagImpl {
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let offsets = match inputs.get(1) {
+            Some(offsets) => i64_values(offsets)?,
+            None => vec![Some(1); bounds.len()],
+        };
+
+        // Arrays we're interleaving from:
+        // 0 => The values.
+        // 1 => The default values, or NULL if no default provided.
+        // 2 => NULL, used for NULL offsets.
+        let nulls = Array::new_typed_null_array(self.datatype.clone(), 1)?;
+        let default = inputs.get(2).copied();
+        let arrays = [inputs[0], default.unwrap_or(&nulls), &nulls];
+
+        let indices: Vec<_> = bounds
+            .iter()
+            .zip(offsets)
+            .enumerate()
+            .map(|(idx, (bounds, offset))| {
+                let offset = match offset {
+                    Some(offset) => offset,
+                    None => return (2, 0),
+                };
+
+                let target = if self.is_lag {
+                    (idx as i64).checked_sub(offset)
+                } else {
+                    (idx as i64).checked_add(offset)
+                };
+
+                match target {
+                    Some(target)
+                        if target >= bounds.partition.start as i64
+                            && target < bounds.partition.end as i64 =>
+                    {
+                        (0, target as usize)
+                    }
+                    _ => match default {
+                        Some(_) => (1, idx),
+                        None => (1, 0),
+                    },
+                }
+            })
+            .collect();
+
+        interleave(&arrays, &indices)
+    }
+}
@@ -0,0 +1,55 @@
+pub mod lead_lag;
+pub mod ntile;
+pub mod rank;
+pub mod row_number;
+pub mod value;
+
+use std::sync::LazyLock;
+
+use rayexec_error::Result;
+
+use super::WindowFunction;
+use crate::arrays::array::physical_type::PhysicalI64;
+use crate::arrays::array::Array;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+
+pub static BUILTIN_WINDOW_FUNCTIONS: LazyLock<Vec<Box<dyn WindowFunction>>> = LazyLock::new(|| {
+    vec![
+        Box::new(row_number::RowNumber),
+        Box::new(rank::Rank),
+        Box::new(rank::DenseRank),
+        Box::new(rank::PercentRank),
+        Box::new(rank::CumeDist),
+        Box::new(ntile::Ntile),
+        Box::new(lead_lag::Lag),
+        Box::new(lead_lag::Lead),
+        Box::new(value::FirstValue),
+        Box::new(value::LastValue),
+        Box::new(value::NthValue),
+    ]
+});
+
+/// Read an Int64 input array into a vector of optional values.
+fn i64_values(array: &Array) -> Result<Vec<Option<i64>>> {
+    let mut values = vec![None; array.logical_len()];
+    UnaryExecutor::for_each2::<PhysicalI64, _>(array, |idx, val| {
+        values[idx] = val;
+    })?;
+    Ok(values)
+}
+
+/// Produce an array by taking rows from `input`.
+///
+/// Rows that are None will be NULL in the output.
+fn take_rows_or_null(input: &Array, rows: &[Option<usize>]) -> Result<Array> {
+    let nulls = Array::new_typed_null_array(input.datatype().clone(), 1)?;
+    let indices: Vec<_> = rows
+        .iter()
+        .map(|row| match row {
+            Some(row) => (0, *row),
+            None => (1, 0),
+        })
+        .collect();
+
+    interleave(&[input, &nulls], &indices)
+}
@@ -0,0 +1,99 @@
+use rayexec_error::{RayexecError, Result};
+
+use super::i64_values;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::window::{
+    PlannedWindowFunction,
+    WindowFunction,
+    WindowFunctionImpl,
+    WindowRowBounds,
+};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Ntile;
+
+impl FunctionInfo for Ntile {
+    fn name(&self) -> &'static str {
+        "ntile"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Int64],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Divide the partition into as equal as possible buckets, returning the bucket number for the current row.",
+                arguments: &["num_buckets"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for Ntile {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Int64,
+            inputs,
+            function_impl: Box::new(NtileImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NtileImpl;
+
+impl WindowFunctionImpl for NtileImpl {
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let num_buckets = i64_values(inputs[0])?;
+
+        let buckets = bounds
+            .iter()
+            .zip(num_buckets)
+            .enumerate()
+            .map(|(idx, (bounds, num_buckets))| {
+                let num_buckets = match num_buckets {
+                    Some(n) if n <= 0 => {
+                        return Err(RayexecError::new(
+                            "Argument to 'ntile' must be greater than zero",
+                        ))
+                    }
+                    Some(n) => n as usize,
+                    None => return Ok(None),
+                };
+
+                let num_rows = bounds.partition.len();
+                let row = idx - bounds.partition.start;
+
+                // The first `extra` buckets get one more row than the rest.
+                let base = num_rows / num_buckets;
+                let extra = num_rows % num_buckets;
+                let large_rows = extra * (base + 1);
+
+                let bucket = if row < large_rows {
+                    row / (base + 1)
+                } else {
+                    (row - large_rows) / base + extra
+                };
+
+                Ok(Some(bucket as i64 + 1))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(Array::from_iter(buckets))
+    }
+}
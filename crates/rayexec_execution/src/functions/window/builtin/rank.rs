@@ -0,0 +1,235 @@
+use rayexec_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::window::{
+    PlannedWindowFunction,
+    WindowFunction,
+    WindowFunctionImpl,
+    WindowRowBounds,
+};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Rank;
+
+impl FunctionInfo for Rank {
+    fn name(&self) -> &'static str {
+        "rank"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Rank of the current row with gaps. Peers receive the same rank.",
+                arguments: &[],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for Rank {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Int64,
+            inputs,
+            function_impl: Box::new(RankImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RankImpl;
+
+impl WindowFunctionImpl for RankImpl {
+    fn evaluate(&self, _inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        Ok(Array::from_iter(bounds.iter().map(|bounds| {
+            (bounds.peers.start - bounds.partition.start + 1) as i64
+        })))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DenseRank;
+
+impl FunctionInfo for DenseRank {
+    fn name(&self) -> &'static str {
+        "dense_rank"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Rank of the current row without gaps. Peers receive the same rank.",
+                arguments: &[],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for DenseRank {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Int64,
+            inputs,
+            function_impl: Box::new(DenseRankImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct DenseRankImpl;
+
+impl WindowFunctionImpl for DenseRankImpl {
+    fn evaluate(&self, _inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let mut rank: i64 = 0;
+        let ranks = bounds.iter().enumerate().map(|(idx, bounds)| {
+            if idx == bounds.partition.start {
+                // New partition, reset rank.
+                rank = 1;
+            } else if idx == bounds.peers.start {
+                // New peer group.
+                rank += 1;
+            }
+            rank
+        });
+
+        Ok(Array::from_iter(ranks))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PercentRank;
+
+impl FunctionInfo for PercentRank {
+    fn name(&self) -> &'static str {
+        "percent_rank"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Float64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Relative rank of the current row: (rank - 1) / (partition rows - 1).",
+                arguments: &[],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for PercentRank {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Float64,
+            inputs,
+            function_impl: Box::new(PercentRankImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct PercentRankImpl;
+
+impl WindowFunctionImpl for PercentRankImpl {
+    fn evaluate(&self, _inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        Ok(Array::from_iter(bounds.iter().map(|bounds| {
+            let num_rows = bounds.partition.len();
+            if num_rows <= 1 {
+                return 0.0;
+            }
+            let rank = bounds.peers.start - bounds.partition.start;
+            rank as f64 / (num_rows - 1) as f64
+        })))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CumeDist;
+
+impl FunctionInfo for CumeDist {
+    fn name(&self) -> &'static str {
+        "cume_dist"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Float64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Cumulative distribution: (rows preceding or peer with current row) / (partition rows).",
+                arguments: &[],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for CumeDist {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Float64,
+            inputs,
+            function_impl: Box::new(CumeDistImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct CumeDistImpl;
+
+impl WindowFunctionImpl for CumeDistImpl {
+    fn evaluate(&self, _inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        Ok(Array::from_iter(bounds.iter().map(|bounds| {
+            let num_rows = bounds.partition.len();
+            let preceding = bounds.peers.end - bounds.partition.start;
+            preceding as f64 / num_rows as f64
+        })))
+    }
+}
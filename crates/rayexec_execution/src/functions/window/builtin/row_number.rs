@@ -0,0 +1,68 @@
+use rayexec_error::Result;
+
+use crate::arrays::array::Array;
+use crate::arrays::datatype::{DataType, DataTypeId};
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation, Example};
+use crate::functions::window::{
+    PlannedWindowFunction,
+    WindowFunction,
+    WindowFunctionImpl,
+    WindowRowBounds,
+};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RowNumber;
+
+impl FunctionInfo for RowNumber {
+    fn name(&self) -> &'static str {
+        "row_number"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[],
+            variadic_arg: None,
+            return_type: DataTypeId::Int64,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Number of the current row within its partition, counting from 1.",
+                arguments: &[],
+                example: Some(Example {
+                    example: "row_number() OVER (ORDER BY a)",
+                    output: "1",
+                }),
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for RowNumber {
+    fn plan(
+        &self,
+        _table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 0)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: DataType::Int64,
+            inputs,
+            function_impl: Box::new(RowNumberImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct RowNumberImpl;
+
+impl WindowFunctionImpl for RowNumberImpl {
+    fn evaluate(&self, _inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        Ok(Array::from_iter(bounds.iter().enumerate().map(
+            |(idx, bounds)| (idx - bounds.partition.start + 1) as i64,
+        )))
+    }
+}
@@ -0,0 +1,192 @@
+use rayexec_error::{RayexecError, Result};
+
+use super::{i64_values, take_rows_or_null};
+use crate::arrays::array::Array;
+use crate::arrays::datatype::DataTypeId;
+use crate::expr::Expression;
+use crate::functions::documentation::{Category, Documentation};
+use crate::functions::window::{
+    PlannedWindowFunction,
+    WindowFunction,
+    WindowFunctionImpl,
+    WindowRowBounds,
+};
+use crate::functions::{plan_check_num_args, FunctionInfo, Signature};
+use crate::logical::binder::table_list::TableList;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FirstValue;
+
+impl FunctionInfo for FirstValue {
+    fn name(&self) -> &'static str {
+        "first_value"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Value of the first row in the window frame.",
+                arguments: &["value"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for FirstValue {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: inputs[0].datatype(table_list)?,
+            inputs,
+            function_impl: Box::new(FirstValueImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct FirstValueImpl;
+
+impl WindowFunctionImpl for FirstValueImpl {
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let rows: Vec<_> = bounds.iter().map(|bounds| bounds.frame_row(0)).collect();
+
+        take_rows_or_null(inputs[0], &rows)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LastValue;
+
+impl FunctionInfo for LastValue {
+    fn name(&self) -> &'static str {
+        "last_value"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Any],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Value of the last row in the window frame.",
+                arguments: &["value"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for LastValue {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 1)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: inputs[0].datatype(table_list)?,
+            inputs,
+            function_impl: Box::new(LastValueImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct LastValueImpl;
+
+impl WindowFunctionImpl for LastValueImpl {
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let rows: Vec<_> = bounds
+            .iter()
+            .map(|bounds| match bounds.frame_len() {
+                0 => None,
+                len => bounds.frame_row(len - 1),
+            })
+            .collect();
+
+        take_rows_or_null(inputs[0], &rows)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NthValue;
+
+impl FunctionInfo for NthValue {
+    fn name(&self) -> &'static str {
+        "nth_value"
+    }
+
+    fn signatures(&self) -> &[Signature] {
+        &[Signature {
+            positional_args: &[DataTypeId::Any, DataTypeId::Int64],
+            variadic_arg: None,
+            return_type: DataTypeId::Any,
+            doc: Some(&Documentation {
+                category: Category::Window,
+                description: "Value of the nth row in the window frame, counting from 1. Returns NULL if there's no such row.",
+                arguments: &["value", "n"],
+                example: None,
+            }),
+        }]
+    }
+}
+
+impl WindowFunction for NthValue {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction> {
+        plan_check_num_args(self, &inputs, 2)?;
+
+        Ok(PlannedWindowFunction {
+            function: Box::new(*self),
+            return_type: inputs[0].datatype(table_list)?,
+            inputs,
+            function_impl: Box::new(NthValueImpl),
+        })
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct NthValueImpl;
+
+impl WindowFunctionImpl for NthValueImpl {
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array> {
+        let ns = i64_values(inputs[1])?;
+
+        let rows = bounds
+            .iter()
+            .zip(ns)
+            .map(|(bounds, n)| {
+                let n = match n {
+                    Some(n) if n <= 0 => {
+                        return Err(RayexecError::new(
+                            "Argument to 'nth_value' must be greater than zero",
+                        ))
+                    }
+                    Some(n) => n as usize,
+                    None => return Ok(None),
+                };
+
+                Ok(bounds.frame_row(n - 1))
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        take_rows_or_null(inputs[0], &rows)
+    }
+}
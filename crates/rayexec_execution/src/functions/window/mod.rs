@@ -0,0 +1,178 @@
+pub mod builtin;
+
+use std::fmt::Debug;
+use std::hash::Hash;
+use std::ops::Range;
+
+use dyn_clone::DynClone;
+use rayexec_error::Result;
+
+use super::FunctionInfo;
+use crate::arrays::array::Array;
+use crate::arrays::datatype::DataType;
+use crate::expr::Expression;
+use crate::logical::binder::table_list::TableList;
+
+/// A generic window function that can be specialized into a more specific
+/// function depending on type.
+///
+/// Window functions are functions that can only be used with an OVER clause
+/// (e.g. `row_number`, `lag`). Aggregate functions used with OVER are handled
+/// separately.
+pub trait WindowFunction: FunctionInfo + Debug + Sync + Send + DynClone {
+    fn plan(
+        &self,
+        table_list: &TableList,
+        inputs: Vec<Expression>,
+    ) -> Result<PlannedWindowFunction>;
+}
+
+impl Clone for Box<dyn WindowFunction> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+impl PartialEq<dyn WindowFunction> for Box<dyn WindowFunction + '_> {
+    fn eq(&self, other: &dyn WindowFunction) -> bool {
+        self.as_ref() == other
+    }
+}
+
+impl PartialEq for dyn WindowFunction + '_ {
+    fn eq(&self, other: &dyn WindowFunction) -> bool {
+        self.name() == other.name() && self.signatures() == other.signatures()
+    }
+}
+
+impl Eq for dyn WindowFunction {}
+
+#[derive(Debug, Clone)]
+pub struct PlannedWindowFunction {
+    pub function: Box<dyn WindowFunction>,
+    pub return_type: DataType,
+    pub inputs: Vec<Expression>,
+    pub function_impl: Box<dyn WindowFunctionImpl>,
+}
+
+/// Assumes that a function with same inputs and return type is using the same
+/// function implementation.
+impl PartialEq for PlannedWindowFunction {
+    fn eq(&self, other: &Self) -> bool {
+        self.function == other.function
+            && self.return_type == other.return_type
+            && self.inputs == other.inputs
+    }
+}
+
+impl Eq for PlannedWindowFunction {}
+
+impl Hash for PlannedWindowFunction {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.function.name().hash(state);
+        self.return_type.hash(state);
+        self.inputs.hash(state);
+    }
+}
+
+pub trait WindowFunctionImpl: Debug + Sync + Send + DynClone {
+    /// Compute the output of the function for every row in the input.
+    ///
+    /// Input arrays have already been sorted by partition and ordering keys.
+    /// `bounds` contains an entry for every row in the input, and the output
+    /// array should be the same length.
+    fn evaluate(&self, inputs: &[&Array], bounds: &[WindowRowBounds]) -> Result<Array>;
+}
+
+impl Clone for Box<dyn WindowFunctionImpl> {
+    fn clone(&self) -> Self {
+        dyn_clone::clone_box(&**self)
+    }
+}
+
+/// Boundaries for a single row in the sorted input to a window function.
+///
+/// All ranges are absolute row indices into the input arrays.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WindowRowBounds {
+    /// Rows in the same partition as this row.
+    pub partition: Range<usize>,
+    /// Rows that are peers with this row according to the window's ORDER BY.
+    ///
+    /// If there's no ORDER BY, all rows in the partition are peers.
+    pub peers: Range<usize>,
+    /// Rows in the window frame for this row, before any exclusions.
+    pub frame: Range<usize>,
+    /// Rows within the frame that are excluded by the frame's EXCLUDE clause.
+    ///
+    /// Always a subrange of `frame`, and empty (positioned at the end of the
+    /// frame) if nothing is excluded.
+    pub exclude: Range<usize>,
+    /// A row inside of `exclude` that remains part of the frame.
+    ///
+    /// Used for EXCLUDE TIES where the current row is kept.
+    pub include: Option<usize>,
+}
+
+impl WindowRowBounds {
+    /// Create bounds for a row, clamping the excluded rows to the frame.
+    pub fn new(
+        partition: Range<usize>,
+        peers: Range<usize>,
+        frame: Range<usize>,
+        exclude: Range<usize>,
+        include: Option<usize>,
+    ) -> Self {
+        let exclude_start = usize::max(exclude.start, frame.start);
+        let exclude_end = usize::min(exclude.end, frame.end);
+
+        let (exclude, include) = if exclude_start < exclude_end {
+            let include = include.filter(|row| (exclude_start..exclude_end).contains(row));
+            (exclude_start..exclude_end, include)
+        } else {
+            (frame.end..frame.end, None)
+        };
+
+        WindowRowBounds {
+            partition,
+            peers,
+            frame,
+            exclude,
+            include,
+        }
+    }
+
+    /// Iterate the non-empty ranges of rows making up the frame after
+    /// exclusions, in order.
+    pub fn frame_ranges(&self) -> impl Iterator<Item = Range<usize>> {
+        let include = match self.include {
+            Some(row) => row..(row + 1),
+            None => self.exclude.end..self.exclude.end,
+        };
+
+        [
+            self.frame.start..self.exclude.start,
+            include,
+            self.exclude.end..self.frame.end,
+        ]
+        .into_iter()
+        .filter(|range| !range.is_empty())
+    }
+
+    /// Number of rows in the frame after exclusions.
+    pub fn frame_len(&self) -> usize {
+        self.frame_ranges().map(|range| range.len()).sum()
+    }
+
+    /// Get the row index for the nth (zero-based) row in the frame after
+    /// exclusions.
+    pub fn frame_row(&self, mut n: usize) -> Option<usize> {
+        for range in self.frame_ranges() {
+            if n < range.len() {
+                return Some(range.start + n);
+            }
+            n -= range.len();
+        }
+        None
+    }
+}
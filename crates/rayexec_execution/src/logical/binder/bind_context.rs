@@ -2,12 +2,19 @@ use std::collections::{HashMap, HashSet};
 use std::fmt;
 
 use rayexec_error::{RayexecError, Result};
+use rayexec_parser::ast;
 
 use super::bind_query::BoundQuery;
 use super::table_list::{Table, TableAlias, TableList, TableRef};
+use crate::arrays::compute::cast::behavior::OverflowBehavior;
+use crate::arrays::compute::collation::Collation;
 use crate::arrays::datatype::DataType;
-use crate::expr::Expression;
+use crate::arrays::scalar::OwnedScalarValue;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::parameter_expr::ParameterExpr;
+use crate::expr::{cast, lit, Expression};
 use crate::logical::operator::{LogicalNode, LogicalOperator};
+use crate::logical::resolver::ResolvedMeta;
 
 /// Reference to a child bind scope.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -66,6 +73,16 @@ pub struct BindContext {
     ///
     /// Referenced via `MaterializationRef`.
     materializations: Vec<PlanMaterialization>,
+    /// Values for positional parameters (`$1`, `?`) in the statement.
+    ///
+    /// None if we're binding a prepared statement to infer the parameter
+    /// types.
+    parameters: Option<Vec<OwnedScalarValue>>,
+    /// Types for positional parameters, either declared or inferred from how
+    /// the parameter is used.
+    parameter_types: Vec<Option<DataType>>,
+    /// How integer arithmetic should handle overflow.
+    overflow_behavior: OverflowBehavior,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
@@ -96,16 +113,25 @@ pub struct BoundCte {
     /// If `materialized` is false and this is None, we need to plan the bound
     /// query first.
     pub mat_ref: Option<MaterializationRef>,
+    /// If this CTE is the working table of a recursive CTE.
+    ///
+    /// The working table is only in scope when binding the recursive term, and
+    /// references to it read the rows produced by the previous iteration
+    /// instead of the bound query.
+    pub working_table: bool,
+    /// Number of times the working table was referenced.
+    pub working_table_refs: usize,
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct UsingColumn {
     /// Normalized column name.
     pub column: String,
-    /// A reference to one of the tables used in the USING condition.
-    pub table_ref: TableRef,
-    /// Column index inside the table.
-    pub col_idx: usize,
+    /// Expression that unqualified references to the column bind to.
+    ///
+    /// This is a column from one of the tables used in the USING condition,
+    /// or the coalesced left and right columns for FULL joins.
+    pub expr: Expression,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -122,6 +148,10 @@ struct BindScope {
     tables: Vec<TableRef>,
     /// CTEs in scope. Keyed by normalized CTE name.
     ctes: HashMap<String, CteRef>,
+    /// Named windows from a WINDOW clause. Keyed by normalized window name.
+    ///
+    /// Definitions referencing other windows have already been expanded.
+    windows: HashMap<String, ast::WindowDefinition<ResolvedMeta>>,
 }
 
 /// A node in the logical plan that will be materialized to allow for multiple
@@ -153,13 +183,94 @@ impl BindContext {
                 correlated_columns: Vec::new(),
                 using_columns: Vec::new(),
                 ctes: HashMap::new(),
+                windows: HashMap::new(),
             }],
             tables: TableList::empty(),
             ctes: Vec::new(),
             materializations: Vec::new(),
+            parameters: Some(Vec::new()),
+            parameter_types: Vec::new(),
+            overflow_behavior: OverflowBehavior::Error,
+        }
+    }
+
+    /// Set the values and known types to use for positional parameters.
+    ///
+    /// If `parameters` is None, parameters will be bound as placeholders and
+    /// their types inferred.
+    pub fn set_parameters(
+        &mut self,
+        parameters: Option<Vec<OwnedScalarValue>>,
+        types: Vec<Option<DataType>>,
+    ) {
+        self.parameters = parameters;
+        self.parameter_types = types;
+    }
+
+    /// Get the types of the positional parameters.
+    ///
+    /// When inferring types, contains an entry for every parameter
+    /// encountered, with None for parameters whose type couldn't be inferred.
+    pub fn parameter_types(&self) -> &[Option<DataType>] {
+        &self.parameter_types
+    }
+
+    /// Bind a 1-based positional parameter.
+    ///
+    /// Produces a literal for the parameter's value, cast to the parameter's
+    /// type if known. Produces a placeholder if we're inferring types.
+    pub fn bind_parameter(&mut self, n: usize) -> Result<Expression> {
+        let idx = n
+            .checked_sub(1)
+            .ok_or_else(|| RayexecError::new("Parameter numbers start at $1"))?;
+        let datatype = self.parameter_types.get(idx).cloned().flatten();
+
+        let expr = match &self.parameters {
+            Some(values) => {
+                let value = values.get(idx).ok_or_else(|| {
+                    RayexecError::new(format!("Missing value for parameter ${n}"))
+                })?;
+                lit(value.clone())
+            }
+            None => {
+                if self.parameter_types.len() < n {
+                    self.parameter_types.resize(n, None);
+                }
+                Expression::Parameter(ParameterExpr { number: n })
+            }
+        };
+
+        // Values are expected to already be the right type, but NULLs and
+        // placeholders still need a cast.
+        match datatype {
+            Some(datatype) if datatype != expr.datatype(self.get_table_list())? => {
+                Ok(cast(expr, datatype))
+            }
+            _ => Ok(expr),
+        }
+    }
+
+    /// Records the type a parameter placeholder is being cast to.
+    ///
+    /// Does nothing if `expr` isn't a placeholder. The first type recorded for
+    /// a parameter is kept.
+    pub fn infer_parameter_type(&mut self, expr: &Expression, datatype: &DataType) {
+        if let Expression::Parameter(param) = expr {
+            if let Some(slot @ None) = self.parameter_types.get_mut(param.number - 1) {
+                *slot = Some(datatype.clone());
+            }
         }
     }
 
+    /// Set how integer arithmetic should handle overflow.
+    pub fn set_overflow_behavior(&mut self, behavior: OverflowBehavior) {
+        self.overflow_behavior = behavior;
+    }
+
+    pub fn overflow_behavior(&self) -> OverflowBehavior {
+        self.overflow_behavior
+    }
+
     pub fn root_scope_ref(&self) -> BindScopeRef {
         BindScopeRef { context_idx: 0 }
     }
@@ -180,6 +291,7 @@ impl BindContext {
             correlated_columns: Vec::new(),
             using_columns: Vec::new(),
             ctes: HashMap::new(),
+            windows: HashMap::new(),
         });
 
         BindScopeRef { context_idx: idx }
@@ -195,6 +307,7 @@ impl BindContext {
             correlated_columns: Vec::new(),
             using_columns: Vec::new(),
             ctes: HashMap::new(),
+            windows: HashMap::new(),
         });
 
         BindScopeRef { context_idx: idx }
@@ -254,6 +367,40 @@ impl BindContext {
             .ok_or_else(|| RayexecError::new(format!("Missing CTE for ref: {cte_ref}")))
     }
 
+    /// Adds a named window definition to the current scope.
+    pub fn add_named_window(
+        &mut self,
+        current: BindScopeRef,
+        name: String,
+        definition: ast::WindowDefinition<ResolvedMeta>,
+    ) -> Result<()> {
+        let scope = self.get_scope_mut(current)?;
+        if scope.windows.contains_key(&name) {
+            return Err(RayexecError::new(format!(
+                "Window '{name}' is already defined"
+            )));
+        }
+
+        scope.windows.insert(name, definition);
+
+        Ok(())
+    }
+
+    /// Get a named window definition.
+    ///
+    /// Only the current scope is searched. Windows defined in an outer query
+    /// cannot be referenced.
+    pub fn get_named_window(
+        &self,
+        current: BindScopeRef,
+        name: &str,
+    ) -> Result<&ast::WindowDefinition<ResolvedMeta>> {
+        self.get_scope(current)?
+            .windows
+            .get(name)
+            .ok_or_else(|| RayexecError::new(format!("Window '{name}' does not exist")))
+    }
+
     /// Adds a plan for materialization to the bind context.
     ///
     /// Scan count for the materialization is initially set to 0.
@@ -439,6 +586,7 @@ impl BindContext {
             alias: None,
             column_types,
             column_names,
+            column_collations: HashMap::new(),
         };
         self.tables.tables.push(scope);
 
@@ -472,6 +620,7 @@ impl BindContext {
             alias: None,
             column_types: table.column_types.clone(),
             column_names: table.column_names.clone(),
+            column_collations: table.column_collations.clone(),
         });
 
         Ok(reference)
@@ -503,6 +652,21 @@ impl BindContext {
         Ok(idx)
     }
 
+    /// Set the collation for a column in a table.
+    ///
+    /// Expressions referencing the column will be compared, sorted, and grouped
+    /// using this collation.
+    pub fn set_column_collation(
+        &mut self,
+        table: TableRef,
+        col_idx: usize,
+        collation: Collation,
+    ) -> Result<()> {
+        let table = self.get_table_mut(table)?;
+        table.column_collations.insert(col_idx, collation);
+        Ok(())
+    }
+
     pub fn get_column(&self, table_ref: TableRef, col_idx: usize) -> Result<(&str, &DataType)> {
         self.tables.get_column(table_ref, col_idx)
     }
@@ -571,10 +735,37 @@ impl BindContext {
         Ok(())
     }
 
+    /// Tries to find an expression for an unqualified column name.
+    ///
+    /// This first searches any USING columns, then proceeds to search all
+    /// tables in this scope. Outer scopes are not searched.
+    pub fn find_expression_for_column(
+        &self,
+        current: BindScopeRef,
+        column: &str,
+    ) -> Result<Option<Expression>> {
+        let using = self
+            .get_using_columns(current)?
+            .iter()
+            .find(|&using| using.column == column);
+        if let Some(using) = using {
+            return Ok(Some(using.expr.clone()));
+        }
+
+        let found = self.find_table_for_column(current, None, column)?;
+
+        Ok(found.map(|(table_ref, col_idx)| {
+            Expression::Column(ColumnExpr {
+                table_scope: table_ref,
+                column: col_idx,
+            })
+        }))
+    }
+
     /// Tries to find the the scope that has a matching column name.
     ///
-    /// This first searches any USING columns if `alias` is None, then proceeds
-    /// to search all tables in this scope. Outer scopes are not searched.
+    /// This searches all tables in this scope, ignoring USING columns. Outer
+    /// scopes are not searched.
     ///
     /// Returns the table reference containing the column, and the relative
     /// index of the column within that table.
@@ -584,16 +775,6 @@ impl BindContext {
         alias: Option<&TableAlias>,
         column: &str,
     ) -> Result<Option<(TableRef, usize)>> {
-        if alias.is_none() {
-            let using = self
-                .get_using_columns(current)?
-                .iter()
-                .find(|&using| using.column == column);
-            if let Some(using) = using {
-                return Ok(Some((using.table_ref, using.col_idx)));
-            }
-        }
-
         let mut found = None;
 
         for table in self.iter_tables_in_scope(current)? {
@@ -641,6 +822,15 @@ impl BindContext {
         Ok(())
     }
 
+    /// Replaces any USING columns with the same name in the current scope
+    /// with the given column.
+    pub fn replace_using_column(&mut self, current: BindScopeRef, col: UsingColumn) -> Result<()> {
+        let scope = self.get_scope_mut(current)?;
+        scope.using_columns.retain(|c| c.column != col.column);
+        scope.using_columns.push(col);
+        Ok(())
+    }
+
     pub fn get_using_columns(&self, current: BindScopeRef) -> Result<&[UsingColumn]> {
         let scope = self.get_scope(current)?;
         Ok(&scope.using_columns)
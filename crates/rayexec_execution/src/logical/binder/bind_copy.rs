@@ -25,6 +25,8 @@ pub struct BoundCopyTo {
     pub source_schema: Schema,
     pub location: FileLocation,
     pub copy_to: Box<dyn CopyToFunction>,
+    /// Indices of columns in the source to partition the output by.
+    pub partition_by: Vec<usize>,
 }
 
 #[derive(Debug)]
@@ -83,6 +85,20 @@ impl<'a> CopyBinder<'a> {
             },
         ));
 
+        let partition_by = copy_to
+            .options
+            .try_get_partition_by()?
+            .into_iter()
+            .map(|name| {
+                source_schema
+                    .iter()
+                    .position(|f| f.name == name)
+                    .ok_or_else(|| {
+                        RayexecError::new(format!("Missing column for PARTITION_BY: '{name}'"))
+                    })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
         let resolved_copy_to = self
             .resolve_context
             .copy_to
@@ -95,6 +111,7 @@ impl<'a> CopyBinder<'a> {
             source_schema,
             location: copy_to.target,
             copy_to: resolved_copy_to.func,
+            partition_by,
         })
     }
 }
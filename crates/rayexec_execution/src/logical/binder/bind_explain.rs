@@ -16,6 +16,7 @@ pub struct BoundExplain {
     pub format: ExplainFormat,
     pub verbose: bool,
     pub analyze: bool,
+    pub timing: bool,
 }
 
 #[derive(Debug)]
@@ -67,6 +68,7 @@ impl<'a> ExplainBinder<'a> {
             format,
             verbose: explain.verbose,
             analyze: explain.analyze,
+            timing: explain.timing,
         })
     }
 }
@@ -89,6 +89,15 @@ impl<'a> InsertBinder<'a> {
             .iter()
             .map(|c| &c.datatype);
 
+        // Parameters inserted directly take the type of the column.
+        if let BoundQuery::Values(values) = &bound_query {
+            for row in &values.rows {
+                for (expr, datatype) in row.iter().zip(table_types.clone()) {
+                    bind_context.infer_parameter_type(expr, datatype);
+                }
+            }
+        }
+
         // Types from the source plan.
         let source_types: Vec<(TableRef, usize, &DataType)> = bind_context
             .iter_tables_in_scope(source_scope)?
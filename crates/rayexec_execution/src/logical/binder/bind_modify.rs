@@ -0,0 +1,923 @@
+use rayexec_error::{RayexecError, Result};
+use rayexec_parser::ast;
+
+use super::bind_context::{BindContext, BindScopeRef};
+use super::bind_query::bind_from::{
+    BoundFrom,
+    BoundFromItem,
+    BoundJoin,
+    BoundSubquery,
+    FromBinder,
+};
+use super::bind_query::bind_select::BoundSelect;
+use super::bind_query::select_list::BoundSelectList;
+use super::bind_query::{BoundQuery, QueryBinder};
+use super::column_binder::DefaultColumnBinder;
+use super::expr_binder::{BaseExpressionBinder, RecursionContext};
+use super::table_list::{TableAlias, TableRef};
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::case_expr::{CaseExpr, WhenThen};
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
+use crate::expr::{and, cast, col_ref, eq, lit, Expression};
+use crate::functions::scalar::builtin::is;
+use crate::functions::scalar::ScalarFunction;
+use crate::logical::logical_join::JoinType;
+use crate::logical::logical_modify::{InsertConflict, MergeAction};
+use crate::logical::operator::LocationRequirement;
+use crate::logical::resolver::resolve_context::{ResolveContext, ResolveListIdx};
+use crate::logical::resolver::resolved_table::{
+    ResolvedTableOrCteReference,
+    ResolvedTableReference,
+};
+use crate::logical::resolver::ResolvedMeta;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundUpdate {
+    /// Table we're updating.
+    pub table: ResolvedTableReference,
+    /// Location of the table being updated.
+    pub table_location: LocationRequirement,
+    /// Scan of the table being updated, joined with any tables from the FROM
+    /// clause.
+    pub source: BoundFrom,
+    /// Expression for WHERE.
+    pub filter: Option<Expression>,
+    /// Projections producing the input to the update.
+    ///
+    /// The first projection is the row id, followed by the new values for
+    /// every column in the table.
+    pub projections: Vec<Expression>,
+    pub projection_table: TableRef,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundDelete {
+    /// Table we're deleting from.
+    pub table: ResolvedTableReference,
+    /// Location of the table being deleted from.
+    pub table_location: LocationRequirement,
+    /// Scan of the table being deleted from, joined with any tables from the
+    /// USING clause.
+    pub source: BoundFrom,
+    /// Expression for WHERE.
+    pub filter: Option<Expression>,
+    /// Projection for the row id of the rows to delete.
+    pub projections: Vec<Expression>,
+    pub projection_table: TableRef,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct BoundMerge {
+    /// Table we're merging into.
+    pub table: ResolvedTableReference,
+    /// Location of the table being merged into.
+    pub table_location: LocationRequirement,
+    /// Source rows left joined with the table being merged into.
+    pub source: BoundFrom,
+    /// Projections producing the input to the merge.
+    ///
+    /// The first projection is the index of the action to apply to the row,
+    /// or NULL if the row should be skipped. The second projection is the row
+    /// id of the matched row in the table, followed by the values needed by
+    /// each action.
+    pub projections: Vec<Expression>,
+    pub projection_table: TableRef,
+    /// Actions that can be applied to rows.
+    pub actions: Vec<MergeAction>,
+    /// Set when binding an INSERT ... ON CONFLICT.
+    pub insert_conflict: Option<InsertConflict>,
+}
+
+/// A WHEN clause for a merge with all expressions bound.
+#[derive(Debug)]
+struct MergeClauseExprs {
+    matched: bool,
+    condition: Option<Expression>,
+    action: MergeClauseAction,
+}
+
+#[derive(Debug)]
+enum MergeClauseAction {
+    /// Insert a new row, containing values for every column in the table.
+    Insert(Vec<Expression>),
+    /// Update the matched row, containing values for every column in the table.
+    Update(Vec<Expression>),
+    Delete,
+    DoNothing,
+}
+
+/// Binds UPDATE, DELETE, and MERGE statements.
+///
+/// All statements scan the target table with row ids, optionally joining in
+/// additional tables, and filter down to the rows being modified.
+///
+/// INSERT ... ON CONFLICT is bound as a merge with the rows being inserted
+/// acting as the source.
+#[derive(Debug)]
+pub struct ModifyBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> ModifyBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        ModifyBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    pub fn bind_update(
+        &self,
+        bind_context: &mut BindContext,
+        update: ast::Update<ResolvedMeta>,
+    ) -> Result<BoundUpdate> {
+        bind_context.push_table(
+            self.current,
+            None,
+            vec![DataType::UInt64],
+            vec!["rows_updated".to_string()],
+        )?;
+
+        let (reference, location) = self.get_table(update.table)?;
+
+        let source_scope = bind_context.new_child_scope(self.current);
+        let (source, target_ref) = self.bind_source(
+            bind_context,
+            source_scope,
+            update.table,
+            update.alias,
+            update.from,
+        )?;
+
+        let filter = self.bind_filter(bind_context, source_scope, update.where_expr)?;
+
+        let columns = &reference.entry.try_as_table_entry()?.columns;
+
+        // Row id first, then the values for each column.
+        let mut projections = Vec::with_capacity(columns.len() + 1);
+        projections.push(col_ref(target_ref, columns.len()));
+        projections.extend(self.bind_assignments(
+            bind_context,
+            source_scope,
+            &reference,
+            target_ref,
+            update.assignments,
+        )?);
+
+        let projection_table = self.new_projection_table(bind_context, &projections)?;
+
+        Ok(BoundUpdate {
+            table: reference,
+            table_location: location,
+            source,
+            filter,
+            projections,
+            projection_table,
+        })
+    }
+
+    pub fn bind_delete(
+        &self,
+        bind_context: &mut BindContext,
+        delete: ast::Delete<ResolvedMeta>,
+    ) -> Result<BoundDelete> {
+        bind_context.push_table(
+            self.current,
+            None,
+            vec![DataType::UInt64],
+            vec!["rows_deleted".to_string()],
+        )?;
+
+        let (reference, location) = self.get_table(delete.table)?;
+
+        let source_scope = bind_context.new_child_scope(self.current);
+        let (source, target_ref) = self.bind_source(
+            bind_context,
+            source_scope,
+            delete.table,
+            delete.alias,
+            delete.using,
+        )?;
+
+        let filter = self.bind_filter(bind_context, source_scope, delete.where_expr)?;
+
+        let num_columns = reference.entry.try_as_table_entry()?.columns.len();
+        let projections = vec![Expression::Column(ColumnExpr {
+            table_scope: target_ref,
+            column: num_columns,
+        })];
+        let projection_table = self.new_projection_table(bind_context, &projections)?;
+
+        Ok(BoundDelete {
+            table: reference,
+            table_location: location,
+            source,
+            filter,
+            projections,
+            projection_table,
+        })
+    }
+
+    pub fn bind_merge(
+        &self,
+        bind_context: &mut BindContext,
+        merge: ast::Merge<ResolvedMeta>,
+    ) -> Result<BoundMerge> {
+        bind_context.push_table(
+            self.current,
+            None,
+            vec![DataType::UInt64],
+            vec!["rows_merged".to_string()],
+        )?;
+
+        let (reference, location) = self.get_table(merge.table)?;
+        let columns = &reference.entry.try_as_table_entry()?.columns;
+
+        let source_scope = bind_context.new_child_scope(self.current);
+        let left_idx = bind_context.new_child_scope(source_scope);
+        let left = FromBinder::new(left_idx, self.resolve_context)
+            .bind(bind_context, Some(merge.source))?;
+
+        let (source, target_ref) = self.bind_merge_source(
+            bind_context,
+            source_scope,
+            left_idx,
+            left,
+            merge.table,
+            merge.alias,
+            |bind_context, _| {
+                BaseExpressionBinder::new(source_scope, self.resolve_context).bind_expression(
+                    bind_context,
+                    &merge.condition,
+                    &mut DefaultColumnBinder,
+                    RecursionContext {
+                        allow_windows: false,
+                        allow_aggregates: false,
+                        is_root: true,
+                    },
+                )
+            },
+        )?;
+
+        let mut clauses = Vec::with_capacity(merge.clauses.len());
+        for clause in merge.clauses {
+            let condition = self.bind_filter(bind_context, source_scope, clause.condition)?;
+
+            let action = match clause.action {
+                ast::MergeAction::Update { assignments } => {
+                    MergeClauseAction::Update(self.bind_assignments(
+                        bind_context,
+                        source_scope,
+                        &reference,
+                        target_ref,
+                        assignments,
+                    )?)
+                }
+                ast::MergeAction::Delete => MergeClauseAction::Delete,
+                ast::MergeAction::Insert {
+                    columns: insert_columns,
+                    values,
+                } => {
+                    // Map each provided value to the column it's being inserted
+                    // into. Inserting by position if no columns provided.
+                    let col_indices = if insert_columns.is_empty() {
+                        (0..columns.len()).collect()
+                    } else {
+                        let mut col_indices = Vec::with_capacity(insert_columns.len());
+                        for column in insert_columns {
+                            let name = column.into_normalized_string();
+                            let col_idx = self.find_column(&reference, &name)?;
+                            if col_indices.contains(&col_idx) {
+                                return Err(RayexecError::new(format!(
+                                    "Column '{name}' specified more than once"
+                                )));
+                            }
+                            col_indices.push(col_idx);
+                        }
+                        col_indices
+                    };
+
+                    if col_indices.len() != values.len() {
+                        return Err(RayexecError::new(format!(
+                            "Invalid number of inputs. Expected {}, got {}",
+                            col_indices.len(),
+                            values.len(),
+                        )));
+                    }
+
+                    let values = BaseExpressionBinder::new(source_scope, self.resolve_context)
+                        .bind_expressions(
+                            bind_context,
+                            &values,
+                            &mut DefaultColumnBinder,
+                            RecursionContext {
+                                allow_windows: false,
+                                allow_aggregates: false,
+                                is_root: true,
+                            },
+                        )?;
+
+                    // Columns not provided get NULL.
+                    let mut row: Vec<_> = columns
+                        .iter()
+                        .map(|c| cast(lit(ScalarValue::Null), c.datatype.clone()))
+                        .collect();
+                    for (col_idx, value) in col_indices.into_iter().zip(values) {
+                        row[col_idx] =
+                            Self::cast_if_needed(bind_context, value, &columns[col_idx].datatype)?;
+                    }
+
+                    MergeClauseAction::Insert(row)
+                }
+                ast::MergeAction::DoNothing => MergeClauseAction::DoNothing,
+            };
+
+            clauses.push(MergeClauseExprs {
+                matched: clause.matched,
+                condition,
+                action,
+            });
+        }
+
+        let (projections, actions) =
+            Self::merge_projections(bind_context, target_ref, columns.len(), clauses)?;
+        let projection_table = self.new_projection_table(bind_context, &projections)?;
+
+        Ok(BoundMerge {
+            table: reference,
+            table_location: location,
+            source,
+            projections,
+            projection_table,
+            actions,
+            insert_conflict: None,
+        })
+    }
+
+    /// Bind an INSERT with an ON CONFLICT clause.
+    ///
+    /// Rows being inserted are available through the `excluded` table, and
+    /// are joined with existing rows in the table on the conflict columns.
+    /// Rows without a conflict are inserted, while rows with a conflict follow
+    /// the ON CONFLICT action.
+    ///
+    /// Without a conflict target, a row conflicts with rows that are equal in
+    /// every column. Only DO NOTHING is allowed without a target.
+    ///
+    /// Conflicts between rows inserted by the same statement are checked
+    /// during execution. DO NOTHING keeps the first of the conflicting rows,
+    /// while DO UPDATE errors.
+    pub fn bind_insert_on_conflict(
+        &self,
+        bind_context: &mut BindContext,
+        insert: ast::Insert<ResolvedMeta>,
+    ) -> Result<BoundMerge> {
+        bind_context.push_table(
+            self.current,
+            None,
+            vec![DataType::UInt64],
+            vec!["rows_inserted".to_string()],
+        )?;
+
+        let on_conflict = insert
+            .on_conflict
+            .ok_or_else(|| RayexecError::new("Missing ON CONFLICT clause"))?;
+
+        let (reference, location) = self.get_table(insert.table)?;
+        let columns = &reference.entry.try_as_table_entry()?.columns;
+
+        // Map each source column to the table column it's being inserted
+        // into. Inserting by position if no columns provided.
+        let col_indices = if insert.columns.is_empty() {
+            (0..columns.len()).collect()
+        } else {
+            let mut col_indices = Vec::with_capacity(insert.columns.len());
+            for column in insert.columns {
+                let name = column.into_normalized_string();
+                let col_idx = self.find_column(&reference, &name)?;
+                if col_indices.contains(&col_idx) {
+                    return Err(RayexecError::new(format!(
+                        "Column '{name}' specified more than once"
+                    )));
+                }
+                col_indices.push(col_idx);
+            }
+            col_indices
+        };
+
+        // Bind the rows being inserted.
+        let query_scope = bind_context.new_orphan_scope();
+        let query = QueryBinder::new(query_scope, self.resolve_context)
+            .bind(bind_context, insert.source)?;
+
+        let source_types: Vec<_> = bind_context
+            .iter_tables_in_scope(query_scope)?
+            .flat_map(|t| t.column_types.iter().cloned())
+            .collect();
+
+        if source_types.len() != col_indices.len() {
+            return Err(RayexecError::new(format!(
+                "Invalid number of inputs. Expected {}, got {}",
+                col_indices.len(),
+                source_types.len(),
+            )));
+        }
+
+        // Project the source rows into a row for the table, casting to the
+        // column types, with columns not provided getting NULL.
+        let raw_scope = bind_context.new_orphan_scope();
+        let raw_ref = bind_context.push_table(
+            raw_scope,
+            None,
+            source_types,
+            (0..col_indices.len())
+                .map(|idx| format!("__generated_insert_source_{idx}"))
+                .collect(),
+        )?;
+
+        let mut row: Vec<_> = columns
+            .iter()
+            .map(|c| cast(lit(ScalarValue::Null), c.datatype.clone()))
+            .collect();
+        for (source_idx, &col_idx) in col_indices.iter().enumerate() {
+            row[col_idx] = Self::cast_if_needed(
+                bind_context,
+                col_ref(raw_ref, source_idx),
+                &columns[col_idx].datatype,
+            )?;
+        }
+
+        let projections_table = self.new_projection_table(bind_context, &row)?;
+        let select = BoundSelect {
+            select_list: BoundSelectList {
+                output: None,
+                projections_table,
+                projections: row,
+                aggregates_table: bind_context.new_ephemeral_table()?,
+                aggregates: Vec::new(),
+                windows_table: bind_context.new_ephemeral_table()?,
+                windows: Vec::new(),
+                grouping_functions_table: bind_context.new_ephemeral_table()?,
+                grouping_functions: Vec::new(),
+            },
+            from: BoundFrom {
+                bind_ref: raw_scope,
+                item: BoundFromItem::Subquery(BoundSubquery {
+                    table_ref: raw_ref,
+                    subquery: Box::new(query),
+                }),
+            },
+            filter: None,
+            having: None,
+            group_by: None,
+            order_by: None,
+            limit: None,
+            groupings: Vec::new(),
+        };
+
+        // Expose the rows being inserted as `excluded`, using the column names
+        // from the table.
+        let source_scope = bind_context.new_child_scope(self.current);
+        let left_idx = bind_context.new_child_scope(source_scope);
+        let excluded_ref = bind_context.push_table(
+            left_idx,
+            Some(TableAlias {
+                database: None,
+                schema: None,
+                table: "excluded".to_string(),
+            }),
+            columns.iter().map(|c| c.datatype.clone()).collect(),
+            columns.iter().map(|c| c.name.clone()).collect(),
+        )?;
+        let left = BoundFrom {
+            bind_ref: left_idx,
+            item: BoundFromItem::Subquery(BoundSubquery {
+                table_ref: excluded_ref,
+                subquery: Box::new(BoundQuery::Select(select)),
+            }),
+        };
+
+        let excluded: Vec<_> = (0..columns.len())
+            .map(|col_idx| col_ref(excluded_ref, col_idx))
+            .collect();
+
+        let conflict_indices = if on_conflict.columns.is_empty() {
+            if matches!(on_conflict.action, ast::OnConflictAction::DoUpdate { .. }) {
+                return Err(RayexecError::new(
+                    "ON CONFLICT DO UPDATE requires a conflict target",
+                ));
+            }
+            (0..columns.len()).collect()
+        } else {
+            let mut conflict_indices = Vec::with_capacity(on_conflict.columns.len());
+            for column in on_conflict.columns {
+                conflict_indices
+                    .push(self.find_column(&reference, &column.into_normalized_string())?);
+            }
+            conflict_indices
+        };
+
+        let (source, target_ref) = self.bind_merge_source(
+            bind_context,
+            source_scope,
+            left_idx,
+            left,
+            insert.table,
+            None,
+            |_, target_ref| {
+                let conditions = conflict_indices
+                    .iter()
+                    .map(|&col_idx| eq(col_ref(target_ref, col_idx), excluded[col_idx].clone()));
+                and(conditions).ok_or_else(|| RayexecError::new("Missing columns for ON CONFLICT"))
+            },
+        )?;
+
+        let mut clauses = Vec::with_capacity(2);
+        let error_on_conflict = match on_conflict.action {
+            ast::OnConflictAction::DoNothing => {
+                clauses.push(MergeClauseExprs {
+                    matched: true,
+                    condition: None,
+                    action: MergeClauseAction::DoNothing,
+                });
+                false
+            }
+            ast::OnConflictAction::DoUpdate {
+                assignments,
+                where_expr,
+            } => {
+                let condition = self.bind_filter(bind_context, source_scope, where_expr)?;
+                let values = self.bind_assignments(
+                    bind_context,
+                    source_scope,
+                    &reference,
+                    target_ref,
+                    assignments,
+                )?;
+                clauses.push(MergeClauseExprs {
+                    matched: true,
+                    condition,
+                    action: MergeClauseAction::Update(values),
+                });
+                true
+            }
+        };
+        clauses.push(MergeClauseExprs {
+            matched: false,
+            condition: None,
+            action: MergeClauseAction::Insert(excluded),
+        });
+
+        let (projections, actions) =
+            Self::merge_projections(bind_context, target_ref, columns.len(), clauses)?;
+        let projection_table = self.new_projection_table(bind_context, &projections)?;
+
+        Ok(BoundMerge {
+            table: reference,
+            table_location: location,
+            source,
+            projections,
+            projection_table,
+            actions,
+            insert_conflict: Some(InsertConflict {
+                columns: conflict_indices,
+                error_on_conflict,
+            }),
+        })
+    }
+
+    fn get_table(
+        &self,
+        table: ResolveListIdx,
+    ) -> Result<(ResolvedTableReference, LocationRequirement)> {
+        match self.resolve_context.tables.try_get_bound(table)? {
+            (ResolvedTableOrCteReference::Table(reference), location) => {
+                Ok((reference.clone(), location))
+            }
+            (ResolvedTableOrCteReference::Cte(name), _) => {
+                Err(RayexecError::new(format!("Cannot modify CTE '{name}'")))
+            }
+        }
+    }
+
+    /// Bind the table being modified along with any additional tables.
+    ///
+    /// Additional tables are cross joined with the table being modified, with
+    /// the WHERE clause providing the join condition.
+    ///
+    /// Returns the bound source and the table ref for the table being
+    /// modified.
+    fn bind_source(
+        &self,
+        bind_context: &mut BindContext,
+        source_scope: BindScopeRef,
+        table: ResolveListIdx,
+        alias: Option<ast::Ident>,
+        from: Option<ast::FromNode<ResolvedMeta>>,
+    ) -> Result<(BoundFrom, TableRef)> {
+        let table = ast::FromBaseTable { reference: table };
+        let alias = alias.map(|alias| ast::FromAlias {
+            alias,
+            columns: None,
+        });
+
+        let from = match from {
+            Some(from) => from,
+            None => {
+                let target = FromBinder::new(source_scope, self.resolve_context)
+                    .bind_table_with_row_ids(bind_context, table, alias)?;
+                let target_ref = Self::base_table_ref(&target)?;
+                return Ok((target, target_ref));
+            }
+        };
+
+        let left_idx = bind_context.new_child_scope(source_scope);
+        let left = FromBinder::new(left_idx, self.resolve_context).bind_table_with_row_ids(
+            bind_context,
+            table,
+            alias,
+        )?;
+        let target_ref = Self::base_table_ref(&left)?;
+
+        let right_idx = bind_context.new_child_scope(left_idx);
+        let right =
+            FromBinder::new(right_idx, self.resolve_context).bind(bind_context, Some(from))?;
+
+        let lateral_columns: Vec<_> = bind_context
+            .correlated_columns(right_idx)?
+            .iter()
+            .filter(|c| c.outer == left_idx)
+            .cloned()
+            .collect();
+
+        bind_context.append_context(source_scope, left_idx)?;
+        bind_context.append_context(source_scope, right_idx)?;
+
+        let source = BoundFrom {
+            bind_ref: source_scope,
+            item: BoundFromItem::Join(BoundJoin {
+                left_bind_ref: left_idx,
+                left: Box::new(left),
+                right_bind_ref: right_idx,
+                right: Box::new(right),
+                join_type: JoinType::Inner,
+                conditions: Vec::new(),
+                lateral_columns,
+            }),
+        };
+
+        Ok((source, target_ref))
+    }
+
+    /// Bind the SET assignments for an update.
+    ///
+    /// Returns the new values for every column in the table, casting to the
+    /// column type if needed. Columns not being assigned keep their existing
+    /// value.
+    fn bind_assignments(
+        &self,
+        bind_context: &mut BindContext,
+        source_scope: BindScopeRef,
+        reference: &ResolvedTableReference,
+        target_ref: TableRef,
+        assignments: Vec<ast::Assignment<ResolvedMeta>>,
+    ) -> Result<Vec<Expression>> {
+        let columns = &reference.entry.try_as_table_entry()?.columns;
+
+        let mut values: Vec<Option<Expression>> = vec![None; columns.len()];
+        for assignment in assignments {
+            let name = assignment.column.into_normalized_string();
+            let col_idx = self.find_column(reference, &name)?;
+
+            if values[col_idx].is_some() {
+                return Err(RayexecError::new(format!(
+                    "Multiple assignments to column '{name}'"
+                )));
+            }
+
+            let expr = BaseExpressionBinder::new(source_scope, self.resolve_context)
+                .bind_expression(
+                    bind_context,
+                    &assignment.expr,
+                    &mut DefaultColumnBinder,
+                    RecursionContext {
+                        allow_windows: false,
+                        allow_aggregates: false,
+                        is_root: true,
+                    },
+                )?;
+
+            values[col_idx] = Some(Self::cast_if_needed(
+                bind_context,
+                expr,
+                &columns[col_idx].datatype,
+            )?);
+        }
+
+        Ok(values
+            .into_iter()
+            .enumerate()
+            .map(|(col_idx, value)| value.unwrap_or_else(|| col_ref(target_ref, col_idx)))
+            .collect())
+    }
+
+    fn find_column(&self, reference: &ResolvedTableReference, name: &str) -> Result<usize> {
+        reference
+            .entry
+            .try_as_table_entry()?
+            .columns
+            .iter()
+            .position(|c| c.name == name)
+            .ok_or_else(|| {
+                RayexecError::new(format!(
+                    "Column '{name}' does not exist in table '{}'",
+                    reference.entry.name
+                ))
+            })
+    }
+
+    fn cast_if_needed(
+        bind_context: &mut BindContext,
+        expr: Expression,
+        datatype: &DataType,
+    ) -> Result<Expression> {
+        if &expr.datatype(bind_context.get_table_list())? != datatype {
+            bind_context.infer_parameter_type(&expr, datatype);
+            Ok(cast(expr, datatype.clone()))
+        } else {
+            Ok(expr)
+        }
+    }
+
+    /// Bind the table being merged into, left joining it with the already
+    /// bound source.
+    ///
+    /// `bind_condition` is called with the table ref for the table being
+    /// merged into once both sides of the join are in scope.
+    ///
+    /// Returns the bound source and the table ref for the table being merged
+    /// into.
+    #[allow(clippy::too_many_arguments)]
+    fn bind_merge_source<F>(
+        &self,
+        bind_context: &mut BindContext,
+        source_scope: BindScopeRef,
+        left_idx: BindScopeRef,
+        left: BoundFrom,
+        table: ResolveListIdx,
+        alias: Option<ast::Ident>,
+        bind_condition: F,
+    ) -> Result<(BoundFrom, TableRef)>
+    where
+        F: FnOnce(&mut BindContext, TableRef) -> Result<Expression>,
+    {
+        let table = ast::FromBaseTable { reference: table };
+        let alias = alias.map(|alias| ast::FromAlias {
+            alias,
+            columns: None,
+        });
+
+        let right_idx = bind_context.new_child_scope(source_scope);
+        let right = FromBinder::new(right_idx, self.resolve_context).bind_table_with_row_ids(
+            bind_context,
+            table,
+            alias,
+        )?;
+        let target_ref = Self::base_table_ref(&right)?;
+
+        bind_context.append_context(source_scope, left_idx)?;
+        bind_context.append_context(source_scope, right_idx)?;
+
+        let condition = bind_condition(bind_context, target_ref)?;
+
+        let source = BoundFrom {
+            bind_ref: source_scope,
+            item: BoundFromItem::Join(BoundJoin {
+                left_bind_ref: left_idx,
+                left: Box::new(left),
+                right_bind_ref: right_idx,
+                right: Box::new(right),
+                join_type: JoinType::Left,
+                conditions: vec![condition],
+                lateral_columns: Vec::new(),
+            }),
+        };
+
+        Ok((source, target_ref))
+    }
+
+    /// Generate the projections and actions for a merge from the bound
+    /// clauses.
+    ///
+    /// Clauses are checked in order, with the first clause matching a row
+    /// determining the action for that row.
+    fn merge_projections(
+        bind_context: &BindContext,
+        target_ref: TableRef,
+        num_columns: usize,
+        clauses: Vec<MergeClauseExprs>,
+    ) -> Result<(Vec<Expression>, Vec<MergeAction>)> {
+        let row_id = col_ref(target_ref, num_columns);
+
+        let mut cases = Vec::with_capacity(clauses.len());
+        let mut actions = Vec::new();
+        let mut values = Vec::new();
+
+        for clause in clauses {
+            // Values start after the action and row id projections.
+            let offset = values.len() + 2;
+            let action = match clause.action {
+                MergeClauseAction::Insert(row) => {
+                    values.extend(row);
+                    Some(MergeAction::Insert { offset })
+                }
+                MergeClauseAction::Update(row) => {
+                    values.extend(row);
+                    Some(MergeAction::Update { offset })
+                }
+                MergeClauseAction::Delete => Some(MergeAction::Delete),
+                MergeClauseAction::DoNothing => None,
+            };
+
+            let then = match action {
+                Some(action) => {
+                    actions.push(action);
+                    lit((actions.len() - 1) as i32)
+                }
+                None => cast(lit(ScalarValue::Null), DataType::Int32),
+            };
+
+            let table_list = bind_context.get_table_list();
+            let matched = if clause.matched {
+                is::IsNotNull.plan(table_list, vec![row_id.clone()])?
+            } else {
+                is::IsNull.plan(table_list, vec![row_id.clone()])?
+            };
+            let matched = Expression::ScalarFunction(ScalarFunctionExpr { function: matched });
+            let when = and([matched].into_iter().chain(clause.condition))
+                .expect("at least one expression");
+
+            cases.push(WhenThen { when, then });
+        }
+
+        let mut projections = Vec::with_capacity(values.len() + 2);
+        projections.push(Expression::Case(CaseExpr {
+            cases,
+            else_expr: None,
+        }));
+        projections.push(row_id);
+        projections.extend(values);
+
+        Ok((projections, actions))
+    }
+
+    fn base_table_ref(from: &BoundFrom) -> Result<TableRef> {
+        match &from.item {
+            BoundFromItem::BaseTable(table) => Ok(table.table_ref),
+            other => Err(RayexecError::new(format!(
+                "Expected base table for table being modified, got {other:?}"
+            ))),
+        }
+    }
+
+    fn bind_filter(
+        &self,
+        bind_context: &mut BindContext,
+        source_scope: BindScopeRef,
+        where_expr: Option<ast::Expr<ResolvedMeta>>,
+    ) -> Result<Option<Expression>> {
+        where_expr
+            .map(|expr| {
+                BaseExpressionBinder::new(source_scope, self.resolve_context).bind_expression(
+                    bind_context,
+                    &expr,
+                    &mut DefaultColumnBinder,
+                    RecursionContext {
+                        allow_windows: false,
+                        allow_aggregates: false,
+                        is_root: true,
+                    },
+                )
+            })
+            .transpose()
+    }
+
+    fn new_projection_table(
+        &self,
+        bind_context: &mut BindContext,
+        projections: &[Expression],
+    ) -> Result<TableRef> {
+        bind_context.new_ephemeral_table_with_columns(
+            projections
+                .iter()
+                .map(|p| p.datatype(bind_context.get_table_list()))
+                .collect::<Result<Vec<_>>>()?,
+            (0..projections.len())
+                .map(|idx| format!("__generated_modify_project_{idx}"))
+                .collect(),
+        )
+    }
+}
@@ -7,9 +7,12 @@ use rayexec_parser::ast;
 use super::{BoundQuery, QueryBinder};
 use crate::arrays::datatype::DataType;
 use crate::database::catalog_entry::CatalogEntry;
-use crate::expr::column_expr::ColumnExpr;
+use crate::expr::case_expr::{CaseExpr, WhenThen};
 use crate::expr::comparison_expr::{ComparisonExpr, ComparisonOperator};
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::is::IsNotNull;
+use crate::functions::scalar::ScalarFunction;
 use crate::functions::table::{PlannedTableFunction, TableFunctionPlanner};
 use crate::logical::binder::bind_context::{
     BindContext,
@@ -24,12 +27,19 @@ use crate::logical::binder::table_list::{TableAlias, TableRef};
 use crate::logical::logical_join::JoinType;
 use crate::logical::operator::LocationRequirement;
 use crate::logical::resolver::resolve_context::ResolveContext;
-use crate::logical::resolver::resolved_table::ResolvedTableOrCteReference;
+use crate::logical::resolver::resolved_table::{
+    ResolvedTableOrCteReference,
+    ResolvedTableReference,
+};
 use crate::logical::resolver::resolved_table_function::ResolvedTableFunctionReference;
 use crate::logical::resolver::{ResolvedMeta, ResolvedSubqueryOptions};
+use crate::logical::statistics::TableStatistics;
 use crate::optimizer::expr_rewrite::const_fold::ConstFold;
 use crate::optimizer::expr_rewrite::ExpressionRewriteRule;
 
+/// Name of the column containing row ids when binding a table with row ids.
+pub const ROW_ID_COLUMN_NAME: &str = "__generated_row_id";
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundFrom {
     pub bind_ref: BindScopeRef,
@@ -43,6 +53,7 @@ pub enum BoundFromItem {
     TableFunction(BoundTableFunction),
     Subquery(BoundSubquery),
     MaterializedCte(BoundMaterializedCte),
+    RecursiveCteScan(BoundRecursiveCteScan),
     Empty,
 }
 
@@ -53,6 +64,13 @@ pub struct BoundBaseTable {
     pub catalog: String,
     pub schema: String,
     pub entry: Arc<CatalogEntry>,
+    /// If the scan should produce row ids as an additional column after all
+    /// table columns.
+    ///
+    /// Used for UPDATE and DELETE to identify the rows being modified.
+    pub row_ids: bool,
+    /// Statistics for the table.
+    pub statistics: TableStatistics,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -75,6 +93,16 @@ pub struct BoundMaterializedCte {
     pub cte_name: String,
 }
 
+/// Scan of the working table of a recursive CTE from within its recursive
+/// term.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundRecursiveCteScan {
+    pub table_ref: TableRef,
+    /// Reference to the working table CTE.
+    pub cte_ref: CteRef,
+    pub cte_name: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct BoundJoin {
     /// Reference to binder for left side of join.
@@ -196,45 +224,7 @@ impl<'a> FromBinder<'a> {
     ) -> Result<BoundFrom> {
         match self.resolve_context.tables.try_get_bound(table.reference)? {
             (ResolvedTableOrCteReference::Table(table), location) => {
-                let column_types = table
-                    .entry
-                    .try_as_table_entry()?
-                    .columns
-                    .iter()
-                    .map(|c| c.datatype.clone())
-                    .collect();
-                let column_names = table
-                    .entry
-                    .try_as_table_entry()?
-                    .columns
-                    .iter()
-                    .map(|c| c.name.clone())
-                    .collect();
-
-                let default_alias = TableAlias {
-                    database: Some(table.catalog.clone()),
-                    schema: Some(table.schema.clone()),
-                    table: table.entry.name.clone(),
-                };
-
-                let table_ref = self.push_table_scope_with_from_alias(
-                    bind_context,
-                    Some(default_alias),
-                    column_names,
-                    column_types,
-                    alias,
-                )?;
-
-                Ok(BoundFrom {
-                    bind_ref: self.current,
-                    item: BoundFromItem::BaseTable(BoundBaseTable {
-                        table_ref,
-                        location,
-                        catalog: table.catalog.clone(),
-                        schema: table.schema.clone(),
-                        entry: table.entry.clone(),
-                    }),
-                })
+                self.bind_base_table(bind_context, table, location, alias, false)
             }
             (ResolvedTableOrCteReference::Cte(name), _location) => {
                 // TODO: Does location matter here?
@@ -243,6 +233,83 @@ impl<'a> FromBinder<'a> {
         }
     }
 
+    /// Bind a table with an additional row id column appended to the table's
+    /// columns.
+    ///
+    /// The row id column will be named `ROW_ID_COLUMN_NAME` and is only
+    /// available for base tables.
+    pub(crate) fn bind_table_with_row_ids(
+        &self,
+        bind_context: &mut BindContext,
+        table: ast::FromBaseTable<ResolvedMeta>,
+        alias: Option<ast::FromAlias>,
+    ) -> Result<BoundFrom> {
+        match self.resolve_context.tables.try_get_bound(table.reference)? {
+            (ResolvedTableOrCteReference::Table(table), location) => {
+                self.bind_base_table(bind_context, table, location, alias, true)
+            }
+            (ResolvedTableOrCteReference::Cte(name), _location) => {
+                Err(RayexecError::new(format!("Cannot modify CTE '{name}'")))
+            }
+        }
+    }
+
+    fn bind_base_table(
+        &self,
+        bind_context: &mut BindContext,
+        table: &ResolvedTableReference,
+        location: LocationRequirement,
+        alias: Option<ast::FromAlias>,
+        row_ids: bool,
+    ) -> Result<BoundFrom> {
+        let mut column_types: Vec<_> = table
+            .entry
+            .try_as_table_entry()?
+            .columns
+            .iter()
+            .map(|c| c.datatype.clone())
+            .collect();
+        let mut column_names: Vec<_> = table
+            .entry
+            .try_as_table_entry()?
+            .columns
+            .iter()
+            .map(|c| c.name.clone())
+            .collect();
+
+        if row_ids {
+            column_types.push(DataType::UInt64);
+            column_names.push(ROW_ID_COLUMN_NAME.to_string());
+        }
+
+        let default_alias = TableAlias {
+            database: Some(table.catalog.clone()),
+            schema: Some(table.schema.clone()),
+            table: table.entry.name.clone(),
+        };
+
+        let table_ref = self.push_table_scope_with_from_alias(
+            bind_context,
+            Some(default_alias),
+            column_names,
+            column_types,
+            alias,
+        )?;
+
+        Ok(BoundFrom {
+            bind_ref: self.current,
+            item: BoundFromItem::BaseTable(BoundBaseTable {
+                table_ref,
+                location,
+                catalog: table.catalog.clone(),
+                schema: table.schema.clone(),
+                entry: table.entry.clone(),
+                row_ids,
+                statistics: table.statistics.clone(),
+            }),
+        })
+    }
+
     fn bind_cte(
         &self,
         bind_context: &mut BindContext,
@@ -261,7 +328,35 @@ impl<'a> FromBinder<'a> {
         let names = cte.column_names.clone();
         let types = cte.column_types.clone();
 
-        if cte.materialized {
+        if cte.working_table {
+            // Referencing a recursive CTE from within its recursive term.
+            if cte.working_table_refs > 0 {
+                return Err(RayexecError::new(format!(
+                    "Recursive reference to CTE '{}' must not appear more than once",
+                    cte.name
+                )));
+            }
+
+            let cte_name = cte.name.clone();
+            let table_ref = self.push_table_scope_with_from_alias(
+                bind_context,
+                Some(table_alias),
+                names,
+                types,
+                alias,
+            )?;
+
+            bind_context.get_cte_mut(cte_ref)?.working_table_refs += 1;
+
+            Ok(BoundFrom {
+                bind_ref: self.current,
+                item: BoundFromItem::RecursiveCteScan(BoundRecursiveCteScan {
+                    table_ref,
+                    cte_ref,
+                    cte_name,
+                }),
+            })
+        } else if cte.materialized {
             let cte_name = cte.name.clone();
             // Binds with the alias provided in the FROM.
             //
@@ -284,7 +379,15 @@ impl<'a> FromBinder<'a> {
             })
         } else {
             // Not materialize, just copy the plan as a subquery.
-            let subquery = cte.bound.clone();
+            let mut subquery = cte.bound.clone();
+
+            // Each reference to a recursive CTE gets its own output table.
+            // Otherwise multiple references would be indistinguishable once
+            // the subquery projection gets pruned.
+            if let BoundQuery::RecursiveCte(recursive) = subquery.as_mut() {
+                recursive.table_ref =
+                    bind_context.clone_to_new_ephemeral_table(recursive.table_ref)?;
+            }
 
             // Binds with the alias provided in the FROM.
             //
@@ -327,7 +430,14 @@ impl<'a> FromBinder<'a> {
 
         let mut names = Vec::new();
         let mut types = Vec::new();
+        let mut collations = Vec::new();
         for table in bind_context.iter_tables_in_scope(nested_scope)? {
+            collations.extend(
+                table
+                    .column_collations
+                    .iter()
+                    .map(|(&idx, &collation)| (types.len() + idx, collation)),
+            );
             types.extend(table.column_types.iter().cloned());
             names.extend(table.column_names.iter().cloned());
         }
@@ -368,6 +478,10 @@ impl<'a> FromBinder<'a> {
             self.push_table_scope_with_from_alias(bind_context, None, names, types, alias)?
         };
 
+        for (idx, collation) in collations {
+            bind_context.set_column_collation(table_ref, idx, collation)?;
+        }
+
         // Move correlated columns into current scope.
         bind_context.append_correlated_columns(self.current, nested_scope)?;
 
@@ -589,14 +703,23 @@ impl<'a> FromBinder<'a> {
             ast::JoinCondition::None => (Vec::new(), Vec::new()),
         };
 
-        let join_type = match join.join_type {
-            ast::JoinType::Inner => JoinType::Inner,
-            ast::JoinType::Left => JoinType::Left,
-            ast::JoinType::Right => JoinType::Right,
-            ast::JoinType::LeftSemi => JoinType::Semi,
-            other => not_implemented!("plan join type: {other:?}"),
+        // RIGHT SEMI and RIGHT ANTI joins are planned as SEMI/ANTI joins with
+        // the inputs swapped.
+        let (join_type, swap_sides) = match join.join_type {
+            ast::JoinType::Inner => (JoinType::Inner, false),
+            ast::JoinType::Left => (JoinType::Left, false),
+            ast::JoinType::Right => (JoinType::Right, false),
+            ast::JoinType::Outer => (JoinType::Full, false),
+            ast::JoinType::LeftSemi => (JoinType::Semi, false),
+            ast::JoinType::LeftAnti => (JoinType::Anti, false),
+            ast::JoinType::RightSemi => (JoinType::Semi, true),
+            ast::JoinType::RightAnti => (JoinType::Anti, true),
         };
 
+        if swap_sides && !lateral_columns.is_empty() {
+            not_implemented!("LATERAL with RIGHT SEMI or RIGHT ANTI join");
+        }
+
         // Move left and right into current context.
         bind_context.append_context(self.current, left_idx)?;
         bind_context.append_context(self.current, right_idx)?;
@@ -621,68 +744,82 @@ impl<'a> FromBinder<'a> {
                 ))
             };
 
-            let (left_table, left_col_idx) = bind_context
-                .find_table_for_column(left_idx, None, &using)?
+            let left = bind_context
+                .find_expression_for_column(left_idx, &using)?
                 .ok_or_else(|| missing_column("left"))?;
-            let (right_table, right_col_idx) = bind_context
-                .find_table_for_column(right_idx, None, &using)?
+            let right = bind_context
+                .find_expression_for_column(right_idx, &using)?
                 .ok_or_else(|| missing_column("right"))?;
 
-            let using_column = match join_type {
+            // Generate additional equality condition.
+            // TODO: Probably make this a method on the expr binder. Easy to miss the cast.
+            let [left_cast, right_cast] = condition_binder.apply_cast_for_operator(
+                bind_context,
+                ComparisonOperator::Eq,
+                [left.clone(), right.clone()],
+            )?;
+
+            let expr = match join_type {
+                _ if swap_sides => right,
                 JoinType::Left
                 | JoinType::Inner
-                | JoinType::Full
                 | JoinType::Semi
                 | JoinType::Anti
-                | JoinType::LeftMark { .. } => UsingColumn {
-                    column: using,
-                    table_ref: left_table,
-                    col_idx: left_col_idx,
-                },
-                JoinType::Right => UsingColumn {
-                    column: using,
-                    table_ref: right_table,
-                    col_idx: right_col_idx,
-                },
+                | JoinType::LeftMark { .. } => left,
+                JoinType::Right => right,
+                // Either side may be NULL, take the first non-NULL value.
+                JoinType::Full => {
+                    let not_null =
+                        IsNotNull.plan(bind_context.get_table_list(), vec![left_cast.clone()])?;
+                    Expression::Case(CaseExpr {
+                        cases: vec![WhenThen {
+                            when: Expression::ScalarFunction(ScalarFunctionExpr {
+                                function: not_null,
+                            }),
+                            then: left_cast.clone(),
+                        }],
+                        else_expr: Some(Box::new(right_cast.clone())),
+                    })
+                }
+            };
+            let using_column = UsingColumn {
+                column: using,
+                expr,
             };
 
             // Add USING column to _current_ scope if we don't already have an
             // equivalent column in our using set.
+            //
+            // The coalesced column from a FULL join always replaces existing
+            // columns since either side may be NULL.
             let already_using = bind_context
                 .get_using_columns(self.current)?
                 .iter()
                 .any(|c| c.column == using_column.column);
 
-            if !already_using {
+            if join_type == JoinType::Full {
+                bind_context.replace_using_column(self.current, using_column)?;
+            } else if !already_using {
                 bind_context.append_using_column(self.current, using_column)?;
             }
 
-            // Generate additional equality condition.
-            // TODO: Probably make this a method on the expr binder. Easy to miss the cast.
-            let [left, right] = condition_binder.apply_cast_for_operator(
-                bind_context,
-                ComparisonOperator::Eq,
-                [
-                    Expression::Column(ColumnExpr {
-                        table_scope: left_table,
-                        column: left_col_idx,
-                    }),
-                    Expression::Column(ColumnExpr {
-                        table_scope: right_table,
-                        column: right_col_idx,
-                    }),
-                ],
-            )?;
-
             conditions.push(Expression::Comparison(ComparisonExpr {
-                left: Box::new(left),
-                right: Box::new(right),
+                left: Box::new(left_cast),
+                right: Box::new(right_cast),
                 op: ComparisonOperator::Eq,
             }))
         }
 
-        // Remove right columns from scope for semi joins.
-        if join_type == JoinType::Semi {
+        // Swap inputs such that the side we're returning rows for is always
+        // on the left.
+        let (left_idx, left, right_idx, right) = if swap_sides {
+            (right_idx, right, left_idx, left)
+        } else {
+            (left_idx, left, right_idx, right)
+        };
+
+        // Remove right columns from scope for semi and anti joins.
+        if matches!(join_type, JoinType::Semi | JoinType::Anti) {
             let right_tables: Vec<_> = bind_context
                 .iter_tables_in_scope(right_idx)?
                 .map(|t| t.reference)
@@ -1,6 +1,6 @@
 use std::collections::BTreeSet;
 
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{RayexecError, Result};
 use rayexec_parser::ast;
 
 use super::select_list::SelectList;
@@ -33,12 +33,24 @@ impl<'a> GroupByBinder<'a> {
         }
     }
 
+    /// Bind the GROUP BY clause.
+    ///
+    /// Returns None if the clause is `GROUP BY ALL` and the select list
+    /// contains no expressions to group on.
     pub fn bind(
         &mut self,
         bind_context: &mut BindContext,
         select_list: &mut SelectList,
         group_by: ast::GroupByNode<ResolvedMeta>,
-    ) -> Result<BoundGroupBy> {
+    ) -> Result<Option<BoundGroupBy>> {
+        let group_by = match group_by {
+            ast::GroupByNode::All => match Self::expand_group_by_all(select_list) {
+                Some(node) => node,
+                None => return Ok(None),
+            },
+            node => node,
+        };
+
         let sets = GroupByWithSets::try_from_ast(group_by)?;
         let group_table = bind_context.new_ephemeral_table()?;
 
@@ -72,10 +84,44 @@ impl<'a> GroupByBinder<'a> {
             })
             .collect::<Result<Vec<_>>>()?;
 
-        Ok(BoundGroupBy {
+        Ok(Some(BoundGroupBy {
             expressions,
             group_exprs_table: group_table,
             grouping_sets: sets.grouping_sets,
+        }))
+    }
+
+    /// Expand `GROUP BY ALL` into ordinals referencing every expression in the
+    /// select list that doesn't contain an aggregate, window, or GROUPING call.
+    ///
+    /// Constant expressions are skipped.
+    fn expand_group_by_all(select_list: &SelectList) -> Option<ast::GroupByNode<ResolvedMeta>> {
+        let exprs: Vec<_> = select_list
+            .projections
+            .iter()
+            .enumerate()
+            .filter_map(|(idx, expr)| {
+                let refs = expr.get_table_references();
+                if refs.is_empty()
+                    || refs.contains(&select_list.aggregates_table)
+                    || refs.contains(&select_list.windows_table)
+                    || refs.contains(&select_list.grouping_functions_table)
+                {
+                    return None;
+                }
+
+                Some(ast::Expr::Literal(ast::Literal::Number(
+                    (idx + 1).to_string(),
+                )))
+            })
+            .collect();
+
+        if exprs.is_empty() {
+            return None;
+        }
+
+        Some(ast::GroupByNode::Exprs {
+            exprs: vec![ast::GroupByExpr::Expr(exprs)],
         })
     }
 }
@@ -145,47 +191,77 @@ struct GroupByWithSets {
 impl GroupByWithSets {
     fn try_from_ast(group_by: ast::GroupByNode<ResolvedMeta>) -> Result<Self> {
         match group_by {
-            ast::GroupByNode::All => not_implemented!("GROUP BY ALL"),
-            ast::GroupByNode::Exprs { mut exprs } => {
-                let expr = match exprs.len() {
-                    1 => exprs.pop().unwrap(),
-                    _ => return Err(RayexecError::new("Invalid number of group by expressions")),
-                };
-
-                let (expressions, grouping_sets) = match expr {
-                    ast::GroupByExpr::Expr(exprs) => {
-                        let len = exprs.len();
-                        (exprs, vec![(0..len).collect()])
-                    }
-                    ast::GroupByExpr::Rollup(exprs) => {
-                        let len = exprs.len();
-                        let mut sets: Vec<_> = (0..len).map(|i| (0..(len - i)).collect()).collect();
-                        sets.push(BTreeSet::new()); // Empty set.
-                        (exprs, sets)
-                    }
-                    ast::GroupByExpr::Cube(exprs) => {
-                        let len = exprs.len();
-                        let mut sets = Vec::new();
-
-                        // Powerset
-                        for mask in 0..(1 << len) {
-                            let mut set = BTreeSet::new();
-                            let mut bitset = mask;
-                            while bitset > 0 {
-                                let right: u64 = bitset & { !(bitset - 1) };
-                                let idx = right.trailing_zeros() as usize;
-                                set.insert(idx);
-                                bitset &= bitset - 1;
+            ast::GroupByNode::All => Err(RayexecError::new(
+                "GROUP BY ALL should have been expanded using the select list",
+            )),
+            ast::GroupByNode::Exprs { exprs } => {
+                let mut expressions = Vec::new();
+                // Final grouping sets are the cross product of the sets
+                // produced by each element, e.g. `GROUP BY a, ROLLUP (b)`
+                // produces sets `(a, b)` and `(a)`.
+                let mut grouping_sets = vec![BTreeSet::new()];
+
+                for expr in exprs {
+                    let element_sets = match expr {
+                        ast::GroupByExpr::Expr(exprs) => {
+                            let set = Self::expression_indices(&mut expressions, exprs);
+                            vec![set.into_iter().collect()]
+                        }
+                        ast::GroupByExpr::Rollup(exprs) => {
+                            let indices = Self::expression_indices(&mut expressions, exprs);
+                            let len = indices.len();
+                            let mut sets: Vec<_> = (0..len)
+                                .map(|i| indices[0..(len - i)].iter().copied().collect())
+                                .collect();
+                            sets.push(BTreeSet::new()); // Empty set.
+                            sets
+                        }
+                        ast::GroupByExpr::Cube(exprs) => {
+                            let indices = Self::expression_indices(&mut expressions, exprs);
+                            let len = indices.len();
+                            let mut sets = Vec::new();
+
+                            // Powerset
+                            for mask in 0..(1 << len) {
+                                let mut set = BTreeSet::new();
+                                let mut bitset = mask;
+                                while bitset > 0 {
+                                    let right: u64 = bitset & { !(bitset - 1) };
+                                    let idx = right.trailing_zeros() as usize;
+                                    set.insert(indices[idx]);
+                                    bitset &= bitset - 1;
+                                }
+                                sets.push(set);
                             }
-                            sets.push(set);
+
+                            sets
                         }
+                        ast::GroupByExpr::GroupingSets(sets) => {
+                            if sets.is_empty() {
+                                return Err(RayexecError::new(
+                                    "GROUPING SETS requires at least one set",
+                                ));
+                            }
 
-                        (exprs, sets)
-                    }
-                    ast::GroupByExpr::GroupingSets(_exprs) => {
-                        not_implemented!("GROUPING SETS")
-                    }
-                };
+                            sets.into_iter()
+                                .map(|exprs| {
+                                    Self::expression_indices(&mut expressions, exprs)
+                                        .into_iter()
+                                        .collect()
+                                })
+                                .collect()
+                        }
+                    };
+
+                    grouping_sets = grouping_sets
+                        .iter()
+                        .flat_map(|left| {
+                            element_sets
+                                .iter()
+                                .map(move |right| left.union(right).copied().collect())
+                        })
+                        .collect();
+                }
 
                 Ok(GroupByWithSets {
                     expressions,
@@ -194,6 +270,27 @@ impl GroupByWithSets {
             }
         }
     }
+
+    /// Get the indices for each expression, adding expressions we haven't seen
+    /// yet.
+    ///
+    /// Expressions are deduplicated such that the same expression appearing in
+    /// multiple grouping elements refers to the same group column.
+    fn expression_indices(
+        expressions: &mut Vec<ast::Expr<ResolvedMeta>>,
+        exprs: Vec<ast::Expr<ResolvedMeta>>,
+    ) -> Vec<usize> {
+        exprs
+            .into_iter()
+            .map(|expr| match expressions.iter().position(|e| e == &expr) {
+                Some(idx) => idx,
+                None => {
+                    expressions.push(expr);
+                    expressions.len() - 1
+                }
+            })
+            .collect()
+    }
 }
 
 #[cfg(test)]
@@ -296,4 +393,90 @@ mod tests {
 
         assert_eq!(expected, sets)
     }
+
+    #[test]
+    fn group_by_with_sets_from_grouping_sets() {
+        // GROUP BY GROUPING SETS ((a, b), a, ())
+        let node = ast::GroupByNode::Exprs {
+            exprs: vec![ast::GroupByExpr::GroupingSets(vec![
+                vec![
+                    ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                    ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ],
+                vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))],
+                vec![],
+            ])],
+        };
+
+        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ],
+            grouping_sets: vec![[0, 1].into(), [0].into(), [].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
+
+    #[test]
+    fn group_by_with_sets_from_expr_and_rollup() {
+        // GROUP BY a, ROLLUP (b, c)
+        let node = ast::GroupByNode::Exprs {
+            exprs: vec![
+                ast::GroupByExpr::Expr(vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))]),
+                ast::GroupByExpr::Rollup(vec![
+                    ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                    ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+                ]),
+            ],
+        };
+
+        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("c")),
+            ],
+            grouping_sets: vec![[0, 1, 2].into(), [0, 1].into(), [0].into()],
+        };
+
+        assert_eq!(expected, sets)
+    }
+
+    #[test]
+    fn group_by_with_sets_cross_product_dedups_expressions() {
+        // GROUP BY ROLLUP (a), CUBE (a, b)
+        let node = ast::GroupByNode::Exprs {
+            exprs: vec![
+                ast::GroupByExpr::Rollup(vec![ast::Expr::Ident(ast::Ident::new_unquoted("a"))]),
+                ast::GroupByExpr::Cube(vec![
+                    ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                    ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+                ]),
+            ],
+        };
+
+        let sets = GroupByWithSets::try_from_ast(node).unwrap();
+        let expected = GroupByWithSets {
+            expressions: vec![
+                ast::Expr::Ident(ast::Ident::new_unquoted("a")),
+                ast::Expr::Ident(ast::Ident::new_unquoted("b")),
+            ],
+            grouping_sets: vec![
+                [0].into(),
+                [0].into(),
+                [0, 1].into(),
+                [0, 1].into(),
+                [].into(),
+                [0].into(),
+                [1].into(),
+                [0, 1].into(),
+            ],
+        };
+
+        assert_eq!(expected, sets)
+    }
 }
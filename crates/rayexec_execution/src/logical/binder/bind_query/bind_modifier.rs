@@ -4,7 +4,11 @@ use rayexec_error::{RayexecError, Result};
 use rayexec_parser::ast;
 
 use super::select_list::SelectList;
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::datatype::DataType;
+use crate::expr::column_expr::ColumnExpr;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::string::Collate;
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::column_binder::{DefaultColumnBinder, ExpressionColumnBinder};
 use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
@@ -16,6 +20,8 @@ pub struct BoundOrderByExpr {
     pub expr: Expression,
     pub desc: bool,
     pub nulls_first: bool,
+    /// Collation to use when ordering string values.
+    pub collation: Collation,
 }
 
 impl fmt::Display for BoundOrderByExpr {
@@ -30,7 +36,11 @@ impl fmt::Display for BoundOrderByExpr {
             } else {
                 "nulls_last"
             }
-        )
+        )?;
+        if !self.collation.is_binary() {
+            write!(f, " collate {}", self.collation)?;
+        }
+        Ok(())
     }
 }
 
@@ -76,8 +86,26 @@ impl<'a> ModifierBinder<'a> {
             None => return Err(RayexecError::new("Missing scope, cannot bind to anything")),
         };
 
-        let exprs = order_by
-            .order_by_nodes
+        let order_by_nodes = match order_by {
+            ast::OrderByModifier::All { typ, nulls } => {
+                // Order by everything in the select list.
+                let columns = (0..select_list.projections.len()).map(|column| {
+                    let expr = Expression::Column(ColumnExpr {
+                        table_scope: select_list.projections_table,
+                        column,
+                    });
+                    let collation =
+                        Collate::collation_for_expr(bind_context.get_table_list(), &expr)
+                            .unwrap_or_default();
+                    (expr, collation)
+                });
+
+                return Ok(Self::bind_order_by_all(typ, nulls, columns));
+            }
+            ast::OrderByModifier::Nodes { order_by_nodes } => order_by_nodes,
+        };
+
+        let exprs = order_by_nodes
             .into_iter()
             .map(|order_by| {
                 let mut column_binder = OrderByColumnBinder {
@@ -100,6 +128,11 @@ impl<'a> ModifierBinder<'a> {
                 // If we bound to an existing item in the select list, use that
                 // expression. If we didn't, push the expression to the appended
                 // list, and bind to to that instead.
+                //
+                // Either way, a COLLATE on the expression or the column it
+                // references determines how the column is ordered.
+                let collation = Collate::collation_for_expr(bind_context.get_table_list(), &expr)
+                    .unwrap_or_default();
                 let expr = if column_binder.did_bind_to_select {
                     expr
                 } else {
@@ -107,26 +140,13 @@ impl<'a> ModifierBinder<'a> {
                     Expression::Column(col)
                 };
 
-                // ASC is default.
-                let desc = matches!(
-                    order_by.typ.unwrap_or(ast::OrderByType::Asc),
-                    ast::OrderByType::Desc
-                );
-
-                // Nulls ordered as if larger than any other value (to match
-                // postgres).
-                //
-                // ASC => NULLS LAST
-                // DESC => NULLS FIRST
-                let nulls_first = match order_by.nulls {
-                    Some(nulls) => matches!(nulls, ast::OrderByNulls::First),
-                    None => desc,
-                };
+                let (desc, nulls_first) = Self::order_direction(order_by.typ, order_by.nulls);
 
                 Ok(BoundOrderByExpr {
                     expr,
                     desc,
                     nulls_first,
+                    collation,
                 })
             })
             .collect::<Result<Vec<_>>>()?;
@@ -134,6 +154,50 @@ impl<'a> ModifierBinder<'a> {
         Ok(BoundOrderBy { exprs })
     }
 
+    /// Bind `ORDER BY ALL`, ordering by each of the provided columns and their
+    /// collations using the same direction and null ordering.
+    pub fn bind_order_by_all(
+        typ: Option<ast::OrderByType>,
+        nulls: Option<ast::OrderByNulls>,
+        columns: impl IntoIterator<Item = (Expression, Collation)>,
+    ) -> BoundOrderBy {
+        let (desc, nulls_first) = Self::order_direction(typ, nulls);
+        let exprs = columns
+            .into_iter()
+            .map(|(expr, collation)| BoundOrderByExpr {
+                expr,
+                desc,
+                nulls_first,
+                collation,
+            })
+            .collect();
+
+        BoundOrderBy { exprs }
+    }
+
+    /// Get the sort direction and null ordering for an ORDER BY expression.
+    ///
+    /// Returns `(desc, nulls_first)`.
+    fn order_direction(
+        typ: Option<ast::OrderByType>,
+        nulls: Option<ast::OrderByNulls>,
+    ) -> (bool, bool) {
+        // ASC is default.
+        let desc = matches!(typ.unwrap_or(ast::OrderByType::Asc), ast::OrderByType::Desc);
+
+        // Nulls ordered as if larger than any other value (to match
+        // postgres).
+        //
+        // ASC => NULLS LAST
+        // DESC => NULLS FIRST
+        let nulls_first = match nulls {
+            Some(nulls) => matches!(nulls, ast::OrderByNulls::First),
+            None => desc,
+        };
+
+        (desc, nulls_first)
+    }
+
     pub fn bind_limit(
         &self,
         bind_context: &mut BindContext,
@@ -166,12 +230,7 @@ impl<'a> ModifierBinder<'a> {
             }
         };
 
-        let limit = limit.try_into_scalar()?.try_as_i64()?;
-        let limit = if limit < 0 {
-            return Err(RayexecError::new("LIMIT cannot be negative"));
-        } else {
-            limit as usize
-        };
+        let limit = Self::bind_count(bind_context, limit, "LIMIT")?;
 
         let offset = match limit_mod.offset {
             Some(offset) => {
@@ -185,18 +244,32 @@ impl<'a> ModifierBinder<'a> {
                         is_root: true,
                     },
                 )?;
-                let offset = offset.try_into_scalar()?.try_as_i64()?;
-                if offset < 0 {
-                    return Err(RayexecError::new("OFFSET cannot be negative"));
-                } else {
-                    Some(offset as usize)
-                }
+                Some(Self::bind_count(bind_context, offset, "OFFSET")?)
             }
             None => None,
         };
 
         Ok(Some(BoundLimit { limit, offset }))
     }
+
+    /// Get the constant count for a LIMIT or OFFSET.
+    ///
+    /// Parameter placeholders are inferred to be Int64. The returned count is
+    /// meaningless in that case, placeholders are only bound when inferring
+    /// parameter types.
+    fn bind_count(bind_context: &mut BindContext, expr: Expression, clause: &str) -> Result<usize> {
+        if let Expression::Parameter(_) = expr {
+            bind_context.infer_parameter_type(&expr, &DataType::Int64);
+            return Ok(0);
+        }
+
+        let count = expr.try_into_scalar()?.try_as_i64()?;
+        if count < 0 {
+            return Err(RayexecError::new(format!("{clause} cannot be negative")));
+        }
+
+        Ok(count as usize)
+    }
 }
 
 #[derive(Debug)]
@@ -0,0 +1,197 @@
+use rayexec_error::{RayexecError, Result};
+use rayexec_parser::ast;
+
+use super::{apply_cte_column_aliases, BoundQuery, QueryBinder};
+use crate::functions::implicit::implicit_cast_score;
+use crate::logical::binder::bind_context::{BindContext, BindScopeRef, BoundCte, CteRef};
+use crate::logical::binder::table_list::TableRef;
+use crate::logical::resolver::resolve_context::ResolveContext;
+use crate::logical::resolver::ResolvedMeta;
+
+/// A bound recursive CTE.
+///
+/// The anchor is executed once to produce the initial working table. The
+/// recursive term is then executed repeatedly against the working table, with
+/// each iteration's output becoming the working table for the next iteration.
+/// Execution stops once an iteration produces no rows.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BoundRecursiveCte {
+    /// The non-recursive term.
+    pub anchor: Box<BoundQuery>,
+    pub anchor_scope: BindScopeRef,
+    /// The recursive term, containing a scan of the working table.
+    pub recursive: Box<BoundQuery>,
+    pub recursive_scope: BindScopeRef,
+    /// Table containing the output of the CTE.
+    pub table_ref: TableRef,
+    /// Reference to the working table scanned in the recursive term.
+    pub working_table: CteRef,
+    /// If duplicate rows should be kept (UNION ALL).
+    pub all: bool,
+    /// Table ref to use when casting the output of the recursive term to the
+    /// types of the anchor.
+    ///
+    /// None if no casting is needed.
+    pub recursive_cast_ref: Option<TableRef>,
+}
+
+#[derive(Debug)]
+pub struct RecursiveCteBinder<'a> {
+    pub current: BindScopeRef,
+    pub resolve_context: &'a ResolveContext,
+}
+
+impl<'a> RecursiveCteBinder<'a> {
+    pub fn new(current: BindScopeRef, resolve_context: &'a ResolveContext) -> Self {
+        RecursiveCteBinder {
+            current,
+            resolve_context,
+        }
+    }
+
+    /// Try to bind a CTE defined in a WITH RECURSIVE clause.
+    ///
+    /// Returns None if the CTE never references itself, in which case it
+    /// should be bound like a normal CTE.
+    pub fn bind(
+        &self,
+        bind_context: &mut BindContext,
+        cte: &ast::CommonTableExpr<ResolvedMeta>,
+    ) -> Result<Option<BoundCte>> {
+        // Only '<anchor> UNION [ALL] <recursive term>' can be recursive.
+        let setop = match cte.body.as_ref() {
+            ast::QueryNode {
+                ctes: None,
+                body: ast::QueryNodeBody::Set(setop),
+                order_by: None,
+                limit:
+                    ast::LimitModifier {
+                        limit: None,
+                        offset: None,
+                    },
+            } if setop.operation == ast::SetOperation::Union => setop,
+            _ => return Ok(None),
+        };
+
+        let name = cte.alias.as_normalized_string();
+        let nested = bind_context.new_child_scope(self.current);
+
+        // Bind the anchor first, its output determines the output of the CTE.
+        let anchor_scope = bind_context.new_child_scope(nested);
+        let anchor = QueryBinder::new(anchor_scope, self.resolve_context).bind_body(
+            bind_context,
+            setop.left.as_ref().clone(),
+            None,
+            ast::LimitModifier {
+                limit: None,
+                offset: None,
+            },
+        )?;
+
+        let mut names = Vec::new();
+        let mut types = Vec::new();
+        for table in bind_context.iter_tables_in_scope(anchor_scope)? {
+            types.extend(table.column_types.iter().cloned());
+            names.extend(table.column_names.iter().cloned());
+        }
+        apply_cte_column_aliases(&mut names, cte.column_aliases.as_deref())?;
+
+        // The working table gets its own scope so it's only visible when
+        // binding the recursive term.
+        let working_scope = bind_context.new_child_scope(nested);
+        let working_table = bind_context.add_cte(
+            working_scope,
+            BoundCte {
+                bind_scope: anchor_scope,
+                materialized: false,
+                name: name.clone(),
+                column_names: names.clone(),
+                column_types: types.clone(),
+                bound: Box::new(anchor.clone()),
+                mat_ref: None,
+                working_table: true,
+                working_table_refs: 0,
+            },
+        )?;
+
+        let recursive_scope = bind_context.new_child_scope(working_scope);
+        let recursive = QueryBinder::new(recursive_scope, self.resolve_context).bind_body(
+            bind_context,
+            setop.right.as_ref().clone(),
+            None,
+            ast::LimitModifier {
+                limit: None,
+                offset: None,
+            },
+        )?;
+
+        if bind_context.get_cte(working_table)?.working_table_refs == 0 {
+            return Ok(None);
+        }
+
+        let recursive_types: Vec<_> = bind_context
+            .iter_tables_in_scope(recursive_scope)?
+            .flat_map(|t| t.column_types.iter().cloned())
+            .collect();
+
+        if recursive_types.len() != types.len() {
+            return Err(RayexecError::new(format!(
+                "Recursive term of CTE '{name}' returns {} columns, expected {}",
+                recursive_types.len(),
+                types.len()
+            )));
+        }
+
+        // Output of the recursive term needs to match the anchor.
+        let mut needs_cast = false;
+        for ((anchor_type, recursive_type), col_name) in
+            types.iter().zip(&recursive_types).zip(&names)
+        {
+            if anchor_type == recursive_type {
+                continue;
+            }
+
+            if implicit_cast_score(recursive_type, anchor_type.datatype_id()).is_none() {
+                return Err(RayexecError::new(format!(
+                    "Column '{col_name}' of CTE '{name}' has type {anchor_type} in the non-recursive term, but type {recursive_type} in the recursive term"
+                )));
+            }
+
+            needs_cast = true;
+        }
+
+        let recursive_cast_ref = if needs_cast {
+            Some(
+                bind_context
+                    .new_ephemeral_table_from_types("__generated_recursive", types.clone())?,
+            )
+        } else {
+            None
+        };
+
+        let table_ref = bind_context.push_table(nested, None, types.clone(), names.clone())?;
+
+        let bound = BoundRecursiveCte {
+            anchor: Box::new(anchor),
+            anchor_scope,
+            recursive: Box::new(recursive),
+            recursive_scope,
+            table_ref,
+            working_table,
+            all: setop.all,
+            recursive_cast_ref,
+        };
+
+        Ok(Some(BoundCte {
+            bind_scope: nested,
+            materialized: cte.materialized,
+            name,
+            column_names: names,
+            column_types: types,
+            bound: Box::new(BoundQuery::RecursiveCte(bound)),
+            mat_ref: None,
+            working_table: false,
+            working_table_refs: 0,
+        }))
+    }
+}
@@ -60,6 +60,20 @@ impl<'a> SelectBinder<'a> {
         let from =
             FromBinder::new(from_bind_ref, self.resolve_context).bind(bind_context, select.from)?;
 
+        // Handle WINDOW
+        //
+        // Named windows need to be available before binding anything that may
+        // contain a window function.
+        for window in select.windows {
+            let definition = BaseExpressionBinder::new(from_bind_ref, self.resolve_context)
+                .expand_window_definition(bind_context, &window.definition)?;
+            bind_context.add_named_window(
+                from_bind_ref,
+                window.name.into_normalized_string(),
+                definition,
+            )?;
+        }
+
         // Expand SELECT
         let projections = SelectExprExpander::new(from_bind_ref, bind_context)
             .expand_all_select_exprs(select.projections)?;
@@ -103,7 +117,8 @@ impl<'a> SelectBinder<'a> {
                 let mut group_by_binder = GroupByBinder::new(from_bind_ref, self.resolve_context);
                 group_by_binder.bind(bind_context, &mut select_list, group_by)
             })
-            .transpose()?;
+            .transpose()?
+            .flatten();
 
         // Handle HAVING
         let mut having = select
@@ -182,6 +197,7 @@ mod tests {
             where_expr: None,
             group_by: None,
             having: None,
+            windows: Vec::new(),
         };
 
         let limit = ast::LimitModifier {
@@ -7,6 +7,7 @@ use super::select_expr_expander::ExpandedSelectExpr;
 use super::select_list::SelectList;
 use crate::expr::column_expr::ColumnExpr;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::string::Collate;
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::column_binder::{DefaultColumnBinder, ExpressionColumnBinder};
 use crate::logical::binder::expr_binder::{BaseExpressionBinder, RecursionContext};
@@ -100,7 +101,7 @@ impl<'a> SelectListBinder<'a> {
                     exprs.push(expr);
                 }
                 ExpandedSelectExpr::Column { expr, .. } => {
-                    exprs.push(Expression::Column(expr));
+                    exprs.push(expr);
                 }
             }
         }
@@ -114,6 +115,16 @@ impl<'a> SelectListBinder<'a> {
         // needed (ORDERY BY, GROUP BY).
         let projections_table = bind_context.new_ephemeral_table_with_columns(types, names)?;
 
+        // Collated projections keep their collation when referenced through an
+        // alias or from an outer query.
+        for (idx, expr) in exprs.iter().enumerate() {
+            if let Some(collation) =
+                Collate::collation_for_expr(bind_context.get_table_list(), expr)
+            {
+                bind_context.set_column_collation(projections_table, idx, collation)?;
+            }
+        }
+
         // Extract aggregates and windows into separate tables.
         let aggregates_table = bind_context.new_ephemeral_table()?;
         let groupings_table = bind_context.new_ephemeral_table()?;
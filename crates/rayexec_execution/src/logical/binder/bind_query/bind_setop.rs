@@ -4,6 +4,9 @@ use rayexec_parser::ast;
 use super::bind_modifier::{BoundLimit, BoundOrderBy};
 use super::bind_select_list::SelectListBinder;
 use super::BoundQuery;
+use crate::arrays::compute::collation::Collation;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::Expression;
 use crate::functions::implicit::implicit_cast_score;
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::bind_query::bind_modifier::ModifierBinder;
@@ -154,6 +157,7 @@ impl<'a> SetOpBinder<'a> {
         };
 
         // Move output into scope.
+        let num_columns = output_types.len();
         let table_ref = bind_context.push_table(self.current, None, output_types, left_names)?;
 
         // ORDER BY and LIMIT on output of the setop.
@@ -163,8 +167,21 @@ impl<'a> SetOpBinder<'a> {
         let mut empty_select_list = SelectListBinder::new(self.current, self.resolve_context)
             .bind(bind_context, Vec::new())?;
         let order_by = order_by
-            .map(|order_by| {
-                modifier_binder.bind_order_by(bind_context, &mut empty_select_list, order_by)
+            .map(|order_by| match order_by {
+                ast::OrderByModifier::All { typ, nulls } => {
+                    // Order by all columns in the output of the setop.
+                    let columns = (0..num_columns).map(|column| {
+                        let expr = Expression::Column(ColumnExpr {
+                            table_scope: table_ref,
+                            column,
+                        });
+                        (expr, Collation::BINARY)
+                    });
+                    Ok(ModifierBinder::bind_order_by_all(typ, nulls, columns))
+                }
+                order_by => {
+                    modifier_binder.bind_order_by(bind_context, &mut empty_select_list, order_by)
+                }
             })
             .transpose()?;
         let limit = modifier_binder.bind_limit(bind_context, limit)?;
@@ -90,6 +90,7 @@ impl<'a> ValuesBinder<'a> {
         for row in &mut rows {
             for (expr, datatype) in row.iter_mut().zip(&types) {
                 if &expr.datatype(bind_context.get_table_list())? != datatype {
+                    bind_context.infer_parameter_type(expr, datatype);
                     *expr = Expression::Cast(CastExpr {
                         to: datatype.clone(),
                         expr: Box::new(expr.clone()), // TODO: Could try to take instead of clone.
@@ -2,6 +2,7 @@ pub mod bind_from;
 pub mod bind_group_by;
 pub mod bind_having;
 pub mod bind_modifier;
+pub mod bind_recursive_cte;
 pub mod bind_select;
 pub mod bind_select_list;
 pub mod bind_setop;
@@ -10,10 +11,11 @@ pub mod bind_values;
 pub mod select_expr_expander;
 pub mod select_list;
 
+use bind_recursive_cte::{BoundRecursiveCte, RecursiveCteBinder};
 use bind_select::{BoundSelect, SelectBinder};
 use bind_setop::{BoundSetOp, SetOpBinder};
 use bind_values::{BoundValues, ValuesBinder};
-use rayexec_error::{not_implemented, RayexecError, Result};
+use rayexec_error::{RayexecError, Result};
 use rayexec_parser::ast;
 
 use super::bind_context::{BindContext, BindScopeRef};
@@ -27,6 +29,7 @@ pub enum BoundQuery {
     Select(BoundSelect),
     Setop(BoundSetOp),
     Values(BoundValues),
+    RecursiveCte(BoundRecursiveCte),
 }
 
 impl BoundQuery {
@@ -38,6 +41,7 @@ impl BoundQuery {
             },
             Self::Setop(setop) => setop.setop_table,
             BoundQuery::Values(values) => values.expressions_table,
+            BoundQuery::RecursiveCte(cte) => cte.table_ref,
         }
     }
 }
@@ -102,11 +106,15 @@ impl<'a> QueryBinder<'a> {
         bind_context: &mut BindContext,
         ctes: ast::CommonTableExprs<ResolvedMeta>,
     ) -> Result<()> {
-        if ctes.recursive {
-            not_implemented!("recursive CTEs");
-        }
-
         for cte in ctes.ctes {
+            if ctes.recursive {
+                let binder = RecursiveCteBinder::new(self.current, self.resolve_context);
+                if let Some(bound) = binder.bind(bind_context, &cte)? {
+                    bind_context.add_cte(self.current, bound)?;
+                    continue;
+                }
+            }
+
             self.bind_cte(bind_context, cte)?
         }
 
@@ -129,22 +137,7 @@ impl<'a> QueryBinder<'a> {
             names.extend(table.column_names.iter().cloned());
         }
 
-        // Sets alias where cte is defined
-        //
-        // WITH my_cte(alias1, alias2) AS ...
-        if let Some(col_aliases) = &cte.column_aliases {
-            if col_aliases.len() > names.len() {
-                return Err(RayexecError::new(format!(
-                    "Expected at most {} column aliases, received {}",
-                    names.len(),
-                    col_aliases.len()
-                )));
-            }
-
-            for (idx, col_alias) in col_aliases.iter().enumerate() {
-                names[idx] = col_alias.as_normalized_string();
-            }
-        }
+        apply_cte_column_aliases(&mut names, cte.column_aliases.as_deref())?;
 
         let cte = BoundCte {
             bind_scope: nested,
@@ -154,6 +147,8 @@ impl<'a> QueryBinder<'a> {
             column_types: types,
             bound: Box::new(bound),
             mat_ref: None,
+            working_table: false,
+            working_table_refs: 0,
         };
 
         // Note that we bind the CTE in a nested scope, but add it to the
@@ -167,3 +162,27 @@ impl<'a> QueryBinder<'a> {
         Ok(())
     }
 }
+
+/// Sets aliases for columns where the CTE is defined.
+///
+/// WITH my_cte(alias1, alias2) AS ...
+fn apply_cte_column_aliases(
+    names: &mut [String],
+    col_aliases: Option<&[ast::Ident]>,
+) -> Result<()> {
+    if let Some(col_aliases) = col_aliases {
+        if col_aliases.len() > names.len() {
+            return Err(RayexecError::new(format!(
+                "Expected at most {} column aliases, received {}",
+                names.len(),
+                col_aliases.len()
+            )));
+        }
+
+        for (idx, col_alias) in col_aliases.iter().enumerate() {
+            names[idx] = col_alias.as_normalized_string();
+        }
+    }
+
+    Ok(())
+}
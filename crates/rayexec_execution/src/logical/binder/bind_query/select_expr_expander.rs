@@ -5,6 +5,7 @@ use rayexec_parser::ast;
 use regex::Regex;
 
 use crate::expr::column_expr::ColumnExpr;
+use crate::expr::Expression;
 use crate::logical::binder::bind_context::{BindContext, BindScopeRef};
 use crate::logical::binder::table_list::TableAlias;
 use crate::logical::resolver::ResolvedMeta;
@@ -25,8 +26,11 @@ pub enum ExpandedSelectExpr {
     /// since they're expanded to match some number of columns in the current
     /// scope.
     Column {
-        /// The column expression representing a column in some scope.
-        expr: ColumnExpr,
+        /// The expression representing a column in some scope.
+        ///
+        /// This is a plain column reference, except for USING columns from
+        /// FULL joins which coalesce the columns from both sides.
+        expr: Expression,
         /// Name as it existed in the bind scope.
         name: String,
     },
@@ -88,10 +92,7 @@ impl<'a> SelectExprExpander<'a> {
                 let mut handled = HashSet::new();
                 for using in self.bind_context.get_using_columns(self.current)? {
                     exprs.push(ExpandedSelectExpr::Column {
-                        expr: ColumnExpr {
-                            table_scope: using.table_ref,
-                            column: using.col_idx,
-                        },
+                        expr: using.expr.clone(),
                         name: using.column.clone(),
                     });
 
@@ -106,10 +107,10 @@ impl<'a> SelectExprExpander<'a> {
                         }
 
                         exprs.push(ExpandedSelectExpr::Column {
-                            expr: ColumnExpr {
+                            expr: Expression::Column(ColumnExpr {
                                 table_scope: table.reference,
                                 column: col_idx,
-                            },
+                            }),
                             name: name.clone(),
                         })
                     }
@@ -150,10 +151,10 @@ impl<'a> SelectExprExpander<'a> {
                 let mut exprs = Vec::new();
                 for (col_idx, name) in table.column_names.iter().enumerate() {
                     exprs.push(ExpandedSelectExpr::Column {
-                        expr: ColumnExpr {
+                        expr: Expression::Column(ColumnExpr {
                             table_scope: table.reference,
                             column: col_idx,
-                        },
+                        }),
                         name: name.clone(),
                     })
                 }
@@ -186,10 +187,10 @@ impl<'a> SelectExprExpander<'a> {
                                     }
 
                                     exprs.push(ExpandedSelectExpr::Column {
-                                        expr: ColumnExpr {
+                                        expr: Expression::Column(ColumnExpr {
                                             table_scope: table.reference,
                                             column: col_idx,
-                                        },
+                                        }),
                                         name: name.clone(),
                                     })
                                 }
@@ -346,17 +347,17 @@ mod tests {
 
         let expected = vec![
             ExpandedSelectExpr::Column {
-                expr: ColumnExpr {
+                expr: Expression::Column(ColumnExpr {
                     table_scope: table_ref,
                     column: 0,
-                },
+                }),
                 name: "c1".to_string(),
             },
             ExpandedSelectExpr::Column {
-                expr: ColumnExpr {
+                expr: Expression::Column(ColumnExpr {
                     table_scope: table_ref,
                     column: 1,
-                },
+                }),
                 name: "c2".to_string(),
             },
         ];
@@ -408,17 +409,17 @@ mod tests {
 
         let expected = vec![
             ExpandedSelectExpr::Column {
-                expr: ColumnExpr {
+                expr: Expression::Column(ColumnExpr {
                     table_scope: t1_table_ref,
                     column: 0,
-                },
+                }),
                 name: "c1".to_string(),
             },
             ExpandedSelectExpr::Column {
-                expr: ColumnExpr {
+                expr: Expression::Column(ColumnExpr {
                     table_scope: t1_table_ref,
                     column: 1,
-                },
+                }),
                 name: "c2".to_string(),
             },
         ];
@@ -5,8 +5,18 @@ use rayexec_parser::ast::{self};
 
 use super::bind_group_by::BoundGroupBy;
 use super::bind_select_list::SelectListBinder;
+use crate::expr::aggregate_expr::AggregateExpr;
+use crate::expr::case_expr::{CaseExpr, WhenThen};
 use crate::expr::column_expr::ColumnExpr;
+use crate::expr::literal_expr::LiteralExpr;
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
 use crate::expr::Expression;
+use crate::functions::aggregate::builtin::first::First;
+use crate::functions::aggregate::AggregateFunction;
+use crate::functions::scalar::builtin::is::IsNotNull;
+use crate::functions::scalar::builtin::string::{Collate, CollationKey};
+use crate::functions::scalar::ScalarFunction;
+use crate::functions::FunctionInfo;
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::table_list::TableRef;
 use crate::logical::logical_aggregate::GroupingFunction;
@@ -94,14 +104,21 @@ impl SelectList {
         bind_context: &mut BindContext,
         mut group_by: Option<&mut BoundGroupBy>,
     ) -> Result<BoundSelectList> {
-        let grouping_functions = self.expressions_to_grouping_functions(&group_by)?;
-
         // Have projections point to the GROUP BY instead of the other way
         // around.
         if let Some(group_by) = group_by.as_mut() {
             self.update_group_by_dependencies(group_by)?;
         }
 
+        // Done after updating dependencies so that GROUPING arguments can match
+        // GROUP BY expressions that referenced the select list (aliases,
+        // ordinals).
+        let grouping_functions = self.expressions_to_grouping_functions(&group_by)?;
+
+        if let Some(group_by) = group_by.as_mut() {
+            self.update_collated_group_by(bind_context, group_by)?;
+        }
+
         self.verify_column_references(
             bind_context,
             self.aggregates_table,
@@ -119,6 +136,7 @@ impl SelectList {
             self.projections.append(&mut self.appended);
 
             let projections_table = bind_context.get_table(self.projections_table)?;
+            let collations = projections_table.column_collations.clone();
             let output_table_ref = bind_context.new_ephemeral_table_with_columns(
                 projections_table
                     .column_types
@@ -133,6 +151,11 @@ impl SelectList {
                     .cloned()
                     .collect(),
             )?;
+            for (idx, collation) in collations {
+                if idx < len {
+                    bind_context.set_column_collation(output_table_ref, idx, collation)?;
+                }
+            }
 
             // Project out only expressions in the original select list.
             let expressions = (0..len)
@@ -167,7 +190,10 @@ impl SelectList {
     }
 
     /// Verify that all column expressions in the project list point to either
-    /// an aggregate or group by expression.
+    /// an aggregate, group by, or window expression.
+    ///
+    /// Extracted window expressions are verified as well since they're executed
+    /// after the aggregate.
     fn verify_column_references(
         &self,
         bind_context: &BindContext,
@@ -200,6 +226,19 @@ impl SelectList {
                     // - An aggregate
                     // - An expression in the group by
                     // - A GROUPING call
+                    // - A window
+                    inner(
+                        bind_context,
+                        expr,
+                        &[
+                            agg_table,
+                            group_by.group_exprs_table,
+                            groupings_table,
+                            self.windows_table,
+                        ],
+                    )?
+                }
+                for expr in &self.windows {
                     inner(
                         bind_context,
                         expr,
@@ -209,6 +248,9 @@ impl SelectList {
             }
             None => {
                 for expr in self.projections.iter().chain(&self.appended) {
+                    inner(bind_context, expr, &[agg_table, self.windows_table])?
+                }
+                for expr in &self.windows {
                     inner(bind_context, expr, &[agg_table])?
                 }
             }
@@ -341,6 +383,113 @@ impl SelectList {
         Ok(grouping_functions)
     }
 
+    /// Groups collated GROUP BY expressions on their collation keys.
+    ///
+    /// References to the group in the select list are replaced with the first
+    /// value seen for that group instead of the collation key, e.g. 'Berlin'
+    /// and 'berlin' grouped by 'nocase' outputs one of the original strings.
+    fn update_collated_group_by(
+        &mut self,
+        bind_context: &mut BindContext,
+        group_by: &mut BoundGroupBy,
+    ) -> Result<()> {
+        for idx in 0..group_by.expressions.len() {
+            let expr = &group_by.expressions[idx];
+            let collation = match Collate::collation_for_expr(bind_context.get_table_list(), expr) {
+                Some(collation) => collation,
+                None => continue,
+            };
+            let input = match expr {
+                Expression::ScalarFunction(scalar)
+                    if scalar.function.function.name() == Collate.name() =>
+                {
+                    scalar.function.inputs[0].clone()
+                }
+                expr => expr.clone(),
+            };
+
+            let key = CollationKey.plan(
+                bind_context.get_table_list(),
+                vec![
+                    input.clone(),
+                    Expression::Literal(LiteralExpr {
+                        literal: collation.to_string().into(),
+                    }),
+                ],
+            )?;
+            group_by.expressions[idx] =
+                Expression::ScalarFunction(ScalarFunctionExpr { function: key });
+
+            let first = First.plan(bind_context.get_table_list(), vec![input])?;
+            let datatype = first.return_type.clone();
+            self.aggregates.push(Expression::Aggregate(AggregateExpr {
+                agg: first,
+                filter: None,
+                distinct: false,
+            }));
+            let agg_col = ColumnExpr {
+                table_scope: self.aggregates_table,
+                column: bind_context.push_column_for_table(
+                    self.aggregates_table,
+                    "__generated_collated_group",
+                    datatype,
+                )?,
+            };
+
+            let group_col = ColumnExpr {
+                table_scope: group_by.group_exprs_table,
+                column: idx,
+            };
+
+            // Grouping sets that don't include this expression produce NULL
+            // for it, while the aggregate would still produce a value.
+            let replacement = if group_by.grouping_sets.iter().all(|set| set.contains(&idx)) {
+                Expression::Column(agg_col)
+            } else {
+                let is_not_null = IsNotNull.plan(
+                    bind_context.get_table_list(),
+                    vec![Expression::Column(group_col)],
+                )?;
+                Expression::Case(CaseExpr {
+                    cases: vec![WhenThen {
+                        when: Expression::ScalarFunction(ScalarFunctionExpr {
+                            function: is_not_null,
+                        }),
+                        then: Expression::Column(agg_col),
+                    }],
+                    else_expr: None,
+                })
+            };
+
+            fn replace_group_col(
+                group_col: ColumnExpr,
+                replacement: &Expression,
+                expr: &mut Expression,
+            ) -> Result<()> {
+                if let Expression::Column(col) = expr {
+                    if *col == group_col {
+                        *expr = replacement.clone();
+                    }
+                    return Ok(());
+                }
+                expr.for_each_child_mut(&mut |child| {
+                    replace_group_col(group_col, replacement, child)
+                })
+            }
+
+            for expr in self
+                .projections
+                .iter_mut()
+                .chain(self.appended.iter_mut())
+                .chain(self.windows.iter_mut())
+            {
+                replace_group_col(group_col, &replacement, expr)?;
+            }
+        }
+
+        Ok(())
+    }
+
     /// Updates expressions in the select list and bound group to ensure the
     /// select list depends on columns in the group by, and not the other way
     /// around.
@@ -396,7 +545,12 @@ impl SelectList {
                 column: idx,
             };
 
-            for expr in self.projections.iter_mut().chain(self.appended.iter_mut()) {
+            for expr in self
+                .projections
+                .iter_mut()
+                .chain(self.appended.iter_mut())
+                .chain(self.windows.iter_mut())
+            {
                 update_projection_expr(group_by_expr, group_by_col, expr)?;
             }
         }
@@ -1,4 +1,4 @@
-use rayexec_error::Result;
+use rayexec_error::{RayexecError, Result};
 use rayexec_parser::statement::Statement;
 
 use super::bind_attach::{AttachBinder, BoundAttach, BoundDetach};
@@ -11,8 +11,11 @@ use super::bind_describe::DescribeBinder;
 use super::bind_drop::DropBinder;
 use super::bind_explain::{BoundExplain, ExplainBinder};
 use super::bind_insert::{BoundInsert, InsertBinder};
+use super::bind_modify::{BoundDelete, BoundMerge, BoundUpdate, ModifyBinder};
 use super::bind_query::BoundQuery;
 use super::bind_set::SetVarBinder;
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::OwnedScalarValue;
 use crate::config::session::SessionConfig;
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::logical_create::{LogicalCreateSchema, LogicalCreateView};
@@ -41,6 +44,9 @@ pub enum BoundStatement {
     Detach(BoundDetach),
     Drop(Node<LogicalDrop>),
     Insert(BoundInsert),
+    Update(BoundUpdate),
+    Delete(BoundDelete),
+    Merge(BoundMerge),
     CreateSchema(Node<LogicalCreateSchema>),
     CreateTable(BoundCreateTable),
     CreateView(Node<LogicalCreateView>),
@@ -53,6 +59,12 @@ pub enum BoundStatement {
 pub struct StatementBinder<'a> {
     pub session_config: &'a SessionConfig,
     pub resolve_context: &'a ResolveContext,
+    /// Values for positional parameters in the statement.
+    ///
+    /// None to bind parameters as placeholders to infer their types.
+    pub parameters: Option<&'a [OwnedScalarValue]>,
+    /// Declared or previously inferred types for the parameters.
+    pub parameter_types: &'a [Option<DataType>],
 }
 
 impl StatementBinder<'_> {
@@ -61,6 +73,11 @@ impl StatementBinder<'_> {
         statement: Statement<ResolvedMeta>,
     ) -> Result<(BoundStatement, BindContext)> {
         let mut context = BindContext::new();
+        context.set_parameters(
+            self.parameters.map(|params| params.to_vec()),
+            self.parameter_types.to_vec(),
+        );
+        context.set_overflow_behavior(self.session_config.integer_overflow);
         let root_scope = context.root_scope_ref();
 
         let statement = match statement {
@@ -89,10 +106,26 @@ impl StatementBinder<'_> {
             Statement::Drop(drop) => {
                 BoundStatement::Drop(DropBinder::new(root_scope).bind_drop(&mut context, drop)?)
             }
+            Statement::Insert(insert) if insert.on_conflict.is_some() => BoundStatement::Merge(
+                ModifyBinder::new(root_scope, self.resolve_context)
+                    .bind_insert_on_conflict(&mut context, insert)?,
+            ),
             Statement::Insert(insert) => BoundStatement::Insert(
                 InsertBinder::new(root_scope, self.resolve_context)
                     .bind_insert(&mut context, insert)?,
             ),
+            Statement::Update(update) => BoundStatement::Update(
+                ModifyBinder::new(root_scope, self.resolve_context)
+                    .bind_update(&mut context, update)?,
+            ),
+            Statement::Delete(delete) => BoundStatement::Delete(
+                ModifyBinder::new(root_scope, self.resolve_context)
+                    .bind_delete(&mut context, delete)?,
+            ),
+            Statement::Merge(merge) => BoundStatement::Merge(
+                ModifyBinder::new(root_scope, self.resolve_context)
+                    .bind_merge(&mut context, merge)?,
+            ),
             Statement::CreateSchema(create) => BoundStatement::CreateSchema(
                 CreateSchemaBinder::new(root_scope).bind_create_schema(&mut context, create)?,
             ),
@@ -116,6 +149,16 @@ impl StatementBinder<'_> {
                 CopyBinder::new(root_scope, self.resolve_context)
                     .bind_copy_to(&mut context, copy_to)?,
             ),
+            Statement::Prepare(_) | Statement::Execute(_) | Statement::Deallocate(_) => {
+                return Err(RayexecError::new(
+                    "PREPARE, EXECUTE, and DEALLOCATE should be handled by the session",
+                ))
+            }
+            Statement::Transaction(_) => {
+                return Err(RayexecError::new(
+                    "Transaction statements should be handled by the session",
+                ))
+            }
         };
 
         Ok((statement, context))
@@ -4,8 +4,13 @@ use rayexec_parser::ast;
 use super::bind_context::{BindContext, BindScopeRef, CorrelatedColumn};
 use super::expr_binder::RecursionContext;
 use super::table_list::TableAlias;
+use crate::arrays::scalar::OwnedScalarValue;
 use crate::expr::column_expr::ColumnExpr;
+use crate::expr::literal_expr::LiteralExpr;
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
 use crate::expr::Expression;
+use crate::functions::scalar::builtin::struct_funcs::StructExtract;
+use crate::functions::scalar::ScalarFunction;
 use crate::logical::resolver::ResolvedMeta;
 
 /// Defined behavior of how to bind idents to actual columns.
@@ -83,8 +88,31 @@ impl ExpressionColumnBinder for DefaultColumnBinder {
         idents: &[ast::Ident],
         _recur: RecursionContext,
     ) -> Result<Option<Expression>> {
-        let (alias, col) = idents_to_alias_and_column(idents)?;
-        self.bind_column(bind_scope, bind_context, alias, &col)
+        // Try the longest qualified column first, falling back to treating
+        // trailing idents as struct field accesses.
+        //
+        // 'a.b.c' => column 'c' in table 'a.b', or field 'c' of column 'b' in
+        // table 'a', or field 'b.c' of column 'a'.
+        for num_col_parts in (1..=idents.len().min(4)).rev() {
+            let (alias, col) = idents_to_alias_and_column(&idents[..num_col_parts])?;
+            let mut expr = match self.bind_column(bind_scope, bind_context, alias, &col)? {
+                Some(expr) => expr,
+                None => continue,
+            };
+
+            for field in &idents[num_col_parts..] {
+                let field = Expression::Literal(LiteralExpr {
+                    literal: OwnedScalarValue::Utf8(field.as_normalized_string().into()),
+                });
+                let planned =
+                    StructExtract.plan(bind_context.get_table_list(), vec![expr, field])?;
+                expr = Expression::ScalarFunction(ScalarFunctionExpr { function: planned });
+            }
+
+            return Ok(Some(expr));
+        }
+
+        Ok(None)
     }
 }
 
@@ -102,31 +130,42 @@ impl DefaultColumnBinder {
     ) -> Result<Option<Expression>> {
         let mut current = bind_scope;
         loop {
-            let table = bind_context.find_table_for_column(current, alias.as_ref(), col)?;
-            match table {
-                Some((table, col_idx)) => {
+            let expr = match &alias {
+                Some(alias) => bind_context
+                    .find_table_for_column(current, Some(alias), col)?
+                    .map(|(table, col_idx)| {
+                        Expression::Column(ColumnExpr {
+                            table_scope: table,
+                            column: col_idx,
+                        })
+                    }),
+                None => bind_context.find_expression_for_column(current, col)?,
+            };
+            match expr {
+                Some(expr) => {
                     // Table containing column found. Check if it's correlated
                     // (referencing an outer context).
                     let is_correlated = current != bind_scope;
 
                     if is_correlated {
-                        // Column is correlated, Push correlation to current
-                        // bind context.
-                        let correlated = CorrelatedColumn {
-                            outer: current,
-                            table,
-                            col_idx,
-                        };
+                        // Column is correlated, Push correlations to current
+                        // bind context. USING columns from a FULL join may
+                        // reference multiple columns.
+                        let correlations =
+                            expr.get_column_references()
+                                .into_iter()
+                                .map(|col| CorrelatedColumn {
+                                    outer: current,
+                                    table: col.table_scope,
+                                    col_idx: col.column,
+                                });
 
                         // Note the original scope, not `current`. We want to
                         // store the context containing the expression.
-                        bind_context.push_correlation(bind_scope, correlated)?;
+                        bind_context.push_correlations(bind_scope, correlations)?;
                     }
 
-                    return Ok(Some(Expression::Column(ColumnExpr {
-                        table_scope: table,
-                        column: col_idx,
-                    })));
+                    return Ok(Some(expr));
                 }
                 None => {
                     // Table not found in current context, go to parent context
@@ -161,7 +200,6 @@ pub fn idents_to_alias_and_column(idents: &[ast::Ident]) -> Result<(Option<Table
             // 2 => 'table.column'
             // 3 => 'schema.table.column'
             // 4 => 'database.schema.table.column'
-            // TODO: Struct fields.
 
             let mut idents = idents.to_vec();
             let col = idents.pop().unwrap().into_normalized_string();
@@ -180,7 +218,7 @@ pub fn idents_to_alias_and_column(idents: &[ast::Ident]) -> Result<(Option<Table
         _ => Err(RayexecError::new(format!(
             "Too many identifier parts in {}",
             ast::ObjectReference(idents.to_vec()),
-        ))), // TODO: Struct fields.
+        ))),
     }
 }
 
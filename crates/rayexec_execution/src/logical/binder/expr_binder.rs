@@ -4,7 +4,10 @@ use rayexec_parser::ast::{self, QueryNode};
 
 use super::bind_context::{BindContext, BindScopeRef};
 use super::column_binder::ExpressionColumnBinder;
-use crate::arrays::datatype::DataType;
+use super::constant_binder::ConstantBinder;
+use super::table_list::TableList;
+use crate::arrays::compute::collation::Collation;
+use crate::arrays::datatype::{DataType, DataTypeId};
 use crate::arrays::scalar::interval::Interval;
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::expr::aggregate_expr::AggregateExpr;
@@ -19,16 +22,29 @@ use crate::expr::negate_expr::{NegateExpr, NegateOperator};
 use crate::expr::scalar_function_expr::ScalarFunctionExpr;
 use crate::expr::subquery_expr::{SubqueryExpr, SubqueryType};
 use crate::expr::unnest_expr::UnnestExpr;
-use crate::expr::window_expr::{WindowExpr, WindowFrameBound, WindowFrameExclusion};
+use crate::expr::window_expr::{
+    WindowExpr,
+    WindowFrameBound,
+    WindowFrameExclusion,
+    WindowFrameUnit,
+    WindowFunctionKind,
+};
 use crate::expr::{AsScalarFunction, Expression};
-use crate::functions::aggregate::AggregateFunction;
-use crate::functions::scalar::builtin::datetime::DatePart;
+use crate::functions::scalar::builtin::datetime::{AtTimeZone, DatePart};
 use crate::functions::scalar::builtin::is;
 use crate::functions::scalar::builtin::list::{ListExtract, ListValues};
-use crate::functions::scalar::builtin::string::{Concat, Like, StartsWith, Substring};
+use crate::functions::scalar::builtin::string::{
+    Collate,
+    CollationKey,
+    Concat,
+    Like,
+    StartsWith,
+    Substring,
+};
+use crate::functions::scalar::builtin::struct_funcs::StructPack;
 use crate::functions::scalar::ScalarFunction;
 use crate::functions::table::TableFunction;
-use crate::functions::CastType;
+use crate::functions::{CandidateSignature, CastType, FunctionInfo};
 use crate::logical::binder::bind_query::bind_modifier::BoundOrderByExpr;
 use crate::logical::binder::bind_query::QueryBinder;
 use crate::logical::resolver::resolve_context::ResolveContext;
@@ -119,6 +135,31 @@ impl<'a> BaseExpressionBinder<'a> {
             ast::Expr::QualifiedWildcard(_) => Err(RayexecError::new(
                 "Qualified wildcard not a valid expression to bind",
             )),
+            ast::Expr::Literal(ast::Literal::Struct { keys, values }) => {
+                // Struct literals are planned as `struct_pack` with
+                // alternating field names and values.
+                let mut exprs = Vec::with_capacity(keys.len() * 2);
+                for (key, value) in keys.iter().zip(values) {
+                    exprs.push(Expression::Literal(LiteralExpr {
+                        literal: OwnedScalarValue::Utf8(key.clone().into()),
+                    }));
+                    exprs.push(self.bind_expression(
+                        bind_context,
+                        value,
+                        column_binder,
+                        RecursionContext {
+                            is_root: false,
+                            ..recur
+                        },
+                    )?);
+                }
+
+                let planned = StructPack.plan(bind_context.get_table_list(), exprs)?;
+
+                Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+                    function: planned,
+                }))
+            }
             ast::Expr::Literal(literal) => {
                 // Use the provided column binder only if this is the root of
                 // the expression.
@@ -133,6 +174,7 @@ impl<'a> BaseExpressionBinder<'a> {
                 }
                 Self::bind_literal(literal)
             }
+            ast::Expr::Parameter(n) => bind_context.bind_parameter(*n),
             ast::Expr::Array(arr) => {
                 let exprs = arr
                     .iter()
@@ -265,6 +307,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::NotEq;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -275,6 +318,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::Eq;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -285,6 +329,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::Lt;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -295,6 +340,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::LtEq;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -305,6 +351,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::Gt;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -315,6 +362,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ComparisonOperator::GtEq;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] = self.apply_collation(bind_context, [left, right])?;
                         Expression::Comparison(ComparisonExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -325,6 +373,8 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ArithOperator::Add;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] =
+                            self.apply_overflow_promotion(bind_context, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -335,6 +385,8 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ArithOperator::Sub;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] =
+                            self.apply_overflow_promotion(bind_context, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -345,6 +397,8 @@ impl<'a> BaseExpressionBinder<'a> {
                         let op = ArithOperator::Mul;
                         let [left, right] =
                             self.apply_cast_for_operator(bind_context, op, [left, right])?;
+                        let [left, right] =
+                            self.apply_overflow_promotion(bind_context, [left, right])?;
                         Expression::Arith(ArithExpr {
                             left: Box::new(left),
                             right: Box::new(right),
@@ -422,7 +476,28 @@ impl<'a> BaseExpressionBinder<'a> {
                 self.bind_subquery(bind_context, subquery, SubqueryType::Scalar)
             }
             ast::Expr::Tuple(_) => not_implemented!("tuple expressions"),
-            ast::Expr::Collate { .. } => not_implemented!("COLLATE"),
+            ast::Expr::Collate { expr, collation } => {
+                let expr = self.bind_expression(
+                    bind_context,
+                    expr,
+                    column_binder,
+                    RecursionContext {
+                        is_root: false,
+                        ..recur
+                    },
+                )?;
+
+                let name = collation
+                    .0
+                    .iter()
+                    .map(|ident| ident.as_normalized_string())
+                    .collect::<Vec<_>>()
+                    .join(".");
+                // Validate early for a nicer error.
+                let collation = Collation::from_name(&name)?;
+
+                self.bind_collate(bind_context, expr, collation)
+            }
             ast::Expr::Exists {
                 subquery,
                 not_exists,
@@ -558,6 +633,7 @@ impl<'a> BaseExpressionBinder<'a> {
                             cmp_op,
                             [needle.clone(), expr],
                         )?;
+                        let [needle, expr] = self.apply_collation(bind_context, [needle, expr])?;
                         Ok(Expression::Comparison(ComparisonExpr {
                             left: Box::new(needle),
                             right: Box::new(expr),
@@ -594,6 +670,7 @@ impl<'a> BaseExpressionBinder<'a> {
                         ..recur
                     },
                 )?;
+                bind_context.infer_parameter_type(&expr, datatype);
                 Ok(Expression::Cast(CastExpr {
                     to: datatype.clone(),
                     expr: Box::new(expr),
@@ -605,10 +682,6 @@ impl<'a> BaseExpressionBinder<'a> {
                 negated,
                 case_insensitive,
             } => {
-                if *case_insensitive {
-                    not_implemented!("case insensitive LIKE")
-                }
-
                 let expr = self.bind_expression(
                     bind_context,
                     expr,
@@ -628,6 +701,21 @@ impl<'a> BaseExpressionBinder<'a> {
                     },
                 )?;
 
+                let inputs = [expr, pattern];
+                let collation = Self::common_collation(bind_context.get_table_list(), &inputs)?;
+                // ILIKE is LIKE with both sides compared case insensitively.
+                let collation = if *case_insensitive {
+                    Some(collation.unwrap_or_default().union(Collation::NOCASE))
+                } else {
+                    collation
+                };
+                let [expr, pattern] = match collation {
+                    Some(collation) => {
+                        self.apply_collation_keys(bind_context, inputs, collation)?
+                    }
+                    None => inputs,
+                };
+
                 let scalar = Like.plan(bind_context.get_table_list(), vec![expr, pattern])?;
 
                 let mut expr = Expression::ScalarFunction(ScalarFunctionExpr { function: scalar });
@@ -798,6 +886,8 @@ impl<'a> BaseExpressionBinder<'a> {
                 };
                 let [low_left, low_right] =
                     self.apply_cast_for_operator(bind_context, low_op, [expr.clone(), low])?;
+                let [low_left, low_right] =
+                    self.apply_collation(bind_context, [low_left, low_right])?;
 
                 let left = Expression::Comparison(ComparisonExpr {
                     left: Box::new(low_left),
@@ -812,6 +902,8 @@ impl<'a> BaseExpressionBinder<'a> {
                 };
                 let [high_left, high_right] =
                     self.apply_cast_for_operator(bind_context, high_op, [expr, high])?;
+                let [high_left, high_right] =
+                    self.apply_collation(bind_context, [high_left, high_right])?;
 
                 let right = Expression::Comparison(ComparisonExpr {
                     left: Box::new(high_left),
@@ -873,7 +965,7 @@ impl<'a> BaseExpressionBinder<'a> {
                     self.bind_expressions(bind_context, results, column_binder, recur.not_root())?;
 
                 // When leading expr is provided, conditions are implicit equalities.
-                let build_condition = |cond_expr| match &expr {
+                let mut build_condition = |cond_expr| match &expr {
                     Some(expr) => {
                         let [left, right] = self.apply_cast_for_operator(
                             bind_context,
@@ -887,7 +979,10 @@ impl<'a> BaseExpressionBinder<'a> {
                             op: ComparisonOperator::Eq,
                         }))
                     }
-                    None => Ok(cond_expr),
+                    None => {
+                        bind_context.infer_parameter_type(&cond_expr, &DataType::Boolean);
+                        Ok(cond_expr)
+                    }
                 };
 
                 // TODO: Cast the results so they all produce the same type.
@@ -970,6 +1065,23 @@ impl<'a> BaseExpressionBinder<'a> {
 
                 Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
             }
+            ast::Expr::AtTimeZone { timestamp, zone } => {
+                let timestamp =
+                    self.bind_expression(bind_context, timestamp, column_binder, recur.not_root())?;
+                let zone =
+                    self.bind_expression(bind_context, zone, column_binder, recur.not_root())?;
+
+                let scalar = Box::new(AtTimeZone);
+                let inputs = self.apply_casts_for_scalar_function(
+                    bind_context,
+                    scalar.as_ref(),
+                    vec![zone, timestamp],
+                )?;
+
+                let function = scalar.plan(bind_context.get_table_list(), inputs)?;
+
+                Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
+            }
             ast::Expr::Columns(_) => {
                 // TODO: This doens't need to be the case, but there's going to
                 // be slightly different handling if this is a top-level select
@@ -1110,7 +1222,11 @@ impl<'a> BaseExpressionBinder<'a> {
             .functions
             .try_get_bound(func.reference)?;
 
-        let recur = if reference.0.is_aggregate() {
+        // Window functions can't be nested in aggregates or other window
+        // functions, check against the context we were called with.
+        let allow_windows = recur.allow_windows;
+
+        let recur = if reference.0.is_aggregate() || reference.0.is_window() {
             RecursionContext {
                 allow_windows: false,
                 allow_aggregates: false,
@@ -1247,7 +1363,7 @@ impl<'a> BaseExpressionBinder<'a> {
                 }
                 if func.over.is_some() {
                     return Err(RayexecError::new(
-                        "OVER only supported for aggregate functions",
+                        "OVER only supported for aggregate and window functions",
                     ));
                 }
 
@@ -1259,91 +1375,29 @@ impl<'a> BaseExpressionBinder<'a> {
                 Ok(Expression::ScalarFunction(ScalarFunctionExpr { function }))
             }
             (ResolvedFunction::Aggregate(agg), _) => {
-                let inputs =
-                    self.apply_casts_for_aggregate_function(bind_context, agg.as_ref(), inputs)?;
+                let inputs = self.apply_casts_for_function(bind_context, agg.as_ref(), inputs)?;
 
                 let agg = agg.plan(bind_context.get_table_list(), inputs)?;
 
                 match &func.over {
                     Some(over) => {
                         // Window
-
-                        match over {
-                            ast::WindowSpec::Named(_) => {
-                                not_implemented!("named window spec")
-                            }
-                            ast::WindowSpec::Definition(window_def) => {
-                                if window_def.existing.is_some() {
-                                    not_implemented!("inherit existing window spec definition")
-                                }
-
-                                let partition_by = self.bind_expressions(
-                                    bind_context,
-                                    &window_def.partition_by,
-                                    column_binder,
-                                    recur,
-                                )?;
-
-                                // Handle order by.
-                                //
-                                // Handled slightly different than statement
-                                // level ORDER BY in that it can't bind to an
-                                // output column.
-                                let order_by = window_def
-                                    .order_by
-                                    .iter()
-                                    .map(|order_by| {
-                                        let expr = self.bind_expression(
-                                            bind_context,
-                                            &order_by.expr,
-                                            column_binder,
-                                            recur,
-                                        )?;
-                                        Ok(BoundOrderByExpr {
-                                            expr,
-                                            desc: matches!(
-                                                order_by.typ.unwrap_or(ast::OrderByType::Asc),
-                                                ast::OrderByType::Desc
-                                            ),
-                                            nulls_first: matches!(
-                                                order_by.nulls.unwrap_or(ast::OrderByNulls::First),
-                                                ast::OrderByNulls::First
-                                            ),
-                                        })
-                                    })
-                                    .collect::<Result<Vec<_>>>()?;
-
-                                let start = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameBound::default_start(),
-                                };
-
-                                let end = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameBound::default_start(),
-                                };
-
-                                let exclude = match &window_def.frame {
-                                    Some(_frame) => {
-                                        not_implemented!("non-default window frame")
-                                    }
-                                    None => WindowFrameExclusion::default(),
-                                };
-
-                                Ok(Expression::Window(WindowExpr {
-                                    agg,
-                                    partition_by,
-                                    order_by,
-                                    start,
-                                    end,
-                                    exclude,
-                                }))
-                            }
+                        if !allow_windows {
+                            return Err(RayexecError::new(
+                                "Window functions not allowed in this context",
+                            ));
+                        }
+                        if func.distinct {
+                            not_implemented!("DISTINCT window aggregates")
                         }
+
+                        self.bind_window(
+                            bind_context,
+                            WindowFunctionKind::Aggregate(agg),
+                            over,
+                            column_binder,
+                            recur,
+                        )
                     }
                     None => {
                         // Normal aggregate.
@@ -1355,12 +1409,367 @@ impl<'a> BaseExpressionBinder<'a> {
                     }
                 }
             }
+            (ResolvedFunction::Window(window), _) => {
+                let over = match &func.over {
+                    Some(over) => over,
+                    None => {
+                        return Err(RayexecError::new(format!(
+                            "Window function '{}' requires an OVER clause",
+                            window.name()
+                        )))
+                    }
+                };
+                if !allow_windows {
+                    return Err(RayexecError::new(
+                        "Window functions not allowed in this context",
+                    ));
+                }
+                if func.distinct || func.filter.is_some() {
+                    return Err(RayexecError::new(format!(
+                        "Window function '{}' does not support DISTINCT or FILTER",
+                        window.name()
+                    )));
+                }
+
+                let inputs =
+                    self.apply_casts_for_function(bind_context, window.as_ref(), inputs)?;
+                let window = window.plan(bind_context.get_table_list(), inputs)?;
+
+                self.bind_window(
+                    bind_context,
+                    WindowFunctionKind::Window(window),
+                    over,
+                    column_binder,
+                    recur,
+                )
+            }
         }
     }
 
-    pub(crate) fn apply_cast_for_operator<const N: usize>(
+    /// Binds the OVER clause for a window function.
+    fn bind_window(
+        &self,
+        bind_context: &mut BindContext,
+        function: WindowFunctionKind,
+        over: &ast::WindowSpec<ResolvedMeta>,
+        column_binder: &mut impl ExpressionColumnBinder,
+        recur: RecursionContext,
+    ) -> Result<Expression> {
+        let window_def = match over {
+            ast::WindowSpec::Named(name) => bind_context
+                .get_named_window(self.current, &name.as_normalized_string())?
+                .clone(),
+            ast::WindowSpec::Definition(window_def) => {
+                self.expand_window_definition(bind_context, window_def)?
+            }
+        };
+
+        // Partition values aren't part of the output, so collated partition
+        // expressions can partition directly on the collation keys.
+        let partition_by = self
+            .bind_expressions(bind_context, &window_def.partition_by, column_binder, recur)?
+            .into_iter()
+            .map(
+                |expr| match Collate::collation_for_expr(bind_context.get_table_list(), &expr) {
+                    Some(collation) => self.bind_collation_key(bind_context, expr, collation),
+                    None => Ok(expr),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+
+        // Handle order by.
+        //
+        // Handled slightly different than statement level ORDER BY in that it
+        // can't bind to an output column.
+        let order_by = window_def
+            .order_by
+            .iter()
+            .map(|order_by| {
+                let expr =
+                    self.bind_expression(bind_context, &order_by.expr, column_binder, recur)?;
+                let desc = matches!(
+                    order_by.typ.unwrap_or(ast::OrderByType::Asc),
+                    ast::OrderByType::Desc
+                );
+                // Same null ordering as statement level ORDER BY.
+                let nulls_first = match order_by.nulls {
+                    Some(nulls) => matches!(nulls, ast::OrderByNulls::First),
+                    None => desc,
+                };
+                let collation = Collate::collation_for_expr(bind_context.get_table_list(), &expr)
+                    .unwrap_or_default();
+                Ok(BoundOrderByExpr {
+                    expr,
+                    desc,
+                    nulls_first,
+                    collation,
+                })
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        let (start, end, exclude) = match &window_def.frame {
+            Some(frame) => self.bind_window_frame(bind_context, frame, &order_by)?,
+            None => (
+                WindowFrameBound::default_start(),
+                WindowFrameBound::default_end(),
+                WindowFrameExclusion::default(),
+            ),
+        };
+
+        Ok(Expression::Window(WindowExpr {
+            function,
+            partition_by,
+            order_by,
+            start,
+            end,
+            exclude,
+        }))
+    }
+
+    /// Expands a window definition that references an existing named window.
+    ///
+    /// The returned definition will not reference any other window.
+    pub(crate) fn expand_window_definition(
         &self,
         bind_context: &BindContext,
+        window_def: &ast::WindowDefinition<ResolvedMeta>,
+    ) -> Result<ast::WindowDefinition<ResolvedMeta>> {
+        let existing = match &window_def.existing {
+            Some(existing) => existing.as_normalized_string(),
+            None => return Ok(window_def.clone()),
+        };
+
+        let base = bind_context.get_named_window(self.current, &existing)?;
+
+        // Rules follow Postgres.
+        if !window_def.partition_by.is_empty() {
+            return Err(RayexecError::new(format!(
+                "Cannot override PARTITION BY clause of window '{existing}'"
+            )));
+        }
+        if !window_def.order_by.is_empty() && !base.order_by.is_empty() {
+            return Err(RayexecError::new(format!(
+                "Cannot override ORDER BY clause of window '{existing}'"
+            )));
+        }
+        if base.frame.is_some() {
+            return Err(RayexecError::new(format!(
+                "Cannot copy window '{existing}' because it has a frame clause"
+            )));
+        }
+
+        let order_by = if window_def.order_by.is_empty() {
+            base.order_by.clone()
+        } else {
+            window_def.order_by.clone()
+        };
+
+        Ok(ast::WindowDefinition {
+            existing: None,
+            partition_by: base.partition_by.clone(),
+            order_by,
+            frame: window_def.frame.clone(),
+        })
+    }
+
+    /// Binds the frame for a window.
+    fn bind_window_frame(
+        &self,
+        bind_context: &mut BindContext,
+        frame: &ast::WindowFrame<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+    ) -> Result<(WindowFrameBound, WindowFrameBound, WindowFrameExclusion)> {
+        let unit = match frame.unit {
+            ast::WindowFrameUnit::Rows => WindowFrameUnit::Rows,
+            ast::WindowFrameUnit::Range => WindowFrameUnit::Range,
+            ast::WindowFrameUnit::Groups => WindowFrameUnit::Groups,
+        };
+
+        if unit == WindowFrameUnit::Groups && order_by.is_empty() {
+            return Err(RayexecError::new("GROUPS mode requires an ORDER BY clause"));
+        }
+
+        // Frame end defaults to CURRENT ROW if only the start is provided.
+        let end = frame
+            .end
+            .as_ref()
+            .unwrap_or(&ast::WindowFrameBound::CurrentRow);
+
+        match (&frame.start, end) {
+            (ast::WindowFrameBound::UnboundedFollowing, _) => {
+                return Err(RayexecError::new(
+                    "Frame start cannot be UNBOUNDED FOLLOWING",
+                ))
+            }
+            (_, ast::WindowFrameBound::UnboundedPreceding) => {
+                return Err(RayexecError::new("Frame end cannot be UNBOUNDED PRECEDING"))
+            }
+            (ast::WindowFrameBound::CurrentRow, ast::WindowFrameBound::Preceding(_)) => {
+                return Err(RayexecError::new(
+                    "Frame starting from current row cannot have preceding rows",
+                ))
+            }
+            (
+                ast::WindowFrameBound::Following(_),
+                ast::WindowFrameBound::Preceding(_) | ast::WindowFrameBound::CurrentRow,
+            ) => {
+                return Err(RayexecError::new(
+                    "Frame starting from following row cannot have preceding rows",
+                ))
+            }
+            _ => (),
+        }
+
+        let start = self.bind_window_frame_bound(bind_context, unit, &frame.start, order_by)?;
+        let end = self.bind_window_frame_bound(bind_context, unit, end, order_by)?;
+
+        let exclude = match frame.exclusion {
+            Some(ast::WindowFrameExclusion::ExcludeCurrentRow) => {
+                WindowFrameExclusion::ExcludeCurrentRow
+            }
+            Some(ast::WindowFrameExclusion::ExcludeGroup) => WindowFrameExclusion::ExcludeGroup,
+            Some(ast::WindowFrameExclusion::ExcludeTies) => WindowFrameExclusion::ExcludeTies,
+            Some(ast::WindowFrameExclusion::ExcludeNoOthers) | None => {
+                WindowFrameExclusion::ExcludeNoOthers
+            }
+        };
+
+        Ok((start, end, exclude))
+    }
+
+    fn bind_window_frame_bound(
+        &self,
+        bind_context: &mut BindContext,
+        unit: WindowFrameUnit,
+        bound: &ast::WindowFrameBound<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+    ) -> Result<WindowFrameBound> {
+        Ok(match bound {
+            ast::WindowFrameBound::CurrentRow => WindowFrameBound::CurrentRow(unit),
+            ast::WindowFrameBound::UnboundedPreceding => WindowFrameBound::UnboundedPreceding(unit),
+            ast::WindowFrameBound::UnboundedFollowing => WindowFrameBound::UnboundedFollowing(unit),
+            ast::WindowFrameBound::Preceding(offset) => {
+                let offset =
+                    self.bind_window_frame_offset(bind_context, unit, offset, order_by, true)?;
+                WindowFrameBound::Preceding(unit, Box::new(offset))
+            }
+            ast::WindowFrameBound::Following(offset) => {
+                let offset =
+                    self.bind_window_frame_offset(bind_context, unit, offset, order_by, false)?;
+                WindowFrameBound::Following(unit, Box::new(offset))
+            }
+        })
+    }
+
+    /// Binds the offset for a PRECEDING or FOLLOWING frame bound.
+    ///
+    /// See `WindowFrameBound` for what the resulting expression represents.
+    fn bind_window_frame_offset(
+        &self,
+        bind_context: &mut BindContext,
+        unit: WindowFrameUnit,
+        offset: &ast::Expr<ResolvedMeta>,
+        order_by: &[BoundOrderByExpr],
+        preceding: bool,
+    ) -> Result<Expression> {
+        let offset = ConstantBinder::new(self.resolve_context).bind_constant_expression(offset)?;
+
+        let is_negative = match &offset {
+            ScalarValue::Null => {
+                return Err(RayexecError::new("Window frame offset cannot be NULL"))
+            }
+            ScalarValue::Float16(v) => v.is_sign_negative(),
+            ScalarValue::Float32(v) => v.is_sign_negative(),
+            ScalarValue::Float64(v) => v.is_sign_negative(),
+            ScalarValue::Int8(v) => *v < 0,
+            ScalarValue::Int16(v) => *v < 0,
+            ScalarValue::Int32(v) => *v < 0,
+            ScalarValue::Int64(v) => *v < 0,
+            ScalarValue::Int128(v) => *v < 0,
+            ScalarValue::Decimal64(v) => v.value < 0,
+            ScalarValue::Decimal128(v) => v.value < 0,
+            ScalarValue::Interval(v) => v.months < 0 || v.days < 0 || v.nanos < 0,
+            _ => false,
+        };
+        if is_negative {
+            return Err(RayexecError::new(format!(
+                "Window frame offset cannot be negative, got {offset}"
+            )));
+        }
+
+        match unit {
+            WindowFrameUnit::Rows | WindowFrameUnit::Groups => {
+                let offset = offset.try_as_i64().map_err(|_| {
+                    RayexecError::new(format!(
+                        "{unit} frame offset must be an integer, got {offset}"
+                    ))
+                })?;
+
+                Ok(Expression::Literal(LiteralExpr {
+                    literal: ScalarValue::Int64(offset),
+                }))
+            }
+            WindowFrameUnit::Range => {
+                let order_by = match order_by {
+                    [order_by] => order_by,
+                    _ => {
+                        return Err(RayexecError::new(
+                            "RANGE with offset PRECEDING/FOLLOWING requires exactly one ORDER BY column",
+                        ))
+                    }
+                };
+
+                // Preceding rows come before the current row in the sort order,
+                // meaning lower values for ascending order and higher values
+                // for descending order.
+                let op = if preceding != order_by.desc {
+                    ArithOperator::Sub
+                } else {
+                    ArithOperator::Add
+                };
+
+                let order_type = order_by.expr.datatype(bind_context.get_table_list())?;
+                let offset_type = offset.datatype();
+
+                let [left, right] = self.apply_cast_for_operator(
+                    bind_context,
+                    op,
+                    [
+                        order_by.expr.clone(),
+                        Expression::Literal(LiteralExpr { literal: offset }),
+                    ],
+                )?;
+                let boundary = Expression::Arith(ArithExpr {
+                    left: Box::new(left),
+                    right: Box::new(right),
+                    op,
+                });
+
+                let boundary_type = boundary
+                    .datatype(bind_context.get_table_list())
+                    .map_err(|_| {
+                    RayexecError::new(format!(
+                        "RANGE offset of type {offset_type} not supported with ORDER BY of type {order_type}"
+                    ))
+                })?;
+
+                // Ensure the boundary values can be compared with the order by
+                // values directly.
+                if boundary_type != order_type {
+                    return Ok(Expression::Cast(CastExpr {
+                        to: order_type,
+                        expr: Box::new(boundary),
+                    }));
+                }
+
+                Ok(boundary)
+            }
+        }
+    }
+
+    pub(crate) fn apply_cast_for_operator<const N: usize>(
+        &self,
+        bind_context: &mut BindContext,
         operator: impl AsScalarFunction,
         inputs: [Expression; N],
     ) -> Result<[Expression; N]> {
@@ -1412,11 +1821,170 @@ impl<'a> BaseExpressionBinder<'a> {
             .map_err(|_| RayexecError::new("Number of casted inputs incorrect"))
     }
 
+    /// Plans a `collate` function call for the expression.
+    ///
+    /// If the expression is already collated, the existing collation is
+    /// replaced.
+    fn bind_collate(
+        &self,
+        bind_context: &BindContext,
+        expr: Expression,
+        collation: Collation,
+    ) -> Result<Expression> {
+        self.plan_collation_function(bind_context, &Collate, expr, collation)
+    }
+
+    /// Plans a `collation_key` function call for the expression, stripping any
+    /// existing collation from the expression.
+    fn bind_collation_key(
+        &self,
+        bind_context: &BindContext,
+        expr: Expression,
+        collation: Collation,
+    ) -> Result<Expression> {
+        self.plan_collation_function(bind_context, &CollationKey, expr, collation)
+    }
+
+    fn plan_collation_function(
+        &self,
+        bind_context: &BindContext,
+        function: &dyn ScalarFunction,
+        expr: Expression,
+        collation: Collation,
+    ) -> Result<Expression> {
+        let expr = match expr {
+            Expression::ScalarFunction(mut scalar)
+                if scalar.function.function.name() == Collate.name() =>
+            {
+                scalar.function.inputs.swap_remove(0)
+            }
+            expr => expr,
+        };
+
+        let datatype = expr.datatype(bind_context.get_table_list())?;
+        let expr = match datatype {
+            DataType::Utf8 => expr,
+            // Allow collating NULL literals and parameters.
+            DataType::Null => Expression::Cast(CastExpr {
+                to: DataType::Utf8,
+                expr: Box::new(expr),
+            }),
+            other => {
+                return Err(RayexecError::new(format!(
+                    "COLLATE can only be applied to strings, got {other}"
+                )))
+            }
+        };
+
+        let scalar = function.plan(
+            bind_context.get_table_list(),
+            vec![
+                expr,
+                Expression::Literal(LiteralExpr {
+                    literal: collation.to_string().into(),
+                }),
+            ],
+        )?;
+
+        Ok(Expression::ScalarFunction(ScalarFunctionExpr {
+            function: scalar,
+        }))
+    }
+
+    /// Get the collation shared by all collated inputs.
+    ///
+    /// Returns None if none of the inputs are collated.
+    fn common_collation(
+        table_list: &TableList,
+        inputs: &[Expression],
+    ) -> Result<Option<Collation>> {
+        let mut collation: Option<Collation> = None;
+        for input in inputs {
+            if let Some(input_collation) = Collate::collation_for_expr(table_list, input) {
+                match collation {
+                    Some(existing) if existing != input_collation => {
+                        return Err(RayexecError::new(format!(
+                            "Conflicting collations: {existing} and {input_collation}"
+                        )))
+                    }
+                    _ => collation = Some(input_collation),
+                }
+            }
+        }
+        Ok(collation)
+    }
+
+    /// Applies the collation of any collated input to all string inputs.
+    ///
+    /// This makes `a COLLATE nocase = b` compare the collation keys of both
+    /// sides.
+    fn apply_collation<const N: usize>(
+        &self,
+        bind_context: &BindContext,
+        inputs: [Expression; N],
+    ) -> Result<[Expression; N]> {
+        match Self::common_collation(bind_context.get_table_list(), &inputs)? {
+            Some(collation) => self.apply_collation_keys(bind_context, inputs, collation),
+            None => Ok(inputs),
+        }
+    }
+
+    /// Replaces all string inputs with their collation keys.
+    fn apply_collation_keys<const N: usize>(
+        &self,
+        bind_context: &BindContext,
+        inputs: [Expression; N],
+        collation: Collation,
+    ) -> Result<[Expression; N]> {
+        let mut outputs = Vec::with_capacity(N);
+        for input in inputs {
+            if Collate::collation_for_expr(bind_context.get_table_list(), &input).is_some() {
+                outputs.push(self.bind_collation_key(bind_context, input, collation)?);
+                continue;
+            }
+            match input.datatype(bind_context.get_table_list())? {
+                DataType::Utf8 | DataType::Null => {
+                    outputs.push(self.bind_collation_key(bind_context, input, collation)?)
+                }
+                _ => outputs.push(input),
+            }
+        }
+
+        outputs
+            .try_into()
+            .map_err(|_| RayexecError::new("Number of collated inputs incorrect"))
+    }
+
+    /// Widens integer inputs to an arithmetic operator if the session is
+    /// configured to promote on overflow.
+    ///
+    /// Both inputs are expected to have already been cast to the same type.
+    fn apply_overflow_promotion(
+        &self,
+        bind_context: &BindContext,
+        inputs: [Expression; 2],
+    ) -> Result<[Expression; 2]> {
+        let datatype = inputs[0].datatype(bind_context.get_table_list())?;
+        if inputs[1].datatype(bind_context.get_table_list())? != datatype {
+            return Ok(inputs);
+        }
+
+        match bind_context.overflow_behavior().promoted_type(&datatype) {
+            Some(promoted) => Ok(inputs.map(|input| {
+                Expression::Cast(CastExpr {
+                    to: promoted.clone(),
+                    expr: Box::new(input),
+                })
+            })),
+            None => Ok(inputs),
+        }
+    }
+
     /// Applies casts to an input expression based on the signatures for a
     /// scalar function.
     fn apply_casts_for_scalar_function(
         &self,
-        bind_context: &BindContext,
+        bind_context: &mut BindContext,
         scalar: &dyn ScalarFunction,
         inputs: Vec<Expression>,
     ) -> Result<Vec<Expression>> {
@@ -1455,12 +2023,24 @@ impl<'a> BaseExpressionBinder<'a> {
             let inputs = inputs
                 .into_iter()
                 .zip(candidate.casts)
-                .map(|(input, cast_to)| {
+                .enumerate()
+                .map(|(idx, (input, cast_to))| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to: to_id, .. } => {
+                            let to = DataType::try_default_datatype(to_id)?;
+                            if is_unambiguous_parameter_cast(
+                                idx,
+                                to_id,
+                                &input_datatypes,
+                                &candidates,
+                            ) {
+                                bind_context.infer_parameter_type(&input, &to);
+                            }
+                            Expression::Cast(CastExpr {
+                                to,
+                                expr: Box::new(input),
+                            })
+                        }
                         CastType::NoCastNeeded => input,
                     })
                 })
@@ -1470,11 +2050,13 @@ impl<'a> BaseExpressionBinder<'a> {
         }
     }
 
+    /// Applies casts to an input expression based on the signatures for an
+    /// aggregate or window function.
     // TODO: Reduce dupliation with the scalar one.
-    fn apply_casts_for_aggregate_function(
+    fn apply_casts_for_function(
         &self,
-        bind_context: &BindContext,
-        agg: &dyn AggregateFunction,
+        bind_context: &mut BindContext,
+        func: &(impl FunctionInfo + ?Sized),
         inputs: Vec<Expression>,
     ) -> Result<Vec<Expression>> {
         let input_datatypes = inputs
@@ -1482,17 +2064,17 @@ impl<'a> BaseExpressionBinder<'a> {
             .map(|expr| expr.datatype(bind_context.get_table_list()))
             .collect::<Result<Vec<_>>>()?;
 
-        if agg.exact_signature(&input_datatypes).is_some() {
+        if func.exact_signature(&input_datatypes).is_some() {
             // Exact
             Ok(inputs)
         } else {
             // Try to find candidates that we can cast to.
-            let mut candidates = agg.candidate(&input_datatypes);
+            let mut candidates = func.candidate(&input_datatypes);
 
             if candidates.is_empty() {
                 return Err(RayexecError::new(format!(
                     "Invalid inputs to '{}': {}",
-                    agg.name(),
+                    func.name(),
                     input_datatypes.display_with_brackets(),
                 )));
             }
@@ -1504,12 +2086,24 @@ impl<'a> BaseExpressionBinder<'a> {
             let inputs = inputs
                 .into_iter()
                 .zip(candidate.casts)
-                .map(|(input, cast_to)| {
+                .enumerate()
+                .map(|(idx, (input, cast_to))| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to: to_id, .. } => {
+                            let to = DataType::try_default_datatype(to_id)?;
+                            if is_unambiguous_parameter_cast(
+                                idx,
+                                to_id,
+                                &input_datatypes,
+                                &candidates,
+                            ) {
+                                bind_context.infer_parameter_type(&input, &to);
+                            }
+                            Expression::Cast(CastExpr {
+                                to,
+                                expr: Box::new(input),
+                            })
+                        }
                         CastType::NoCastNeeded => input,
                     })
                 })
@@ -1525,7 +2119,7 @@ impl<'a> BaseExpressionBinder<'a> {
     // See: <https://github.com/rust-lang/rust/issues/65991>
     pub(crate) fn apply_casts_for_table_function(
         &self,
-        bind_context: &BindContext,
+        bind_context: &mut BindContext,
         table: &dyn TableFunction,
         inputs: Vec<Expression>,
     ) -> Result<Vec<Expression>> {
@@ -1557,12 +2151,24 @@ impl<'a> BaseExpressionBinder<'a> {
             let inputs = inputs
                 .into_iter()
                 .zip(candidate.casts)
-                .map(|(input, cast_to)| {
+                .enumerate()
+                .map(|(idx, (input, cast_to))| {
                     Ok(match cast_to {
-                        CastType::Cast { to, .. } => Expression::Cast(CastExpr {
-                            to: DataType::try_default_datatype(to)?,
-                            expr: Box::new(input),
-                        }),
+                        CastType::Cast { to: to_id, .. } => {
+                            let to = DataType::try_default_datatype(to_id)?;
+                            if is_unambiguous_parameter_cast(
+                                idx,
+                                to_id,
+                                &input_datatypes,
+                                &candidates,
+                            ) {
+                                bind_context.infer_parameter_type(&input, &to);
+                            }
+                            Expression::Cast(CastExpr {
+                                to,
+                                expr: Box::new(input),
+                            })
+                        }
                         CastType::NoCastNeeded => input,
                     })
                 })
@@ -1572,3 +2178,27 @@ impl<'a> BaseExpressionBinder<'a> {
         }
     }
 }
+
+/// Checks if a placeholder can take its type from a cast chosen for a
+/// function input.
+///
+/// Placeholders are bound as untyped NULLs, so the chosen signature is
+/// arbitrary (e.g. `abs($1)`) unless another input already has the type, or
+/// every other candidate would cast the input to the same type.
+fn is_unambiguous_parameter_cast(
+    idx: usize,
+    to: DataTypeId,
+    input_datatypes: &[DataType],
+    other_candidates: &[CandidateSignature],
+) -> bool {
+    let matches_input = input_datatypes
+        .iter()
+        .enumerate()
+        .any(|(other, datatype)| other != idx && datatype.datatype_id() == to);
+
+    let all_agree = other_candidates.iter().all(|candidate| {
+        matches!(candidate.casts.get(idx), Some(CastType::Cast { to: other, .. }) if *other == to)
+    });
+
+    matches_input || all_agree
+}
@@ -8,6 +8,7 @@ pub mod bind_describe;
 pub mod bind_drop;
 pub mod bind_explain;
 pub mod bind_insert;
+pub mod bind_modify;
 pub mod bind_query;
 pub mod bind_set;
 pub mod bind_statement;
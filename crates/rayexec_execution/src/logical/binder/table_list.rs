@@ -1,8 +1,10 @@
+use std::collections::HashMap;
 use std::fmt;
 
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
+use crate::arrays::compute::collation::Collation;
 use crate::arrays::datatype::DataType;
 
 /// Reference to a table in a context.
@@ -73,6 +75,11 @@ pub struct Table {
     pub alias: Option<TableAlias>,
     pub column_types: Vec<DataType>,
     pub column_names: Vec<String>,
+    /// Collations for columns bound from collated expressions, keyed by column
+    /// index.
+    ///
+    /// Lets the collation follow a column through aliases and subqueries.
+    pub column_collations: HashMap<usize, Collation>,
 }
 
 impl Table {
@@ -128,6 +135,7 @@ impl TableList {
             alias,
             column_types,
             column_names,
+            column_collations: HashMap::new(),
         };
         self.tables.push(table);
 
@@ -18,11 +18,24 @@ pub struct LogicalCopyTo {
     pub source_schema: Schema,
     pub location: FileLocation,
     pub copy_to: Box<dyn CopyToFunction>,
+    /// Columns to partition the output by.
+    ///
+    /// If not empty, the location is a directory that hive partitioned files
+    /// are written to.
+    pub partition_by: Vec<usize>,
 }
 
 impl Explainable for LogicalCopyTo {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("CopyTo")
+        let mut ent = ExplainEntry::new("CopyTo");
+        if !self.partition_by.is_empty() {
+            let names = self
+                .partition_by
+                .iter()
+                .map(|&idx| &self.source_schema.fields[idx].name);
+            ent = ent.with_values("partition_by", names);
+        }
+        ent
     }
 }
 
@@ -16,6 +16,8 @@ pub enum ExplainFormat {
 pub struct LogicalExplain {
     pub analyze: bool,
     pub verbose: bool,
+    /// If timings should be included in EXPLAIN ANALYZE output.
+    pub timing: bool,
     pub format: ExplainFormat,
     pub logical_unoptimized: Box<LogicalOperator>,
     pub logical_optimized: Option<Box<LogicalOperator>>,
@@ -0,0 +1,150 @@
+use std::sync::Arc;
+
+use rayexec_error::Result;
+
+use super::binder::bind_context::BindContext;
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::database::catalog_entry::CatalogEntry;
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// Update rows in a table.
+///
+/// Input is the row id followed by the new values for all table columns.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalUpdate {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+}
+
+impl Explainable for LogicalUpdate {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Update").with_value("table", &self.table.name)
+    }
+}
+
+impl LogicalNode for Node<LogicalUpdate> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+/// Delete rows from a table.
+///
+/// Input is a single column containing row ids to delete.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalDelete {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+}
+
+impl Explainable for LogicalDelete {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Delete").with_value("table", &self.table.name)
+    }
+}
+
+impl LogicalNode for Node<LogicalDelete> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+/// Action to apply to rows from the input of a merge.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MergeAction {
+    /// Insert a new row, with values for every table column starting at
+    /// `offset` in the input.
+    Insert { offset: usize },
+    /// Update the matched row, with values for every table column starting at
+    /// `offset` in the input.
+    Update { offset: usize },
+    /// Delete the matched row.
+    Delete,
+}
+
+/// How to handle conflicts between rows inserted by the same INSERT ... ON
+/// CONFLICT.
+///
+/// Conflicts with rows already in the table are handled by the join feeding
+/// the merge. This handles the remaining case where multiple rows being
+/// inserted have the same values for the conflict columns.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InsertConflict {
+    /// Indices of the table columns making up the conflict target.
+    pub columns: Vec<usize>,
+    /// Error on a conflicting row (DO UPDATE) instead of skipping it (DO
+    /// NOTHING).
+    pub error_on_conflict: bool,
+}
+
+/// Insert, update, and delete rows in a table.
+///
+/// Input is the index of the action to apply, followed by the row id of the
+/// matched row, followed by the values referenced by the actions.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogicalMerge {
+    pub catalog: String,
+    pub schema: String,
+    pub table: Arc<CatalogEntry>,
+    pub actions: Vec<MergeAction>,
+    /// Set when planning an INSERT ... ON CONFLICT.
+    pub insert_conflict: Option<InsertConflict>,
+}
+
+impl Explainable for LogicalMerge {
+    fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
+        ExplainEntry::new("Merge").with_value("table", &self.table.name)
+    }
+}
+
+impl LogicalNode for Node<LogicalMerge> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        Vec::new()
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
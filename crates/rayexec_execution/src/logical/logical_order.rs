@@ -3,6 +3,7 @@ use rayexec_error::Result;
 use super::binder::bind_context::BindContext;
 use super::binder::bind_query::bind_modifier::BoundOrderByExpr;
 use super::binder::table_list::TableRef;
+use super::logical_limit::LogicalLimit;
 use super::operator::{LogicalNode, Node};
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
 use crate::expr::Expression;
@@ -10,11 +11,23 @@ use crate::expr::Expression;
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalOrder {
     pub exprs: Vec<BoundOrderByExpr>,
+    /// Limit to apply to the sorted output.
+    ///
+    /// Set by the optimizer when a LIMIT sits directly on top of this ORDER BY,
+    /// letting us only keep the top rows instead of sorting everything.
+    pub limit: Option<LogicalLimit>,
 }
 
 impl Explainable for LogicalOrder {
     fn explain_entry(&self, _conf: ExplainConfig) -> ExplainEntry {
-        ExplainEntry::new("Order").with_values("expressions", &self.exprs)
+        let mut ent = ExplainEntry::new("Order").with_values("expressions", &self.exprs);
+        if let Some(limit) = &self.limit {
+            ent = ent.with_value("limit", limit.limit);
+            if let Some(offset) = limit.offset {
+                ent = ent.with_value("offset", offset);
+            }
+        }
+        ent
     }
 }
 
@@ -0,0 +1,102 @@
+use rayexec_error::Result;
+
+use super::binder::bind_context::{BindContext, CteRef};
+use super::binder::table_list::TableRef;
+use super::operator::{LogicalNode, Node};
+use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
+use crate::expr::Expression;
+
+/// Iteratively executes the recursive term of a CTE until it produces no rows.
+///
+/// The first child is the anchor (non-recursive term) and the second child is
+/// the recursive term. The recursive term reads the rows produced by the
+/// previous iteration through a `LogicalRecursiveCteScan` referencing the same
+/// working table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalRecursiveCte {
+    /// Table ref for the output of the CTE.
+    pub table_ref: TableRef,
+    /// The working table read by the recursive term.
+    pub working_table: CteRef,
+    /// If duplicate rows should be kept (UNION ALL).
+    pub all: bool,
+}
+
+impl Explainable for LogicalRecursiveCte {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        let kind = if self.all { "UNION ALL" } else { "UNION" };
+
+        let mut ent = ExplainEntry::new("RecursiveCte")
+            .with_value("kind", kind)
+            .with_value("working_table", self.working_table);
+        if conf.verbose {
+            ent = ent.with_value("table_ref", self.table_ref);
+        }
+
+        ent
+    }
+}
+
+impl LogicalNode for Node<LogicalRecursiveCte> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        vec![self.node.table_ref]
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
+
+/// Scan of the working table of a recursive CTE.
+///
+/// Only valid within the recursive term of the `LogicalRecursiveCte` with the
+/// same working table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LogicalRecursiveCteScan {
+    /// Table ref for the output of the scan.
+    pub table_ref: TableRef,
+    /// The working table being scanned.
+    pub working_table: CteRef,
+}
+
+impl Explainable for LogicalRecursiveCteScan {
+    fn explain_entry(&self, conf: ExplainConfig) -> ExplainEntry {
+        let mut ent =
+            ExplainEntry::new("RecursiveCteScan").with_value("working_table", self.working_table);
+        if conf.verbose {
+            ent = ent.with_value("table_ref", self.table_ref);
+        }
+
+        ent
+    }
+}
+
+impl LogicalNode for Node<LogicalRecursiveCteScan> {
+    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
+        vec![self.node.table_ref]
+    }
+
+    fn for_each_expr<F>(&self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+
+    fn for_each_expr_mut<F>(&mut self, _func: &mut F) -> Result<()>
+    where
+        F: FnMut(&mut Expression) -> Result<()>,
+    {
+        Ok(())
+    }
+}
@@ -5,8 +5,8 @@ use rayexec_error::Result;
 use super::binder::bind_context::BindContext;
 use super::binder::table_list::TableRef;
 use super::operator::{LogicalNode, Node};
-use super::scan_filter::ScanFilter;
-use super::statistics::StatisticsValue;
+use super::scan_filter::{ScanFilter, ScanFilterType};
+use super::statistics::{ColumnStatistics, TableStatistics};
 use crate::arrays::datatype::DataType;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::explain::explainable::{ExplainConfig, ExplainEntry, Explainable};
@@ -21,6 +21,9 @@ pub enum ScanSource {
         catalog: String,
         schema: String,
         source: Arc<CatalogEntry>,
+        /// If the table should produce row ids as an additional column after
+        /// all table columns.
+        row_ids: bool,
     },
     TableFunction {
         function: PlannedTableFunction,
@@ -35,17 +38,6 @@ pub enum ScanSource {
     },
 }
 
-impl ScanSource {
-    pub fn cardinality(&self) -> StatisticsValue<usize> {
-        match self {
-            Self::Table { .. } => StatisticsValue::Unknown,
-            Self::TableFunction { function } => function.cardinality,
-            Self::ExpressionList { rows } => StatisticsValue::Exact(rows.len()),
-            Self::View { .. } => StatisticsValue::Unknown,
-        }
-    }
-}
-
 /// Represents a scan from some source.
 #[derive(Debug, Clone, PartialEq)]
 pub struct LogicalScan {
@@ -75,6 +67,19 @@ pub struct LogicalScan {
     pub scan_filters: Vec<ScanFilter>,
     /// Source of the scan.
     pub source: ScanSource,
+    /// Statistics for the source.
+    ///
+    /// Column statistics are indexed by the column's position in the source,
+    /// not the position in the projection.
+    pub statistics: TableStatistics,
+}
+
+impl LogicalScan {
+    /// Get statistics for a column by its index in the projection.
+    pub fn projected_column_statistics(&self, idx: usize) -> Option<&ColumnStatistics> {
+        let col = *self.projection.get(idx)?;
+        self.statistics.column(col)
+    }
 }
 
 impl Explainable for LogicalScan {
@@ -88,6 +93,7 @@ impl Explainable for LogicalScan {
                 catalog,
                 schema,
                 source,
+                ..
             }
             | ScanSource::View {
                 catalog,
@@ -102,6 +108,19 @@ impl Explainable for LogicalScan {
             }
         }
 
+        if !self.scan_filters.is_empty() {
+            let filters: Vec<_> = self
+                .scan_filters
+                .iter()
+                .map(|filter| match &filter.filter {
+                    ScanFilterType::ConstComparison { op, constant } => {
+                        format!("{} {op} {constant}", self.names[filter.column])
+                    }
+                })
+                .collect();
+            ent = ent.with_values("filters", filters);
+        }
+
         if conf.verbose {
             ent = ent
                 .with_value("table_ref", self.table_ref)
@@ -28,8 +28,12 @@ impl Explainable for LogicalWindow {
 }
 
 impl LogicalNode for Node<LogicalWindow> {
-    fn get_output_table_refs(&self, _bind_context: &BindContext) -> Vec<TableRef> {
-        vec![self.node.windows_table]
+    /// Windows output all columns from the child, followed by the computed
+    /// window columns.
+    fn get_output_table_refs(&self, bind_context: &BindContext) -> Vec<TableRef> {
+        let mut refs = self.get_children_table_refs(bind_context);
+        refs.push(self.node.windows_table);
+        refs
     }
 
     fn for_each_expr<F>(&self, func: &mut F) -> Result<()>
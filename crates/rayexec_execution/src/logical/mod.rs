@@ -59,8 +59,10 @@ pub mod logical_insert;
 pub mod logical_join;
 pub mod logical_limit;
 pub mod logical_materialization;
+pub mod logical_modify;
 pub mod logical_order;
 pub mod logical_project;
+pub mod logical_recursive_cte;
 pub mod logical_scan;
 pub mod logical_set;
 pub mod logical_setop;
@@ -25,8 +25,10 @@ use super::logical_join::{
 };
 use super::logical_limit::LogicalLimit;
 use super::logical_materialization::{LogicalMagicMaterializationScan, LogicalMaterializationScan};
+use super::logical_modify::{LogicalDelete, LogicalMerge, LogicalUpdate};
 use super::logical_order::LogicalOrder;
 use super::logical_project::LogicalProject;
+use super::logical_recursive_cte::{LogicalRecursiveCte, LogicalRecursiveCteScan};
 use super::logical_scan::LogicalScan;
 use super::logical_set::{LogicalResetVar, LogicalSetVar, LogicalShowVar};
 use super::logical_setop::LogicalSetop;
@@ -280,6 +282,9 @@ pub enum LogicalOperator {
     DetachDatabase(Node<LogicalDetachDatabase>),
     Drop(Node<LogicalDrop>),
     Insert(Node<LogicalInsert>),
+    Update(Node<LogicalUpdate>),
+    Delete(Node<LogicalDelete>),
+    Merge(Node<LogicalMerge>),
     CreateSchema(Node<LogicalCreateSchema>),
     CreateTable(Node<LogicalCreateTable>),
     CreateView(Node<LogicalCreateView>),
@@ -293,6 +298,8 @@ pub enum LogicalOperator {
     Unnest(Node<LogicalUnnest>),
     Window(Node<LogicalWindow>),
     InOut(Node<LogicalInOut>),
+    RecursiveCte(Node<LogicalRecursiveCte>),
+    RecursiveCteScan(Node<LogicalRecursiveCteScan>),
 }
 
 impl LogicalOperator {
@@ -378,6 +385,9 @@ impl LogicalOperator {
             Self::DetachDatabase(n) => &n.children,
             Self::Drop(n) => &n.children,
             Self::Insert(n) => &n.children,
+            Self::Update(n) => &n.children,
+            Self::Delete(n) => &n.children,
+            Self::Merge(n) => &n.children,
             Self::CreateSchema(n) => &n.children,
             Self::CreateTable(n) => &n.children,
             Self::CreateView(n) => &n.children,
@@ -391,6 +401,8 @@ impl LogicalOperator {
             Self::Unnest(n) => &n.children,
             Self::Window(n) => &n.children,
             Self::InOut(n) => &n.children,
+            Self::RecursiveCte(n) => &n.children,
+            Self::RecursiveCteScan(n) => &n.children,
         }
     }
 
@@ -415,6 +427,9 @@ impl LogicalOperator {
             Self::DetachDatabase(n) => &mut n.children,
             Self::Drop(n) => &mut n.children,
             Self::Insert(n) => &mut n.children,
+            Self::Update(n) => &mut n.children,
+            Self::Delete(n) => &mut n.children,
+            Self::Merge(n) => &mut n.children,
             Self::CreateSchema(n) => &mut n.children,
             Self::CreateTable(n) => &mut n.children,
             Self::CreateView(n) => &mut n.children,
@@ -428,6 +443,8 @@ impl LogicalOperator {
             Self::Unnest(n) => &mut n.children,
             Self::Window(n) => &mut n.children,
             Self::InOut(n) => &mut n.children,
+            Self::RecursiveCte(n) => &mut n.children,
+            Self::RecursiveCteScan(n) => &mut n.children,
         }
     }
 
@@ -456,6 +473,9 @@ impl LogicalOperator {
             LogicalOperator::DetachDatabase(n) => n.estimated_cardinality,
             LogicalOperator::Drop(n) => n.estimated_cardinality,
             LogicalOperator::Insert(n) => n.estimated_cardinality,
+            LogicalOperator::Update(n) => n.estimated_cardinality,
+            LogicalOperator::Delete(n) => n.estimated_cardinality,
+            LogicalOperator::Merge(n) => n.estimated_cardinality,
             LogicalOperator::CreateSchema(n) => n.estimated_cardinality,
             LogicalOperator::CreateTable(n) => n.estimated_cardinality,
             LogicalOperator::CreateView(n) => n.estimated_cardinality,
@@ -469,6 +489,8 @@ impl LogicalOperator {
             LogicalOperator::Unnest(n) => n.estimated_cardinality,
             LogicalOperator::Window(n) => n.estimated_cardinality,
             LogicalOperator::InOut(n) => n.estimated_cardinality,
+            LogicalOperator::RecursiveCte(n) => n.estimated_cardinality,
+            LogicalOperator::RecursiveCteScan(n) => n.estimated_cardinality,
         }
     }
 }
@@ -495,6 +517,9 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::DetachDatabase(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Drop(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Insert(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Update(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Delete(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::Merge(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateSchema(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateTable(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::CreateView(n) => n.get_output_table_refs(bind_context),
@@ -508,6 +533,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::Window(n) => n.get_output_table_refs(bind_context),
             LogicalOperator::InOut(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::RecursiveCte(n) => n.get_output_table_refs(bind_context),
+            LogicalOperator::RecursiveCteScan(n) => n.get_output_table_refs(bind_context),
         }
     }
 
@@ -535,6 +562,9 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::DetachDatabase(n) => n.for_each_expr(func),
             LogicalOperator::Drop(n) => n.for_each_expr(func),
             LogicalOperator::Insert(n) => n.for_each_expr(func),
+            LogicalOperator::Update(n) => n.for_each_expr(func),
+            LogicalOperator::Delete(n) => n.for_each_expr(func),
+            LogicalOperator::Merge(n) => n.for_each_expr(func),
             LogicalOperator::CreateSchema(n) => n.for_each_expr(func),
             LogicalOperator::CreateTable(n) => n.for_each_expr(func),
             LogicalOperator::CreateView(n) => n.for_each_expr(func),
@@ -548,6 +578,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.for_each_expr(func),
             LogicalOperator::Window(n) => n.for_each_expr(func),
             LogicalOperator::InOut(n) => n.for_each_expr(func),
+            LogicalOperator::RecursiveCte(n) => n.for_each_expr(func),
+            LogicalOperator::RecursiveCteScan(n) => n.for_each_expr(func),
         }
     }
 
@@ -575,6 +607,9 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::DetachDatabase(n) => n.for_each_expr_mut(func),
             LogicalOperator::Drop(n) => n.for_each_expr_mut(func),
             LogicalOperator::Insert(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Update(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Delete(n) => n.for_each_expr_mut(func),
+            LogicalOperator::Merge(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateSchema(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateTable(n) => n.for_each_expr_mut(func),
             LogicalOperator::CreateView(n) => n.for_each_expr_mut(func),
@@ -588,6 +623,8 @@ impl LogicalNode for LogicalOperator {
             LogicalOperator::Unnest(n) => n.for_each_expr_mut(func),
             LogicalOperator::Window(n) => n.for_each_expr_mut(func),
             LogicalOperator::InOut(n) => n.for_each_expr_mut(func),
+            LogicalOperator::RecursiveCte(n) => n.for_each_expr_mut(func),
+            LogicalOperator::RecursiveCteScan(n) => n.for_each_expr_mut(func),
         }
     }
 }
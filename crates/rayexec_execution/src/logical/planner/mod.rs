@@ -35,7 +35,9 @@ mod plan_copy;
 mod plan_create_table;
 mod plan_explain;
 mod plan_insert;
+mod plan_modify;
 mod plan_query;
+mod plan_recursive_cte;
 mod plan_select;
 mod plan_setop;
 mod plan_subquery;
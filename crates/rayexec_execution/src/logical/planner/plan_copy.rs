@@ -29,6 +29,7 @@ impl CopyPlanner {
                 source_schema: copy_to.source_schema,
                 location: copy_to.location,
                 copy_to: copy_to.copy_to,
+                partition_by: copy_to.partition_by,
             },
             location: LocationRequirement::ClientLocal,
             children: vec![source],
@@ -22,6 +22,7 @@ impl ExplainPlanner {
             node: LogicalExplain {
                 analyze: explain.analyze,
                 verbose: explain.verbose,
+                timing: explain.timing,
                 format: explain.format,
                 logical_unoptimized: Box::new(plan.clone()),
                 logical_optimized: None,
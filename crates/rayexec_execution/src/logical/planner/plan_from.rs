@@ -22,6 +22,7 @@ use crate::logical::logical_join::{
 };
 use crate::logical::logical_materialization::LogicalMaterializationScan;
 use crate::logical::logical_project::LogicalProject;
+use crate::logical::logical_recursive_cte::LogicalRecursiveCteScan;
 use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
 use crate::logical::statistics::StatisticsValue;
@@ -47,8 +48,10 @@ impl FromPlanner {
                     catalog: table.catalog,
                     schema: table.schema,
                     source: table.entry,
+                    row_ids: table.row_ids,
                 };
-                let estimated_cardinality = source.cardinality();
+                let statistics = table.statistics;
+                let estimated_cardinality = statistics.num_rows;
 
                 Ok(LogicalOperator::Scan(Node {
                     node: LogicalScan {
@@ -59,6 +62,7 @@ impl FromPlanner {
                         did_prune_columns: false,
                         scan_filters: Vec::new(),
                         source,
+                        statistics,
                     },
                     location: table.location,
                     children: Vec::new(),
@@ -78,10 +82,11 @@ impl FromPlanner {
                     TableFunctionImpl::Scan(_) => {
                         let projection = (0..types.len()).collect();
 
+                        let statistics = func.function.statistics.clone();
+                        let estimated_cardinality = statistics.num_rows;
                         let source = ScanSource::TableFunction {
                             function: func.function,
                         };
-                        let estimated_cardinality = source.cardinality();
 
                         Ok(LogicalOperator::Scan(Node {
                             node: LogicalScan {
@@ -92,6 +97,7 @@ impl FromPlanner {
                                 did_prune_columns: false,
                                 scan_filters: Vec::new(),
                                 source,
+                                statistics,
                             },
                             location: func.location,
                             children: Vec::new(),
@@ -99,7 +105,7 @@ impl FromPlanner {
                         }))
                     }
                     TableFunctionImpl::InOut(_) => {
-                        let cardinality = func.function.cardinality;
+                        let cardinality = func.function.statistics.num_rows;
 
                         // In/out always requires one input. Initialize its
                         // input with an empty operator. Subquery planning will
@@ -204,6 +210,15 @@ impl FromPlanner {
                     estimated_cardinality: StatisticsValue::Unknown,
                 }))
             }
+            BoundFromItem::RecursiveCteScan(scan) => Ok(LogicalOperator::RecursiveCteScan(Node {
+                node: LogicalRecursiveCteScan {
+                    table_ref: scan.table_ref,
+                    working_table: scan.cte_ref,
+                },
+                location: LocationRequirement::Any,
+                children: Vec::new(),
+                estimated_cardinality: StatisticsValue::Unknown,
+            })),
             BoundFromItem::Empty => Ok(LogicalOperator::Empty(Node {
                 node: LogicalEmpty,
                 location: LocationRequirement::Any,
@@ -0,0 +1,172 @@
+use rayexec_error::Result;
+
+use super::plan_from::FromPlanner;
+use super::plan_subquery::SubqueryPlanner;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::scalar_function_expr::ScalarFunctionExpr;
+use crate::expr::Expression;
+use crate::functions::scalar::builtin::is;
+use crate::functions::scalar::ScalarFunction;
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_modify::{BoundDelete, BoundMerge, BoundUpdate};
+use crate::logical::binder::bind_query::bind_from::{BoundFrom, BoundFromItem};
+use crate::logical::binder::table_list::TableRef;
+use crate::logical::logical_distinct::LogicalDistinct;
+use crate::logical::logical_filter::LogicalFilter;
+use crate::logical::logical_modify::{LogicalDelete, LogicalMerge, LogicalUpdate};
+use crate::logical::logical_project::LogicalProject;
+use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
+use crate::logical::statistics::StatisticsValue;
+
+#[derive(Debug)]
+pub struct ModifyPlanner;
+
+impl ModifyPlanner {
+    pub fn plan_update(
+        &self,
+        bind_context: &mut BindContext,
+        update: BoundUpdate,
+    ) -> Result<LogicalOperator> {
+        let source = self.plan_source(
+            bind_context,
+            update.source,
+            update.filter,
+            update.projections,
+            update.projection_table,
+        )?;
+
+        Ok(LogicalOperator::Update(Node {
+            node: LogicalUpdate {
+                catalog: update.table.catalog,
+                schema: update.table.schema,
+                table: update.table.entry,
+            },
+            location: update.table_location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+
+    pub fn plan_delete(
+        &self,
+        bind_context: &mut BindContext,
+        delete: BoundDelete,
+    ) -> Result<LogicalOperator> {
+        // A row may match multiple rows from the USING tables. Deduplicate on
+        // row id so the row only gets counted once.
+        let has_using = matches!(delete.source.item, BoundFromItem::Join(_));
+
+        let mut source = self.plan_source(
+            bind_context,
+            delete.source,
+            delete.filter,
+            delete.projections,
+            delete.projection_table,
+        )?;
+
+        if has_using {
+            source = LogicalOperator::Distinct(Node {
+                node: LogicalDistinct {
+                    on: vec![Expression::Column(ColumnExpr {
+                        table_scope: delete.projection_table,
+                        column: 0,
+                    })],
+                },
+                location: LocationRequirement::Any,
+                children: vec![source],
+                estimated_cardinality: StatisticsValue::Unknown,
+            });
+        }
+
+        Ok(LogicalOperator::Delete(Node {
+            node: LogicalDelete {
+                catalog: delete.table.catalog,
+                schema: delete.table.schema,
+                table: delete.table.entry,
+            },
+            location: delete.table_location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+
+    pub fn plan_merge(
+        &self,
+        bind_context: &mut BindContext,
+        merge: BoundMerge,
+    ) -> Result<LogicalOperator> {
+        let source = self.plan_source(
+            bind_context,
+            merge.source,
+            None,
+            merge.projections,
+            merge.projection_table,
+        )?;
+
+        // Only keep rows with an action to apply.
+        let filter = is::IsNotNull.plan(
+            bind_context.get_table_list(),
+            vec![Expression::Column(ColumnExpr {
+                table_scope: merge.projection_table,
+                column: 0,
+            })],
+        )?;
+        let source = LogicalOperator::Filter(Node {
+            node: LogicalFilter {
+                filter: Expression::ScalarFunction(ScalarFunctionExpr { function: filter }),
+            },
+            location: LocationRequirement::Any,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        });
+
+        Ok(LogicalOperator::Merge(Node {
+            node: LogicalMerge {
+                catalog: merge.table.catalog,
+                schema: merge.table.schema,
+                table: merge.table.entry,
+                actions: merge.actions,
+                insert_conflict: merge.insert_conflict,
+            },
+            location: merge.table_location,
+            children: vec![source],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+
+    /// Plan the input to the update, delete, or merge, producing the rows to modify.
+    fn plan_source(
+        &self,
+        bind_context: &mut BindContext,
+        source: BoundFrom,
+        filter: Option<Expression>,
+        mut projections: Vec<Expression>,
+        projection_table: TableRef,
+    ) -> Result<LogicalOperator> {
+        let mut plan = FromPlanner.plan(bind_context, source)?;
+
+        if let Some(mut filter) = filter {
+            plan = SubqueryPlanner.plan_expression(bind_context, &mut filter, plan)?;
+            plan = LogicalOperator::Filter(Node {
+                node: LogicalFilter { filter },
+                location: LocationRequirement::Any,
+                children: vec![plan],
+                estimated_cardinality: StatisticsValue::Unknown,
+            });
+        }
+
+        for expr in &mut projections {
+            plan = SubqueryPlanner.plan_expression(bind_context, expr, plan)?;
+        }
+
+        Ok(LogicalOperator::Project(Node {
+            node: LogicalProject {
+                projections,
+                projection_table,
+            },
+            location: LocationRequirement::Any,
+            children: vec![plan],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+}
@@ -1,12 +1,13 @@
 use rayexec_error::Result;
 
+use super::plan_recursive_cte::RecursiveCtePlanner;
 use super::plan_setop::SetOpPlanner;
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::bind_query::BoundQuery;
 use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
 use crate::logical::planner::plan_select::SelectPlanner;
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::statistics::{StatisticsValue, TableStatistics};
 
 #[derive(Debug)]
 pub struct QueryPlanner;
@@ -20,8 +21,12 @@ impl QueryPlanner {
         match query {
             BoundQuery::Select(select) => SelectPlanner.plan(bind_context, select),
             BoundQuery::Setop(setop) => SetOpPlanner.plan(bind_context, setop),
+            BoundQuery::RecursiveCte(cte) => RecursiveCtePlanner.plan(bind_context, cte),
             BoundQuery::Values(values) => {
                 let table = bind_context.get_table(values.expressions_table)?;
+                let statistics =
+                    TableStatistics::with_num_rows(StatisticsValue::Exact(values.rows.len()));
+                let estimated_cardinality = statistics.num_rows;
 
                 Ok(LogicalOperator::Scan(Node {
                     node: LogicalScan {
@@ -32,10 +37,11 @@ impl QueryPlanner {
                         did_prune_columns: false,
                         scan_filters: Vec::new(),
                         source: ScanSource::ExpressionList { rows: values.rows },
+                        statistics,
                     },
                     location: LocationRequirement::Any,
                     children: Vec::new(),
-                    estimated_cardinality: StatisticsValue::Unknown,
+                    estimated_cardinality,
                 }))
             }
         }
@@ -0,0 +1,39 @@
+use rayexec_error::Result;
+
+use crate::logical::binder::bind_context::BindContext;
+use crate::logical::binder::bind_query::bind_recursive_cte::BoundRecursiveCte;
+use crate::logical::logical_recursive_cte::LogicalRecursiveCte;
+use crate::logical::operator::{LocationRequirement, LogicalOperator, Node};
+use crate::logical::planner::plan_query::QueryPlanner;
+use crate::logical::planner::plan_setop::SetOpPlanner;
+use crate::logical::statistics::StatisticsValue;
+
+#[derive(Debug)]
+pub struct RecursiveCtePlanner;
+
+impl RecursiveCtePlanner {
+    pub fn plan(
+        &self,
+        bind_context: &mut BindContext,
+        cte: BoundRecursiveCte,
+    ) -> Result<LogicalOperator> {
+        let anchor = QueryPlanner.plan(bind_context, *cte.anchor)?;
+        let mut recursive = QueryPlanner.plan(bind_context, *cte.recursive)?;
+
+        if let Some(cast_ref) = cte.recursive_cast_ref {
+            recursive =
+                SetOpPlanner.wrap_cast(bind_context, recursive, cte.recursive_scope, cast_ref)?;
+        }
+
+        Ok(LogicalOperator::RecursiveCte(Node {
+            node: LogicalRecursiveCte {
+                table_ref: cte.table_ref,
+                working_table: cte.working_table,
+                all: cte.all,
+            },
+            location: LocationRequirement::Any,
+            children: vec![anchor, recursive],
+            estimated_cardinality: StatisticsValue::Unknown,
+        }))
+    }
+}
@@ -134,6 +134,7 @@ impl SelectPlanner {
             plan = LogicalOperator::Order(Node {
                 node: LogicalOrder {
                     exprs: order_by.exprs,
+                    limit: None,
                 },
                 location: LocationRequirement::Any,
                 children: vec![plan],
@@ -59,6 +59,7 @@ impl SetOpPlanner {
             plan = LogicalOperator::Order(Node {
                 node: LogicalOrder {
                     exprs: order_by.exprs,
+                    limit: None,
                 },
                 location: LocationRequirement::Any,
                 children: vec![plan],
@@ -82,7 +83,7 @@ impl SetOpPlanner {
         Ok(plan)
     }
 
-    fn wrap_cast(
+    pub fn wrap_cast(
         &self,
         bind_context: &BindContext,
         orig_plan: LogicalOperator,
@@ -4,6 +4,7 @@ use super::plan_copy::CopyPlanner;
 use super::plan_create_table::CreateTablePlanner;
 use super::plan_explain::ExplainPlanner;
 use super::plan_insert::InsertPlanner;
+use super::plan_modify::ModifyPlanner;
 use super::plan_query::QueryPlanner;
 use crate::logical::binder::bind_attach::{BoundAttach, BoundDetach};
 use crate::logical::binder::bind_context::BindContext;
@@ -32,6 +33,9 @@ impl StatementPlanner {
             }
             BoundStatement::Drop(plan) => Ok(LogicalOperator::Drop(plan)),
             BoundStatement::Insert(insert) => InsertPlanner.plan(bind_context, insert),
+            BoundStatement::Update(update) => ModifyPlanner.plan_update(bind_context, update),
+            BoundStatement::Delete(delete) => ModifyPlanner.plan_delete(bind_context, delete),
+            BoundStatement::Merge(merge) => ModifyPlanner.plan_merge(bind_context, merge),
             BoundStatement::CreateSchema(plan) => Ok(LogicalOperator::CreateSchema(plan)),
             BoundStatement::CreateTable(create) => CreateTablePlanner.plan(bind_context, create),
             BoundStatement::CreateView(create) => Ok(LogicalOperator::CreateView(create)),
@@ -83,9 +83,13 @@ impl<'a> ExpressionResolver<'a> {
             ast::GroupByExpr::Rollup(exprs) => {
                 ast::GroupByExpr::Rollup(self.resolve_expressions(exprs, resolve_context).await?)
             }
-            ast::GroupByExpr::GroupingSets(exprs) => ast::GroupByExpr::GroupingSets(
-                self.resolve_expressions(exprs, resolve_context).await?,
-            ),
+            ast::GroupByExpr::GroupingSets(sets) => {
+                let mut resolved = Vec::with_capacity(sets.len());
+                for exprs in sets {
+                    resolved.push(self.resolve_expressions(exprs, resolve_context).await?);
+                }
+                ast::GroupByExpr::GroupingSets(resolved)
+            }
         })
     }
 
@@ -211,6 +215,7 @@ impl<'a> ExpressionResolver<'a> {
         match expr {
             ast::Expr::Ident(ident) => Ok(ast::Expr::Ident(ident)),
             ast::Expr::CompoundIdent(idents) => Ok(ast::Expr::CompoundIdent(idents)),
+            ast::Expr::Parameter(n) => Ok(ast::Expr::Parameter(n)),
             ast::Expr::Literal(lit) => Ok(ast::Expr::Literal(match lit {
                 ast::Literal::Number(s) => ast::Literal::Number(s),
                 ast::Literal::SingleQuotedString(s) => ast::Literal::SingleQuotedString(s),
@@ -251,12 +256,12 @@ impl<'a> ExpressionResolver<'a> {
                     .await
             }
             ast::Expr::TypedString { datatype, value } => {
-                let datatype = Resolver::ast_datatype_to_exec_datatype(datatype)?;
+                let datatype = self.resolver.ast_datatype_to_exec_datatype(datatype)?;
                 Ok(ast::Expr::TypedString { datatype, value })
             }
             ast::Expr::Cast { datatype, expr } => {
                 let expr = Box::pin(self.resolve_expression(*expr, resolve_context)).await?;
-                let datatype = Resolver::ast_datatype_to_exec_datatype(datatype)?;
+                let datatype = self.resolver.ast_datatype_to_exec_datatype(datatype)?;
                 Ok(ast::Expr::Cast {
                     datatype,
                     expr: Box::new(expr),
@@ -389,6 +394,22 @@ impl<'a> ExpressionResolver<'a> {
                     expr: Box::new(expr),
                 })
             }
+            ast::Expr::AtTimeZone { timestamp, zone } => {
+                let timestamp =
+                    Box::pin(self.resolve_expression(*timestamp, resolve_context)).await?;
+                let zone = Box::pin(self.resolve_expression(*zone, resolve_context)).await?;
+                Ok(ast::Expr::AtTimeZone {
+                    timestamp: Box::new(timestamp),
+                    zone: Box::new(zone),
+                })
+            }
+            ast::Expr::Collate { expr, collation } => {
+                let expr = Box::pin(self.resolve_expression(*expr, resolve_context)).await?;
+                Ok(ast::Expr::Collate {
+                    expr: Box::new(expr),
+                    collation,
+                })
+            }
             ast::Expr::Columns(col) => Ok(ast::Expr::Columns(col)),
             other => not_implemented!("resolve expr {other:?}"),
         }
@@ -708,6 +729,21 @@ impl<'a> ExpressionResolver<'a> {
             })));
         }
 
+        // Now check window functions.
+        if let Some(window) = schema_ent.get_window_function(self.resolver.tx, &func_name)? {
+            let resolve_idx = resolve_context.functions.push_resolved(
+                ResolvedFunction::Window(window.try_as_window_function_entry()?.function.clone()),
+                LocationRequirement::Any,
+            );
+            return Ok(ast::Expr::Function(Box::new(ast::Function {
+                reference: resolve_idx,
+                distinct: func.distinct,
+                args,
+                filter,
+                over,
+            })));
+        }
+
         Err(create_user_facing_resolve_err(
             self.resolver.tx,
             Some(&schema_ent),
@@ -767,6 +803,22 @@ impl<'a> ExpressionResolver<'a> {
         over: ast::WindowSpec<Raw>,
         resolve_context: &mut ResolveContext,
     ) -> Result<ast::WindowSpec<ResolvedMeta>> {
+        match over {
+            ast::WindowSpec::Definition(window_def) => Ok(ast::WindowSpec::Definition(
+                self.resolve_window_definition(window_def, resolve_context)
+                    .await?,
+            )),
+            ast::WindowSpec::Named(ident) => Ok(ast::WindowSpec::Named(ident)),
+        }
+    }
+
+    /// Resolve a window definition, either from an `OVER (...)` clause or
+    /// from a `WINDOW` clause.
+    pub(crate) async fn resolve_window_definition(
+        &self,
+        window_def: ast::WindowDefinition<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::WindowDefinition<ResolvedMeta>> {
         async fn resolve_window_frame_bound(
             resolver: &ExpressionResolver<'_>,
             bound: ast::WindowFrameBound<Raw>,
@@ -793,53 +845,45 @@ impl<'a> ExpressionResolver<'a> {
             })
         }
 
-        match over {
-            ast::WindowSpec::Definition(window_def) => {
-                let partition_by =
-                    Box::pin(self.resolve_expressions(window_def.partition_by, resolve_context))
-                        .await?;
-
-                let mut order_by = Vec::with_capacity(window_def.order_by.len());
-                for order in window_def.order_by {
-                    let order = ast::OrderByNode {
-                        typ: order.typ,
-                        nulls: order.nulls,
-                        expr: Box::pin(self.resolve_expression(order.expr, resolve_context))
-                            .await?,
-                    };
+        let partition_by =
+            Box::pin(self.resolve_expressions(window_def.partition_by, resolve_context)).await?;
 
-                    order_by.push(order);
-                }
+        let mut order_by = Vec::with_capacity(window_def.order_by.len());
+        for order in window_def.order_by {
+            let order = ast::OrderByNode {
+                typ: order.typ,
+                nulls: order.nulls,
+                expr: Box::pin(self.resolve_expression(order.expr, resolve_context)).await?,
+            };
 
-                let frame = match window_def.frame {
-                    Some(frame) => {
-                        let start =
-                            resolve_window_frame_bound(self, frame.start, resolve_context).await?;
-                        let end = match frame.end {
-                            Some(end) => {
-                                Some(resolve_window_frame_bound(self, end, resolve_context).await?)
-                            }
-                            None => None,
-                        };
-
-                        Some(ast::WindowFrame {
-                            unit: frame.unit,
-                            start,
-                            end,
-                            exclusion: frame.exclusion,
-                        })
+            order_by.push(order);
+        }
+
+        let frame = match window_def.frame {
+            Some(frame) => {
+                let start = resolve_window_frame_bound(self, frame.start, resolve_context).await?;
+                let end = match frame.end {
+                    Some(end) => {
+                        Some(resolve_window_frame_bound(self, end, resolve_context).await?)
                     }
                     None => None,
                 };
 
-                Ok(ast::WindowSpec::Definition(ast::WindowDefinition {
-                    existing: window_def.existing,
-                    partition_by,
-                    order_by,
-                    frame,
-                }))
+                Some(ast::WindowFrame {
+                    unit: frame.unit,
+                    start,
+                    end,
+                    exclusion: frame.exclusion,
+                })
             }
-            ast::WindowSpec::Named(ident) => Ok(ast::WindowSpec::Named(ident)),
-        }
+            None => None,
+        };
+
+        Ok(ast::WindowDefinition {
+            existing: window_def.existing,
+            partition_by,
+            order_by,
+            frame,
+        })
     }
 }
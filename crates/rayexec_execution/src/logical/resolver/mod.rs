@@ -21,14 +21,22 @@ use resolve_context::{ItemReference, MaybeResolved, ResolveContext, ResolveListI
 use resolve_normal::{MaybeResolvedTable, NormalResolver};
 use resolved_copy_to::ResolvedCopyTo;
 use resolved_cte::ResolvedCte;
-use resolved_table::ResolvedTableOrCteReference;
+use resolved_table::{ResolvedTableOrCteReference, UnresolvedTableReference};
 use resolved_table_function::{ResolvedTableFunctionReference, UnresolvedTableFunctionReference};
 use serde::{Deserialize, Serialize};
 
 use super::binder::constant_binder::ConstantBinder;
 use super::binder::expr_binder::BaseExpressionBinder;
 use super::binder::table_list::TableAlias;
-use crate::arrays::datatype::{DataType, DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
+use crate::arrays::datatype::{
+    DataType,
+    DecimalTypeMeta,
+    ListTypeMeta,
+    StructTypeMeta,
+    TimeUnit,
+    TimestampTypeMeta,
+};
+use crate::arrays::field::Field;
 use crate::arrays::scalar::decimal::{Decimal128Type, Decimal64Type, DecimalType};
 use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::database::builtin_views::{
@@ -115,6 +123,8 @@ impl ResolveMode {
 #[derive(Debug)]
 pub struct ResolveConfig {
     pub enable_function_chaining: bool,
+    /// Session time zone, used for `TIMESTAMPTZ` types.
+    pub time_zone: String,
 }
 
 /// Resolves references in a raw SQL AST with entries in the catalog.
@@ -161,6 +171,7 @@ impl<'a> Resolver<'a> {
                     verbose: explain.verbose,
                     body,
                     output: explain.output,
+                    timing: explain.timing,
                 })
             }
             Statement::CopyTo(copy_to) => {
@@ -180,6 +191,15 @@ impl<'a> Resolver<'a> {
             Statement::Insert(insert) => {
                 Statement::Insert(self.resolve_insert(insert, &mut resolve_context).await?)
             }
+            Statement::Update(update) => {
+                Statement::Update(self.resolve_update(update, &mut resolve_context).await?)
+            }
+            Statement::Delete(delete) => {
+                Statement::Delete(self.resolve_delete(delete, &mut resolve_context).await?)
+            }
+            Statement::Merge(merge) => {
+                Statement::Merge(self.resolve_merge(merge, &mut resolve_context).await?)
+            }
             Statement::CreateTable(create) => Statement::CreateTable(
                 self.resolve_create_table(create, &mut resolve_context)
                     .await?,
@@ -211,6 +231,23 @@ impl<'a> Resolver<'a> {
                 Statement::Attach(self.resolve_attach(attach, &mut resolve_context).await?)
             }
             Statement::Detach(detach) => Statement::Detach(self.resolve_detach(detach).await?),
+            Statement::Prepare(prepare) => Statement::Prepare(ast::Prepare {
+                name: prepare.name,
+                param_types: prepare
+                    .param_types
+                    .into_iter()
+                    .map(|datatype| self.ast_datatype_to_exec_datatype(datatype))
+                    .collect::<Result<Vec<_>>>()?,
+                statement: prepare.statement,
+            }),
+            Statement::Execute(execute) => Statement::Execute(ast::Execute {
+                name: execute.name,
+                params: ExpressionResolver::new(&self)
+                    .resolve_expressions(execute.params, &mut resolve_context)
+                    .await?,
+            }),
+            Statement::Deallocate(deallocate) => Statement::Deallocate(deallocate),
+            Statement::Transaction(transaction) => Statement::Transaction(transaction),
         };
 
         Ok((bound, resolve_context))
@@ -296,6 +333,24 @@ impl<'a> Resolver<'a> {
         })
     }
 
+    /// Convert a resolved COPY TO option expression into a constant value.
+    ///
+    /// Identifiers are treated as strings, allowing for example `(FORMAT
+    /// parquet)` without needing to quote parquet. Tuples produce lists, e.g.
+    /// `(PARTITION_BY (a, b))`.
+    fn copy_to_option_value(expr: ast::Expr<ResolvedMeta>) -> Result<OwnedScalarValue> {
+        Ok(match expr {
+            ast::Expr::Literal(lit) => BaseExpressionBinder::bind_literal(&lit)?.try_into_scalar()?,
+            ast::Expr::Ident(ident) => OwnedScalarValue::Utf8(ident.into_normalized_string().into()),
+            ast::Expr::Nested(expr) => Self::copy_to_option_value(*expr)?,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "COPY TO options must be constant, got: {other:?}"
+                )))
+            }
+        })
+    }
+
     async fn resolve_copy_to(
         &self,
         copy_to: ast::CopyTo<Raw>,
@@ -343,22 +398,23 @@ impl<'a> Resolver<'a> {
         let mut options = HashMap::with_capacity(copy_to.options.len());
         for opt in copy_to.options {
             let key = opt.key.into_normalized_string();
-            let expr = ExpressionResolver::new(self)
-                .resolve_expression(opt.val, resolve_context)
-                .await?;
-
-            let val = match expr {
-                ast::Expr::Literal(lit) => {
-                    BaseExpressionBinder::bind_literal(&lit)?.try_into_scalar()?
-                }
-                // Ident allows for example `(FORMAT parquet)`, the user doesn't need to quote parquet.
-                ast::Expr::Ident(ident) => {
-                    OwnedScalarValue::Utf8(ident.into_normalized_string().into())
+            let val = match opt.val {
+                // Lists of values, e.g. `PARTITION_BY (a, b)`.
+                ast::Expr::Tuple(exprs) => {
+                    let mut vals = Vec::with_capacity(exprs.len());
+                    for expr in exprs {
+                        let expr = ExpressionResolver::new(self)
+                            .resolve_expression(expr, resolve_context)
+                            .await?;
+                        vals.push(Self::copy_to_option_value(expr)?);
+                    }
+                    OwnedScalarValue::List(vals)
                 }
-                other => {
-                    return Err(RayexecError::new(format!(
-                        "COPY TO options must be constant, got: {other:?}"
-                    )))
+                expr => {
+                    let expr = ExpressionResolver::new(self)
+                        .resolve_expression(expr, resolve_context)
+                        .await?;
+                    Self::copy_to_option_value(expr)?
                 }
             };
 
@@ -496,7 +552,7 @@ impl<'a> Resolver<'a> {
             .map(|col| {
                 Ok(ColumnDef::<ResolvedMeta> {
                     name: col.name,
-                    datatype: Self::ast_datatype_to_exec_datatype(col.datatype)?,
+                    datatype: self.ast_datatype_to_exec_datatype(col.datatype)?,
                     opts: col.opts,
                 })
             })
@@ -556,16 +612,212 @@ impl<'a> Resolver<'a> {
         insert: ast::Insert<Raw>,
         resolve_context: &mut ResolveContext,
     ) -> Result<ast::Insert<ResolvedMeta>> {
+        let table = self
+            .resolve_target_table(&insert.table, resolve_context)
+            .await?;
+
+        let source = self.resolve_query(insert.source, resolve_context).await?;
+
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let on_conflict = match insert.on_conflict {
+            Some(on_conflict) => {
+                let action = match on_conflict.action {
+                    ast::OnConflictAction::DoNothing => ast::OnConflictAction::DoNothing,
+                    ast::OnConflictAction::DoUpdate {
+                        assignments,
+                        where_expr,
+                    } => ast::OnConflictAction::DoUpdate {
+                        assignments: self
+                            .resolve_assignments(assignments, resolve_context)
+                            .await?,
+                        where_expr: match where_expr {
+                            Some(expr) => Some(
+                                ExpressionResolver::new(self)
+                                    .resolve_expression(expr, resolve_context)
+                                    .await?,
+                            ),
+                            None => None,
+                        },
+                    },
+                };
+                Some(ast::OnConflict {
+                    columns: on_conflict.columns,
+                    action,
+                })
+            }
+            None => None,
+        };
+
+        Ok(ast::Insert {
+            table: idx,
+            columns: insert.columns,
+            source,
+            on_conflict,
+        })
+    }
+
+    async fn resolve_update(
+        &self,
+        update: ast::Update<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Update<ResolvedMeta>> {
+        let table = self
+            .resolve_target_table(&update.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let assignments = self
+            .resolve_assignments(update.assignments, resolve_context)
+            .await?;
+
+        let from = match update.from {
+            Some(from) => Some(self.resolve_from(from, resolve_context).await?),
+            None => None,
+        };
+
+        let where_expr = match update.where_expr {
+            Some(expr) => Some(
+                ExpressionResolver::new(self)
+                    .resolve_expression(expr, resolve_context)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        Ok(ast::Update {
+            table: idx,
+            alias: update.alias,
+            assignments,
+            from,
+            where_expr,
+        })
+    }
+
+    async fn resolve_delete(
+        &self,
+        delete: ast::Delete<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Delete<ResolvedMeta>> {
+        let table = self
+            .resolve_target_table(&delete.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let using = match delete.using {
+            Some(using) => Some(self.resolve_from(using, resolve_context).await?),
+            None => None,
+        };
+
+        let where_expr = match delete.where_expr {
+            Some(expr) => Some(
+                ExpressionResolver::new(self)
+                    .resolve_expression(expr, resolve_context)
+                    .await?,
+            ),
+            None => None,
+        };
+
+        Ok(ast::Delete {
+            table: idx,
+            alias: delete.alias,
+            using,
+            where_expr,
+        })
+    }
+
+    async fn resolve_merge(
+        &self,
+        merge: ast::Merge<Raw>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<ast::Merge<ResolvedMeta>> {
+        let table = self
+            .resolve_target_table(&merge.table, resolve_context)
+            .await?;
+        let idx = resolve_context.tables.push_maybe_resolved(table);
+
+        let source = self.resolve_from(merge.source, resolve_context).await?;
+
+        let condition = ExpressionResolver::new(self)
+            .resolve_expression(merge.condition, resolve_context)
+            .await?;
+
+        let mut clauses = Vec::with_capacity(merge.clauses.len());
+        for clause in merge.clauses {
+            let condition = match clause.condition {
+                Some(expr) => Some(
+                    ExpressionResolver::new(self)
+                        .resolve_expression(expr, resolve_context)
+                        .await?,
+                ),
+                None => None,
+            };
+
+            let action = match clause.action {
+                ast::MergeAction::Update { assignments } => ast::MergeAction::Update {
+                    assignments: self
+                        .resolve_assignments(assignments, resolve_context)
+                        .await?,
+                },
+                ast::MergeAction::Delete => ast::MergeAction::Delete,
+                ast::MergeAction::Insert { columns, values } => ast::MergeAction::Insert {
+                    columns,
+                    values: ExpressionResolver::new(self)
+                        .resolve_expressions(values, resolve_context)
+                        .await?,
+                },
+                ast::MergeAction::DoNothing => ast::MergeAction::DoNothing,
+            };
+
+            clauses.push(ast::MergeClause {
+                matched: clause.matched,
+                condition,
+                action,
+            });
+        }
+
+        Ok(ast::Merge {
+            table: idx,
+            alias: merge.alias,
+            source,
+            condition,
+            clauses,
+        })
+    }
+
+    async fn resolve_assignments(
+        &self,
+        assignments: Vec<ast::Assignment<Raw>>,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<Vec<ast::Assignment<ResolvedMeta>>> {
+        let mut resolved = Vec::with_capacity(assignments.len());
+        for assignment in assignments {
+            resolved.push(ast::Assignment {
+                column: assignment.column,
+                expr: ExpressionResolver::new(self)
+                    .resolve_expression(assignment.expr, resolve_context)
+                    .await?,
+            });
+        }
+        Ok(resolved)
+    }
+
+    /// Resolve the table being modified by an INSERT, UPDATE, DELETE, or MERGE.
+    async fn resolve_target_table(
+        &self,
+        reference: &ObjectReference,
+        resolve_context: &mut ResolveContext,
+    ) -> Result<MaybeResolved<ResolvedTableOrCteReference, UnresolvedTableReference>> {
         let table = match self.resolve_mode {
             ResolveMode::Normal => {
                 let table = NormalResolver::new(self.tx, self.context)
-                    .require_resolve_table_or_cte(&insert.table, resolve_context)
+                    .require_resolve_table_or_cte(reference, resolve_context)
                     .await?;
                 MaybeResolved::Resolved(table, LocationRequirement::ClientLocal)
             }
             ResolveMode::Hybrid => {
                 let table = NormalResolver::new(self.tx, self.context)
-                    .resolve_table_or_cte(&insert.table, resolve_context)
+                    .resolve_table_or_cte(reference, resolve_context)
                     .await?;
 
                 match table {
@@ -578,22 +830,14 @@ impl<'a> Resolver<'a> {
                     MaybeResolvedTable::Unresolved => {
                         return Err(RayexecError::new(format!(
                             "Missing table or view for reference '{}'",
-                            insert.table
+                            reference
                         )))
                     }
                 }
             }
         };
 
-        let source = self.resolve_query(insert.source, resolve_context).await?;
-
-        let idx = resolve_context.tables.push_maybe_resolved(table);
-
-        Ok(ast::Insert {
-            table: idx,
-            columns: insert.columns,
-            source,
-        })
+        Ok(table)
     }
 
     async fn resolve_query(
@@ -621,12 +865,15 @@ impl<'a> Resolver<'a> {
 
             // Resolve ORDER BY
             let order_by = match query.order_by {
-                Some(order_by) => {
-                    let mut order_bys = Vec::with_capacity(order_by.order_by_nodes.len());
-                    for expr in order_by.order_by_nodes {
+                Some(ast::OrderByModifier::All { typ, nulls }) => {
+                    Some(ast::OrderByModifier::All { typ, nulls })
+                }
+                Some(ast::OrderByModifier::Nodes { order_by_nodes }) => {
+                    let mut order_bys = Vec::with_capacity(order_by_nodes.len());
+                    for expr in order_by_nodes {
                         order_bys.push(resolver.resolve_order_by(expr, resolve_context).await?);
                     }
-                    Some(ast::OrderByModifier {
+                    Some(ast::OrderByModifier::Nodes {
                         order_by_nodes: order_bys,
                     })
                 }
@@ -709,13 +956,21 @@ impl<'a> Resolver<'a> {
         for cte in ctes.ctes.into_iter() {
             let depth = resolve_context.current_depth;
 
-            let resolved_body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
             let resolved_cte = ResolvedCte {
                 name: cte.alias.as_normalized_string(),
                 depth,
             };
 
-            resolve_context.push_cte(resolved_cte);
+            // Recursive CTEs are able to reference themselves, so need to be in
+            // scope when resolving the body.
+            let resolved_body = if ctes.recursive {
+                resolve_context.push_cte(resolved_cte);
+                Box::pin(self.resolve_query(*cte.body, resolve_context)).await?
+            } else {
+                let body = Box::pin(self.resolve_query(*cte.body, resolve_context)).await?;
+                resolve_context.push_cte(resolved_cte);
+                body
+            };
 
             resolved_ctes.push(ast::CommonTableExpr {
                 alias: cte.alias,
@@ -804,6 +1059,16 @@ impl<'a> Resolver<'a> {
             None => None,
         };
 
+        let mut windows = Vec::with_capacity(select.windows.len());
+        for window in select.windows {
+            windows.push(ast::NamedWindowDefinition {
+                name: window.name,
+                definition: ExpressionResolver::new(self)
+                    .resolve_window_definition(window.definition, resolve_context)
+                    .await?,
+            });
+        }
+
         Ok(ast::SelectNode {
             distinct,
             projections,
@@ -811,6 +1076,7 @@ impl<'a> Resolver<'a> {
             where_expr,
             group_by,
             having,
+            windows,
         })
     }
 
@@ -1123,7 +1389,7 @@ impl<'a> Resolver<'a> {
             .collect()
     }
 
-    fn ast_datatype_to_exec_datatype(datatype: ast::DataType) -> Result<DataType> {
+    fn ast_datatype_to_exec_datatype(&self, datatype: ast::DataType) -> Result<DataType> {
         Ok(match datatype {
             ast::DataType::Varchar(_) => DataType::Utf8,
             ast::DataType::TinyInt => DataType::Int8,
@@ -1181,7 +1447,44 @@ impl<'a> Resolver<'a> {
                 // Microsecond matches postgres default.
                 DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
             }
+            ast::DataType::TimestampTz => DataType::Timestamp(TimestampTypeMeta::new_with_tz(
+                TimeUnit::Microsecond,
+                self.config.time_zone.clone(),
+            )),
             ast::DataType::Interval => DataType::Interval,
+            ast::DataType::Time => DataType::Time64,
+            ast::DataType::Blob => DataType::Binary,
+            ast::DataType::Uuid => DataType::Uuid,
+            ast::DataType::List(child) => DataType::List(ListTypeMeta::new(
+                self.ast_datatype_to_exec_datatype(*child)?,
+            )),
+            ast::DataType::Struct(fields) => {
+                let mut exec_fields: Vec<Field> = Vec::with_capacity(fields.len());
+                for field in fields {
+                    let name = field.name.into_normalized_string();
+                    if exec_fields.iter().any(|f| f.name == name) {
+                        return Err(RayexecError::new(format!(
+                            "Duplicate struct field name: {name}"
+                        )));
+                    }
+                    let datatype = self.ast_datatype_to_exec_datatype(field.datatype)?;
+                    exec_fields.push(Field::new(name, datatype, true));
+                }
+                DataType::Struct(StructTypeMeta {
+                    fields: exec_fields,
+                })
+            }
+            ast::DataType::Map(key, value) => {
+                // Maps are represented as a list of key/value structs.
+                let key = self.ast_datatype_to_exec_datatype(*key)?;
+                let value = self.ast_datatype_to_exec_datatype(*value)?;
+                DataType::List(ListTypeMeta::new(DataType::Struct(StructTypeMeta {
+                    fields: vec![
+                        Field::new("key", key, false),
+                        Field::new("value", value, true),
+                    ],
+                })))
+            }
         })
     }
 }
@@ -146,6 +146,7 @@ impl<'a> HybridResolver<'a> {
                 EMPTY_FILE_HANDLER_REF,
                 ResolveConfig {
                     enable_function_chaining: true, // TODO: We'll need to get this from the client.
+                    time_zone: "UTC".to_string(),   // TODO: Same here.
                 },
             ),
         }
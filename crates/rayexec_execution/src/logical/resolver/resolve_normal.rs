@@ -16,6 +16,7 @@ use crate::database::create::{CreateSchemaInfo, CreateTableInfo, OnConflict};
 use crate::database::memory_catalog::MemorySchema;
 use crate::database::{Database, DatabaseContext};
 use crate::functions::table::TableFunction;
+use crate::logical::statistics::TableStatistics;
 
 pub fn create_user_facing_resolve_err(
     tx: &CatalogTx,
@@ -181,11 +182,13 @@ impl<'a> NormalResolver<'a> {
 
         // Try reading from in-memory catalog first.
         if let Some(entry) = self.resolve_from_memory_catalog(database, &schema, &table)? {
+            let statistics = self.table_statistics(database, &schema, &entry);
             return Ok(MaybeResolvedTable::Resolved(
                 ResolvedTableOrCteReference::Table(ResolvedTableReference {
                     catalog,
                     schema,
                     entry,
+                    statistics,
                 }),
             ));
         }
@@ -244,11 +247,13 @@ impl<'a> NormalResolver<'a> {
 
         // Read from catalog again.
         if let Some(entry) = self.resolve_from_memory_catalog(database, &schema, &table)? {
+            let statistics = self.table_statistics(database, &schema, &entry);
             Ok(MaybeResolvedTable::Resolved(
                 ResolvedTableOrCteReference::Table(ResolvedTableReference {
                     catalog,
                     schema,
                     entry,
+                    statistics,
                 }),
             ))
         } else {
@@ -262,6 +267,27 @@ impl<'a> NormalResolver<'a> {
         }
     }
 
+    /// Get statistics for a table from its table storage.
+    ///
+    /// Statistics are only used for planning, so failing to get them isn't an
+    /// error.
+    fn table_statistics(
+        &self,
+        database: &Database,
+        schema: &str,
+        entry: &CatalogEntry,
+    ) -> TableStatistics {
+        let storage = match database.table_storage.as_ref() {
+            Some(storage) => storage,
+            None => return TableStatistics::unknown(),
+        };
+
+        storage
+            .data_table(self.tx, schema, entry)
+            .and_then(|table| table.statistics())
+            .unwrap_or_else(|_| TableStatistics::unknown())
+    }
+
     fn resolve_from_memory_catalog(
         &self,
         database: &Database,
@@ -3,6 +3,7 @@ use rayexec_error::{OptionExt, Result};
 use crate::database::DatabaseContext;
 use crate::functions::aggregate::AggregateFunction;
 use crate::functions::scalar::ScalarFunction;
+use crate::functions::window::WindowFunction;
 use crate::proto::DatabaseProtoConv;
 
 /// "Builtin" functions that require special handling.
@@ -32,11 +33,12 @@ impl SpecialBuiltinFunction {
     }
 }
 
-/// A resolved aggregate or scalar function.
+/// A resolved scalar, aggregate, or window function.
 #[derive(Debug, Clone, PartialEq)]
 pub enum ResolvedFunction {
     Scalar(Box<dyn ScalarFunction>),
     Aggregate(Box<dyn AggregateFunction>),
+    Window(Box<dyn WindowFunction>),
     Special(SpecialBuiltinFunction),
 }
 
@@ -45,6 +47,7 @@ impl ResolvedFunction {
         match self {
             Self::Scalar(f) => f.name(),
             Self::Aggregate(f) => f.name(),
+            Self::Window(f) => f.name(),
             Self::Special(f) => f.name(),
         }
     }
@@ -52,6 +55,10 @@ impl ResolvedFunction {
     pub fn is_aggregate(&self) -> bool {
         matches!(self, ResolvedFunction::Aggregate(_))
     }
+
+    pub fn is_window(&self) -> bool {
+        matches!(self, ResolvedFunction::Window(_))
+    }
 }
 
 impl DatabaseProtoConv for ResolvedFunction {
@@ -63,6 +70,7 @@ impl DatabaseProtoConv for ResolvedFunction {
         let value = match self {
             Self::Scalar(scalar) => Value::Scalar(scalar.to_proto_ctx(context)?),
             Self::Aggregate(agg) => Value::Aggregate(agg.to_proto_ctx(context)?),
+            Self::Window(window) => Value::Window(window.to_proto_ctx(context)?),
             Self::Special(_) => todo!(),
         };
 
@@ -79,6 +87,9 @@ impl DatabaseProtoConv for ResolvedFunction {
             Value::Aggregate(agg) => {
                 Self::Aggregate(DatabaseProtoConv::from_proto_ctx(agg, context)?)
             }
+            Value::Window(window) => {
+                Self::Window(DatabaseProtoConv::from_proto_ctx(window, context)?)
+            }
         })
     }
 }
@@ -6,6 +6,7 @@ use rayexec_proto::ProtoConv;
 
 use crate::database::catalog_entry::CatalogEntry;
 use crate::database::{AttachInfo, DatabaseContext};
+use crate::logical::statistics::TableStatistics;
 use crate::proto::DatabaseProtoConv;
 
 /// Table or CTE found in the FROM clause.
@@ -25,6 +26,10 @@ pub struct ResolvedTableReference {
     pub catalog: String,
     pub schema: String,
     pub entry: Arc<CatalogEntry>,
+    /// Statistics for the table at the time of resolving.
+    ///
+    /// Not serialized, tables resolved remotely will have unknown statistics.
+    pub statistics: TableStatistics,
 }
 
 impl DatabaseProtoConv for ResolvedTableOrCteReference {
@@ -42,6 +47,7 @@ impl DatabaseProtoConv for ResolvedTableOrCteReference {
                 catalog,
                 schema,
                 entry,
+                ..
             }) => Value::Table(ProtoResolvedTableReference {
                 catalog: catalog.clone(),
                 schema: schema.clone(),
@@ -64,6 +70,7 @@ impl DatabaseProtoConv for ResolvedTableOrCteReference {
                     table.entry.required("entry")?,
                     context,
                 )?),
+                statistics: TableStatistics::unknown(),
             }),
             Value::Cte(cte) => Self::Cte(cte.name),
         })
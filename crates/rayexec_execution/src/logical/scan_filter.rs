@@ -1,4 +1,7 @@
-use crate::arrays::scalar::OwnedScalarValue;
+use std::cmp::Ordering;
+
+use super::statistics::selectivity::compare_scalars;
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use crate::expr::comparison_expr::ComparisonOperator;
 
 /// A simplified filter that can be pushed into a scan.
@@ -12,6 +15,110 @@ pub struct ScanFilter {
     pub filter: ScanFilterType,
 }
 
+impl ScanFilter {
+    /// Check if a column containing only `value` could pass this filter.
+    ///
+    /// Returns true if we can't determine the result, e.g. when the value
+    /// isn't comparable with the constant.
+    pub fn could_match_value(&self, value: &ScalarValue) -> bool {
+        match &self.filter {
+            ScanFilterType::ConstComparison { op, constant } => {
+                if matches!(value, ScalarValue::Null) || matches!(constant, ScalarValue::Null) {
+                    // Comparisons with NULL are never true.
+                    return false;
+                }
+
+                match compare_bounds(value, constant) {
+                    Some(ord) => match op {
+                        ComparisonOperator::Eq => ord == Ordering::Equal,
+                        ComparisonOperator::NotEq => ord != Ordering::Equal,
+                        ComparisonOperator::Lt => ord == Ordering::Less,
+                        ComparisonOperator::LtEq => ord != Ordering::Greater,
+                        ComparisonOperator::Gt => ord == Ordering::Greater,
+                        ComparisonOperator::GtEq => ord != Ordering::Less,
+                    },
+                    None => true,
+                }
+            }
+        }
+    }
+
+    /// Check if a column whose non-null values are within `min` and `max`
+    /// could contain a value passing this filter.
+    ///
+    /// A None bound is treated as unknown. Returns true if we can't determine
+    /// the result.
+    pub fn could_match_range(&self, min: Option<&ScalarValue>, max: Option<&ScalarValue>) -> bool {
+        match &self.filter {
+            ScanFilterType::ConstComparison { op, constant } => {
+                if matches!(constant, ScalarValue::Null) {
+                    return false;
+                }
+
+                let min_ord = min.and_then(|min| compare_bounds(min, constant));
+                let max_ord = max.and_then(|max| compare_bounds(max, constant));
+
+                match op {
+                    ComparisonOperator::Eq => {
+                        min_ord != Some(Ordering::Greater) && max_ord != Some(Ordering::Less)
+                    }
+                    ComparisonOperator::NotEq => {
+                        !(min_ord == Some(Ordering::Equal) && max_ord == Some(Ordering::Equal))
+                    }
+                    ComparisonOperator::Lt => min_ord == Some(Ordering::Less) || min_ord.is_none(),
+                    ComparisonOperator::LtEq => min_ord != Some(Ordering::Greater),
+                    ComparisonOperator::Gt => {
+                        max_ord == Some(Ordering::Greater) || max_ord.is_none()
+                    }
+                    ComparisonOperator::GtEq => max_ord != Some(Ordering::Less),
+                }
+            }
+        }
+    }
+}
+
+/// Compare a value from the scan with a filter constant.
+///
+/// Values of the same type are compared exactly. Otherwise the values are
+/// compared as floats, in which case equality is uncertain due to precision
+/// loss and None is returned.
+fn compare_bounds(value: &ScalarValue, constant: &ScalarValue) -> Option<Ordering> {
+    let exact = match (value, constant) {
+        (ScalarValue::Boolean(a), ScalarValue::Boolean(b)) => Some(a.cmp(b)),
+        (ScalarValue::Int8(a), ScalarValue::Int8(b)) => Some(a.cmp(b)),
+        (ScalarValue::Int16(a), ScalarValue::Int16(b)) => Some(a.cmp(b)),
+        (ScalarValue::Int32(a), ScalarValue::Int32(b)) => Some(a.cmp(b)),
+        (ScalarValue::Int64(a), ScalarValue::Int64(b)) => Some(a.cmp(b)),
+        (ScalarValue::UInt8(a), ScalarValue::UInt8(b)) => Some(a.cmp(b)),
+        (ScalarValue::UInt16(a), ScalarValue::UInt16(b)) => Some(a.cmp(b)),
+        (ScalarValue::UInt32(a), ScalarValue::UInt32(b)) => Some(a.cmp(b)),
+        (ScalarValue::UInt64(a), ScalarValue::UInt64(b)) => Some(a.cmp(b)),
+        (ScalarValue::Date32(a), ScalarValue::Date32(b)) => Some(a.cmp(b)),
+        (ScalarValue::Date64(a), ScalarValue::Date64(b)) => Some(a.cmp(b)),
+        (ScalarValue::Time64(a), ScalarValue::Time64(b)) => Some(a.cmp(b)),
+        (ScalarValue::Decimal64(a), ScalarValue::Decimal64(b)) if a.scale == b.scale => {
+            Some(a.value.cmp(&b.value))
+        }
+        (ScalarValue::Decimal128(a), ScalarValue::Decimal128(b)) if a.scale == b.scale => {
+            Some(a.value.cmp(&b.value))
+        }
+        (ScalarValue::Timestamp(a), ScalarValue::Timestamp(b)) if a.unit == b.unit => {
+            Some(a.value.cmp(&b.value))
+        }
+        _ => None,
+    };
+
+    match exact {
+        Some(ord) => Some(ord),
+        None => match compare_scalars(value, constant)? {
+            Ordering::Equal if !matches!(value, ScalarValue::Utf8(_) | ScalarValue::Binary(_)) => {
+                None
+            }
+            ord => Some(ord),
+        },
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ScanFilterType {
     ConstComparison {
@@ -24,3 +131,82 @@ pub enum ScanFilterType {
         constant: OwnedScalarValue,
     },
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn filter(op: ComparisonOperator, constant: impl Into<OwnedScalarValue>) -> ScanFilter {
+        ScanFilter {
+            column: 0,
+            filter: ScanFilterType::ConstComparison {
+                op,
+                constant: constant.into(),
+            },
+        }
+    }
+
+    #[test]
+    fn range_eq() {
+        let min = ScalarValue::Int32(10);
+        let max = ScalarValue::Int32(20);
+
+        let f = filter(ComparisonOperator::Eq, ScalarValue::Int32(15));
+        assert!(f.could_match_range(Some(&min), Some(&max)));
+
+        let f = filter(ComparisonOperator::Eq, ScalarValue::Int32(21));
+        assert!(!f.could_match_range(Some(&min), Some(&max)));
+        assert!(f.could_match_range(Some(&min), None));
+
+        let f = filter(ComparisonOperator::Eq, ScalarValue::Int32(9));
+        assert!(!f.could_match_range(Some(&min), Some(&max)));
+    }
+
+    #[test]
+    fn range_inequalities() {
+        let min = ScalarValue::Int32(10);
+        let max = ScalarValue::Int32(20);
+
+        let cases = [
+            (ComparisonOperator::Lt, 10, false),
+            (ComparisonOperator::Lt, 11, true),
+            (ComparisonOperator::LtEq, 10, true),
+            (ComparisonOperator::LtEq, 9, false),
+            (ComparisonOperator::Gt, 20, false),
+            (ComparisonOperator::Gt, 19, true),
+            (ComparisonOperator::GtEq, 20, true),
+            (ComparisonOperator::GtEq, 21, false),
+            (ComparisonOperator::NotEq, 10, true),
+        ];
+
+        for (op, constant, expected) in cases {
+            let f = filter(op, ScalarValue::Int32(constant));
+            assert_eq!(
+                expected,
+                f.could_match_range(Some(&min), Some(&max)),
+                "op: {op}, constant: {constant}"
+            );
+        }
+
+        let f = filter(ComparisonOperator::NotEq, ScalarValue::Int32(10));
+        assert!(!f.could_match_range(Some(&min), Some(&min)));
+    }
+
+    #[test]
+    fn range_strings() {
+        let min = ScalarValue::from("b");
+        let max = ScalarValue::from("d");
+
+        assert!(filter(ComparisonOperator::Eq, "c").could_match_range(Some(&min), Some(&max)));
+        assert!(!filter(ComparisonOperator::Eq, "a").could_match_range(Some(&min), Some(&max)));
+        assert!(!filter(ComparisonOperator::Gt, "d").could_match_range(Some(&min), Some(&max)));
+    }
+
+    #[test]
+    fn range_mixed_types_equality_uncertain() {
+        // Large values lose precision when compared as floats.
+        let min = ScalarValue::Int64(i64::MAX - 1);
+        let f = filter(ComparisonOperator::Lt, ScalarValue::UInt64(i64::MAX as u64));
+        assert!(f.could_match_range(Some(&min), Some(&min)));
+    }
+}
@@ -1,6 +1,10 @@
+pub mod selectivity;
+
 use std::cmp::Ordering;
 use std::fmt;
 
+use crate::arrays::scalar::OwnedScalarValue;
+
 pub mod assumptions {
     //! Assumptions when we don't have complete statistics available to us.
 
@@ -31,6 +35,41 @@ impl<T> StatisticsValue<T> {
             Self::Unknown => None,
         }
     }
+
+    /// Map the inner value, preserving if the value is exact or estimated.
+    pub fn map<U, F>(self, f: F) -> StatisticsValue<U>
+    where
+        F: FnOnce(T) -> U,
+    {
+        match self {
+            Self::Exact(v) => StatisticsValue::Exact(f(v)),
+            Self::Estimated(v) => StatisticsValue::Estimated(f(v)),
+            Self::Unknown => StatisticsValue::Unknown,
+        }
+    }
+
+    /// Downgrade an exact value to an estimated one.
+    pub fn into_estimated(self) -> Self {
+        match self {
+            Self::Exact(v) => Self::Estimated(v),
+            other => other,
+        }
+    }
+
+    /// Combine two values, producing an exact value only if both inputs are
+    /// exact, and unknown if either is unknown.
+    pub fn combine<F>(self, other: Self, f: F) -> Self
+    where
+        F: FnOnce(T, T) -> T,
+    {
+        match (self, other) {
+            (Self::Exact(a), Self::Exact(b)) => Self::Exact(f(a, b)),
+            (Self::Exact(a) | Self::Estimated(a), Self::Exact(b) | Self::Estimated(b)) => {
+                Self::Estimated(f(a, b))
+            }
+            _ => Self::Unknown,
+        }
+    }
 }
 
 impl<T> PartialOrd for StatisticsValue<T>
@@ -69,8 +108,110 @@ where
     }
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+/// Statistics for a table or other source of rows.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TableStatistics {
+    /// Number of rows in the table.
+    pub num_rows: StatisticsValue<usize>,
+    /// Per-column statistics, one entry per column in the table.
+    ///
+    /// May be empty if no column statistics are available.
+    pub columns: Vec<ColumnStatistics>,
+}
+
+impl TableStatistics {
+    /// Statistics where nothing is known about the table.
+    pub const fn unknown() -> Self {
+        TableStatistics {
+            num_rows: StatisticsValue::Unknown,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Statistics where only the row count is known.
+    pub const fn with_num_rows(num_rows: StatisticsValue<usize>) -> Self {
+        TableStatistics {
+            num_rows,
+            columns: Vec::new(),
+        }
+    }
+
+    /// Get statistics for a column by index.
+    pub fn column(&self, idx: usize) -> Option<&ColumnStatistics> {
+        self.columns.get(idx)
+    }
+
+    /// Merge statistics from another part of the same table (e.g. another
+    /// file or row group).
+    ///
+    /// Row and null counts are summed, and min/max are widened. Distinct
+    /// counts can't be merged exactly, so the larger of the two is used as an
+    /// estimate.
+    pub fn merge(self, other: TableStatistics) -> TableStatistics {
+        let num_rows = self.num_rows.combine(other.num_rows, |a, b| a + b);
+
+        let columns = if self.columns.len() == other.columns.len() {
+            self.columns
+                .into_iter()
+                .zip(other.columns)
+                .map(|(a, b)| a.merge(b))
+                .collect()
+        } else {
+            Vec::new()
+        };
+
+        TableStatistics { num_rows, columns }
+    }
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
 pub struct ColumnStatistics {
     /// Number of distinct values in the column.
     pub num_distinct: StatisticsValue<usize>,
+    /// Number of nulls in the column.
+    pub null_count: StatisticsValue<usize>,
+    /// Minimum non-null value in the column.
+    pub min: StatisticsValue<OwnedScalarValue>,
+    /// Maximum non-null value in the column.
+    pub max: StatisticsValue<OwnedScalarValue>,
+}
+
+impl ColumnStatistics {
+    pub const fn unknown() -> Self {
+        ColumnStatistics {
+            num_distinct: StatisticsValue::Unknown,
+            null_count: StatisticsValue::Unknown,
+            min: StatisticsValue::Unknown,
+            max: StatisticsValue::Unknown,
+        }
+    }
+
+    /// Merge statistics for the same column from another part of the table.
+    pub fn merge(self, other: ColumnStatistics) -> ColumnStatistics {
+        let num_distinct = self
+            .num_distinct
+            .combine(other.num_distinct, usize::max)
+            .into_estimated();
+        let null_count = self.null_count.combine(other.null_count, |a, b| a + b);
+
+        let min = self.min.combine(other.min, |a, b| {
+            match selectivity::compare_scalars(&a, &b) {
+                Some(Ordering::Greater) => b,
+                _ => a,
+            }
+        });
+        let max = self.max.combine(other.max, |a, b| {
+            match selectivity::compare_scalars(&a, &b) {
+                Some(Ordering::Less) => b,
+                _ => a,
+            }
+        });
+
+        ColumnStatistics {
+            num_distinct,
+            null_count,
+            min,
+            max,
+        }
+    }
 }
@@ -0,0 +1,415 @@
+//! Filter selectivity estimation using column statistics.
+//!
+//! Falls back to the fixed selectivities in `assumptions` for any expression
+//! (or column) we don't have enough information about.
+
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+use super::assumptions::{DEFAULT_SELECTIVITY, EQUALITY_SELECTIVITY, INEQUALITY_SELECTIVITY};
+use super::{ColumnStatistics, StatisticsValue};
+use crate::arrays::datatype::TimeUnit;
+use crate::arrays::scalar::ScalarValue;
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::comparison_expr::ComparisonOperator;
+use crate::expr::conjunction_expr::ConjunctionOperator;
+use crate::expr::is_expr::IsOperator;
+use crate::expr::Expression;
+
+/// Estimates the fraction of rows a filter expression will keep.
+#[derive(Debug, Clone, Copy)]
+pub struct SelectivityEstimator<'a> {
+    /// Statistics for columns that may be referenced in the expression.
+    pub columns: &'a HashMap<ColumnExpr, ColumnStatistics>,
+    /// Number of input rows, if known.
+    ///
+    /// Used to turn null counts into fractions.
+    pub num_rows: Option<f64>,
+}
+
+impl SelectivityEstimator<'_> {
+    /// Estimate the selectivity of a filter expression.
+    ///
+    /// The returned value is always within [0, 1].
+    pub fn estimate(&self, expr: &Expression) -> f64 {
+        self.estimate_inner(expr).clamp(0.0, 1.0)
+    }
+
+    fn estimate_inner(&self, expr: &Expression) -> f64 {
+        match expr {
+            Expression::Conjunction(conj) => {
+                let sels = conj.expressions.iter().map(|expr| self.estimate(expr));
+                match conj.op {
+                    // Assume independence between the children.
+                    ConjunctionOperator::And => sels.product(),
+                    ConjunctionOperator::Or => sels.fold(0.0, |acc, sel| acc + sel - acc * sel),
+                }
+            }
+            Expression::Comparison(cmp) => {
+                match (strip_casts(&cmp.left), strip_casts(&cmp.right)) {
+                    (Expression::Column(col), Expression::Literal(lit)) => {
+                        self.estimate_column_literal(col, cmp.op, &lit.literal)
+                    }
+                    (Expression::Literal(lit), Expression::Column(col)) => {
+                        self.estimate_column_literal(col, cmp.op.flip(), &lit.literal)
+                    }
+                    (Expression::Column(left), Expression::Column(right)) => {
+                        self.estimate_column_column(left, right, cmp.op)
+                    }
+                    _ => default_for_op(cmp.op),
+                }
+            }
+            Expression::Between(between) => {
+                let col = match strip_casts(&between.input) {
+                    Expression::Column(col) => col,
+                    _ => return INEQUALITY_SELECTIVITY,
+                };
+                match (strip_casts(&between.lower), strip_casts(&between.upper)) {
+                    (Expression::Literal(lower), Expression::Literal(upper)) => {
+                        let below_upper =
+                            self.estimate_range(col, ComparisonOperator::LtEq, &upper.literal);
+                        let below_lower =
+                            self.estimate_range(col, ComparisonOperator::Lt, &lower.literal);
+                        match (below_upper, below_lower) {
+                            (Some(upper), Some(lower)) => {
+                                (upper - lower).max(0.0) * self.non_null_fraction(col)
+                            }
+                            _ => INEQUALITY_SELECTIVITY,
+                        }
+                    }
+                    _ => INEQUALITY_SELECTIVITY,
+                }
+            }
+            Expression::Is(is) => {
+                let null_frac = match strip_casts(&is.input) {
+                    Expression::Column(col) => self.null_fraction(col),
+                    _ => None,
+                };
+                match (is.op, null_frac) {
+                    (IsOperator::IsNull, Some(frac)) => frac,
+                    (IsOperator::IsNotNull, Some(frac)) => 1.0 - frac,
+                    _ => DEFAULT_SELECTIVITY,
+                }
+            }
+            Expression::Literal(lit) => match lit.literal {
+                ScalarValue::Boolean(true) => 1.0,
+                ScalarValue::Boolean(false) | ScalarValue::Null => 0.0,
+                _ => DEFAULT_SELECTIVITY,
+            },
+            _ => DEFAULT_SELECTIVITY,
+        }
+    }
+
+    fn estimate_column_literal(
+        &self,
+        col: &ColumnExpr,
+        op: ComparisonOperator,
+        literal: &ScalarValue,
+    ) -> f64 {
+        if matches!(literal, ScalarValue::Null) {
+            // Comparisons against NULL never pass.
+            return 0.0;
+        }
+
+        match op {
+            ComparisonOperator::Eq => self
+                .estimate_equality(col, literal)
+                .unwrap_or(EQUALITY_SELECTIVITY),
+            ComparisonOperator::NotEq => match self.estimate_equality(col, literal) {
+                Some(eq) => self.non_null_fraction(col) - eq,
+                None => INEQUALITY_SELECTIVITY,
+            },
+            op => match self.estimate_range(col, op, literal) {
+                Some(frac) => frac * self.non_null_fraction(col),
+                None => INEQUALITY_SELECTIVITY,
+            },
+        }
+    }
+
+    /// Estimate `col = literal` assuming a uniform distribution of values.
+    fn estimate_equality(&self, col: &ColumnExpr, literal: &ScalarValue) -> Option<f64> {
+        let stats = self.columns.get(col)?;
+
+        // If the literal falls outside of the column's range, nothing can
+        // match. Only trust exact bounds here, estimated bounds (e.g. from
+        // sampling) may have missed the true extremes.
+        if let (StatisticsValue::Exact(min), StatisticsValue::Exact(max)) = (&stats.min, &stats.max)
+        {
+            let below = compare_scalars(literal, min) == Some(Ordering::Less);
+            let above = compare_scalars(literal, max) == Some(Ordering::Greater);
+            if below || above {
+                return Some(0.0);
+            }
+        }
+
+        let ndv = *stats.num_distinct.value()?;
+        if ndv == 0 {
+            return Some(0.0);
+        }
+
+        Some(self.non_null_fraction(col) / ndv as f64)
+    }
+
+    /// Estimate the fraction of non-null values that satisfy `col <op>
+    /// literal` by interpolating between the column's min and max.
+    ///
+    /// Only works for values that can be represented on a number line.
+    fn estimate_range(
+        &self,
+        col: &ColumnExpr,
+        op: ComparisonOperator,
+        literal: &ScalarValue,
+    ) -> Option<f64> {
+        let stats = self.columns.get(col)?;
+        let min = scalar_as_f64(stats.min.value()?)?;
+        let max = scalar_as_f64(stats.max.value()?)?;
+        let val = scalar_as_f64(literal)?;
+
+        if max < min {
+            return None;
+        }
+
+        if max == min {
+            // Single value in the column, the comparison is either true for
+            // all or none of the rows.
+            let matches = match op {
+                ComparisonOperator::Lt => min < val,
+                ComparisonOperator::LtEq => min <= val,
+                ComparisonOperator::Gt => min > val,
+                ComparisonOperator::GtEq => min >= val,
+                ComparisonOperator::Eq | ComparisonOperator::NotEq => return None,
+            };
+            return Some(if matches { 1.0 } else { 0.0 });
+        }
+
+        // Fraction of the values that are less than `val`.
+        let below = ((val - min) / (max - min)).clamp(0.0, 1.0);
+
+        let frac = match op {
+            ComparisonOperator::Lt | ComparisonOperator::LtEq => below,
+            ComparisonOperator::Gt | ComparisonOperator::GtEq => 1.0 - below,
+            ComparisonOperator::Eq | ComparisonOperator::NotEq => return None,
+        };
+
+        Some(frac)
+    }
+
+    fn estimate_column_column(
+        &self,
+        left: &ColumnExpr,
+        right: &ColumnExpr,
+        op: ComparisonOperator,
+    ) -> f64 {
+        let ndv = |col: &ColumnExpr| {
+            self.columns
+                .get(col)
+                .and_then(|stats| stats.num_distinct.value().copied())
+        };
+
+        match (op, ndv(left), ndv(right)) {
+            (ComparisonOperator::Eq, Some(left), Some(right)) => {
+                1.0 / (usize::max(left, right).max(1) as f64)
+            }
+            (ComparisonOperator::Eq, Some(ndv), None)
+            | (ComparisonOperator::Eq, None, Some(ndv)) => 1.0 / (ndv.max(1) as f64),
+            (op, _, _) => default_for_op(op),
+        }
+    }
+
+    fn null_fraction(&self, col: &ColumnExpr) -> Option<f64> {
+        let stats = self.columns.get(col)?;
+        let null_count = *stats.null_count.value()? as f64;
+        let num_rows = self.num_rows?;
+        if num_rows <= 0.0 {
+            return None;
+        }
+        Some((null_count / num_rows).clamp(0.0, 1.0))
+    }
+
+    fn non_null_fraction(&self, col: &ColumnExpr) -> f64 {
+        1.0 - self.null_fraction(col).unwrap_or(0.0)
+    }
+}
+
+fn default_for_op(op: ComparisonOperator) -> f64 {
+    match op {
+        ComparisonOperator::Eq => EQUALITY_SELECTIVITY,
+        _ => INEQUALITY_SELECTIVITY,
+    }
+}
+
+/// Casts don't change the distribution of values enough to matter for
+/// estimation.
+fn strip_casts(expr: &Expression) -> &Expression {
+    match expr {
+        Expression::Cast(cast) => strip_casts(&cast.expr),
+        other => other,
+    }
+}
+
+/// Convert a scalar to a point on a number line for interpolation.
+///
+/// Returns None for values that don't have a meaningful numeric
+/// representation.
+pub fn scalar_as_f64(scalar: &ScalarValue) -> Option<f64> {
+    Some(match scalar {
+        ScalarValue::Boolean(v) => *v as u8 as f64,
+        ScalarValue::Float16(v) => v.to_f64(),
+        ScalarValue::Float32(v) => *v as f64,
+        ScalarValue::Float64(v) => *v,
+        ScalarValue::Int8(v) => *v as f64,
+        ScalarValue::Int16(v) => *v as f64,
+        ScalarValue::Int32(v) => *v as f64,
+        ScalarValue::Int64(v) => *v as f64,
+        ScalarValue::Int128(v) => *v as f64,
+        ScalarValue::UInt8(v) => *v as f64,
+        ScalarValue::UInt16(v) => *v as f64,
+        ScalarValue::UInt32(v) => *v as f64,
+        ScalarValue::UInt64(v) => *v as f64,
+        ScalarValue::UInt128(v) => *v as f64,
+        ScalarValue::Decimal64(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        ScalarValue::Decimal128(v) => v.value as f64 / 10_f64.powi(v.scale as i32),
+        ScalarValue::Date32(v) => *v as f64,
+        // Milliseconds, normalize to days to match Date32.
+        ScalarValue::Date64(v) => *v as f64 / 86_400_000.0,
+        ScalarValue::Timestamp(v) => {
+            // Normalize to microseconds.
+            let value = v.value as f64;
+            match v.unit {
+                TimeUnit::Second => value * 1_000_000.0,
+                TimeUnit::Millisecond => value * 1_000.0,
+                TimeUnit::Microsecond => value,
+                TimeUnit::Nanosecond => value / 1_000.0,
+            }
+        }
+        ScalarValue::Time64(v) => *v as f64,
+        _ => return None,
+    })
+}
+
+/// Compare two scalars for the purposes of tracking min/max.
+///
+/// Numeric-like values are compared by value, strings and binary values are
+/// compared lexicographically. Returns None if the values aren't comparable.
+pub fn compare_scalars(a: &ScalarValue, b: &ScalarValue) -> Option<Ordering> {
+    match (a, b) {
+        (ScalarValue::Utf8(a), ScalarValue::Utf8(b)) => Some(a.cmp(b)),
+        (ScalarValue::Binary(a), ScalarValue::Binary(b)) => Some(a.cmp(b)),
+        (a, b) => scalar_as_f64(a)?.partial_cmp(&scalar_as_f64(b)?),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::is_expr::IsExpr;
+    use crate::expr::{self, lit};
+    use crate::logical::binder::table_list::TableRef;
+
+    fn column() -> ColumnExpr {
+        ColumnExpr::new(TableRef { table_idx: 0 }, 0)
+    }
+
+    fn stats(
+        ndv: usize,
+        nulls: usize,
+        min: i32,
+        max: i32,
+    ) -> HashMap<ColumnExpr, ColumnStatistics> {
+        [(
+            column(),
+            ColumnStatistics {
+                num_distinct: StatisticsValue::Exact(ndv),
+                null_count: StatisticsValue::Exact(nulls),
+                min: StatisticsValue::Exact(ScalarValue::Int32(min)),
+                max: StatisticsValue::Exact(ScalarValue::Int32(max)),
+            },
+        )]
+        .into_iter()
+        .collect()
+    }
+
+    #[test]
+    fn equality_uses_ndv() {
+        let columns = stats(50, 0, 0, 100);
+        let est = SelectivityEstimator {
+            columns: &columns,
+            num_rows: Some(1000.0),
+        };
+
+        let sel = est.estimate(&expr::eq(expr::col_ref(column().table_scope, 0), lit(10)));
+        assert_eq!(1.0 / 50.0, sel);
+
+        // Out of range.
+        let sel = est.estimate(&expr::eq(expr::col_ref(column().table_scope, 0), lit(500)));
+        assert_eq!(0.0, sel);
+    }
+
+    #[test]
+    fn equality_estimated_bounds_uses_ndv() {
+        let mut columns = stats(50, 0, 0, 100);
+        let col_stats = columns.get_mut(&column()).unwrap();
+        col_stats.min = col_stats.min.clone().into_estimated();
+        col_stats.max = col_stats.max.clone().into_estimated();
+        let est = SelectivityEstimator {
+            columns: &columns,
+            num_rows: Some(1000.0),
+        };
+
+        // Sampled bounds may have missed the value, don't estimate zero rows.
+        let sel = est.estimate(&expr::eq(expr::col_ref(column().table_scope, 0), lit(500)));
+        assert_eq!(1.0 / 50.0, sel);
+    }
+
+    #[test]
+    fn range_interpolates_min_max() {
+        let columns = stats(100, 0, 0, 100);
+        let est = SelectivityEstimator {
+            columns: &columns,
+            num_rows: Some(1000.0),
+        };
+
+        let sel = est.estimate(&expr::lt(expr::col_ref(column().table_scope, 0), lit(25)));
+        assert_eq!(0.25, sel);
+
+        // Flipped.
+        let sel = est.estimate(&expr::lt(lit(25), expr::col_ref(column().table_scope, 0)));
+        assert_eq!(0.75, sel);
+    }
+
+    #[test]
+    fn is_null_uses_null_count() {
+        let columns = stats(100, 250, 0, 100);
+        let est = SelectivityEstimator {
+            columns: &columns,
+            num_rows: Some(1000.0),
+        };
+
+        let sel = est.estimate(&Expression::Is(IsExpr {
+            op: IsOperator::IsNull,
+            input: Box::new(expr::col_ref(column().table_scope, 0)),
+        }));
+        assert_eq!(0.25, sel);
+    }
+
+    #[test]
+    fn fallback_without_stats() {
+        let columns = HashMap::new();
+        let est = SelectivityEstimator {
+            columns: &columns,
+            num_rows: None,
+        };
+
+        let sel = est.estimate(&expr::eq(expr::col_ref(column().table_scope, 0), lit(10)));
+        assert_eq!(EQUALITY_SELECTIVITY, sel);
+
+        let sel = est.estimate(
+            &expr::and([
+                expr::eq(expr::col_ref(column().table_scope, 0), lit(10)),
+                expr::gt(expr::col_ref(column().table_scope, 0), lit(10)),
+            ])
+            .unwrap(),
+        );
+        assert_eq!(EQUALITY_SELECTIVITY * INEQUALITY_SELECTIVITY, sel);
+    }
+}
@@ -2,6 +2,8 @@ use rayexec_error::{RayexecError, Result};
 
 use super::ExpressionRewriteRule;
 use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::arrays::scalar::ScalarValue;
 use crate::expr::literal_expr::LiteralExpr;
 use crate::expr::physical::planner::PhysicalExpressionPlanner;
 use crate::expr::Expression;
@@ -44,6 +46,17 @@ fn maybe_fold(table_list: &TableList, expr: &mut Expression) -> Result<()> {
                 ))
             })?;
 
+        // Scalars for empty nested values (e.g. `[]`) don't carry their
+        // element types. Keep the original expression so we don't lose the
+        // type.
+        let datatype = expr.datatype(table_list)?;
+        if matches!(datatype, DataType::List(_) | DataType::Struct(_))
+            && !matches!(val, ScalarValue::Null)
+            && val.datatype() != datatype
+        {
+            return Ok(());
+        }
+
         // Our brand new expression.
         *expr = Expression::Literal(LiteralExpr {
             literal: val.into_owned(),
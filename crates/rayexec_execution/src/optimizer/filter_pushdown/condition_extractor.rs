@@ -224,7 +224,11 @@ where
                                 continue;
                             }
 
-                            // TODO: Do we need to push to arbitrary here?
+                            // Otherwise we'll need to evaluate the comparison
+                            // after the join.
+                            extracted
+                                .arbitrary
+                                .push(Expression::Comparison(ComparisonExpr { left, right, op }));
                         }
                         other => {
                             extracted.arbitrary.push(other);
@@ -232,15 +236,21 @@ where
                     }
                 }
                 ExprJoinSide::Right => {
-                    if matches!(self.join_type, JoinType::Left | JoinType::Inner) {
-                        // Filter right input into LEFT join.
+                    if matches!(
+                        self.join_type,
+                        JoinType::Left | JoinType::Inner | JoinType::Semi | JoinType::Anti
+                    ) {
+                        // Filter right input into LEFT/SEMI/ANTI join.
                         extracted.right_filter.push(expr);
                     } else {
                         extracted.arbitrary.push(expr);
                     }
                 }
                 ExprJoinSide::Left => {
-                    if matches!(self.join_type, JoinType::Right | JoinType::Inner) {
+                    if matches!(
+                        self.join_type,
+                        JoinType::Right | JoinType::Inner | JoinType::Semi
+                    ) {
                         // Filter left input into RIGHT join.
                         extracted.left_filter.push(expr);
                     } else {
@@ -10,6 +10,7 @@ use rayexec_error::{RayexecError, Result};
 use split::split_conjunction;
 
 use super::OptimizeRule;
+use crate::arrays::scalar::ScalarValue;
 use crate::expr::{self, Expression};
 use crate::logical::binder::bind_context::BindContext;
 use crate::logical::binder::table_list::TableRef;
@@ -26,8 +27,10 @@ use crate::logical::logical_join::{
 use crate::logical::logical_materialization::LogicalMaterializationScan;
 use crate::logical::logical_order::LogicalOrder;
 use crate::logical::logical_project::LogicalProject;
+use crate::logical::logical_scan::{LogicalScan, ScanSource};
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
 use crate::logical::planner::plan_from::FromPlanner;
+use crate::logical::scan_filter::{ScanFilter, ScanFilterType};
 use crate::logical::statistics::StatisticsValue;
 
 // TODO: ExtractedFilter seems to not be entirely worth it here. There's
@@ -61,6 +64,7 @@ impl OptimizeRule for FilterPushdown {
             LogicalOperator::MaterializationScan(mat) => {
                 self.pushdown_materialized_scan(bind_context, mat)
             }
+            LogicalOperator::Scan(scan) => self.pushdown_scan(bind_context, scan),
             other => self.stop_pushdown(bind_context, other),
         }
     }
@@ -147,6 +151,34 @@ impl FilterPushdown {
         self.stop_pushdown(bind_context, LogicalOperator::MaterializationScan(plan))
     }
 
+    /// Push down into a scan.
+    ///
+    /// Simple comparisons between a column and a constant are copied into the
+    /// scan's filters so that the source can skip data. All filters are still
+    /// applied in a filter directly above the scan.
+    fn pushdown_scan(
+        &mut self,
+        bind_context: &mut BindContext,
+        mut plan: Node<LogicalScan>,
+    ) -> Result<LogicalOperator> {
+        if matches!(
+            plan.node.source,
+            ScanSource::Table { .. } | ScanSource::TableFunction { .. }
+        ) {
+            let filters = std::mem::take(&mut self.filter_gen).into_expressions();
+            for filter in &filters {
+                if let Some(scan_filter) = try_scan_filter(&plan.node, filter) {
+                    if !plan.node.scan_filters.contains(&scan_filter) {
+                        plan.node.scan_filters.push(scan_filter);
+                    }
+                }
+            }
+            self.add_filters(filters);
+        }
+
+        self.stop_pushdown(bind_context, LogicalOperator::Scan(plan))
+    }
+
     /// Push down through a project.
     ///
     /// Column references for stored filters will be updated to point to the
@@ -622,6 +654,38 @@ impl FilterPushdown {
     }
 }
 
+/// Try to convert a filter expression into a scan filter for the given scan.
+///
+/// Only handles comparisons between a column from the scan and a non-null
+/// constant.
+fn try_scan_filter(scan: &LogicalScan, filter: &Expression) -> Option<ScanFilter> {
+    let cmp = match filter {
+        Expression::Comparison(cmp) => cmp,
+        _ => return None,
+    };
+
+    let (col, constant, op) = match (cmp.left.as_ref(), cmp.right.as_ref()) {
+        (Expression::Column(col), Expression::Literal(lit)) => (col, &lit.literal, cmp.op),
+        (Expression::Literal(lit), Expression::Column(col)) => (col, &lit.literal, cmp.op.flip()),
+        _ => return None,
+    };
+
+    if col.table_scope != scan.table_ref || matches!(constant, ScalarValue::Null) {
+        return None;
+    }
+
+    // Scan filters reference columns prior to projection.
+    let column = *scan.projection.get(col.column)?;
+
+    Some(ScanFilter {
+        column,
+        filter: ScanFilterType::ConstComparison {
+            op,
+            constant: constant.clone(),
+        },
+    })
+}
+
 /// Recursively replaces column references in `expr` with the underlying column
 /// expression via cloning.
 ///
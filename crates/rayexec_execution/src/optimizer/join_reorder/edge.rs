@@ -9,6 +9,7 @@ use super::ReorderableCondition;
 use crate::explain::context_display::{ContextDisplay, ContextDisplayMode};
 use crate::expr::column_expr::ColumnExpr;
 use crate::expr::comparison_expr::ComparisonOperator;
+use crate::expr::Expression;
 use crate::logical::binder::table_list::TableRef;
 
 pub type HyperEdgeId = usize;
@@ -29,10 +30,11 @@ pub struct HyperEdge {
     pub id: HyperEdgeId,
     /// All distinct edges making up this hyper edge.
     pub edges: HashMap<EdgeId, Edge>,
-    /// Minimum num distinct values across all relations connected by this hyper
-    /// edge.
+    /// Minimum num distinct values across all edges in this hyper edge.
     ///
-    /// This is the basis for our cardinality estimate.
+    /// This is the basis for our cardinality estimate. Each edge's NDV is
+    /// derived from column statistics when available, and relation
+    /// cardinalities otherwise.
     pub min_ndv: f64,
     /// All column expressions within this hyper edge.
     pub columns: HashSet<ColumnExpr>,
@@ -222,8 +224,6 @@ impl HyperEdges {
         condition: ReorderableCondition,
         base_relations: &HashMap<RelId, BaseRelation>,
     ) -> Result<()> {
-        let mut min_ndv = f64::MAX;
-
         let [left_refs, right_refs] = condition.get_left_right_table_refs();
 
         let mut left_rel = None;
@@ -232,30 +232,27 @@ impl HyperEdges {
         for (&rel_id, rel) in base_relations {
             if left_refs.is_subset(&rel.output_refs) {
                 left_rel = Some(rel_id);
-
-                // Note we initialize NDV to relation cardinality which will
-                // typically overestimate NDV, but by taking the min of all
-                // cardinalities involved in the condition, we can
-                // significantly reduce it.
-                min_ndv = f64::min(min_ndv, rel.cardinality);
             }
 
             if right_refs.is_subset(&rel.output_refs) {
                 right_rel = Some(rel_id);
-
-                // See above.
-                min_ndv = f64::min(min_ndv, rel.cardinality);
             }
         }
 
+        let left_rel = left_rel.ok_or_else(|| RayexecError::new("Missing left rel id"))?;
+        let right_rel = right_rel.ok_or_else(|| RayexecError::new("Missing right rel id"))?;
+
+        let min_ndv = Self::condition_ndv(
+            &condition,
+            &base_relations[&left_rel],
+            &base_relations[&right_rel],
+        );
+
         // We have the "local" min_ndv, check existing hyper edges to see if
         // it can be added to one.
 
         let cols = condition.get_column_refs();
 
-        let left_rel = left_rel.ok_or_else(|| RayexecError::new("Missing left rel id"))?;
-        let right_rel = right_rel.ok_or_else(|| RayexecError::new("Missing right rel id"))?;
-
         let edge = Edge {
             filter: Some(condition),
             left_refs,
@@ -307,6 +304,50 @@ impl HyperEdges {
     }
 }
 
+impl HyperEdges {
+    /// Compute the NDV to use for a join condition between two relations.
+    ///
+    /// For equality conditions between two columns with known distinct
+    /// counts, this is the max of the two counts (assuming containment of
+    /// values from the smaller side in the larger side).
+    ///
+    /// Otherwise we fall back to the relation cardinalities which will
+    /// typically overestimate NDV, but by taking the min of all cardinalities
+    /// involved in the condition, we can significantly reduce it.
+    fn condition_ndv(
+        condition: &ReorderableCondition,
+        left: &BaseRelation,
+        right: &BaseRelation,
+    ) -> f64 {
+        let column_ndv = |expr: &Expression, rel: &BaseRelation| match expr {
+            Expression::Column(col) => {
+                let ndv = *rel.columns.get(col)?.num_distinct.value()? as f64;
+                // Filters on the relation may have reduced the number of
+                // distinct values.
+                Some(f64::min(ndv, rel.cardinality).max(1.0))
+            }
+            _ => None,
+        };
+
+        let known = match condition {
+            ReorderableCondition::Inner { condition } => {
+                match (
+                    column_ndv(&condition.left, left),
+                    column_ndv(&condition.right, right),
+                ) {
+                    (Some(left), Some(right)) => Some(f64::max(left, right)),
+                    (Some(ndv), None) => Some(f64::min(ndv, right.cardinality)),
+                    (None, Some(ndv)) => Some(f64::min(ndv, left.cardinality)),
+                    (None, None) => None,
+                }
+            }
+            ReorderableCondition::Semi { .. } => None,
+        };
+
+        known.unwrap_or_else(|| f64::min(left.cardinality, right.cardinality))
+    }
+}
+
 impl ContextDisplay for HyperEdges {
     fn fmt_using_context(
         &self,
@@ -10,9 +10,10 @@
 //! relations and are not considered to be edges.
 //!
 //! Join conditions are converted into "hyper edges" which contains a min NDV (Num
-//! Distinct Values). This NDV is initialized to be the min cardinality of all base
-//! relations connected by the hyper edge. The min NDV is used when computing the
-//! estimated output cardinality of a join.
+//! Distinct Values). This NDV is computed from column statistics of the joined
+//! columns if available, otherwise it's initialized to be the min cardinality of
+//! all base relations connected by the hyper edge. The min NDV is used when
+//! computing the estimated output cardinality of a join.
 //!
 //! Estimating the cardinality is done by dividing the product of all base relation
 //! cardinalities taking part in the join by the a selectivity denominator. The
@@ -29,7 +30,11 @@ use fmtutil::IntoDisplayableSlice;
 use rayexec_error::{RayexecError, Result};
 
 use super::edge::{EdgeId, EdgeType, HyperEdges, NeighborEdge};
-use super::statistics::propagate_estimated_cardinality;
+use super::statistics::{
+    collect_column_statistics,
+    propagate_estimated_cardinality,
+    ColumnStatisticsMap,
+};
 use super::subgraph::Subgraph;
 use super::ReorderableCondition;
 use crate::expr;
@@ -43,7 +48,7 @@ use crate::logical::logical_join::{
     LogicalCrossJoin,
 };
 use crate::logical::operator::{LocationRequirement, LogicalNode, LogicalOperator, Node};
-use crate::logical::statistics::assumptions::DEFAULT_SELECTIVITY;
+use crate::logical::statistics::selectivity::SelectivityEstimator;
 use crate::logical::statistics::StatisticsValue;
 use crate::optimizer::filter_pushdown::extracted_filter::ExtractedFilter;
 
@@ -84,6 +89,8 @@ pub struct BaseRelation {
     pub operator: LogicalOperator,
     pub output_refs: HashSet<TableRef>,
     pub cardinality: f64,
+    /// Statistics for columns output by this relation.
+    pub columns: ColumnStatisticsMap,
 }
 
 #[derive(Debug, Clone)]
@@ -237,6 +244,10 @@ pub struct Graph {
     filters: HashMap<FilterId, ExtractedFilter>,
     /// Base input relations.
     base_relations: HashMap<RelId, BaseRelation>,
+    /// Statistics for columns across all base relations.
+    ///
+    /// Used for estimating the selectivity of extra filters.
+    column_statistics: ColumnStatisticsMap,
     /// Best join node plans we've found for the given set of relations.
     best_plans: HashMap<RelationSet, JoinNode>,
     /// Count of join pairs considered.
@@ -268,6 +279,7 @@ impl Graph {
                     .value()
                     .copied()
                     .unwrap_or(20_000) as f64;
+                let columns = collect_column_statistics(&op);
 
                 (
                     rel_id,
@@ -276,6 +288,7 @@ impl Graph {
                         operator: op,
                         output_refs,
                         cardinality,
+                        columns,
                     },
                 )
             })
@@ -283,6 +296,11 @@ impl Graph {
 
         let hyper_edges = HyperEdges::new(conditions, &base_relations)?;
 
+        let column_statistics = base_relations
+            .values()
+            .flat_map(|rel| rel.columns.iter().map(|(col, stats)| (*col, stats.clone())))
+            .collect();
+
         let filters = filters.into_iter().enumerate().collect();
 
         let mut best_plans = HashMap::with_capacity(base_relations.len());
@@ -318,6 +336,7 @@ impl Graph {
             filters,
             best_plans,
             base_relations,
+            column_statistics,
             pairs_considered: 0,
         })
     }
@@ -558,8 +577,12 @@ impl Graph {
         //
         // We cannot assume that the filter is filtering on the same attributes
         // we're joining on, so avoid updating the denominator.
-        for _ in 0..left_filters.len() + right_filters.len() {
-            subgraph.numerator *= DEFAULT_SELECTIVITY;
+        let estimator = SelectivityEstimator {
+            columns: &self.column_statistics,
+            num_rows: None,
+        };
+        for (_, filter) in left_filters.iter().chain(&right_filters) {
+            subgraph.numerator *= estimator.estimate(&filter.filter);
         }
 
         // Update denominator based on edges used for this join.
@@ -766,6 +789,9 @@ impl Graph {
             .remove(&node.right)
             .ok_or_else(|| RayexecError::new("Missing right input"))?;
 
+        let mut left_filters = &node.left_filters;
+        let mut right_filters = &node.right_filters;
+
         // If any of the conditions are part of a semi join, ensure the correct
         // order of the plans and prevent swapping sides.
         let mut any_semi = false;
@@ -779,8 +805,10 @@ impl Graph {
                 let [left_refs, _right_refs] = cond.get_left_right_table_refs();
 
                 if !left_refs.is_subset(&left_gen.output_refs) {
-                    // Need to swap to get the plans on the right side.
+                    // Need to swap to get the plans on the right side. Filters
+                    // need to follow their plans.
                     std::mem::swap(&mut left_gen, &mut right_gen);
+                    std::mem::swap(&mut left_filters, &mut right_filters);
                 }
 
                 any_semi = true;
@@ -824,8 +852,8 @@ impl Graph {
         let left = self.build_from_generated(&left_gen)?;
         let right = self.build_from_generated(&right_gen)?;
 
-        let left = self.apply_filters(left, &node.left_filters)?;
-        let right = self.apply_filters(right, &node.right_filters)?;
+        let left = self.apply_filters(left, left_filters)?;
+        let right = self.apply_filters(right, right_filters)?;
 
         // Determine if we should swap sides. We always want left (build) side
         // to have the lower cardinality (not necessarily cost).
@@ -1,11 +1,20 @@
+use std::collections::HashMap;
+
 use rayexec_error::Result;
 
+use crate::expr::column_expr::ColumnExpr;
+use crate::expr::Expression;
+use crate::logical::binder::table_list::TableRef;
 use crate::logical::logical_aggregate::LogicalAggregate;
 use crate::logical::logical_filter::LogicalFilter;
 use crate::logical::logical_project::LogicalProject;
 use crate::logical::operator::{LogicalOperator, Node};
 use crate::logical::statistics::assumptions::DEFAULT_SELECTIVITY;
-use crate::logical::statistics::StatisticsValue;
+use crate::logical::statistics::selectivity::SelectivityEstimator;
+use crate::logical::statistics::{ColumnStatistics, StatisticsValue};
+
+/// Column statistics keyed by the column expressions that reference them.
+pub type ColumnStatisticsMap = HashMap<ColumnExpr, ColumnStatistics>;
 
 /// Propagates estimated cardinalities from the bottom up.
 pub fn propagate_estimated_cardinality(op: &mut LogicalOperator) -> Result<()> {
@@ -19,6 +28,68 @@ pub fn propagate_estimated_cardinality(op: &mut LogicalOperator) -> Result<()> {
     Ok(())
 }
 
+/// Collect statistics for the columns output by an operator.
+///
+/// Statistics originate from scans, and are passed through projections,
+/// filters, and group by columns when the column is referenced directly.
+pub fn collect_column_statistics(op: &LogicalOperator) -> ColumnStatisticsMap {
+    let mut stats = ColumnStatisticsMap::new();
+
+    match op {
+        LogicalOperator::Scan(scan) => {
+            for idx in 0..scan.node.projection.len() {
+                if let Some(col_stats) = scan.node.projected_column_statistics(idx) {
+                    stats.insert(ColumnExpr::new(scan.node.table_ref, idx), col_stats.clone());
+                }
+            }
+        }
+        LogicalOperator::Filter(filter) => {
+            if let Some(child) = filter.children.first() {
+                stats = collect_column_statistics(child);
+            }
+        }
+        LogicalOperator::Project(project) => {
+            if let Some(child) = project.children.first() {
+                let child_stats = collect_column_statistics(child);
+                stats = passthrough_columns(
+                    &child_stats,
+                    &project.node.projections,
+                    project.node.projection_table,
+                );
+            }
+        }
+        LogicalOperator::Aggregate(agg) => {
+            if let (Some(child), Some(group_table)) = (agg.children.first(), agg.node.group_table) {
+                let child_stats = collect_column_statistics(child);
+                stats = passthrough_columns(&child_stats, &agg.node.group_exprs, group_table);
+            }
+        }
+        _ => (),
+    }
+
+    stats
+}
+
+/// Map statistics for expressions that directly reference a column to the
+/// output columns of the given table.
+fn passthrough_columns(
+    child_stats: &ColumnStatisticsMap,
+    exprs: &[Expression],
+    output_table: TableRef,
+) -> ColumnStatisticsMap {
+    exprs
+        .iter()
+        .enumerate()
+        .filter_map(|(idx, expr)| match expr {
+            Expression::Column(col) => {
+                let col_stats = child_stats.get(col)?;
+                Some((ColumnExpr::new(output_table, idx), col_stats.clone()))
+            }
+            _ => None,
+        })
+        .collect()
+}
+
 fn propagate_project(op: &mut Node<LogicalProject>) -> Result<()> {
     let child = op.get_nth_child_mut(0)?;
     propagate_estimated_cardinality(child)?;
@@ -28,13 +99,20 @@ fn propagate_project(op: &mut Node<LogicalProject>) -> Result<()> {
 }
 
 fn propagate_filter(op: &mut Node<LogicalFilter>) -> Result<()> {
-    let child = op.get_nth_child_mut(0)?;
-    propagate_estimated_cardinality(child)?;
+    propagate_estimated_cardinality(op.get_nth_child_mut(0)?)?;
+    let child = op.get_nth_child(0)?;
 
     let estimated = match child.estimated_cardinality().value() {
         Some(v) => {
-            // TODO: Use more specific selectivities if we can.
-            let est = (*v as f64) * DEFAULT_SELECTIVITY;
+            let columns = collect_column_statistics(child);
+            let estimator = SelectivityEstimator {
+                columns: &columns,
+                num_rows: Some(*v as f64),
+            };
+            let est = (*v as f64) * estimator.estimate(&op.node.filter);
+            // Avoid estimating zero rows for non-empty inputs, it makes every
+            // join order above this look free.
+            let est = if *v > 0 { f64::max(est, 1.0) } else { 0.0 };
             StatisticsValue::Estimated(est as usize)
         }
         None => StatisticsValue::Unknown,
@@ -46,8 +124,8 @@ fn propagate_filter(op: &mut Node<LogicalFilter>) -> Result<()> {
 }
 
 fn propagate_aggregate(op: &mut Node<LogicalAggregate>) -> Result<()> {
-    let child = op.get_nth_child_mut(0)?;
-    propagate_estimated_cardinality(child)?;
+    propagate_estimated_cardinality(op.get_nth_child_mut(0)?)?;
+    let child = op.get_nth_child(0)?;
 
     let child_card = child.estimated_cardinality();
 
@@ -56,10 +134,21 @@ fn propagate_aggregate(op: &mut Node<LogicalAggregate>) -> Result<()> {
     } else {
         let estimated = match child_card.value() {
             Some(v) => {
-                // TODO: Do something a bit more reasonable. We should be
-                // looking at the columns in the group expressions and checking
-                // distinc values.
-                let est = (*v as f64) * DEFAULT_SELECTIVITY;
+                // Number of groups is bounded by the product of distinct
+                // values in each group column.
+                let columns = collect_column_statistics(child);
+                let group_ndv = op.node.group_exprs.iter().try_fold(1.0, |acc, expr| {
+                    let ndv = match expr {
+                        Expression::Column(col) => columns.get(col)?.num_distinct.value()?,
+                        _ => return None,
+                    };
+                    Some(acc * (*ndv as f64))
+                });
+
+                let est = match group_ndv {
+                    Some(ndv) => f64::min(ndv, *v as f64),
+                    None => (*v as f64) * DEFAULT_SELECTIVITY,
+                };
                 StatisticsValue::Estimated(est as usize)
             }
             None => StatisticsValue::Unknown,
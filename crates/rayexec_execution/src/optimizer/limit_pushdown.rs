@@ -5,6 +5,9 @@ use crate::logical::binder::bind_context::BindContext;
 use crate::logical::operator::LogicalOperator;
 
 /// Push down a limit below a project.
+///
+/// A limit that ends up directly on top of an order is folded into the order
+/// itself so that we can plan a TopK instead of a full sort.
 #[derive(Debug)]
 pub struct LimitPushdown;
 
@@ -23,6 +26,16 @@ impl OptimizeRule for LimitPushdown {
                 *project.children_mut() = vec![LogicalOperator::Limit(limit)];
 
                 plan = project;
+            } else if limit.children.len() == 1
+                && matches!(&limit.children[0], LogicalOperator::Order(order) if order.node.limit.is_none())
+            {
+                let mut order = match limit.children.pop().unwrap() {
+                    LogicalOperator::Order(order) => order,
+                    _ => unreachable!("child checked to be order"),
+                };
+                order.node.limit = Some(limit.node);
+
+                plan = LogicalOperator::Order(order);
             } else {
                 plan = LogicalOperator::Limit(limit);
             }
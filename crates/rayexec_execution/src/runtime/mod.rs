@@ -1,4 +1,5 @@
 pub mod handle;
+pub mod spill;
 pub mod time;
 
 use std::fmt::Debug;
@@ -8,6 +9,7 @@ use handle::QueryHandle;
 use rayexec_error::{RayexecError, Result};
 use rayexec_io::http::HttpClient;
 use rayexec_io::FileProvider;
+use spill::SpillProvider;
 use time::RuntimeInstant;
 
 use crate::execution::executable::pipeline::ExecutablePipeline;
@@ -64,6 +66,12 @@ pub trait Runtime: Debug + Sync + Send + Clone + 'static {
     /// Data sources should error if they require tokio and if this returns
     /// None.
     fn tokio_handle(&self) -> &Self::TokioHandle;
+
+    /// Returns the provider operators spill data to when exceeding the memory
+    /// limit.
+    ///
+    /// Operators will error instead of spilling if this returns None.
+    fn spill_provider(&self) -> Option<Arc<dyn SpillProvider>>;
 }
 
 pub trait TokioHandlerProvider {
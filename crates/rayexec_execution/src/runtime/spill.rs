@@ -0,0 +1,123 @@
+use std::fmt::Debug;
+use std::task::{Context, Poll};
+
+use rayexec_error::Result;
+
+use crate::execution::operators::util::spill::MemoryReservation;
+
+/// Provides storage for data operators spill when buffering it in memory would
+/// exceed the memory limit.
+///
+/// Runtimes without somewhere to spill to (e.g. wasm) don't provide one, and
+/// queries that exceed the memory limit will error instead.
+pub trait SpillProvider: Debug + Sync + Send {
+    /// Create new, empty storage for spilled data.
+    fn create_storage(&self) -> Result<Box<dyn SpillStorage>>;
+}
+
+/// A chunk of spilled data waiting to be persisted.
+///
+/// Memory for the chunk stays reserved until the chunk is dropped, so storage
+/// should hold on to it until the data no longer needs to be kept in memory.
+#[derive(Debug)]
+pub struct SpillChunk {
+    data: Vec<u8>,
+    reservation: MemoryReservation,
+}
+
+impl SpillChunk {
+    pub(crate) fn new(data: Vec<u8>, reservation: MemoryReservation) -> Self {
+        SpillChunk { data, reservation }
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    /// Take the data for the chunk, releasing its reservation.
+    pub fn into_data(mut self) -> Vec<u8> {
+        self.reservation.release();
+        self.data
+    }
+}
+
+/// Append-only storage for chunks of spilled data.
+///
+/// Any persisted data should be removed once this is dropped.
+pub trait SpillStorage: Debug + Sync + Send {
+    /// Append a chunk to the storage.
+    ///
+    /// This must not block. Errors encountered while persisting the chunk may
+    /// be returned from later calls instead.
+    fn write_chunk(&mut self, chunk: SpillChunk) -> Result<()>;
+
+    /// Mark the storage as finished. No more chunks will be written.
+    ///
+    /// This must not block.
+    fn finish(&mut self) -> Result<()>;
+
+    /// Create a reader for reading back all chunks in the order they were
+    /// written.
+    ///
+    /// Storage must be finished before creating a reader.
+    fn reader(&self) -> Result<Box<dyn SpillStorageReader>>;
+}
+
+/// Reads chunks back from spill storage.
+pub trait SpillStorageReader: Debug + Sync + Send {
+    /// Poll for the next chunk, returning None once all chunks have been read.
+    fn poll_next_chunk(&mut self, cx: &mut Context) -> Poll<Result<Option<Vec<u8>>>>;
+}
+
+/// Spill provider keeping all chunks in memory.
+#[cfg(test)]
+#[derive(Debug, Default)]
+pub struct MemorySpillProvider;
+
+#[cfg(test)]
+impl SpillProvider for MemorySpillProvider {
+    fn create_storage(&self) -> Result<Box<dyn SpillStorage>> {
+        Ok(Box::new(MemorySpillStorage::default()))
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug, Default)]
+struct MemorySpillStorage {
+    chunks: Vec<Vec<u8>>,
+    finished: bool,
+}
+
+#[cfg(test)]
+impl SpillStorage for MemorySpillStorage {
+    fn write_chunk(&mut self, chunk: SpillChunk) -> Result<()> {
+        assert!(!self.finished);
+        self.chunks.push(chunk.into_data());
+        Ok(())
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.finished = true;
+        Ok(())
+    }
+
+    fn reader(&self) -> Result<Box<dyn SpillStorageReader>> {
+        assert!(self.finished);
+        Ok(Box::new(MemorySpillStorageReader {
+            chunks: self.chunks.clone().into(),
+        }))
+    }
+}
+
+#[cfg(test)]
+#[derive(Debug)]
+struct MemorySpillStorageReader {
+    chunks: std::collections::VecDeque<Vec<u8>>,
+}
+
+#[cfg(test)]
+impl SpillStorageReader for MemorySpillStorageReader {
+    fn poll_next_chunk(&mut self, _cx: &mut Context) -> Poll<Result<Option<Vec<u8>>>> {
+        Poll::Ready(Ok(self.chunks.pop_front()))
+    }
+}
@@ -14,6 +14,12 @@ pub trait RuntimeInstant {
     /// `earlier` is later than `self`, this should return a duration
     /// representing zero.
     fn duration_since(&self, earlier: Self) -> Duration;
+
+    /// Returns the duration since some fixed reference point.
+    ///
+    /// The reference point is the same for all instants in the process,
+    /// allowing comparing instants that are no longer around.
+    fn since_reference(&self) -> Duration;
 }
 
 #[derive(Debug)]
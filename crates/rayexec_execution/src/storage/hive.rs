@@ -0,0 +1,511 @@
+//! Hive-style partitioning.
+//!
+//! Files are laid out in directories named `key=value`, e.g.
+//! `year=2024/month=05/part-0.parquet`. Each key is exposed as a virtual
+//! column with the value taken from the file's path.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+use futures::future::BoxFuture;
+use futures::FutureExt;
+use indexmap::IndexMap;
+use rayexec_error::{RayexecError, Result};
+use rayexec_io::location::FileLocation;
+
+use crate::arrays::batch::Batch;
+use crate::arrays::compute::cast::parse::{Date32Parser, Parser};
+use crate::arrays::datatype::DataType;
+use crate::arrays::field::{Field, Schema};
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use crate::arrays::selection::SelectionVector;
+use crate::execution::operators::sink::PartitionSink;
+use crate::functions::copy::CopyToFunction;
+use crate::logical::statistics::selectivity::compare_scalars;
+use crate::logical::statistics::{ColumnStatistics, StatisticsValue};
+
+/// Directory name used for NULL partition values.
+pub const HIVE_DEFAULT_PARTITION: &str = "__HIVE_DEFAULT_PARTITION__";
+
+/// Partition columns and values parsed from the paths of a set of files.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HivePartitions {
+    /// Partition columns, in the order they appear in the paths.
+    pub fields: Vec<Field>,
+    /// Values for each file, one value per partition column.
+    values: Vec<Vec<OwnedScalarValue>>,
+}
+
+impl HivePartitions {
+    /// Parse partitions from file locations.
+    ///
+    /// If `hive_partitioning` is None, partitioning is enabled only if the
+    /// first location contains a `key=value` directory. If true, an error is
+    /// returned if there are no partitions.
+    ///
+    /// Every file must have the same partition keys in the same order.
+    pub fn try_from_locations(
+        locations: &[FileLocation],
+        hive_partitioning: Option<bool>,
+    ) -> Result<Option<Self>> {
+        if hive_partitioning == Some(false) {
+            return Ok(None);
+        }
+
+        let parsed = locations
+            .iter()
+            .map(parse_partition_segments)
+            .collect::<Result<Vec<_>>>()?;
+
+        let first_keys: Vec<_> = match parsed.first() {
+            Some(first) => first.iter().map(|(key, _)| key.as_str()).collect(),
+            None => return Ok(None),
+        };
+
+        if first_keys.is_empty() {
+            if hive_partitioning == Some(true) {
+                return Err(RayexecError::new(format!(
+                    "No hive partitions found in '{}'",
+                    locations[0]
+                )));
+            }
+            return Ok(None);
+        }
+
+        for (idx, segments) in parsed.iter().enumerate().skip(1) {
+            let keys: Vec<_> = segments.iter().map(|(key, _)| key.as_str()).collect();
+            if keys != first_keys {
+                return Err(RayexecError::new(format!(
+                    "Hive partition keys in '{}' ({}) don't match keys in '{}' ({})",
+                    locations[idx],
+                    keys.join(", "),
+                    locations[0],
+                    first_keys.join(", "),
+                )));
+            }
+        }
+
+        let fields: Vec<_> = first_keys
+            .iter()
+            .enumerate()
+            .map(|(col, key)| {
+                let datatype = infer_type(parsed.iter().map(|segments| &segments[col].1));
+                Field::new(*key, datatype, true)
+            })
+            .collect();
+
+        let values = parsed
+            .into_iter()
+            .map(|segments| {
+                segments
+                    .into_iter()
+                    .zip(&fields)
+                    .map(|((_, value), field)| parse_value(value.as_deref(), &field.datatype))
+                    .collect()
+            })
+            .collect();
+
+        Ok(Some(HivePartitions { fields, values }))
+    }
+
+    /// Get the partition values for a file.
+    pub fn file_values(&self, file_idx: usize) -> &[OwnedScalarValue] {
+        &self.values[file_idx]
+    }
+
+    /// Get exact statistics for a partition column across all files.
+    ///
+    /// The null count is only known if there are no NULL values since we don't
+    /// know the number of rows in each file.
+    pub fn column_statistics(&self, col: usize) -> ColumnStatistics {
+        let mut distinct: Vec<&OwnedScalarValue> = Vec::new();
+        let mut has_nulls = false;
+
+        for value in self.values.iter().map(|values| &values[col]) {
+            if matches!(value, ScalarValue::Null) {
+                has_nulls = true;
+            } else if !distinct.contains(&value) {
+                distinct.push(value);
+            }
+        }
+
+        let min_max = |want: std::cmp::Ordering| {
+            distinct
+                .iter()
+                .copied()
+                .reduce(|a, b| match compare_scalars(b, a) {
+                    Some(ord) if ord == want => b,
+                    _ => a,
+                })
+                .cloned()
+        };
+
+        ColumnStatistics {
+            num_distinct: StatisticsValue::Exact(distinct.len()),
+            null_count: if has_nulls {
+                StatisticsValue::Unknown
+            } else {
+                StatisticsValue::Exact(0)
+            },
+            min: min_max(std::cmp::Ordering::Less)
+                .map_or(StatisticsValue::Unknown, StatisticsValue::Exact),
+            max: min_max(std::cmp::Ordering::Greater)
+                .map_or(StatisticsValue::Unknown, StatisticsValue::Exact),
+        }
+    }
+}
+
+/// Parse `key=value` directory segments from a file location.
+///
+/// The file name itself is never treated as a partition. Empty values and the
+/// hive default partition are returned as None.
+pub fn parse_partition_segments(location: &FileLocation) -> Result<Vec<(String, Option<String>)>> {
+    let dirs: Vec<String> = match location {
+        FileLocation::Url(url) => match url.path_segments() {
+            // Url segments are percent encoded on top of the hive escaping.
+            Some(segments) => segments.map(unescape).collect(),
+            None => Vec::new(),
+        },
+        FileLocation::Path(path) => path
+            .iter()
+            .map(|seg| seg.to_string_lossy().into_owned())
+            .collect(),
+    };
+
+    let mut partitions: Vec<(String, Option<String>)> = Vec::new();
+    for dir in dirs.iter().take(dirs.len().saturating_sub(1)) {
+        let (key, value) = match dir.split_once('=') {
+            Some((key, value)) if !key.is_empty() => (unescape(key), unescape(value)),
+            _ => continue,
+        };
+
+        if partitions.iter().any(|(existing, _)| *existing == key) {
+            return Err(RayexecError::new(format!(
+                "Duplicate hive partition key '{key}' in '{location}'"
+            )));
+        }
+
+        let value = if value.is_empty() || value == HIVE_DEFAULT_PARTITION {
+            None
+        } else {
+            Some(value)
+        };
+        partitions.push((key, value));
+    }
+
+    Ok(partitions)
+}
+
+/// Create the `key=value` directory name for writing a partition.
+pub fn partition_dir_name(key: &str, value: &ScalarValue) -> String {
+    let value = match value {
+        ScalarValue::Null => HIVE_DEFAULT_PARTITION.to_string(),
+        value => escape(&value.to_string()),
+    };
+    format!("{}={value}", escape(key))
+}
+
+/// Infer the type for a partition column from its values.
+///
+/// Integers are preferred, followed by dates, falling back to strings. NULL
+/// values are ignored.
+fn infer_type<'a>(values: impl Iterator<Item = &'a Option<String>> + Clone) -> DataType {
+    let mut non_null = values.flatten().peekable();
+    if non_null.peek().is_none() {
+        return DataType::Utf8;
+    }
+
+    if non_null.clone().all(|v| v.parse::<i64>().is_ok()) {
+        DataType::Int64
+    } else if non_null.all(|v| Date32Parser.parse(v).is_some()) {
+        DataType::Date32
+    } else {
+        DataType::Utf8
+    }
+}
+
+fn parse_value(value: Option<&str>, datatype: &DataType) -> OwnedScalarValue {
+    let value = match value {
+        Some(value) => value,
+        None => return OwnedScalarValue::Null,
+    };
+
+    // Types were inferred such that every value can be parsed.
+    match datatype {
+        DataType::Int64 => value.parse::<i64>().map(OwnedScalarValue::Int64).ok(),
+        DataType::Date32 => Date32Parser.parse(value).map(OwnedScalarValue::Date32),
+        _ => None,
+    }
+    .unwrap_or_else(|| OwnedScalarValue::Utf8(value.to_string().into()))
+}
+
+/// Characters escaped in partition directory names, matching hive.
+fn needs_escape(c: char) -> bool {
+    c.is_ascii_control()
+        || matches!(
+            c,
+            '"' | '#' | '%' | '\'' | '*' | '/' | ':' | '=' | '?' | '\\' | '{' | '[' | ']' | '^'
+        )
+}
+
+fn escape(s: &str) -> String {
+    let mut out = String::with_capacity(s.len());
+    for c in s.chars() {
+        if needs_escape(c) {
+            out.push_str(&format!("%{:02X}", c as u32));
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+fn unescape(s: &str) -> String {
+    let bytes = s.as_bytes();
+    let mut out = Vec::with_capacity(bytes.len());
+    let mut idx = 0;
+    while idx < bytes.len() {
+        if bytes[idx] == b'%' && idx + 2 < bytes.len() {
+            let hex = |b: u8| (b as char).to_digit(16);
+            if let (Some(hi), Some(lo)) = (hex(bytes[idx + 1]), hex(bytes[idx + 2])) {
+                out.push((hi * 16 + lo) as u8);
+                idx += 3;
+                continue;
+            }
+        }
+        out.push(bytes[idx]);
+        idx += 1;
+    }
+    String::from_utf8_lossy(&out).into_owned()
+}
+
+/// Sink for writing a hive partitioned directory tree.
+///
+/// Batches are split on the values of the partition columns, and each
+/// distinct set of values is written to its own file through a sink created by
+/// the COPY TO function. Partition columns are not written to the files.
+pub struct HivePartitionedSink {
+    copy_to: Box<dyn CopyToFunction>,
+    /// Root directory to write to.
+    root: FileLocation,
+    /// Schema of the batches written to each file.
+    file_schema: Schema,
+    /// Indices of the partition columns in the input.
+    partition_by: Vec<usize>,
+    /// Names of the partition columns.
+    partition_names: Vec<String>,
+    /// Indices of all other columns in the input.
+    data_columns: Vec<usize>,
+    /// Name of the files written by this sink.
+    file_name: String,
+    /// Sinks keyed by partition directory.
+    sinks: HashMap<String, Box<dyn PartitionSink>>,
+}
+
+impl HivePartitionedSink {
+    pub fn try_new(
+        copy_to: Box<dyn CopyToFunction>,
+        root: FileLocation,
+        schema: &Schema,
+        partition_by: Vec<usize>,
+        file_name: String,
+    ) -> Result<Self> {
+        let data_columns: Vec<_> = (0..schema.fields.len())
+            .filter(|idx| !partition_by.contains(idx))
+            .collect();
+        if data_columns.is_empty() {
+            return Err(RayexecError::new(
+                "Cannot partition by every column, at least one column must be written to the files",
+            ));
+        }
+
+        let partition_names = partition_by
+            .iter()
+            .map(|&idx| schema.fields[idx].name.clone())
+            .collect();
+        let file_schema = Schema::new(data_columns.iter().map(|&idx| schema.fields[idx].clone()));
+
+        Ok(HivePartitionedSink {
+            copy_to,
+            root,
+            file_schema,
+            partition_by,
+            partition_names,
+            data_columns,
+            file_name,
+            sinks: HashMap::new(),
+        })
+    }
+
+    #[allow(deprecated)]
+    async fn push_inner(&mut self, batch: Batch) -> Result<()> {
+        // Rows for each partition, in the order the partitions are seen.
+        let mut partitions: IndexMap<String, Vec<usize>> = IndexMap::new();
+        for row in 0..batch.num_rows() {
+            let mut dirs = Vec::with_capacity(self.partition_by.len());
+            for (&col, name) in self.partition_by.iter().zip(&self.partition_names) {
+                let array = batch
+                    .array(col)
+                    .ok_or_else(|| RayexecError::new(format!("Missing partition column {col}")))?;
+                dirs.push(partition_dir_name(name, &array.logical_value(row)?));
+            }
+            partitions.entry(dirs.join("/")).or_default().push(row);
+        }
+
+        for (dir, rows) in partitions {
+            let selected = batch.select_old(Arc::new(SelectionVector::from(rows)));
+            let selected = selected.project(&self.data_columns);
+
+            if !self.sinks.contains_key(&dir) {
+                let location = self
+                    .root
+                    .join(dir.split('/').chain([self.file_name.as_str()]))?;
+                let sink = self
+                    .copy_to
+                    .create_sinks(self.file_schema.clone(), location, 1)?
+                    .pop()
+                    .ok_or_else(|| RayexecError::new("COPY TO function returned no sinks"))?;
+                self.sinks.insert(dir.clone(), sink);
+            }
+
+            let sink = self.sinks.get_mut(&dir).expect("sink to exist");
+            sink.push(selected).await?;
+        }
+
+        Ok(())
+    }
+
+    async fn finalize_inner(&mut self) -> Result<()> {
+        for sink in self.sinks.values_mut() {
+            sink.finalize().await?;
+        }
+        Ok(())
+    }
+}
+
+impl PartitionSink for HivePartitionedSink {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        self.push_inner(batch).boxed()
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        self.finalize_inner().boxed()
+    }
+}
+
+impl fmt::Debug for HivePartitionedSink {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("HivePartitionedSink")
+            .field("root", &self.root)
+            .field("partition_by", &self.partition_by)
+            .finish_non_exhaustive()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn locations(paths: &[&str]) -> Vec<FileLocation> {
+        paths.iter().map(|path| FileLocation::parse(path)).collect()
+    }
+
+    #[test]
+    fn parse_segments() {
+        let segments = parse_partition_segments(&FileLocation::parse(
+            "data/year=2024/month=05/part-0.parquet",
+        ))
+        .unwrap();
+        assert_eq!(
+            vec![
+                ("year".to_string(), Some("2024".to_string())),
+                ("month".to_string(), Some("05".to_string())),
+            ],
+            segments
+        );
+
+        // File name isn't a partition.
+        let segments = parse_partition_segments(&FileLocation::parse("dir/a=1.csv")).unwrap();
+        assert!(segments.is_empty());
+
+        let segments = parse_partition_segments(&FileLocation::parse(
+            "s3://bucket/city=New%20York/k=__HIVE_DEFAULT_PARTITION__/f.parquet",
+        ))
+        .unwrap();
+        assert_eq!(
+            vec![
+                ("city".to_string(), Some("New York".to_string())),
+                ("k".to_string(), None),
+            ],
+            segments
+        );
+    }
+
+    #[test]
+    fn infer_partition_types() {
+        let partitions = HivePartitions::try_from_locations(
+            &locations(&[
+                "year=2024/day=2024-05-01/name=a/f.csv",
+                "year=2023/day=2023-01-10/name=1/f.csv",
+            ]),
+            None,
+        )
+        .unwrap()
+        .unwrap();
+
+        let types: Vec<_> = partitions.fields.iter().map(|f| &f.datatype).collect();
+        assert_eq!(
+            vec![&DataType::Int64, &DataType::Date32, &DataType::Utf8],
+            types
+        );
+        assert_eq!(
+            &[
+                ScalarValue::Int64(2023),
+                ScalarValue::Date32(19367),
+                ScalarValue::from("1")
+            ],
+            partitions.file_values(1)
+        );
+    }
+
+    #[test]
+    fn mismatched_keys() {
+        HivePartitions::try_from_locations(&locations(&["a=1/f.csv", "b=1/f.csv"]), None)
+            .unwrap_err();
+    }
+
+    #[test]
+    fn no_partitions() {
+        let locs = locations(&["dir/f.csv"]);
+        assert_eq!(
+            None,
+            HivePartitions::try_from_locations(&locs, None).unwrap()
+        );
+        HivePartitions::try_from_locations(&locs, Some(true)).unwrap_err();
+
+        let locs = locations(&["a=1/f.csv"]);
+        assert_eq!(
+            None,
+            HivePartitions::try_from_locations(&locs, Some(false)).unwrap()
+        );
+    }
+
+    #[test]
+    fn dir_name_roundtrip() {
+        let name = partition_dir_name("k", &ScalarValue::from("a/b=c%"));
+        assert_eq!("k=a%2Fb%3Dc%25", name);
+
+        let segments =
+            parse_partition_segments(&FileLocation::parse(&format!("{name}/f.csv"))).unwrap();
+        assert_eq!(
+            vec![("k".to_string(), Some("a/b=c%".to_string()))],
+            segments
+        );
+
+        assert_eq!(
+            "k=__HIVE_DEFAULT_PARTITION__",
+            partition_dir_name("k", &ScalarValue::Null)
+        );
+    }
+}
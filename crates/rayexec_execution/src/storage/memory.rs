@@ -1,19 +1,56 @@
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
 use futures::future::BoxFuture;
 use parking_lot::Mutex;
 use rayexec_error::{RayexecError, Result};
 
-use super::table_storage::{DataTable, DataTableScan, ProjectedScan, Projections, TableStorage};
+use super::table_storage::{
+    DataTable,
+    DataTableDelete,
+    DataTableScan,
+    DataTableUpdate,
+    ProjectedScan,
+    Projections,
+    TableStorage,
+};
+use crate::arrays::array::physical_type::PhysicalU64;
+use crate::arrays::array::Array;
 use crate::arrays::batch::Batch;
+use crate::arrays::executor::scalar::{interleave, UnaryExecutor};
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use crate::arrays::selection::SelectionVector;
+use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::CatalogEntry;
+use crate::database::mvcc::{VersionChain, VersionedMap};
 use crate::execution::computed_batch::ComputedBatches;
 use crate::execution::operators::sink::PartitionSink;
 use crate::execution::operators::util::resizer::{BatchResizer, DEFAULT_TARGET_BATCH_SIZE};
+use crate::logical::scan_filter::ScanFilter;
+use crate::logical::statistics::selectivity::compare_scalars;
+use crate::logical::statistics::{ColumnStatistics, StatisticsValue, TableStatistics};
 
 #[derive(Debug, Default)]
 pub struct MemoryTableStorage {
-    tables: scc::HashIndex<TableKey, MemoryDataTable>,
+    tables: VersionedMap<TableKey, TableData>,
+}
+
+/// Batches for a single table.
+///
+/// Each batch is versioned independently so that inserts, updates, and deletes
+/// are only visible to other transactions once committed. Chains are only ever
+/// appended, so a chain's index in the table never changes.
+type TableData = Arc<Mutex<Vec<Arc<VersionChain<Batch>>>>>;
+
+/// Get the row id for a row in the batch at the given chain index.
+///
+/// Row ids encode the chain index in the upper 32 bits and the row's position
+/// within the batch in the lower 32 bits. Since updates and deletes replace a
+/// batch within its chain, row ids remain valid even if other transactions
+/// add batches to the table.
+fn row_id(chain_idx: usize, row_idx: usize) -> u64 {
+    ((chain_idx as u64) << 32) | row_idx as u64
 }
 
 // Temporary, we'd want to key by oid or something to handle table renames
@@ -25,23 +62,32 @@ struct TableKey {
 }
 
 impl TableStorage for MemoryTableStorage {
-    fn data_table(&self, schema: &str, ent: &CatalogEntry) -> Result<Box<dyn DataTable>> {
+    fn data_table(
+        &self,
+        tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> Result<Box<dyn DataTable>> {
         let key = TableKey {
             schema: schema.to_string(),
             name: ent.name.clone(),
         };
 
-        let table = self.tables.get(&key).ok_or_else(|| {
+        let data = self.tables.get(tx, &key).ok_or_else(|| {
             RayexecError::new(format!(
                 "Missing physical memory table for entry: {ent:?}. Cannot get data table",
             ))
         })?;
 
-        Ok(Box::new(table.get().clone()))
+        Ok(Box::new(MemoryDataTable {
+            data,
+            tx: tx.clone(),
+        }))
     }
 
     fn create_physical_table(
         &self,
+        tx: &CatalogTx,
         schema: &str,
         ent: &CatalogEntry,
     ) -> BoxFuture<'_, Result<Box<dyn DataTable>>> {
@@ -49,30 +95,33 @@ impl TableStorage for MemoryTableStorage {
             schema: schema.to_string(),
             name: ent.name.clone(),
         };
+        let tx = tx.clone();
 
-        Box::pin(async {
-            match self.tables.entry(key) {
-                scc::hash_index::Entry::Occupied(ent) => Err(RayexecError::new(format!(
-                    "Duplicate physical table for entry: {:?}",
-                    ent.key(),
-                ))),
-                scc::hash_index::Entry::Vacant(hash_ent) => {
-                    let table = MemoryDataTable::default();
-                    hash_ent.insert_entry(table.clone());
-                    Ok(Box::new(table) as _)
-                }
+        Box::pin(async move {
+            let data = TableData::default();
+            if !self.tables.insert(&tx, key.clone(), data.clone())? {
+                return Err(RayexecError::new(format!(
+                    "Duplicate physical table for entry: {key:?}",
+                )));
             }
+            Ok(Box::new(MemoryDataTable { data, tx }) as _)
         })
     }
 
-    fn drop_physical_table(&self, schema: &str, ent: &CatalogEntry) -> BoxFuture<'_, Result<()>> {
+    fn drop_physical_table(
+        &self,
+        tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> BoxFuture<'_, Result<()>> {
         let key = TableKey {
             schema: schema.to_string(),
             name: ent.name.clone(),
         };
+        let tx = tx.clone();
 
         Box::pin(async move {
-            if !self.tables.remove(&key) {
+            if !self.tables.remove(&tx, &key)? {
                 return Err(RayexecError::new(format!(
                     "Missing physical memory table for entry: {key:?}. Cannot drop table.",
                 )));
@@ -82,27 +131,42 @@ impl TableStorage for MemoryTableStorage {
     }
 }
 
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct MemoryDataTable {
-    data: Arc<Mutex<Vec<Batch>>>,
+    data: TableData,
+    /// Transaction used for reading and writing batches.
+    tx: CatalogTx,
 }
 
-impl DataTable for MemoryDataTable {
-    fn scan(
+impl MemoryDataTable {
+    /// Get the batch chains in the table along with the index of the chain and
+    /// the batch in each chain visible to this table's transaction.
+    fn visible_batches(&self) -> Vec<(usize, Arc<VersionChain<Batch>>, Batch)> {
+        visible_batches(&self.tx, &self.data)
+    }
+
+    fn scan_inner(
         &self,
         projections: Projections,
         num_partitions: usize,
+        row_ids: bool,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let mut scans: Vec<_> = (0..num_partitions)
             .map(|_| MemoryDataTableScan { data: Vec::new() })
             .collect();
 
-        let data = {
-            let data = self.data.lock();
-            data.clone()
-        };
+        for (idx, (chain_idx, _, batch)) in self.visible_batches().into_iter().enumerate() {
+            let batch = if row_ids {
+                let row_id_array =
+                    Array::from_iter((0..batch.num_rows()).map(|row| row_id(chain_idx, row)));
+
+                let mut arrays = batch.into_arrays();
+                arrays.push(row_id_array);
+                Batch::try_from_arrays(arrays)?
+            } else {
+                batch
+            };
 
-        for (idx, batch) in data.into_iter().enumerate() {
             scans[idx % num_partitions].data.push(batch);
         }
 
@@ -111,20 +175,92 @@ impl DataTable for MemoryDataTable {
             .map(|scan| Box::new(ProjectedScan::new(scan, projections.clone())) as Box<_>)
             .collect())
     }
+}
+
+impl DataTable for MemoryDataTable {
+    fn scan(
+        &self,
+        projections: Projections,
+        _filters: &[ScanFilter],
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        self.scan_inner(projections, num_partitions, false)
+    }
+
+    fn statistics(&self) -> Result<TableStatistics> {
+        let batches: Vec<_> = self
+            .visible_batches()
+            .into_iter()
+            .map(|(_, _, batch)| batch)
+            .collect();
+        collect_statistics(&batches)
+    }
+
+    fn scan_with_row_ids(
+        &self,
+        projections: Projections,
+        num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        self.scan_inner(projections, num_partitions, true)
+    }
 
     fn insert(&self, input_partitions: usize) -> Result<Vec<Box<dyn PartitionSink>>> {
+        let pending = Arc::new(Mutex::new(PendingModification {
+            partitions_remaining: input_partitions,
+            batches: Vec::new(),
+        }));
+
         let inserts: Vec<_> = (0..input_partitions)
             .map(|_| {
                 Box::new(MemoryDataTableInsert {
                     resizer: BatchResizer::new(DEFAULT_TARGET_BATCH_SIZE),
                     collected: Vec::new(),
-                    data: self.data.clone(),
+                    pending: pending.clone(),
+                    table: self.clone(),
                 }) as _
             })
             .collect();
 
         Ok(inserts)
     }
+
+    fn update(&self, input_partitions: usize) -> Result<Vec<Box<dyn DataTableUpdate>>> {
+        let pending = Arc::new(Mutex::new(PendingModification {
+            partitions_remaining: input_partitions,
+            batches: Vec::new(),
+        }));
+
+        let updates: Vec<_> = (0..input_partitions)
+            .map(|_| {
+                Box::new(MemoryDataTableUpdate {
+                    collected: Vec::new(),
+                    pending: pending.clone(),
+                    table: self.clone(),
+                }) as _
+            })
+            .collect();
+
+        Ok(updates)
+    }
+
+    fn delete(&self, input_partitions: usize) -> Result<Vec<Box<dyn DataTableDelete>>> {
+        let pending = Arc::new(Mutex::new(PendingModification {
+            partitions_remaining: input_partitions,
+            batches: Vec::new(),
+        }));
+
+        let deletes: Vec<_> = (0..input_partitions)
+            .map(|_| {
+                Box::new(MemoryDataTableDelete {
+                    collected: Vec::new(),
+                    pending: pending.clone(),
+                    table: self.clone(),
+                }) as _
+            })
+            .collect();
+
+        Ok(deletes)
+    }
 }
 
 #[derive(Debug)]
@@ -142,7 +278,8 @@ impl DataTableScan for MemoryDataTableScan {
 pub struct MemoryDataTableInsert {
     resizer: BatchResizer, // TODO: Need to replace.
     collected: Vec<ComputedBatches>,
-    data: Arc<Mutex<Vec<Batch>>>,
+    pending: Arc<Mutex<PendingModification>>,
+    table: MemoryDataTable,
 }
 
 impl PartitionSink for MemoryDataTableInsert {
@@ -162,14 +299,348 @@ impl PartitionSink for MemoryDataTableInsert {
             let batches = self.resizer.flush_remaining()?;
             self.collected.push(batches);
 
-            let mut data = self.data.lock();
+            let mut collected = Vec::new();
             for mut computed in self.collected.drain(..) {
                 while let Some(batch) = computed.try_pop_front()? {
-                    data.push(batch);
+                    collected.push(batch);
                 }
             }
 
+            let batches = match self.pending.lock().finish_partition(&mut collected) {
+                Some(batches) => batches,
+                None => return Ok(()),
+            };
+
+            let mut data = self.table.data.lock();
+            for batch in batches {
+                data.push(VersionChain::new_with_item(&self.table.tx, batch)?);
+            }
+
             Ok(())
         })
     }
 }
+
+/// Batches for an insert, update, or delete collected across all partitions.
+///
+/// Modifications are only applied to the table once the last partition
+/// finalizes so that the statement's changes are added to the table together,
+/// and so that row ids produced by the scan remain stable for the duration of
+/// the query.
+#[derive(Debug)]
+struct PendingModification {
+    partitions_remaining: usize,
+    batches: Vec<Batch>,
+}
+
+impl PendingModification {
+    /// Add batches from a finalized partition, returning all collected batches
+    /// if this was the last partition.
+    fn finish_partition(&mut self, batches: &mut Vec<Batch>) -> Option<Vec<Batch>> {
+        self.batches.append(batches);
+        self.partitions_remaining -= 1;
+
+        if self.partitions_remaining == 0 {
+            Some(std::mem::take(&mut self.batches))
+        } else {
+            None
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct MemoryDataTableUpdate {
+    collected: Vec<Batch>,
+    pending: Arc<Mutex<PendingModification>>,
+    table: MemoryDataTable,
+}
+
+impl DataTableUpdate for MemoryDataTableUpdate {}
+
+impl PartitionSink for MemoryDataTableUpdate {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async {
+            self.collected.push(batch);
+            Ok(())
+        })
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async {
+            let batches = match self.pending.lock().finish_partition(&mut self.collected) {
+                Some(batches) => batches,
+                None => return Ok(()),
+            };
+
+            // Map row ids to the location of their new values, offset by one
+            // since the table's own batch is the first input when
+            // interleaving. A row may be matched more than once when updating
+            // with a FROM clause. Only the first match is applied.
+            let mut updates: HashMap<u64, (usize, usize)> = HashMap::new();
+            for (batch_idx, batch) in batches.iter().enumerate() {
+                let row_ids = batch
+                    .array(0)
+                    .ok_or_else(|| RayexecError::new("Missing row id column for update"))?;
+
+                UnaryExecutor::for_each2::<PhysicalU64, _>(row_ids, |idx, row_id| {
+                    if let Some(row_id) = row_id {
+                        updates.entry(row_id).or_insert((batch_idx + 1, idx));
+                    }
+                })?;
+            }
+
+            if updates.is_empty() {
+                return Ok(());
+            }
+
+            // Each batch is replaced with a new version in the same chain, with
+            // rows in the same position, so that row ids remain stable for any
+            // other modifications happening as part of the same query.
+            for (chain_idx, chain, table_batch) in self.table.visible_batches() {
+                let num_rows = table_batch.num_rows();
+                let mut has_updates = false;
+                let mut indices = Vec::with_capacity(num_rows);
+                for idx in 0..num_rows {
+                    match updates.get(&row_id(chain_idx, idx)) {
+                        Some(&location) => {
+                            has_updates = true;
+                            indices.push(location);
+                        }
+                        None => indices.push((0, idx)),
+                    }
+                }
+
+                if !has_updates {
+                    continue;
+                }
+
+                let arrays =
+                    (0..table_batch.num_arrays())
+                        .map(|col_idx| {
+                            let mut inputs = Vec::with_capacity(batches.len() + 1);
+                            inputs.push(table_batch.array(col_idx).expect("column to exist"));
+                            for batch in &batches {
+                                inputs.push(batch.array(col_idx + 1).ok_or_else(|| {
+                                    RayexecError::new("Missing column for update")
+                                })?);
+                            }
+                            interleave(&inputs, &indices)
+                        })
+                        .collect::<Result<Vec<_>>>()?;
+
+                chain.replace(&self.table.tx, Batch::try_from_arrays(arrays)?)?;
+            }
+
+            Ok(())
+        })
+    }
+}
+
+#[derive(Debug)]
+pub struct MemoryDataTableDelete {
+    collected: Vec<Batch>,
+    pending: Arc<Mutex<PendingModification>>,
+    table: MemoryDataTable,
+}
+
+impl DataTableDelete for MemoryDataTableDelete {}
+
+impl PartitionSink for MemoryDataTableDelete {
+    fn push(&mut self, batch: Batch) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async {
+            self.collected.push(batch);
+            Ok(())
+        })
+    }
+
+    fn finalize(&mut self) -> BoxFuture<'_, Result<()>> {
+        Box::pin(async {
+            let batches = match self.pending.lock().finish_partition(&mut self.collected) {
+                Some(batches) => batches,
+                None => return Ok(()),
+            };
+
+            let mut deleted = HashSet::new();
+            for batch in batches {
+                let row_ids = batch
+                    .array(0)
+                    .ok_or_else(|| RayexecError::new("Missing row id column for delete"))?;
+
+                UnaryExecutor::for_each2::<PhysicalU64, _>(row_ids, |_, row_id| {
+                    if let Some(row_id) = row_id {
+                        deleted.insert(row_id);
+                    }
+                })?;
+            }
+
+            remove_rows(&self.table, &deleted)
+        })
+    }
+}
+
+/// Get the batch chains in the table along with the index of the chain and the
+/// batch in each chain visible to the transaction.
+fn visible_batches(
+    tx: &CatalogTx,
+    data: &TableData,
+) -> Vec<(usize, Arc<VersionChain<Batch>>, Batch)> {
+    let chains = data.lock().clone();
+    chains
+        .into_iter()
+        .enumerate()
+        .filter_map(|(chain_idx, chain)| {
+            let batch = chain.get(tx)?;
+            Some((chain_idx, chain, batch))
+        })
+        .collect()
+}
+
+/// Max number of rows we'll look at when computing column statistics for a
+/// table.
+///
+/// Row counts are always exact. If the table has more rows than this, the
+/// column statistics are estimated from an evenly spaced sample.
+const STATISTICS_SAMPLE_SIZE: usize = 16 * 1024;
+
+/// Compute statistics for a set of batches.
+fn collect_statistics(batches: &[Batch]) -> Result<TableStatistics> {
+    let num_rows: usize = batches.iter().map(|b| b.num_rows()).sum();
+    let num_columns = match batches.first() {
+        Some(batch) => batch.arrays().len(),
+        None => return Ok(TableStatistics::with_num_rows(StatisticsValue::Exact(0))),
+    };
+
+    let stride = num_rows.div_ceil(STATISTICS_SAMPLE_SIZE).max(1);
+    let sampled = stride > 1;
+
+    let mut columns = Vec::with_capacity(num_columns);
+    for col_idx in 0..num_columns {
+        let mut sample_rows = 0;
+        let mut null_count = 0;
+        let mut min: Option<OwnedScalarValue> = None;
+        let mut max: Option<OwnedScalarValue> = None;
+        let mut counts: HashMap<OwnedScalarValue, usize> = HashMap::new();
+
+        let mut row_offset = 0;
+        for batch in batches {
+            let array = &batch.arrays()[col_idx];
+
+            // First row in this batch that lands on the stride.
+            let mut idx = (stride - row_offset % stride) % stride;
+            while idx < batch.num_rows() {
+                sample_rows += 1;
+                let val = array.logical_value(idx)?.into_owned();
+                idx += stride;
+
+                if val == ScalarValue::Null {
+                    null_count += 1;
+                    continue;
+                }
+
+                if min
+                    .as_ref()
+                    .is_none_or(|min| compare_scalars(&val, min) == Some(Ordering::Less))
+                {
+                    min = Some(val.clone());
+                }
+                if max
+                    .as_ref()
+                    .is_none_or(|max| compare_scalars(&val, max) == Some(Ordering::Greater))
+                {
+                    max = Some(val.clone());
+                }
+
+                *counts.entry(val).or_insert(0) += 1;
+            }
+
+            row_offset += batch.num_rows();
+        }
+
+        fn stat<T>(v: T, sampled: bool) -> StatisticsValue<T> {
+            if sampled {
+                StatisticsValue::Estimated(v)
+            } else {
+                StatisticsValue::Exact(v)
+            }
+        }
+
+        let num_distinct = if sampled {
+            StatisticsValue::Estimated(estimate_distinct(&counts, sample_rows, num_rows))
+        } else {
+            StatisticsValue::Exact(counts.len())
+        };
+
+        let null_count = if sampled && sample_rows > 0 {
+            (null_count as f64 * num_rows as f64 / sample_rows as f64) as usize
+        } else {
+            null_count
+        };
+
+        columns.push(ColumnStatistics {
+            num_distinct,
+            null_count: stat(null_count, sampled),
+            // Sampling may miss the true min and max.
+            min: min.map(|v| stat(v, sampled)).unwrap_or_default(),
+            max: max.map(|v| stat(v, sampled)).unwrap_or_default(),
+        });
+    }
+
+    Ok(TableStatistics {
+        num_rows: StatisticsValue::Exact(num_rows),
+        columns,
+    })
+}
+
+/// Estimate the number of distinct values in a column from a sample using the
+/// Duj1 estimator.
+///
+/// See "Sampling-Based Estimation of the Number of Distinct Values of an
+/// Attribute" (Haas et al).
+fn estimate_distinct(
+    counts: &HashMap<OwnedScalarValue, usize>,
+    sample_rows: usize,
+    num_rows: usize,
+) -> usize {
+    let d = counts.len() as f64;
+    let n = sample_rows as f64;
+    let total = num_rows as f64;
+    // Number of values seen exactly once.
+    let f1 = counts.values().filter(|&&c| c == 1).count() as f64;
+
+    let denom = n - f1 + f1 * n / total;
+    if denom <= 0.0 {
+        return num_rows;
+    }
+
+    ((n * d / denom) as usize).clamp(counts.len(), num_rows)
+}
+
+/// Remove rows from the table by row id.
+#[allow(deprecated)]
+fn remove_rows(table: &MemoryDataTable, row_ids: &HashSet<u64>) -> Result<()> {
+    if row_ids.is_empty() {
+        return Ok(());
+    }
+
+    for (chain_idx, chain, batch) in table.visible_batches() {
+        let num_rows = batch.num_rows();
+        let keep: Vec<_> = (0..num_rows)
+            .filter(|&idx| !row_ids.contains(&row_id(chain_idx, idx)))
+            .collect();
+
+        if keep.len() == num_rows {
+            continue;
+        }
+
+        if keep.is_empty() {
+            chain.delete(&table.tx)?;
+        } else {
+            chain.replace(
+                &table.tx,
+                batch.select_old(Arc::new(SelectionVector::from_iter(keep))),
+            )?;
+        }
+    }
+
+    Ok(())
+}
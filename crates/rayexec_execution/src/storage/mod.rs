@@ -1,3 +1,5 @@
 pub mod catalog_storage;
+pub mod hive;
 pub mod memory;
+pub mod multi_file;
 pub mod table_storage;
@@ -0,0 +1,753 @@
+//! Helpers for data tables that read from multiple files.
+//!
+//! Each file may have a slightly different schema. `MultiFileSchema` unifies
+//! the file schemas into a single output schema, and `FileProjection` adapts
+//! batches read from a single file to that output schema.
+
+use std::collections::HashMap;
+
+use rayexec_error::{RayexecError, Result};
+use rayexec_io::location::FileLocation;
+
+use super::hive::HivePartitions;
+use super::table_storage::Projections;
+use crate::arrays::array::Array;
+use crate::arrays::batch::Batch;
+use crate::arrays::datatype::DataType;
+use crate::arrays::field::{Field, Schema};
+use crate::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use crate::logical::scan_filter::ScanFilter;
+use crate::logical::statistics::{ColumnStatistics, StatisticsValue, TableStatistics};
+
+/// Name of the virtual column containing the file a row was read from.
+pub const FILENAME_COLUMN: &str = "filename";
+
+/// Options for combining multiple files into a single table.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct MultiFileOptions {
+    /// Match columns across files by name, taking the union of all columns.
+    ///
+    /// Columns missing from a file are read as NULL. When false, every file
+    /// must have the same set of columns as the first file.
+    pub union_by_name: bool,
+    /// Include a virtual column containing the file each row was read from.
+    pub filename: bool,
+    /// Include virtual columns for `key=value` directories in file paths.
+    ///
+    /// If None, partitions are detected from the path of the first file.
+    pub hive_partitioning: Option<bool>,
+}
+
+impl MultiFileOptions {
+    /// Get options from the named arguments of a table function.
+    pub fn try_from_named_args(named: &HashMap<String, OwnedScalarValue>) -> Result<Self> {
+        let get_bool = |name: &str| named.get(name).map(|val| val.try_as_bool()).transpose();
+
+        Ok(MultiFileOptions {
+            union_by_name: get_bool("union_by_name")?.unwrap_or(false),
+            filename: get_bool(FILENAME_COLUMN)?.unwrap_or(false),
+            hive_partitioning: get_bool("hive_partitioning")?,
+        })
+    }
+
+    /// Get the number of files, starting from the first, that need to have
+    /// their schemas read during planning.
+    ///
+    /// Files in a hive partitioned table are assumed to share the first file's
+    /// schema unless `union_by_name` is set, so only the first file needs to be
+    /// opened. Remaining files are checked against the table schema once
+    /// they're scanned, which allows files to be pruned without being opened.
+    pub fn num_files_to_infer(&self, locations: &[FileLocation]) -> Result<usize> {
+        if self.union_by_name {
+            return Ok(locations.len());
+        }
+
+        let partitions = HivePartitions::try_from_locations(locations, self.hive_partitioning)?;
+        if partitions.is_some() {
+            Ok(usize::min(1, locations.len()))
+        } else {
+            Ok(locations.len())
+        }
+    }
+}
+
+/// Unified schema for a set of files.
+///
+/// The output schema contains all columns read from files, followed by hive
+/// partition columns, followed by the filename column if requested.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MultiFileSchema {
+    /// Output schema, including any virtual columns.
+    pub schema: Schema,
+    /// Locations of all files.
+    locations: Vec<FileLocation>,
+    /// Number of leading columns in the output schema read from files.
+    num_file_columns: usize,
+    /// If columns missing from a file should be read as NULL.
+    union_by_name: bool,
+    /// Partitions parsed from the file locations.
+    partitions: Option<HivePartitions>,
+    /// If the last column is the filename column.
+    filename: bool,
+}
+
+impl MultiFileSchema {
+    /// Unify schemas for files.
+    ///
+    /// `schemas` contains schemas for some number of files starting from the
+    /// first file, see `MultiFileOptions::num_files_to_infer`.
+    ///
+    /// Columns are matched by name. Columns with the same name must have the
+    /// same type in every file.
+    pub fn try_unify(
+        locations: Vec<FileLocation>,
+        schemas: &[Schema],
+        options: MultiFileOptions,
+    ) -> Result<Self> {
+        if schemas.len() > locations.len() {
+            return Err(RayexecError::new("Expected at most one schema per file")
+                .with_field("locations", locations.len())
+                .with_field("schemas", schemas.len()));
+        }
+        let first = schemas
+            .first()
+            .ok_or_else(|| RayexecError::new("Cannot create a table from zero files"))?;
+
+        // Output fields along with the file index that first added the field.
+        let mut fields: Vec<(Field, usize)> = first.iter().map(|f| (f.clone(), 0)).collect();
+
+        for (file_idx, schema) in schemas.iter().enumerate().skip(1) {
+            if !options.union_by_name {
+                let same_names = schema.fields.len() == fields.len()
+                    && schema
+                        .iter()
+                        .all(|f| fields.iter().any(|(field, _)| field.name == f.name));
+                if !same_names {
+                    return Err(mismatched_columns_error(
+                        &locations[file_idx],
+                        &locations[0],
+                    ));
+                }
+            }
+
+            for field in schema.iter() {
+                match fields.iter_mut().find(|(f, _)| f.name == field.name) {
+                    Some((existing, existing_idx)) => {
+                        if existing.datatype != field.datatype {
+                            return Err(RayexecError::new(format!(
+                                "Column '{}' has type {} in '{}', but type {} in '{}'",
+                                field.name,
+                                existing.datatype,
+                                locations[*existing_idx],
+                                field.datatype,
+                                locations[file_idx],
+                            )));
+                        }
+                        existing.nullable |= field.nullable;
+                    }
+                    None => {
+                        // Only reachable with union by name. Missing values
+                        // in other files are NULL.
+                        let mut field = field.clone();
+                        field.nullable = true;
+                        fields.push((field, file_idx));
+                    }
+                }
+            }
+        }
+
+        let mut fields: Vec<_> = fields.into_iter().map(|(f, _)| f).collect();
+
+        if options.union_by_name {
+            // Columns missing from any file are nullable.
+            for field in fields.iter_mut() {
+                if schemas
+                    .iter()
+                    .any(|s| !s.iter().any(|f| f.name == field.name))
+                {
+                    field.nullable = true;
+                }
+            }
+        }
+
+        let num_file_columns = fields.len();
+
+        let partitions = HivePartitions::try_from_locations(&locations, options.hive_partitioning)?;
+        if let Some(partitions) = &partitions {
+            for partition in &partitions.fields {
+                if fields.iter().any(|f| f.name == partition.name) {
+                    return Err(RayexecError::new(format!(
+                        "Hive partition column '{}' conflicts with a column in '{}', set 'hive_partitioning' to false to ignore partitions",
+                        partition.name, locations[0],
+                    )));
+                }
+                fields.push(partition.clone());
+            }
+        }
+
+        if options.filename {
+            if fields.iter().any(|f| f.name == FILENAME_COLUMN) {
+                return Err(RayexecError::new(format!(
+                    "Cannot add '{FILENAME_COLUMN}' column, file already contains a column with that name"
+                )));
+            }
+            fields.push(Field::new(FILENAME_COLUMN, DataType::Utf8, false));
+        }
+
+        Ok(MultiFileSchema {
+            schema: Schema::new(fields),
+            locations,
+            num_file_columns,
+            union_by_name: options.union_by_name,
+            partitions,
+            filename: options.filename,
+        })
+    }
+
+    /// Locations of all files, in the order they were provided.
+    pub fn locations(&self) -> &[FileLocation] {
+        &self.locations
+    }
+
+    /// Get the indices of files that may contain rows passing the filters.
+    ///
+    /// Files are pruned using the values of the partition and filename
+    /// columns, which are constant for each file.
+    pub fn files_matching(&self, filters: &[ScanFilter]) -> Vec<usize> {
+        (0..self.locations.len())
+            .filter(|&file_idx| {
+                filters.iter().all(
+                    |filter| match self.constant_value(file_idx, filter.column) {
+                        Some(value) => filter.could_match_value(&value),
+                        None => true,
+                    },
+                )
+            })
+            .collect()
+    }
+
+    /// Map filters on the output schema to filters on a file's columns.
+    ///
+    /// Filters on virtual columns, or on columns missing from the file, are
+    /// dropped. Pruning using the returned filters should happen after
+    /// `files_matching`.
+    pub fn file_filters(&self, file_schema: &Schema, filters: &[ScanFilter]) -> Vec<ScanFilter> {
+        filters
+            .iter()
+            .filter_map(|filter| {
+                let field = self.schema.fields[..self.num_file_columns].get(filter.column)?;
+                let column = file_schema
+                    .iter()
+                    .position(|f| f.name == field.name && f.datatype == field.datatype)?;
+
+                Some(ScanFilter {
+                    column,
+                    filter: filter.filter.clone(),
+                })
+            })
+            .collect()
+    }
+
+    /// Get the value of a virtual column for a file.
+    ///
+    /// Returns None for columns read from the file.
+    fn constant_value(&self, file_idx: usize, column: usize) -> Option<OwnedScalarValue> {
+        if column < self.num_file_columns {
+            return None;
+        }
+
+        let partition_idx = column - self.num_file_columns;
+        match &self.partitions {
+            Some(partitions) if partition_idx < partitions.fields.len() => {
+                Some(partitions.file_values(file_idx)[partition_idx].clone())
+            }
+            _ => {
+                if self.filename && column == self.schema.fields.len() - 1 {
+                    Some(self.locations[file_idx].to_string().into())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+
+    /// Create the projection for reading a single file.
+    ///
+    /// `file_schema` is the schema of the file itself, and `projections`
+    /// references columns in the output schema. Errors if the file's columns
+    /// aren't compatible with the output schema.
+    pub fn file_projection(
+        &self,
+        file_idx: usize,
+        file_schema: &Schema,
+        projections: &Projections,
+    ) -> Result<FileProjection> {
+        let location = &self.locations[file_idx];
+
+        if !self.union_by_name && file_schema.fields.len() != self.num_file_columns {
+            return Err(mismatched_columns_error(location, &self.locations[0]));
+        }
+
+        let sources = self.schema.fields[..self.num_file_columns]
+            .iter()
+            .map(
+                |field| match file_schema.iter().position(|f| f.name == field.name) {
+                    Some(idx) => {
+                        let datatype = &file_schema.fields[idx].datatype;
+                        if *datatype != field.datatype {
+                            return Err(RayexecError::new(format!(
+                                "Column '{}' has type {} in '{location}', but type {} in the table",
+                                field.name, datatype, field.datatype,
+                            )));
+                        }
+                        Ok(Some(idx))
+                    }
+                    None if self.union_by_name => Ok(None),
+                    None => Err(mismatched_columns_error(location, &self.locations[0])),
+                },
+            )
+            .collect::<Result<Vec<_>>>()?;
+
+        let output_columns = match &projections.column_indices {
+            Some(indices) => indices.clone(),
+            None => (0..self.schema.fields.len()).collect(),
+        };
+
+        let mut file_columns: Vec<usize> = output_columns
+            .iter()
+            .filter_map(|&idx| sources.get(idx).copied().flatten())
+            .collect();
+        file_columns.sort_unstable();
+        file_columns.dedup();
+
+        // Always read at least one column from the file so we know how many
+        // rows to produce for NULL and virtual columns.
+        if file_columns.is_empty() && !file_schema.fields.is_empty() {
+            file_columns.push(0);
+        }
+
+        let outputs = output_columns
+            .iter()
+            .map(|&idx| {
+                let datatype = &self.schema.fields[idx].datatype;
+                match sources.get(idx) {
+                    Some(Some(file_col)) => OutputColumn::File(
+                        file_columns
+                            .iter()
+                            .position(|col| col == file_col)
+                            .expect("file column to be projected"),
+                    ),
+                    Some(None) => OutputColumn::Null(datatype.clone()),
+                    None => match self.constant_value(file_idx, idx) {
+                        Some(ScalarValue::Null) | None => OutputColumn::Null(datatype.clone()),
+                        Some(value) => OutputColumn::Constant(value),
+                    },
+                }
+            })
+            .collect();
+
+        Ok(FileProjection {
+            file_columns,
+            outputs,
+        })
+    }
+
+    /// Combine per-file statistics into statistics for the output schema.
+    ///
+    /// `file_stats` should contain the schema and statistics for some number of
+    /// files starting from the first file. If statistics aren't available for
+    /// every file, statistics for the remaining files are extrapolated.
+    pub fn table_statistics<'a>(
+        &self,
+        file_stats: impl IntoIterator<Item = (&'a Schema, TableStatistics)>,
+    ) -> TableStatistics {
+        let mut num_known = 0;
+
+        let stats = file_stats
+            .into_iter()
+            .map(|(file_schema, stats)| {
+                num_known += 1;
+
+                let columns = self.schema.fields[..self.num_file_columns]
+                    .iter()
+                    .map(
+                        |field| match file_schema.iter().position(|f| f.name == field.name) {
+                            Some(idx) => stats
+                                .column(idx)
+                                .cloned()
+                                .unwrap_or_else(ColumnStatistics::unknown),
+                            None => ColumnStatistics {
+                                num_distinct: StatisticsValue::Exact(0),
+                                null_count: stats.num_rows,
+                                min: StatisticsValue::Unknown,
+                                max: StatisticsValue::Unknown,
+                            },
+                        },
+                    )
+                    .collect();
+
+                TableStatistics {
+                    num_rows: stats.num_rows,
+                    columns,
+                }
+            })
+            .reduce(TableStatistics::merge);
+
+        let mut stats = stats.unwrap_or_default();
+
+        let num_files = self.locations.len();
+        if num_known < num_files {
+            // Assume files we haven't seen look like the ones we have.
+            let scale = |n: usize| n * num_files / usize::max(num_known, 1);
+            stats.num_rows = stats.num_rows.map(scale).into_estimated();
+            for col in &mut stats.columns {
+                col.num_distinct = col.num_distinct.into_estimated();
+                col.null_count = col.null_count.map(scale).into_estimated();
+                col.min = col.min.clone().into_estimated();
+                col.max = col.max.clone().into_estimated();
+            }
+        }
+
+        if stats.columns.len() != self.num_file_columns {
+            stats.columns = vec![ColumnStatistics::unknown(); self.num_file_columns];
+        }
+
+        if let Some(partitions) = &self.partitions {
+            for idx in 0..partitions.fields.len() {
+                stats.columns.push(partitions.column_statistics(idx));
+            }
+        }
+
+        if self.filename {
+            let names: Vec<_> = self.locations.iter().map(|loc| loc.to_string()).collect();
+            let min = names.iter().min().cloned();
+            let max = names.iter().max().cloned();
+            stats.columns.push(ColumnStatistics {
+                num_distinct: StatisticsValue::Exact(num_files),
+                null_count: StatisticsValue::Exact(0),
+                min: min.map_or(StatisticsValue::Unknown, |v| {
+                    StatisticsValue::Exact(v.into())
+                }),
+                max: max.map_or(StatisticsValue::Unknown, |v| {
+                    StatisticsValue::Exact(v.into())
+                }),
+            });
+        }
+
+        stats
+    }
+}
+
+fn mismatched_columns_error(location: &FileLocation, first: &FileLocation) -> RayexecError {
+    RayexecError::new(format!(
+        "Columns in '{location}' don't match columns in '{first}', set 'union_by_name' to true to combine files with different columns",
+    ))
+}
+
+/// Output column for a batch read from a single file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum OutputColumn {
+    /// Column at this index in the batch read from the file.
+    File(usize),
+    /// All NULL column with the given type.
+    Null(DataType),
+    /// Column with the same value for every row, e.g. a partition value.
+    Constant(OwnedScalarValue),
+}
+
+/// Projection for reading a single file of a multi-file table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FileProjection {
+    /// Columns to read from the file, in ascending order.
+    pub file_columns: Vec<usize>,
+    /// Output columns.
+    outputs: Vec<OutputColumn>,
+}
+
+impl FileProjection {
+    /// Projections to use when reading from the file.
+    pub fn file_projections(&self) -> Projections {
+        Projections {
+            column_indices: Some(self.file_columns.clone()),
+        }
+    }
+
+    /// Adapt a batch containing only the projected file columns to the output
+    /// columns.
+    pub fn project_batch(&self, batch: Batch) -> Result<Batch> {
+        let num_rows = batch.num_rows();
+        if self.outputs.is_empty() {
+            return Ok(Batch::empty_with_num_rows(num_rows));
+        }
+
+        let arrays = batch.into_arrays();
+        let outputs = self
+            .outputs
+            .iter()
+            .map(|output| match output {
+                OutputColumn::File(idx) => arrays.get(*idx).cloned().ok_or_else(|| {
+                    RayexecError::new(format!("Missing file column at index {idx}"))
+                }),
+                OutputColumn::Null(datatype) => {
+                    Array::new_typed_null_array(datatype.clone(), num_rows)
+                }
+                OutputColumn::Constant(value) => value.as_array(num_rows),
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Batch::try_from_arrays(outputs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::expr::comparison_expr::ComparisonOperator;
+    use crate::logical::scan_filter::ScanFilterType;
+
+    fn schema(fields: &[(&str, DataType)]) -> Schema {
+        Schema::new(
+            fields
+                .iter()
+                .map(|(name, datatype)| Field::new(*name, datatype.clone(), true)),
+        )
+    }
+
+    fn locations(n: usize) -> Vec<FileLocation> {
+        (0..n)
+            .map(|idx| FileLocation::parse(&format!("file{idx}.parquet")))
+            .collect()
+    }
+
+    #[test]
+    fn unify_same_columns_different_order() {
+        let schemas = [
+            schema(&[("a", DataType::Int32), ("b", DataType::Utf8)]),
+            schema(&[("b", DataType::Utf8), ("a", DataType::Int32)]),
+        ];
+        let unified =
+            MultiFileSchema::try_unify(locations(2), &schemas, MultiFileOptions::default())
+                .unwrap();
+
+        assert_eq!(schemas[0], unified.schema);
+
+        let projection = unified
+            .file_projection(1, &schemas[1], &Projections::all())
+            .unwrap();
+        assert_eq!(vec![0, 1], projection.file_columns);
+        assert_eq!(
+            vec![OutputColumn::File(1), OutputColumn::File(0)],
+            projection.outputs
+        );
+    }
+
+    #[test]
+    fn unify_different_columns_requires_union_by_name() {
+        let schemas = [
+            schema(&[("a", DataType::Int32)]),
+            schema(&[("a", DataType::Int32), ("b", DataType::Utf8)]),
+        ];
+        MultiFileSchema::try_unify(locations(2), &schemas, MultiFileOptions::default())
+            .unwrap_err();
+
+        let unified = MultiFileSchema::try_unify(
+            locations(2),
+            &schemas,
+            MultiFileOptions {
+                union_by_name: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(schemas[1], unified.schema);
+
+        let projection = unified
+            .file_projection(0, &schemas[0], &Projections::all())
+            .unwrap();
+        assert_eq!(vec![0], projection.file_columns);
+        assert_eq!(
+            vec![OutputColumn::File(0), OutputColumn::Null(DataType::Utf8)],
+            projection.outputs
+        );
+    }
+
+    #[test]
+    fn unify_mismatched_types() {
+        let schemas = [
+            schema(&[("a", DataType::Int32)]),
+            schema(&[("a", DataType::Int64)]),
+        ];
+        MultiFileSchema::try_unify(locations(2), &schemas, MultiFileOptions::default())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn filename_only_projection_reads_one_column() {
+        let schemas = [schema(&[("a", DataType::Int32), ("b", DataType::Utf8)])];
+        let unified = MultiFileSchema::try_unify(
+            locations(1),
+            &schemas,
+            MultiFileOptions {
+                filename: true,
+                ..Default::default()
+            },
+        )
+        .unwrap();
+        assert_eq!(3, unified.schema.fields.len());
+
+        let projection = unified
+            .file_projection(
+                0,
+                &schemas[0],
+                &Projections {
+                    column_indices: Some(vec![2]),
+                },
+            )
+            .unwrap();
+        assert_eq!(vec![0], projection.file_columns);
+
+        let batch = Batch::try_from_arrays([Array::from_iter([1, 2, 3])]).unwrap();
+        let out = projection.project_batch(batch).unwrap();
+        assert_eq!(3, out.num_rows());
+        assert_eq!(
+            ScalarValue::from("file0.parquet"),
+            out.array(0).unwrap().logical_value(2).unwrap()
+        );
+    }
+
+    fn hive_locations() -> Vec<FileLocation> {
+        [
+            "data/year=2023/month=12/part-0.parquet",
+            "data/year=2024/month=01/part-0.parquet",
+            "data/year=2024/month=02/part-0.parquet",
+        ]
+        .into_iter()
+        .map(FileLocation::parse)
+        .collect()
+    }
+
+    #[test]
+    fn hive_partition_columns() {
+        let locations = hive_locations();
+        let options = MultiFileOptions::default();
+        assert_eq!(1, options.num_files_to_infer(&locations).unwrap());
+
+        let schemas = [schema(&[("a", DataType::Utf8)])];
+        let unified = MultiFileSchema::try_unify(locations, &schemas, options).unwrap();
+        assert_eq!(
+            schema(&[
+                ("a", DataType::Utf8),
+                ("year", DataType::Int64),
+                ("month", DataType::Int64)
+            ]),
+            unified.schema
+        );
+
+        // File that wasn't used to infer the schema.
+        let projection = unified
+            .file_projection(2, &schemas[0], &Projections::all())
+            .unwrap();
+        assert_eq!(
+            vec![
+                OutputColumn::File(0),
+                OutputColumn::Constant(ScalarValue::Int64(2024)),
+                OutputColumn::Constant(ScalarValue::Int64(2)),
+            ],
+            projection.outputs
+        );
+
+        // Files not matching the table schema error when scanned.
+        unified
+            .file_projection(2, &schema(&[("b", DataType::Utf8)]), &Projections::all())
+            .unwrap_err();
+    }
+
+    #[test]
+    fn hive_partitioning_disabled() {
+        let locations = hive_locations();
+        let options = MultiFileOptions {
+            hive_partitioning: Some(false),
+            ..Default::default()
+        };
+        assert_eq!(3, options.num_files_to_infer(&locations).unwrap());
+
+        let schemas = vec![schema(&[("a", DataType::Utf8)]); 3];
+        let unified = MultiFileSchema::try_unify(locations, &schemas, options).unwrap();
+        assert_eq!(schemas[0], unified.schema);
+    }
+
+    #[test]
+    fn hive_prune_files() {
+        let schemas = [schema(&[("a", DataType::Utf8)])];
+        let unified =
+            MultiFileSchema::try_unify(hive_locations(), &schemas, MultiFileOptions::default())
+                .unwrap();
+
+        let filter = |column, op, constant| ScanFilter {
+            column,
+            filter: ScanFilterType::ConstComparison { op, constant },
+        };
+
+        let filters = [filter(1, ComparisonOperator::Eq, ScalarValue::Int64(2024))];
+        assert_eq!(vec![1, 2], unified.files_matching(&filters));
+
+        let filters = [
+            filter(1, ComparisonOperator::Eq, ScalarValue::Int64(2024)),
+            filter(2, ComparisonOperator::Lt, ScalarValue::Int64(2)),
+        ];
+        assert_eq!(vec![1], unified.files_matching(&filters));
+
+        // Filters on file columns don't prune.
+        let filters = [filter(0, ComparisonOperator::Eq, "x".into())];
+        assert_eq!(vec![0, 1, 2], unified.files_matching(&filters));
+    }
+
+    #[test]
+    fn file_filters_remapped() {
+        let schemas = [schema(&[("a", DataType::Utf8), ("b", DataType::Int32)])];
+        let unified =
+            MultiFileSchema::try_unify(hive_locations(), &schemas, MultiFileOptions::default())
+                .unwrap();
+
+        let filter = |column, constant| ScanFilter {
+            column,
+            filter: ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Eq,
+                constant,
+            },
+        };
+
+        // File has columns in a different order. Filters on partition columns
+        // are dropped.
+        let file_schema = schema(&[("b", DataType::Int32), ("a", DataType::Utf8)]);
+        let filters = [
+            filter(0, "x".into()),
+            filter(1, ScalarValue::Int32(4)),
+            filter(2, ScalarValue::Int64(2024)),
+        ];
+        assert_eq!(
+            vec![filter(1, "x".into()), filter(0, ScalarValue::Int32(4))],
+            unified.file_filters(&file_schema, &filters)
+        );
+    }
+
+    #[test]
+    fn hive_statistics_extrapolated() {
+        let schemas = [schema(&[("a", DataType::Utf8)])];
+        let unified =
+            MultiFileSchema::try_unify(hive_locations(), &schemas, MultiFileOptions::default())
+                .unwrap();
+
+        let stats = unified.table_statistics([(
+            &schemas[0],
+            TableStatistics::with_num_rows(StatisticsValue::Exact(10)),
+        )]);
+        assert_eq!(StatisticsValue::Estimated(30), stats.num_rows);
+        assert_eq!(3, stats.columns.len());
+        assert_eq!(StatisticsValue::Exact(2), stats.columns[1].num_distinct);
+        assert_eq!(
+            StatisticsValue::Exact(ScalarValue::Int64(2023)),
+            stats.columns[1].min
+        );
+    }
+}
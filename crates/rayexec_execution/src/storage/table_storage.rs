@@ -5,8 +5,12 @@ use rayexec_error::{RayexecError, Result};
 use rayexec_proto::ProtoConv;
 
 use crate::arrays::batch::Batch;
+use crate::database::catalog::CatalogTx;
 use crate::database::catalog_entry::CatalogEntry;
 use crate::execution::operators::sink::PartitionSink;
+use crate::explain::explainable::ExplainEntry;
+use crate::logical::scan_filter::ScanFilter;
+use crate::logical::statistics::TableStatistics;
 
 /// Scan projections.
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -58,15 +62,26 @@ impl ProtoConv for Projections {
 }
 
 pub trait TableStorage: Debug + Sync + Send {
-    fn data_table(&self, schema: &str, ent: &CatalogEntry) -> Result<Box<dyn DataTable>>;
+    fn data_table(
+        &self,
+        tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> Result<Box<dyn DataTable>>;
 
     fn create_physical_table(
         &self,
+        tx: &CatalogTx,
         schema: &str,
         ent: &CatalogEntry,
     ) -> BoxFuture<'_, Result<Box<dyn DataTable>>>;
 
-    fn drop_physical_table(&self, schema: &str, ent: &CatalogEntry) -> BoxFuture<'_, Result<()>>;
+    fn drop_physical_table(
+        &self,
+        tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> BoxFuture<'_, Result<()>>;
 }
 
 pub trait DataTable: Debug + Sync + Send {
@@ -76,12 +91,47 @@ pub trait DataTable: Debug + Sync + Send {
     /// partitions in the table output. However, the table may return a
     /// different number of partitions if it's unable to use the provided
     /// number.
+    ///
+    /// `filters` may be used to skip reading data that can't pass the
+    /// filters. Applying them is optional, the filters are always applied again
+    /// on the scan output.
     fn scan(
         &self,
         projections: Projections,
+        filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>>;
 
+    /// Return table scanners that produce a row id column in addition to the
+    /// table's columns.
+    ///
+    /// The row id column is a UInt64 column positioned directly after all
+    /// table columns, and projections may reference it by that position. Row
+    /// ids produced by this scan are what get passed to `update` and
+    /// `delete`.
+    fn scan_with_row_ids(
+        &self,
+        _projections: Projections,
+        _num_partitions: usize,
+    ) -> Result<Vec<Box<dyn DataTableScan>>> {
+        Err(RayexecError::new("Data table does not support row ids"))
+    }
+
+    /// Return statistics for the table, used during planning.
+    ///
+    /// Defaults to nothing being known about the table.
+    fn statistics(&self) -> Result<TableStatistics> {
+        Ok(TableStatistics::unknown())
+    }
+
+    /// Add details about scanning this table with the given filters to an
+    /// explain entry, e.g. how much of the table gets skipped.
+    ///
+    /// This may be called both before and after the table is scanned.
+    fn explain_scan(&self, ent: ExplainEntry, _filters: &[ScanFilter]) -> ExplainEntry {
+        ent
+    }
+
     fn insert(&self, _input_partitions: usize) -> Result<Vec<Box<dyn PartitionSink>>> {
         Err(RayexecError::new("Data table does not support inserts"))
     }
@@ -91,7 +141,7 @@ pub trait DataTable: Debug + Sync + Send {
     }
 
     fn delete(&self, _input_partitions: usize) -> Result<Vec<Box<dyn DataTableDelete>>> {
-        Err(RayexecError::new("Data table does not support updates"))
+        Err(RayexecError::new("Data table does not support deletes"))
     }
 }
 
@@ -151,6 +201,15 @@ impl DataTableScan for EmptyTableScan {
     }
 }
 
-pub trait DataTableUpdate: Debug + Sync + Send {}
+/// Sink for updating rows in a table.
+///
+/// Each pushed batch contains the row id as the first column, followed by the
+/// new values for every column in the table. Updates are only required to be
+/// visible once all partitions have been finalized.
+pub trait DataTableUpdate: PartitionSink {}
 
-pub trait DataTableDelete: Debug + Sync + Send {}
+/// Sink for deleting rows from a table.
+///
+/// Each pushed batch contains a single column of row ids to delete. Deletes
+/// are only required to be visible once all partitions have been finalized.
+pub trait DataTableDelete: PartitionSink {}
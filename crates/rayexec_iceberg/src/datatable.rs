@@ -3,6 +3,7 @@ use std::sync::Arc;
 use futures::future::BoxFuture;
 use rayexec_error::Result;
 use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
 
 use crate::table::{Table, TableScan};
@@ -16,6 +17,7 @@ impl DataTable for IcebergDataTable {
     fn scan(
         &self,
         projections: Projections,
+        _filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let scans = self.table.scan(projections, num_partitions)?;
@@ -17,7 +17,6 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
 use rayexec_execution::runtime::Runtime;
 
 use crate::datatable::IcebergDataTable;
@@ -75,9 +74,9 @@ impl<R: Runtime> ReadIceberg<R> {
             try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
         let provider = self.runtime.file_provider();
 
-        // TODO: Fetch stats, use during planning.
         let table = Table::load(location.clone(), provider, conf.clone()).await?;
         let schema = table.schema()?;
+        let statistics = table.statistics()?;
 
         Ok(PlannedTableFunction {
             function: Box::new(self),
@@ -86,7 +85,7 @@ impl<R: Runtime> ReadIceberg<R> {
             function_impl: TableFunctionImpl::Scan(Arc::new(IcebergDataTable {
                 table: Arc::new(table), // TODO: Arc Arc
             })),
-            cardinality: StatisticsValue::Unknown,
+            statistics,
             schema,
         })
     }
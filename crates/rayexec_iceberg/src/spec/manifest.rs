@@ -292,6 +292,30 @@ pub struct DataFile {
     pub sort_order_id: Option<i32>,
 }
 
+impl DataFile {
+    /// Get the null count for a field.
+    pub fn null_value_count(&self, field_id: i32) -> Option<i64> {
+        I64Entry::find(self.null_value_counts.as_deref()?, field_id)
+    }
+
+    /// Get the distinct count for a field.
+    pub fn distinct_count(&self, field_id: i32) -> Option<i64> {
+        I64Entry::find(self.distinct_counts.as_deref()?, field_id)
+    }
+
+    /// Get the serialized lower bound for a field.
+    ///
+    /// See <https://iceberg.apache.org/spec/#binary-single-value-serialization>
+    pub fn lower_bound(&self, field_id: i32) -> Option<&[u8]> {
+        BinaryEntry::find(self.lower_bounds.as_deref()?, field_id)
+    }
+
+    /// Get the serialized upper bound for a field.
+    pub fn upper_bound(&self, field_id: i32) -> Option<&[u8]> {
+        BinaryEntry::find(self.upper_bounds.as_deref()?, field_id)
+    }
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BinaryEntry {
     key: i32,
@@ -304,3 +328,21 @@ pub struct I64Entry {
     key: i32,
     value: i64,
 }
+
+impl BinaryEntry {
+    fn find(entries: &[BinaryEntry], key: i32) -> Option<&[u8]> {
+        entries
+            .iter()
+            .find(|ent| ent.key == key)
+            .map(|ent| ent.value.as_slice())
+    }
+}
+
+impl I64Entry {
+    fn find(entries: &[I64Entry], key: i32) -> Option<i64> {
+        entries
+            .iter()
+            .find(|ent| ent.key == key)
+            .map(|ent| ent.value)
+    }
+}
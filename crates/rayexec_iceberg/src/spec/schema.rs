@@ -10,6 +10,9 @@ use rayexec_execution::arrays::datatype::{
     TimestampTypeMeta,
 };
 use rayexec_execution::arrays::field::{Field, Schema as BulletSchema};
+use rayexec_execution::arrays::scalar::decimal::Decimal128Scalar;
+use rayexec_execution::arrays::scalar::timestamp::TimestampScalar;
+use rayexec_execution::arrays::scalar::{OwnedScalarValue, ScalarValue};
 use regex::Regex;
 use serde::{de, Deserialize, Deserializer};
 
@@ -60,7 +63,9 @@ impl TryFrom<PrimitiveType> for DataType {
             PrimitiveType::Timestamp => {
                 DataType::Timestamp(TimestampTypeMeta::new(TimeUnit::Microsecond))
             }
-            PrimitiveType::Timestamptz => not_implemented!("Timestamp with timezone"),
+            PrimitiveType::Timestamptz => {
+                DataType::Timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "UTC"))
+            }
             PrimitiveType::String => DataType::Utf8,
             PrimitiveType::Uuid => DataType::Utf8,
             PrimitiveType::Fixed(_) => not_implemented!("Fixed sized binary"),
@@ -69,6 +74,58 @@ impl TryFrom<PrimitiveType> for DataType {
     }
 }
 
+impl PrimitiveType {
+    /// Decode a value using Iceberg's binary single-value serialization.
+    ///
+    /// This is the encoding used for lower and upper bounds in manifests.
+    /// Returns None if the bytes aren't valid for the type, or if we don't
+    /// support decoding the type.
+    ///
+    /// See <https://iceberg.apache.org/spec/#binary-single-value-serialization>
+    pub fn decode_single_value(&self, buf: &[u8]) -> Option<OwnedScalarValue> {
+        Some(match self {
+            PrimitiveType::Boolean => ScalarValue::Boolean(*buf.first()? != 0),
+            PrimitiveType::Int => ScalarValue::Int32(i32::from_le_bytes(buf.try_into().ok()?)),
+            PrimitiveType::Long => ScalarValue::Int64(i64::from_le_bytes(buf.try_into().ok()?)),
+            PrimitiveType::Float => ScalarValue::Float32(f32::from_le_bytes(buf.try_into().ok()?)),
+            PrimitiveType::Double => {
+                ScalarValue::Float64(f64::from_le_bytes(buf.try_into().ok()?))
+            }
+            PrimitiveType::Date => ScalarValue::Date32(i32::from_le_bytes(buf.try_into().ok()?)),
+            PrimitiveType::Time | PrimitiveType::Timestamp => {
+                ScalarValue::Timestamp(TimestampScalar {
+                    unit: TimeUnit::Microsecond,
+                    value: i64::from_le_bytes(buf.try_into().ok()?),
+                    tz: None,
+                })
+            }
+            PrimitiveType::Timestamptz => ScalarValue::Timestamp(TimestampScalar {
+                unit: TimeUnit::Microsecond,
+                value: i64::from_le_bytes(buf.try_into().ok()?),
+                tz: Some("UTC".to_string()),
+            }),
+            PrimitiveType::Decimal { p, s } => {
+                // Big-endian two's complement using the minimum number of
+                // bytes.
+                if buf.is_empty() || buf.len() > 16 {
+                    return None;
+                }
+                let fill = if buf[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+                let mut bytes = [fill; 16];
+                bytes[16 - buf.len()..].copy_from_slice(buf);
+                ScalarValue::Decimal128(Decimal128Scalar {
+                    precision: *p,
+                    scale: *s as i8,
+                    value: i128::from_be_bytes(bytes),
+                })
+            }
+            PrimitiveType::String => ScalarValue::from(std::str::from_utf8(buf).ok()?.to_string()),
+            PrimitiveType::Binary => ScalarValue::Binary(buf.to_vec().into()),
+            PrimitiveType::Uuid | PrimitiveType::Fixed(_) => return None,
+        })
+    }
+}
+
 impl<'de> Deserialize<'de> for PrimitiveType {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -290,6 +347,29 @@ impl Schema {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_decode_single_value() {
+        assert_eq!(
+            Some(ScalarValue::Int64(-2)),
+            PrimitiveType::Long.decode_single_value(&(-2_i64).to_le_bytes())
+        );
+        assert_eq!(
+            Some(ScalarValue::from("abc".to_string())),
+            PrimitiveType::String.decode_single_value(b"abc")
+        );
+        // -1.23 as decimal(5, 2), unscaled -123
+        assert_eq!(
+            Some(ScalarValue::Decimal128(Decimal128Scalar {
+                precision: 5,
+                scale: 2,
+                value: -123,
+            })),
+            PrimitiveType::Decimal { p: 5, s: 2 }.decode_single_value(&[0xFF, 0x85])
+        );
+        // Wrong width.
+        assert_eq!(None, PrimitiveType::Int.decode_single_value(&[1, 2]));
+    }
+
     #[test]
     fn test_deserialize_long_field() {
         let json = r#"
@@ -7,6 +7,7 @@ use futures::StreamExt;
 use rayexec_error::{RayexecError, Result, ResultExt};
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::logical::statistics::{ColumnStatistics, StatisticsValue, TableStatistics};
 use rayexec_execution::storage::table_storage::Projections;
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::{FileProvider, FileSource, FileSourceExt};
@@ -14,6 +15,8 @@ use rayexec_parquet::metadata::Metadata;
 use rayexec_parquet::reader::AsyncBatchReader;
 
 use crate::spec::{
+    self,
+    AnyType,
     DataFile,
     Manifest,
     ManifestContent,
@@ -129,21 +132,7 @@ impl Table {
     pub fn scan(&self, projections: Projections, num_partitions: usize) -> Result<Vec<TableScan>> {
         // Find all data files in the manifests. We'll distribute these evenly
         // over however many partitions we need.
-        let data_files_iter = self
-            .manifests
-            .iter()
-            .filter(|m| matches!(m.metadata.content, ManifestContent::Data))
-            .flat_map(|m| {
-                m.entries.iter().filter_map(|ent| {
-                    let status: ManifestEntryStatus = ent.status.try_into().unwrap_or_default();
-                    if status.is_deleted() {
-                        // Ignore deleted entries during table scans.
-                        None
-                    } else {
-                        Some(&ent.data_file)
-                    }
-                })
-            });
+        let data_files_iter = self.live_data_files();
 
         let mut partitioned_files: Vec<_> = (0..num_partitions).map(|_| VecDeque::new()).collect();
 
@@ -179,8 +168,47 @@ impl Table {
     }
 
     pub fn schema(&self) -> Result<Schema> {
-        let schema = self
-            .metadata
+        self.current_schema()?.to_schema()
+    }
+
+    /// Get table statistics from the data file metrics in the manifests.
+    ///
+    /// Only top-level primitive columns get column statistics.
+    pub fn statistics(&self) -> Result<TableStatistics> {
+        let schema = self.current_schema()?;
+
+        let mut combined: Option<TableStatistics> = None;
+        for data_file in self.live_data_files() {
+            let file_stats = data_file_statistics(schema, data_file);
+            combined = Some(match combined {
+                Some(combined) => combined.merge(file_stats),
+                None => file_stats,
+            });
+        }
+
+        Ok(combined.unwrap_or(TableStatistics::with_num_rows(StatisticsValue::Exact(0))))
+    }
+
+    /// Iterate all data files in the manifests that haven't been deleted.
+    fn live_data_files(&self) -> impl Iterator<Item = &DataFile> {
+        self.manifests
+            .iter()
+            .filter(|m| matches!(m.metadata.content, ManifestContent::Data))
+            .flat_map(|m| {
+                m.entries.iter().filter_map(|ent| {
+                    let status: ManifestEntryStatus = ent.status.try_into().unwrap_or_default();
+                    if status.is_deleted() {
+                        // Ignore deleted entries during table scans.
+                        None
+                    } else {
+                        Some(&ent.data_file)
+                    }
+                })
+            })
+    }
+
+    fn current_schema(&self) -> Result<&spec::Schema> {
+        self.metadata
             .schemas
             .iter()
             .find(|s| s.schema_id == self.metadata.current_schema_id)
@@ -189,9 +217,7 @@ impl Table {
                     "Missing schema for id: {}",
                     self.metadata.current_schema_id
                 ))
-            })?;
-
-        schema.to_schema()
+            })
     }
 
     async fn read_manifests(&self) -> Result<Vec<Manifest>> {
@@ -254,6 +280,43 @@ impl Table {
     }
 }
 
+/// Convert the metrics for a single data file into table statistics.
+fn data_file_statistics(schema: &spec::Schema, data_file: &DataFile) -> TableStatistics {
+    let columns = schema
+        .fields
+        .iter()
+        .map(|field| {
+            let typ = match &field.r#type {
+                AnyType::Primitive(typ) => typ,
+                _ => return ColumnStatistics::unknown(),
+            };
+
+            let count = |v: Option<i64>| match v {
+                Some(v) => StatisticsValue::Exact(v as usize),
+                None => StatisticsValue::Unknown,
+            };
+            // Bounds may be truncated (e.g. for strings), so these are only
+            // estimates.
+            let bound = |v: Option<&[u8]>| match v.and_then(|v| typ.decode_single_value(v)) {
+                Some(v) => StatisticsValue::Estimated(v),
+                None => StatisticsValue::Unknown,
+            };
+
+            ColumnStatistics {
+                num_distinct: count(data_file.distinct_count(field.id)),
+                null_count: count(data_file.null_value_count(field.id)),
+                min: bound(data_file.lower_bound(field.id)),
+                max: bound(data_file.upper_bound(field.id)),
+            }
+        })
+        .collect();
+
+    TableStatistics {
+        num_rows: StatisticsValue::Exact(data_file.record_count as usize),
+        columns,
+    }
+}
+
 #[derive(Debug)]
 pub struct TableScan {
     /// Root of the table.
@@ -0,0 +1,333 @@
+use futures::StreamExt;
+use rayexec_error::{RayexecError, Result};
+
+use crate::location::{AccessConfig, FileLocation};
+use crate::FileProvider;
+
+/// Check if a location string contains any glob characters.
+///
+/// '?' begins the query string in http urls, so only '*' and '[' are
+/// considered glob characters for those.
+pub fn is_glob(location: &str) -> bool {
+    location.contains(glob_chars(location))
+}
+
+fn glob_chars(location: &str) -> &'static [char] {
+    if location.starts_with("http://") || location.starts_with("https://") {
+        &['*', '[']
+    } else {
+        &['*', '?', '[']
+    }
+}
+
+/// Expand a location string into the locations of all files it matches.
+///
+/// Locations without glob characters are returned as-is without checking if
+/// the file exists. Globs are expanded by listing the directory containing all
+/// possible matches, and errors if nothing matches.
+pub async fn expand_locations<P>(
+    provider: &P,
+    location: &str,
+    conf: &AccessConfig,
+) -> Result<Vec<FileLocation>>
+where
+    P: FileProvider + ?Sized,
+{
+    if !is_glob(location) {
+        return Ok(vec![FileLocation::parse(location)]);
+    }
+
+    let pattern = GlobPattern::try_new(location)?;
+
+    let mut stream = provider.list_prefix(pattern.list_location(), conf);
+    let mut locations = Vec::new();
+    while let Some(paths) = stream.next().await {
+        for path in paths? {
+            if pattern.matches(&path) {
+                locations.push(pattern.root_location().join(path.split('/'))?);
+            }
+        }
+    }
+
+    if locations.is_empty() {
+        return Err(RayexecError::new(format!(
+            "No files found matching '{location}'"
+        )));
+    }
+
+    locations.sort_by_key(|loc| loc.to_string());
+
+    Ok(locations)
+}
+
+/// A glob pattern split into a root directory and segments to match paths
+/// relative to that root.
+///
+/// Supported syntax:
+///
+/// - `*` matches any number of characters within a path segment.
+/// - `**` matches any number of path segments, including zero.
+/// - `?` matches a single character.
+/// - `[abc]`, `[a-z]` and `[!a-z]` match a single character in (or not in)
+///   the set.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GlobPattern {
+    /// Everything before the first segment containing a glob character.
+    root: String,
+    /// Segments to match.
+    segments: Vec<Segment>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Segment {
+    /// '**', any number of segments.
+    Recursive,
+    /// Pattern matching exactly one segment.
+    Pattern(Vec<Token>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Token {
+    Char(char),
+    AnyChar,
+    AnyString,
+    Class {
+        negated: bool,
+        ranges: Vec<(char, char)>,
+    },
+}
+
+impl GlobPattern {
+    pub fn try_new(location: &str) -> Result<Self> {
+        let glob_chars = glob_chars(location);
+        let parts: Vec<_> = location.split('/').collect();
+
+        let first_glob = parts
+            .iter()
+            .position(|part| part.contains(glob_chars))
+            .ok_or_else(|| RayexecError::new(format!("'{location}' is not a glob pattern")))?;
+
+        let root = parts[..first_glob].join("/");
+        let root = if root.is_empty() && location.starts_with('/') {
+            "/".to_string()
+        } else {
+            root
+        };
+
+        let segments = parts[first_glob..]
+            .iter()
+            .filter(|part| !part.is_empty())
+            .map(|part| {
+                if *part == "**" {
+                    Ok(Segment::Recursive)
+                } else {
+                    Ok(Segment::Pattern(parse_segment(part, glob_chars)?))
+                }
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        Ok(GlobPattern { root, segments })
+    }
+
+    /// Location that all matched paths are relative to.
+    pub fn root_location(&self) -> FileLocation {
+        FileLocation::parse(&self.root)
+    }
+
+    /// Location to list to find all candidate paths.
+    ///
+    /// Always ends with a '/' so that object stores only list keys inside the
+    /// root "directory", and not keys that happen to share the same prefix. An
+    /// empty root lists the current directory.
+    pub fn list_location(&self) -> FileLocation {
+        if self.root.is_empty() {
+            FileLocation::parse("./")
+        } else if self.root.ends_with('/') {
+            self.root_location()
+        } else {
+            FileLocation::parse(&format!("{}/", self.root))
+        }
+    }
+
+    /// Check if a path relative to the root matches this pattern.
+    pub fn matches(&self, path: &str) -> bool {
+        let parts: Vec<_> = path.split('/').filter(|part| !part.is_empty()).collect();
+        match_segments(&self.segments, &parts)
+    }
+}
+
+fn parse_segment(segment: &str, glob_chars: &[char]) -> Result<Vec<Token>> {
+    let mut tokens = Vec::new();
+    let mut chars = segment.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        let token = match c {
+            '*' if glob_chars.contains(&'*') => {
+                // Consecutive stars within a segment are the same as one.
+                while chars.peek() == Some(&'*') {
+                    chars.next();
+                }
+                Token::AnyString
+            }
+            '?' if glob_chars.contains(&'?') => Token::AnyChar,
+            '[' if glob_chars.contains(&'[') => {
+                let negated = matches!(chars.peek(), Some('!') | Some('^'));
+                if negated {
+                    chars.next();
+                }
+
+                let mut ranges = Vec::new();
+                loop {
+                    let start = match chars.next() {
+                        Some(']') if !ranges.is_empty() => break,
+                        Some(c) => c,
+                        None => {
+                            return Err(RayexecError::new(format!(
+                                "Unclosed character class in glob segment '{segment}'"
+                            )))
+                        }
+                    };
+
+                    let mut lookahead = chars.clone();
+                    if lookahead.next() == Some('-') && lookahead.peek().is_some_and(|&c| c != ']')
+                    {
+                        chars.next();
+                        let end = chars.next().expect("lookahead to have end char");
+                        ranges.push((start, end));
+                    } else {
+                        ranges.push((start, start));
+                    }
+                }
+
+                Token::Class { negated, ranges }
+            }
+            other => Token::Char(other),
+        };
+        tokens.push(token);
+    }
+
+    Ok(tokens)
+}
+
+fn match_segments(segments: &[Segment], parts: &[&str]) -> bool {
+    match segments.split_first() {
+        None => parts.is_empty(),
+        Some((Segment::Recursive, rest)) => {
+            (0..=parts.len()).any(|skip| match_segments(rest, &parts[skip..]))
+        }
+        Some((Segment::Pattern(tokens), rest)) => match parts.split_first() {
+            Some((part, remaining)) => {
+                let chars: Vec<_> = part.chars().collect();
+                match_tokens(tokens, &chars) && match_segments(rest, remaining)
+            }
+            None => false,
+        },
+    }
+}
+
+fn match_tokens(tokens: &[Token], chars: &[char]) -> bool {
+    match tokens.split_first() {
+        None => chars.is_empty(),
+        Some((Token::AnyString, rest)) => {
+            (0..=chars.len()).any(|skip| match_tokens(rest, &chars[skip..]))
+        }
+        Some((token, rest)) => match chars.split_first() {
+            Some((c, remaining)) => {
+                let matched = match token {
+                    Token::Char(expected) => c == expected,
+                    Token::AnyChar => true,
+                    Token::Class { negated, ranges } => {
+                        let in_class = ranges.iter().any(|(start, end)| start <= c && c <= end);
+                        in_class != *negated
+                    }
+                    Token::AnyString => unreachable!("handled above"),
+                };
+                matched && match_tokens(rest, remaining)
+            }
+            None => false,
+        },
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detect_glob() {
+        assert!(is_glob("dir/*.parquet"));
+        assert!(is_glob("dir/file_?.csv"));
+        assert!(is_glob("s3://bucket/[ab].csv"));
+        assert!(is_glob("https://example.com/*.csv"));
+
+        assert!(!is_glob("dir/file.parquet"));
+        assert!(!is_glob("https://example.com/file.csv?token=abc"));
+    }
+
+    #[test]
+    fn pattern_root() {
+        let pattern = GlobPattern::try_new("dir/sub/*.parquet").unwrap();
+        assert_eq!(FileLocation::parse("dir/sub"), pattern.root_location());
+
+        let pattern = GlobPattern::try_new("s3://bucket/dir/**/*.parquet").unwrap();
+        assert_eq!("s3://bucket/dir", pattern.root_location().to_string());
+
+        let pattern = GlobPattern::try_new("/*.csv").unwrap();
+        assert_eq!(FileLocation::parse("/"), pattern.root_location());
+
+        let pattern = GlobPattern::try_new("*.csv").unwrap();
+        assert_eq!(FileLocation::parse("./"), pattern.list_location());
+
+        let pattern = GlobPattern::try_new("s3://bucket/dir/*.csv").unwrap();
+        assert_eq!("s3://bucket/dir/", pattern.list_location().to_string());
+    }
+
+    #[test]
+    fn match_star() {
+        let pattern = GlobPattern::try_new("dir/*.parquet").unwrap();
+        assert!(pattern.matches("a.parquet"));
+        assert!(pattern.matches(".parquet"));
+        assert!(!pattern.matches("a.csv"));
+        assert!(!pattern.matches("sub/a.parquet"));
+    }
+
+    #[test]
+    fn match_recursive() {
+        let pattern = GlobPattern::try_new("dir/**/*.parquet").unwrap();
+        assert!(pattern.matches("a.parquet"));
+        assert!(pattern.matches("sub/a.parquet"));
+        assert!(pattern.matches("sub/sub2/a.parquet"));
+        assert!(!pattern.matches("sub/a.csv"));
+
+        let pattern = GlobPattern::try_new("dir/**/data/*.csv").unwrap();
+        assert!(pattern.matches("data/a.csv"));
+        assert!(pattern.matches("x/y/data/a.csv"));
+        assert!(!pattern.matches("x/y/a.csv"));
+    }
+
+    #[test]
+    fn match_single_char_and_class() {
+        let pattern = GlobPattern::try_new("file_?.csv").unwrap();
+        assert!(pattern.matches("file_1.csv"));
+        assert!(!pattern.matches("file_10.csv"));
+
+        let pattern = GlobPattern::try_new("file_[0-2].csv").unwrap();
+        assert!(pattern.matches("file_0.csv"));
+        assert!(pattern.matches("file_2.csv"));
+        assert!(!pattern.matches("file_3.csv"));
+
+        let pattern = GlobPattern::try_new("file_[!0-2].csv").unwrap();
+        assert!(!pattern.matches("file_0.csv"));
+        assert!(pattern.matches("file_3.csv"));
+
+        let pattern = GlobPattern::try_new("file_[ab-].csv").unwrap();
+        assert!(pattern.matches("file_a.csv"));
+        assert!(pattern.matches("file_-.csv"));
+        assert!(!pattern.matches("file_c.csv"));
+    }
+
+    #[test]
+    fn unclosed_class() {
+        GlobPattern::try_new("file_[ab.csv").unwrap_err();
+    }
+}
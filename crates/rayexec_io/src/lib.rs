@@ -1,3 +1,4 @@
+pub mod glob;
 pub mod http;
 pub mod location;
 pub mod memory;
@@ -19,8 +20,7 @@ use rayexec_error::Result;
 /// Different source/sink implementations can be returned depending on the file
 /// location variant (url or path). If this file provider cannot handle a specific
 /// variant, this should just return an error.
-// TODO: List/delete. With globs and hive, we could do something fancy where we
-// have an async stream of sources, but idk if that's good idea yet.
+// TODO: Delete.
 pub trait FileProvider: Sync + Send + Debug {
     /// Gets a file source at some location.
     fn file_source(
@@ -64,7 +64,10 @@ impl ProtoConv for AccessConfig {
 }
 
 /// Location for a file.
-// TODO: Glob/hive
+///
+/// Glob patterns are expanded into individual file locations before being
+/// parsed, see `glob::expand_locations`.
+// TODO: Hive
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub enum FileLocation {
     Url(Url),
@@ -76,7 +79,7 @@ impl FileLocation {
     ///
     /// Current implementation assumes that if the string fails to parse as a
     /// url, it must be a path. However further checks will need to be done when
-    /// we support globs and hive partitioning here.
+    /// we support hive partitioning here.
     pub fn parse(s: &str) -> Self {
         match Url::parse(s) {
             Ok(url) => FileLocation::Url(url),
@@ -105,6 +108,7 @@ impl FileLocation {
             Self::Url(url) => {
                 url.path_segments_mut()
                     .map_err(|_| RayexecError::new("Failed to get path segments for url"))?
+                    .pop_if_empty()
                     .extend(segments);
             }
             Self::Path(path) => {
@@ -186,6 +190,14 @@ mod tests {
         // TODO: Should this be allowed?
         // location.join_mut(["d/e"]).unwrap();
         // assert_eq!("s3://bucket/path/a/b/c/d/e", location.to_string());
+
+        let mut location = FileLocation::parse("s3://bucket/path/");
+        location.join_mut(["a"]).unwrap();
+        assert_eq!("s3://bucket/path/a", location.to_string());
+
+        let mut location = FileLocation::parse("s3://bucket");
+        location.join_mut(["a"]).unwrap();
+        assert_eq!("s3://bucket/a", location.to_string());
     }
 
     #[test]
@@ -115,16 +115,16 @@ impl<C: HttpClient + 'static> S3Client<C> {
             let keys: Vec<_> = contents
                 .into_iter()
                 .map(|c| {
-                    // TODO: Probably want to be little less cowboy-y about
-                    // this.
-                    c.key
-                        .strip_prefix(&state.location.url.path()[1..])
-                        .unwrap()
-                        .strip_prefix('/')
-                        .unwrap()
-                        .to_string()
+                    let prefix = &state.location.url.path()[1..];
+                    let key = c.key.strip_prefix(prefix).ok_or_else(|| {
+                        RayexecError::new(format!(
+                            "Listed key '{}' does not start with prefix '{prefix}'",
+                            c.key
+                        ))
+                    })?;
+                    Ok(key.trim_start_matches('/').to_string())
                 })
-                .collect();
+                .collect::<Result<_>>()?;
 
             Ok(Some((keys, state)))
         })
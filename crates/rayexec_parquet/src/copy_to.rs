@@ -23,6 +23,10 @@ impl<R: Runtime> CopyToFunction for ParquetCopyToFunction<R> {
         "parquet_copy_to"
     }
 
+    fn file_extension(&self) -> Option<&'static str> {
+        Some("parquet")
+    }
+
     fn create_sinks(
         &self,
         schema: Schema,
@@ -6,77 +6,313 @@ use futures::future::BoxFuture;
 use rayexec_error::Result;
 use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::explain::explainable::ExplainEntry;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::runtime::Runtime;
+use rayexec_execution::storage::multi_file::{FileProjection, MultiFileSchema};
 use rayexec_execution::storage::table_storage::{DataTable, DataTableScan, Projections};
 use rayexec_io::location::{AccessConfig, FileLocation};
 use rayexec_io::{FileProvider, FileSource};
 
 use crate::metadata::Metadata;
+use crate::pruning::{PruningStats, RowGroupPruner};
 use crate::reader::AsyncBatchReader;
+use crate::schema::from_parquet_schema;
 
-/// Data table implementation which parallelizes on row groups. During scanning,
-/// each returned scan object is responsible for distinct row groups to read.
-#[derive(Debug)]
-pub struct RowGroupPartitionedDataTable<R: Runtime> {
+const BATCH_SIZE: usize = 4096; // TODO
+
+/// A single parquet file that's part of a table.
+#[derive(Debug, Clone)]
+pub struct ParquetFile {
     pub metadata: Arc<Metadata>,
+    /// Schema of the file itself.
     pub schema: Schema,
-    pub location: FileLocation,
+}
+
+impl ParquetFile {
+    /// Load the metadata for a file.
+    pub async fn load<P>(provider: &P, location: FileLocation, conf: &AccessConfig) -> Result<Self>
+    where
+        P: FileProvider + ?Sized,
+    {
+        let mut source = provider.file_source(location, conf)?;
+        let size = source.size().await?;
+
+        let metadata = Metadata::new_from_source(source.as_mut(), size).await?;
+        let schema = from_parquet_schema(metadata.decoded_metadata.file_metadata().schema_descr())?;
+
+        Ok(ParquetFile {
+            metadata: Arc::new(metadata),
+            schema,
+        })
+    }
+}
+
+/// Data table implementation which parallelizes on row groups across one or
+/// more files. During scanning, each returned scan object is responsible for
+/// distinct row groups to read.
+#[derive(Debug)]
+pub struct RowGroupPartitionedDataTable<R: Runtime> {
+    /// Files in the table, in the same order as the locations in the schema.
+    ///
+    /// Files that haven't been opened yet are None, and are loaded when
+    /// scanned.
+    pub files: Vec<Option<ParquetFile>>,
+    pub schema: Arc<MultiFileSchema>,
     pub conf: AccessConfig,
     pub runtime: R,
+    /// Row groups pruned across all scans of this table.
+    pub pruning: Arc<PruningStats>,
+}
+
+impl<R: Runtime> RowGroupPartitionedDataTable<R> {
+    /// Get the row groups in a loaded file that may contain rows passing the
+    /// filters according to the column chunk statistics.
+    ///
+    /// Returns the row groups along with the total number of row groups in
+    /// the file.
+    fn row_groups_matching(
+        &self,
+        file: &ParquetFile,
+        filters: &[ScanFilter],
+    ) -> (VecDeque<usize>, usize) {
+        let num_row_groups = file.metadata.decoded_metadata.num_row_groups();
+        let mut row_groups: VecDeque<_> = (0..num_row_groups).collect();
+
+        let file_filters = self.schema.file_filters(&file.schema, filters);
+        if !file_filters.is_empty() {
+            RowGroupPruner::new(file.metadata.clone(), &file.schema, file_filters)
+                .prune_with_statistics(&mut row_groups);
+        }
+
+        (row_groups, num_row_groups)
+    }
 }
 
 impl<R: Runtime> DataTable for RowGroupPartitionedDataTable<R> {
     fn scan(
         &self,
         projections: Projections,
+        filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
-        let file_provider = self.runtime.file_provider();
+        let mut partitioned_files = vec![VecDeque::<PendingFile>::new(); num_partitions];
+        let locations = self.schema.locations();
 
-        let mut partitioned_row_groups = vec![VecDeque::new(); num_partitions];
+        // Split row groups across all files into individual partitions. Files
+        // that haven't been loaded are read in their entirety by a single
+        // partition since we don't know their row groups yet.
+        let mut partition = 0;
+        for file_idx in self.schema.files_matching(filters) {
+            let file = match &self.files[file_idx] {
+                Some(file) => file,
+                None => {
+                    partitioned_files[partition].push_back(PendingFile {
+                        file_idx,
+                        location: locations[file_idx].clone(),
+                        file: None,
+                        row_groups: None,
+                    });
+                    partition = (partition + 1) % num_partitions;
+                    continue;
+                }
+            };
 
-        // Split row groups into individual partitions.
-        for row_group in 0..self.metadata.decoded_metadata.row_groups().len() {
-            let partition = row_group % num_partitions;
-            partitioned_row_groups[partition].push_back(row_group);
+            let (row_groups, num_row_groups) = self.row_groups_matching(file, filters);
+            PruningStats::add(&self.pruning.row_groups, num_row_groups);
+            PruningStats::add(
+                &self.pruning.pruned_by_statistics,
+                num_row_groups - row_groups.len(),
+            );
+
+            for row_group in row_groups {
+                let pending = &mut partitioned_files[partition];
+                match pending.back_mut() {
+                    Some(PendingFile {
+                        file_idx: last_idx,
+                        row_groups: Some(row_groups),
+                        ..
+                    }) if *last_idx == file_idx => row_groups.push_back(row_group),
+                    _ => pending.push_back(PendingFile {
+                        file_idx,
+                        location: locations[file_idx].clone(),
+                        file: Some(file.clone()),
+                        row_groups: Some(VecDeque::from([row_group])),
+                    }),
+                }
+                partition = (partition + 1) % num_partitions;
+            }
         }
 
-        let readers = partitioned_row_groups
+        let scans: Vec<Box<dyn DataTableScan>> = partitioned_files
             .into_iter()
-            .map(|row_groups| {
-                let reader = file_provider.file_source(self.location.clone(), &self.conf)?;
-                const BATCH_SIZE: usize = 4096; // TODO
-                AsyncBatchReader::try_new(
-                    reader,
-                    row_groups,
-                    self.metadata.clone(),
-                    &self.schema,
-                    BATCH_SIZE,
-                    projections.clone(),
-                )
+            .map(|pending| {
+                Box::new(RowGroupsScan {
+                    runtime: self.runtime.clone(),
+                    conf: self.conf.clone(),
+                    schema: self.schema.clone(),
+                    projections: projections.clone(),
+                    filters: filters.to_vec(),
+                    pruning: self.pruning.clone(),
+                    pending,
+                    current: None,
+                }) as _
             })
-            .collect::<Result<Vec<_>>>()?;
-
-        let scans: Vec<Box<dyn DataTableScan>> = readers
-            .into_iter()
-            .map(|reader| Box::new(RowGroupsScan { reader }) as _)
             .collect();
 
         Ok(scans)
     }
+
+    fn explain_scan(&self, ent: ExplainEntry, filters: &[ScanFilter]) -> ExplainEntry {
+        if filters.is_empty() {
+            return ent;
+        }
+
+        let row_groups = PruningStats::get(&self.pruning.row_groups);
+        if row_groups > 0 {
+            // Table's been scanned, report what was actually pruned.
+            let by_statistics = PruningStats::get(&self.pruning.pruned_by_statistics);
+            let by_page_index = PruningStats::get(&self.pruning.pruned_by_page_index);
+            let by_bloom_filter = PruningStats::get(&self.pruning.pruned_by_bloom_filter);
+
+            return ent
+                .with_value("row_groups", row_groups)
+                .with_value(
+                    "pruned_row_groups",
+                    by_statistics + by_page_index + by_bloom_filter,
+                )
+                .with_named_map(
+                    "pruned_by",
+                    "row_groups",
+                    [
+                        ("statistics", by_statistics),
+                        ("page_index", by_page_index),
+                        ("bloom_filter", by_bloom_filter),
+                    ],
+                );
+        }
+
+        // Otherwise report what we know we'll prune using statistics from the
+        // files we've already loaded.
+        let mut row_groups = 0;
+        let mut pruned = 0;
+        for file_idx in self.schema.files_matching(filters) {
+            if let Some(file) = &self.files[file_idx] {
+                let (matching, num_row_groups) = self.row_groups_matching(file, filters);
+                row_groups += num_row_groups;
+                pruned += num_row_groups - matching.len();
+            }
+        }
+
+        ent.with_value("row_groups", row_groups)
+            .with_value("pruned_row_groups", pruned)
+    }
+}
+
+/// Row groups in a file that a scan has yet to read.
+#[derive(Debug, Clone)]
+struct PendingFile {
+    file_idx: usize,
+    location: FileLocation,
+    /// The file if it's already been loaded.
+    file: Option<ParquetFile>,
+    /// Row groups to read, None to read all row groups.
+    row_groups: Option<VecDeque<usize>>,
 }
 
-struct RowGroupsScan {
+struct CurrentFile {
     reader: AsyncBatchReader<Box<dyn FileSource>>,
+    projection: FileProjection,
+}
+
+struct RowGroupsScan<R: Runtime> {
+    runtime: R,
+    conf: AccessConfig,
+    schema: Arc<MultiFileSchema>,
+    projections: Projections,
+    filters: Vec<ScanFilter>,
+    pruning: Arc<PruningStats>,
+    pending: VecDeque<PendingFile>,
+    current: Option<CurrentFile>,
+}
+
+impl<R: Runtime> RowGroupsScan<R> {
+    async fn pull_inner(&mut self) -> Result<Option<Batch>> {
+        loop {
+            if let Some(current) = &mut self.current {
+                if let Some(batch) = current.reader.read_next().await? {
+                    return Ok(Some(current.projection.project_batch(batch)?));
+                }
+                self.current = None;
+            }
+
+            let pending = match self.pending.pop_front() {
+                Some(pending) => pending,
+                None => return Ok(None),
+            };
+
+            let provider = self.runtime.file_provider();
+            let file = match pending.file {
+                Some(file) => file,
+                None => {
+                    ParquetFile::load(provider.as_ref(), pending.location.clone(), &self.conf)
+                        .await?
+                }
+            };
+            let file_filters = self.schema.file_filters(&file.schema, &self.filters);
+            let pruner = RowGroupPruner::new(file.metadata.clone(), &file.schema, file_filters);
+
+            let mut row_groups = match pending.row_groups {
+                Some(row_groups) => row_groups,
+                None => {
+                    // File wasn't loaded when the scan was created, prune
+                    // using statistics now.
+                    let num_row_groups = file.metadata.decoded_metadata.num_row_groups();
+                    let mut row_groups = (0..num_row_groups).collect();
+                    let pruned = pruner.prune_with_statistics(&mut row_groups);
+                    PruningStats::add(&self.pruning.row_groups, num_row_groups);
+                    PruningStats::add(&self.pruning.pruned_by_statistics, pruned);
+                    row_groups
+                }
+            };
+
+            let mut source = provider.file_source(pending.location, &self.conf)?;
+
+            let (by_page_index, by_bloom_filter) = pruner
+                .prune_with_indexes(source.as_mut(), &mut row_groups)
+                .await?;
+            PruningStats::add(&self.pruning.pruned_by_page_index, by_page_index);
+            PruningStats::add(&self.pruning.pruned_by_bloom_filter, by_bloom_filter);
+
+            if row_groups.is_empty() {
+                continue;
+            }
+
+            let projection =
+                self.schema
+                    .file_projection(pending.file_idx, &file.schema, &self.projections)?;
+
+            let reader = AsyncBatchReader::try_new(
+                source,
+                row_groups,
+                file.metadata,
+                &file.schema,
+                BATCH_SIZE,
+                projection.file_projections(),
+            )?;
+
+            self.current = Some(CurrentFile { reader, projection });
+        }
+    }
 }
 
-impl DataTableScan for RowGroupsScan {
+impl<R: Runtime> DataTableScan for RowGroupsScan<R> {
     fn pull(&mut self) -> BoxFuture<'_, Result<Option<Batch>>> {
-        Box::pin(async { self.reader.read_next().await })
+        Box::pin(async { self.pull_inner().await })
     }
 }
 
-impl fmt::Debug for RowGroupsScan {
+impl<R: Runtime> fmt::Debug for RowGroupsScan<R> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         f.debug_struct("RowGroupsScan").finish_non_exhaustive()
     }
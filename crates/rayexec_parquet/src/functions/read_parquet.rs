@@ -1,7 +1,7 @@
 use std::collections::HashMap;
 use std::sync::Arc;
 
-use futures::future::BoxFuture;
+use futures::future::{self, BoxFuture};
 use futures::FutureExt;
 use rayexec_error::Result;
 use rayexec_execution::arrays::datatype::DataTypeId;
@@ -9,7 +9,7 @@ use rayexec_execution::arrays::scalar::OwnedScalarValue;
 use rayexec_execution::database::DatabaseContext;
 use rayexec_execution::expr;
 use rayexec_execution::functions::table::{
-    try_location_and_access_config_from_args,
+    try_locations_and_access_config_from_args,
     PlannedTableFunction,
     ScanPlanner,
     TableFunction,
@@ -17,13 +17,11 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
 use rayexec_execution::runtime::Runtime;
-use rayexec_io::FileProvider;
+use rayexec_execution::storage::multi_file::{MultiFileOptions, MultiFileSchema};
 
-use super::datatable::RowGroupPartitionedDataTable;
-use crate::metadata::Metadata;
-use crate::schema::from_parquet_schema;
+use super::datatable::{ParquetFile, RowGroupPartitionedDataTable};
+use crate::statistics::table_statistics;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct ReadParquet<R: Runtime> {
@@ -73,32 +71,47 @@ impl<R: Runtime> ReadParquet<R> {
         positional_inputs: Vec<OwnedScalarValue>,
         named_inputs: HashMap<String, OwnedScalarValue>,
     ) -> Result<PlannedTableFunction> {
-        let (location, conf) =
-            try_location_and_access_config_from_args(&self, &positional_inputs, &named_inputs)?;
+        let provider = self.runtime.file_provider();
+        let (locations, conf) = try_locations_and_access_config_from_args(
+            &self,
+            &positional_inputs,
+            &named_inputs,
+            provider.as_ref(),
+        )
+        .await?;
+        let options = MultiFileOptions::try_from_named_args(&named_inputs)?;
 
-        let mut source = self
-            .runtime
-            .file_provider()
-            .file_source(location.clone(), &conf)?;
+        // Fetch metadata for the files we need to determine the schema
+        // concurrently. Any remaining files are loaded when scanned.
+        let num_infer = options.num_files_to_infer(&locations)?;
+        let loaded = future::try_join_all(
+            locations[..num_infer]
+                .iter()
+                .map(|location| ParquetFile::load(provider.as_ref(), location.clone(), &conf)),
+        )
+        .await?;
 
-        let size = source.size().await?;
+        let file_schemas: Vec<_> = loaded.iter().map(|file| file.schema.clone()).collect();
+        let multi_schema = MultiFileSchema::try_unify(locations, &file_schemas, options)?;
 
-        let metadata = Metadata::new_from_source(source.as_mut(), size).await?;
-        let schema = from_parquet_schema(metadata.decoded_metadata.file_metadata().schema_descr())?;
+        let statistics = multi_schema.table_statistics(loaded.iter().map(|file| {
+            (
+                &file.schema,
+                table_statistics(&file.metadata.decoded_metadata, &file.schema),
+            )
+        }));
 
-        let num_rows = metadata
-            .decoded_metadata
-            .row_groups()
-            .iter()
-            .map(|g| g.num_rows())
-            .sum::<i64>() as usize;
+        let mut files: Vec<_> = loaded.into_iter().map(Some).collect();
+        files.resize(multi_schema.locations().len(), None);
+
+        let schema = multi_schema.schema.clone();
 
         let datatable = RowGroupPartitionedDataTable {
-            metadata: Arc::new(metadata),
-            schema: schema.clone(),
-            location,
+            files,
+            schema: Arc::new(multi_schema),
             conf,
             runtime: self.runtime.clone(),
+            pruning: Arc::default(),
         };
 
         Ok(PlannedTableFunction {
@@ -106,7 +119,7 @@ impl<R: Runtime> ReadParquet<R> {
             positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
             named_inputs,
             function_impl: TableFunctionImpl::Scan(Arc::new(datatable)),
-            cardinality: StatisticsValue::Exact(num_rows),
+            statistics,
             schema,
         })
     }
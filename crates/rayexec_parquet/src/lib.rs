@@ -1,7 +1,9 @@
 pub mod copy_to;
 pub mod functions;
 pub mod metadata;
+pub mod pruning;
 pub mod reader;
+pub mod statistics;
 pub mod writer;
 
 mod schema;
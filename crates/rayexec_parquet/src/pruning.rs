@@ -0,0 +1,482 @@
+//! Skipping row groups that can't contain rows passing a scan's filters.
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use parquet::basic::{TimeUnit as ParquetTimeUnit, Type as PhysicalType};
+use parquet::bloom_filter::{read_bloom_filter_header_and_length, Sbbf, SBBF_HEADER_SIZE_ESTIMATE};
+use parquet::data_type::ByteArray;
+use parquet::file::metadata::ColumnChunkMetaData;
+use parquet::file::page_index::index::{Index, PageIndex};
+use parquet::file::page_index::index_reader::decode_column_index;
+use rayexec_error::{Result, ResultExt};
+use rayexec_execution::arrays::datatype::DataType;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use rayexec_execution::expr::comparison_expr::ComparisonOperator;
+use rayexec_execution::logical::scan_filter::{ScanFilter, ScanFilterType};
+use rayexec_io::FileSource;
+
+use crate::metadata::Metadata;
+use crate::schema::time_unit;
+use crate::statistics::{
+    bool_scalar,
+    byte_array_scalar,
+    chunk_min_max,
+    double_scalar,
+    float_scalar,
+    int32_scalar,
+    int64_scalar,
+    leaf_indices,
+};
+
+/// Counts of row groups skipped while scanning a table.
+///
+/// Shared across all scans for a table.
+#[derive(Debug, Default)]
+pub struct PruningStats {
+    /// Total number of row groups considered for reading.
+    pub row_groups: AtomicUsize,
+    /// Row groups skipped using column chunk statistics.
+    pub pruned_by_statistics: AtomicUsize,
+    /// Row groups skipped using the page index.
+    pub pruned_by_page_index: AtomicUsize,
+    /// Row groups skipped using bloom filters.
+    pub pruned_by_bloom_filter: AtomicUsize,
+}
+
+impl PruningStats {
+    pub fn add(counter: &AtomicUsize, n: usize) {
+        counter.fetch_add(n, Ordering::Relaxed);
+    }
+
+    pub fn get(counter: &AtomicUsize) -> usize {
+        counter.load(Ordering::Relaxed)
+    }
+}
+
+/// A filter on a single leaf column in the file.
+#[derive(Debug)]
+struct LeafFilter {
+    leaf: usize,
+    datatype: DataType,
+    filter: ScanFilter,
+}
+
+impl LeafFilter {
+    /// Get the constant if this is an equality filter.
+    fn eq_constant(&self) -> Option<&OwnedScalarValue> {
+        match &self.filter.filter {
+            ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Eq,
+                constant,
+            } => Some(constant),
+            _ => None,
+        }
+    }
+}
+
+/// Prunes row groups for a single file.
+#[derive(Debug)]
+pub struct RowGroupPruner {
+    metadata: Arc<Metadata>,
+    filters: Vec<LeafFilter>,
+}
+
+impl RowGroupPruner {
+    /// Create a new pruner.
+    ///
+    /// `filters` should reference columns in the file's schema. Filters on
+    /// nested columns are ignored.
+    pub fn new(metadata: Arc<Metadata>, schema: &Schema, filters: Vec<ScanFilter>) -> Self {
+        let leaves = leaf_indices(&metadata.decoded_metadata, schema);
+        let filters = filters
+            .into_iter()
+            .filter_map(|filter| {
+                let leaf = (*leaves.get(filter.column)?)?;
+                Some(LeafFilter {
+                    leaf,
+                    datatype: schema.fields[filter.column].datatype.clone(),
+                    filter,
+                })
+            })
+            .collect();
+
+        RowGroupPruner { metadata, filters }
+    }
+
+    /// Remove row groups where column chunk statistics show that no rows can
+    /// pass the filters.
+    ///
+    /// Returns the number of row groups removed.
+    pub fn prune_with_statistics(&self, row_groups: &mut VecDeque<usize>) -> usize {
+        let before = row_groups.len();
+        row_groups.retain(|&idx| self.statistics_could_match(idx));
+        before - row_groups.len()
+    }
+
+    /// Remove row groups where the page index or bloom filters show that no
+    /// rows can pass an equality filter.
+    ///
+    /// This requires reading from the file. Returns the number of row groups
+    /// removed by the page index and bloom filters respectively.
+    pub async fn prune_with_indexes(
+        &self,
+        source: &mut dyn FileSource,
+        row_groups: &mut VecDeque<usize>,
+    ) -> Result<(usize, usize)> {
+        if self.filters.iter().all(|f| f.eq_constant().is_none()) {
+            return Ok((0, 0));
+        }
+
+        let mut pruned_page_index = 0;
+        let mut pruned_bloom_filter = 0;
+
+        let mut retained = VecDeque::with_capacity(row_groups.len());
+        'row_groups: for idx in row_groups.drain(..) {
+            let group = self.metadata.decoded_metadata.row_group(idx);
+
+            for filter in &self.filters {
+                let constant = match filter.eq_constant() {
+                    Some(constant) => constant,
+                    None => continue,
+                };
+                let chunk = group.column(filter.leaf);
+
+                if !page_index_could_match(source, chunk, filter).await? {
+                    pruned_page_index += 1;
+                    continue 'row_groups;
+                }
+
+                if !bloom_filter_could_match(source, chunk, &filter.datatype, constant).await? {
+                    pruned_bloom_filter += 1;
+                    continue 'row_groups;
+                }
+            }
+
+            retained.push_back(idx);
+        }
+
+        *row_groups = retained;
+
+        Ok((pruned_page_index, pruned_bloom_filter))
+    }
+
+    fn statistics_could_match(&self, row_group: usize) -> bool {
+        let group = self.metadata.decoded_metadata.row_group(row_group);
+        if group.num_rows() == 0 {
+            return false;
+        }
+
+        self.filters.iter().all(|filter| {
+            let stats = match group.column(filter.leaf).statistics() {
+                Some(stats) => stats,
+                None => return true,
+            };
+
+            if stats.null_count() as i64 == group.num_rows() {
+                // Only NULLs, which never pass a comparison.
+                return false;
+            }
+
+            let desc = group.column(filter.leaf).column_descr();
+            match chunk_min_max(stats, &filter.datatype, desc) {
+                Some((min, max)) => filter.filter.could_match_range(Some(&min), Some(&max)),
+                None => true,
+            }
+        })
+    }
+}
+
+/// Check if any page in a column chunk could contain values passing the
+/// filter using the chunk's column index.
+///
+/// Returns true if the chunk doesn't have a column index.
+async fn page_index_could_match(
+    source: &mut dyn FileSource,
+    chunk: &ColumnChunkMetaData,
+    filter: &LeafFilter,
+) -> Result<bool> {
+    let range = match chunk.column_index_range() {
+        Some(range) => range,
+        None => return Ok(true),
+    };
+
+    let buf = source.read_range(range.start, range.len()).await?;
+    let index =
+        decode_column_index(&buf, chunk.column_type()).context("failed to decode column index")?;
+
+    fn any_page<T>(
+        pages: &[PageIndex<T>],
+        filter: &ScanFilter,
+        to_scalar: impl Fn(&T) -> Option<OwnedScalarValue>,
+    ) -> bool {
+        pages.iter().any(|page| match (page.min(), page.max()) {
+            (Some(min), Some(max)) => match (to_scalar(min), to_scalar(max)) {
+                (Some(min), Some(max)) => filter.could_match_range(Some(&min), Some(&max)),
+                _ => true,
+            },
+            // Page only contains NULLs.
+            _ => false,
+        })
+    }
+
+    let datatype = &filter.datatype;
+    let filter = &filter.filter;
+    let desc = chunk.column_descr();
+
+    Ok(match &index {
+        Index::BOOLEAN(index) => any_page(&index.indexes, filter, |&v| bool_scalar(v, datatype)),
+        Index::INT32(index) => {
+            any_page(&index.indexes, filter, |&v| int32_scalar(v, datatype, desc))
+        }
+        Index::INT64(index) => {
+            any_page(&index.indexes, filter, |&v| int64_scalar(v, datatype, desc))
+        }
+        Index::FLOAT(index) => any_page(&index.indexes, filter, |&v| float_scalar(v, datatype)),
+        Index::DOUBLE(index) => any_page(&index.indexes, filter, |&v| double_scalar(v, datatype)),
+        Index::BYTE_ARRAY(index) => {
+            any_page(&index.indexes, filter, |v| byte_array_scalar(v, datatype))
+        }
+        Index::NONE | Index::INT96(_) | Index::FIXED_LEN_BYTE_ARRAY(_) => true,
+    })
+}
+
+/// Check if a column chunk could contain a value using the chunk's bloom
+/// filter.
+///
+/// Returns true if the chunk doesn't have a bloom filter, or if the value
+/// can't be checked against it.
+async fn bloom_filter_could_match(
+    source: &mut dyn FileSource,
+    chunk: &ColumnChunkMetaData,
+    datatype: &DataType,
+    value: &ScalarValue<'_>,
+) -> Result<bool> {
+    // The value needs to be hashed using the same physical representation it
+    // was written with.
+    if value.datatype() != *datatype {
+        return Ok(true);
+    }
+
+    let offset = match chunk.bloom_filter_offset() {
+        Some(offset) => offset as usize,
+        None => return Ok(true),
+    };
+
+    let buf = match chunk.bloom_filter_length() {
+        Some(len) => source.read_range(offset, len as usize).await?,
+        None => {
+            // Older writers don't write the length, read the header first to
+            // get the size of the bitset.
+            let header = source.read_range(offset, SBBF_HEADER_SIZE_ESTIMATE).await?;
+            let (header, header_len) = read_bloom_filter_header_and_length(header)
+                .context("failed to read bloom filter header")?;
+            let len = header_len as usize + header.num_bytes as usize;
+            source.read_range(offset, len).await?
+        }
+    };
+    let sbbf = Sbbf::try_from_bytes(buf).context("failed to read bloom filter")?;
+
+    Ok(match (chunk.column_type(), value) {
+        (PhysicalType::BOOLEAN, ScalarValue::Boolean(v)) => sbbf.check(v),
+        (PhysicalType::INT32, ScalarValue::Int8(v)) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Int16(v)) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Int32(v)) => sbbf.check(v),
+        (PhysicalType::INT32, ScalarValue::UInt8(v)) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::UInt16(v)) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::UInt32(v)) => sbbf.check(&(*v as i32)),
+        (PhysicalType::INT32, ScalarValue::Date32(v)) => sbbf.check(v),
+        (PhysicalType::INT32, ScalarValue::Decimal64(v)) => sbbf.check(&(v.value as i32)),
+        (PhysicalType::INT64, ScalarValue::Int64(v)) => sbbf.check(v),
+        (PhysicalType::INT64, ScalarValue::UInt64(v)) => sbbf.check(&(*v as i64)),
+        (PhysicalType::INT64, ScalarValue::Date64(v)) => sbbf.check(v),
+        // TIME values are rescaled when read, only check values that are
+        // stored as is.
+        (PhysicalType::INT64, ScalarValue::Time64(v))
+            if matches!(time_unit(chunk.column_descr()), ParquetTimeUnit::MICROS(_)) =>
+        {
+            sbbf.check(v)
+        }
+        (PhysicalType::INT64, ScalarValue::Timestamp(v)) => sbbf.check(&v.value),
+        (PhysicalType::INT64, ScalarValue::Decimal64(v)) => sbbf.check(&v.value),
+        (PhysicalType::BYTE_ARRAY, ScalarValue::Utf8(v)) => sbbf.check(&v.as_ref()),
+        (PhysicalType::BYTE_ARRAY, ScalarValue::Binary(v)) => {
+            sbbf.check(&ByteArray::from(v.to_vec()))
+        }
+        // Floats have multiple representations for the same value (0.0 and
+        // -0.0), don't rely on hashing them.
+        _ => true,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use bytes::Bytes;
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+    use rayexec_execution::arrays::field::Field;
+    use rayexec_execution::logical::statistics::StatisticsValue;
+    use rayexec_io::memory::MemoryFileSystem;
+
+    use super::*;
+    use crate::statistics::table_statistics;
+
+    /// Write a file with a single int32 column, with a row group per slice of
+    /// values.
+    fn write_file(row_groups: &[&[i32]], props: WriterProperties) -> Bytes {
+        let schema = Arc::new(parse_message_type("message test { REQUIRED INT32 a; }").unwrap());
+        let mut writer = SerializedFileWriter::new(Vec::new(), schema, Arc::new(props)).unwrap();
+
+        for values in row_groups {
+            let mut group = writer.next_row_group().unwrap();
+            let mut col = group.next_column().unwrap().unwrap();
+            col.typed::<i32>().write_batch(values, None, None).unwrap();
+            col.close().unwrap();
+            group.close().unwrap();
+        }
+
+        writer.into_inner().unwrap().into()
+    }
+
+    fn eq_filter(value: i32) -> ScanFilter {
+        ScanFilter {
+            column: 0,
+            filter: ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Eq,
+                constant: ScalarValue::Int32(value),
+            },
+        }
+    }
+
+    fn pruner_and_source(
+        buf: Bytes,
+        datatype: DataType,
+        filters: Vec<ScanFilter>,
+    ) -> (RowGroupPruner, Box<dyn FileSource>) {
+        let fs = MemoryFileSystem::default();
+        fs.register_file(Path::new("test.parquet"), buf.clone())
+            .unwrap();
+        let mut source = fs.file_source(Path::new("test.parquet")).unwrap();
+
+        let size = buf.len();
+        let metadata =
+            futures::executor::block_on(Metadata::new_from_source(source.as_mut(), size)).unwrap();
+
+        let schema = Schema::new([Field::new("a", datatype, false)]);
+        let pruner = RowGroupPruner::new(Arc::new(metadata), &schema, filters);
+
+        (pruner, source)
+    }
+
+    #[test]
+    fn prune_with_statistics() {
+        let buf = write_file(
+            &[&[1, 2, 3], &[4, 5, 6], &[7, 8, 9]],
+            WriterProperties::new(),
+        );
+        let (pruner, _) = pruner_and_source(buf, DataType::Int32, vec![eq_filter(5)]);
+
+        let mut row_groups = VecDeque::from([0, 1, 2]);
+        assert_eq!(2, pruner.prune_with_statistics(&mut row_groups));
+        assert_eq!(VecDeque::from([1]), row_groups);
+    }
+
+    #[test]
+    fn prune_with_page_index() {
+        // Values in the gap between pages.
+        let props = WriterProperties::builder()
+            .set_data_page_row_count_limit(2)
+            .set_write_batch_size(2)
+            .build();
+        let buf = write_file(&[&[1, 2, 8, 9]], props);
+        let (pruner, mut source) = pruner_and_source(buf, DataType::Int32, vec![eq_filter(5)]);
+
+        let mut row_groups = VecDeque::from([0]);
+        assert_eq!(0, pruner.prune_with_statistics(&mut row_groups));
+
+        let pruned = futures::executor::block_on(
+            pruner.prune_with_indexes(source.as_mut(), &mut row_groups),
+        )
+        .unwrap();
+        assert_eq!((1, 0), pruned);
+        assert!(row_groups.is_empty());
+    }
+
+    #[test]
+    fn prune_with_bloom_filter() {
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .set_statistics_enabled(parquet::file::properties::EnabledStatistics::Chunk)
+            .build();
+        let buf = write_file(&[&[1, 3, 9], &[1, 5, 9]], props);
+        let (pruner, mut source) = pruner_and_source(buf, DataType::Int32, vec![eq_filter(5)]);
+
+        let mut row_groups = VecDeque::from([0, 1]);
+        assert_eq!(0, pruner.prune_with_statistics(&mut row_groups));
+
+        let pruned = futures::executor::block_on(
+            pruner.prune_with_indexes(source.as_mut(), &mut row_groups),
+        )
+        .unwrap();
+        assert_eq!((0, 1), pruned);
+        assert_eq!(VecDeque::from([1]), row_groups);
+    }
+
+    #[test]
+    fn time_nanos_statistics_use_micros() {
+        let schema = Arc::new(
+            parse_message_type("message test { REQUIRED INT64 a (TIME(NANOS,true)); }").unwrap(),
+        );
+        let props = WriterProperties::builder()
+            .set_bloom_filter_enabled(true)
+            .build();
+        let mut writer = SerializedFileWriter::new(Vec::new(), schema, Arc::new(props)).unwrap();
+        let mut group = writer.next_row_group().unwrap();
+        let mut col = group.next_column().unwrap().unwrap();
+        col.typed::<i64>()
+            .write_batch(&[1_000_000_000, 2_000_000_000, 3_000_000_000], None, None)
+            .unwrap();
+        col.close().unwrap();
+        group.close().unwrap();
+        let buf: Bytes = writer.into_inner().unwrap().into();
+
+        // 2 seconds, in microseconds.
+        let filter = ScanFilter {
+            column: 0,
+            filter: ScanFilterType::ConstComparison {
+                op: ComparisonOperator::Eq,
+                constant: ScalarValue::Time64(2_000_000),
+            },
+        };
+        let (pruner, mut source) = pruner_and_source(buf, DataType::Time64, vec![filter]);
+
+        let stats = &table_statistics(
+            &pruner.metadata.decoded_metadata,
+            &Schema::new([Field::new("a", DataType::Time64, false)]),
+        )
+        .columns[0];
+        assert_eq!(
+            StatisticsValue::Exact(ScalarValue::Time64(1_000_000)),
+            stats.min
+        );
+        assert_eq!(
+            StatisticsValue::Exact(ScalarValue::Time64(3_000_000)),
+            stats.max
+        );
+
+        let mut row_groups = VecDeque::from([0]);
+        assert_eq!(0, pruner.prune_with_statistics(&mut row_groups));
+
+        let pruned = futures::executor::block_on(
+            pruner.prune_with_indexes(source.as_mut(), &mut row_groups),
+        )
+        .unwrap();
+        assert_eq!((0, 0), pruned);
+        assert_eq!(VecDeque::from([0]), row_groups);
+    }
+}
@@ -1,11 +1,16 @@
+pub mod nested;
 pub mod primitive;
 pub mod varlen;
 
+#[cfg(test)]
+mod test_util;
+
 use std::collections::VecDeque;
 use std::fmt::{self, Debug};
 use std::sync::Arc;
 
 use bytes::{Buf, Bytes};
+use nested::LevelNode;
 use parquet::basic::Type as PhysicalType;
 use parquet::column::page::PageReader;
 use parquet::column::reader::decoder::{
@@ -26,13 +31,50 @@ use rayexec_execution::arrays::datatype::DataType;
 use rayexec_execution::arrays::field::Schema;
 use rayexec_execution::storage::table_storage::Projections;
 use rayexec_io::FileSource;
-use varlen::VarlenArrayReader;
+use varlen::{FixedLenArrayReader, VarlenArrayReader};
 
 use crate::metadata::Metadata;
 
+/// An array read from a single leaf column along with its levels.
+#[derive(Debug)]
+pub struct LeafArray {
+    /// Array containing a value for every level read. Levels below the
+    /// column's max definition level are null.
+    pub array: Array,
+    /// Definition levels, None if the column has a max definition level of 0.
+    pub def_levels: Option<Vec<i16>>,
+    /// Repetition levels, None if the column has a max repetition level of 0.
+    pub rep_levels: Option<Vec<i16>>,
+}
+
+impl LeafArray {
+    /// Number of levels read.
+    pub fn len(&self) -> usize {
+        self.array.logical_len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn def_level(&self, idx: usize) -> i16 {
+        self.def_levels
+            .as_ref()
+            .map(|levels| levels[idx])
+            .unwrap_or(0)
+    }
+
+    pub fn rep_level(&self, idx: usize) -> i16 {
+        self.rep_levels
+            .as_ref()
+            .map(|levels| levels[idx])
+            .unwrap_or(0)
+    }
+}
+
 pub trait ArrayBuilder<P: PageReader>: Send {
     /// Consume the current buffer and build an array.
-    fn build(&mut self) -> Result<Array>;
+    fn build(&mut self) -> Result<LeafArray>;
 
     /// Sets the page reader the builder should now be reading from.
     fn set_page_reader(&mut self, page_reader: P) -> Result<()>;
@@ -53,21 +95,36 @@ where
     P: PageReader + 'static,
 {
     match (&datatype, physical) {
+        (_, PhysicalType::FIXED_LEN_BYTE_ARRAY) => Ok(Box::new(FixedLenArrayReader::<P>::new(
+            batch_size, datatype, desc,
+        ))),
         (DataType::Boolean, _) => Ok(Box::new(PrimitiveArrayReader::<bool, P>::new(
             batch_size, datatype, desc,
         ))),
+        (DataType::Int8, _) => Ok(Box::new(PrimitiveArrayReader::<i32, P>::new(
+            batch_size, datatype, desc,
+        ))),
         (DataType::Int16, _) => Ok(Box::new(PrimitiveArrayReader::<i32, P>::new(
             batch_size, datatype, desc,
         ))),
         (DataType::Int32, _) => Ok(Box::new(PrimitiveArrayReader::<i32, P>::new(
             batch_size, datatype, desc,
         ))),
+        (DataType::UInt8, PhysicalType::INT32) => Ok(Box::new(
+            PrimitiveArrayReader::<i32, P>::new(batch_size, datatype, desc),
+        )),
         (DataType::UInt16, PhysicalType::INT32) => Ok(Box::new(
             PrimitiveArrayReader::<i32, P>::new(batch_size, datatype, desc),
         )),
+        (DataType::UInt32, PhysicalType::INT32) => Ok(Box::new(
+            PrimitiveArrayReader::<i32, P>::new(batch_size, datatype, desc),
+        )),
         (DataType::Int64, _) => Ok(Box::new(PrimitiveArrayReader::<i64, P>::new(
             batch_size, datatype, desc,
         ))),
+        (DataType::UInt64, PhysicalType::INT64) => Ok(Box::new(
+            PrimitiveArrayReader::<i64, P>::new(batch_size, datatype, desc),
+        )),
         (DataType::Timestamp(_), PhysicalType::INT64) => Ok(Box::new(
             PrimitiveArrayReader::<i64, P>::new(batch_size, datatype, desc),
         )),
@@ -85,6 +142,12 @@ where
         (DataType::Date32, _) => Ok(Box::new(PrimitiveArrayReader::<i32, P>::new(
             batch_size, datatype, desc,
         ))),
+        (DataType::Time64, PhysicalType::INT32) => Ok(Box::new(
+            PrimitiveArrayReader::<i32, P>::new(batch_size, datatype, desc),
+        )),
+        (DataType::Time64, PhysicalType::INT64) => Ok(Box::new(
+            PrimitiveArrayReader::<i64, P>::new(batch_size, datatype, desc),
+        )),
         (DataType::Decimal64(_), PhysicalType::INT32) => Ok(Box::new(
             PrimitiveArrayReader::<i32, P>::new(batch_size, datatype, desc),
         )),
@@ -101,6 +164,12 @@ where
                 batch_size, datatype, desc,
             )))
         }
+        (DataType::Decimal64(_), PhysicalType::BYTE_ARRAY) => Ok(Box::new(
+            VarlenArrayReader::<P>::new(batch_size, datatype, desc),
+        )),
+        (DataType::Decimal128(_), PhysicalType::BYTE_ARRAY) => Ok(Box::new(
+            VarlenArrayReader::<P>::new(batch_size, datatype, desc),
+        )),
         (DataType::Utf8, _) => Ok(Box::new(VarlenArrayReader::<P>::new(
             batch_size, datatype, desc,
         ))),
@@ -118,12 +187,12 @@ pub trait IntoArrayData {
     fn into_array_data(self) -> ArrayData2;
 }
 
-pub fn def_levels_into_bitmap(def_levels: Vec<i16>) -> Bitmap {
-    Bitmap::from_iter(def_levels.into_iter().map(|v| match v {
-        0 => false,
-        1 => true,
-        other => unimplemented!("nested unimplemented, def level: {other}"),
-    }))
+/// Create a validity bitmap from definition levels.
+///
+/// Only values at the max definition level are valid. Anything below is
+/// either null itself, or has a null (or empty) ancestor.
+pub fn def_levels_into_bitmap(def_levels: &[i16], max_def_level: i16) -> Bitmap {
+    Bitmap::from_iter(def_levels.iter().map(|&v| v == max_def_level))
 }
 
 /// Insert null (meaningless) values into the vec according to the validity
@@ -134,10 +203,11 @@ pub fn insert_null_values<T>(values: &mut Vec<T>, bitmap: &Bitmap)
 where
     T: Copy + Default,
 {
+    let num_values = values.len();
     values.resize(bitmap.len(), T::default());
 
-    for (current_idx, new_idx) in (0..values.len()).rev().zip(bitmap.index_iter().rev()) {
-        if current_idx <= new_idx {
+    for (current_idx, new_idx) in (0..num_values).rev().zip(bitmap.index_iter().rev()) {
+        if current_idx == new_idx {
             break;
         }
         values[new_idx] = values[current_idx];
@@ -161,8 +231,17 @@ pub struct AsyncBatchReader<R: FileSource> {
     column_states: Vec<ColumnState>,
 }
 
+/// State for a single top-level field in the file.
 struct ColumnState {
-    /// Index of the column in the parquet file.
+    /// How arrays read from the leaf columns are assembled into the output
+    /// array.
+    node: LevelNode,
+    /// States for each leaf column making up this field.
+    leaves: Vec<LeafState>,
+}
+
+struct LeafState {
+    /// Index of the leaf column in the parquet file.
     column_idx: usize,
     /// Builder for this column.
     builder: Box<dyn ArrayBuilder<SerializedPageReader<InMemoryColumnChunk>>>,
@@ -180,9 +259,6 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
         projections: Projections,
     ) -> Result<Self> {
         // Create projection bitmap.
-        //
-        // TODO: This will need to change to accomodate structs in parquet
-        // files.
         let bitmap = match &projections.column_indices {
             Some(indices) => {
                 let mut bitmap = Bitmap::new_with_all_false(schema.fields.len());
@@ -194,32 +270,50 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
             None => Bitmap::new_with_all_true(schema.fields.len()),
         };
 
+        let descr = metadata.decoded_metadata.file_metadata().schema_descr();
+        let root_fields = descr.root_schema().get_fields();
+
         let mut states = Vec::with_capacity(schema.fields.len());
 
-        for (col_idx, ((datatype, column_chunk_meta), projected)) in schema
-            .iter()
-            .map(|f| f.datatype.clone())
-            .zip(metadata.decoded_metadata.row_group(0).columns())
-            .zip(bitmap.iter())
-            .enumerate()
-        {
-            if projected {
-                let physical = column_chunk_meta.column_type();
-                let builder = builder_for_type(
-                    batch_size,
-                    datatype,
-                    physical,
-                    column_chunk_meta.column_descr_ptr(),
-                )?;
-
-                let state = ColumnState {
-                    column_idx: col_idx,
-                    builder,
-                    column_chunk: None,
-                };
-
-                states.push(state)
+        for (field_idx, (field, projected)) in schema.fields.iter().zip(bitmap.iter()).enumerate() {
+            if !projected {
+                continue;
+            }
+
+            let parquet_type = root_fields.get(field_idx).ok_or_else(|| {
+                RayexecError::new(format!("Missing parquet field for {}", field.name))
+            })?;
+            let (node, leaf_types) = LevelNode::try_new(parquet_type, &field.datatype)?;
+
+            let column_indices: Vec<_> = (0..descr.num_columns())
+                .filter(|&leaf| descr.get_column_root_idx(leaf) == field_idx)
+                .collect();
+            if column_indices.len() != leaf_types.len() {
+                return Err(RayexecError::new(format!(
+                    "Expected {} leaf columns for {}, got {}",
+                    leaf_types.len(),
+                    field.name,
+                    column_indices.len()
+                )));
             }
+
+            let leaves = column_indices
+                .into_iter()
+                .zip(leaf_types)
+                .map(|(column_idx, datatype)| {
+                    let desc = descr.column(column_idx);
+                    let builder =
+                        builder_for_type(batch_size, datatype, desc.physical_type(), desc)?;
+
+                    Ok(LeafState {
+                        column_idx,
+                        builder,
+                        column_chunk: None,
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+
+            states.push(ColumnState { node, leaves })
         }
 
         Ok(AsyncBatchReader {
@@ -267,13 +361,21 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
     ///
     /// Returns Ok(None) when there's nothing left to read.
     fn maybe_read_batch(&mut self) -> Result<Option<Batch>> {
-        for state in self.column_states.iter_mut() {
-            state.builder.read_rows(self.batch_size)?;
-        }
         let arrays = self
             .column_states
             .iter_mut()
-            .map(|state| state.builder.build())
+            .map(|state| {
+                let leaves = state
+                    .leaves
+                    .iter_mut()
+                    .map(|leaf| {
+                        leaf.builder.read_rows(self.batch_size)?;
+                        leaf.builder.build()
+                    })
+                    .collect::<Result<Vec<_>>>()?;
+
+                state.node.build(&leaves)
+            })
             .collect::<Result<Vec<_>>>()?;
 
         let batch = Batch::try_from_arrays(arrays)?;
@@ -286,30 +388,34 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
     }
 
     fn set_page_readers(&mut self) -> Result<()> {
-        for state in self.column_states.iter_mut() {
-            let row_group = self.current_row_group.expect("current row group to be set");
+        let row_group = self.current_row_group.expect("current row group to be set");
+        let row_group_meta = self.metadata.decoded_metadata.row_group(row_group);
+
+        for leaf in self
+            .column_states
+            .iter_mut()
+            .flat_map(|state| state.leaves.iter_mut())
+        {
             let locations = self
                 .metadata
                 .decoded_metadata
                 .offset_index()
-                .map(|row_groups| row_groups[row_group][state.column_idx].clone());
-
-            let row_group_meta = self.metadata.decoded_metadata.row_group(row_group);
+                .map(|row_groups| row_groups[row_group][leaf.column_idx].clone());
 
-            let chunk = match std::mem::take(&mut state.column_chunk) {
+            let chunk = match std::mem::take(&mut leaf.column_chunk) {
                 Some(chunk) => Arc::new(chunk),
                 None => return Err(RayexecError::new("Expected column chunk")),
             };
 
             let page_reader = SerializedPageReader::new(
                 chunk,
-                row_group_meta.column(state.column_idx),
+                row_group_meta.column(leaf.column_idx),
                 row_group_meta.num_rows() as usize,
                 locations,
             )
             .context("failed to create serialize page reader")?;
 
-            state.builder.set_page_reader(page_reader)?;
+            leaf.builder.set_page_reader(page_reader)?;
         }
 
         Ok(())
@@ -317,23 +423,29 @@ impl<R: FileSource + 'static> AsyncBatchReader<R> {
 
     /// Fetches the column chunks for the current row group.
     async fn fetch_column_chunks(&mut self) -> Result<()> {
-        for state in self.column_states.iter_mut() {
+        let row_group = self.current_row_group.expect("current row group to be set");
+
+        for leaf in self
+            .column_states
+            .iter_mut()
+            .flat_map(|state| state.leaves.iter_mut())
+        {
             // We already have data for this.
-            if state.column_chunk.is_some() {
+            if leaf.column_chunk.is_some() {
                 continue;
             }
 
             let col = self
                 .metadata
                 .decoded_metadata
-                .row_group(self.current_row_group.expect("current row group to be set"))
-                .column(state.column_idx);
+                .row_group(row_group)
+                .column(leaf.column_idx);
             let (start, len) = col.byte_range();
 
             // TODO: Parallel reads.
             let buf = self.reader.read_range(start as usize, len as usize).await?;
 
-            state.column_chunk = Some(InMemoryColumnChunk {
+            leaf.column_chunk = Some(InMemoryColumnChunk {
                 offset: start as usize,
                 buf,
             })
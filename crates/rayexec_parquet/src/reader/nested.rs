@@ -0,0 +1,539 @@
+use parquet::basic::{ConvertedType, Repetition};
+use parquet::schema::types::Type;
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::array::Array;
+use rayexec_execution::arrays::bitmap::Bitmap;
+use rayexec_execution::arrays::datatype::DataType;
+use rayexec_execution::arrays::executor::scalar::interleave;
+use rayexec_execution::arrays::storage::{ListItemMetadata2, ListStorage, StructStorage};
+
+use super::LeafArray;
+use crate::schema::{list_element, repeated_field, ListElement};
+
+/// Describes how the leaf columns for a single top-level field are assembled
+/// into an array using definition and repetition levels.
+///
+/// Nodes are assembled using the levels of the first leaf in their subtree.
+/// Every leaf below a node agrees on the levels up to and including that node,
+/// so which leaf is used doesn't matter.
+#[derive(Debug, Clone, PartialEq)]
+pub enum LevelNode {
+    /// A leaf column.
+    Leaf {
+        /// Index of the leaf within the field.
+        leaf: usize,
+    },
+    Struct {
+        datatype: DataType,
+        /// Definition level at which the struct is non-null.
+        def_level: i16,
+        children: Vec<LevelNode>,
+    },
+    List {
+        datatype: DataType,
+        /// Definition level at which the list is non-null.
+        def_level: i16,
+        /// Definition level at which the list has at least one element.
+        element_def_level: i16,
+        /// Repetition level indicating a new element in this list.
+        element_rep_level: i16,
+        child: Box<LevelNode>,
+    },
+}
+
+impl LevelNode {
+    /// Create the level tree for a top-level field in the parquet schema.
+    ///
+    /// Also returns the data types for each leaf column in the field, in the
+    /// same order as the leaf columns in the file.
+    pub fn try_new(parquet_type: &Type, datatype: &DataType) -> Result<(Self, Vec<DataType>)> {
+        let mut leaves = Vec::new();
+        let node = Self::from_field(parquet_type, datatype, 0, 0, &mut leaves)?;
+        Ok((node, leaves))
+    }
+
+    /// Assemble an array from the arrays and levels read for each leaf.
+    pub fn build(&self, leaves: &[LeafArray]) -> Result<Array> {
+        self.build_slots(leaves, 0, 0)
+    }
+
+    /// Build a node taking into account its repetition and definition levels.
+    fn from_field(
+        parquet_type: &Type,
+        datatype: &DataType,
+        def: i16,
+        rep: i16,
+        leaves: &mut Vec<DataType>,
+    ) -> Result<Self> {
+        let info = parquet_type.get_basic_info();
+        let repetition = if info.has_repetition() {
+            info.repetition()
+        } else {
+            Repetition::REQUIRED
+        };
+
+        match repetition {
+            Repetition::REQUIRED => Self::from_type(parquet_type, datatype, def, rep, leaves),
+            Repetition::OPTIONAL => Self::from_type(parquet_type, datatype, def + 1, rep, leaves),
+            Repetition::REPEATED => {
+                // Repeated field outside of a LIST, each repetition is an
+                // element.
+                let child = Self::from_type(
+                    parquet_type,
+                    list_element_datatype(datatype)?,
+                    def + 1,
+                    rep + 1,
+                    leaves,
+                )?;
+
+                Ok(LevelNode::List {
+                    datatype: datatype.clone(),
+                    def_level: def,
+                    element_def_level: def + 1,
+                    element_rep_level: rep + 1,
+                    child: Box::new(child),
+                })
+            }
+        }
+    }
+
+    /// Build a node for a type, where `def` and `rep` already account for the
+    /// type's repetition.
+    fn from_type(
+        parquet_type: &Type,
+        datatype: &DataType,
+        def: i16,
+        rep: i16,
+        leaves: &mut Vec<DataType>,
+    ) -> Result<Self> {
+        match parquet_type {
+            Type::PrimitiveType { .. } => {
+                leaves.push(datatype.clone());
+                Ok(LevelNode::Leaf {
+                    leaf: leaves.len() - 1,
+                })
+            }
+            Type::GroupType { basic_info, fields } => match basic_info.converted_type() {
+                ConvertedType::LIST => {
+                    let repeated = repeated_field(parquet_type, fields)?;
+                    let element_type = list_element_datatype(datatype)?;
+
+                    let child = match list_element(parquet_type.name(), repeated) {
+                        ListElement::Repeated(repeated) => Self::from_list_element(
+                            repeated,
+                            element_type,
+                            def + 1,
+                            rep + 1,
+                            leaves,
+                        )?,
+                        ListElement::Child(child) => {
+                            Self::from_field(child, element_type, def + 1, rep + 1, leaves)?
+                        }
+                    };
+
+                    Ok(LevelNode::List {
+                        datatype: datatype.clone(),
+                        def_level: def,
+                        element_def_level: def + 1,
+                        element_rep_level: rep + 1,
+                        child: Box::new(child),
+                    })
+                }
+                ConvertedType::MAP | ConvertedType::MAP_KEY_VALUE => {
+                    let key_value = repeated_field(parquet_type, fields)?;
+                    let child = Self::from_list_element(
+                        key_value,
+                        list_element_datatype(datatype)?,
+                        def + 1,
+                        rep + 1,
+                        leaves,
+                    )?;
+
+                    Ok(LevelNode::List {
+                        datatype: datatype.clone(),
+                        def_level: def,
+                        element_def_level: def + 1,
+                        element_rep_level: rep + 1,
+                        child: Box::new(child),
+                    })
+                }
+                _ => Self::from_struct(parquet_type, datatype, def, rep, leaves),
+            },
+        }
+    }
+
+    /// Build a node for a repeated field acting as a list element.
+    ///
+    /// Groups are always treated as structs regardless of annotation.
+    fn from_list_element(
+        repeated: &Type,
+        datatype: &DataType,
+        def: i16,
+        rep: i16,
+        leaves: &mut Vec<DataType>,
+    ) -> Result<Self> {
+        if repeated.is_primitive() {
+            return Self::from_type(repeated, datatype, def, rep, leaves);
+        }
+        Self::from_struct(repeated, datatype, def, rep, leaves)
+    }
+
+    fn from_struct(
+        group: &Type,
+        datatype: &DataType,
+        def: i16,
+        rep: i16,
+        leaves: &mut Vec<DataType>,
+    ) -> Result<Self> {
+        let meta = match datatype {
+            DataType::Struct(meta) => meta,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected struct data type for parquet group '{}', got {other}",
+                    group.name()
+                )))
+            }
+        };
+
+        let fields = group.get_fields();
+        if fields.len() != meta.fields.len() {
+            return Err(RayexecError::new(format!(
+                "Struct field count mismatch for parquet group '{}', expected {}, got {}",
+                group.name(),
+                meta.fields.len(),
+                fields.len(),
+            )));
+        }
+
+        let children = fields
+            .iter()
+            .zip(&meta.fields)
+            .map(|(field, exec_field)| {
+                Self::from_field(field, &exec_field.datatype, def, rep, leaves)
+            })
+            .collect::<Result<Vec<_>>>()?;
+
+        if children.is_empty() {
+            return Err(RayexecError::new(format!(
+                "Parquet group '{}' has no fields",
+                group.name()
+            )));
+        }
+
+        Ok(LevelNode::Struct {
+            datatype: datatype.clone(),
+            def_level: def,
+            children,
+        })
+    }
+
+    /// Index of the first leaf in this node's subtree.
+    fn first_leaf(&self) -> usize {
+        match self {
+            LevelNode::Leaf { leaf } => *leaf,
+            LevelNode::Struct { children, .. } => children[0].first_leaf(),
+            LevelNode::List { child, .. } => child.first_leaf(),
+        }
+    }
+
+    /// Build the array for this node.
+    ///
+    /// A level starts a new value (slot) for this node if its repetition level
+    /// is at most `rep_slot` and its definition level is at least `def_slot`.
+    /// Levels with a lower definition level indicate a null or empty list
+    /// ancestor, and so don't have a slot at this node.
+    fn build_slots(&self, leaves: &[LeafArray], rep_slot: i16, def_slot: i16) -> Result<Array> {
+        match self {
+            LevelNode::Leaf { leaf } => {
+                let leaf = get_leaf(leaves, *leaf)?;
+                if def_slot == 0 {
+                    // Every level is a slot.
+                    return Ok(leaf.array.clone());
+                }
+
+                let indices: Vec<_> = (0..leaf.len())
+                    .filter(|&idx| leaf.def_level(idx) >= def_slot)
+                    .map(|idx| (0, idx))
+                    .collect();
+
+                interleave(&[&leaf.array], &indices)
+            }
+            LevelNode::Struct {
+                datatype,
+                def_level,
+                children,
+            } => {
+                let levels = get_leaf(leaves, self.first_leaf())?;
+                let validity = Bitmap::from_iter(
+                    (0..levels.len())
+                        .filter(|&idx| {
+                            levels.rep_level(idx) <= rep_slot && levels.def_level(idx) >= def_slot
+                        })
+                        .map(|idx| levels.def_level(idx) >= *def_level),
+                );
+
+                let children = children
+                    .iter()
+                    .map(|child| child.build_slots(leaves, rep_slot, def_slot))
+                    .collect::<Result<Vec<_>>>()?;
+
+                let len = validity.len();
+
+                Ok(Array::new_with_validity_and_array_data(
+                    datatype.clone(),
+                    validity,
+                    StructStorage::try_new(children, len)?,
+                ))
+            }
+            LevelNode::List {
+                datatype,
+                def_level,
+                element_def_level,
+                element_rep_level,
+                child,
+            } => {
+                let levels = get_leaf(leaves, self.first_leaf())?;
+
+                let mut metadata: Vec<ListItemMetadata2> = Vec::new();
+                let mut validity = Vec::new();
+                let mut num_elements = 0;
+                // Whether the current level is part of a list in this array.
+                let mut in_list = false;
+
+                for idx in 0..levels.len() {
+                    let def = levels.def_level(idx);
+                    let rep = levels.rep_level(idx);
+
+                    if rep <= rep_slot {
+                        in_list = def >= def_slot;
+                        if in_list {
+                            metadata.push(ListItemMetadata2 {
+                                offset: num_elements,
+                                len: 0,
+                            });
+                            validity.push(def >= *def_level);
+                        }
+                    }
+
+                    if in_list && rep <= *element_rep_level && def >= *element_def_level {
+                        // Last metadata is always the current list.
+                        metadata.last_mut().expect("list metadata to exist").len += 1;
+                        num_elements += 1;
+                    }
+                }
+
+                let child = child.build_slots(leaves, *element_rep_level, *element_def_level)?;
+                if child.logical_len() != num_elements as usize {
+                    return Err(RayexecError::new(
+                        "List child array length doesn't match number of elements",
+                    )
+                    .with_field("expected", num_elements)
+                    .with_field("got", child.logical_len()));
+                }
+
+                Ok(Array::new_with_validity_and_array_data(
+                    datatype.clone(),
+                    Bitmap::from_iter(validity),
+                    ListStorage::try_new(metadata, child)?,
+                ))
+            }
+        }
+    }
+}
+
+fn get_leaf(leaves: &[LeafArray], leaf: usize) -> Result<&LeafArray> {
+    leaves
+        .get(leaf)
+        .ok_or_else(|| RayexecError::new(format!("Missing leaf array {leaf}")))
+}
+
+fn list_element_datatype(datatype: &DataType) -> Result<&DataType> {
+    match datatype {
+        DataType::List(meta) => Ok(&meta.datatype),
+        other => Err(RayexecError::new(format!(
+            "Expected list data type for repeated parquet field, got {other}"
+        ))),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::path::Path;
+
+    use rayexec_execution::arrays::batch::Batch;
+    use rayexec_execution::arrays::datatype::{ListTypeMeta, StructTypeMeta};
+    use rayexec_execution::arrays::field::{Field, Schema};
+    use rayexec_io::memory::MemoryFileSystem;
+
+    use super::*;
+    use crate::reader::test_util::{read_file, write_file, Leaf};
+    use crate::writer::AsyncBatchWriter;
+
+    #[test]
+    fn two_level_list() {
+        let buf = write_file(
+            "message test {
+                OPTIONAL GROUP a (LIST) {
+                    REPEATED INT32 element;
+                }
+            }",
+            &[Leaf::Int32(&[1, 2, 3], &[2, 2, 1, 0, 2], &[0, 1, 0, 0, 0])],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        assert_eq!(
+            DataType::List(ListTypeMeta::new(DataType::Int32)),
+            schema.fields[0].datatype
+        );
+        assert_eq!(vec!["[1, 2]", "[]", "NULL", "[3]"], columns[0]);
+    }
+
+    #[test]
+    fn map() {
+        let buf = write_file(
+            "message test {
+                OPTIONAL GROUP m (MAP) {
+                    REPEATED GROUP key_value {
+                        REQUIRED BYTE_ARRAY key (UTF8);
+                        OPTIONAL INT32 value;
+                    }
+                }
+            }",
+            &[
+                Leaf::Utf8(&["a", "b"], &[2, 2, 0, 1], &[0, 1, 0, 0]),
+                Leaf::Int32(&[1], &[3, 2, 0, 1], &[0, 1, 0, 0]),
+            ],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        let expected_type = DataType::List(ListTypeMeta::new(DataType::Struct(StructTypeMeta {
+            fields: vec![
+                Field::new("key", DataType::Utf8, false),
+                Field::new("value", DataType::Int32, true),
+            ],
+        })));
+        assert_eq!(expected_type, schema.fields[0].datatype);
+        assert_eq!(
+            vec!["[{key: a, value: 1}, {key: b, value: NULL}]", "NULL", "[]"],
+            columns[0]
+        );
+    }
+
+    #[test]
+    fn repeated_group_without_annotation() {
+        let buf = write_file(
+            "message test {
+                REQUIRED INT32 id;
+                REPEATED GROUP phone {
+                    REQUIRED INT64 number;
+                    OPTIONAL BYTE_ARRAY kind (UTF8);
+                }
+            }",
+            &[
+                Leaf::Int32(&[1, 2], &[], &[]),
+                Leaf::Int64(&[1, 2], &[1, 1, 0], &[0, 1, 0]),
+                Leaf::Utf8(&["home"], &[2, 1, 0], &[0, 1, 0]),
+            ],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        let expected_type = DataType::List(ListTypeMeta::new(DataType::Struct(StructTypeMeta {
+            fields: vec![
+                Field::new("number", DataType::Int64, false),
+                Field::new("kind", DataType::Utf8, true),
+            ],
+        })));
+        assert_eq!(expected_type, schema.fields[1].datatype);
+        assert_eq!(vec!["1", "2"], columns[0]);
+        assert_eq!(
+            vec!["[{number: 1, kind: home}, {number: 2, kind: NULL}]", "[]"],
+            columns[1]
+        );
+    }
+
+    #[test]
+    fn round_trip_nested_lists_across_batches() {
+        // List<List<Int32>> where every third row is null, and every fifth
+        // inner list is null.
+        let num_rows: usize = 3000;
+        let mut inner_metadata = Vec::new();
+        let mut inner_validity = Vec::new();
+        let mut values = Vec::new();
+        let mut outer_metadata = Vec::new();
+        let mut outer_validity = Vec::new();
+        let mut expected = Vec::new();
+
+        for row in 0..num_rows {
+            if row % 3 == 0 {
+                outer_metadata.push(ListItemMetadata2::default());
+                outer_validity.push(false);
+                expected.push("NULL".to_string());
+                continue;
+            }
+
+            let num_inner = row % 4;
+            outer_metadata.push(ListItemMetadata2 {
+                offset: inner_metadata.len() as i32,
+                len: num_inner as i32,
+            });
+            outer_validity.push(true);
+
+            let mut inner_strs = Vec::new();
+            for inner in 0..num_inner {
+                if (row + inner) % 5 == 0 {
+                    inner_metadata.push(ListItemMetadata2::default());
+                    inner_validity.push(false);
+                    inner_strs.push("NULL".to_string());
+                    continue;
+                }
+
+                let inner_vals: Vec<_> = (0..inner + 1).map(|v| (row + v) as i32).collect();
+                inner_metadata.push(ListItemMetadata2 {
+                    offset: values.len() as i32,
+                    len: inner_vals.len() as i32,
+                });
+                inner_validity.push(true);
+                inner_strs.push(format!(
+                    "[{}]",
+                    inner_vals
+                        .iter()
+                        .map(|v| v.to_string())
+                        .collect::<Vec<_>>()
+                        .join(", ")
+                ));
+                values.extend(inner_vals);
+            }
+            expected.push(format!("[{}]", inner_strs.join(", ")));
+        }
+
+        let inner_type = DataType::List(ListTypeMeta::new(DataType::Int32));
+        let outer_type = DataType::List(ListTypeMeta::new(inner_type.clone()));
+
+        let inner = Array::new_with_validity_and_array_data(
+            inner_type,
+            Bitmap::from_iter(inner_validity),
+            ListStorage::try_new(inner_metadata, Array::from_iter(values)).unwrap(),
+        );
+        let outer = Array::new_with_validity_and_array_data(
+            outer_type.clone(),
+            Bitmap::from_iter(outer_validity),
+            ListStorage::try_new(outer_metadata, inner).unwrap(),
+        );
+
+        let schema = Schema::new([Field::new("a", outer_type.clone(), true)]);
+        let fs = MemoryFileSystem::default();
+        let sink = fs.file_sink(Path::new("test.parquet")).unwrap();
+        let mut writer = AsyncBatchWriter::try_new(sink, schema).unwrap();
+        futures::executor::block_on(writer.write(&Batch::try_from_arrays([outer]).unwrap()))
+            .unwrap();
+        futures::executor::block_on(writer.finish()).unwrap();
+
+        let mut source = fs.file_source(Path::new("test.parquet")).unwrap();
+        let size = futures::executor::block_on(source.size()).unwrap();
+        let buf = futures::executor::block_on(source.read_range(0, size)).unwrap();
+
+        let (schema, columns) = read_file(buf, 1000);
+        assert_eq!(outer_type, schema.fields[0].datatype);
+        assert_eq!(expected, columns[0]);
+    }
+}
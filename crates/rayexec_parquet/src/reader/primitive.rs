@@ -1,8 +1,8 @@
-use parquet::basic::Type as PhysicalType;
+use parquet::basic::{TimeUnit as ParquetTimeUnit, Type as PhysicalType};
 use parquet::column::page::PageReader;
 use parquet::column::reader::basic::BasicColumnValueDecoder;
 use parquet::data_type::{DataType as ParquetDataType, Int96};
-use parquet::schema::types::ColumnDescPtr;
+use parquet::schema::types::{ColumnDescPtr, ColumnDescriptor};
 use rayexec_error::{RayexecError, Result};
 use rayexec_execution::arrays::array::{Array, ArrayData2};
 use rayexec_execution::arrays::bitmap::Bitmap;
@@ -16,8 +16,10 @@ use super::{
     insert_null_values,
     ArrayBuilder,
     IntoArrayData,
+    LeafArray,
     ValuesReader,
 };
+use crate::schema::time_unit;
 
 pub struct PrimitiveArrayReader<T: ParquetDataType, P: PageReader> {
     batch_size: usize,
@@ -43,18 +45,19 @@ where
     }
 
     /// Take the currently read values and convert into an array.
-    pub fn take_array(&mut self) -> Result<Array> {
+    pub fn take_array(&mut self) -> Result<LeafArray> {
         let def_levels = self.values_reader.take_def_levels();
-        let _rep_levels = self.values_reader.take_rep_levels();
+        let rep_levels = self.values_reader.take_rep_levels();
+        let max_def_level = self.values_reader.description.max_def_level();
 
         // Basis of the array.
         let mut data =
             std::mem::replace(&mut self.values_buffer, Vec::with_capacity(self.batch_size));
 
         // Insert nulls as needed.
-        let bitmap = match def_levels {
+        let bitmap = match &def_levels {
             Some(levels) => {
-                let bitmap = def_levels_into_bitmap(levels);
+                let bitmap = def_levels_into_bitmap(levels, max_def_level);
                 insert_null_values(&mut data, &bitmap);
                 Some(bitmap)
             }
@@ -68,16 +71,22 @@ where
         // happens, we'll apply cast after building.
         let (array_data, build_type) = match (T::get_physical_type(), &self.datatype) {
             (PhysicalType::BOOLEAN, DataType::Boolean) => (data.into_array_data(), self.datatype.clone()),
+            (PhysicalType::INT32, DataType::Int8) => (data.into_array_data(), DataType::Int32),
             (PhysicalType::INT32, DataType::Int16) => (data.into_array_data(), DataType::Int32),
             (PhysicalType::INT32, DataType::Int32) => (data.into_array_data(), self.datatype.clone()),
+            (PhysicalType::INT32, DataType::UInt8) => (data.into_array_data(), DataType::Int32),
             (PhysicalType::INT32, DataType::UInt16) => (data.into_array_data(), DataType::Int32),
+            (PhysicalType::INT32, DataType::UInt32) => (reinterpret_unsigned(data.into_array_data())?, self.datatype.clone()),
             (PhysicalType::INT32, DataType::Date32) => (data.into_array_data(), self.datatype.clone()),
-            (PhysicalType::INT32, DataType::Decimal64(_)) => (data.into_array_data(), DataType::Int32),
-            (PhysicalType::INT32, DataType::Decimal128(_)) => (data.into_array_data(), DataType::Int32),
+            (PhysicalType::INT32, DataType::Time64) => (time_to_micros(data.into_array_data(), &self.values_reader.description)?, self.datatype.clone()),
+            (PhysicalType::INT32, DataType::Decimal64(_)) => (unscaled_decimal(data.into_array_data(), &self.datatype)?, self.datatype.clone()),
+            (PhysicalType::INT32, DataType::Decimal128(_)) => (unscaled_decimal(data.into_array_data(), &self.datatype)?, self.datatype.clone()),
             (PhysicalType::INT64, DataType::Int64) => (data.into_array_data(), self.datatype.clone()),
+            (PhysicalType::INT64, DataType::UInt64) => (reinterpret_unsigned(data.into_array_data())?, self.datatype.clone()),
             (PhysicalType::INT64, DataType::Decimal64(_)) => (data.into_array_data(), self.datatype.clone()),
-            (PhysicalType::INT64, DataType::Decimal128(_)) => (data.into_array_data(), DataType::Int64), // TODO
+            (PhysicalType::INT64, DataType::Decimal128(_)) => (unscaled_decimal(data.into_array_data(), &self.datatype)?, self.datatype.clone()),
             (PhysicalType::INT64, DataType::Timestamp(_)) => (data.into_array_data(), self.datatype.clone()),
+            (PhysicalType::INT64, DataType::Time64) => (time_to_micros(data.into_array_data(), &self.values_reader.description)?, self.datatype.clone()),
             (PhysicalType::INT96, DataType::Timestamp(_)) => (data.into_array_data(), self.datatype.clone()),
             (PhysicalType::FLOAT, DataType::Float32) => (data.into_array_data(), self.datatype.clone()),
             (PhysicalType::DOUBLE, DataType::Float64) => (data.into_array_data(), self.datatype.clone()),
@@ -95,7 +104,11 @@ where
             array = cast_array(&array, self.datatype.clone(), CastFailBehavior::Null)?;
         }
 
-        Ok(array)
+        Ok(LeafArray {
+            array,
+            def_levels,
+            rep_levels,
+        })
     }
 }
 
@@ -106,7 +119,7 @@ where
     T::T: Copy + Default,
     Vec<T::T>: IntoArrayData,
 {
-    fn build(&mut self) -> Result<Array> {
+    fn build(&mut self) -> Result<LeafArray> {
         self.take_array()
     }
 
@@ -120,6 +133,78 @@ where
     }
 }
 
+/// Convert parquet TIME values to microseconds since midnight.
+fn time_to_micros(data: ArrayData2, desc: &ColumnDescriptor) -> Result<ArrayData2> {
+    let micros: Vec<i64> = match (data, time_unit(desc)) {
+        (ArrayData2::Int32(d), ParquetTimeUnit::MILLIS(_)) => {
+            d.as_slice().iter().map(|&v| v as i64 * 1000).collect()
+        }
+        (ArrayData2::Int64(d), ParquetTimeUnit::MICROS(_)) => return Ok(ArrayData2::Int64(d)),
+        (ArrayData2::Int64(d), ParquetTimeUnit::NANOS(_)) => {
+            d.as_slice().iter().map(|&v| v / 1000).collect()
+        }
+        (data, unit) => {
+            return Err(RayexecError::new(format!(
+                "Unexpected physical type {} for parquet time unit {unit:?}",
+                data.physical_type()
+            )))
+        }
+    };
+
+    Ok(PrimitiveStorage::from(micros).into())
+}
+
+/// Widen unscaled decimal values read from INT32 or INT64 columns to the
+/// storage type for the decimal.
+///
+/// Values are used as is, the scale is carried by the data type.
+fn unscaled_decimal(data: ArrayData2, datatype: &DataType) -> Result<ArrayData2> {
+    Ok(match (data, datatype) {
+        (ArrayData2::Int32(d), DataType::Decimal64(_)) => {
+            let values: Vec<_> = d.as_slice().iter().map(|&v| v as i64).collect();
+            PrimitiveStorage::from(values).into()
+        }
+        (ArrayData2::Int32(d), DataType::Decimal128(_)) => {
+            let values: Vec<_> = d.as_slice().iter().map(|&v| v as i128).collect();
+            PrimitiveStorage::from(values).into()
+        }
+        (ArrayData2::Int64(d), DataType::Decimal64(_)) => ArrayData2::Int64(d),
+        (ArrayData2::Int64(d), DataType::Decimal128(_)) => {
+            let values: Vec<_> = d.as_slice().iter().map(|&v| v as i128).collect();
+            PrimitiveStorage::from(values).into()
+        }
+        (data, datatype) => {
+            return Err(RayexecError::new(format!(
+                "Unexpected physical type {} for decimal type {datatype}",
+                data.physical_type()
+            )))
+        }
+    })
+}
+
+/// Reinterpret signed values as unsigned.
+///
+/// Parquet stores UINT_32 and UINT_64 in INT32 and INT64 columns, so values
+/// greater than the signed max will have wrapped.
+fn reinterpret_unsigned(data: ArrayData2) -> Result<ArrayData2> {
+    Ok(match data {
+        ArrayData2::Int32(d) => {
+            let values: Vec<_> = d.as_slice().iter().map(|&v| v as u32).collect();
+            PrimitiveStorage::from(values).into()
+        }
+        ArrayData2::Int64(d) => {
+            let values: Vec<_> = d.as_slice().iter().map(|&v| v as u64).collect();
+            PrimitiveStorage::from(values).into()
+        }
+        other => {
+            return Err(RayexecError::new(format!(
+                "Cannot reinterpret physical type {} as unsigned",
+                other.physical_type()
+            )))
+        }
+    })
+}
+
 impl IntoArrayData for Vec<bool> {
     fn into_array_data(self) -> ArrayData2 {
         let values = Bitmap::from_iter(self);
@@ -156,3 +241,78 @@ impl IntoArrayData for Vec<Int96> {
         PrimitiveStorage::from(values).into()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use rayexec_execution::arrays::datatype::{DecimalTypeMeta, TimeUnit, TimestampTypeMeta};
+
+    use super::*;
+    use crate::reader::test_util::{read_file, write_file, Leaf};
+
+    #[test]
+    fn int32_and_int64_decimals() {
+        let buf = write_file(
+            "message test {
+                OPTIONAL INT32 a (DECIMAL(9, 2));
+                REQUIRED INT64 b (DECIMAL(18, 3));
+            }",
+            &[
+                Leaf::Int32(&[1234, -5], &[1, 0, 1], &[]),
+                Leaf::Int64(&[1, -123456789012345, 1000], &[], &[]),
+            ],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        assert_eq!(
+            DataType::Decimal64(DecimalTypeMeta::new(9, 2)),
+            schema.fields[0].datatype
+        );
+        assert_eq!(
+            DataType::Decimal64(DecimalTypeMeta::new(18, 3)),
+            schema.fields[1].datatype
+        );
+        assert_eq!(vec!["12.34", "NULL", "-0.05"], columns[0]);
+        assert_eq!(vec!["0.001", "-123456789012.345", "1.000"], columns[1]);
+    }
+
+    #[test]
+    fn legacy_converted_types() {
+        let buf = write_file(
+            "message test {
+                REQUIRED INT64 ts_millis (TIMESTAMP_MILLIS);
+                REQUIRED INT64 ts_micros (TIMESTAMP_MICROS);
+                REQUIRED INT32 d (DATE);
+                REQUIRED INT32 u32 (UINT_32);
+                REQUIRED INT64 u64 (UINT_64);
+                REQUIRED INT32 i8 (INT_8);
+            }",
+            &[
+                Leaf::Int64(&[1_700_000_000_123], &[], &[]),
+                Leaf::Int64(&[1_700_000_000_123_456], &[], &[]),
+                Leaf::Int32(&[19797], &[], &[]),
+                Leaf::Int32(&[-1], &[], &[]),
+                Leaf::Int64(&[-1], &[], &[]),
+                Leaf::Int32(&[-8], &[], &[]),
+            ],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        assert_eq!(
+            DataType::Timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Millisecond, "UTC")),
+            schema.fields[0].datatype
+        );
+        assert_eq!(
+            DataType::Timestamp(TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "UTC")),
+            schema.fields[1].datatype
+        );
+        assert_eq!(DataType::Date32, schema.fields[2].datatype);
+        assert_eq!(DataType::UInt32, schema.fields[3].datatype);
+        assert_eq!(DataType::UInt64, schema.fields[4].datatype);
+        assert_eq!(DataType::Int8, schema.fields[5].datatype);
+
+        assert_eq!(vec!["2024-03-15"], columns[2]);
+        assert_eq!(vec![u32::MAX.to_string()], columns[3]);
+        assert_eq!(vec![u64::MAX.to_string()], columns[4]);
+        assert_eq!(vec!["-8"], columns[5]);
+    }
+}
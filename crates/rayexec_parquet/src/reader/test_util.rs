@@ -0,0 +1,116 @@
+//! Utilities for testing readers against hand written parquet files.
+use std::path::Path;
+use std::sync::Arc;
+
+use bytes::Bytes;
+use parquet::data_type::{ByteArray, FixedLenByteArray};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::SerializedFileWriter;
+use parquet::schema::parser::parse_message_type;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::storage::table_storage::Projections;
+use rayexec_io::memory::MemoryFileSystem;
+
+use crate::metadata::Metadata;
+use crate::reader::AsyncBatchReader;
+use crate::schema::from_parquet_schema;
+
+/// Values and levels for a leaf column.
+pub enum Leaf<'a> {
+    Int32(&'a [i32], &'a [i16], &'a [i16]),
+    Int64(&'a [i64], &'a [i16], &'a [i16]),
+    Utf8(&'a [&'a str], &'a [i16], &'a [i16]),
+    /// Raw bytes for a BYTE_ARRAY column.
+    Bytes(&'a [&'a [u8]], &'a [i16], &'a [i16]),
+    /// Raw bytes for a FIXED_LEN_BYTE_ARRAY column.
+    FixedLen(&'a [&'a [u8]], &'a [i16], &'a [i16]),
+}
+
+fn levels(levels: &[i16]) -> Option<&[i16]> {
+    (!levels.is_empty()).then_some(levels)
+}
+
+/// Write a file with a single row group containing the given leaves.
+pub fn write_file(message_type: &str, leaves: &[Leaf]) -> Bytes {
+    let schema = Arc::new(parse_message_type(message_type).unwrap());
+    let props = Arc::new(WriterProperties::new());
+    let mut writer = SerializedFileWriter::new(Vec::new(), schema, props).unwrap();
+
+    let mut group = writer.next_row_group().unwrap();
+    for leaf in leaves {
+        let mut col = group.next_column().unwrap().unwrap();
+        match leaf {
+            Leaf::Int32(values, def, rep) => {
+                col.typed::<i32>()
+                    .write_batch(values, levels(def), levels(rep))
+                    .unwrap();
+            }
+            Leaf::Int64(values, def, rep) => {
+                col.typed::<i64>()
+                    .write_batch(values, levels(def), levels(rep))
+                    .unwrap();
+            }
+            Leaf::Utf8(values, def, rep) => {
+                let values: Vec<_> = values.iter().map(|&v| ByteArray::from(v)).collect();
+                col.typed::<ByteArray>()
+                    .write_batch(&values, levels(def), levels(rep))
+                    .unwrap();
+            }
+            Leaf::Bytes(values, def, rep) => {
+                let values: Vec<_> = values.iter().map(|&v| ByteArray::from(v)).collect();
+                col.typed::<ByteArray>()
+                    .write_batch(&values, levels(def), levels(rep))
+                    .unwrap();
+            }
+            Leaf::FixedLen(values, def, rep) => {
+                let values: Vec<_> = values
+                    .iter()
+                    .map(|&v| FixedLenByteArray::from(v.to_vec()))
+                    .collect();
+                col.typed::<FixedLenByteArray>()
+                    .write_batch(&values, levels(def), levels(rep))
+                    .unwrap();
+            }
+        }
+        col.close().unwrap();
+    }
+    group.close().unwrap();
+
+    writer.into_inner().unwrap().into()
+}
+
+/// Read all batches from a file, returning the schema and the formatted
+/// values for each column.
+pub fn read_file(buf: Bytes, batch_size: usize) -> (Schema, Vec<Vec<String>>) {
+    let fs = MemoryFileSystem::default();
+    fs.register_file(Path::new("test.parquet"), buf.clone())
+        .unwrap();
+    let mut source = fs.file_source(Path::new("test.parquet")).unwrap();
+
+    let metadata =
+        futures::executor::block_on(Metadata::new_from_source(source.as_mut(), buf.len())).unwrap();
+    let schema =
+        from_parquet_schema(metadata.decoded_metadata.file_metadata().schema_descr()).unwrap();
+
+    let row_groups = (0..metadata.decoded_metadata.num_row_groups()).collect();
+    let mut reader = AsyncBatchReader::try_new(
+        source,
+        row_groups,
+        Arc::new(metadata),
+        &schema,
+        batch_size,
+        Projections::all(),
+    )
+    .unwrap();
+
+    let mut columns = vec![Vec::new(); schema.fields.len()];
+    while let Some(batch) = futures::executor::block_on(reader.read_next()).unwrap() {
+        for (col, array) in columns.iter_mut().zip(batch.arrays()) {
+            for row in 0..array.logical_len() {
+                col.push(array.logical_value(row).unwrap().to_string());
+            }
+        }
+    }
+
+    (schema, columns)
+}
@@ -1,15 +1,24 @@
-use parquet::basic::Type as PhysicalType;
+use half::f16;
 use parquet::column::page::PageReader;
+use parquet::column::reader::basic::BasicColumnValueDecoder;
 use parquet::column::reader::view::ViewColumnValueDecoder;
-use parquet::data_type::{ByteArray, DataType as ParquetDataType};
+use parquet::data_type::FixedLenByteArray;
 use parquet::decoding::view::ViewBuffer;
 use parquet::schema::types::ColumnDescPtr;
 use rayexec_error::{RayexecError, Result};
-use rayexec_execution::arrays::array::Array;
+use rayexec_execution::arrays::array::{Array, ArrayData2};
 use rayexec_execution::arrays::datatype::DataType;
-use rayexec_execution::arrays::executor::builder::ArrayDataBuffer;
+use rayexec_execution::arrays::executor::builder::{ArrayDataBuffer, GermanVarlenBuffer};
+use rayexec_execution::arrays::scalar::interval::Interval;
+use rayexec_execution::arrays::storage::PrimitiveStorage;
 
-use super::{def_levels_into_bitmap, insert_null_values, ArrayBuilder, ValuesReader};
+use super::{
+    def_levels_into_bitmap,
+    insert_null_values,
+    ArrayBuilder,
+    LeafArray,
+    ValuesReader,
+};
 
 #[derive(Debug)]
 pub struct VarlenArrayReader<P: PageReader> {
@@ -32,40 +41,55 @@ where
         }
     }
 
-    pub fn take_array(&mut self) -> Result<Array> {
+    pub fn take_array(&mut self) -> Result<LeafArray> {
         let def_levels = self.values_reader.take_def_levels();
-        let _rep_levels = self.values_reader.take_rep_levels();
+        let rep_levels = self.values_reader.take_rep_levels();
+        let max_def_level = self.values_reader.description.max_def_level();
 
         // Replace the view buffer, what we take is the basis for the array.
         let view_buffer =
             std::mem::replace(&mut self.values_buffer, ViewBuffer::new(self.batch_size));
 
-        let arr = match (ByteArray::get_physical_type(), &self.datatype) {
-            (PhysicalType::BYTE_ARRAY, _) => {
-                match def_levels {
-                    Some(levels) => {
-                        // Logical validities, used to insert null values into
-                        // the metadata vec.
-                        let bitmap = def_levels_into_bitmap(levels);
-
-                        let mut buffer = view_buffer.into_buffer();
-
-                        // Insert nulls into the correct location.
-                        //
-                        // The "null" values will just be zeroed metadata fields.
-                        insert_null_values(buffer.metadata_mut(), &bitmap);
-
-                        Array::new_with_validity_and_array_data(self.datatype.clone(), bitmap, buffer.into_data())
-                    }
-                    None => {
-                        Array::new_with_array_data(self.datatype.clone(), view_buffer.into_buffer().into_data())
-                    }
-                }
+        let mut buffer = view_buffer.into_buffer();
+
+        // Logical validities, used to insert null values into the metadata
+        // vec.
+        //
+        // The "null" values will just be zeroed metadata fields.
+        let bitmap = match &def_levels {
+            Some(levels) => {
+                let bitmap = def_levels_into_bitmap(levels, max_def_level);
+                insert_null_values(buffer.metadata_mut(), &bitmap);
+                Some(bitmap)
+            }
+            None => None,
+        };
+
+        let data = match &self.datatype {
+            DataType::Utf8 | DataType::Binary => buffer.into_data(),
+            DataType::Decimal64(_) | DataType::Decimal128(_) => {
+                let values: Vec<_> = buffer.iter().collect();
+                array_data_from_bytes(&self.datatype, &values)?
+            }
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Unknown conversion from parquet BYTE_ARRAY to {other} in varlen reader"
+                )))
             }
-            (p_other, d_other) => return Err(RayexecError::new(format!("Unknown conversion from parquet to glaredb type in varlen reader; parqet: {p_other}, bullet: {d_other}")))
         };
 
-        Ok(arr)
+        let arr = match bitmap {
+            Some(bitmap) => {
+                Array::new_with_validity_and_array_data(self.datatype.clone(), bitmap, data)
+            }
+            None => Array::new_with_array_data(self.datatype.clone(), data),
+        };
+
+        Ok(LeafArray {
+            array: arr,
+            def_levels,
+            rep_levels,
+        })
     }
 }
 
@@ -73,7 +97,7 @@ impl<P> ArrayBuilder<P> for VarlenArrayReader<P>
 where
     P: PageReader,
 {
-    fn build(&mut self) -> Result<Array> {
+    fn build(&mut self) -> Result<LeafArray> {
         self.take_array()
     }
 
@@ -86,3 +110,262 @@ where
         self.values_reader.read_records(n, &mut self.values_buffer)
     }
 }
+
+/// Reader for FIXED_LEN_BYTE_ARRAY columns.
+pub struct FixedLenArrayReader<P: PageReader> {
+    batch_size: usize,
+    datatype: DataType,
+    values_reader: ValuesReader<BasicColumnValueDecoder<FixedLenByteArray>, P>,
+    values_buffer: Vec<FixedLenByteArray>,
+}
+
+impl<P> FixedLenArrayReader<P>
+where
+    P: PageReader,
+{
+    pub fn new(batch_size: usize, datatype: DataType, desc: ColumnDescPtr) -> Self {
+        FixedLenArrayReader {
+            batch_size,
+            datatype,
+            values_reader: ValuesReader::new(desc),
+            values_buffer: Vec::with_capacity(batch_size),
+        }
+    }
+
+    pub fn take_array(&mut self) -> Result<LeafArray> {
+        let def_levels = self.values_reader.take_def_levels();
+        let rep_levels = self.values_reader.take_rep_levels();
+        let max_def_level = self.values_reader.description.max_def_level();
+
+        let values =
+            std::mem::replace(&mut self.values_buffer, Vec::with_capacity(self.batch_size));
+
+        // Spread values out to their logical positions, nulls being empty
+        // slices.
+        let (bitmap, slices) = match &def_levels {
+            Some(levels) => {
+                let bitmap = def_levels_into_bitmap(levels, max_def_level);
+                let mut values_iter = values.iter();
+                let slices: Vec<&[u8]> = bitmap
+                    .iter()
+                    .map(|valid| match valid {
+                        true => values_iter.next().map(|v| v.data()).unwrap_or(&[]),
+                        false => &[],
+                    })
+                    .collect();
+                (Some(bitmap), slices)
+            }
+            None => (None, values.iter().map(|v| v.data()).collect()),
+        };
+
+        let data = array_data_from_bytes(&self.datatype, &slices)?;
+
+        let array = match bitmap {
+            Some(bitmap) => {
+                Array::new_with_validity_and_array_data(self.datatype.clone(), bitmap, data)
+            }
+            None => Array::new_with_array_data(self.datatype.clone(), data),
+        };
+
+        Ok(LeafArray {
+            array,
+            def_levels,
+            rep_levels,
+        })
+    }
+}
+
+impl<P> ArrayBuilder<P> for FixedLenArrayReader<P>
+where
+    P: PageReader,
+{
+    fn build(&mut self) -> Result<LeafArray> {
+        self.take_array()
+    }
+
+    fn set_page_reader(&mut self, page_reader: P) -> Result<()> {
+        let decoder = BasicColumnValueDecoder::new(&self.values_reader.description);
+        self.values_reader.set_page_reader(decoder, page_reader)
+    }
+
+    fn read_rows(&mut self, n: usize) -> Result<usize> {
+        self.values_reader.read_records(n, &mut self.values_buffer)
+    }
+}
+
+/// Convert raw bytes into array data for the given type.
+///
+/// Empty slices are treated as null placeholders and produce a default value.
+fn array_data_from_bytes(datatype: &DataType, values: &[&[u8]]) -> Result<ArrayData2> {
+    Ok(match datatype {
+        DataType::Decimal64(_) => {
+            let values = values
+                .iter()
+                .map(|v| {
+                    let v = decimal_from_be_bytes(v)?;
+                    i64::try_from(v).map_err(|_| {
+                        RayexecError::new(format!("Decimal value {v} too large for Decimal64"))
+                    })
+                })
+                .collect::<Result<Vec<_>>>()?;
+            PrimitiveStorage::from(values).into()
+        }
+        DataType::Decimal128(_) => {
+            let values = values
+                .iter()
+                .map(|v| decimal_from_be_bytes(v))
+                .collect::<Result<Vec<_>>>()?;
+            PrimitiveStorage::from(values).into()
+        }
+        DataType::Uuid => {
+            let values = values
+                .iter()
+                .map(|v| Ok(u128::from_be_bytes(fixed_bytes(v)?)))
+                .collect::<Result<Vec<_>>>()?;
+            PrimitiveStorage::from(values).into()
+        }
+        DataType::Float16 => {
+            let values = values
+                .iter()
+                .map(|v| Ok(f16::from_le_bytes(fixed_bytes(v)?)))
+                .collect::<Result<Vec<_>>>()?;
+            PrimitiveStorage::from(values).into()
+        }
+        DataType::Interval => {
+            // Months, days, and milliseconds stored as unsigned little endian
+            // integers.
+            let values = values
+                .iter()
+                .map(|v| {
+                    let v: [u8; 12] = fixed_bytes(v)?;
+                    let months = u32::from_le_bytes([v[0], v[1], v[2], v[3]]);
+                    let days = u32::from_le_bytes([v[4], v[5], v[6], v[7]]);
+                    let millis = u32::from_le_bytes([v[8], v[9], v[10], v[11]]);
+                    Ok(Interval::new(
+                        months as i32,
+                        days as i32,
+                        millis as i64 * Interval::NANOSECONDS_IN_MILLISECOND,
+                    ))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            PrimitiveStorage::from(values).into()
+        }
+        DataType::Binary => {
+            let mut buffer = GermanVarlenBuffer::<[u8]>::with_len(values.len());
+            for (idx, v) in values.iter().enumerate() {
+                buffer.put(idx, v);
+            }
+            buffer.into_data()
+        }
+        other => {
+            return Err(RayexecError::new(format!(
+                "Unknown conversion from parquet bytes to {other}"
+            )))
+        }
+    })
+}
+
+/// Decode a big-endian two's complement unscaled decimal value.
+fn decimal_from_be_bytes(bytes: &[u8]) -> Result<i128> {
+    if bytes.len() > 16 {
+        return Err(RayexecError::new(format!(
+            "Decimal with byte length {} too large",
+            bytes.len()
+        )));
+    }
+
+    // Sign extend.
+    let fill = if bytes.first().is_some_and(|&b| b & 0x80 != 0) {
+        0xFF
+    } else {
+        0
+    };
+    let mut buf = [fill; 16];
+    buf[16 - bytes.len()..].copy_from_slice(bytes);
+
+    Ok(i128::from_be_bytes(buf))
+}
+
+/// Get a fixed size array from a slice, with empty slices (nulls) producing
+/// zeroed bytes.
+fn fixed_bytes<const N: usize>(bytes: &[u8]) -> Result<[u8; N]> {
+    if bytes.is_empty() {
+        return Ok([0; N]);
+    }
+
+    bytes.try_into().map_err(|_| {
+        RayexecError::new(format!(
+            "Expected fixed length value of {N} bytes, got {}",
+            bytes.len()
+        ))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use rayexec_execution::arrays::datatype::DecimalTypeMeta;
+
+    use super::*;
+    use crate::reader::test_util::{read_file, write_file, Leaf};
+
+    #[test]
+    fn byte_array_decimals() {
+        let buf = write_file(
+            "message test {
+                OPTIONAL BYTE_ARRAY a (DECIMAL(20, 2));
+            }",
+            &[Leaf::Bytes(
+                &[&[0x30, 0x39], &[0xFF, 0xFF, 0xFE], &[]],
+                &[1, 1, 0, 1],
+                &[],
+            )],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        assert_eq!(
+            DataType::Decimal128(DecimalTypeMeta::new(20, 2)),
+            schema.fields[0].datatype
+        );
+        assert_eq!(vec!["123.45", "-0.02", "NULL", "0.00"], columns[0]);
+    }
+
+    #[test]
+    fn fixed_len_byte_arrays() {
+        let uuid = 0xa0eebc999c0b4ef8bb6d6bb9bd380a11_u128.to_be_bytes();
+        let half = f16::from_f32(1.5).to_le_bytes();
+        let interval = [1, 0, 0, 0, 2, 0, 0, 0, 0xE8, 0x03, 0, 0];
+
+        let buf = write_file(
+            "message test {
+                OPTIONAL FIXED_LEN_BYTE_ARRAY (5) d (DECIMAL(10, 3));
+                REQUIRED FIXED_LEN_BYTE_ARRAY (16) u (UUID);
+                REQUIRED FIXED_LEN_BYTE_ARRAY (2) h (FLOAT16);
+                REQUIRED FIXED_LEN_BYTE_ARRAY (12) i (INTERVAL);
+                REQUIRED FIXED_LEN_BYTE_ARRAY (3) b;
+            }",
+            &[
+                Leaf::FixedLen(&[&[0xFF, 0xFF, 0xFF, 0xFF, 0x85]], &[0, 1], &[]),
+                Leaf::FixedLen(&[&uuid, &uuid], &[], &[]),
+                Leaf::FixedLen(&[&half, &half], &[], &[]),
+                Leaf::FixedLen(&[&interval, &interval], &[], &[]),
+                Leaf::FixedLen(&[b"abc", b"xyz"], &[], &[]),
+            ],
+        );
+
+        let (schema, columns) = read_file(buf, 1024);
+        assert_eq!(
+            DataType::Decimal64(DecimalTypeMeta::new(10, 3)),
+            schema.fields[0].datatype
+        );
+        assert_eq!(DataType::Uuid, schema.fields[1].datatype);
+        assert_eq!(DataType::Float16, schema.fields[2].datatype);
+        assert_eq!(DataType::Interval, schema.fields[3].datatype);
+        assert_eq!(DataType::Binary, schema.fields[4].datatype);
+
+        assert_eq!(vec!["NULL", "-0.123"], columns[0]);
+        assert_eq!("a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11", columns[1][0]);
+        assert_eq!("1.5", columns[2][0]);
+        assert_eq!("1 mon 2 days 00:00:01", columns[3][0]);
+        assert_eq!(vec!["\\x616263", "\\x78797a"], columns[4]);
+    }
+}
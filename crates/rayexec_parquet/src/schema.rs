@@ -8,12 +8,13 @@ use parquet::basic::{
     Type as PhysicalType,
 };
 use parquet::format::{MicroSeconds, MilliSeconds, NanoSeconds};
-use parquet::schema::types::{BasicTypeInfo, SchemaDescriptor, Type};
-use rayexec_error::{not_implemented, RayexecError, Result, ResultExt};
+use parquet::schema::types::{BasicTypeInfo, ColumnDescriptor, SchemaDescriptor, Type};
+use rayexec_error::{RayexecError, Result, ResultExt};
 use rayexec_execution::arrays::datatype::{
     DataType,
     DecimalTypeMeta,
     ListTypeMeta,
+    StructTypeMeta,
     TimeUnit,
     TimestampTypeMeta,
 };
@@ -38,7 +39,7 @@ pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
     let types = schema
         .fields
         .iter()
-        .map(|f| Ok(Arc::new(to_parquet_type(f)?)))
+        .map(|f| Ok(Arc::new(to_parquet_type(&f.name, &f.datatype, f.nullable)?)))
         .collect::<Result<Vec<_>>>()?;
 
     Ok(SchemaDescriptor::new(Arc::new(
@@ -49,106 +50,178 @@ pub fn to_parquet_schema(schema: &Schema) -> Result<SchemaDescriptor> {
     )))
 }
 
-fn to_parquet_type(field: &Field) -> Result<Type> {
-    let rep = if field.nullable {
-        // TODO: CHANGE ME.
-        Repetition::REQUIRED
+fn to_parquet_type(name: &str, datatype: &DataType, nullable: bool) -> Result<Type> {
+    let rep = if nullable {
+        Repetition::OPTIONAL
     } else {
         Repetition::REQUIRED
     };
 
-    let result = match &field.datatype {
-        DataType::Boolean => Type::primitive_type_builder(&field.name, PhysicalType::BOOLEAN)
+    let result = match datatype {
+        DataType::Boolean => Type::primitive_type_builder(name, PhysicalType::BOOLEAN)
             .with_repetition(rep)
             .build(),
-        DataType::Int8 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::Int8 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 8,
                 is_signed: true,
             }))
             .build(),
-        DataType::Int16 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::Int16 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 16,
                 is_signed: true,
             }))
             .build(),
-        DataType::Int32 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::Int32 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 32,
                 is_signed: true,
             }))
             .build(),
-        DataType::Int64 => Type::primitive_type_builder(&field.name, PhysicalType::INT64)
+        DataType::Int64 => Type::primitive_type_builder(name, PhysicalType::INT64)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 64,
                 is_signed: true,
             }))
             .build(),
-        DataType::UInt8 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::UInt8 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 8,
                 is_signed: false,
             }))
             .build(),
-        DataType::UInt16 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::UInt16 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 16,
                 is_signed: false,
             }))
             .build(),
-        DataType::UInt32 => Type::primitive_type_builder(&field.name, PhysicalType::INT32)
+        DataType::UInt32 => Type::primitive_type_builder(name, PhysicalType::INT32)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 32,
                 is_signed: false,
             }))
             .build(),
-        DataType::UInt64 => Type::primitive_type_builder(&field.name, PhysicalType::INT64)
+        DataType::UInt64 => Type::primitive_type_builder(name, PhysicalType::INT64)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::Integer {
                 bit_width: 64,
                 is_signed: false,
             }))
             .build(),
-        DataType::Float32 => Type::primitive_type_builder(&field.name, PhysicalType::FLOAT)
+        DataType::Float32 => Type::primitive_type_builder(name, PhysicalType::FLOAT)
             .with_repetition(rep)
             .build(),
-        DataType::Float64 => Type::primitive_type_builder(&field.name, PhysicalType::DOUBLE)
+        DataType::Float64 => Type::primitive_type_builder(name, PhysicalType::DOUBLE)
             .with_repetition(rep)
             .build(),
         DataType::Timestamp(meta) => {
             let logical_type = match meta.unit {
                 TimeUnit::Second => None,
                 TimeUnit::Millisecond => Some(LogicalType::Timestamp {
-                    is_adjusted_to_u_t_c: false,
+                    is_adjusted_to_u_t_c: meta.tz.is_some(),
                     unit: ParquetTimeUnit::MILLIS(MilliSeconds::new()),
                 }),
                 TimeUnit::Microsecond => Some(LogicalType::Timestamp {
-                    is_adjusted_to_u_t_c: false,
+                    is_adjusted_to_u_t_c: meta.tz.is_some(),
                     unit: ParquetTimeUnit::MICROS(MicroSeconds::new()),
                 }),
                 TimeUnit::Nanosecond => Some(LogicalType::Timestamp {
-                    is_adjusted_to_u_t_c: false,
+                    is_adjusted_to_u_t_c: meta.tz.is_some(),
                     unit: ParquetTimeUnit::NANOS(NanoSeconds::new()),
                 }),
             };
 
-            Type::primitive_type_builder(&field.name, PhysicalType::INT64)
+            Type::primitive_type_builder(name, PhysicalType::INT64)
                 .with_repetition(rep)
                 .with_logical_type(logical_type)
                 .build()
         }
-        DataType::Utf8 => Type::primitive_type_builder(&field.name, PhysicalType::BYTE_ARRAY)
+        DataType::Decimal64(meta) => Type::primitive_type_builder(name, PhysicalType::INT64)
+            .with_repetition(rep)
+            .with_logical_type(Some(LogicalType::Decimal {
+                scale: meta.scale as i32,
+                precision: meta.precision as i32,
+            }))
+            .with_precision(meta.precision as i32)
+            .with_scale(meta.scale as i32)
+            .build(),
+        DataType::Decimal128(meta) => {
+            Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+                .with_repetition(rep)
+                .with_length(16)
+                .with_logical_type(Some(LogicalType::Decimal {
+                    scale: meta.scale as i32,
+                    precision: meta.precision as i32,
+                }))
+                .with_precision(meta.precision as i32)
+                .with_scale(meta.scale as i32)
+                .build()
+        }
+        DataType::Date32 => Type::primitive_type_builder(name, PhysicalType::INT32)
+            .with_repetition(rep)
+            .with_logical_type(Some(LogicalType::Date))
+            .build(),
+        DataType::Time64 => Type::primitive_type_builder(name, PhysicalType::INT64)
+            .with_repetition(rep)
+            .with_logical_type(Some(LogicalType::Time {
+                is_adjusted_to_u_t_c: false,
+                unit: ParquetTimeUnit::MICROS(MicroSeconds::new()),
+            }))
+            .build(),
+        DataType::Utf8 => Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
             .with_repetition(rep)
             .with_logical_type(Some(LogicalType::String))
             .build(),
+        DataType::Binary => Type::primitive_type_builder(name, PhysicalType::BYTE_ARRAY)
+            .with_repetition(rep)
+            .build(),
+        DataType::Uuid => Type::primitive_type_builder(name, PhysicalType::FIXED_LEN_BYTE_ARRAY)
+            .with_repetition(rep)
+            .with_length(16)
+            .with_logical_type(Some(LogicalType::Uuid))
+            .build(),
+        DataType::Struct(meta) => {
+            let fields = meta
+                .fields
+                .iter()
+                .map(|f| Ok(Arc::new(to_parquet_type(&f.name, &f.datatype, f.nullable)?)))
+                .collect::<Result<Vec<_>>>()?;
+
+            Type::group_type_builder(name)
+                .with_repetition(rep)
+                .with_fields(fields)
+                .build()
+        }
+        DataType::List(meta) => {
+            // Always written using the standard three-level structure.
+            //
+            // <nullable> group <name> (LIST) {
+            //   repeated group list {
+            //     optional <element-type> element;
+            //   }
+            // }
+            let element = to_parquet_type("element", &meta.datatype, true)?;
+            let repeated = Type::group_type_builder("list")
+                .with_repetition(Repetition::REPEATED)
+                .with_fields(vec![Arc::new(element)])
+                .build()
+                .context("failed to build repeated list group")?;
+
+            Type::group_type_builder(name)
+                .with_repetition(rep)
+                .with_logical_type(Some(LogicalType::List))
+                .with_fields(vec![Arc::new(repeated)])
+                .build()
+        }
         other => {
             return Err(RayexecError::new(format!(
                 "Unimplemented type conversion to parquet type: {other}"
@@ -163,84 +236,175 @@ fn convert_types_to_fields<T: AsRef<Type>>(typs: &[T]) -> Result<Vec<Field>> {
     let mut fields = Vec::with_capacity(typs.len());
 
     for parquet_type in typs.iter() {
-        let field = convert_type_to_field(parquet_type)?;
+        let field = convert_type_to_field(parquet_type.as_ref())?;
         fields.push(field);
     }
 
     Ok(fields)
 }
 
-fn convert_type_to_field(parquet_type: impl AsRef<Type>) -> Result<Field> {
-    let parquet_type = parquet_type.as_ref();
-    let dt = if parquet_type.is_primitive() {
-        convert_primitive(parquet_type)?
+fn convert_type_to_field(parquet_type: &Type) -> Result<Field> {
+    let dt = convert_type(parquet_type)?;
+
+    let info = parquet_type.get_basic_info();
+    let repetition = if info.has_repetition() {
+        info.repetition()
     } else {
-        convert_complex(parquet_type)?
+        Repetition::OPTIONAL
     };
 
-    let field = Field::new(parquet_type.name(), dt, true); // TODO: Nullable from repetition.
+    let field = match repetition {
+        Repetition::OPTIONAL => Field::new(parquet_type.name(), dt, true),
+        Repetition::REQUIRED => Field::new(parquet_type.name(), dt, false),
+        // A repeated field outside of a LIST or MAP is a non-null list where
+        // each repetition is an element.
+        Repetition::REPEATED => Field::new(
+            parquet_type.name(),
+            DataType::List(ListTypeMeta::new(dt)),
+            false,
+        ),
+    };
 
     Ok(field)
 }
 
+/// Converts a parquet type to a data type, ignoring its repetition.
+fn convert_type(parquet_type: &Type) -> Result<DataType> {
+    if parquet_type.is_primitive() {
+        convert_primitive(parquet_type)
+    } else {
+        convert_complex(parquet_type)
+    }
+}
+
+/// The element of a list as determined from its repeated field.
+#[derive(Debug, Clone, Copy)]
+pub(crate) enum ListElement<'a> {
+    /// The repeated field itself is the element.
+    Repeated(&'a Type),
+    /// The element is the only child of the repeated group.
+    Child(&'a Type),
+}
+
+/// Determine the element of a LIST group from its repeated field, following
+/// the backward compatibility rules.
+///
+/// <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#backward-compatibility-rules>
+pub(crate) fn list_element<'a>(list_name: &str, repeated: &'a Type) -> ListElement<'a> {
+    if repeated.is_primitive() {
+        return ListElement::Repeated(repeated);
+    }
+
+    let fields = repeated.get_fields();
+    if fields.len() != 1
+        || repeated.name() == "array"
+        || repeated.name() == format!("{list_name}_tuple")
+    {
+        return ListElement::Repeated(repeated);
+    }
+
+    ListElement::Child(&fields[0])
+}
+
+/// Get the unit TIME values are stored with in a column.
+///
+/// TIME_MILLIS is stored as INT32, while TIME_MICROS and TIME_NANOS are stored
+/// as INT64.
+pub(crate) fn time_unit(desc: &ColumnDescriptor) -> ParquetTimeUnit {
+    match desc.logical_type() {
+        Some(LogicalType::Time { unit, .. }) => unit,
+        _ => match desc.converted_type() {
+            ConvertedType::TIME_MILLIS => ParquetTimeUnit::MILLIS(Default::default()),
+            _ => ParquetTimeUnit::MICROS(Default::default()),
+        },
+    }
+}
+
+/// Get the single repeated field for a LIST or MAP group.
+pub(crate) fn repeated_field<'a>(group: &'a Type, fields: &'a [Arc<Type>]) -> Result<&'a Type> {
+    if fields.len() != 1 {
+        return Err(RayexecError::new(format!(
+            "Expected a single repeated field for '{}', got {} fields",
+            group.name(),
+            fields.len(),
+        )));
+    }
+
+    let repeated = fields[0].as_ref();
+    let info = repeated.get_basic_info();
+    if !info.has_repetition() || info.repetition() != Repetition::REPEATED {
+        return Err(RayexecError::new(format!(
+            "Field '{}' in '{}' requires REPEATED repetition",
+            repeated.name(),
+            group.name(),
+        )));
+    }
+
+    Ok(repeated)
+}
+
 fn convert_complex(parquet_type: &Type) -> Result<DataType> {
     match parquet_type {
         Type::GroupType { basic_info, fields } => {
             match basic_info.converted_type() {
                 ConvertedType::LIST => {
                     // https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#lists
-                    if fields.len() != 1 {
-                        return Err(RayexecError::new(format!(
-                            "List can only have a single type, got {}",
-                            fields.len(),
-                        )));
-                    }
+                    let repeated = repeated_field(parquet_type, fields)?;
 
-                    let nested = &fields[0];
-                    if !nested.get_basic_info().has_repetition() {
-                        return Err(RayexecError::new("List field requires repetition"));
-                    }
-                    if nested.get_basic_info().repetition() != Repetition::REPEATED {
-                        return Err(RayexecError::new("List field requires REPEATED repetition"));
-                    }
-
-                    // // List<Integer> (nullable list, non-null elements)
-                    // optional group my_list (LIST) {
-                    //   repeated int32 element;
-                    // }
-                    if nested.is_primitive() {
-                        let nested_type = convert_primitive(nested.as_ref())?;
-                        return Ok(DataType::List(ListTypeMeta::new(nested_type)));
-                    }
-
-                    // TODO: Other backwards compat types.
+                    let element = match list_element(parquet_type.name(), repeated) {
+                        ListElement::Repeated(repeated) => convert_list_element(repeated)?,
+                        ListElement::Child(child) => convert_type_to_field(child)?.datatype,
+                    };
 
-                    let inner_fields = nested.get_fields();
-                    if inner_fields.len() != 1 {
+                    Ok(DataType::List(ListTypeMeta::new(element)))
+                }
+                ConvertedType::MAP | ConvertedType::MAP_KEY_VALUE => {
+                    // https://github.com/apache/parquet-format/blob/master/LogicalTypes.md#maps
+                    //
+                    // Maps are represented as a list of key/value structs.
+                    let key_value = repeated_field(parquet_type, fields)?;
+                    if key_value.is_primitive() || key_value.get_fields().len() != 2 {
                         return Err(RayexecError::new(format!(
-                            "Unsupported number of inner fields for list, expected 1, got {}",
-                            inner_fields.len(),
+                            "Map '{}' requires a repeated group with a key and value",
+                            parquet_type.name()
                         )));
                     }
 
-                    let inner_field = &inner_fields[0];
-                    let inner_field = convert_type_to_field(inner_field)?;
+                    let key = convert_type_to_field(&key_value.get_fields()[0])?;
+                    let value = convert_type_to_field(&key_value.get_fields()[1])?;
 
-                    Ok(DataType::List(ListTypeMeta::new(inner_field.datatype)))
-                }
-                ConvertedType::MAP | ConvertedType::MAP_KEY_VALUE => {
-                    not_implemented!("parquet map")
-                }
-                _ => {
-                    // let struct_fields = convert_group_fields(parquet_type)?;
-                    not_implemented!("parquet struct")
+                    Ok(DataType::List(ListTypeMeta::new(DataType::Struct(
+                        StructTypeMeta {
+                            fields: vec![
+                                Field::new("key", key.datatype, key.nullable),
+                                Field::new("value", value.datatype, value.nullable),
+                            ],
+                        },
+                    ))))
                 }
+                _ => Ok(DataType::Struct(StructTypeMeta {
+                    fields: convert_types_to_fields(fields)?,
+                })),
             }
         }
         Type::PrimitiveType { .. } => unreachable!(),
     }
 }
 
+/// Convert a repeated field that's acting as a list element.
+///
+/// Primitives are used as is, while groups are treated as structs regardless
+/// of any annotation.
+fn convert_list_element(repeated: &Type) -> Result<DataType> {
+    if repeated.is_primitive() {
+        return convert_primitive(repeated);
+    }
+
+    Ok(DataType::Struct(StructTypeMeta {
+        fields: convert_types_to_fields(repeated.get_fields())?,
+    }))
+}
+
 /// Convert a primitive type to a bullet data type.
 ///
 /// <https://github.com/apache/parquet-format/blob/master/LogicalTypes.md>
@@ -249,7 +413,7 @@ fn convert_primitive(parquet_type: &Type) -> Result<DataType> {
         Type::PrimitiveType {
             basic_info,
             physical_type,
-            type_length: _,
+            type_length,
             scale,
             precision,
         } => match physical_type {
@@ -263,7 +427,7 @@ fn convert_primitive(parquet_type: &Type) -> Result<DataType> {
             parquet::basic::Type::DOUBLE => Ok(DataType::Float64),
             parquet::basic::Type::BYTE_ARRAY => from_byte_array(basic_info, *precision, *scale),
             parquet::basic::Type::FIXED_LEN_BYTE_ARRAY => {
-                not_implemented!("parquet fixed len byte array")
+                from_fixed_len_byte_array(basic_info, *type_length, *precision, *scale)
             }
         },
         Type::GroupType { .. } => unreachable!(),
@@ -295,7 +459,7 @@ fn decimal_type(precision: i32, scale: i32) -> Result<DataType> {
     }
 }
 
-fn from_int32(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<DataType> {
+fn from_int32(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataType> {
     match (info.logical_type(), info.converted_type()) {
         (None, ConvertedType::NONE) => Ok(DataType::Int32),
         (
@@ -318,15 +482,10 @@ fn from_int32(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
                 t
             ))),
         },
-        (Some(LogicalType::Decimal { scale, precision }), _) => {
-            Ok(DataType::Decimal128(DecimalTypeMeta {
-                precision: precision as u8,
-                scale: scale as i8,
-            }))
-        }
-        (Some(LogicalType::Date), _) => unimplemented!(),
+        (Some(LogicalType::Decimal { scale, precision }), _) => decimal_type(precision, scale),
+        (Some(LogicalType::Date), _) => Ok(DataType::Date32),
         (Some(LogicalType::Time { unit, .. }), _) => match unit {
-            ParquetTimeUnit::MILLIS(_) => unimplemented!(),
+            ParquetTimeUnit::MILLIS(_) => Ok(DataType::Time64),
             _ => Err(RayexecError::new(format!(
                 "Cannot create INT32 physical type from {:?}",
                 unit
@@ -341,8 +500,8 @@ fn from_int32(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
         (None, ConvertedType::INT_16) => Ok(DataType::Int16),
         (None, ConvertedType::INT_32) => Ok(DataType::Int32),
         (None, ConvertedType::DATE) => Ok(DataType::Date32),
-        (None, ConvertedType::TIME_MILLIS) => unimplemented!(),
-        (None, ConvertedType::DECIMAL) => unimplemented!(),
+        (None, ConvertedType::TIME_MILLIS) => Ok(DataType::Time64),
+        (None, ConvertedType::DECIMAL) => decimal_type(precision, scale),
         (logical, converted) => Err(RayexecError::new(format!(
             "Unable to convert parquet INT32 logical type {:?} or converted type {}",
             logical, converted
@@ -350,7 +509,7 @@ fn from_int32(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
     }
 }
 
-fn from_int64(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<DataType> {
+fn from_int64(info: &BasicTypeInfo, scale: i32, precision: i32) -> Result<DataType> {
     match (info.logical_type(), info.converted_type()) {
         (None, ConvertedType::NONE) => Ok(DataType::Int64),
         (
@@ -367,8 +526,7 @@ fn from_int64(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
             ParquetTimeUnit::MILLIS(_) => Err(RayexecError::new(
                 "Cannot create INT64 from MILLIS time unit",
             )),
-            ParquetTimeUnit::MICROS(_) => unimplemented!(),
-            ParquetTimeUnit::NANOS(_) => unimplemented!(),
+            ParquetTimeUnit::MICROS(_) | ParquetTimeUnit::NANOS(_) => Ok(DataType::Time64),
         },
         (
             Some(LogicalType::Timestamp {
@@ -383,17 +541,25 @@ fn from_int64(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
                 ParquetTimeUnit::NANOS(_) => TimeUnit::Nanosecond,
             };
             if is_adjusted_to_u_t_c {
-                not_implemented!("parquet timestamp adjusted to utc");
+                // Values are instants since the UTC epoch.
+                return Ok(DataType::Timestamp(TimestampTypeMeta::new_with_tz(
+                    unit, "UTC",
+                )));
             }
             Ok(DataType::Timestamp(TimestampTypeMeta::new(unit)))
         }
         (None, ConvertedType::INT_64) => Ok(DataType::Int64),
         (None, ConvertedType::UINT_64) => Ok(DataType::UInt64),
-        (None, ConvertedType::TIME_MICROS) => unimplemented!(),
-        (None, ConvertedType::TIMESTAMP_MILLIS) => unimplemented!(),
-        (None, ConvertedType::TIMESTAMP_MICROS) => unimplemented!(),
+        (None, ConvertedType::TIME_MICROS) => Ok(DataType::Time64),
+        // Legacy timestamps are always relative to the UTC epoch.
+        (None, ConvertedType::TIMESTAMP_MILLIS) => Ok(DataType::Timestamp(
+            TimestampTypeMeta::new_with_tz(TimeUnit::Millisecond, "UTC"),
+        )),
+        (None, ConvertedType::TIMESTAMP_MICROS) => Ok(DataType::Timestamp(
+            TimestampTypeMeta::new_with_tz(TimeUnit::Microsecond, "UTC"),
+        )),
         (Some(LogicalType::Decimal { scale, precision }), _) => decimal_type(precision, scale),
-        (None, ConvertedType::DECIMAL) => unimplemented!(),
+        (None, ConvertedType::DECIMAL) => decimal_type(precision, scale),
         (logical, converted) => Err(RayexecError::new(format!(
             "Unable to convert parquet INT64 logical type {:?} or converted type {}",
             logical, converted
@@ -401,7 +567,7 @@ fn from_int64(info: &BasicTypeInfo, _scale: i32, _precision: i32) -> Result<Data
     }
 }
 
-fn from_byte_array(info: &BasicTypeInfo, _precision: i32, _scale: i32) -> Result<DataType> {
+fn from_byte_array(info: &BasicTypeInfo, precision: i32, scale: i32) -> Result<DataType> {
     match (info.logical_type(), info.converted_type()) {
         (Some(LogicalType::String), _) => Ok(DataType::Utf8),
         (Some(LogicalType::Json), _) => Ok(DataType::Utf8),
@@ -412,17 +578,32 @@ fn from_byte_array(info: &BasicTypeInfo, _precision: i32, _scale: i32) -> Result
         (None, ConvertedType::BSON) => Ok(DataType::Binary),
         (None, ConvertedType::ENUM) => Ok(DataType::Binary),
         (None, ConvertedType::UTF8) => Ok(DataType::Utf8),
-        (
-            Some(LogicalType::Decimal {
-                scale: _,
-                precision: _,
-            }),
-            _,
-        ) => unimplemented!(),
-        (None, ConvertedType::DECIMAL) => unimplemented!(),
+        (Some(LogicalType::Decimal { scale, precision }), _) => decimal_type(precision, scale),
+        (None, ConvertedType::DECIMAL) => decimal_type(precision, scale),
         (logical, converted) => Err(RayexecError::new(format!(
             "Unable to convert parquet BYTE_ARRAY logical type {:?} or converted type {}",
             logical, converted
         ))),
     }
 }
+
+fn from_fixed_len_byte_array(
+    info: &BasicTypeInfo,
+    type_length: i32,
+    precision: i32,
+    scale: i32,
+) -> Result<DataType> {
+    match (info.logical_type(), info.converted_type()) {
+        (Some(LogicalType::Decimal { scale, precision }), _) => decimal_type(precision, scale),
+        (None, ConvertedType::DECIMAL) => decimal_type(precision, scale),
+        (Some(LogicalType::Uuid), _) if type_length == 16 => Ok(DataType::Uuid),
+        (Some(LogicalType::Float16), _) if type_length == 2 => Ok(DataType::Float16),
+        // Months, days and milliseconds as little endian u32s.
+        (None, ConvertedType::INTERVAL) if type_length == 12 => Ok(DataType::Interval),
+        (None, ConvertedType::NONE) => Ok(DataType::Binary),
+        (logical, converted) => Err(RayexecError::new(format!(
+            "Unable to convert parquet FIXED_LEN_BYTE_ARRAY({type_length}) logical type {:?} or converted type {}",
+            logical, converted
+        ))),
+    }
+}
@@ -0,0 +1,258 @@
+use parquet::basic::TimeUnit as ParquetTimeUnit;
+use parquet::data_type::ByteArray;
+use parquet::file::metadata::{ParquetMetaData, RowGroupMetaData};
+use parquet::file::statistics::Statistics;
+use parquet::schema::types::ColumnDescriptor;
+use rayexec_execution::arrays::datatype::DataType;
+use rayexec_execution::arrays::field::Schema;
+use rayexec_execution::arrays::scalar::decimal::{Decimal128Scalar, Decimal64Scalar};
+use rayexec_execution::arrays::scalar::timestamp::TimestampScalar;
+use rayexec_execution::arrays::scalar::{OwnedScalarValue, ScalarValue};
+use rayexec_execution::logical::statistics::{ColumnStatistics, StatisticsValue, TableStatistics};
+
+use crate::schema::time_unit;
+
+/// Compute table statistics from the column chunk statistics stored in the
+/// parquet footer.
+///
+/// Statistics are only produced for top-level columns that map to a single
+/// leaf column. Nested columns get unknown statistics.
+pub fn table_statistics(metadata: &ParquetMetaData, schema: &Schema) -> TableStatistics {
+    let leaves = leaf_indices(metadata, schema);
+
+    let row_groups = metadata.row_groups();
+    if row_groups.is_empty() {
+        return TableStatistics {
+            num_rows: StatisticsValue::Exact(0),
+            columns: vec![
+                ColumnStatistics {
+                    num_distinct: StatisticsValue::Exact(0),
+                    null_count: StatisticsValue::Exact(0),
+                    min: StatisticsValue::Unknown,
+                    max: StatisticsValue::Unknown,
+                };
+                schema.fields.len()
+            ],
+        };
+    }
+
+    row_groups
+        .iter()
+        .map(|group| row_group_statistics(group, schema, &leaves))
+        .reduce(TableStatistics::merge)
+        .unwrap_or_default()
+}
+
+/// Compute statistics for a single row group.
+pub fn row_group_statistics(
+    group: &RowGroupMetaData,
+    schema: &Schema,
+    leaves: &[Option<usize>],
+) -> TableStatistics {
+    let columns = schema
+        .fields
+        .iter()
+        .zip(leaves)
+        .map(|(field, leaf)| {
+            let chunk = leaf.map(|leaf| group.column(leaf));
+            match chunk.and_then(|chunk| Some((chunk, chunk.statistics()?))) {
+                Some((chunk, stats)) => {
+                    column_statistics(stats, &field.datatype, chunk.column_descr())
+                }
+                None => ColumnStatistics::unknown(),
+            }
+        })
+        .collect();
+
+    TableStatistics {
+        num_rows: StatisticsValue::Exact(group.num_rows() as usize),
+        columns,
+    }
+}
+
+/// Get the leaf column index for each top-level field in the schema.
+///
+/// None if the field is nested.
+pub fn leaf_indices(metadata: &ParquetMetaData, schema: &Schema) -> Vec<Option<usize>> {
+    let descr = metadata.file_metadata().schema_descr();
+
+    (0..schema.fields.len())
+        .map(|field_idx| {
+            let mut leaves = (0..descr.num_columns())
+                .filter(|&leaf| descr.get_column_root_idx(leaf) == field_idx);
+            let leaf = leaves.next()?;
+            if leaves.next().is_some() || descr.column(leaf).path().parts().len() != 1 {
+                return None;
+            }
+            Some(leaf)
+        })
+        .collect()
+}
+
+fn column_statistics(
+    stats: &Statistics,
+    datatype: &DataType,
+    desc: &ColumnDescriptor,
+) -> ColumnStatistics {
+    let (min, max) = match chunk_min_max(stats, datatype, desc) {
+        Some((min, max)) => {
+            let min = if stats.min_is_exact() {
+                StatisticsValue::Exact(min)
+            } else {
+                StatisticsValue::Estimated(min)
+            };
+            let max = if stats.max_is_exact() {
+                StatisticsValue::Exact(max)
+            } else {
+                StatisticsValue::Estimated(max)
+            };
+            (min, max)
+        }
+        None => (StatisticsValue::Unknown, StatisticsValue::Unknown),
+    };
+
+    let num_distinct = match stats.distinct_count() {
+        Some(count) => StatisticsValue::Exact(count as usize),
+        None => StatisticsValue::Unknown,
+    };
+
+    ColumnStatistics {
+        num_distinct,
+        null_count: StatisticsValue::Exact(stats.null_count() as usize),
+        min,
+        max,
+    }
+}
+
+/// Convert the physical min/max values to scalars matching the column's
+/// logical type.
+fn min_max_scalars(
+    stats: &Statistics,
+    datatype: &DataType,
+    desc: &ColumnDescriptor,
+) -> Option<(OwnedScalarValue, OwnedScalarValue)> {
+    fn both<T, F>(min: &T, max: &T, f: F) -> Option<(OwnedScalarValue, OwnedScalarValue)>
+    where
+        F: Fn(&T) -> Option<OwnedScalarValue>,
+    {
+        Some((f(min)?, f(max)?))
+    }
+
+    match stats {
+        Statistics::Boolean(s) => both(s.min(), s.max(), |&v| bool_scalar(v, datatype)),
+        Statistics::Int32(s) => both(s.min(), s.max(), |&v| int32_scalar(v, datatype, desc)),
+        Statistics::Int64(s) => both(s.min(), s.max(), |&v| int64_scalar(v, datatype, desc)),
+        Statistics::Float(s) => both(s.min(), s.max(), |&v| float_scalar(v, datatype)),
+        Statistics::Double(s) => both(s.min(), s.max(), |&v| double_scalar(v, datatype)),
+        Statistics::ByteArray(s) => both(s.min(), s.max(), |v| byte_array_scalar(v, datatype)),
+        // INT96 timestamps and fixed length byte arrays aren't ordered in a
+        // way we can cheaply use.
+        Statistics::Int96(_) | Statistics::FixedLenByteArray(_) => None,
+    }
+}
+
+/// Get the min and max values from column chunk statistics.
+///
+/// Truncated values are still returned as they remain valid bounds for the
+/// values in the column chunk.
+pub fn chunk_min_max(
+    stats: &Statistics,
+    datatype: &DataType,
+    desc: &ColumnDescriptor,
+) -> Option<(OwnedScalarValue, OwnedScalarValue)> {
+    if !stats.has_min_max_set() || stats.is_min_max_deprecated() {
+        return None;
+    }
+    min_max_scalars(stats, datatype, desc)
+}
+
+pub(crate) fn bool_scalar(v: bool, datatype: &DataType) -> Option<OwnedScalarValue> {
+    match datatype {
+        DataType::Boolean => Some(ScalarValue::Boolean(v)),
+        _ => None,
+    }
+}
+
+pub(crate) fn int32_scalar(
+    v: i32,
+    datatype: &DataType,
+    desc: &ColumnDescriptor,
+) -> Option<OwnedScalarValue> {
+    Some(match datatype {
+        DataType::Int8 => ScalarValue::Int8(v as i8),
+        DataType::Int16 => ScalarValue::Int16(v as i16),
+        DataType::Int32 => ScalarValue::Int32(v),
+        DataType::UInt8 => ScalarValue::UInt8(v as u8),
+        DataType::UInt16 => ScalarValue::UInt16(v as u16),
+        DataType::UInt32 => ScalarValue::UInt32(v as u32),
+        DataType::Date32 => ScalarValue::Date32(v),
+        DataType::Time64 => ScalarValue::Time64(time_micros(v as i64, desc)),
+        DataType::Decimal64(m) => ScalarValue::Decimal64(Decimal64Scalar {
+            precision: m.precision,
+            scale: m.scale,
+            value: v as i64,
+        }),
+        _ => return None,
+    })
+}
+
+pub(crate) fn int64_scalar(
+    v: i64,
+    datatype: &DataType,
+    desc: &ColumnDescriptor,
+) -> Option<OwnedScalarValue> {
+    Some(match datatype {
+        DataType::Int64 => ScalarValue::Int64(v),
+        DataType::UInt64 => ScalarValue::UInt64(v as u64),
+        DataType::Date64 => ScalarValue::Date64(v),
+        DataType::Time64 => ScalarValue::Time64(time_micros(v, desc)),
+        DataType::Timestamp(m) => ScalarValue::Timestamp(TimestampScalar {
+            unit: m.unit,
+            value: v,
+            tz: m.tz.clone(),
+        }),
+        DataType::Decimal64(m) => ScalarValue::Decimal64(Decimal64Scalar {
+            precision: m.precision,
+            scale: m.scale,
+            value: v,
+        }),
+        DataType::Decimal128(m) => ScalarValue::Decimal128(Decimal128Scalar {
+            precision: m.precision,
+            scale: m.scale,
+            value: v as i128,
+        }),
+        _ => return None,
+    })
+}
+
+/// Convert a stored TIME value to microseconds since midnight, matching the
+/// values produced when reading the column.
+pub(crate) fn time_micros(v: i64, desc: &ColumnDescriptor) -> i64 {
+    match time_unit(desc) {
+        ParquetTimeUnit::MILLIS(_) => v * 1000,
+        ParquetTimeUnit::MICROS(_) => v,
+        ParquetTimeUnit::NANOS(_) => v / 1000,
+    }
+}
+
+pub(crate) fn float_scalar(v: f32, datatype: &DataType) -> Option<OwnedScalarValue> {
+    match datatype {
+        DataType::Float32 => Some(ScalarValue::Float32(v)),
+        _ => None,
+    }
+}
+
+pub(crate) fn double_scalar(v: f64, datatype: &DataType) -> Option<OwnedScalarValue> {
+    match datatype {
+        DataType::Float64 => Some(ScalarValue::Float64(v)),
+        _ => None,
+    }
+}
+
+pub(crate) fn byte_array_scalar(v: &ByteArray, datatype: &DataType) -> Option<OwnedScalarValue> {
+    match datatype {
+        DataType::Utf8 => Some(ScalarValue::from(v.as_utf8().ok()?.to_string())),
+        DataType::Binary => Some(ScalarValue::Binary(v.data().to_vec().into())),
+        _ => None,
+    }
+}
@@ -1,16 +1,23 @@
+pub mod nested;
+
 use std::fmt;
 use std::sync::Arc;
 
 use bytes::Bytes;
 use parquet::column::page::{CompressedPage, PageWriteSpec, PageWriter};
-use parquet::column::writer::{get_column_writer, ColumnCloseResult, ColumnWriter};
-use parquet::data_type::ByteArray;
+use parquet::column::writer::{
+    get_column_writer,
+    ColumnCloseResult,
+    ColumnWriter,
+    GenericColumnWriter,
+};
+use parquet::data_type::{ByteArray, FixedLenByteArray};
 use parquet::errors::ParquetError;
 use parquet::file::metadata::ColumnChunkMetaData;
 use parquet::file::properties::{WriterProperties, WriterPropertiesPtr};
 use parquet::file::writer::{write_page, SerializedFileWriter};
 use parquet::format::FileMetaData;
-use parquet::schema::types::SchemaDescriptor;
+use parquet::schema::types::{ColumnDescPtr, SchemaDescriptor};
 use rayexec_error::{not_implemented, OptionExt, RayexecError, Result, ResultExt};
 use rayexec_execution::arrays::array::physical_type::{PhysicalBinary, PhysicalStorage};
 use rayexec_execution::arrays::array::{Array, ArrayData2};
@@ -21,6 +28,7 @@ use rayexec_execution::arrays::storage::AddressableStorage;
 use rayexec_io::FileSink;
 
 use crate::schema::to_parquet_schema;
+use crate::writer::nested::{shred_array, LeafLevels};
 
 /// Writes batches out to a parquet file.
 ///
@@ -129,7 +137,13 @@ impl fmt::Debug for AsyncBatchWriter {
 }
 
 struct RowGroupWriter {
+    /// Fields we're writing, used to determine nullability when shredding
+    /// arrays.
+    schema: Schema,
+    /// Writers for every leaf column in the schema.
     column_writers: Vec<ColumnWriter<BufferedPageWriter>>,
+    /// Descriptors for every leaf column in the schema.
+    column_descs: Vec<ColumnDescPtr>,
     /// Number of rows currently serialized in the row group.
     num_rows: usize,
 }
@@ -140,54 +154,39 @@ impl RowGroupWriter {
         schema: &Schema,
         props: &WriterPropertiesPtr,
     ) -> Result<Self> {
-        let mut leaves = parquet_schema.columns().iter();
-        let mut writers = Vec::with_capacity(schema.fields.len());
-
         for field in schema.fields.iter() {
-            match &field.datatype {
-                DataType::Int8
-                | DataType::Int16
-                | DataType::Int32
-                | DataType::Int64
-                | DataType::UInt8
-                | DataType::UInt16
-                | DataType::UInt32
-                | DataType::UInt64
-                | DataType::Float32
-                | DataType::Float64
-                | DataType::Timestamp(_)
-                | DataType::Decimal64(_)
-                | DataType::Decimal128(_)
-                | DataType::Utf8 => {
-                    let page_writer = BufferedPageWriter {
-                        buf: ColumnBuffer(Vec::new()), // TODO: Could reuse across row groups.
-                    };
-                    let desc = leaves
-                        .next()
-                        .ok_or_else(|| RayexecError::new("Missing column desc"))?;
-                    let writer = get_column_writer(desc.clone(), props.clone(), page_writer);
-                    writers.push(writer);
-                }
-                other => not_implemented!("writer datatype {other}"),
-            }
+            check_writable(&field.datatype)?;
         }
 
+        let column_descs = parquet_schema.columns().to_vec();
+        let writers = column_descs
+            .iter()
+            .map(|desc| {
+                let page_writer = BufferedPageWriter {
+                    buf: ColumnBuffer(Vec::new()), // TODO: Could reuse across row groups.
+                };
+                get_column_writer(desc.clone(), props.clone(), page_writer)
+            })
+            .collect();
+
         Ok(RowGroupWriter {
+            schema: schema.clone(),
             column_writers: writers,
+            column_descs,
             num_rows: 0,
         })
     }
 
     fn write(&mut self, batch: &Batch) -> Result<()> {
-        for (writer, col) in self.column_writers.iter_mut().zip(batch.arrays()) {
-            // if col.has_selection() {
-            //     unimplemented!()
-            //     // let unselected_array = col.unselect()?;
-            //     // write_array(writer, &unselected_array)?;
-            // } else {
-            //     write_array(writer, col)?;
-            // }
-            write_array(writer, col)?;
+        let mut writers = self.column_writers.iter_mut().zip(&self.column_descs);
+
+        for (field, col) in self.schema.fields.iter().zip(batch.arrays()) {
+            for leaf in shred_array(col, field.nullable)? {
+                let (writer, desc) = writers
+                    .next()
+                    .ok_or_else(|| RayexecError::new("Missing column writer"))?;
+                write_leaf(writer, desc, &leaf)?;
+            }
         }
 
         self.num_rows += batch.num_rows();
@@ -234,9 +233,63 @@ impl PageWriter for BufferedPageWriter {
     }
 }
 
+/// Check that we're able to write a data type, recursing into nested types.
+fn check_writable(datatype: &DataType) -> Result<()> {
+    match datatype {
+        DataType::Boolean
+        | DataType::Int8
+        | DataType::Int16
+        | DataType::Int32
+        | DataType::Int64
+        | DataType::UInt8
+        | DataType::UInt16
+        | DataType::UInt32
+        | DataType::UInt64
+        | DataType::Float32
+        | DataType::Float64
+        | DataType::Timestamp(_)
+        | DataType::Date32
+        | DataType::Time64
+        | DataType::Decimal64(_)
+        | DataType::Decimal128(_)
+        | DataType::Uuid
+        | DataType::Utf8
+        | DataType::Binary => Ok(()),
+        DataType::List(meta) => check_writable(&meta.datatype),
+        DataType::Struct(meta) => {
+            for field in &meta.fields {
+                check_writable(&field.datatype)?;
+            }
+            Ok(())
+        }
+        other => not_implemented!("writer datatype {other}"),
+    }
+}
+
+/// Write the values and levels for a leaf into its column writer.
+///
+/// Levels are only provided to the writer if the column has them.
+fn write_leaf<P: PageWriter>(
+    writer: &mut ColumnWriter<P>,
+    desc: &ColumnDescPtr,
+    leaf: &LeafLevels,
+) -> Result<()> {
+    let def_levels = (desc.max_def_level() > 0).then_some(leaf.def_levels.as_slice());
+    let rep_levels = (desc.max_rep_level() > 0).then_some(leaf.rep_levels.as_slice());
+
+    write_array(writer, &leaf.values, def_levels, rep_levels)
+}
+
 /// Write an array into the column writer.
-// TODO: Validity.
-fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Result<()> {
+///
+/// The array should only contain non-null values, with nulls being indicated
+/// by the definition levels.
+fn write_array<P: PageWriter>(
+    writer: &mut ColumnWriter<P>,
+    array: &Array,
+    def_levels: Option<&[i16]>,
+    rep_levels: Option<&[i16]>,
+) -> Result<()> {
     if array.has_selection() {
         return Err(RayexecError::new(
             "Array needs to be unselected before it can be written",
@@ -249,8 +302,8 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
                 ArrayData2::Boolean(d) => {
                     let bools: Vec<_> = d.as_ref().as_ref().iter().collect();
                     writer
-                        .write_batch(&bools, None, None)
-                        .context("failed to write bools")?; // TODO: Def, rep
+                        .write_batch(&bools, def_levels, rep_levels)
+                        .context("failed to write bools")?;
                     Ok(())
                 }
                 _ => Err(RayexecError::new("expected bool data")),
@@ -259,7 +312,7 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
         ColumnWriter::Int32ColumnWriter(writer) => match array.array_data() {
             ArrayData2::Int32(d) => {
                 writer
-                    .write_batch(d.as_slice(), None, None)
+                    .write_batch(d.as_slice(), def_levels, rep_levels)
                     .context("failed to write i32 data")?;
                 Ok(())
             }
@@ -268,16 +321,22 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
                 // upstream behavior.
                 let data = unsafe { d.try_reintepret_cast::<i32>()? };
                 writer
-                    .write_batch(data.as_slice(), None, None)
+                    .write_batch(data.as_slice(), def_levels, rep_levels)
                     .context("failed to write i32 data")?;
                 Ok(())
             }
+            ArrayData2::Int8(d) => write_widened_i32(writer, d.as_slice(), def_levels, rep_levels),
+            ArrayData2::Int16(d) => write_widened_i32(writer, d.as_slice(), def_levels, rep_levels),
+            ArrayData2::UInt8(d) => write_widened_i32(writer, d.as_slice(), def_levels, rep_levels),
+            ArrayData2::UInt16(d) => {
+                write_widened_i32(writer, d.as_slice(), def_levels, rep_levels)
+            }
             _ => Err(RayexecError::new("expected i32/u32 data")),
         },
         ColumnWriter::Int64ColumnWriter(writer) => match array.array_data() {
             ArrayData2::Int64(d) => {
                 writer
-                    .write_batch(d.as_slice(), None, None)
+                    .write_batch(d.as_slice(), def_levels, rep_levels)
                     .context("failed to write i64 data")?;
                 Ok(())
             }
@@ -286,7 +345,7 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
                 // upstream behavior.
                 let data = unsafe { d.try_reintepret_cast::<i64>()? };
                 writer
-                    .write_batch(data.as_slice(), None, None)
+                    .write_batch(data.as_slice(), def_levels, rep_levels)
                     .context("failed to write i64 data")?;
                 Ok(())
             }
@@ -295,7 +354,7 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
         ColumnWriter::FloatColumnWriter(writer) => match array.array_data() {
             ArrayData2::Float32(d) => {
                 writer
-                    .write_batch(d.as_slice(), None, None)
+                    .write_batch(d.as_slice(), def_levels, rep_levels)
                     .context("failed to write f32 data")?;
                 Ok(())
             }
@@ -304,7 +363,7 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
         ColumnWriter::DoubleColumnWriter(writer) => match array.array_data() {
             ArrayData2::Float64(d) => {
                 writer
-                    .write_batch(d.as_slice(), None, None)
+                    .write_batch(d.as_slice(), def_levels, rep_levels)
                     .context("failed to write f64 data")?;
                 Ok(())
             }
@@ -324,7 +383,7 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
                 }
 
                 writer
-                    .write_batch(&data, None, None)
+                    .write_batch(&data, def_levels, rep_levels)
                     .context("failed to write binary data")?;
 
                 Ok(())
@@ -332,8 +391,46 @@ fn write_array<P: PageWriter>(writer: &mut ColumnWriter<P>, array: &Array) -> Re
             _ => Err(RayexecError::new("expected binary data")),
         },
         ColumnWriter::Int96ColumnWriter(_) => not_implemented!("int96 writer"),
-        ColumnWriter::FixedLenByteArrayColumnWriter(_) => {
-            not_implemented!("fixed byte array writer")
+        ColumnWriter::FixedLenByteArrayColumnWriter(writer) => {
+            // Decimal128 and UUIDs are both written as 16 byte big endian
+            // values.
+            let data: Vec<_> = match array.array_data() {
+                ArrayData2::Int128(d) => d
+                    .as_slice()
+                    .iter()
+                    .map(|v| FixedLenByteArray::from(v.to_be_bytes().to_vec()))
+                    .collect(),
+                ArrayData2::UInt128(d) => d
+                    .as_slice()
+                    .iter()
+                    .map(|v| FixedLenByteArray::from(v.to_be_bytes().to_vec()))
+                    .collect(),
+                _ => return Err(RayexecError::new("expected i128/u128 data")),
+            };
+
+            writer
+                .write_batch(&data, def_levels, rep_levels)
+                .context("failed to write fixed len byte array data")?;
+
+            Ok(())
         }
     }
 }
+
+/// Write smaller integers to an INT32 column.
+fn write_widened_i32<P, T>(
+    writer: &mut GenericColumnWriter<i32, P>,
+    values: &[T],
+    def_levels: Option<&[i16]>,
+    rep_levels: Option<&[i16]>,
+) -> Result<()>
+where
+    P: PageWriter,
+    T: Copy + Into<i32>,
+{
+    let data: Vec<i32> = values.iter().map(|&v| v.into()).collect();
+    writer
+        .write_batch(&data, def_levels, rep_levels)
+        .context("failed to write i32 data")?;
+    Ok(())
+}
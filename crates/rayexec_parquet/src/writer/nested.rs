@@ -0,0 +1,169 @@
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::array::physical_type::PhysicalList;
+use rayexec_execution::arrays::array::{Array, ArrayData2};
+use rayexec_execution::arrays::datatype::DataType;
+use rayexec_execution::arrays::executor::scalar::{
+    interleave,
+    struct_child_with_selection,
+    UnaryExecutor,
+};
+
+/// Values and levels to write for a single leaf column.
+#[derive(Debug)]
+pub struct LeafLevels {
+    /// Non-null values for the leaf, one for every level at the max definition
+    /// level.
+    pub values: Array,
+    pub def_levels: Vec<i16>,
+    pub rep_levels: Vec<i16>,
+}
+
+/// A value position within a (possibly nested) array.
+#[derive(Debug, Clone, Copy)]
+struct Slot {
+    /// Row in the array for this slot. None if the slot is null or an ancestor
+    /// is null or an empty list.
+    row: Option<usize>,
+    /// Definition level reached so far.
+    def: i16,
+    /// Repetition level for the slot.
+    rep: i16,
+}
+
+/// Shred an array for a top-level field into values and levels for each of
+/// its leaf columns.
+///
+/// Leaves are returned in the same order as the leaf columns in the parquet
+/// schema produced for the field.
+pub fn shred_array(array: &Array, nullable: bool) -> Result<Vec<LeafLevels>> {
+    let slots: Vec<_> = (0..array.logical_len())
+        .map(|row| Slot {
+            row: Some(row),
+            def: 0,
+            rep: 0,
+        })
+        .collect();
+
+    let mut leaves = Vec::new();
+    shred(array, nullable, &slots, 0, &mut leaves)?;
+
+    Ok(leaves)
+}
+
+fn shred(
+    array: &Array,
+    nullable: bool,
+    slots: &[Slot],
+    rep_depth: i16,
+    leaves: &mut Vec<LeafLevels>,
+) -> Result<()> {
+    // Apply validity for this array. Optional fields get an additional
+    // definition level when the value is non-null.
+    let slots = slots
+        .iter()
+        .map(|slot| {
+            let row = match slot.row {
+                Some(row) => row,
+                None => return Ok(*slot),
+            };
+
+            let valid = array.is_valid(row).unwrap_or(false);
+            match (valid, nullable) {
+                (true, true) => Ok(Slot {
+                    def: slot.def + 1,
+                    ..*slot
+                }),
+                (true, false) => Ok(*slot),
+                (false, true) => Ok(Slot { row: None, ..*slot }),
+                (false, false) => Err(RayexecError::new(
+                    "Cannot write null value to a non-nullable parquet column",
+                )),
+            }
+        })
+        .collect::<Result<Vec<_>>>()?;
+
+    match array.datatype() {
+        DataType::Struct(meta) => {
+            for (child_idx, field) in meta.fields.iter().enumerate() {
+                let child = struct_child_with_selection(array, child_idx)?;
+                shred(&child, field.nullable, &slots, rep_depth, leaves)?;
+            }
+            Ok(())
+        }
+        DataType::List(_) => {
+            let child = match array.array_data() {
+                ArrayData2::List(list) => list.inner_array(),
+                other => {
+                    return Err(RayexecError::new(format!(
+                        "Expected list array data, got {:?}",
+                        other.physical_type()
+                    )))
+                }
+            };
+
+            // List offsets index directly into the child.
+            if child.has_selection() {
+                return Err(RayexecError::new("List child array has selection"));
+            }
+
+            let mut metadatas = Vec::with_capacity(array.logical_len());
+            UnaryExecutor::for_each2::<PhysicalList, _>(array, |_row, metadata| {
+                metadatas.push(metadata.map(|m| (m.offset as usize, m.len as usize)))
+            })?;
+
+            let element_rep = rep_depth + 1;
+            let mut child_slots = Vec::with_capacity(slots.len());
+
+            for slot in slots {
+                match slot.row.and_then(|row| metadatas[row]) {
+                    Some((offset, len)) if len > 0 => {
+                        // The repeated group adds a definition level for
+                        // non-empty lists.
+                        child_slots.extend((0..len).map(|idx| Slot {
+                            row: Some(offset + idx),
+                            def: slot.def + 1,
+                            rep: if idx == 0 { slot.rep } else { element_rep },
+                        }));
+                    }
+                    _ => child_slots.push(Slot { row: None, ..slot }),
+                }
+            }
+
+            // List elements are always written as optional.
+            shred(child, true, &child_slots, element_rep, leaves)
+        }
+        _ => {
+            let mut indices = Vec::new();
+            let mut def_levels = Vec::with_capacity(slots.len());
+            let mut rep_levels = Vec::with_capacity(slots.len());
+
+            for slot in slots {
+                def_levels.push(slot.def);
+                rep_levels.push(slot.rep);
+                if let Some(row) = slot.row {
+                    indices.push((0, row));
+                }
+            }
+
+            // Avoid copying if we're writing every row as is.
+            let is_identity = indices.len() == array.logical_len()
+                && indices
+                    .iter()
+                    .enumerate()
+                    .all(|(idx, &(_, row))| idx == row);
+            let values = if is_identity && !array.has_selection() {
+                array.clone()
+            } else {
+                interleave(&[array], &indices)?
+            };
+
+            leaves.push(LeafLevels {
+                values,
+                def_levels,
+                rep_levels,
+            });
+
+            Ok(())
+        }
+    }
+}
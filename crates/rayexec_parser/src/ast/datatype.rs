@@ -1,7 +1,7 @@
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, Expr};
+use super::{AstParseable, Expr, Ident};
 use crate::keywords::Keyword;
 use crate::parser::Parser;
 use crate::tokens::Token;
@@ -30,10 +30,30 @@ pub enum DataType {
     Bool,
     /// DATE
     Date,
-    /// TIMESTAMP
+    /// TIMESTAMP, TIMESTAMP WITHOUT TIME ZONE
     Timestamp,
+    /// TIMESTAMPTZ, TIMESTAMP WITH TIME ZONE
+    TimestampTz,
     /// INTERVAL
     Interval,
+    /// TIME, TIME WITHOUT TIME ZONE
+    Time,
+    /// BLOB, BYTEA, BINARY, VARBINARY
+    Blob,
+    /// UUID
+    Uuid,
+    /// LIST(<type>), <type>[]
+    List(Box<DataType>),
+    /// STRUCT(<name> <type>, ...)
+    Struct(Vec<StructField>),
+    /// MAP(<key>, <value>)
+    Map(Box<DataType>, Box<DataType>),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StructField {
+    pub name: Ident,
+    pub datatype: DataType,
 }
 
 impl AstParseable for DataType {
@@ -46,7 +66,7 @@ impl AstParseable for DataType {
             None => return Err(RayexecError::new("Unexpected end of query")),
         };
 
-        Ok(match kw {
+        let mut datatype = match kw {
             Keyword::VARCHAR => DataType::Varchar(None), // TODO: With length.
             Keyword::TEXT | Keyword::STRING => DataType::Varchar(None),
             Keyword::TINYINT | Keyword::INT1 => DataType::TinyInt,
@@ -62,14 +82,68 @@ impl AstParseable for DataType {
             }
             Keyword::BOOL | Keyword::BOOLEAN => DataType::Bool,
             Keyword::DATE => DataType::Date,
-            Keyword::TIMESTAMP => DataType::Timestamp,
+            Keyword::TIMESTAMP => {
+                if parser.parse_keyword_sequence(&[Keyword::WITH, Keyword::TIME, Keyword::ZONE]) {
+                    DataType::TimestampTz
+                } else {
+                    let _ = parser.parse_keyword_sequence(&[
+                        Keyword::WITHOUT,
+                        Keyword::TIME,
+                        Keyword::ZONE,
+                    ]);
+                    DataType::Timestamp
+                }
+            }
+            Keyword::TIMESTAMPTZ => DataType::TimestampTz,
             Keyword::INTERVAL => DataType::Interval,
+            Keyword::TIME => {
+                let _ = parser.parse_keyword_sequence(&[
+                    Keyword::WITHOUT,
+                    Keyword::TIME,
+                    Keyword::ZONE,
+                ]);
+                DataType::Time
+            }
+            Keyword::BLOB | Keyword::BYTEA | Keyword::BINARY | Keyword::VARBINARY => DataType::Blob,
+            Keyword::UUID => DataType::Uuid,
+            Keyword::LIST => {
+                parser.expect_token(&Token::LeftParen)?;
+                let child = DataType::parse(parser)?;
+                parser.expect_token(&Token::RightParen)?;
+                DataType::List(Box::new(child))
+            }
+            Keyword::STRUCT => {
+                parser.expect_token(&Token::LeftParen)?;
+                let fields = parser.parse_comma_separated(|parser| {
+                    let name = Ident::parse(parser)?;
+                    let datatype = DataType::parse(parser)?;
+                    Ok(StructField { name, datatype })
+                })?;
+                parser.expect_token(&Token::RightParen)?;
+                DataType::Struct(fields)
+            }
+            Keyword::MAP => {
+                parser.expect_token(&Token::LeftParen)?;
+                let key = DataType::parse(parser)?;
+                parser.expect_token(&Token::Comma)?;
+                let value = DataType::parse(parser)?;
+                parser.expect_token(&Token::RightParen)?;
+                DataType::Map(Box::new(key), Box::new(value))
+            }
             other => {
                 return Err(RayexecError::new(format!(
                     "Unexpected keyword for data type: {other:?}",
                 )))
             }
-        })
+        };
+
+        // Postfix array syntax, e.g. `INT[]` or `INT[][]`.
+        while parser.consume_token(&Token::LeftBracket) {
+            parser.expect_token(&Token::RightBracket)?;
+            datatype = DataType::List(Box::new(datatype));
+        }
+
+        Ok(datatype)
     }
 }
 
@@ -131,8 +205,20 @@ mod tests {
         assert_ast_eq(DataType::Date, "date");
 
         assert_ast_eq(DataType::Timestamp, "TIMESTAMP");
+        assert_ast_eq(DataType::Timestamp, "timestamp without time zone");
+        assert_ast_eq(DataType::TimestampTz, "timestamptz");
+        assert_ast_eq(DataType::TimestampTz, "timestamp with time zone");
 
         assert_ast_eq(DataType::Interval, "INTERVAL");
+
+        assert_ast_eq(DataType::Time, "time");
+        assert_ast_eq(DataType::Time, "time without time zone");
+
+        assert_ast_eq(DataType::Blob, "blob");
+        assert_ast_eq(DataType::Blob, "bytea");
+        assert_ast_eq(DataType::Blob, "varbinary");
+
+        assert_ast_eq(DataType::Uuid, "uuid");
     }
 
     #[test]
@@ -144,4 +230,49 @@ mod tests {
 
         assert_ast_eq(DataType::Decimal(Some(4), Some(1)), "numeric(4, 1)");
     }
+
+    #[test]
+    fn list() {
+        assert_ast_eq(DataType::List(Box::new(DataType::Integer)), "int[]");
+        assert_ast_eq(DataType::List(Box::new(DataType::Integer)), "LIST(INT)");
+        assert_ast_eq(
+            DataType::List(Box::new(DataType::List(Box::new(DataType::Varchar(None))))),
+            "varchar[][]",
+        );
+    }
+
+    #[test]
+    fn struct_type() {
+        assert_ast_eq(
+            DataType::Struct(vec![
+                StructField {
+                    name: Ident::new_unquoted("lat"),
+                    datatype: DataType::Double,
+                },
+                StructField {
+                    name: Ident::new_unquoted("lon"),
+                    datatype: DataType::Double,
+                },
+            ]),
+            "STRUCT(lat DOUBLE, lon DOUBLE)",
+        );
+        assert_ast_eq(
+            DataType::List(Box::new(DataType::Struct(vec![StructField {
+                name: Ident::new_unquoted("a"),
+                datatype: DataType::List(Box::new(DataType::BigInt)),
+            }]))),
+            "struct(a bigint[])[]",
+        );
+    }
+
+    #[test]
+    fn map() {
+        assert_ast_eq(
+            DataType::Map(
+                Box::new(DataType::Varchar(None)),
+                Box::new(DataType::Integer),
+            ),
+            "MAP(VARCHAR, INT)",
+        );
+    }
 }
@@ -0,0 +1,105 @@
+use rayexec_error::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{AstParseable, Expr, FromNode, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Delete<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub using: Option<FromNode<T>>,
+    pub where_expr: Option<Expr<T>>,
+}
+
+impl AstParseable for Delete<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::DELETE)?;
+        parser.expect_keyword(Keyword::FROM)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        let using = if parser.parse_keyword(Keyword::USING) {
+            Some(FromNode::parse(parser)?)
+        } else {
+            None
+        };
+
+        let where_expr = if parser.parse_keyword(Keyword::WHERE) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        Ok(Delete {
+            table,
+            alias,
+            using,
+            where_expr,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, FromBaseTable, FromNodeBody, Literal};
+
+    #[test]
+    fn basic() {
+        let got = parse_ast("delete from t1").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            using: None,
+            where_expr: None,
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn alias_with_where() {
+        let got = parse_ast("delete from t1 a where c1 = 1").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("a")),
+            using: None,
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("c1"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Literal(Literal::Number("1".to_string()))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn with_using() {
+        let got = parse_ast("delete from t1 using t2 where c1 = c2").unwrap();
+        let expected = Delete {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            using: Some(FromNode {
+                alias: None,
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            }),
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("c1"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Ident(Ident::new_unquoted("c2"))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn missing_from() {
+        parse_ast::<Delete<_>>("delete t1").unwrap_err();
+    }
+}
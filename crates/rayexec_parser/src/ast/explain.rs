@@ -20,6 +20,8 @@ pub struct ExplainNode<T: AstMeta> {
     pub verbose: bool,
     pub body: ExplainBody<T>,
     pub output: Option<ExplainOutput>,
+    /// If timings should be included in EXPLAIN ANALYZE output.
+    pub timing: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -34,21 +36,47 @@ impl AstParseable for ExplainNode<Raw> {
         let analyze = parser.parse_keyword(Keyword::ANALYZE);
         let verbose = parser.parse_keyword(Keyword::VERBOSE);
 
-        let output = if parser.consume_token(&Token::LeftParen) {
-            // Just FORMAT for now.
-            parser.expect_keyword(Keyword::FORMAT)?;
-            let format = if parser.parse_keyword(Keyword::JSON) {
-                ExplainOutput::Json
-            } else if parser.parse_keyword(Keyword::TEXT) {
-                ExplainOutput::Text
-            } else {
-                return Err(RayexecError::new("Expect JSON or TEXT for explain format"));
-            };
+        let mut output = None;
+        let mut timing = None;
+        if parser.consume_token(&Token::LeftParen) {
+            loop {
+                if parser.parse_keyword(Keyword::FORMAT) {
+                    let format = if parser.parse_keyword(Keyword::JSON) {
+                        ExplainOutput::Json
+                    } else if parser.parse_keyword(Keyword::TEXT) {
+                        ExplainOutput::Text
+                    } else {
+                        return Err(RayexecError::new("Expect JSON or TEXT for explain format"));
+                    };
+                    output = Some(format);
+                } else if parser.parse_keyword(Keyword::TIMING) {
+                    let enabled = if parser.parse_keyword(Keyword::OFF)
+                        || parser.parse_keyword(Keyword::FALSE)
+                    {
+                        false
+                    } else {
+                        // 'TIMING' on its own enables timing.
+                        let _ = parser.parse_keyword(Keyword::ON)
+                            || parser.parse_keyword(Keyword::TRUE);
+                        true
+                    };
+                    timing = Some(enabled);
+                } else {
+                    return Err(RayexecError::new(
+                        "Expected FORMAT or TIMING explain option",
+                    ));
+                }
+
+                if !parser.consume_token(&Token::Comma) {
+                    break;
+                }
+            }
             parser.expect_token(&Token::RightParen)?;
-            Some(format)
-        } else {
-            None
-        };
+        }
+
+        if timing.is_some() && !analyze {
+            return Err(RayexecError::new("EXPLAIN option TIMING requires ANALYZE"));
+        }
 
         let body = match parser.parse_statement()? {
             Statement::Query(query) => ExplainBody::Query(query),
@@ -64,6 +92,7 @@ impl AstParseable for ExplainNode<Raw> {
             verbose,
             body,
             output,
+            timing: timing.unwrap_or(true),
         })
     }
 }
@@ -85,6 +114,7 @@ mod tests {
                 where_expr: None,
                 group_by: None,
                 having: None,
+                windows: Vec::new(),
             })),
             order_by: None,
             limit: LimitModifier {
@@ -102,6 +132,7 @@ mod tests {
             verbose: false,
             body: ExplainBody::Query(query_node_select_1()),
             output: None,
+            timing: true,
         };
         assert_eq!(expected, explain)
     }
@@ -114,6 +145,7 @@ mod tests {
             verbose: false,
             body: ExplainBody::Query(query_node_select_1()),
             output: Some(ExplainOutput::Json),
+            timing: true,
         };
         assert_eq!(expected, explain)
     }
@@ -126,6 +158,7 @@ mod tests {
             verbose: false,
             body: ExplainBody::Query(query_node_select_1()),
             output: Some(ExplainOutput::Text),
+            timing: true,
         };
         assert_eq!(expected, explain)
     }
@@ -143,6 +176,7 @@ mod tests {
             verbose: false,
             body: ExplainBody::Query(query_node_select_1()),
             output: None,
+            timing: true,
         };
         assert_eq!(expected, explain)
     }
@@ -155,6 +189,7 @@ mod tests {
             verbose: true,
             body: ExplainBody::Query(query_node_select_1()),
             output: None,
+            timing: true,
         };
         assert_eq!(expected, explain)
     }
@@ -167,10 +202,30 @@ mod tests {
             verbose: true,
             body: ExplainBody::Query(query_node_select_1()),
             output: None,
+            timing: true,
+        };
+        assert_eq!(expected, explain)
+    }
+
+    #[test]
+    fn analyze_timing_off() {
+        let explain: ExplainNode<_> =
+            parse_ast("explain analyze (format json, timing off) select 1").unwrap();
+        let expected = ExplainNode {
+            analyze: true,
+            verbose: false,
+            body: ExplainBody::Query(query_node_select_1()),
+            output: Some(ExplainOutput::Json),
+            timing: false,
         };
         assert_eq!(expected, explain)
     }
 
+    #[test]
+    fn timing_requires_analyze() {
+        let _ = parse_ast::<ExplainNode<_>>("explain (timing off) select 1").unwrap_err();
+    }
+
     #[test]
     fn verbose_analyze() {
         let _ = parse_ast::<ExplainNode<_>>("explain verbose analyze select 1").unwrap_err();
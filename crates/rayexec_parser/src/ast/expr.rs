@@ -176,6 +176,10 @@ pub enum Expr<T: AstMeta> {
     QualifiedWildcard(Vec<Ident>),
     /// An expression literal,
     Literal(Literal<T>),
+    /// A 1-based positional parameter.
+    ///
+    /// `$1` or `?`
+    Parameter(usize),
     /// [<expr1>, <expr2>, ...]
     Array(Vec<Expr<T>>),
     /// COLUMNS(...)
@@ -314,6 +318,13 @@ pub enum Expr<T: AstMeta> {
         date_part: DatePart,
         expr: Box<Expr<T>>,
     },
+    /// Time zone conversion.
+    ///
+    /// `<timestamp> AT TIME ZONE <zone>`
+    AtTimeZone {
+        timestamp: Box<Expr<T>>,
+        zone: Box<Expr<T>>,
+    },
 }
 
 impl AstParseable for Expr<Raw> {
@@ -335,8 +346,8 @@ impl Expr<Raw> {
     const PREC_MUL_DIV_MOD: u8 = 90;
     const _PREC_EXPONENTIATION: u8 = 100;
     const PREC_UNARY_MINUS: u8 = 105;
-    const _PREC_AT: u8 = 110; // AT TIME ZONE
-    const _PREC_COLLATE: u8 = 120;
+    const PREC_AT: u8 = 110; // AT TIME ZONE
+    const PREC_COLLATE: u8 = 120;
     const PREC_ARRAY_ELEM: u8 = 130; // []
     const PREC_CAST: u8 = 140; // ::
 
@@ -360,19 +371,24 @@ impl Expr<Raw> {
         //
         // DATE '1992-10-11'
         // BOOL 'true'
-        match parser.maybe_parse(DataType::parse) {
+        //
+        // The string literal is required to be part of the typed string so
+        // that columns sharing a name with a type (e.g. `uuid`) can still be
+        // referenced.
+        let typed = parser.maybe_parse(|parser| match DataType::parse(parser)? {
             // INTERVAL is a special case.
-            Some(DataType::Interval) => {
+            DataType::Interval => Ok(None),
+            datatype => {
+                let value = Self::parse_string_literal(parser)?;
+                Ok(Some(Expr::TypedString { datatype, value }))
+            }
+        });
+        match typed {
+            Some(Some(expr)) => return Ok(expr),
+            Some(None) => {
                 let interval = Interval::parse(parser)?;
                 return Ok(Expr::Interval(interval));
             }
-            Some(dt) => {
-                let s = Self::parse_string_literal(parser)?;
-                return Ok(Expr::TypedString {
-                    datatype: dt,
-                    value: s,
-                });
-            }
             None => (), // Continue trying to parse a normal expression.
         }
 
@@ -530,8 +546,42 @@ impl Expr<Raw> {
                     expr
                 }
             }
+            Token::LeftBrace => {
+                // Struct literal, e.g. `{'a': 1, 'b': 2}`
+                let mut keys = Vec::new();
+                let mut values = Vec::new();
+                if !parser.consume_token(&Token::RightBrace) {
+                    loop {
+                        let key = match parser.next().map(|t| &t.token) {
+                            Some(Token::SingleQuotedString(s)) => s.clone(),
+                            Some(Token::Word(w)) => w.value.clone(),
+                            other => {
+                                return Err(RayexecError::new(format!(
+                                    "Expected struct key, got {other:?}"
+                                )))
+                            }
+                        };
+                        parser.expect_token(&Token::Colon)?;
+                        keys.push(key);
+                        values.push(Expr::parse(parser)?);
+
+                        if !parser.consume_token(&Token::Comma) {
+                            break;
+                        }
+                    }
+                    parser.expect_token(&Token::RightBrace)?;
+                }
+                Expr::Literal(Literal::Struct { keys, values })
+            }
             Token::SingleQuotedString(s) => Expr::Literal(Literal::SingleQuotedString(s.clone())),
             Token::Number(s) => Expr::Literal(Literal::Number(s.clone())),
+            Token::Parameter(n) => {
+                if *n == 0 {
+                    return Err(RayexecError::new("Parameter numbers start at $1"));
+                }
+                Expr::Parameter(*n)
+            }
+            Token::QuestionMark => Expr::Parameter(parser.next_anonymous_parameter()),
             Token::LeftParen => {
                 let expr = if QueryNode::is_query_node_start(parser) {
                     let subquery = QueryNode::parse(parser)?;
@@ -639,6 +689,18 @@ impl Expr<Raw> {
             };
 
             match kw {
+                Keyword::AT => {
+                    parser.expect_keyword(Keyword::TIME)?;
+                    parser.expect_keyword(Keyword::ZONE)?;
+                    Ok(Expr::AtTimeZone {
+                        timestamp: Box::new(prefix),
+                        zone: Box::new(Expr::parse_subexpr(parser, Self::PREC_AT)?),
+                    })
+                }
+                Keyword::COLLATE => Ok(Expr::Collate {
+                    expr: Box::new(prefix),
+                    collation: ObjectReference::parse(parser)?,
+                }),
                 Keyword::IS => match parser.next_keyword()? {
                     Keyword::NULL => Ok(Expr::IsNull {
                         expr: Box::new(prefix),
@@ -833,6 +895,15 @@ impl Expr<Raw> {
                     _ => Ok(Self::PREC_IS),
                 }
             }
+            Token::Word(w) if w.keyword == Some(Keyword::AT) => {
+                // Only an operator if followed by TIME ZONE, otherwise it may
+                // be an alias.
+                match parser.peek_nth(1).and_then(|tok| tok.keyword()) {
+                    Some(Keyword::TIME) => Ok(Self::PREC_AT),
+                    _ => Ok(0),
+                }
+            }
+            Token::Word(w) if w.keyword == Some(Keyword::COLLATE) => Ok(Self::PREC_COLLATE),
             Token::Word(w) if w.keyword == Some(Keyword::IN) => Ok(Self::PREC_CONTAINMENT),
             Token::Word(w) if w.keyword == Some(Keyword::BETWEEN) => Ok(Self::PREC_CONTAINMENT),
 
@@ -1317,6 +1388,36 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn at_time_zone() {
+        let expr: Expr<_> = parse_ast("a AT TIME ZONE 'UTC' + b").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::AtTimeZone {
+                timestamp: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                zone: Box::new(Expr::Literal(Literal::SingleQuotedString(
+                    "UTC".to_string(),
+                ))),
+            }),
+            op: BinaryOperator::Plus,
+            right: Box::new(Expr::Ident(Ident::new_unquoted("b"))),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn collate() {
+        let expr: Expr<_> = parse_ast("a COLLATE nocase.noaccent = b").unwrap();
+        let expected = Expr::BinaryExpr {
+            left: Box::new(Expr::Collate {
+                expr: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+                collation: ObjectReference::from_strings(["nocase", "noaccent"]),
+            }),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::Ident(Ident::new_unquoted("b"))),
+        };
+        assert_eq!(expected, expr);
+    }
+
     #[test]
     fn binary_op() {
         let expr: Expr<_> = parse_ast("5 + 8").unwrap();
@@ -1514,6 +1615,22 @@ mod tests {
         assert_eq!(expected, expr);
     }
 
+    #[test]
+    fn uuid_typed_string() {
+        let expr: Expr<_> = parse_ast("uuid 'a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11'").unwrap();
+        let expected = Expr::TypedString {
+            datatype: DataType::Uuid,
+            value: "a0eebc99-9c0b-4ef8-bb6d-6bb9bd380a11".to_string(),
+        };
+        assert_eq!(expected, expr);
+    }
+
+    #[test]
+    fn type_keyword_as_column() {
+        let expr: Expr<_> = parse_ast("uuid").unwrap();
+        assert_eq!(Expr::Ident(Ident::new_unquoted("uuid")), expr);
+    }
+
     #[test]
     fn double_colon_cast() {
         let expr: Expr<_> = parse_ast("4::TEXT").unwrap();
@@ -1689,6 +1806,29 @@ mod tests {
         assert_eq!(expected, expr)
     }
 
+    #[test]
+    fn struct_literal() {
+        let expr: Expr<_> = parse_ast("{'lat': 52.5, lon: 13.4}").unwrap();
+        let expected = Expr::Literal(Literal::Struct {
+            keys: vec!["lat".to_string(), "lon".to_string()],
+            values: vec![
+                Expr::Literal(Literal::Number("52.5".to_string())),
+                Expr::Literal(Literal::Number("13.4".to_string())),
+            ],
+        });
+        assert_eq!(expected, expr)
+    }
+
+    #[test]
+    fn cast_to_list() {
+        let expr: Expr<_> = parse_ast("CAST(a AS INT[])").unwrap();
+        let expected = Expr::Cast {
+            datatype: DataType::List(Box::new(DataType::Integer)),
+            expr: Box::new(Expr::Ident(Ident::new_unquoted("a"))),
+        };
+        assert_eq!(expected, expr)
+    }
+
     #[test]
     fn string_contains_sugar() {
         let expr: Expr<_> = parse_ast("s1 ^@ s2").unwrap();
@@ -146,6 +146,11 @@ impl AstParseable for FromNode<Raw> {
                         parser.expect_keyword(Keyword::JOIN)?;
                         JoinType::LeftSemi
                     }
+                    Keyword::ANTI => {
+                        parser.expect_keyword(Keyword::ANTI)?;
+                        parser.expect_keyword(Keyword::JOIN)?;
+                        JoinType::LeftAnti
+                    }
                     _ => return Ok(node), // Unknown join keyword, probably time to start working on a different part of the query.
                 };
 
@@ -628,6 +633,31 @@ mod tests {
         assert_eq!(expected, node);
     }
 
+    #[test]
+    fn anti_join_using() {
+        let node: FromNode<_> = parse_ast("table1 ANTI JOIN table2 USING (c1)").unwrap();
+        let expected = FromNode {
+            alias: None,
+            body: FromNodeBody::Join(FromJoin {
+                left: Box::new(FromNode {
+                    alias: None,
+                    body: FromNodeBody::BaseTable(FromBaseTable {
+                        reference: ObjectReference::from_strings(["table1"]),
+                    }),
+                }),
+                right: Box::new(FromNode {
+                    alias: None,
+                    body: FromNodeBody::BaseTable(FromBaseTable {
+                        reference: ObjectReference::from_strings(["table2"]),
+                    }),
+                }),
+                join_type: JoinType::LeftAnti,
+                join_condition: JoinCondition::Using(vec![Ident::new_unquoted("c1")]),
+            }),
+        };
+        assert_eq!(expected, node);
+    }
+
     #[test]
     fn nested_join() {
         let node: FromNode<_> = parse_ast("t1 LEFT JOIN t2 RIGHT JOIN t3").unwrap();
@@ -0,0 +1,275 @@
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{Assignment, AstParseable, Expr, FromNode, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+use crate::tokens::Token;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Merge<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub source: FromNode<T>,
+    pub condition: Expr<T>,
+    pub clauses: Vec<MergeClause<T>>,
+}
+
+/// `WHEN [NOT] MATCHED [AND <expr>] THEN <action>`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MergeClause<T: AstMeta> {
+    /// If this clause applies to source rows that matched a row in the target
+    /// table.
+    pub matched: bool,
+    pub condition: Option<Expr<T>>,
+    pub action: MergeAction<T>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub enum MergeAction<T: AstMeta> {
+    /// `UPDATE SET <assignments>`, only valid for matched rows.
+    Update { assignments: Vec<Assignment<T>> },
+    /// `DELETE`, only valid for matched rows.
+    Delete,
+    /// `INSERT [(<columns>)] VALUES (<exprs>)`, only valid for unmatched rows.
+    Insert {
+        columns: Vec<Ident>,
+        values: Vec<Expr<T>>,
+    },
+    /// `DO NOTHING`
+    DoNothing,
+}
+
+impl AstParseable for Merge<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::MERGE)?;
+        parser.expect_keyword(Keyword::INTO)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        parser.expect_keyword(Keyword::USING)?;
+        let source = FromNode::parse(parser)?;
+
+        parser.expect_keyword(Keyword::ON)?;
+        let condition = Expr::parse(parser)?;
+
+        let mut clauses = Vec::new();
+        while parser.parse_keyword(Keyword::WHEN) {
+            clauses.push(MergeClause::parse(parser)?);
+        }
+
+        if clauses.is_empty() {
+            return Err(RayexecError::new("MERGE requires at least one WHEN clause"));
+        }
+
+        Ok(Merge {
+            table,
+            alias,
+            source,
+            condition,
+            clauses,
+        })
+    }
+}
+
+impl AstParseable for MergeClause<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let matched = !parser.parse_keyword(Keyword::NOT);
+        parser.expect_keyword(Keyword::MATCHED)?;
+
+        let condition = if parser.parse_keyword(Keyword::AND) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        parser.expect_keyword(Keyword::THEN)?;
+
+        let action = match parser.next_keyword()? {
+            Keyword::UPDATE if matched => {
+                parser.expect_keyword(Keyword::SET)?;
+                let assignments = parser.parse_comma_separated(Assignment::parse)?;
+                MergeAction::Update { assignments }
+            }
+            Keyword::DELETE if matched => MergeAction::Delete,
+            Keyword::INSERT if !matched => {
+                let columns = if parser.consume_token(&Token::LeftParen) {
+                    let columns = parser.parse_comma_separated(Ident::parse)?;
+                    parser.expect_token(&Token::RightParen)?;
+                    columns
+                } else {
+                    Vec::new()
+                };
+
+                parser.expect_keyword(Keyword::VALUES)?;
+                parser.expect_token(&Token::LeftParen)?;
+                let values = parser.parse_comma_separated(Expr::parse)?;
+                parser.expect_token(&Token::RightParen)?;
+
+                MergeAction::Insert { columns, values }
+            }
+            Keyword::DO => {
+                parser.expect_keyword(Keyword::NOTHING)?;
+                MergeAction::DoNothing
+            }
+            other if matched => {
+                return Err(RayexecError::new(format!(
+                    "Expected UPDATE, DELETE, or DO NOTHING for WHEN MATCHED, got {other}"
+                )))
+            }
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected INSERT or DO NOTHING for WHEN NOT MATCHED, got {other}"
+                )))
+            }
+        };
+
+        Ok(MergeClause {
+            matched,
+            condition,
+            action,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, FromAlias, FromBaseTable, FromNodeBody, Literal};
+
+    fn ident_eq(left: &[&str], right: &[&str]) -> Expr<Raw> {
+        Expr::BinaryExpr {
+            left: Box::new(Expr::CompoundIdent(
+                left.iter().map(|s| Ident::new_unquoted(*s)).collect(),
+            )),
+            op: BinaryOperator::Eq,
+            right: Box::new(Expr::CompoundIdent(
+                right.iter().map(|s| Ident::new_unquoted(*s)).collect(),
+            )),
+        }
+    }
+
+    #[test]
+    fn basic() {
+        let got = parse_ast(
+            "merge into t1 using t2 on t1.a = t2.a
+               when matched then update set b = 1
+               when not matched then insert values (2, 3)",
+        )
+        .unwrap();
+        let expected = Merge {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            source: FromNode {
+                alias: None,
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            },
+            condition: ident_eq(&["t1", "a"], &["t2", "a"]),
+            clauses: vec![
+                MergeClause {
+                    matched: true,
+                    condition: None,
+                    action: MergeAction::Update {
+                        assignments: vec![Assignment {
+                            column: Ident::new_unquoted("b"),
+                            expr: Expr::Literal(Literal::Number("1".to_string())),
+                        }],
+                    },
+                },
+                MergeClause {
+                    matched: false,
+                    condition: None,
+                    action: MergeAction::Insert {
+                        columns: Vec::new(),
+                        values: vec![
+                            Expr::Literal(Literal::Number("2".to_string())),
+                            Expr::Literal(Literal::Number("3".to_string())),
+                        ],
+                    },
+                },
+            ],
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn aliases_and_conditions() {
+        let got = parse_ast(
+            "merge into t1 as t using t2 s on t.a = s.a
+               when matched and s.b then delete
+               when matched then do nothing
+               when not matched and s.b then insert (a) values (s.a)
+               when not matched then do nothing",
+        )
+        .unwrap();
+        let expected = Merge {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("t")),
+            source: FromNode {
+                alias: Some(FromAlias {
+                    alias: Ident::new_unquoted("s"),
+                    columns: None,
+                }),
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            },
+            condition: ident_eq(&["t", "a"], &["s", "a"]),
+            clauses: vec![
+                MergeClause {
+                    matched: true,
+                    condition: Some(Expr::CompoundIdent(vec![
+                        Ident::new_unquoted("s"),
+                        Ident::new_unquoted("b"),
+                    ])),
+                    action: MergeAction::Delete,
+                },
+                MergeClause {
+                    matched: true,
+                    condition: None,
+                    action: MergeAction::DoNothing,
+                },
+                MergeClause {
+                    matched: false,
+                    condition: Some(Expr::CompoundIdent(vec![
+                        Ident::new_unquoted("s"),
+                        Ident::new_unquoted("b"),
+                    ])),
+                    action: MergeAction::Insert {
+                        columns: vec![Ident::new_unquoted("a")],
+                        values: vec![Expr::CompoundIdent(vec![
+                            Ident::new_unquoted("s"),
+                            Ident::new_unquoted("a"),
+                        ])],
+                    },
+                },
+                MergeClause {
+                    matched: false,
+                    condition: None,
+                    action: MergeAction::DoNothing,
+                },
+            ],
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn missing_when() {
+        parse_ast::<Merge<_>>("merge into t1 using t2 on t1.a = t2.a").unwrap_err();
+    }
+
+    #[test]
+    fn invalid_action_for_clause() {
+        parse_ast::<Merge<_>>(
+            "merge into t1 using t2 on t1.a = t2.a when matched then insert values (1)",
+        )
+        .unwrap_err();
+        parse_ast::<Merge<_>>("merge into t1 using t2 on t1.a = t2.a when not matched then delete")
+            .unwrap_err();
+    }
+}
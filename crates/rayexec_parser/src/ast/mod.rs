@@ -27,6 +27,16 @@ pub mod explain;
 pub use explain::*;
 pub mod insert;
 pub use insert::*;
+pub mod update;
+pub use update::*;
+pub mod delete;
+pub use delete::*;
+pub mod merge;
+pub use merge::*;
+pub mod prepare;
+pub use prepare::*;
+pub mod transaction;
+pub use transaction::*;
 pub mod variable;
 pub use variable::*;
 pub mod cte;
@@ -7,8 +7,28 @@ use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
-pub struct OrderByModifier<T: AstMeta> {
-    pub order_by_nodes: Vec<OrderByNode<T>>,
+pub enum OrderByModifier<T: AstMeta> {
+    /// `ORDER BY ALL [ASC|DESC] [NULLS FIRST|LAST]`
+    ///
+    /// Orders by every column in the select list, left to right.
+    All {
+        typ: Option<OrderByType>,
+        nulls: Option<OrderByNulls>,
+    },
+    /// `ORDER BY <expr> [ASC|DESC] [NULLS FIRST|LAST][, ...]`
+    Nodes { order_by_nodes: Vec<OrderByNode<T>> },
+}
+
+impl AstParseable for OrderByModifier<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        if parser.parse_keyword(Keyword::ALL) {
+            let (typ, nulls) = OrderByNode::parse_type_and_nulls(parser);
+            Ok(OrderByModifier::All { typ, nulls })
+        } else {
+            let order_by_nodes = parser.parse_comma_separated(OrderByNode::parse)?;
+            Ok(OrderByModifier::Nodes { order_by_nodes })
+        }
+    }
 }
 
 /// A single node in an ORDER BY clause.
@@ -22,7 +42,16 @@ pub struct OrderByNode<T: AstMeta> {
 impl AstParseable for OrderByNode<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
         let expr = Expr::parse(parser)?;
+        let (typ, nulls) = Self::parse_type_and_nulls(parser);
 
+        Ok(OrderByNode { typ, nulls, expr })
+    }
+}
+
+impl OrderByNode<Raw> {
+    /// Parse the optional `ASC|DESC` and `NULLS FIRST|LAST` following an
+    /// ordering expression.
+    fn parse_type_and_nulls(parser: &mut Parser) -> (Option<OrderByType>, Option<OrderByNulls>) {
         let typ = if parser.parse_keyword(Keyword::ASC) {
             Some(OrderByType::Asc)
         } else if parser.parse_keyword(Keyword::DESC) {
@@ -39,7 +68,7 @@ impl AstParseable for OrderByNode<Raw> {
             None
         };
 
-        Ok(OrderByNode { typ, nulls, expr })
+        (typ, nulls)
     }
 }
 
@@ -0,0 +1,197 @@
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::{AstParseable, DataType, Expr, Ident};
+use crate::keywords::Keyword;
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+use crate::statement::RawStatement;
+use crate::tokens::Token;
+
+/// `PREPARE <name> [(<types>)] AS <statement>`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Prepare<T: AstMeta> {
+    pub name: Ident,
+    /// Optional types for the parameters. Parameter types are inferred during
+    /// binding for any parameter not listed here.
+    pub param_types: Vec<T::DataType>,
+    /// The statement to prepare.
+    ///
+    /// This is kept unresolved since it's resolved and bound each time it's
+    /// executed.
+    pub statement: Box<RawStatement>,
+}
+
+impl AstParseable for Prepare<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::PREPARE)?;
+        let name = Ident::parse(parser)?;
+
+        let param_types = if parser.consume_token(&Token::LeftParen) {
+            let types = parser.parse_comma_separated(DataType::parse)?;
+            parser.expect_token(&Token::RightParen)?;
+            types
+        } else {
+            Vec::new()
+        };
+
+        parser.expect_keyword(Keyword::AS)?;
+
+        let statement = match parser.peek_keyword() {
+            Some(Keyword::PREPARE | Keyword::EXECUTE | Keyword::DEALLOCATE) => {
+                return Err(RayexecError::new(
+                    "Cannot prepare PREPARE, EXECUTE, or DEALLOCATE statements",
+                ))
+            }
+            _ => parser.parse_statement()?,
+        };
+
+        Ok(Prepare {
+            name,
+            param_types,
+            statement: Box::new(statement),
+        })
+    }
+}
+
+/// `EXECUTE <name> [(<params>)]`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Execute<T: AstMeta> {
+    pub name: Ident,
+    pub params: Vec<Expr<T>>,
+}
+
+impl AstParseable for Execute<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::EXECUTE)?;
+        let name = Ident::parse(parser)?;
+
+        let params = if parser.consume_token(&Token::LeftParen) {
+            let params = parser.parse_comma_separated(Expr::parse)?;
+            parser.expect_token(&Token::RightParen)?;
+            params
+        } else {
+            Vec::new()
+        };
+
+        Ok(Execute { name, params })
+    }
+}
+
+/// `DEALLOCATE [PREPARE] <name>`
+/// `DEALLOCATE [PREPARE] ALL`
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Deallocate {
+    /// Name of the prepared statement to deallocate, None if deallocating all
+    /// prepared statements.
+    pub name: Option<Ident>,
+}
+
+impl AstParseable for Deallocate {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::DEALLOCATE)?;
+        let _ = parser.parse_keyword(Keyword::PREPARE);
+
+        let name = if parser.parse_keyword(Keyword::ALL) {
+            None
+        } else {
+            Some(Ident::parse(parser)?)
+        };
+
+        Ok(Deallocate { name })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, LimitModifier, Literal, QueryNode, QueryNodeBody, Values};
+
+    /// Statement for 'values (<exprs>)'
+    fn values_statement(exprs: Vec<Expr<Raw>>) -> RawStatement {
+        RawStatement::Query(QueryNode {
+            ctes: None,
+            order_by: None,
+            body: QueryNodeBody::Values(Values { rows: vec![exprs] }),
+            limit: LimitModifier {
+                limit: None,
+                offset: None,
+            },
+        })
+    }
+
+    #[test]
+    fn prepare_dollar_params() {
+        let got = parse_ast("prepare q as values ($1 + $2)").unwrap();
+        let expected = Prepare {
+            name: Ident::new_unquoted("q"),
+            param_types: Vec::new(),
+            statement: Box::new(values_statement(vec![Expr::BinaryExpr {
+                left: Box::new(Expr::Parameter(1)),
+                op: BinaryOperator::Plus,
+                right: Box::new(Expr::Parameter(2)),
+            }])),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn prepare_question_mark_params_with_types() {
+        let got = parse_ast("prepare q (int, text) as values (?, ?)").unwrap();
+        let expected = Prepare {
+            name: Ident::new_unquoted("q"),
+            param_types: vec![DataType::Integer, DataType::Varchar(None)],
+            statement: Box::new(values_statement(vec![
+                Expr::Parameter(1),
+                Expr::Parameter(2),
+            ])),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn prepare_nested_prepare() {
+        parse_ast::<Prepare<_>>("prepare q as prepare q2 as select 1").unwrap_err();
+    }
+
+    #[test]
+    fn zero_parameter() {
+        parse_ast::<Prepare<_>>("prepare q as select $0").unwrap_err();
+    }
+
+    #[test]
+    fn execute_with_params() {
+        let got = parse_ast("execute q(1, 'a')").unwrap();
+        let expected = Execute {
+            name: Ident::new_unquoted("q"),
+            params: vec![
+                Expr::Literal(Literal::Number("1".to_string())),
+                Expr::Literal(Literal::SingleQuotedString("a".to_string())),
+            ],
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn execute_no_params() {
+        let got = parse_ast("execute q").unwrap();
+        let expected = Execute {
+            name: Ident::new_unquoted("q"),
+            params: Vec::new(),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn deallocate() {
+        let got: Deallocate = parse_ast("deallocate q").unwrap();
+        assert_eq!(Some(Ident::new_unquoted("q")), got.name);
+
+        let got: Deallocate = parse_ast("deallocate prepare q").unwrap();
+        assert_eq!(Some(Ident::new_unquoted("q")), got.name);
+
+        let got: Deallocate = parse_ast("deallocate all").unwrap();
+        assert_eq!(None, got.name);
+    }
+}
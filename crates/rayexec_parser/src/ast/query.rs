@@ -1,15 +1,7 @@
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{
-    AstParseable,
-    CommonTableExprs,
-    Expr,
-    LimitModifier,
-    OrderByModifier,
-    OrderByNode,
-    SelectNode,
-};
+use super::{AstParseable, CommonTableExprs, Expr, LimitModifier, OrderByModifier, SelectNode};
 use crate::keywords::Keyword;
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -34,9 +26,7 @@ impl AstParseable for QueryNode<Raw> {
         let body = QueryNodeBody::parse(parser)?;
 
         let order_by = if parser.parse_keyword_sequence(&[Keyword::ORDER, Keyword::BY]) {
-            Some(OrderByModifier {
-                order_by_nodes: parser.parse_comma_separated(OrderByNode::parse)?,
-            })
+            Some(OrderByModifier::parse(parser)?)
         } else {
             None
         };
@@ -165,7 +155,7 @@ mod tests {
 
     use super::*;
     use crate::ast::testutil::parse_ast;
-    use crate::ast::Literal;
+    use crate::ast::{Literal, OrderByNode, OrderByNulls, OrderByType};
 
     #[test]
     fn values_one_row() {
@@ -200,4 +190,45 @@ mod tests {
         };
         assert_eq!(expected, values);
     }
+
+    #[test]
+    fn order_by_all() {
+        let query: QueryNode<_> = parse_ast("SELECT * FROM t ORDER BY ALL").unwrap();
+        let expected = OrderByModifier::All {
+            typ: None,
+            nulls: None,
+        };
+        assert_eq!(Some(expected), query.order_by);
+    }
+
+    #[test]
+    fn order_by_all_desc_nulls_last() {
+        let query: QueryNode<_> =
+            parse_ast("SELECT * FROM t ORDER BY ALL DESC NULLS LAST").unwrap();
+        let expected = OrderByModifier::All {
+            typ: Some(OrderByType::Desc),
+            nulls: Some(OrderByNulls::Last),
+        };
+        assert_eq!(Some(expected), query.order_by);
+    }
+
+    #[test]
+    fn order_by_exprs() {
+        let query: QueryNode<_> = parse_ast("SELECT * FROM t ORDER BY 1 DESC, 2").unwrap();
+        let expected = OrderByModifier::Nodes {
+            order_by_nodes: vec![
+                OrderByNode {
+                    typ: Some(OrderByType::Desc),
+                    nulls: None,
+                    expr: Expr::Literal(Literal::Number("1".to_string())),
+                },
+                OrderByNode {
+                    typ: None,
+                    nulls: None,
+                    expr: Expr::Literal(Literal::Number("2".to_string())),
+                },
+            ],
+        };
+        assert_eq!(Some(expected), query.order_by);
+    }
 }
@@ -1,7 +1,15 @@
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, DistinctModifier, Expr, FromNode, Ident, ObjectReference};
+use super::{
+    AstParseable,
+    DistinctModifier,
+    Expr,
+    FromNode,
+    Ident,
+    NamedWindowDefinition,
+    ObjectReference,
+};
 use crate::keywords::{Keyword, RESERVED_FOR_COLUMN_ALIAS};
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -21,6 +29,8 @@ pub struct SelectNode<T: AstMeta> {
     pub group_by: Option<GroupByNode<T>>,
     /// HAVING
     pub having: Option<Expr<T>>,
+    /// WINDOW
+    pub windows: Vec<NamedWindowDefinition<T>>,
 }
 
 impl AstParseable for SelectNode<Raw> {
@@ -58,6 +68,13 @@ impl AstParseable for SelectNode<Raw> {
             None
         };
 
+        // WINDOW
+        let windows = if parser.parse_keyword(Keyword::WINDOW) {
+            parser.parse_comma_separated(NamedWindowDefinition::parse)?
+        } else {
+            Vec::new()
+        };
+
         Ok(SelectNode {
             distinct: None,
             projections,
@@ -65,6 +82,7 @@ impl AstParseable for SelectNode<Raw> {
             where_expr,
             group_by,
             having,
+            windows,
         })
     }
 }
@@ -261,7 +279,18 @@ impl AstParseable for GroupByNode<Raw> {
         if parser.parse_keyword(Keyword::ALL) {
             Ok(GroupByNode::All)
         } else {
-            let exprs = parser.parse_comma_separated(GroupByExpr::parse)?;
+            let elements = parser.parse_comma_separated(GroupByExpr::parse)?;
+
+            // Merge adjacent plain expressions into a single element such that
+            // `GROUP BY a, b, ROLLUP (c)` has elements `[a, b]` and `ROLLUP (c)`.
+            let mut exprs: Vec<GroupByExpr<Raw>> = Vec::with_capacity(elements.len());
+            for element in elements {
+                match (exprs.last_mut(), element) {
+                    (Some(GroupByExpr::Expr(prev)), GroupByExpr::Expr(curr)) => prev.extend(curr),
+                    (_, element) => exprs.push(element),
+                }
+            }
+
             Ok(GroupByNode::Exprs { exprs })
         }
     }
@@ -275,8 +304,11 @@ pub enum GroupByExpr<T: AstMeta> {
     Cube(Vec<Expr<T>>),
     /// `GROUP BY ROLLUP (<expr>)`
     Rollup(Vec<Expr<T>>),
-    /// `GROUP BY GROUPING SETS (<expr>)`
-    GroupingSets(Vec<Expr<T>>), // TODO: vec vec
+    /// `GROUP BY GROUPING SETS (<set>[, ...])`
+    ///
+    /// Each set is either a single expression, or a parenthesized list of zero
+    /// or more expressions.
+    GroupingSets(Vec<Vec<Expr<T>>>),
 }
 
 impl AstParseable for GroupByExpr<Raw> {
@@ -302,17 +334,43 @@ impl AstParseable for GroupByExpr<Raw> {
                     let exprs = parser.parse_parenthesized_comma_separated(Expr::parse)?;
                     return Ok(GroupByExpr::Rollup(exprs));
                 }
-                Keyword::GROUPING => {
+                Keyword::GROUPING
+                    if parser.peek_nth(1).and_then(|tok| tok.keyword()) == Some(Keyword::SETS) =>
+                {
                     parser.next();
-                    parser.expect_keyword(Keyword::SETS)?;
-                    let exprs = parser.parse_parenthesized_comma_separated(Expr::parse)?;
-                    return Ok(GroupByExpr::GroupingSets(exprs));
+                    parser.next();
+                    let sets =
+                        parser.parse_parenthesized_comma_separated(Self::parse_grouping_set)?;
+                    return Ok(GroupByExpr::GroupingSets(sets));
                 }
                 _ => (), // Fallthrough, need to parse as an expression.
             }
         }
 
-        let exprs = parser.parse_comma_separated(Expr::parse)?;
-        Ok(GroupByExpr::Expr(exprs))
+        let expr = Expr::parse(parser)?;
+        Ok(GroupByExpr::Expr(vec![expr]))
+    }
+}
+
+impl GroupByExpr<Raw> {
+    /// Parse a single set within `GROUPING SETS`.
+    ///
+    /// Parenthesized lists are tried first, falling back to parsing a single
+    /// expression for things like `(a + b) * 2`.
+    fn parse_grouping_set(parser: &mut Parser) -> Result<Vec<Expr<Raw>>> {
+        if matches!(parser.peek().map(|tok| &tok.token), Some(Token::LeftParen)) {
+            let exprs = parser.maybe_parse(|parser| {
+                let exprs = parser.parse_parenthesized_comma_separated(Expr::parse)?;
+                match parser.peek().map(|tok| &tok.token) {
+                    Some(Token::Comma) | Some(Token::RightParen) => Ok(exprs),
+                    _ => Err(RayexecError::new("Expected end of grouping set")),
+                }
+            });
+            if let Some(exprs) = exprs {
+                return Ok(exprs);
+            }
+        }
+
+        Ok(vec![Expr::parse(parser)?])
     }
 }
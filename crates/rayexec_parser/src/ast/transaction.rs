@@ -0,0 +1,87 @@
+use rayexec_error::{RayexecError, Result};
+use serde::{Deserialize, Serialize};
+
+use super::AstParseable;
+use crate::keywords::Keyword;
+use crate::parser::Parser;
+
+/// `BEGIN [TRANSACTION | WORK]`
+/// `START TRANSACTION`
+/// `COMMIT [TRANSACTION | WORK]`
+/// `END [TRANSACTION | WORK]`
+/// `ROLLBACK [TRANSACTION | WORK]`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TransactionStatement {
+    Begin,
+    Commit,
+    Rollback,
+}
+
+impl AstParseable for TransactionStatement {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let stmt = match parser.next_keyword()? {
+            Keyword::BEGIN => TransactionStatement::Begin,
+            Keyword::START => {
+                parser.expect_keyword(Keyword::TRANSACTION)?;
+                return Ok(TransactionStatement::Begin);
+            }
+            Keyword::COMMIT | Keyword::END => TransactionStatement::Commit,
+            Keyword::ROLLBACK => TransactionStatement::Rollback,
+            other => {
+                return Err(RayexecError::new(format!(
+                    "Expected BEGIN, START, COMMIT, END, or ROLLBACK, got {other}"
+                )))
+            }
+        };
+
+        let _ = parser.parse_one_of_keywords(&[Keyword::TRANSACTION, Keyword::WORK]);
+
+        Ok(stmt)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+
+    #[test]
+    fn begin() {
+        for sql in [
+            "begin",
+            "begin transaction",
+            "begin work",
+            "start transaction",
+        ] {
+            let got: TransactionStatement = parse_ast(sql).unwrap();
+            assert_eq!(TransactionStatement::Begin, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn commit() {
+        for sql in [
+            "commit",
+            "commit transaction",
+            "commit work",
+            "end",
+            "end transaction",
+        ] {
+            let got: TransactionStatement = parse_ast(sql).unwrap();
+            assert_eq!(TransactionStatement::Commit, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn rollback() {
+        for sql in ["rollback", "rollback transaction", "rollback work"] {
+            let got: TransactionStatement = parse_ast(sql).unwrap();
+            assert_eq!(TransactionStatement::Rollback, got, "sql: {sql}");
+        }
+    }
+
+    #[test]
+    fn start_requires_transaction() {
+        parse_ast::<TransactionStatement>("start").unwrap_err();
+    }
+}
@@ -0,0 +1,145 @@
+use rayexec_error::Result;
+use serde::{Deserialize, Serialize};
+
+use super::{AstParseable, Expr, FromNode, Ident, ObjectReference};
+use crate::keywords::{Keyword, RESERVED_FOR_TABLE_ALIAS};
+use crate::meta::{AstMeta, Raw};
+use crate::parser::Parser;
+use crate::tokens::Token;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Update<T: AstMeta> {
+    pub table: T::TableReference,
+    pub alias: Option<Ident>,
+    pub assignments: Vec<Assignment<T>>,
+    pub from: Option<FromNode<T>>,
+    pub where_expr: Option<Expr<T>>,
+}
+
+/// A single `<column> = <expr>` in the SET clause.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Assignment<T: AstMeta> {
+    pub column: Ident,
+    pub expr: Expr<T>,
+}
+
+impl AstParseable for Update<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        parser.expect_keyword(Keyword::UPDATE)?;
+
+        let table = ObjectReference::parse(parser)?;
+        let alias = parser.parse_alias(RESERVED_FOR_TABLE_ALIAS)?;
+
+        parser.expect_keyword(Keyword::SET)?;
+        let assignments = parser.parse_comma_separated(Assignment::parse)?;
+
+        let from = if parser.parse_keyword(Keyword::FROM) {
+            Some(FromNode::parse(parser)?)
+        } else {
+            None
+        };
+
+        let where_expr = if parser.parse_keyword(Keyword::WHERE) {
+            Some(Expr::parse(parser)?)
+        } else {
+            None
+        };
+
+        Ok(Update {
+            table,
+            alias,
+            assignments,
+            from,
+            where_expr,
+        })
+    }
+}
+
+impl AstParseable for Assignment<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let column = Ident::parse(parser)?;
+        parser.expect_token(&Token::Eq)?;
+        let expr = Expr::parse(parser)?;
+
+        Ok(Assignment { column, expr })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ast::testutil::parse_ast;
+    use crate::ast::{BinaryOperator, FromBaseTable, FromNodeBody, Literal};
+
+    #[test]
+    fn basic() {
+        let got = parse_ast("update t1 set c1 = 1").unwrap();
+        let expected = Update {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            assignments: vec![Assignment {
+                column: Ident::new_unquoted("c1"),
+                expr: Expr::Literal(Literal::Number("1".to_string())),
+            }],
+            from: None,
+            where_expr: None,
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn alias_multiple_assignments_where() {
+        let got = parse_ast("update t1 AS a set c1 = 1, c2 = c3 where c1 > 2").unwrap();
+        let expected = Update {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: Some(Ident::new_unquoted("a")),
+            assignments: vec![
+                Assignment {
+                    column: Ident::new_unquoted("c1"),
+                    expr: Expr::Literal(Literal::Number("1".to_string())),
+                },
+                Assignment {
+                    column: Ident::new_unquoted("c2"),
+                    expr: Expr::Ident(Ident::new_unquoted("c3")),
+                },
+            ],
+            from: None,
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("c1"))),
+                op: BinaryOperator::Gt,
+                right: Box::new(Expr::Literal(Literal::Number("2".to_string()))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn with_from() {
+        let got = parse_ast("update t1 set c1 = 1 from t2 where c2 = c3").unwrap();
+        let expected = Update {
+            table: ObjectReference::from_strings(["t1"]),
+            alias: None,
+            assignments: vec![Assignment {
+                column: Ident::new_unquoted("c1"),
+                expr: Expr::Literal(Literal::Number("1".to_string())),
+            }],
+            from: Some(FromNode {
+                alias: None,
+                body: FromNodeBody::BaseTable(FromBaseTable {
+                    reference: ObjectReference::from_strings(["t2"]),
+                }),
+            }),
+            where_expr: Some(Expr::BinaryExpr {
+                left: Box::new(Expr::Ident(Ident::new_unquoted("c2"))),
+                op: BinaryOperator::Eq,
+                right: Box::new(Expr::Ident(Ident::new_unquoted("c3"))),
+            }),
+        };
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn missing_set() {
+        parse_ast::<Update<_>>("update t1 c1 = 1").unwrap_err();
+    }
+}
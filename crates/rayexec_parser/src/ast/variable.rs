@@ -1,7 +1,7 @@
 use rayexec_error::{RayexecError, Result};
 use serde::{Deserialize, Serialize};
 
-use super::{AstParseable, Expr, ObjectReference};
+use super::{AstParseable, Expr, Ident, ObjectReference};
 use crate::keywords::Keyword;
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
@@ -17,6 +17,15 @@ impl AstParseable for SetVariable<Raw> {
     fn parse(parser: &mut Parser) -> Result<Self> {
         parser.expect_keyword(Keyword::SET)?;
 
+        // `SET TIME ZONE <value>` is an alias for `SET timezone = <value>`.
+        if parser.parse_keyword_sequence(&[Keyword::TIME, Keyword::ZONE]) {
+            let expr = Expr::parse(parser)?;
+            return Ok(SetVariable {
+                reference: ObjectReference(vec![Ident::new_unquoted("timezone")]),
+                value: expr,
+            });
+        }
+
         let name = ObjectReference::parse(parser)?;
         if parser.parse_keyword(Keyword::TO) || parser.consume_token(&Token::Eq) {
             let expr = Expr::parse(parser)?;
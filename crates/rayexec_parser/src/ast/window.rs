@@ -5,6 +5,7 @@ use super::{AstParseable, Expr, Ident, OrderByNode};
 use crate::keywords::Keyword;
 use crate::meta::{AstMeta, Raw};
 use crate::parser::Parser;
+use crate::tokens::Token;
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub enum WindowSpec<T: AstMeta> {
@@ -63,6 +64,33 @@ impl AstParseable for WindowDefinition<Raw> {
     }
 }
 
+/// A window definition in a WINDOW clause.
+///
+/// `WINDOW <name> AS (<definition>)`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct NamedWindowDefinition<T: AstMeta> {
+    pub name: Ident,
+    pub definition: WindowDefinition<T>,
+}
+
+impl AstParseable for NamedWindowDefinition<Raw> {
+    fn parse(parser: &mut Parser) -> Result<Self> {
+        let name = Ident::parse(parser)?;
+        parser.expect_keyword(Keyword::AS)?;
+        parser.expect_token(&Token::LeftParen)?;
+
+        let definition = if parser.consume_token(&Token::RightParen) {
+            WindowDefinition::default()
+        } else {
+            let definition = WindowDefinition::parse(parser)?;
+            parser.expect_token(&Token::RightParen)?;
+            definition
+        };
+
+        Ok(NamedWindowDefinition { name, definition })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
 pub enum WindowFrameUnit {
     Rows,
@@ -224,6 +252,36 @@ mod tests {
         assert_eq!(expected, frame)
     }
 
+    #[test]
+    fn named_window_definition() {
+        let window: NamedWindowDefinition<_> =
+            parse_ast("w AS (PARTITION BY a ORDER BY b)").unwrap();
+        let expected = NamedWindowDefinition {
+            name: Ident::new_unquoted("w"),
+            definition: WindowDefinition {
+                existing: None,
+                partition_by: vec![Expr::Ident(Ident::new_unquoted("a"))],
+                order_by: vec![OrderByNode {
+                    typ: None,
+                    nulls: None,
+                    expr: Expr::Ident(Ident::new_unquoted("b")),
+                }],
+                frame: None,
+            },
+        };
+        assert_eq!(expected, window)
+    }
+
+    #[test]
+    fn named_window_definition_empty() {
+        let window: NamedWindowDefinition<_> = parse_ast("w AS ()").unwrap();
+        let expected = NamedWindowDefinition {
+            name: Ident::new_unquoted("w"),
+            definition: WindowDefinition::default(),
+        };
+        assert_eq!(expected, window)
+    }
+
     #[test]
     fn window_rows_expr_following() {
         let frame: WindowFrame<_> = parse_ast("ROWS 4 FOLLOWING").unwrap();
@@ -52,6 +52,7 @@ define_keywords!(
     ANY,
     AS,
     ASC,
+    AT,
     ATTACH,
     BEGIN,
     BETWEEN,
@@ -59,9 +60,11 @@ define_keywords!(
     BIGINT,
     BIGNUMERIC,
     BINARY,
+    BLOB,
     BOOL,
     BOOLEAN,
     BY,
+    BYTEA,
     CASCADE,
     CASE,
     CAST,
@@ -69,7 +72,10 @@ define_keywords!(
     CENTURIES,
     CENTURY,
     CLUSTER,
+    COLLATE,
     COLUMNS,
+    COMMIT,
+    CONFLICT,
     COPY,
     CREATE,
     CROSS,
@@ -80,14 +86,17 @@ define_keywords!(
     DATE,
     DAY,
     DAYS,
+    DEALLOCATE,
     DECADE,
     DECADES,
     DECIMAL,
+    DELETE,
     DESC,
     DESCRIBE,
     DETACH,
     DISTINCT,
     DISTRIBUTE,
+    DO,
     DOUBLE,
     DOW,
     DOY,
@@ -97,6 +106,7 @@ define_keywords!(
     EPOCH,
     EXCEPT,
     EXCLUDE,
+    EXECUTE,
     EXISTS,
     EXPLAIN,
     EXTERNAL,
@@ -148,7 +158,11 @@ define_keywords!(
     LEFT,
     LIKE,
     LIMIT,
+    LIST,
+    MAP,
+    MATCHED,
     MATERIALIZED,
+    MERGE,
     MICROSECOND,
     MICROSECONDS,
     MILLENIUM,
@@ -164,9 +178,11 @@ define_keywords!(
     NATURAL,
     NO,
     NOT,
+    NOTHING,
     NULL,
     NULLS,
     NUMERIC,
+    OFF,
     OFFSET,
     ON,
     OR,
@@ -177,6 +193,7 @@ define_keywords!(
     PARTITION,
     PIVOT,
     PRECEDING,
+    PREPARE,
     PRIMARY,
     QUALIFY,
     QUARTER,
@@ -206,7 +223,9 @@ define_keywords!(
     SMALLINT,
     SOME,
     SORT,
+    START,
     STRING,
+    STRUCT,
     SUBSTRING,
     TABLE,
     TABLES,
@@ -215,20 +234,26 @@ define_keywords!(
     TEXT,
     THEN,
     TIES,
+    TIME,
     TIMESTAMP,
     TIMESTAMPTZ,
     TIMEZONE,
     TIMEZONE_HOUR,
     TIMEZONE_MINUTE,
+    TIMING,
     TINYINT,
     TO,
     TOP,
+    TRANSACTION,
     TRUE,
     UNBOUNDED,
     UNION,
     UNPIVOT,
+    UPDATE,
     USING,
+    UUID,
     VALUES,
+    VARBINARY,
     VARCHAR,
     VERBOSE,
     VIEW,
@@ -238,8 +263,11 @@ define_keywords!(
     WHERE,
     WINDOW,
     WITH,
+    WITHOUT,
+    WORK,
     YEAR,
     YEARS,
+    ZONE,
 );
 
 /// These keywords can't be used as a table alias, so that `FROM table_name alias`
@@ -276,6 +304,7 @@ pub const RESERVED_FOR_TABLE_ALIAS: &[Keyword] = &[
     Keyword::RIGHT,
     Keyword::NATURAL,
     Keyword::SEMI,
+    Keyword::ANTI,
     Keyword::USING,
     Keyword::CLUSTER,
     Keyword::DISTRIBUTE,
@@ -313,10 +342,13 @@ pub const RESERVED_FOR_COLUMN_ALIAS: &[Keyword] = &[
     Keyword::INTERSECT,
     Keyword::CLUSTER,
     Keyword::DISTRIBUTE,
+    Keyword::WINDOW,
     // Reserved only as a column alias in the `SELECT` clause
     Keyword::FROM,
     Keyword::INTO,
     Keyword::END,
+    // For `INSERT ... SELECT ... ON CONFLICT`
+    Keyword::ON,
 ];
 
 #[cfg(test)]
@@ -8,16 +8,23 @@ use crate::ast::{
     CreateSchema,
     CreateTable,
     CreateView,
+    Deallocate,
+    Delete,
     Describe,
     Detach,
     DropStatement,
+    Execute,
     ExplainNode,
     Ident,
     Insert,
+    Merge,
+    Prepare,
     QueryNode,
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
+    Update,
 };
 use crate::keywords::{Keyword, RESERVED_FOR_COLUMN_ALIAS};
 use crate::meta::Raw;
@@ -37,12 +44,19 @@ pub struct Parser<'a> {
     sql: &'a str,
     /// Index of token we should process next.
     pub(crate) idx: usize,
+    /// Number of `?` parameters seen in the current statement.
+    anonymous_params: usize,
 }
 
 impl<'a> Parser<'a> {
     /// Create a parser with arbitrary tokens.
     pub fn with_tokens(toks: Vec<TokenWithLocation>, sql: &'a str) -> Self {
-        Parser { toks, sql, idx: 0 }
+        Parser {
+            toks,
+            sql,
+            idx: 0,
+            anonymous_params: 0,
+        }
     }
 
     /// Parse any number of statements, including zero statements.
@@ -74,6 +88,9 @@ impl<'a> Parser<'a> {
                 )));
             }
 
+            // `?` parameters are numbered per statement.
+            self.anonymous_params = 0;
+
             let stmt = self.parse_statement()?;
             stmts.push(stmt);
 
@@ -116,7 +133,20 @@ impl<'a> Parser<'a> {
                         Ok(RawStatement::Query(QueryNode::parse(self)?))
                     }
                     Keyword::INSERT => Ok(RawStatement::Insert(Insert::parse(self)?)),
+                    Keyword::UPDATE => Ok(RawStatement::Update(Update::parse(self)?)),
+                    Keyword::DELETE => Ok(RawStatement::Delete(Delete::parse(self)?)),
+                    Keyword::MERGE => Ok(RawStatement::Merge(Merge::parse(self)?)),
+                    Keyword::PREPARE => Ok(RawStatement::Prepare(Prepare::parse(self)?)),
+                    Keyword::EXECUTE => Ok(RawStatement::Execute(Execute::parse(self)?)),
+                    Keyword::DEALLOCATE => Ok(RawStatement::Deallocate(Deallocate::parse(self)?)),
                     Keyword::EXPLAIN => Ok(RawStatement::Explain(ExplainNode::parse(self)?)),
+                    Keyword::BEGIN
+                    | Keyword::START
+                    | Keyword::COMMIT
+                    | Keyword::END
+                    | Keyword::ROLLBACK => Ok(RawStatement::Transaction(
+                        TransactionStatement::parse(self)?,
+                    )),
                     other => Err(RayexecError::new(format!("Unexpected keyword: {other:?}",))),
                 }
             }
@@ -402,6 +432,12 @@ impl<'a> Parser<'a> {
         }
     }
 
+    /// Get the number to use for the next `?` parameter.
+    pub(crate) fn next_anonymous_parameter(&mut self) -> usize {
+        self.anonymous_params += 1;
+        self.anonymous_params
+    }
+
     /// Get the next token.
     ///
     /// Ignores whitespace.
@@ -6,15 +6,22 @@ use crate::ast::{
     CreateSchema,
     CreateTable,
     CreateView,
+    Deallocate,
+    Delete,
     Describe,
     Detach,
     DropStatement,
+    Execute,
     ExplainNode,
     Insert,
+    Merge,
+    Prepare,
     QueryNode,
     ResetVariable,
     SetVariable,
     Show,
+    TransactionStatement,
+    Update,
 };
 use crate::meta::{AstMeta, Raw};
 
@@ -52,6 +59,27 @@ pub enum Statement<T: AstMeta> {
     /// INSERT INTO ...
     Insert(Insert<T>),
 
+    /// UPDATE ...
+    Update(Update<T>),
+
+    /// DELETE FROM ...
+    Delete(Delete<T>),
+
+    /// MERGE INTO ...
+    Merge(Merge<T>),
+
+    /// PREPARE <name> AS <statement>
+    Prepare(Prepare<T>),
+
+    /// EXECUTE <name>(<params>)
+    Execute(Execute<T>),
+
+    /// DEALLOCATE <name>
+    Deallocate(Deallocate),
+
+    /// BEGIN/COMMIT/ROLLBACK
+    Transaction(TransactionStatement),
+
     /// SET <variable> TO <value>
     SetVariable(SetVariable<T>),
 
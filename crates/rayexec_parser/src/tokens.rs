@@ -9,6 +9,8 @@ pub enum Token {
     Word(Word),
     SingleQuotedString(String),
     Number(String),
+    /// Positional parameter, e.g. '$1'
+    Parameter(usize),
     Whitespace,
     /// '='
     Eq,
@@ -70,6 +72,8 @@ pub enum Token {
     Caret,
     /// '^@'
     CaretAt,
+    /// '?'
+    QuestionMark,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -275,6 +279,14 @@ impl<'a> Tokenizer<'a> {
                 self.state.next();
                 Token::RightBracket
             }
+            '{' => {
+                self.state.next();
+                Token::LeftBrace
+            }
+            '}' => {
+                self.state.next();
+                Token::RightBrace
+            }
             ',' => {
                 self.state.next();
                 Token::Comma
@@ -369,6 +381,22 @@ impl<'a> Tokenizer<'a> {
                     _ => Token::Colon,
                 }
             }
+            '?' => {
+                self.state.next();
+                Token::QuestionMark
+            }
+            // Parameters
+            '$' => {
+                self.state.next();
+                let s = self.state.take_while(|c| c.is_ascii_digit());
+                if s.is_empty() {
+                    return Err(RayexecError::new("Expected parameter number after '$'"));
+                }
+                let n = s
+                    .parse::<usize>()
+                    .map_err(|_| RayexecError::new(format!("Parameter number too large: ${s}")))?;
+                Token::Parameter(n)
+            }
             // Strings
             '\'' => {
                 self.state.next();
@@ -505,4 +533,28 @@ mod tests {
         // hi
         assert_eq!(toks[6].start_idx, 14);
     }
+
+    #[test]
+    fn parameters() {
+        let toks: Vec<_> = Tokenizer::new("$1+$23 ?")
+            .tokenize()
+            .unwrap()
+            .into_iter()
+            .map(|t| t.token)
+            .collect();
+
+        let expected = vec![
+            Token::Parameter(1),
+            Token::Plus,
+            Token::Parameter(23),
+            Token::Whitespace,
+            Token::QuestionMark,
+        ];
+        assert_eq!(expected, toks);
+    }
+
+    #[test]
+    fn parameter_missing_number() {
+        Tokenizer::new("select $a").tokenize().unwrap_err();
+    }
 }
@@ -1,6 +1,7 @@
 pub mod read_postgres;
 
 mod decimal;
+mod types;
 
 use std::collections::HashMap;
 use std::fmt;
@@ -16,6 +17,7 @@ use rayexec_execution::arrays::batch::Batch;
 use rayexec_execution::arrays::datatype::{DataType, DecimalTypeMeta};
 use rayexec_execution::arrays::field::Field;
 use rayexec_execution::arrays::scalar::OwnedScalarValue;
+use rayexec_execution::database::catalog::CatalogTx;
 use rayexec_execution::database::catalog_entry::{CatalogEntry, TableEntry};
 use rayexec_execution::database::memory_catalog::MemoryCatalog;
 use rayexec_execution::datasource::{
@@ -26,6 +28,7 @@ use rayexec_execution::datasource::{
     DataSourceConnection,
 };
 use rayexec_execution::functions::table::TableFunction;
+use rayexec_execution::logical::scan_filter::ScanFilter;
 use rayexec_execution::runtime::{Runtime, TokioHandlerProvider};
 use rayexec_execution::storage::catalog_storage::CatalogStorage;
 use rayexec_execution::storage::table_storage::{
@@ -41,6 +44,7 @@ use tokio_postgres::binary_copy::{BinaryCopyOutRow, BinaryCopyOutStream};
 use tokio_postgres::types::{FromSql, Type as PostgresType};
 use tokio_postgres::NoTls;
 use tracing::debug;
+use types::{PostgresTime, PostgresUuid};
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct PostgresDataSource<R: Runtime> {
@@ -129,7 +133,12 @@ impl<R: Runtime> CatalogStorage for PostgresConnection<R> {
 }
 
 impl<R: Runtime> TableStorage for PostgresConnection<R> {
-    fn data_table(&self, schema: &str, ent: &CatalogEntry) -> Result<Box<dyn DataTable>> {
+    fn data_table(
+        &self,
+        _tx: &CatalogTx,
+        schema: &str,
+        ent: &CatalogEntry,
+    ) -> Result<Box<dyn DataTable>> {
         Ok(Box::new(PostgresDataTable {
             client: self.client.clone(),
             schema: schema.to_string(),
@@ -139,6 +148,7 @@ impl<R: Runtime> TableStorage for PostgresConnection<R> {
 
     fn create_physical_table(
         &self,
+        _tx: &CatalogTx,
         _schema: &str,
         _ent: &CatalogEntry,
     ) -> BoxFuture<'_, Result<Box<dyn DataTable>>> {
@@ -149,7 +159,12 @@ impl<R: Runtime> TableStorage for PostgresConnection<R> {
         })
     }
 
-    fn drop_physical_table(&self, _schema: &str, _ent: &CatalogEntry) -> BoxFuture<'_, Result<()>> {
+    fn drop_physical_table(
+        &self,
+        _tx: &CatalogTx,
+        _schema: &str,
+        _ent: &CatalogEntry,
+    ) -> BoxFuture<'_, Result<()>> {
         Box::pin(async {
             Err(RayexecError::new(
                 "Drop physical table unsupported (postgres)",
@@ -169,6 +184,7 @@ impl DataTable for PostgresDataTable {
     fn scan(
         &self,
         projections: Projections,
+        _filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let schema = self.schema.clone();
@@ -369,9 +385,10 @@ impl PostgresClient {
                 | &PostgresType::VARCHAR
                 | &PostgresType::TEXT
                 | &PostgresType::JSONB
-                | &PostgresType::JSON
-                | &PostgresType::UUID => DataType::Utf8,
+                | &PostgresType::JSON => DataType::Utf8,
+                &PostgresType::UUID => DataType::Uuid,
                 &PostgresType::BYTEA => DataType::Binary,
+                &PostgresType::TIME => DataType::Time64,
                 // While postgres numerics are "unconstrained" by default, we need
                 // to specify the precision and scale for the column. Setting these
                 // same as bigquery.
@@ -413,18 +430,29 @@ impl PostgresClient {
                         decimal.map(|d| d.0.value)
                     }));
 
-                    match primitives.validity() {
-                        Some(validity) => Array::new_with_validity_and_array_data(
-                            DataType::Decimal128(DecimalTypeMeta::new(m.precision, m.scale)),
-                            validity.clone(),
-                            primitives.array_data().clone(),
-                        ),
-                        None => Array::new_with_array_data(
-                            DataType::Decimal128(DecimalTypeMeta::new(m.precision, m.scale)),
-                            primitives.array_data().clone(),
-                        ),
-                    }
+                    with_datatype(
+                        primitives,
+                        DataType::Decimal128(DecimalTypeMeta::new(m.precision, m.scale)),
+                    )
+                }
+                DataType::Time64 => {
+                    let primitives = Array::from_iter(
+                        rows.iter()
+                            .map(|row| row.try_get::<PostgresTime>(idx).ok().map(|t| t.0)),
+                    );
+                    with_datatype(primitives, DataType::Time64)
                 }
+                DataType::Uuid => {
+                    let primitives = Array::from_iter(
+                        rows.iter()
+                            .map(|row| row.try_get::<PostgresUuid>(idx).ok().map(|u| u.0)),
+                    );
+                    with_datatype(primitives, DataType::Uuid)
+                }
+                DataType::Binary => Array::from_iter(
+                    rows.iter()
+                        .map(|row| -> Option<&[u8]> { row.try_get(idx).ok() }),
+                ),
 
                 DataType::Utf8 => Array::from_iter(
                     rows.iter()
@@ -442,3 +470,16 @@ impl PostgresClient {
         Batch::try_from_arrays(arrays)
     }
 }
+
+/// Reinterpret an array built from primitive values as a logical type with the
+/// same physical representation.
+fn with_datatype(primitives: Array, datatype: DataType) -> Array {
+    match primitives.validity() {
+        Some(validity) => Array::new_with_validity_and_array_data(
+            datatype,
+            validity.clone(),
+            primitives.array_data().clone(),
+        ),
+        None => Array::new_with_array_data(datatype, primitives.array_data().clone()),
+    }
+}
@@ -17,7 +17,7 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
+use rayexec_execution::logical::statistics::TableStatistics;
 use rayexec_execution::runtime::Runtime;
 
 use crate::{PostgresClient, PostgresDataTable};
@@ -99,7 +99,7 @@ impl<R: Runtime> ReadPostgres<R> {
             positional_inputs: positional_inputs.into_iter().map(expr::lit).collect(),
             named_inputs,
             function_impl: TableFunctionImpl::Scan(Arc::new(datatable)),
-            cardinality: StatisticsValue::Unknown,
+            statistics: TableStatistics::unknown(),
             schema: table_schema,
         })
     }
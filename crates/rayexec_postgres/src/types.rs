@@ -0,0 +1,45 @@
+//! Decoders for postgres types that tokio-postgres only supports behind
+//! optional features.
+
+use rayexec_error::RayexecError;
+use tokio_postgres::types::{FromSql, Type};
+
+/// Microseconds since midnight.
+#[derive(Debug)]
+pub struct PostgresTime(pub i64);
+
+impl<'a> FromSql<'a> for PostgresTime {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let buf: [u8; 8] = raw
+            .try_into()
+            .map_err(|_| Box::new(RayexecError::new("binary buffer not 8 bytes for time")))?;
+        Ok(PostgresTime(i64::from_be_bytes(buf)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::TIME)
+    }
+}
+
+/// 128-bit UUID.
+#[derive(Debug)]
+pub struct PostgresUuid(pub u128);
+
+impl<'a> FromSql<'a> for PostgresUuid {
+    fn from_sql(
+        _ty: &Type,
+        raw: &'a [u8],
+    ) -> Result<Self, Box<dyn std::error::Error + Sync + Send>> {
+        let buf: [u8; 16] = raw
+            .try_into()
+            .map_err(|_| Box::new(RayexecError::new("binary buffer not 16 bytes for uuid")))?;
+        Ok(PostgresUuid(u128::from_be_bytes(buf)))
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(*ty, Type::UUID)
+    }
+}
@@ -1,4 +1,7 @@
 use pyo3::{pyclass, pymethods, Python};
+use rayexec_error::RayexecError;
+use rayexec_execution::explain::formatter::format_execution_profile;
+use rayexec_execution::logical::logical_explain::ExplainFormat;
 use rayexec_shell::result_table::MaterializedResultTable;
 
 use crate::errors::Result;
@@ -43,4 +46,25 @@ impl PythonMaterializedResultTable {
 
         Ok(())
     }
+
+    /// Renders the execution profile in the same form as the output of
+    /// EXPLAIN ANALYZE.
+    ///
+    /// `format` is either "text" or "json". Returns None if the query wasn't
+    /// run with `collect_profile_data=True`.
+    #[pyo3(signature = (format="text"))]
+    fn execution_profile(&self, format: &str) -> Result<Option<String>> {
+        let format = match format {
+            "text" => ExplainFormat::Text,
+            "json" => ExplainFormat::Json,
+            other => {
+                return Err(RayexecError::new(format!("Unknown profile format: {other}")).into())
+            }
+        };
+
+        match self.table.execution_profile_data() {
+            Some(data) => Ok(Some(format_execution_profile(data, format, true)?)),
+            None => Ok(None),
+        }
+    }
 }
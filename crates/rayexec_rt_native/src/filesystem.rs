@@ -33,6 +33,22 @@ impl LocalFileSystemProvider {
     }
 
     pub fn file_sink(&self, path: &Path) -> Result<Box<dyn FileSink>> {
+        // Writes may be to a new directory, e.g. for hive partitioned
+        // writes.
+        if let Some(parent) = path.parent() {
+            if !parent.as_os_str().is_empty() {
+                fs::create_dir_all(parent).map_err(|e| {
+                    RayexecError::with_source(
+                        format!(
+                            "Failed to create directory for writing at location: {}",
+                            parent.to_string_lossy()
+                        ),
+                        Box::new(e),
+                    )
+                })?;
+            }
+        }
+
         let file = OpenOptions::new()
             .write(true)
             .create(true)
@@ -54,16 +70,16 @@ impl LocalFileSystemProvider {
     }
 
     pub fn list_prefix(&self, dir: &Path) -> Result<Vec<String>> {
-        fn inner(dir: &Path, paths: &mut Vec<String>) -> Result<()> {
+        fn inner(root: &Path, dir: &Path, paths: &mut Vec<String>) -> Result<()> {
             if dir.is_dir() {
                 for entry in fs::read_dir(dir).context("read dir")? {
                     let entry = entry.context("entry")?;
                     let path = entry.path();
                     if path.is_dir() {
-                        inner(&path, paths)?;
+                        inner(root, &path, paths)?;
                     } else {
                         let path = path
-                            .strip_prefix(dir)
+                            .strip_prefix(root)
                             .context("failed to strip path prefix")?;
                         paths.push(
                             path.to_str()
@@ -77,7 +93,7 @@ impl LocalFileSystemProvider {
         }
 
         let mut paths = Vec::new();
-        inner(dir, &mut paths)?;
+        inner(dir, dir, &mut paths)?;
 
         paths.sort();
 
@@ -1,6 +1,7 @@
 //! Implementation of the execution runtime using native threads and thread
 //! pools.
 pub mod runtime;
+pub mod spill;
 pub mod threaded;
 
 mod filesystem;
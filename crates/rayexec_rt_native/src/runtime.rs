@@ -9,6 +9,7 @@ use rayexec_execution::execution::executable::pipeline::{
     ExecutablePipeline,
 };
 use rayexec_execution::runtime::handle::QueryHandle;
+use rayexec_execution::runtime::spill::SpillProvider;
 use rayexec_execution::runtime::{
     ErrorSink,
     OptionalTokioRuntime,
@@ -23,6 +24,7 @@ use rayexec_io::{FileProvider, FileSink, FileSource};
 
 use crate::filesystem::LocalFileSystemProvider;
 use crate::http::TokioWrappedHttpClient;
+use crate::spill::NativeSpillProvider;
 use crate::threaded::ThreadedScheduler;
 use crate::time::NativeInstant;
 
@@ -80,6 +82,7 @@ pub type ThreadedNativeExecutor = NativeExecutor<ThreadedScheduler>;
 #[derive(Debug, Clone)]
 pub struct NativeRuntime {
     tokio: Arc<OptionalTokioRuntime>,
+    spill: Arc<NativeSpillProvider>,
 }
 
 impl NativeRuntime {
@@ -97,6 +100,7 @@ impl NativeRuntime {
 
         Ok(NativeRuntime {
             tokio: Arc::new(OptionalTokioRuntime::new(Some(tokio))),
+            spill: Arc::new(NativeSpillProvider::try_new()?),
         })
     }
 }
@@ -122,6 +126,10 @@ impl Runtime for NativeRuntime {
     fn tokio_handle(&self) -> &Self::TokioHandle {
         self.tokio.as_ref()
     }
+
+    fn spill_provider(&self) -> Option<Arc<dyn SpillProvider>> {
+        Some(self.spill.clone())
+    }
 }
 
 #[derive(Debug, Clone)]
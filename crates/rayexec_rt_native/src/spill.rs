@@ -0,0 +1,238 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, Read, Write};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{mpsc, Arc};
+use std::task::{Context, Poll};
+
+use futures::channel::oneshot;
+use futures::FutureExt;
+use parking_lot::Mutex;
+use rayexec_error::{RayexecError, Result, ResultExt};
+use rayexec_execution::runtime::spill::{
+    SpillChunk,
+    SpillProvider,
+    SpillStorage,
+    SpillStorageReader,
+};
+
+/// File IO to run on the spill thread.
+type SpillJob = Box<dyn FnOnce() + Send>;
+
+/// Counter for generating unique spill file names within this process.
+static NEXT_SPILL_FILE: AtomicUsize = AtomicUsize::new(0);
+
+/// Spills to files in the system's temp directory.
+///
+/// All file IO happens on a dedicated thread so that operators never block on
+/// disk. Jobs run in the order they're submitted, so chunks are always written
+/// before they're read back.
+///
+/// Chunks waiting to be written keep their memory reserved, so a backed up
+/// spill thread counts against the memory limit.
+#[derive(Debug)]
+pub struct NativeSpillProvider {
+    dir: PathBuf,
+    jobs: mpsc::Sender<SpillJob>,
+}
+
+impl NativeSpillProvider {
+    pub fn try_new() -> Result<Self> {
+        let (jobs, recv) = mpsc::channel::<SpillJob>();
+
+        // Thread exits once the provider and all spill files have been
+        // dropped.
+        std::thread::Builder::new()
+            .name("rayexec_spill".to_string())
+            .spawn(move || {
+                for job in recv {
+                    job();
+                }
+            })
+            .context("Failed to spawn spill thread")?;
+
+        Ok(NativeSpillProvider {
+            dir: std::env::temp_dir(),
+            jobs,
+        })
+    }
+}
+
+impl SpillProvider for NativeSpillProvider {
+    fn create_storage(&self) -> Result<Box<dyn SpillStorage>> {
+        let name = format!(
+            "rayexec_spill_{}_{}",
+            std::process::id(),
+            NEXT_SPILL_FILE.fetch_add(1, Ordering::Relaxed)
+        );
+
+        Ok(Box::new(NativeSpillFile {
+            file: Arc::new(SharedSpillFile {
+                path: self.dir.join(name),
+                writer: Mutex::new(None),
+                error: Mutex::new(None),
+            }),
+            jobs: self.jobs.clone(),
+        }))
+    }
+}
+
+fn submit(jobs: &mpsc::Sender<SpillJob>, job: impl FnOnce() + Send + 'static) -> Result<()> {
+    jobs.send(Box::new(job))
+        .map_err(|_| RayexecError::new("Spill thread stopped"))
+}
+
+/// Spill file state shared with jobs on the spill thread.
+#[derive(Debug)]
+struct SharedSpillFile {
+    path: PathBuf,
+    /// Writer for the file, created on the first write.
+    ///
+    /// Only accessed on the spill thread.
+    writer: Mutex<Option<BufWriter<File>>>,
+    /// Error encountered when writing to the file.
+    error: Mutex<Option<String>>,
+}
+
+impl SharedSpillFile {
+    fn check_error(&self) -> Result<()> {
+        match self.error.lock().as_ref() {
+            Some(msg) => Err(RayexecError::new(format!(
+                "Failed to write spill file at '{}': {msg}",
+                self.path.display()
+            ))),
+            None => Ok(()),
+        }
+    }
+
+    /// Write to the file, creating it if needed.
+    ///
+    /// Errors are stored and returned on the next write or read.
+    fn write(&self, f: impl FnOnce(&mut BufWriter<File>) -> io::Result<()>) {
+        if self.error.lock().is_some() {
+            return;
+        }
+
+        let mut writer = self.writer.lock();
+        if writer.is_none() {
+            match File::create(&self.path) {
+                Ok(file) => *writer = Some(BufWriter::new(file)),
+                Err(e) => {
+                    *self.error.lock() = Some(e.to_string());
+                    return;
+                }
+            }
+        }
+
+        if let Err(e) = f(writer.as_mut().expect("writer to exist")) {
+            *self.error.lock() = Some(e.to_string());
+        }
+    }
+
+    /// Read the next length-prefixed chunk from the file.
+    fn read_chunk(&self, reader: &mut Option<BufReader<File>>) -> Result<Option<Vec<u8>>> {
+        self.check_error()?;
+
+        if reader.is_none() {
+            let file = File::open(&self.path)
+                .context_fn(|| format!("Failed to open spill file at '{}'", self.path.display()))?;
+            *reader = Some(BufReader::new(file));
+        }
+        let reader = reader.as_mut().expect("reader to exist");
+
+        let mut len = [0; 8];
+        match reader.read_exact(&mut len) {
+            Ok(()) => (),
+            Err(e) if e.kind() == io::ErrorKind::UnexpectedEof => return Ok(None),
+            Err(e) => return Err(e.into()),
+        }
+
+        let mut chunk = vec![0; u64::from_le_bytes(len) as usize];
+        reader.read_exact(&mut chunk)?;
+
+        Ok(Some(chunk))
+    }
+}
+
+#[derive(Debug)]
+struct NativeSpillFile {
+    file: Arc<SharedSpillFile>,
+    jobs: mpsc::Sender<SpillJob>,
+}
+
+impl SpillStorage for NativeSpillFile {
+    fn write_chunk(&mut self, chunk: SpillChunk) -> Result<()> {
+        self.file.check_error()?;
+
+        // Chunk (and its reservation) is dropped once written.
+        let file = self.file.clone();
+        submit(&self.jobs, move || {
+            file.write(|writer| {
+                let data = chunk.data();
+                writer.write_all(&(data.len() as u64).to_le_bytes())?;
+                writer.write_all(data)
+            })
+        })
+    }
+
+    fn finish(&mut self) -> Result<()> {
+        self.file.check_error()?;
+
+        let file = self.file.clone();
+        submit(&self.jobs, move || file.write(|writer| writer.flush()))
+    }
+
+    fn reader(&self) -> Result<Box<dyn SpillStorageReader>> {
+        Ok(Box::new(NativeSpillReader {
+            file: self.file.clone(),
+            jobs: self.jobs.clone(),
+            reader: Arc::new(Mutex::new(None)),
+            pending: None,
+        }))
+    }
+}
+
+impl Drop for NativeSpillFile {
+    fn drop(&mut self) {
+        // Removed after any pending writes and reads have completed.
+        let file = self.file.clone();
+        let _ = submit(&self.jobs, move || {
+            file.writer.lock().take();
+            let _ = fs::remove_file(&file.path);
+        });
+    }
+}
+
+#[derive(Debug)]
+struct NativeSpillReader {
+    file: Arc<SharedSpillFile>,
+    jobs: mpsc::Sender<SpillJob>,
+    /// Reader for the file, opened on the first read.
+    ///
+    /// Only accessed on the spill thread.
+    reader: Arc<Mutex<Option<BufReader<File>>>>,
+    /// Receiver for a read that's been submitted but not yet received.
+    pending: Option<oneshot::Receiver<Result<Option<Vec<u8>>>>>,
+}
+
+impl SpillStorageReader for NativeSpillReader {
+    fn poll_next_chunk(&mut self, cx: &mut Context) -> Poll<Result<Option<Vec<u8>>>> {
+        if self.pending.is_none() {
+            let (tx, rx) = oneshot::channel();
+            let file = self.file.clone();
+            let reader = self.reader.clone();
+            submit(&self.jobs, move || {
+                let _ = tx.send(file.read_chunk(&mut reader.lock()));
+            })?;
+            self.pending = Some(rx);
+        }
+
+        let result = match self.pending.as_mut().expect("pending read").poll_unpin(cx) {
+            Poll::Ready(result) => result,
+            Poll::Pending => return Poll::Pending,
+        };
+        self.pending = None;
+
+        Poll::Ready(result.unwrap_or_else(|_| Err(RayexecError::new("Spill thread stopped"))))
+    }
+}
@@ -1,15 +1,29 @@
+use std::sync::OnceLock;
+use std::time::Duration;
+
 use rayexec_execution::runtime::time::RuntimeInstant;
 
+/// Reference point for converting instants into durations.
+static REFERENCE: OnceLock<std::time::Instant> = OnceLock::new();
+
 /// Instant implementation that wraps std Instant.
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NativeInstant(std::time::Instant);
 
 impl RuntimeInstant for NativeInstant {
     fn now() -> Self {
-        NativeInstant(std::time::Instant::now())
+        let now = std::time::Instant::now();
+        // Ensure the reference point is never later than an instant.
+        REFERENCE.get_or_init(|| now);
+        NativeInstant(now)
     }
 
     fn duration_since(&self, earlier: Self) -> std::time::Duration {
         self.0.saturating_duration_since(earlier.0)
     }
+
+    fn since_reference(&self) -> Duration {
+        let reference = REFERENCE.get_or_init(|| self.0);
+        self.0.saturating_duration_since(*reference)
+    }
 }
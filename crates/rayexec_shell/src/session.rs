@@ -134,7 +134,7 @@ where
         let mut session = self.session.lock().await;
 
         session.prepare(UNNAMED, self.statement)?;
-        session.bind(UNNAMED, UNNAMED).await?;
+        session.bind(UNNAMED, UNNAMED, Vec::new()).await?;
 
         let result = session.execute(UNNAMED).await?;
 
@@ -23,7 +23,8 @@ use rayexec_execution::functions::table::{
     TableFunctionPlanner,
 };
 use rayexec_execution::functions::{FunctionInfo, Signature};
-use rayexec_execution::logical::statistics::StatisticsValue;
+use rayexec_execution::logical::scan_filter::ScanFilter;
+use rayexec_execution::logical::statistics::TableStatistics;
 use rayexec_execution::runtime::Runtime;
 use rayexec_execution::storage::table_storage::{
     DataTable,
@@ -317,7 +318,7 @@ impl<R: Runtime, O: UnityObjectsOperation<R>> UnityObjects<R, O> {
             function_impl: TableFunctionImpl::Scan(Arc::new(UnityObjectsDataTable::<R, O> {
                 state,
             })),
-            cardinality: StatisticsValue::Unknown,
+            statistics: TableStatistics::unknown(),
             schema: O::schema(),
         })
     }
@@ -332,6 +333,7 @@ impl<R: Runtime, O: UnityObjectsOperation<R>> DataTable for UnityObjectsDataTabl
     fn scan(
         &self,
         projections: Projections,
+        _filters: &[ScanFilter],
         num_partitions: usize,
     ) -> Result<Vec<Box<dyn DataTableScan>>> {
         let stream = O::create_stream_state(&self.state)?;
@@ -12,6 +12,7 @@ use rayexec_execution::execution::executable::pipeline::{
 };
 use rayexec_execution::execution::executable::profiler::ExecutionProfileData;
 use rayexec_execution::runtime::handle::QueryHandle;
+use rayexec_execution::runtime::spill::SpillProvider;
 use rayexec_execution::runtime::{ErrorSink, PipelineExecutor, Runtime, TokioHandlerProvider};
 use rayexec_io::http::HttpClientReader;
 use rayexec_io::location::{AccessConfig, FileLocation};
@@ -58,6 +59,11 @@ impl Runtime for WasmRuntime {
     fn tokio_handle(&self) -> &Self::TokioHandle {
         &MissingTokioHandle
     }
+
+    fn spill_provider(&self) -> Option<Arc<dyn SpillProvider>> {
+        // No file system to spill to.
+        None
+    }
 }
 
 #[derive(Debug, Clone, Copy)]
@@ -39,4 +39,9 @@ impl RuntimeInstant for PerformanceInstant {
         // subtract them.
         self.0.saturating_sub(earlier.0)
     }
+
+    fn since_reference(&self) -> Duration {
+        // Already relative to the time origin.
+        self.0
+    }
 }
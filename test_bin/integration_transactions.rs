@@ -0,0 +1,157 @@
+use std::sync::Arc;
+
+use futures::TryStreamExt;
+use rayexec_error::{RayexecError, Result};
+use rayexec_execution::arrays::batch::Batch;
+use rayexec_execution::datasource::{DataSourceRegistry, MemoryDataSource};
+use rayexec_execution::engine::session::Session;
+use rayexec_execution::engine::Engine;
+use rayexec_execution::runtime::{Runtime, TokioHandlerProvider};
+use rayexec_rt_native::runtime::{NativeRuntime, ThreadedNativeExecutor};
+
+type TestSession = Session<ThreadedNativeExecutor, NativeRuntime>;
+
+/// Checks what concurrent sessions sharing a database can see of each other's
+/// transactions.
+fn main() -> Result<()> {
+    let sched = ThreadedNativeExecutor::try_new().unwrap();
+    let runtime = NativeRuntime::with_default_tokio().unwrap();
+    let tokio_handle = runtime
+        .tokio_handle()
+        .handle()
+        .expect("tokio to be configured");
+
+    let registry =
+        || DataSourceRegistry::default().with_datasource("memory", Box::new(MemoryDataSource));
+    let engine = Engine::new_with_registry(sched.clone(), runtime.clone(), registry()?)?;
+    let session_registry = Arc::new(registry()?);
+
+    // Only temp tables are currently supported, so share the first session's
+    // temp database with the second session.
+    let context1 = engine.new_base_database_context()?;
+    let mut context2 = engine.new_base_database_context()?;
+    context2.detach_database("temp")?;
+    context2.attach_database("temp", context1.get_database("temp")?.clone())?;
+
+    let new_session = |context| -> TestSession {
+        Session::new(
+            context,
+            sched.clone(),
+            runtime.clone(),
+            session_registry.clone(),
+        )
+    };
+    let sessions = [new_session(context1), new_session(context2)];
+    let [mut s1, mut s2] = sessions;
+
+    tokio_handle.block_on(async move {
+        // Uncommitted DDL and inserts aren't visible to other sessions.
+        query(&mut s1, "BEGIN").await?;
+        query(&mut s1, "CREATE TEMP TABLE t1 (a INT)").await?;
+        query(&mut s1, "INSERT INTO t1 VALUES (1), (2)").await?;
+        assert_rows(&mut s1, "SELECT count(*) FROM t1", &["2"]).await?;
+        assert_error(&mut s2, "SELECT * FROM t1").await?;
+
+        // Rolling back undoes the DDL.
+        query(&mut s1, "ROLLBACK").await?;
+        assert_error(&mut s1, "SELECT * FROM t1").await?;
+        assert_error(&mut s2, "SELECT * FROM t1").await?;
+
+        // Committed changes become visible.
+        query(&mut s1, "BEGIN").await?;
+        query(&mut s1, "CREATE TEMP TABLE t2 (a INT)").await?;
+        query(&mut s1, "INSERT INTO t2 VALUES (1), (2)").await?;
+        assert_error(&mut s2, "SELECT * FROM t2").await?;
+        query(&mut s1, "COMMIT").await?;
+        assert_rows(&mut s2, "SELECT count(*) FROM t2", &["2"]).await?;
+
+        // Uncommitted inserts into an existing table aren't visible.
+        query(&mut s1, "BEGIN").await?;
+        query(&mut s1, "INSERT INTO t2 VALUES (3)").await?;
+        assert_rows(&mut s1, "SELECT count(*) FROM t2", &["3"]).await?;
+        assert_rows(&mut s2, "SELECT count(*) FROM t2", &["2"]).await?;
+        query(&mut s1, "ROLLBACK").await?;
+        assert_rows(&mut s1, "SELECT count(*) FROM t2", &["2"]).await?;
+
+        // A reader keeps its snapshot while another session commits.
+        query(&mut s2, "BEGIN").await?;
+        assert_rows(&mut s2, "SELECT count(*) FROM t2", &["2"]).await?;
+        query(&mut s1, "INSERT INTO t2 VALUES (4)").await?;
+        query(&mut s1, "CREATE TEMP TABLE t3 (a INT)").await?;
+        assert_rows(&mut s1, "SELECT count(*) FROM t2", &["3"]).await?;
+        assert_rows(&mut s2, "SELECT count(*) FROM t2", &["2"]).await?;
+        assert_error(&mut s2, "SELECT * FROM t3").await?;
+        query(&mut s2, "COMMIT").await?;
+
+        // New transaction sees the latest commits.
+        assert_rows(&mut s2, "SELECT a FROM t2 ORDER BY a", &["1", "2", "4"]).await?;
+        assert_rows(&mut s2, "SELECT count(*) FROM t3", &["0"]).await?;
+
+        // Snapshots taken while another session runs autocommit updates see
+        // each update either fully applied or not at all.
+        query(&mut s1, "CREATE TEMP TABLE t4 (a INT)").await?;
+        for a in 1..=50 {
+            // Each insert adds a separate batch to the table, and each batch
+            // is updated separately.
+            query(&mut s1, &format!("INSERT INTO t4 VALUES ({a})")).await?;
+        }
+
+        let consistent = "SELECT count(*) = 50 AND max(a) - min(a) = 49 FROM t4";
+        let updates = async {
+            for _ in 0..100 {
+                query(&mut s1, "UPDATE t4 SET a = a + 1").await?;
+            }
+            Ok::<(), RayexecError>(())
+        };
+        let snapshots = async {
+            for _ in 0..100 {
+                query(&mut s2, "BEGIN").await?;
+                assert_rows(&mut s2, consistent, &["true"]).await?;
+                query(&mut s2, "COMMIT").await?;
+                assert_rows(&mut s2, consistent, &["true"]).await?;
+            }
+            Ok::<(), RayexecError>(())
+        };
+        futures::try_join!(updates, snapshots)?;
+        assert_rows(&mut s2, "SELECT min(a) FROM t4", &["101"]).await?;
+
+        Ok::<(), RayexecError>(())
+    })?;
+
+    Ok(())
+}
+
+/// Execute a single statement, returning the first column of every row.
+async fn query(session: &mut TestSession, sql: &str) -> Result<Vec<String>> {
+    let mut rows = Vec::new();
+    for result in session.simple(sql).await? {
+        let batches: Vec<Batch> = result.stream.try_collect().await?;
+        for batch in batches {
+            for idx in 0..batch.num_rows() {
+                if let Some(arr) = batch.array(0) {
+                    rows.push(arr.logical_value(idx)?.to_string());
+                }
+            }
+        }
+    }
+    Ok(rows)
+}
+
+async fn assert_rows(session: &mut TestSession, sql: &str, expected: &[&str]) -> Result<()> {
+    let rows = query(session, sql).await?;
+    if rows != expected {
+        return Err(RayexecError::new(format!(
+            "Unexpected rows for '{sql}', expected: {expected:?}, got: {rows:?}"
+        )));
+    }
+    Ok(())
+}
+
+async fn assert_error(session: &mut TestSession, sql: &str) -> Result<()> {
+    match query(session, sql).await {
+        Ok(rows) => Err(RayexecError::new(format!(
+            "Expected '{sql}' to fail, got: {rows:?}"
+        ))),
+        Err(_) => Ok(()),
+    }
+}